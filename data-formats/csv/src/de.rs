@@ -0,0 +1,402 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+
+//!
+//! Serde [`Deserializer`] for the rows of a [`CSVMapReader`], mapping columns onto fields by
+//! header name.
+//!
+
+use std::fmt::Display;
+use std::io::Read;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use serde::de::value::StrDeserializer;
+use serde::de::{
+    DeserializeOwned, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess,
+    Visitor,
+};
+
+use crate::{CSVError, CSVErrorType, CSVMapReader, Dialect};
+
+impl serde::de::Error for CSVError {
+    fn custom<T: Display>(msg: T) -> Self {
+        CSVError::new(CSVErrorType::SerdeError, msg.to_string())
+    }
+}
+
+impl<T: Read + Sized> CSVMapReader<T> {
+    ///
+    /// Maybe read a single row from the CSV file and deserialize it into a `R`, matching the
+    /// fields of `R` against the header by name.  Empty cells deserialize as [`None`] into
+    /// [`Option`] fields, and numbers & booleans are parsed from the cell text.
+    ///
+    /// Will return [`Result::Ok(None)`] upon EOF
+    /// Will return [`Result::Err(CSVError)`] upon any error from [`Self::next_row`], or if the
+    /// row can't be deserialized, in which case the error includes the row & column number.
+    pub fn next_record<R: DeserializeOwned>(&mut self) -> Result<Option<R>, CSVError> {
+        let Some(row) = self.next_row()? else {
+            return Ok(None);
+        };
+        let keys: Vec<String> = row.keys.iter().map(|k| self.dialect.unquote(k)).collect();
+        R::deserialize(RecordDeserializer {
+            keys: &keys,
+            values: &row.data,
+            dialect: &self.dialect,
+        })
+        .map(Some)
+        .map_err(|e| e.at_row(self.row))
+    }
+
+    ///
+    /// Returns an iterator deserializing each remaining row of the CSV file into a `R`, see
+    /// [`Self::next_record`]
+    pub fn records<R: DeserializeOwned>(self) -> Records<T, R> {
+        Records {
+            reader: self,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+///
+/// Iterator over the deserialized rows of a [`CSVMapReader`], returned by
+/// [`CSVMapReader::records`]
+pub struct Records<T: Read + Sized, R: DeserializeOwned> {
+    reader: CSVMapReader<T>,
+    _phantom: PhantomData<R>,
+}
+
+impl<T: Read + Sized, R: DeserializeOwned> Iterator for Records<T, R> {
+    type Item = Result<R, CSVError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.next_record().transpose()
+    }
+}
+
+/// Deserializes a whole row, as a map of header to value, or a sequence of values.
+struct RecordDeserializer<'a> {
+    keys: &'a [String],
+    values: &'a [String],
+    dialect: &'a Dialect,
+}
+
+impl<'de> Deserializer<'de> for RecordDeserializer<'_> {
+    type Error = CSVError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(RecordAccess::new(self))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_map(RecordAccess::new(self))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_map(visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct enum identifier ignored_any
+    }
+}
+
+/// Walks the columns of a row, as either [`MapAccess`] or [`SeqAccess`]
+struct RecordAccess<'a> {
+    record: RecordDeserializer<'a>,
+    /// Index of the next column
+    index: usize,
+}
+
+impl<'a> RecordAccess<'a> {
+    fn new(record: RecordDeserializer<'a>) -> Self {
+        RecordAccess { record, index: 0 }
+    }
+
+    /// Returns the deserializer for the next column, moving past it.
+    fn next_field(&mut self) -> Option<FieldDeserializer<'a>> {
+        let value = self.record.values.get(self.index)?;
+        let name = self.record.keys.get(self.index).map(String::as_str);
+        self.index += 1;
+        Some(FieldDeserializer {
+            value: self.record.dialect.unquote(value),
+            name: name.unwrap_or_default(),
+            column: self.index,
+        })
+    }
+}
+
+impl<'de> MapAccess<'de> for RecordAccess<'_> {
+    type Error = CSVError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let Some(key) = self.record.keys.get(self.index) else {
+            return Ok(None);
+        };
+        let key: StrDeserializer<CSVError> = key.as_str().into_deserializer();
+        seed.deserialize(key)
+            .map(Some)
+            .map_err(|e| e.at_column(self.index + 1))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let Some(field) = self.next_field() else {
+            return Err(CSVError::new(
+                CSVErrorType::HeaderDataMismatchError,
+                "Row has fewer values than the header".to_string(),
+            ));
+        };
+        let column = field.column;
+        seed.deserialize(field).map_err(|e| e.at_column(column))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.record.keys.len().saturating_sub(self.index))
+    }
+}
+
+impl<'de> SeqAccess<'de> for RecordAccess<'_> {
+    type Error = CSVError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        let Some(field) = self.next_field() else {
+            return Ok(None);
+        };
+        let column = field.column;
+        seed.deserialize(field)
+            .map(Some)
+            .map_err(|e| e.at_column(column))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.record.values.len().saturating_sub(self.index))
+    }
+}
+
+/// Deserializes the value of a single cell, parsing it into whatever type is requested.
+struct FieldDeserializer<'a> {
+    value: String,
+    name: &'a str,
+    /// Column number, starting from 1
+    column: usize,
+}
+
+impl FieldDeserializer<'_> {
+    fn error<M: Display>(&self, msg: M) -> CSVError {
+        CSVError::new(
+            CSVErrorType::SerdeError,
+            format!("Column '{}': {msg}", self.name),
+        )
+        .at_column(self.column)
+    }
+
+    fn parse<T: FromStr>(&self) -> Result<T, CSVError>
+    where
+        T::Err: Display,
+    {
+        let value = self.value.trim();
+        value
+            .parse()
+            .map_err(|e| self.error(format_args!("Unable to parse '{value}': {e}")))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.value.trim().is_empty()
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                visitor.$visit(self.parse::<$ty>()?)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for FieldDeserializer<'_> {
+    type Error = CSVError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_string(self.value)
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let value = self.value.trim();
+        if value.eq_ignore_ascii_case("true") {
+            visitor.visit_bool(true)
+        } else if value.eq_ignore_ascii_case("false") {
+            visitor.visit_bool(false)
+        } else {
+            Err(self.error(format_args!("Unable to parse '{value}' as a bool")))
+        }
+    }
+
+    deserialize_parsed! {
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_i128 => visit_i128: i128,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_u128 => visit_u128: u128,
+        deserialize_f32 => visit_f32: f32,
+        deserialize_f64 => visit_f64: f64,
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let mut chars = self.value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(self.error(format_args!(
+                "Expected a single character, not '{}'",
+                self.value
+            ))),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_string(self.value)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_string(self.value)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_byte_buf(self.value.into_bytes())
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_byte_buf(self.value.into_bytes())
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.is_empty() {
+            visitor.visit_unit()
+        } else {
+            Err(self.error(format_args!(
+                "Expected an empty value, not '{}'",
+                self.value
+            )))
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(self.error("Sequences can't be read from a single value"))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(self.error("Tuples can't be read from a single value"))
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(self.error("Tuple structs can't be read from a single value"))
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(self.error("Maps can't be read from a single value"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(self.error("Structs can't be read from a single value"))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        // only unit variants, by name.
+        let variant: StrDeserializer<CSVError> = self.value.trim().into_deserializer();
+        visitor.visit_enum(variant)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_string(self.value)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+}
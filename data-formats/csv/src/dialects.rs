@@ -14,6 +14,8 @@ pub struct Dialect {
     line_separators: &'static str,
     field_separators: &'static str,
     comment_chars: &'static str,
+    quote_char: Option<u8>,
+    escape_char: Option<u8>,
 }
 impl Default for Dialect {
     fn default() -> Self {
@@ -31,6 +33,26 @@ impl Dialect {
             line_separators,
             field_separators,
             comment_chars,
+            quote_char: Some(b'"'),
+            escape_char: None,
+        }
+    }
+
+    ///
+    /// Sets the (ASCII) character used to quote fields, or [`None`] to disable quoting.
+    #[must_use]
+    pub const fn with_quote_char(self, quote_char: Option<u8>) -> Dialect {
+        Dialect { quote_char, ..self }
+    }
+
+    ///
+    /// Sets the (ASCII) character used to escape a quote character within a quoted field.  If
+    /// [`None`], quote characters are escaped by doubling them, as in RFC4180.
+    #[must_use]
+    pub const fn with_escape_char(self, escape_char: Option<u8>) -> Dialect {
+        Dialect {
+            escape_char,
+            ..self
         }
     }
 
@@ -57,6 +79,73 @@ impl Dialect {
     pub const fn get_comment_chars(&self) -> &str {
         self.comment_chars
     }
+
+    ///
+    /// Returns the optional character used to quote fields,
+    /// Defaults to `"`
+    #[must_use]
+    pub const fn get_quote_char(&self) -> Option<u8> {
+        self.quote_char
+    }
+
+    ///
+    /// Returns the optional character used to escape quotes within a quoted field,
+    /// Defaults to `None`, where quotes are doubled instead.
+    #[must_use]
+    pub const fn get_escape_char(&self) -> Option<u8> {
+        self.escape_char
+    }
+
+    ///
+    /// Wraps the field in the quote character, escaping any quote or escape characters within
+    /// it.  Returns the field unchanged if this dialect has no quote character.
+    #[must_use]
+    pub fn quote(&self, field: &str) -> String {
+        let Some(quote) = self.quote_char.map(char::from) else {
+            return field.to_string();
+        };
+        let mut out = String::with_capacity(field.len() + 2);
+        out.push(quote);
+        for c in field.chars() {
+            match self.escape_char.map(char::from) {
+                Some(esc) if c == quote || c == esc => out.push(esc),
+                None if c == quote => out.push(quote),
+                _ => {}
+            }
+            out.push(c);
+        }
+        out.push(quote);
+        out
+    }
+
+    ///
+    /// Removes the quote characters surrounding the field, if any, and un-escapes any quote
+    /// or escape characters within it.
+    #[must_use]
+    pub fn unquote(&self, field: &str) -> String {
+        let Some(quote) = self.quote_char.map(char::from) else {
+            return field.to_string();
+        };
+        let Some(inner) = field
+            .strip_prefix(quote)
+            .and_then(|f| f.strip_suffix(quote))
+        else {
+            return field.to_string();
+        };
+        let escape = self.escape_char.map_or(quote, char::from);
+        let mut out = String::with_capacity(inner.len());
+        let mut chars = inner.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == escape {
+                if let Some(next) = chars.next_if(|n| *n == quote || *n == escape) {
+                    out.push(next);
+                    continue;
+                }
+            }
+            out.push(c);
+        }
+        out
+    }
 }
 
 ///
@@ -3,23 +3,34 @@
 
 use std::fmt::Display;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum CSVErrorType {
     IOError,
     MissingHeaderError,
     HeaderDataMismatchError,
     DuplicateKeyInHeaderError,
+    SniffError,
+    SerdeError,
 }
 
 #[derive(Debug, Clone)]
 pub struct CSVError {
     error_type: CSVErrorType,
     error: String,
+    row: Option<usize>,
+    column: Option<usize>,
 }
 
 impl Display for CSVError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!("{:?}: {}", self.error_type, self.error))
+        f.write_fmt(format_args!("{:?}", self.error_type))?;
+        match (self.row, self.column) {
+            (Some(row), Some(column)) => write!(f, " at row {row}, column {column}")?,
+            (Some(row), None) => write!(f, " at row {row}")?,
+            (None, Some(column)) => write!(f, " at column {column}")?,
+            (None, None) => {}
+        }
+        f.write_fmt(format_args!(": {}", self.error))
     }
 }
 
@@ -28,11 +39,56 @@ impl std::error::Error for CSVError {}
 impl CSVError {
     #[must_use]
     pub fn new(error_type: CSVErrorType, error: String) -> CSVError {
-        CSVError { error_type, error }
+        CSVError {
+            error_type,
+            error,
+            row: None,
+            column: None,
+        }
     }
     pub fn err<T>(error_type: CSVErrorType, error: String) -> Result<T, CSVError> {
         Err(Self::new(error_type, error))
     }
+
+    ///
+    /// Sets the row the error occurred on, numbered from 1 including any header, unless
+    /// it's already set.
+    #[must_use]
+    pub fn at_row(self, row: usize) -> CSVError {
+        CSVError {
+            row: self.row.or(Some(row)),
+            ..self
+        }
+    }
+
+    ///
+    /// Sets the column the error occurred in, numbered from 1, unless it's already set.
+    #[must_use]
+    pub fn at_column(self, column: usize) -> CSVError {
+        CSVError {
+            column: self.column.or(Some(column)),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn error_type(&self) -> CSVErrorType {
+        self.error_type
+    }
+
+    ///
+    /// Returns the row the error occurred on, numbered from 1 including any header
+    #[must_use]
+    pub fn row(&self) -> Option<usize> {
+        self.row
+    }
+
+    ///
+    /// Returns the column the error occurred in, numbered from 1
+    #[must_use]
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
 }
 
 impl From<std::io::Error> for CSVError {
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+
+//!
+//! Infers the types of the values within each column of a CSV file.
+//!
+
+use irox_types::{PrimitiveType, Primitives, VariableType};
+
+///
+/// Infers the narrowest type the value can be parsed as: a `bool`, `i64`, `u64`, `f64`, or
+/// failing all of those, a `str`.  Empty values are `null`.
+#[must_use]
+pub fn infer_type(value: &str) -> PrimitiveType {
+    let value = value.trim();
+    if value.is_empty() {
+        return Primitives::null.into();
+    }
+    if value == "true" || value == "false" {
+        return Primitives::bool.into();
+    }
+    if value.parse::<i64>().is_ok() {
+        return Primitives::i64.into();
+    }
+    if value.parse::<u64>().is_ok() {
+        return Primitives::u64.into();
+    }
+    // rust also parses words like 'inf' and 'NaN' as floats, which are more likely text.
+    if value.contains(|c: char| c.is_ascii_digit()) && value.parse::<f64>().is_ok() {
+        return Primitives::f64.into();
+    }
+    VariableType::str.into()
+}
+
+///
+/// Returns the type able to represent values of both types.  Integers widen to `f64`, and
+/// anything else that differs widens to `str`.  `null` is compatible with everything.
+#[must_use]
+pub fn widen_type(left: PrimitiveType, right: PrimitiveType) -> PrimitiveType {
+    let null = PrimitiveType::Primitive(Primitives::null);
+    if left == right || right == null {
+        return left;
+    }
+    if left == null {
+        return right;
+    }
+    let numeric = |p: PrimitiveType| {
+        matches!(
+            p,
+            PrimitiveType::Primitive(Primitives::i64 | Primitives::u64 | Primitives::f64)
+        )
+    };
+    if numeric(left) && numeric(right) {
+        return Primitives::f64.into();
+    }
+    VariableType::str.into()
+}
+
+///
+/// Accumulates the inferred type of each column, row by row.
+#[derive(Debug, Default, Clone)]
+pub struct TypeInference {
+    columns: Vec<PrimitiveType>,
+    /// Whether each column has had a negative value, so `u64` can't hold it.
+    negative: Vec<bool>,
+}
+
+impl TypeInference {
+    #[must_use]
+    pub fn new() -> TypeInference {
+        TypeInference::default()
+    }
+
+    ///
+    /// Widens the type of each column to include the values in this row.  Rows may be of
+    /// differing lengths, missing values are treated as `null`.  Integer columns that only
+    /// exceed `i64` with positive values widen to `u64` rather than `f64`.
+    pub fn add_row<R: AsRef<str>>(&mut self, row: &[R]) {
+        for (idx, value) in row.iter().enumerate() {
+            let value = value.as_ref().trim();
+            let inferred = infer_type(value);
+            let negative = value.starts_with('-');
+            let (Some(column), Some(was_negative)) =
+                (self.columns.get_mut(idx), self.negative.get_mut(idx))
+            else {
+                self.columns.push(inferred);
+                self.negative.push(negative);
+                continue;
+            };
+            *was_negative |= negative;
+            let unsigned = PrimitiveType::Primitive(Primitives::u64);
+            let integer = |p: PrimitiveType| {
+                matches!(
+                    p,
+                    PrimitiveType::Primitive(Primitives::i64 | Primitives::u64)
+                )
+            };
+            *column = if integer(*column)
+                && integer(inferred)
+                && (*column == unsigned || inferred == unsigned)
+                && !*was_negative
+            {
+                unsigned
+            } else {
+                widen_type(*column, inferred)
+            };
+        }
+    }
+
+    ///
+    /// Returns the inferred type of each column, columns with no values are `null`.
+    #[must_use]
+    pub fn column_types(&self) -> &[PrimitiveType] {
+        &self.columns
+    }
+
+    #[must_use]
+    pub fn into_column_types(self) -> Vec<PrimitiveType> {
+        self.columns
+    }
+}
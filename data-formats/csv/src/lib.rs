@@ -3,14 +3,24 @@
 
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "serde")]
+pub use de::Records;
 pub use dialects::*;
 pub use error::*;
+pub use inference::*;
 pub use reader::*;
+pub use sniffer::*;
 pub use tokenizers::*;
 pub use writer::*;
 
+#[cfg(feature = "serde")]
+mod de;
 mod dialects;
 mod error;
+mod inference;
 mod reader;
+#[cfg(feature = "serde")]
+mod ser;
+mod sniffer;
 mod tokenizers;
 mod writer;
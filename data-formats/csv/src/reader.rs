@@ -87,8 +87,11 @@ pub struct CSVMapReader<T>
 where
     T: Read + Sized,
 {
-    reader: CSVReader<T>,
-    keys: Vec<String>,
+    pub(crate) reader: CSVReader<T>,
+    pub(crate) keys: Vec<String>,
+    pub(crate) dialect: Dialect,
+    /// The number of the last row read, counting the header as row 1
+    pub(crate) row: usize,
 }
 
 impl<T: Read + Sized> CSVMapReader<T> {
@@ -110,7 +113,12 @@ impl<T: Read + Sized> CSVMapReader<T> {
         let mut reader = CSVReader::dialect(read, dialect);
         let keys = reader.read_line()?;
         match keys {
-            Some(keys) => Ok(CSVMapReader { reader, keys }),
+            Some(keys) => Ok(CSVMapReader {
+                reader,
+                keys,
+                dialect,
+                row: 1,
+            }),
             None => CSVError::err(
                 CSVErrorType::MissingHeaderError,
                 "Missing header or empty file".to_string(),
@@ -118,6 +126,13 @@ impl<T: Read + Sized> CSVMapReader<T> {
         }
     }
 
+    ///
+    /// Returns the dialect used to read the file
+    #[must_use]
+    pub fn get_dialect(&self) -> &Dialect {
+        &self.dialect
+    }
+
     ///
     /// Maybe return a single row from the CSV file.
     ///
@@ -129,13 +144,15 @@ impl<T: Read + Sized> CSVMapReader<T> {
         let Some(data) = data else {
             return Ok(None);
         };
+        self.row += 1;
         let hdrlen = self.keys.len();
         let datalen = data.len();
         if hdrlen != datalen {
-            return CSVError::err(
+            return Err(CSVError::new(
                 CSVErrorType::HeaderDataMismatchError,
                 format!("Headers length ({hdrlen}) != data length ({datalen})"),
-            );
+            )
+            .at_row(self.row));
         }
 
         Ok(Some(Row {
@@ -160,10 +177,10 @@ impl<T: Read + Sized> CSVMapReader<T> {
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct Row {
     /// A list of the Map Keys (may be repeats!)
-    keys: Vec<String>,
+    pub(crate) keys: Vec<String>,
 
     /// A list of the row values (may be repeats!)
-    data: Vec<String>,
+    pub(crate) data: Vec<String>,
 }
 
 impl Row {
@@ -0,0 +1,481 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+
+//!
+//! Serde [`Serializer`] for the records written by a [`CSVWriter`], taking the header from the
+//! field names of the first record.
+//!
+
+use std::cell::Cell;
+use std::fmt::Display;
+use std::io::Write;
+
+use serde::ser::{
+    Impossible, SerializeMap, SerializeSeq, SerializeStruct, SerializeTuple, SerializeTupleStruct,
+};
+use serde::{Serialize, Serializer};
+
+use crate::{CSVError, CSVErrorType, CSVWriter};
+
+impl serde::ser::Error for CSVError {
+    fn custom<T: Display>(msg: T) -> Self {
+        CSVError::new(CSVErrorType::SerdeError, msg.to_string())
+    }
+}
+
+impl<T: Write + Sized> CSVWriter<T> {
+    ///
+    /// Serializes the record and writes it as a single row.
+    ///
+    /// Structs & maps are written by field name in the order of the columns, and if no columns
+    /// have been provided, the field names of the first record are used as the header.  Any
+    /// missing fields are written as empty strings, as are [`None`] values.  Sequences & tuples
+    /// are written in order, like [`Self::write_line`].
+    ///
+    /// Will return [`Result::Err(CSVError)`] upon any I/O error, if the record isn't a struct,
+    /// map or sequence of plain values, or if it has a field that isn't one of the columns.  The
+    /// error includes the row & column number, counting the header as row 1.
+    pub fn write_record<S: Serialize + ?Sized>(&mut self, record: &S) -> Result<(), CSVError> {
+        let named = Cell::new(false);
+        let fields = record.serialize(RecordSerializer { named: &named });
+        let header_pending = !self.wrote_header && (self.columns.is_some() || named.get());
+        let row = self.rows + 1 + usize::from(header_pending);
+        let fields = fields.map_err(|e| e.at_row(row))?;
+        if !named.get() {
+            let values: Vec<String> = fields.into_iter().map(|(_, value)| value).collect();
+            return self.write_line(&values);
+        }
+
+        if self.columns.is_none() && !self.wrote_header {
+            self.columns = Some(fields.iter().map(|(name, _)| name.clone()).collect());
+        }
+        self.write_header()?;
+        let Some(cols) = &self.columns else {
+            return Err(CSVError::new(
+                CSVErrorType::MissingHeaderError,
+                "No header columns specified".to_string(),
+            )
+            .at_row(row));
+        };
+        let mut out = vec![String::new(); cols.len()];
+        for (idx, (name, value)) in fields.into_iter().enumerate() {
+            let Some(field) = cols
+                .iter()
+                .position(|col| *col == name)
+                .and_then(|pos| out.get_mut(pos))
+            else {
+                return Err(CSVError::new(
+                    CSVErrorType::HeaderDataMismatchError,
+                    format!("Field '{name}' isn't one of the header columns"),
+                )
+                .at_row(row)
+                .at_column(idx + 1));
+            };
+            *field = value;
+        }
+        let line = self.make_line(&out);
+        self.output.write_all(line.as_bytes())?;
+        self.rows += 1;
+        Ok(())
+    }
+}
+
+/// Serializes a whole record into a list of (name, value) pairs.  Names are empty for
+/// sequences, `named` is set for structs & maps.
+struct RecordSerializer<'a> {
+    named: &'a Cell<bool>,
+}
+
+impl RecordSerializer<'_> {
+    fn unsupported<O>(kind: &str) -> Result<O, CSVError> {
+        CSVError::err(
+            CSVErrorType::SerdeError,
+            format!("Records must be a struct, map or sequence, not {kind}"),
+        )
+    }
+}
+
+macro_rules! unsupported_record {
+    ($($method:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $method(self, _v: $ty) -> Result<Self::Ok, Self::Error> {
+                Self::unsupported(stringify!($ty))
+            }
+        )*
+    };
+}
+
+impl Serializer for RecordSerializer<'_> {
+    type Ok = Vec<(String, String)>;
+    type Error = CSVError;
+    type SerializeSeq = RecordFields;
+    type SerializeTuple = RecordFields;
+    type SerializeTupleStruct = RecordFields;
+    type SerializeTupleVariant = Impossible<Self::Ok, CSVError>;
+    type SerializeMap = RecordFields;
+    type SerializeStruct = RecordFields;
+    type SerializeStructVariant = Impossible<Self::Ok, CSVError>;
+
+    unsupported_record! {
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_i128: i128,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_u128: u128,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_char: char,
+        serialize_str: &str,
+        serialize_bytes: &[u8],
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Self::unsupported("None")
+    }
+
+    fn serialize_some<V: Serialize + ?Sized>(self, value: &V) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Self::unsupported("()")
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Self::unsupported(name)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Self::unsupported(name)
+    }
+
+    fn serialize_newtype_struct<V: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &V,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<V: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &V,
+    ) -> Result<Self::Ok, Self::Error> {
+        Self::unsupported(name)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(RecordFields::with_capacity(len))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(RecordFields::with_capacity(Some(len)))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(RecordFields::with_capacity(Some(len)))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Self::unsupported(name)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        self.named.set(true);
+        Ok(RecordFields::with_capacity(len))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        self.named.set(true);
+        Ok(RecordFields::with_capacity(Some(len)))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Self::unsupported(name)
+    }
+}
+
+/// Collects the (name, value) pairs of a record
+struct RecordFields {
+    fields: Vec<(String, String)>,
+    /// The name of a map entry, whose value is yet to be serialized.
+    key: Option<String>,
+}
+
+impl RecordFields {
+    fn with_capacity(len: Option<usize>) -> Self {
+        RecordFields {
+            fields: Vec::with_capacity(len.unwrap_or_default()),
+            key: None,
+        }
+    }
+
+    fn push<V: Serialize + ?Sized>(&mut self, name: String, value: &V) -> Result<(), CSVError> {
+        let column = self.fields.len() + 1;
+        let value = value
+            .serialize(FieldSerializer)
+            .map_err(|e| e.at_column(column))?;
+        self.fields.push((name, value));
+        Ok(())
+    }
+}
+
+impl SerializeSeq for RecordFields {
+    type Ok = Vec<(String, String)>;
+    type Error = CSVError;
+
+    fn serialize_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<(), CSVError> {
+        self.push(String::new(), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, CSVError> {
+        Ok(self.fields)
+    }
+}
+
+impl SerializeTuple for RecordFields {
+    type Ok = Vec<(String, String)>;
+    type Error = CSVError;
+
+    fn serialize_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<(), CSVError> {
+        self.push(String::new(), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, CSVError> {
+        Ok(self.fields)
+    }
+}
+
+impl SerializeTupleStruct for RecordFields {
+    type Ok = Vec<(String, String)>;
+    type Error = CSVError;
+
+    fn serialize_field<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<(), CSVError> {
+        self.push(String::new(), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, CSVError> {
+        Ok(self.fields)
+    }
+}
+
+impl SerializeMap for RecordFields {
+    type Ok = Vec<(String, String)>;
+    type Error = CSVError;
+
+    fn serialize_key<K: Serialize + ?Sized>(&mut self, key: &K) -> Result<(), CSVError> {
+        let column = self.fields.len() + 1;
+        self.key = Some(
+            key.serialize(FieldSerializer)
+                .map_err(|e| e.at_column(column))?,
+        );
+        Ok(())
+    }
+
+    fn serialize_value<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<(), CSVError> {
+        let key = self.key.take().unwrap_or_default();
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, CSVError> {
+        Ok(self.fields)
+    }
+}
+
+impl SerializeStruct for RecordFields {
+    type Ok = Vec<(String, String)>;
+    type Error = CSVError;
+
+    fn serialize_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<(), CSVError> {
+        self.push(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, CSVError> {
+        Ok(self.fields)
+    }
+}
+
+/// Serializes a single value into the text of a cell.
+struct FieldSerializer;
+
+impl FieldSerializer {
+    fn unsupported<O>(kind: &str) -> Result<O, CSVError> {
+        CSVError::err(
+            CSVErrorType::SerdeError,
+            format!("Values must be plain, {kind} can't be written to a single cell"),
+        )
+    }
+}
+
+macro_rules! serialize_display {
+    ($($method:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Self::Ok, Self::Error> {
+                Ok(v.to_string())
+            }
+        )*
+    };
+}
+
+impl Serializer for FieldSerializer {
+    type Ok = String;
+    type Error = CSVError;
+    type SerializeSeq = Impossible<String, CSVError>;
+    type SerializeTuple = Impossible<String, CSVError>;
+    type SerializeTupleStruct = Impossible<String, CSVError>;
+    type SerializeTupleVariant = Impossible<String, CSVError>;
+    type SerializeMap = Impossible<String, CSVError>;
+    type SerializeStruct = Impossible<String, CSVError>;
+    type SerializeStructVariant = Impossible<String, CSVError>;
+
+    serialize_display! {
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_i128: i128,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_u128: u128,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_char: char,
+        serialize_str: &str,
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Ok(String::from_utf8_lossy(v).to_string())
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn serialize_some<V: Serialize + ?Sized>(self, value: &V) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(variant.to_string())
+    }
+
+    fn serialize_newtype_struct<V: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &V,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<V: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &V,
+    ) -> Result<Self::Ok, Self::Error> {
+        Self::unsupported(name)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Self::unsupported("sequences")
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Self::unsupported("tuples")
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Self::unsupported(name)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Self::unsupported(name)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Self::unsupported("maps")
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Self::unsupported(name)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Self::unsupported(name)
+    }
+}
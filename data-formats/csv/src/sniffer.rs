@@ -0,0 +1,302 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+
+//!
+//! Detects the dialect of a CSV file from a sample of it.
+//!
+
+use irox_types::{PrimitiveType, Primitives, VariableType};
+
+use crate::{infer_type, CSVError, CSVErrorType, Dialect, TypeInference};
+
+///
+/// Field separators tried by default, in order of preference.
+pub const DEFAULT_SNIFF_SEPARATORS: &[&str] = &[",", "\t", ";", "|", ":"];
+
+const QUOTE_CHARS: [char; 2] = ['"', '\''];
+const ESCAPE_CHAR: char = '\\';
+const COMMENT_CHARS: &str = "#";
+
+///
+/// Inspects a sample of a CSV file to work out its dialect, similar to Python's
+/// `csv.Sniffer`.
+#[derive(Debug, Clone)]
+pub struct Sniffer {
+    field_separators: Vec<&'static str>,
+    infer_types: bool,
+}
+
+impl Default for Sniffer {
+    fn default() -> Self {
+        Sniffer {
+            field_separators: DEFAULT_SNIFF_SEPARATORS.to_vec(),
+            infer_types: false,
+        }
+    }
+}
+
+///
+/// The results of sniffing a sample
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sniffed {
+    /// The detected dialect
+    pub dialect: Dialect,
+    /// True if the first row appears to be a header
+    pub has_header: bool,
+    /// The types of each column (excluding the header), if requested with
+    /// [`Sniffer::with_type_inference`]
+    pub column_types: Option<Vec<PrimitiveType>>,
+}
+
+impl Sniffer {
+    #[must_use]
+    pub fn new() -> Sniffer {
+        Sniffer::default()
+    }
+
+    ///
+    /// Sets the candidate field separators, in order of preference.
+    #[must_use]
+    pub fn with_field_separators(self, field_separators: &[&'static str]) -> Self {
+        Sniffer {
+            field_separators: field_separators.to_vec(),
+            ..self
+        }
+    }
+
+    ///
+    /// Also infer the types of each column in the sample
+    #[must_use]
+    pub fn with_type_inference(self, infer_types: bool) -> Self {
+        Sniffer {
+            infer_types,
+            ..self
+        }
+    }
+
+    ///
+    /// Sniffs the sample, which should contain at least a few complete lines of the file.  A
+    /// trailing partial line is ignored.
+    ///
+    /// Will return [`CSVErrorType::SniffError`] if none of the candidate field separators
+    /// split the sample consistently.
+    pub fn sniff(&self, sample: &[u8]) -> Result<Sniffed, CSVError> {
+        let sample = String::from_utf8_lossy(sample);
+        let line_separators = if sample.contains("\r\n") {
+            "\r\n"
+        } else {
+            "\n"
+        };
+
+        let mut best: Option<(Score, &'static str, char)> = None;
+        for quote in QUOTE_CHARS {
+            for separator in &self.field_separators {
+                let split = split_sample(&sample, separator, quote, None);
+                let Some(score) = Score::of(&split) else {
+                    continue;
+                };
+                if best.as_ref().is_none_or(|(b, _, _)| score > *b) {
+                    best = Some((score, separator, quote));
+                }
+            }
+        }
+        let Some((_, field_separators, quote)) = best else {
+            return CSVError::err(
+                CSVErrorType::SniffError,
+                "Unable to determine the field separator of the sample".to_string(),
+            );
+        };
+
+        // backslash escapes are only used if quotes are never doubled.
+        let split = split_sample(&sample, field_separators, quote, Some(ESCAPE_CHAR));
+        let escape = (split.escaped > 0 && split.doubled == 0).then_some(ESCAPE_CHAR);
+        let split = split_sample(&sample, field_separators, quote, escape);
+
+        let dialect = Dialect::new(line_separators, field_separators, COMMENT_CHARS)
+            .with_quote_char(Some(quote as u8))
+            .with_escape_char(escape.map(|e| e as u8));
+        let rows: Vec<Vec<String>> = split
+            .records
+            .iter()
+            .map(|r| r.iter().map(|f| dialect.unquote(f)).collect())
+            .collect();
+        let has_header = has_header(&rows);
+        let column_types = self.infer_types.then(|| {
+            let mut types = TypeInference::new();
+            for row in rows.iter().skip(usize::from(has_header)) {
+                types.add_row(row);
+            }
+            types.into_column_types()
+        });
+        Ok(Sniffed {
+            dialect,
+            has_header,
+            column_types,
+        })
+    }
+}
+
+/// How well a separator splits the sample, compared in field order.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+struct Score {
+    /// Fraction of the rows with the most common number of fields
+    consistency: f64,
+    /// Number of fields wrapped in quotes
+    quoted: usize,
+}
+
+impl Score {
+    /// Returns the score, or [`None`] if the separator doesn't split the sample at all
+    fn of(split: &Split) -> Option<Score> {
+        let mut counts: Vec<(usize, usize)> = Vec::new();
+        for record in &split.records {
+            match counts.iter_mut().find(|(len, _)| *len == record.len()) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((record.len(), 1)),
+            }
+        }
+        let (fields, rows) = counts
+            .iter()
+            .copied()
+            .max_by_key(|(len, count)| (*count, *len))?;
+        if fields < 2 {
+            return None;
+        }
+        Some(Score {
+            consistency: rows as f64 / split.records.len() as f64,
+            quoted: split.quoted,
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct Split {
+    records: Vec<Vec<String>>,
+    /// Fields wrapped in quotes
+    quoted: usize,
+    /// Quotes escaped by doubling them
+    doubled: usize,
+    /// Quotes escaped by the escape character
+    escaped: usize,
+}
+
+///
+/// Splits the sample into records of raw fields, skipping blank & comment lines.  The last
+/// record is dropped if the sample ends partway through it.
+fn split_sample(sample: &str, separator: &str, quote: char, escape: Option<char>) -> Split {
+    let mut split = Split::default();
+    let mut record: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = sample.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        if in_quotes {
+            field.push(c);
+            if Some(c) == escape && chars.peek().is_some_and(|(_, n)| *n == quote) {
+                split.escaped += 1;
+                if let Some((_, n)) = chars.next() {
+                    field.push(n);
+                }
+            } else if c == quote {
+                if chars.peek().is_some_and(|(_, n)| *n == quote) {
+                    split.doubled += 1;
+                    if let Some((_, n)) = chars.next() {
+                        field.push(n);
+                    }
+                } else {
+                    in_quotes = false;
+                }
+            }
+            continue;
+        }
+        if c == '\n' {
+            if field.ends_with('\r') {
+                field.pop();
+            }
+            finish_record(&mut split, &mut record, &mut field, quote);
+            continue;
+        }
+        let rest = sample.get(idx..).unwrap_or_default();
+        if !separator.is_empty() && rest.starts_with(separator) {
+            finish_field(&mut split, &mut record, &mut field, quote);
+            for _ in 1..separator.chars().count() {
+                chars.next();
+            }
+            continue;
+        }
+        if c == quote && field.is_empty() {
+            in_quotes = true;
+        }
+        field.push(c);
+    }
+    if split.records.is_empty() {
+        finish_record(&mut split, &mut record, &mut field, quote);
+    }
+    split
+}
+
+fn finish_field(split: &mut Split, record: &mut Vec<String>, field: &mut String, quote: char) {
+    if field.len() >= 2 && field.starts_with(quote) && field.ends_with(quote) {
+        split.quoted += 1;
+    }
+    record.push(core::mem::take(field));
+}
+
+fn finish_record(split: &mut Split, record: &mut Vec<String>, field: &mut String, quote: char) {
+    let blank = record.is_empty() && field.trim().is_empty();
+    let comment = record.is_empty() && field.starts_with(COMMENT_CHARS);
+    if blank || comment {
+        field.clear();
+        return;
+    }
+    finish_field(split, record, field, quote);
+    split.records.push(core::mem::take(record));
+}
+
+///
+/// Votes on whether the first row is a header, by comparing each of its values with the
+/// values below it.  A header value that doesn't match the type of a typed column, or the
+/// length of a column of fixed length text, is a vote for a header.
+fn has_header(rows: &[Vec<String>]) -> bool {
+    let Some((header, data)) = rows.split_first() else {
+        return false;
+    };
+    if data.is_empty() {
+        return false;
+    }
+    let mut types = TypeInference::new();
+    for row in data {
+        types.add_row(row);
+    }
+    let mut votes = 0_i32;
+    for (idx, (value, column)) in header.iter().zip(types.column_types()).enumerate() {
+        match column {
+            PrimitiveType::DynamicallySized(VariableType::str) => {
+                let mut lengths = data
+                    .iter()
+                    .filter_map(|row| row.get(idx))
+                    .map(|v| v.chars().count());
+                let Some(first) = lengths.next() else {
+                    continue;
+                };
+                if lengths.all(|l| l == first) {
+                    votes += if value.chars().count() == first {
+                        -1
+                    } else {
+                        1
+                    };
+                }
+            }
+            PrimitiveType::Primitive(Primitives::null) => {}
+            column => {
+                let header_type = infer_type(value);
+                votes += if crate::widen_type(*column, header_type) == *column {
+                    -1
+                } else {
+                    1
+                };
+            }
+        }
+    }
+    votes > 0
+}
@@ -63,15 +63,35 @@ impl<T: Read + Sized> BasicTokenReader<T> {
     ///
     /// Token reader using the specified dialect
     pub fn dialect(reader: T, dialect: Dialect) -> Self {
-        let delims = &[
-            sc::Token::new(dialect.get_field_separators(), InnerToken::Field)
-                .with_quote_char(QuotedChars::DoubleQuotes),
-            sc::Token::new(dialect.get_line_separators(), InnerToken::Newline)
-                .with_quote_char(QuotedChars::DoubleQuotes),
-            sc::Token::new(dialect.get_comment_chars(), InnerToken::Comment),
+        let quoted = |token: sc::Token<InnerToken>| {
+            let token = match dialect.get_quote_char() {
+                Some(quote) => token.with_quote_char(QuotedChars::Other(quote)),
+                None => token,
+            };
+            match dialect.get_escape_char() {
+                Some(escape) => token.with_escape_char(escape),
+                None => token,
+            }
+        };
+        let mut delims = vec![
+            quoted(sc::Token::new(
+                dialect.get_field_separators(),
+                InnerToken::Field,
+            )),
+            quoted(sc::Token::new(
+                dialect.get_line_separators(),
+                InnerToken::Newline,
+            )),
         ];
+        // an empty comment string would match everywhere.
+        if !dialect.get_comment_chars().is_empty() {
+            delims.push(quoted(sc::Token::new(
+                dialect.get_comment_chars(),
+                InnerToken::Comment,
+            )));
+        }
         Self {
-            scanner: Scanner::new(reader, delims),
+            scanner: Scanner::new(reader, &delims),
         }
     }
 }
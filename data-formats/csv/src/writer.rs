@@ -3,6 +3,22 @@ use std::io::Write;
 
 use crate::{CSVError, CSVErrorType, Dialect};
 
+///
+/// When the writer wraps fields in the dialect's quote character
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum QuoteStyle {
+    /// Only fields containing a separator, quote, escape or newline character, or that would
+    /// otherwise be read as a comment.
+    #[default]
+    Minimal,
+    /// Every field
+    All,
+    /// Every field that isn't a number
+    NonNumeric,
+    /// No fields, they're written as-is.
+    Never,
+}
+
 ///
 /// Flexible CSV writer, wherein one can specify the dialect and optional column
 /// headers
@@ -13,7 +29,10 @@ where
     pub(crate) output: T,
     pub(crate) columns: Option<Vec<String>>,
     pub(crate) dialect: Dialect,
+    pub(crate) quote_style: QuoteStyle,
     pub(crate) wrote_header: bool,
+    /// The number of rows written so far, including the header
+    pub(crate) rows: usize,
 }
 
 impl<T: Write + Sized> CSVWriter<T> {
@@ -25,7 +44,9 @@ impl<T: Write + Sized> CSVWriter<T> {
             output,
             columns: None,
             dialect: Dialect::default(),
+            quote_style: QuoteStyle::default(),
             wrote_header: false,
+            rows: 0,
         }
     }
 
@@ -36,6 +57,16 @@ impl<T: Write + Sized> CSVWriter<T> {
         CSVWriter { dialect, ..self }
     }
 
+    ///
+    /// Sets when fields are quoted, defaults to [`QuoteStyle::Minimal`]
+    #[must_use]
+    pub fn with_quote_style(self, quote_style: QuoteStyle) -> Self {
+        CSVWriter {
+            quote_style,
+            ..self
+        }
+    }
+
     ///
     /// Sets the column names to use as the header
     #[must_use]
@@ -61,6 +92,7 @@ impl<T: Write + Sized> CSVWriter<T> {
         let line = self.make_line(cols);
         self.output.write_all(line.as_bytes())?;
         self.wrote_header = true;
+        self.rows += 1;
         Ok(())
     }
 
@@ -69,10 +101,45 @@ impl<T: Write + Sized> CSVWriter<T> {
     /// newline character(s)
     #[must_use]
     pub(crate) fn make_line(&self, fields: &[String]) -> String {
-        let line = fields.join(self.dialect.get_field_separators());
+        let line = fields
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| {
+                if self.needs_quotes(field, idx == 0) {
+                    self.dialect.quote(field)
+                } else {
+                    field.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(self.dialect.get_field_separators());
         format!("{line}{}", self.dialect.get_line_separators())
     }
 
+    fn needs_quotes(&self, field: &str, first: bool) -> bool {
+        let dialect = &self.dialect;
+        let Some(quote) = dialect.get_quote_char() else {
+            return false;
+        };
+        match self.quote_style {
+            QuoteStyle::All => true,
+            QuoteStyle::Never => false,
+            QuoteStyle::NonNumeric => field.trim().parse::<f64>().is_err(),
+            QuoteStyle::Minimal => {
+                let special = |s: &str| !s.is_empty() && field.contains(s);
+                special(dialect.get_field_separators())
+                    || special(dialect.get_line_separators())
+                    || field.contains(['\r', '\n', char::from(quote)])
+                    || dialect
+                        .get_escape_char()
+                        .is_some_and(|e| field.contains(char::from(e)))
+                    || (first
+                        && !dialect.get_comment_chars().is_empty()
+                        && field.starts_with(dialect.get_comment_chars()))
+            }
+        }
+    }
+
     ///
     /// Raw low-level write of a set of fields to this file in simple iteration order.  This does
     /// NOT check against previous lines to ensure the fields are the same length as priors.
@@ -81,6 +148,7 @@ impl<T: Write + Sized> CSVWriter<T> {
         let fields: Vec<String> = fields.iter().map(|f| f.as_ref().to_string()).collect();
         let line = self.make_line(fields.as_slice());
         self.output.write_all(line.as_bytes())?;
+        self.rows += 1;
         Ok(())
     }
 
@@ -119,6 +187,7 @@ impl<T: Write + Sized> CSVWriter<T> {
         }
         let line = self.make_line(&out);
         self.output.write_all(line.as_bytes())?;
+        self.rows += 1;
         Ok(())
     }
 }
@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+
+use irox_csv::{CSVError, CSVErrorType, CSVMapReader, CSVWriter, UNIX_DIALECT};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Kind {
+    Peak,
+    Saddle,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Summit {
+    name: String,
+    elevation: f64,
+    rank: u32,
+    kind: Kind,
+    windy: bool,
+    notes: Option<String>,
+}
+
+static SUMMITS: &str = "rank,name,notes,elevation,windy,kind
+1,\"Mt. Washington\",\"windy, cold\",1916.6,true,Peak
+2,Mt. Adams,,1760,False,Peak
+";
+
+#[test]
+pub fn deserialize_1() -> Result<(), CSVError> {
+    let reader = CSVMapReader::dialect(SUMMITS.as_bytes(), UNIX_DIALECT)?;
+    let summits = reader.records::<Summit>().collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(
+        vec![
+            Summit {
+                name: "Mt. Washington".to_string(),
+                elevation: 1916.6,
+                rank: 1,
+                kind: Kind::Peak,
+                windy: true,
+                notes: Some("windy, cold".to_string()),
+            },
+            Summit {
+                name: "Mt. Adams".to_string(),
+                elevation: 1760.,
+                rank: 2,
+                kind: Kind::Peak,
+                windy: false,
+                notes: None,
+            },
+        ],
+        summits
+    );
+    Ok(())
+}
+
+#[test]
+pub fn deserialize_errors() -> Result<(), CSVError> {
+    let input = "rank,name,notes,elevation,windy,kind
+1,Mt. Washington,,1916.6,true,Peak
+2,Mt. Adams,,high,false,Peak
+";
+    let mut reader = CSVMapReader::dialect(input.as_bytes(), UNIX_DIALECT)?;
+    assert!(reader.next_record::<Summit>()?.is_some());
+    let Err(err) = reader.next_record::<Summit>() else {
+        return CSVError::err(CSVErrorType::SerdeError, "expected an error".to_string());
+    };
+    assert_eq!(CSVErrorType::SerdeError, err.error_type());
+    assert_eq!(Some(3), err.row());
+    assert_eq!(Some(4), err.column());
+    assert!(err.to_string().contains("elevation"), "{err}");
+
+    let input = "rank,name\n1,Mt. Washington\n";
+    let mut reader = CSVMapReader::dialect(input.as_bytes(), UNIX_DIALECT)?;
+    let Err(err) = reader.next_record::<Summit>() else {
+        return CSVError::err(CSVErrorType::SerdeError, "expected an error".to_string());
+    };
+    assert_eq!(Some(2), err.row());
+    assert_eq!(None, err.column());
+    Ok(())
+}
+
+#[test]
+pub fn serialize_1() -> Result<(), CSVError> {
+    let mut buf: Vec<u8> = Vec::new();
+    let mut writer = CSVWriter::new(&mut buf).with_dialect(UNIX_DIALECT);
+    writer.write_record(&Summit {
+        name: "Mt. Washington".to_string(),
+        elevation: 1916.6,
+        rank: 1,
+        kind: Kind::Peak,
+        windy: true,
+        notes: Some("windy, cold".to_string()),
+    })?;
+    writer.write_record(&Summit {
+        name: "Mt. Adams".to_string(),
+        elevation: 1760.,
+        rank: 2,
+        kind: Kind::Saddle,
+        windy: false,
+        notes: None,
+    })?;
+    writer.write_record(&("Mt. Jefferson", 1741))?;
+    assert_eq!(
+        "name,elevation,rank,kind,windy,notes
+Mt. Washington,1916.6,1,Peak,true,\"windy, cold\"
+Mt. Adams,1760,2,Saddle,false,
+Mt. Jefferson,1741
+",
+        String::from_utf8_lossy(&buf)
+    );
+
+    // round trip
+    let reader = CSVMapReader::dialect(buf.as_slice(), UNIX_DIALECT)?;
+    let mut records = reader.records::<Summit>();
+    assert_eq!(
+        Some("Mt. Washington"),
+        records
+            .next()
+            .transpose()?
+            .as_ref()
+            .map(|s| s.name.as_str())
+    );
+    assert_eq!(
+        Some(Kind::Saddle),
+        records.next().transpose()?.map(|s| s.kind)
+    );
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct Nested {
+    name: String,
+    position: (f64, f64),
+}
+
+#[test]
+pub fn serialize_errors() -> Result<(), CSVError> {
+    let mut buf: Vec<u8> = Vec::new();
+    let mut writer = CSVWriter::new(&mut buf)
+        .with_dialect(UNIX_DIALECT)
+        .with_column_names(&["name", "elevation"]);
+    writer.write_line(&["Mt. Washington", "1916.6"])?;
+
+    let Err(err) = writer.write_record(&Nested {
+        name: "Mt. Adams".to_string(),
+        position: (44.32, -71.29),
+    }) else {
+        return CSVError::err(CSVErrorType::SerdeError, "expected an error".to_string());
+    };
+    assert_eq!(CSVErrorType::SerdeError, err.error_type());
+    assert_eq!(Some(3), err.row());
+    assert_eq!(Some(2), err.column());
+
+    let Err(err) = writer.write_record(&Summit {
+        name: "Mt. Adams".to_string(),
+        elevation: 1760.,
+        rank: 2,
+        kind: Kind::Peak,
+        windy: false,
+        notes: None,
+    }) else {
+        return CSVError::err(CSVErrorType::SerdeError, "expected an error".to_string());
+    };
+    assert_eq!(CSVErrorType::HeaderDataMismatchError, err.error_type());
+    assert_eq!(Some(3), err.row());
+    assert_eq!(Some(3), err.column());
+    Ok(())
+}
@@ -3,7 +3,11 @@
 
 use std::collections::BTreeMap;
 
-use irox_csv::{CSVError, CSVWriter, PIPE_FIELD_DIALECT, UNIX_DIALECT};
+use irox_csv::{
+    CSVError, CSVErrorType, CSVWriter, Dialect, QuoteStyle, Sniffer, TypeInference,
+    PIPE_FIELD_DIALECT, UNIX_DIALECT,
+};
+use irox_types::{PrimitiveType, Primitives, VariableType};
 
 static INPUT_1: &str = "header1,header2,header3,header4
 one,two,three,four\nfive,six,seven,eight\n1,2,3,4
@@ -157,3 +161,140 @@ pub fn reader_3() -> Result<(), CSVError> {
     assert_eq!(1, idx);
     Ok(())
 }
+
+#[test]
+pub fn writer_quoting() -> Result<(), CSVError> {
+    let fields = ["plain", "with,comma", "say \"hi\"", "1.5", ""];
+    let mut expected = Vec::new();
+    for (style, line) in [
+        (
+            QuoteStyle::Minimal,
+            "plain,\"with,comma\",\"say \"\"hi\"\"\",1.5,\n",
+        ),
+        (
+            QuoteStyle::All,
+            "\"plain\",\"with,comma\",\"say \"\"hi\"\"\",\"1.5\",\"\"\n",
+        ),
+        (
+            QuoteStyle::NonNumeric,
+            "\"plain\",\"with,comma\",\"say \"\"hi\"\"\",1.5,\"\"\n",
+        ),
+        (QuoteStyle::Never, "plain,with,comma,say \"hi\",1.5,\n"),
+    ] {
+        let mut buf: Vec<u8> = Vec::new();
+        CSVWriter::new(&mut buf)
+            .with_dialect(UNIX_DIALECT)
+            .with_quote_style(style)
+            .write_line(&fields)?;
+        expected.push((style, String::from_utf8_lossy(&buf).to_string(), line));
+    }
+    for (style, actual, line) in expected {
+        assert_eq!(line, actual, "{style:?}");
+    }
+
+    // escaped rather than doubled quotes, read back with the same dialect
+    let dialect = UNIX_DIALECT
+        .with_quote_char(Some(b'\''))
+        .with_escape_char(Some(b'\\'));
+    let mut buf: Vec<u8> = Vec::new();
+    CSVWriter::new(&mut buf)
+        .with_dialect(dialect)
+        .write_line(&["#not a comment", "it's", "a,b"])?;
+    assert_eq!(
+        "'#not a comment','it\\'s','a,b'\n",
+        String::from_utf8_lossy(&buf)
+    );
+    let mut reader = irox_csv::CSVReader::dialect(buf.as_slice(), dialect);
+    let line = reader.read_line()?.unwrap_or_default();
+    let line: Vec<String> = line.iter().map(|f| dialect.unquote(f)).collect();
+    assert_eq!(vec!["#not a comment", "it's", "a,b"], line);
+    Ok(())
+}
+
+static SNIFF_1: &str = "name;elevation;visited;notes\r
+\"Mt. Washington\";1916.6;true;\"windy; cold\"\r
+\"Mt. Adams\";1760;false;\r
+\"Mt. Jefferson\";1741;true;\"said \"\"hi\"\"\"\r
+\"Mt. Mon";
+
+#[test]
+pub fn sniff_1() -> Result<(), CSVError> {
+    let sniffed = Sniffer::new()
+        .with_type_inference(true)
+        .sniff(SNIFF_1.as_bytes())?;
+    let dialect = sniffed.dialect;
+    assert_eq!(";", dialect.get_field_separators());
+    assert_eq!("\r\n", dialect.get_line_separators());
+    assert_eq!(Some(b'"'), dialect.get_quote_char());
+    assert_eq!(None, dialect.get_escape_char());
+    assert!(sniffed.has_header);
+    assert_eq!(
+        Some(vec![
+            PrimitiveType::DynamicallySized(VariableType::str),
+            Primitives::f64.into(),
+            Primitives::bool.into(),
+            VariableType::str.into(),
+        ]),
+        sniffed.column_types
+    );
+
+    let mut reader = irox_csv::CSVMapReader::dialect(SNIFF_1.as_bytes(), dialect)?;
+    let row = reader.next_row()?.unwrap_or_default().into_map_lossy();
+    assert_eq!(Some(&"\"windy; cold\"".to_string()), row.get("notes"));
+    Ok(())
+}
+
+#[test]
+pub fn sniff_2() -> Result<(), CSVError> {
+    // no header, tab separated, single quotes with backslash escapes
+    let sample = "1\t'a\\'b'\t2.5\n2\t'c'\t3\n3\t'd\te'\t-1\n";
+    let sniffed = Sniffer::new()
+        .with_type_inference(true)
+        .sniff(sample.as_bytes())?;
+    let dialect = sniffed.dialect;
+    assert_eq!("\t", dialect.get_field_separators());
+    assert_eq!("\n", dialect.get_line_separators());
+    assert_eq!(Some(b'\''), dialect.get_quote_char());
+    assert_eq!(Some(b'\\'), dialect.get_escape_char());
+    assert!(!sniffed.has_header);
+    assert_eq!(
+        Some(vec![
+            Primitives::i64.into(),
+            VariableType::str.into(),
+            Primitives::f64.into()
+        ]),
+        sniffed.column_types
+    );
+    assert_eq!("a'b", dialect.unquote("'a\\'b'"));
+
+    // a single column can't be sniffed
+    let Err(e) = Sniffer::new().sniff(b"one\ntwo\nthree\n") else {
+        return CSVError::err(CSVErrorType::SniffError, "expected an error".to_string());
+    };
+    assert!(e.to_string().starts_with("SniffError"));
+    Ok(())
+}
+
+#[test]
+pub fn type_inference() {
+    let mut types = TypeInference::new();
+    types.add_row(&["1", "true", "", "x", "18446744073709551615"]);
+    types.add_row(&["-2", "false", "", "2", "1"]);
+    types.add_row(&["3.5", "true", "", "y"]);
+    assert_eq!(
+        &[
+            PrimitiveType::Primitive(Primitives::f64),
+            Primitives::bool.into(),
+            Primitives::null.into(),
+            VariableType::str.into(),
+            Primitives::u64.into(),
+        ],
+        types.column_types()
+    );
+    assert_eq!(
+        PrimitiveType::DynamicallySized(VariableType::str),
+        irox_csv::infer_type("inf")
+    );
+    let dialect = Dialect::default();
+    assert_eq!("\"a \"\"b\"\"\"", dialect.quote("a \"b\""));
+}
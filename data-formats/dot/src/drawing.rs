@@ -11,10 +11,47 @@ irox_tools::cfg_feature_egui! {
     pub mod app;
 
 }
+pub mod layout;
 
+use crate::{Graph, ParseError};
 use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
+///
+/// Error loading a graph with [`DotJson::load`]
+#[derive(Debug)]
+pub enum LoadError {
+    Json(serde_json::Error),
+    Dot(ParseError),
+    Encoding(std::str::Utf8Error),
+}
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Json(e) => write!(f, "Invalid Graphviz JSON: {e}"),
+            LoadError::Dot(e) => write!(f, "Invalid DOT graph: {e}"),
+            LoadError::Encoding(e) => write!(f, "DOT graph is not UTF-8: {e}"),
+        }
+    }
+}
+impl std::error::Error for LoadError {}
+impl From<serde_json::Error> for LoadError {
+    fn from(value: serde_json::Error) -> Self {
+        LoadError::Json(value)
+    }
+}
+impl From<ParseError> for LoadError {
+    fn from(value: ParseError) -> Self {
+        LoadError::Dot(value)
+    }
+}
+impl From<std::str::Utf8Error> for LoadError {
+    fn from(value: std::str::Utf8Error) -> Self {
+        LoadError::Encoding(value)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DotJson {
     pub name: String,
@@ -48,6 +85,29 @@ impl DotJson {
     pub fn from_reader<R: std::io::Read>(r: R) -> Result<Self, serde_json::Error> {
         serde_json::from_reader(r)
     }
+
+    ///
+    /// Loads either the JSON output of Graphviz (`dot -Tjson`), or DOT source which is laid out
+    /// natively with [`layout::layout`].
+    pub fn load(data: &[u8]) -> Result<Self, LoadError> {
+        if data.trim_ascii_start().starts_with(b"{") {
+            return Ok(Self::from_slice(data)?);
+        }
+        let graph = Graph::parse(std::str::from_utf8(data)?)?;
+        Ok(layout::layout(&graph))
+    }
+
+    ///
+    /// Returns the bounding box of the drawing as `[llx, lly, urx, ury]`, in points.
+    pub fn bounding_box(&self) -> Option<Rect> {
+        let mut parts = self.bb.split(',').map(|v| v.trim().parse::<f64>().ok());
+        Some([
+            parts.next()??,
+            parts.next()??,
+            parts.next()??,
+            parts.next()??,
+        ])
+    }
 }
 impl FromStr for DotJson {
     type Err = serde_json::Error;
@@ -22,6 +22,8 @@ use std::sync::mpsc::Sender;
 
 pub struct InitData {
     pub dotjsondata: Vec<u8>,
+    /// An already loaded drawing, used instead of loading `dotjsondata`
+    pub dotjson: Option<DotJson>,
     pub scale: f32,
     pub pos: [f32; 2],
 }
@@ -76,12 +78,15 @@ impl App {
             o.parallel_tessellation = true;
             o.bezier_tolerance = 0.5;
         });
-        let data = match DotJson::from_slice(&init.dotjsondata) {
-            Ok(d) => d,
-            Err(e) => {
-                error!("Error loading dotjson data: {e:#?}");
-                return;
-            }
+        let data = match init.dotjson {
+            Some(d) => d,
+            None => match DotJson::load(&init.dotjsondata) {
+                Ok(d) => d,
+                Err(e) => {
+                    error!("Error loading graph data: {e}");
+                    return;
+                }
+            },
         };
         let mut draw = DrawContext::new(ctx);
 
@@ -0,0 +1,1278 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! Native layout of parsed DOT graphs into the [`DotJson`] drawing model, for when Graphviz
+//! isn't available.  Directed graphs are laid out in layers (Sugiyama style), undirected graphs
+//! with the [`irox_graphing::fdp`] force simulation.
+
+// vertex indices are all created within this module, and are always in bounds.
+#![allow(clippy::indexing_slicing)]
+
+use crate::drawing::{Color, DotJson, Draw, Ellipse, Font, Metanode, Points, Style, Text};
+use crate::{Element, Graph, GraphType};
+use irox_graphing::fdp::{
+    Centering, DefaultNodePlacement, EdgeForce, Force, Repulsive, Simulation, SimulationParams,
+};
+use irox_graphing::{Edge as FdpEdge, EdgeDescriptor, Graph as FdpGraph, Node as FdpNode};
+use irox_tools::identifier::Identifier;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+
+const POINTS_PER_INCH: f64 = 72.;
+const DEFAULT_FONT_SIZE: f64 = 14.;
+const DEFAULT_FONT_FACE: &str = "Times-Roman";
+/// Approximate width of a character, relative to the font size
+const CHAR_WIDTH: f64 = 0.55;
+const LINE_HEIGHT: f64 = 1.2;
+const ARROW_LENGTH: f64 = 10.;
+const ARROW_WIDTH: f64 = 3.5;
+const MARGIN: f64 = 4.;
+const CROSSING_SWEEPS: usize = 8;
+const POSITION_SWEEPS: usize = 8;
+
+///
+/// The layout algorithm to use.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum Engine {
+    /// Nodes are placed in ranks following the edge direction, like Graphviz `dot`.
+    #[default]
+    Layered,
+    /// Nodes are placed by a force simulation, like Graphviz `fdp`.
+    Force,
+}
+
+impl Engine {
+    ///
+    /// Picks the engine named by the graph's `layout` attribute, otherwise layered for
+    /// digraphs and force directed for undirected graphs.
+    pub fn for_graph(graph: &Graph) -> Engine {
+        let named = graph.elements.iter().find_map(|e| match e {
+            Element::Attribute(a) if a.name == "layout" => Some(a.text()),
+            _ => None,
+        });
+        match named.as_deref() {
+            Some("dot") => Engine::Layered,
+            Some("fdp" | "sfdp" | "neato") => Engine::Force,
+            _ => match graph.graph_type {
+                GraphType::Digraph => Engine::Layered,
+                GraphType::Graph => Engine::Force,
+            },
+        }
+    }
+}
+
+///
+/// Spacing of the laid out graph, in points.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LayoutOptions {
+    /// Minimum gap between adjacent nodes within a rank
+    pub node_separation: f64,
+    /// Minimum gap between ranks
+    pub rank_separation: f64,
+    /// Iterations of the force simulation
+    pub force_iterations: u32,
+}
+
+impl Default for LayoutOptions {
+    fn default() -> Self {
+        // Graphviz defaults of nodesep=0.25in and ranksep=0.5in
+        LayoutOptions {
+            node_separation: 18.,
+            rank_separation: 36.,
+            force_iterations: 300,
+        }
+    }
+}
+
+///
+/// Lays out the graph with the engine from [`Engine::for_graph`] and the default options.
+pub fn layout(graph: &Graph) -> DotJson {
+    layout_with(graph, Engine::for_graph(graph), &LayoutOptions::default())
+}
+
+///
+/// Lays out the graph with the provided engine and options.
+pub fn layout_with(graph: &Graph, engine: Engine, options: &LayoutOptions) -> DotJson {
+    let mut model = Model::new(graph);
+    let routes = match engine {
+        Engine::Layered => model.layered(options),
+        Engine::Force => model.force(options),
+    };
+    model.into_dotjson(routes)
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum RankDir {
+    TopBottom,
+    BottomTop,
+    LeftRight,
+    RightLeft,
+}
+
+impl RankDir {
+    fn name(self) -> &'static str {
+        match self {
+            RankDir::TopBottom => "TB",
+            RankDir::BottomTop => "BT",
+            RankDir::LeftRight => "LR",
+            RankDir::RightLeft => "RL",
+        }
+    }
+    fn is_horizontal(self) -> bool {
+        matches!(self, RankDir::LeftRight | RankDir::RightLeft)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Shape {
+    Ellipse,
+    Box,
+    Point,
+    /// Label only, no outline
+    Plain,
+}
+
+type Attrs = BTreeMap<String, String>;
+
+struct LayoutNode {
+    name: String,
+    attrs: Attrs,
+    shape: Shape,
+    lines: Vec<String>,
+    font_size: f64,
+    width: f64,
+    height: f64,
+    /// Center, in Graphviz coordinates (y up)
+    center: [f64; 2],
+}
+
+struct LayoutEdge {
+    tail: usize,
+    head: usize,
+    attrs: Attrs,
+}
+
+/// The default attributes in scope while collecting statements
+#[derive(Clone, Default)]
+struct Defaults {
+    node: Attrs,
+    edge: Attrs,
+}
+
+struct Model {
+    name: String,
+    directed: bool,
+    strict: bool,
+    graph_attrs: Attrs,
+    rankdir: RankDir,
+    nodes: Vec<LayoutNode>,
+    index: HashMap<String, usize>,
+    edges: Vec<LayoutEdge>,
+    size: [f64; 2],
+}
+
+impl Model {
+    fn new(graph: &Graph) -> Model {
+        let mut model = Model {
+            name: graph.id.clone().unwrap_or_default(),
+            directed: graph.graph_type == GraphType::Digraph,
+            strict: graph.is_strict,
+            graph_attrs: Attrs::new(),
+            rankdir: RankDir::TopBottom,
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            edges: Vec::new(),
+            size: [0.; 2],
+        };
+        model.collect(&graph.elements, &mut Defaults::default(), true);
+        model.rankdir = match model.graph_attrs.get("rankdir").map(String::as_str) {
+            Some("BT") => RankDir::BottomTop,
+            Some("LR") => RankDir::LeftRight,
+            Some("RL") => RankDir::RightLeft,
+            _ => RankDir::TopBottom,
+        };
+        for node in &mut model.nodes {
+            node.measure();
+        }
+        model
+    }
+
+    fn collect(&mut self, elements: &[Element], defaults: &mut Defaults, root: bool) {
+        for element in elements {
+            match element {
+                Element::NodeAttr(a) => {
+                    defaults.node.insert(a.name.clone(), a.text());
+                }
+                Element::EdgeAttr(a) => {
+                    defaults.edge.insert(a.name.clone(), a.text());
+                }
+                Element::Attribute(a) => {
+                    // subgraph attributes (rank=same, cluster labels) aren't laid out.
+                    if root {
+                        self.graph_attrs.insert(a.name.clone(), a.text());
+                    }
+                }
+                Element::Node(n) => {
+                    let idx = self.node(&n.id, &defaults.node);
+                    for a in &n.attributes.0 {
+                        self.nodes[idx].attrs.insert(a.name.clone(), a.text());
+                    }
+                }
+                Element::Edge(e) => {
+                    let tail = self.node(&e.first_node, &defaults.node);
+                    let head = self.node(&e.second_node, &defaults.node);
+                    let mut attrs = defaults.edge.clone();
+                    for a in &e.attributes.0 {
+                        attrs.insert(a.name.clone(), a.text());
+                    }
+                    if self.strict
+                        && self.edges.iter().any(|e| {
+                            (e.tail, e.head) == (tail, head)
+                                || (!self.directed && (e.tail, e.head) == (head, tail))
+                        })
+                    {
+                        continue;
+                    }
+                    self.edges.push(LayoutEdge { tail, head, attrs });
+                }
+                Element::Subgraph(s) => {
+                    self.collect(&s.elements, &mut defaults.clone(), false);
+                }
+            }
+        }
+    }
+
+    /// Returns the index of the named node, creating it with the defaults if it's new
+    fn node(&mut self, name: &str, defaults: &Attrs) -> usize {
+        if let Some(idx) = self.index.get(name) {
+            return *idx;
+        }
+        let idx = self.nodes.len();
+        self.nodes.push(LayoutNode {
+            name: name.to_string(),
+            attrs: defaults.clone(),
+            shape: Shape::Ellipse,
+            lines: Vec::new(),
+            font_size: DEFAULT_FONT_SIZE,
+            width: 0.,
+            height: 0.,
+            center: [0.; 2],
+        });
+        self.index.insert(name.to_string(), idx);
+        idx
+    }
+
+    ///
+    /// Layered layout: cycles are broken by reversing back edges, nodes are ranked by longest
+    /// path, long edges are split with virtual nodes, ranks are ordered by barycenter to reduce
+    /// crossings, then positions are relaxed towards their neighbors.  Returns the route of
+    /// each edge, from tail to head.
+    fn layered(&mut self, options: &LayoutOptions) -> Vec<Vec<[f64; 2]>> {
+        let count = self.nodes.len();
+        let reversed = self.back_edges();
+        let ends: Vec<(usize, usize)> = self
+            .edges
+            .iter()
+            .zip(&reversed)
+            .map(|(e, rev)| {
+                if *rev {
+                    (e.head, e.tail)
+                } else {
+                    (e.tail, e.head)
+                }
+            })
+            .collect();
+
+        let ranks = longest_path_ranks(count, &ends);
+        let horizontal = self.rankdir.is_horizontal();
+        let mut vertices: Vec<Vertex> = self
+            .nodes
+            .iter()
+            .zip(&ranks)
+            .map(|(n, rank)| {
+                let (breadth, depth) = if horizontal {
+                    (n.height, n.width)
+                } else {
+                    (n.width, n.height)
+                };
+                Vertex {
+                    rank: *rank,
+                    breadth,
+                    depth,
+                    up: Vec::new(),
+                    down: Vec::new(),
+                }
+            })
+            .collect();
+
+        // each edge becomes a chain of vertices, one per rank.
+        let mut chains: Vec<Vec<usize>> = Vec::with_capacity(ends.len());
+        for (from, to) in &ends {
+            let mut chain = vec![*from];
+            if from != to {
+                let mut last = *from;
+                for rank in ranks[*from] + 1..ranks[*to] {
+                    let dummy = vertices.len();
+                    vertices.push(Vertex {
+                        rank,
+                        breadth: 0.,
+                        depth: 0.,
+                        up: vec![last],
+                        down: Vec::new(),
+                    });
+                    vertices[last].down.push(dummy);
+                    chain.push(dummy);
+                    last = dummy;
+                }
+                vertices[last].down.push(*to);
+                vertices[*to].up.push(last);
+                chain.push(*to);
+            }
+            chains.push(chain);
+        }
+
+        let mut layers = order_ranks(&vertices);
+        let breadth = place_in_ranks(&vertices, &mut layers, options.node_separation);
+
+        // centers along the rank axis.
+        let mut rank_centers = Vec::with_capacity(layers.len());
+        let mut offset = MARGIN;
+        for layer in &layers {
+            let depth = layer.iter().map(|v| vertices[*v].depth).fold(0., f64::max);
+            rank_centers.push(offset + depth / 2.);
+            offset += depth + options.rank_separation;
+        }
+        let depth = offset - options.rank_separation + MARGIN;
+        let extent = layers
+            .iter()
+            .flatten()
+            .map(|v| breadth[*v] + vertices[*v].breadth / 2.)
+            .fold(0., f64::max)
+            + MARGIN;
+
+        let (width, height) = if horizontal {
+            (depth, extent)
+        } else {
+            (extent, depth)
+        };
+        self.size = [width.max(0.), height.max(0.)];
+        let rankdir = self.rankdir;
+        let to_graph = |along: f64, across: f64| -> [f64; 2] {
+            match rankdir {
+                RankDir::TopBottom => [along, height - across],
+                RankDir::BottomTop => [along, across],
+                RankDir::LeftRight => [across, height - along],
+                RankDir::RightLeft => [width - across, height - along],
+            }
+        };
+        let position = |v: usize| to_graph(breadth[v], rank_centers[vertices[v].rank]);
+        for (idx, node) in self.nodes.iter_mut().enumerate() {
+            node.center = position(idx);
+        }
+        chains
+            .iter()
+            .zip(&reversed)
+            .map(|(chain, rev)| {
+                let mut route: Vec<[f64; 2]> = chain.iter().map(|v| position(*v)).collect();
+                if *rev {
+                    route.reverse();
+                }
+                route
+            })
+            .collect()
+    }
+
+    ///
+    /// Finds the edges that close a cycle in a depth first search, which are reversed to make
+    /// the graph acyclic.  Self loops aren't reversed.
+    fn back_edges(&self) -> Vec<bool> {
+        let count = self.nodes.len();
+        let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); count];
+        for (idx, edge) in self.edges.iter().enumerate() {
+            outgoing[edge.tail].push(idx);
+        }
+        let mut reversed = vec![false; self.edges.len()];
+        // 0: unvisited, 1: on the stack, 2: finished
+        let mut state = vec![0_u8; count];
+        for root in 0..count {
+            if state[root] != 0 {
+                continue;
+            }
+            state[root] = 1;
+            let mut stack = vec![(root, 0_usize)];
+            while let Some((node, next)) = stack.pop() {
+                let Some(edge) = outgoing[node].get(next).copied() else {
+                    state[node] = 2;
+                    continue;
+                };
+                stack.push((node, next + 1));
+                let head = self.edges[edge].head;
+                match state[head] {
+                    0 => {
+                        state[head] = 1;
+                        stack.push((head, 0));
+                    }
+                    1 if head != node => reversed[edge] = true,
+                    _ => {}
+                }
+            }
+        }
+        reversed
+    }
+
+    ///
+    /// Force directed layout with the [`irox_graphing::fdp`] simulation.  Overlaps left over by
+    /// the simulation are pushed apart.  Returns the route of each edge, from tail to head.
+    fn force(&mut self, options: &LayoutOptions) -> Vec<Vec<[f64; 2]>> {
+        let ids: Vec<Identifier> = self
+            .nodes
+            .iter()
+            .map(|n| Identifier::String(n.name.clone()))
+            .collect();
+        let mut graph = FdpGraph::new();
+        for id in &ids {
+            let _ = graph.add_node(FdpNode::from_id(id.clone()).into());
+        }
+        for (idx, edge) in self.edges.iter().enumerate() {
+            if edge.tail == edge.head {
+                continue;
+            }
+            let descriptor = EdgeDescriptor(Identifier::Integer(idx as u64).into());
+            let _ = graph.add_edge(
+                FdpEdge::Undirected {
+                    descriptor,
+                    left: ids[edge.tail].clone().into(),
+                    right: ids[edge.head].clone().into(),
+                }
+                .into(),
+            );
+        }
+
+        let largest = self
+            .nodes
+            .iter()
+            .map(|n| n.width.max(n.height))
+            .fold(0., f64::max);
+        let distance = largest + options.rank_separation;
+        let params = SimulationParams::default().with_target_iterations(options.force_iterations);
+        let forces = vec![
+            Force::Centering(Centering::new(0.01)),
+            Force::Edge(EdgeForce::default().with_distance(distance)),
+            Force::Repulsive(
+                Repulsive::default()
+                    .with_strength(-distance)
+                    .with_edge_distance(distance),
+            ),
+        ];
+        let mut sim = Simulation::new(
+            params,
+            forces,
+            Rc::new(RefCell::new(graph)),
+            Box::new(DefaultNodePlacement::default().with_initial_radius(distance)),
+        );
+        sim.stepping(|_| {});
+        for (node, id) in self.nodes.iter_mut().zip(&ids) {
+            sim.node(&id.clone().into(), |working| {
+                node.center = [working.current_position.vx, working.current_position.vy];
+            });
+        }
+
+        self.separate(options.node_separation);
+        self.normalize();
+        self.edges
+            .iter()
+            .map(|e| vec![self.nodes[e.tail].center, self.nodes[e.head].center])
+            .collect()
+    }
+
+    /// Pushes overlapping nodes apart along the axis with the least overlap
+    fn separate(&mut self, gap: f64) {
+        for _ in 0..100 {
+            let mut moved = false;
+            for a in 0..self.nodes.len() {
+                for b in a + 1..self.nodes.len() {
+                    let (na, nb) = (&self.nodes[a], &self.nodes[b]);
+                    let dx = nb.center[0] - na.center[0];
+                    let dy = nb.center[1] - na.center[1];
+                    let ox = f64::midpoint(na.width, nb.width) + gap - dx.abs();
+                    let oy = f64::midpoint(na.height, nb.height) + gap - dy.abs();
+                    if ox <= 0. || oy <= 0. {
+                        continue;
+                    }
+                    moved = true;
+                    // coincident nodes are split by index, so the push has a direction.
+                    let push = if ox < oy {
+                        [ox / 2. * sign(dx, a < b), 0.]
+                    } else {
+                        [0., oy / 2. * sign(dy, a < b)]
+                    };
+                    for (idx, dir) in [(a, -1.), (b, 1.)] {
+                        let center = &mut self.nodes[idx].center;
+                        center[0] += push[0] * dir;
+                        center[1] += push[1] * dir;
+                    }
+                }
+            }
+            if !moved {
+                break;
+            }
+        }
+    }
+
+    /// Moves the nodes so the bounding box starts at the origin
+    fn normalize(&mut self) {
+        let mut min = [f64::INFINITY; 2];
+        let mut max = [f64::NEG_INFINITY; 2];
+        for node in &self.nodes {
+            let half = [node.width / 2., node.height / 2.];
+            for (((min, max), center), half) in
+                min.iter_mut().zip(&mut max).zip(node.center).zip(half)
+            {
+                *min = min.min(center - half);
+                *max = max.max(center + half);
+            }
+        }
+        if self.nodes.is_empty() {
+            return;
+        }
+        for node in &mut self.nodes {
+            for (center, min) in node.center.iter_mut().zip(min) {
+                *center += MARGIN - min;
+            }
+        }
+        self.size = [max[0] - min[0] + 2. * MARGIN, max[1] - min[1] + 2. * MARGIN];
+    }
+
+    fn into_dotjson(self, routes: Vec<Vec<[f64; 2]>>) -> DotJson {
+        let objects = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(idx, n)| n.metanode(idx))
+            .collect();
+        let edges = self
+            .edges
+            .iter()
+            .zip(routes)
+            .enumerate()
+            .map(|(idx, (e, route))| self.draw_edge(idx, e, route))
+            .collect();
+        let [width, height] = self.size;
+        DotJson {
+            name: self.name,
+            directed: self.directed,
+            strict: self.strict,
+            drawopts: Vec::new(),
+            bb: format!("0,0,{width:.2},{height:.2}"),
+            fontname: None,
+            fontsize: None,
+            label: self.graph_attrs.get("label").cloned(),
+            lheight: None,
+            lp: None,
+            lwidth: None,
+            size: None,
+            ssize: None,
+            style: None,
+            rankdir: self.rankdir.name().to_string(),
+            xdotversion: "1.7".to_string(),
+            _subgraph_cnt: 0,
+            objects,
+            edges,
+        }
+    }
+
+    fn draw_edge(
+        &self,
+        idx: usize,
+        edge: &LayoutEdge,
+        route: Vec<[f64; 2]>,
+    ) -> crate::drawing::Edge {
+        let tail = &self.nodes[edge.tail];
+        let head = &self.nodes[edge.head];
+        let mut draw = color_ops(edge.attrs.get("color"));
+        let mut head_draw = None;
+
+        let mut spline = if edge.tail == edge.head {
+            tail.self_loop()
+        } else {
+            let mut route = route;
+            let len = route.len();
+            if len >= 2 {
+                route[0] = tail.clip(route[1]);
+                route[len - 1] = head.clip(route[len - 2]);
+            }
+            catmull_rom(&route)
+        };
+        if self.directed {
+            if let [.., before, end] = spline.as_mut_slice() {
+                let tip = *end;
+                let base = towards(tip, *before, ARROW_LENGTH);
+                *end = base;
+                let mut ops = color_ops(edge.attrs.get("color"));
+                ops.insert(
+                    0,
+                    Draw::Style(Style {
+                        style: "solid".to_string(),
+                    }),
+                );
+                ops.push(Draw::Polygon(Points {
+                    points: arrowhead(base, tip),
+                }));
+                head_draw = Some(ops);
+            }
+        }
+        let pos = spline
+            .iter()
+            .map(|[x, y]| format!("{x:.2},{y:.2}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let label = edge.attrs.get("label").cloned();
+        let mut lp = None;
+        let mut label_draw = None;
+        if let Some(text) = &label {
+            let [x, y] = midpoint(&spline);
+            let font_size = font_size(&edge.attrs);
+            let lines = label_lines(text, "");
+            let width = text_width(&lines, font_size);
+            let center = [x + width / 2. + MARGIN, y];
+            lp = Some(format!("{:.2},{:.2}", center[0], center[1]));
+            label_draw = Some(text_ops(
+                &lines,
+                center,
+                font_size,
+                edge.attrs.get("fontcolor"),
+            ));
+        }
+        draw.push(Draw::BSPLine(Points {
+            points: spline.iter().map(|p| to_point2(*p)).collect(),
+        }));
+        crate::drawing::Edge {
+            _gvid: idx as i32,
+            head: edge.head as i32,
+            tail: edge.tail as i32,
+            _hldraw_: None,
+            _tdraw_: None,
+            _ldraw_: label_draw,
+            _draw_: Some(draw),
+            _tldraw_: None,
+            _hdraw_: head_draw,
+            label,
+            lp,
+            pos: Some(pos),
+            tailport: edge.attrs.get("tailport").cloned(),
+        }
+    }
+}
+
+impl LayoutNode {
+    /// Resolves the label & shape from the attributes, and sizes the node to fit
+    fn measure(&mut self) {
+        self.shape = match self.attrs.get("shape").map(String::as_str) {
+            Some("box" | "rect" | "rectangle" | "square" | "record" | "Mrecord") => Shape::Box,
+            Some("point") => Shape::Point,
+            Some("plaintext" | "plain" | "none") => Shape::Plain,
+            _ => Shape::Ellipse,
+        };
+        self.font_size = font_size(&self.attrs);
+        let label = self.attrs.get("label").map_or("\\N", String::as_str);
+        self.lines = if self.shape == Shape::Point {
+            Vec::new()
+        } else {
+            label_lines(label, &self.name)
+        };
+        let text_width = text_width(&self.lines, self.font_size);
+        let text_height = self.lines.len() as f64 * self.font_size * LINE_HEIGHT;
+        let (mut width, mut height) = match self.shape {
+            Shape::Ellipse => (
+                (text_width + 16.) * core::f64::consts::SQRT_2,
+                (text_height + 4.) * core::f64::consts::SQRT_2,
+            ),
+            Shape::Box | Shape::Plain => (text_width + 16., text_height + 8.),
+            Shape::Point => (7.2, 7.2),
+        };
+        let inches = |name: &str| {
+            self.attrs
+                .get(name)
+                .and_then(|v| v.parse::<f64>().ok())
+                .map(|v| v * POINTS_PER_INCH)
+        };
+        if self.shape != Shape::Point {
+            width = width.max(inches("width").unwrap_or(54.));
+            height = height.max(inches("height").unwrap_or(36.));
+        }
+        if self
+            .attrs
+            .get("shape")
+            .is_some_and(|s| s == "square" || s == "circle")
+        {
+            width = width.max(height);
+            height = width;
+        }
+        self.width = width;
+        self.height = height;
+    }
+
+    fn metanode(&self, idx: usize) -> Metanode {
+        let [x, y] = self.center;
+        let (w, h) = (self.width / 2., self.height / 2.);
+        let mut draw = color_ops(self.attrs.get("color"));
+        match self.shape {
+            Shape::Ellipse | Shape::Point => {
+                draw.push(Draw::Ellipse(Ellipse { rect: [x, y, w, h] }))
+            }
+            Shape::Box => draw.push(Draw::Polygon(Points {
+                points: [
+                    [x - w, y - h],
+                    [x + w, y - h],
+                    [x + w, y + h],
+                    [x - w, y + h],
+                ]
+                .into_iter()
+                .map(to_point2)
+                .collect(),
+            })),
+            Shape::Plain => {}
+        }
+        let ldraw = (!self.lines.is_empty()).then(|| {
+            text_ops(
+                &self.lines,
+                self.center,
+                self.font_size,
+                self.attrs.get("fontcolor"),
+            )
+        });
+        Metanode {
+            name: self.name.clone(),
+            _draw_: draw,
+            _ldraw_: ldraw,
+            _gvid: idx as i32,
+            subgraphs: None,
+            height: Some(format!("{:.3}", self.height / POINTS_PER_INCH)),
+            label: self.lines.join("\n"),
+            pos: Some(format!("{x:.2},{y:.2}")),
+            width: Some(format!("{:.3}", self.width / POINTS_PER_INCH)),
+            color: self.attrs.get("color").cloned(),
+            fontsize: self.attrs.get("fontsize").cloned(),
+            textcolor: self.attrs.get("fontcolor").cloned(),
+        }
+    }
+
+    /// Returns where the line from the center towards `other` crosses the node outline
+    fn clip(&self, other: [f64; 2]) -> [f64; 2] {
+        let [cx, cy] = self.center;
+        let (dx, dy) = (other[0] - cx, other[1] - cy);
+        let (w, h) = (self.width / 2., self.height / 2.);
+        let scale = match self.shape {
+            Shape::Ellipse | Shape::Point => 1. / ((dx / w).powi(2) + (dy / h).powi(2)).sqrt(),
+            Shape::Box | Shape::Plain => (w / dx.abs()).min(h / dy.abs()),
+        };
+        if !scale.is_finite() || scale >= 1. {
+            return self.center;
+        }
+        [cx + dx * scale, cy + dy * scale]
+    }
+
+    /// A loop off the right side of the node, as bezier control points
+    fn self_loop(&self) -> Vec<[f64; 2]> {
+        let [cx, cy] = self.center;
+        let (w, h) = (self.width / 2., self.height / 2.);
+        let start = self.clip([cx + w, cy + h / 2.]);
+        let end = self.clip([cx + w, cy - h / 2.]);
+        let reach = cx + w + 2. * ARROW_LENGTH;
+        vec![start, [reach, cy + h], [reach, cy - h], end]
+    }
+}
+
+struct Vertex {
+    rank: usize,
+    /// Size across the rank
+    breadth: f64,
+    /// Size along the rank axis
+    depth: f64,
+    up: Vec<usize>,
+    down: Vec<usize>,
+}
+
+/// Ranks the nodes so every edge points to a higher rank, edges must be acyclic
+fn longest_path_ranks(count: usize, ends: &[(usize, usize)]) -> Vec<usize> {
+    let mut incoming = vec![0_usize; count];
+    let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); count];
+    for (from, to) in ends {
+        if from != to {
+            incoming[*to] += 1;
+            outgoing[*from].push(*to);
+        }
+    }
+    let mut ranks = vec![0_usize; count];
+    let mut ready: Vec<usize> = (0..count).filter(|n| incoming[*n] == 0).rev().collect();
+    while let Some(node) = ready.pop() {
+        for next in &outgoing[node] {
+            ranks[*next] = ranks[*next].max(ranks[node] + 1);
+            incoming[*next] -= 1;
+            if incoming[*next] == 0 {
+                ready.push(*next);
+            }
+        }
+    }
+    ranks
+}
+
+///
+/// Orders the vertices within each rank with alternating barycenter sweeps, keeping the order
+/// with the fewest crossings.
+fn order_ranks(vertices: &[Vertex]) -> Vec<Vec<usize>> {
+    let ranks = vertices
+        .iter()
+        .map(|v| v.rank + 1)
+        .max()
+        .unwrap_or_default();
+    let mut layers: Vec<Vec<usize>> = vec![Vec::new(); ranks];
+    for (idx, vertex) in vertices.iter().enumerate() {
+        layers[vertex.rank].push(idx);
+    }
+    let mut order = vec![0_usize; vertices.len()];
+    let index = |layers: &[Vec<usize>], order: &mut [usize]| {
+        for layer in layers {
+            for (pos, v) in layer.iter().enumerate() {
+                order[*v] = pos;
+            }
+        }
+    };
+    index(&layers, &mut order);
+    let mut best = layers.clone();
+    let mut best_crossings = crossings(vertices, &layers, &order);
+
+    for sweep in 0..CROSSING_SWEEPS {
+        let downwards = sweep % 2 == 0;
+        let sequence: Vec<usize> = if downwards {
+            (1..ranks).collect()
+        } else {
+            (0..ranks.saturating_sub(1)).rev().collect()
+        };
+        for rank in sequence {
+            let mut keyed: Vec<(f64, usize)> = layers[rank]
+                .iter()
+                .map(|v| {
+                    let vertex = &vertices[*v];
+                    let neighbors = if downwards { &vertex.up } else { &vertex.down };
+                    let key = if neighbors.is_empty() {
+                        order[*v] as f64
+                    } else {
+                        neighbors.iter().map(|n| order[*n] as f64).sum::<f64>()
+                            / neighbors.len() as f64
+                    };
+                    (key, *v)
+                })
+                .collect();
+            keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+            layers[rank] = keyed.into_iter().map(|(_, v)| v).collect();
+            for (pos, v) in layers[rank].iter().enumerate() {
+                order[*v] = pos;
+            }
+        }
+        let count = crossings(vertices, &layers, &order);
+        if count < best_crossings {
+            best_crossings = count;
+            best.clone_from(&layers);
+        }
+    }
+    best
+}
+
+/// Counts the edge crossings between each pair of adjacent ranks
+fn crossings(vertices: &[Vertex], layers: &[Vec<usize>], order: &[usize]) -> usize {
+    let mut count = 0;
+    for layer in layers {
+        let segments: Vec<(usize, usize)> = layer
+            .iter()
+            .flat_map(|v| vertices[*v].down.iter().map(|d| (order[*v], order[*d])))
+            .collect();
+        for (idx, (a0, a1)) in segments.iter().enumerate() {
+            for (b0, b1) in segments.iter().skip(idx + 1) {
+                if (a0 < b0 && a1 > b1) || (a0 > b0 && a1 < b1) {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+///
+/// Positions the vertices across their rank.  Each vertex is pulled towards the mean position
+/// of its neighbors in the adjacent rank, while keeping the order and the separation.
+fn place_in_ranks(vertices: &[Vertex], layers: &mut [Vec<usize>], separation: f64) -> Vec<f64> {
+    let mut position = vec![0.; vertices.len()];
+    for layer in layers.iter() {
+        let mut next = MARGIN;
+        for v in layer {
+            let breadth = vertices[*v].breadth;
+            position[*v] = next + breadth / 2.;
+            next += breadth + separation;
+        }
+    }
+    let gap =
+        |a: usize, b: usize| f64::midpoint(vertices[a].breadth, vertices[b].breadth) + separation;
+
+    for sweep in 0..POSITION_SWEEPS {
+        let downwards = sweep % 2 == 0;
+        let count = layers.len();
+        for step in 0..count {
+            let rank = if downwards { step } else { count - 1 - step };
+            let layer = &layers[rank];
+            let desired: Vec<f64> = layer
+                .iter()
+                .map(|v| {
+                    let vertex = &vertices[*v];
+                    let neighbors = if downwards { &vertex.up } else { &vertex.down };
+                    if neighbors.is_empty() {
+                        position[*v]
+                    } else {
+                        neighbors.iter().map(|n| position[*n]).sum::<f64>() / neighbors.len() as f64
+                    }
+                })
+                .collect();
+            // pack from the left and from the right, the average of both keeps the gaps.
+            let mut left = desired.clone();
+            for idx in 1..layer.len() {
+                left[idx] = left[idx].max(left[idx - 1] + gap(layer[idx - 1], layer[idx]));
+            }
+            let mut right = desired;
+            for idx in (0..layer.len().saturating_sub(1)).rev() {
+                right[idx] = right[idx].min(right[idx + 1] - gap(layer[idx], layer[idx + 1]));
+            }
+            for (idx, v) in layer.iter().enumerate() {
+                position[*v] = f64::midpoint(left[idx], right[idx]);
+            }
+        }
+    }
+
+    let min = layers
+        .iter()
+        .flatten()
+        .map(|v| position[*v] - vertices[*v].breadth / 2.)
+        .fold(f64::INFINITY, f64::min);
+    if min.is_finite() {
+        for p in &mut position {
+            *p += MARGIN - min;
+        }
+    }
+    position
+}
+
+/// Splits a label into lines, substituting the node name for `\N`
+fn label_lines(label: &str, name: &str) -> Vec<String> {
+    let label = label.replace("\\N", name);
+    let label = match label.strip_prefix('<').and_then(|l| l.strip_suffix('>')) {
+        Some(html) => strip_tags(html),
+        None => label,
+    };
+    label
+        .replace("\\l", "\n")
+        .replace("\\r", "\n")
+        .replace("\\n", "\n")
+        .trim_end_matches('\n')
+        .split('\n')
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Drops the markup from an HTML label, keeping the text and turning `<br/>` into line breaks
+fn strip_tags(html: &str) -> String {
+    let mut out = String::new();
+    let mut tag: Option<String> = None;
+    for c in html.chars() {
+        match (&mut tag, c) {
+            (None, '<') => tag = Some(String::new()),
+            (Some(t), '>') => {
+                if t.trim_start().to_ascii_lowercase().starts_with("br") {
+                    out.push('\n');
+                }
+                tag = None;
+            }
+            (Some(t), c) => t.push(c),
+            (None, c) => out.push(c),
+        }
+    }
+    out
+}
+
+fn font_size(attrs: &Attrs) -> f64 {
+    attrs
+        .get("fontsize")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_FONT_SIZE)
+}
+
+fn text_width(lines: &[String], font_size: f64) -> f64 {
+    lines
+        .iter()
+        .map(|l| l.chars().count() as f64 * font_size * CHAR_WIDTH)
+        .fold(0., f64::max)
+}
+
+/// Draws the lines of text centered on the point
+fn text_ops(
+    lines: &[String],
+    center: [f64; 2],
+    font_size: f64,
+    color: Option<&String>,
+) -> Vec<Draw> {
+    let mut ops = vec![Draw::Font(Font {
+        size: font_size,
+        face: DEFAULT_FONT_FACE.to_string(),
+    })];
+    ops.append(&mut color_ops(color));
+    let line_height = font_size * LINE_HEIGHT;
+    let top = center[1] + (lines.len() as f64 - 1.) * line_height / 2.;
+    for (idx, line) in lines.iter().enumerate() {
+        // the text point is the baseline, which sits below the middle of the line.
+        let baseline = top - idx as f64 * line_height - font_size * 0.26;
+        ops.push(Draw::Text(Text {
+            pt: to_point2([center[0], baseline]),
+            width: text_width(core::slice::from_ref(line), font_size),
+            align: "c".to_string(),
+            text: line.clone(),
+        }));
+    }
+    ops
+}
+
+fn color_ops(color: Option<&String>) -> Vec<Draw> {
+    color
+        .and_then(|c| hex_color(c))
+        .map(|color| {
+            Draw::Color(Color {
+                grad: "none".to_string(),
+                color,
+            })
+        })
+        .into_iter()
+        .collect()
+}
+
+/// Converts a color name to the `#rrggbb` form used by the drawing model
+fn hex_color(name: &str) -> Option<String> {
+    if name.starts_with('#') {
+        return Some(name.to_string());
+    }
+    let hex = match name.to_ascii_lowercase().as_str() {
+        "black" => "#000000",
+        "white" => "#ffffff",
+        "red" => "#ff0000",
+        "green" => "#00ff00",
+        "blue" => "#0000ff",
+        "yellow" => "#ffff00",
+        "cyan" => "#00ffff",
+        "magenta" => "#ff00ff",
+        "orange" => "#ffa500",
+        "purple" => "#a020f0",
+        "brown" => "#a52a2a",
+        "pink" => "#ffc0cb",
+        "gray" | "grey" => "#c0c0c0",
+        "darkgreen" => "#006400",
+        "navy" => "#000080",
+        _ => return None,
+    };
+    Some(hex.to_string())
+}
+
+/// Converts the polyline through the points into cubic bezier control points
+fn catmull_rom(points: &[[f64; 2]]) -> Vec<[f64; 2]> {
+    let Some(first) = points.first() else {
+        return Vec::new();
+    };
+    let mut out = vec![*first];
+    for idx in 0..points.len().saturating_sub(1) {
+        let p0 = points[idx.saturating_sub(1)];
+        let p1 = points[idx];
+        let p2 = points[idx + 1];
+        let p3 = points.get(idx + 2).copied().unwrap_or(p2);
+        out.push([p1[0] + (p2[0] - p0[0]) / 6., p1[1] + (p2[1] - p0[1]) / 6.]);
+        out.push([p2[0] - (p3[0] - p1[0]) / 6., p2[1] - (p3[1] - p1[1]) / 6.]);
+        out.push(p2);
+    }
+    out
+}
+
+/// Returns the point `distance` from `from` towards `to`
+fn towards(from: [f64; 2], to: [f64; 2], distance: f64) -> [f64; 2] {
+    let (dx, dy) = (to[0] - from[0], to[1] - from[1]);
+    let len = dx.hypot(dy);
+    if len <= f64::EPSILON {
+        return from;
+    }
+    [from[0] + dx / len * distance, from[1] + dy / len * distance]
+}
+
+fn arrowhead(base: [f64; 2], tip: [f64; 2]) -> Vec<[f32; 2]> {
+    let (dx, dy) = (tip[0] - base[0], tip[1] - base[1]);
+    let len = dx.hypot(dy).max(f64::EPSILON);
+    let (nx, ny) = (-dy / len * ARROW_WIDTH, dx / len * ARROW_WIDTH);
+    [
+        [base[0] + nx, base[1] + ny],
+        tip,
+        [base[0] - nx, base[1] - ny],
+    ]
+    .into_iter()
+    .map(to_point2)
+    .collect()
+}
+
+/// The middle point of the curve
+fn midpoint(spline: &[[f64; 2]]) -> [f64; 2] {
+    let Some(mid) = spline.get(spline.len() / 2) else {
+        return [0.; 2];
+    };
+    *mid
+}
+
+fn sign(delta: f64, forward: bool) -> f64 {
+    if delta > 0. || (delta == 0. && forward) {
+        1.
+    } else {
+        -1.
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn to_point2(p: [f64; 2]) -> [f32; 2] {
+    [p[0] as f32, p[1] as f32]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{layout, layout_with, Engine, LayoutOptions};
+    use crate::drawing::{DotJson, Draw, Metanode};
+    use crate::{Graph, ParseError};
+
+    fn center(node: &Metanode) -> Option<[f64; 2]> {
+        let (x, y) = node.pos.as_deref()?.split_once(',')?;
+        Some([x.parse().ok()?, y.parse().ok()?])
+    }
+
+    fn size(node: &Metanode) -> Option<[f64; 2]> {
+        let w: f64 = node.width.as_deref()?.parse().ok()?;
+        let h: f64 = node.height.as_deref()?.parse().ok()?;
+        Some([w * 72., h * 72.])
+    }
+
+    fn find<'a>(json: &'a DotJson, name: &str) -> Option<&'a Metanode> {
+        json.objects.iter().find(|o| o.name == name)
+    }
+
+    /// Returns true if no two nodes overlap, and every node is within the bounding box
+    fn well_placed(json: &DotJson) -> bool {
+        let Some([x0, y0, x1, y1]) = json.bounding_box() else {
+            return false;
+        };
+        let boxes: Vec<[f64; 4]> = json
+            .objects
+            .iter()
+            .filter_map(|o| {
+                let [x, y] = center(o)?;
+                let [w, h] = size(o)?;
+                Some([x - w / 2., y - h / 2., x + w / 2., y + h / 2.])
+            })
+            .collect();
+        let inside = boxes.iter().all(|[l, b, r, t]| {
+            *l >= x0 - 0.01 && *b >= y0 - 0.01 && *r <= x1 + 0.01 && *t <= y1 + 0.01
+        });
+        let overlap = boxes.iter().enumerate().any(|(idx, a)| {
+            boxes
+                .iter()
+                .skip(idx + 1)
+                .any(|b| a[0] < b[2] && b[0] < a[2] && a[1] < b[3] && b[1] < a[3])
+        });
+        boxes.len() == json.objects.len() && inside && !overlap
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_layered() -> Result<(), ParseError> {
+        let graph = Graph::parse(
+            "digraph G {
+                node [shape=box];
+                a -> b -> c -> a;
+                a -> d; d -> c;
+                b -> e [label=\"long edge\"];
+                e -> e;
+                f [label=\"two\\nlines\", shape=ellipse];
+            }",
+        )?;
+        assert_eq!(Engine::Layered, Engine::for_graph(&graph));
+        let json = layout(&graph);
+        assert!(json.directed);
+        assert_eq!(6, json.objects.len());
+        assert_eq!(7, json.edges.len());
+        assert!(well_placed(&json));
+
+        // ranks run down the page, so y decreases along the edges.
+        let y = |name: &str| find(&json, name).and_then(center).map(|[_, y]| y);
+        assert!(y("a") > y("b"));
+        assert!(y("b") > y("c"));
+        assert!(y("d") < y("a") && y("d") > y("c"));
+        assert_eq!(
+            Some(vec!["two".to_string(), "lines".to_string()]),
+            find(&json, "f")
+                .and_then(|f| f._ldraw_.as_ref())
+                .map(|ops| ops
+                    .iter()
+                    .filter_map(|op| match op {
+                        Draw::Text(t) => Some(t.text.clone()),
+                        _ => None,
+                    })
+                    .collect())
+        );
+        // every edge has a curve & arrowhead, the back edge still points at its head.
+        for edge in &json.edges {
+            assert!(edge._draw_.as_ref().is_some_and(|d| d
+                .iter()
+                .any(|op| matches!(op, Draw::BSPLine(p) if p.points.len() % 3 == 1))));
+            assert!(edge._hdraw_.is_some());
+        }
+        assert!(json
+            .edges
+            .iter()
+            .any(|e| e.label.as_deref() == Some("long edge") && e.lp.is_some()));
+
+        // round trips through the JSON form read from Graphviz.
+        let text = serde_json::to_string(&json).unwrap_or_default();
+        assert!(DotJson::load(text.as_bytes()).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_rankdir() -> Result<(), ParseError> {
+        let graph = Graph::parse("digraph { rankdir=LR; a -> b; a -> c; b -> d; c -> d }")?;
+        let json = layout(&graph);
+        assert_eq!("LR", json.rankdir);
+        assert!(well_placed(&json));
+        let x = |name: &str| find(&json, name).and_then(center).map(|[x, _]| x);
+        assert!(x("a") < x("b"));
+        assert!(x("b") < x("d"));
+        assert_eq!(x("b"), x("c"));
+        Ok(())
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_force() -> Result<(), ParseError> {
+        let graph =
+            Graph::parse("graph { a -- b -- c -- a; c -- d; d -- e; e -- f; f -- d; g; a -- a }")?;
+        assert_eq!(Engine::Force, Engine::for_graph(&graph));
+        let json = layout_with(&graph, Engine::Force, &LayoutOptions::default());
+        assert!(!json.directed);
+        assert_eq!(7, json.objects.len());
+        assert!(well_placed(&json));
+        assert!(json.edges.iter().all(|e| e._hdraw_.is_none()));
+
+        let forced = Graph::parse("digraph { layout=fdp; a -> b }")?;
+        assert_eq!(Engine::Force, Engine::for_graph(&forced));
+        Ok(())
+    }
+}
@@ -2,7 +2,9 @@
 // Copyright 2023 IROX Contributors
 
 //!
-//! DOT Graph Description Language writer, compatible with GraphViz
+//! DOT Graph Description Language reader & writer, compatible with GraphViz
+//!
+//! Graphs are parsed with [`Graph::parse`], see the [`parser`] module.
 //!
 //! ### Example:
 //! ```
@@ -55,6 +57,8 @@ macro_rules! cfg_feature_drawing {
 cfg_feature_drawing! {
     pub mod drawing;
 }
+pub mod parser;
+pub use parser::{ParseError, Span};
 
 use irox_bits::{BitsError, FormatBits, MutBits};
 use std::collections::HashSet;
@@ -105,9 +109,9 @@ impl DotLine for Element {
             Element::Node(n) => n.get_line(),
             Element::Edge(e) => e.get_line(),
             Element::Attribute(a) => a.get_line(),
-            _ => {
-                todo!()
-            }
+            Element::NodeAttr(a) => format!("node [{}]", a.get_line()),
+            Element::EdgeAttr(a) => format!("edge [{}]", a.get_line()),
+            Element::Subgraph(s) => s.get_line(),
         }
     }
 }
@@ -169,6 +173,27 @@ pub struct Subgraph {
     pub id: Option<String>,
     pub elements: Vec<Element>,
 }
+impl Subgraph {
+    /// Clusters are subgraphs whose ID starts with `cluster`, and are drawn in their own box.
+    pub fn is_cluster(&self) -> bool {
+        self.id.as_ref().is_some_and(|id| id.starts_with("cluster"))
+    }
+}
+impl DotLine for Subgraph {
+    fn get_line(&self) -> String {
+        let id = match &self.id {
+            Some(id) => format!("{} ", parser::quote(id)),
+            None => String::new(),
+        };
+        let elements = self
+            .elements
+            .iter()
+            .map(DotLine::get_line)
+            .collect::<Vec<_>>()
+            .join("; ");
+        format!("subgraph {id}{{ {elements} }}")
+    }
+}
 
 #[derive(Default, Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Attribute {
@@ -0,0 +1,860 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! Parser for the full DOT language grammar, see `doc/lang.md`.
+//!
+//! Node IDs are unescaped, so `"Node \"1\""` becomes the node `Node "1"`.  Attribute values are
+//! kept in their DOT source form, so quoted strings keep their quotes and HTML-like labels keep
+//! their angle brackets, and [`Graph::write_to`] writes them back out unchanged.  Edge chains and
+//! edges to subgraphs are expanded into an [`Edge`] per pair of nodes, and edge ports become the
+//! `tailport` and `headport` attributes.
+
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::{AttrList, Attribute, Edge, Element, Graph, GraphType, Node, Subgraph};
+
+///
+/// A region of the source text
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct Span {
+    /// Byte offset of the start of the region
+    pub start: usize,
+    /// Byte offset just past the end of the region
+    pub end: usize,
+    /// 1-based line of the start of the region
+    pub line: usize,
+    /// 1-based column (in characters) of the start of the region
+    pub column: usize,
+}
+
+///
+/// An error encountered while parsing, and where in the source it occurred
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    pub span: Span,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}: {}",
+            self.span.line, self.span.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Graph {
+    ///
+    /// Parses a DOT graph.
+    pub fn parse(input: &str) -> Result<Graph, ParseError> {
+        let tokens = Lexer::new(input).tokenize()?;
+        Parser {
+            tokens,
+            pos: 0,
+            graph_type: GraphType::default(),
+            known_nodes: HashSet::new(),
+        }
+        .parse_graph()
+    }
+}
+
+impl FromStr for Graph {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Graph::parse(s)
+    }
+}
+
+impl Attribute {
+    ///
+    /// Returns the text of the value, without the quotes of a quoted string and with any
+    /// escaped quotes unescaped.  HTML-like values are returned as-is.
+    pub fn text(&self) -> String {
+        match self
+            .value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+        {
+            Some(inner) => inner.replace("\\\"", "\""),
+            None => self.value.clone(),
+        }
+    }
+}
+
+/// An ID, as its unescaped text and its DOT source form
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Id {
+    text: String,
+    raw: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Tok {
+    Id(Id),
+    Strict,
+    Graph,
+    Digraph,
+    Subgraph,
+    Node,
+    Edge,
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Semicolon,
+    Comma,
+    Equals,
+    Colon,
+    Plus,
+    EdgeOp(GraphType),
+    Eof,
+}
+
+impl Display for Tok {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tok::Id(id) => write!(f, "'{}'", id.raw),
+            Tok::Strict => f.write_str("'strict'"),
+            Tok::Graph => f.write_str("'graph'"),
+            Tok::Digraph => f.write_str("'digraph'"),
+            Tok::Subgraph => f.write_str("'subgraph'"),
+            Tok::Node => f.write_str("'node'"),
+            Tok::Edge => f.write_str("'edge'"),
+            Tok::LBrace => f.write_str("'{'"),
+            Tok::RBrace => f.write_str("'}'"),
+            Tok::LBracket => f.write_str("'['"),
+            Tok::RBracket => f.write_str("']'"),
+            Tok::Semicolon => f.write_str("';'"),
+            Tok::Comma => f.write_str("','"),
+            Tok::Equals => f.write_str("'='"),
+            Tok::Colon => f.write_str("':'"),
+            Tok::Plus => f.write_str("'+'"),
+            Tok::EdgeOp(t) => write!(f, "'{}'", t.get_arrow()),
+            Tok::Eof => f.write_str("end of input"),
+        }
+    }
+}
+
+struct Token {
+    tok: Tok,
+    span: Span,
+}
+
+struct Lexer<'a> {
+    src: &'a str,
+    pos: usize,
+    line: usize,
+    column: usize,
+    /// true until a non-whitespace character is seen on the current line
+    line_start: bool,
+}
+
+fn is_id_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || !c.is_ascii()
+}
+
+fn is_id_char(c: char) -> bool {
+    is_id_start(c) || c.is_ascii_digit()
+}
+
+impl<'a> Lexer<'a> {
+    fn new(src: &'a str) -> Self {
+        Lexer {
+            src,
+            pos: 0,
+            line: 1,
+            column: 1,
+            line_start: true,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.src.get(self.pos..)?.chars().next()
+    }
+
+    fn peek_second(&self) -> Option<char> {
+        self.src.get(self.pos..)?.chars().nth(1)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+            self.line_start = true;
+        } else {
+            self.column += 1;
+            if !c.is_whitespace() {
+                self.line_start = false;
+            }
+        }
+        Some(c)
+    }
+
+    fn span_from(&self, start: Span) -> Span {
+        Span {
+            end: self.pos,
+            ..start
+        }
+    }
+
+    fn here(&self) -> Span {
+        Span {
+            start: self.pos,
+            end: self.pos,
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    fn error(&self, start: Span, message: &str) -> ParseError {
+        ParseError {
+            message: message.to_string(),
+            span: self.span_from(start),
+        }
+    }
+
+    /// Skips whitespace, comments, and `#` preprocessor output lines
+    fn skip_trivia(&mut self) -> Result<(), ParseError> {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.bump();
+            } else if c == '#' && self.line_start {
+                while self.peek().is_some_and(|c| c != '\n') {
+                    self.bump();
+                }
+            } else if c == '/' && self.peek_second() == Some('/') {
+                while self.peek().is_some_and(|c| c != '\n') {
+                    self.bump();
+                }
+            } else if c == '/' && self.peek_second() == Some('*') {
+                let start = self.here();
+                self.bump();
+                self.bump();
+                loop {
+                    match self.bump() {
+                        Some('*') if self.peek() == Some('/') => {
+                            self.bump();
+                            break;
+                        }
+                        Some(_) => {}
+                        None => return Err(self.error(start, "Unterminated comment")),
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn tokenize(mut self) -> Result<Vec<Token>, ParseError> {
+        let mut out = Vec::new();
+        loop {
+            self.skip_trivia()?;
+            let start = self.here();
+            let Some(c) = self.peek() else {
+                out.push(Token {
+                    tok: Tok::Eof,
+                    span: start,
+                });
+                return Ok(out);
+            };
+            let tok = match c {
+                '{' | '}' | '[' | ']' | ';' | ',' | '=' | ':' | '+' => {
+                    self.bump();
+                    match c {
+                        '{' => Tok::LBrace,
+                        '}' => Tok::RBrace,
+                        '[' => Tok::LBracket,
+                        ']' => Tok::RBracket,
+                        ';' => Tok::Semicolon,
+                        ',' => Tok::Comma,
+                        '=' => Tok::Equals,
+                        ':' => Tok::Colon,
+                        _ => Tok::Plus,
+                    }
+                }
+                '-' if self.peek_second() == Some('>') => {
+                    self.bump();
+                    self.bump();
+                    Tok::EdgeOp(GraphType::Digraph)
+                }
+                '-' if self.peek_second() == Some('-') => {
+                    self.bump();
+                    self.bump();
+                    Tok::EdgeOp(GraphType::Graph)
+                }
+                '"' => self.quoted(start)?,
+                '<' => self.html(start)?,
+                '-' | '.' | '0'..='9' => self.numeral(start)?,
+                c if is_id_start(c) => {
+                    while self.peek().is_some_and(is_id_char) {
+                        self.bump();
+                    }
+                    let text = self.src.get(start.start..self.pos).unwrap_or_default();
+                    match text.to_ascii_lowercase().as_str() {
+                        "strict" => Tok::Strict,
+                        "graph" => Tok::Graph,
+                        "digraph" => Tok::Digraph,
+                        "subgraph" => Tok::Subgraph,
+                        "node" => Tok::Node,
+                        "edge" => Tok::Edge,
+                        _ => Tok::Id(Id {
+                            text: text.to_string(),
+                            raw: text.to_string(),
+                        }),
+                    }
+                }
+                _ => {
+                    self.bump();
+                    return Err(self.error(start, &format!("Unexpected character '{c}'")));
+                }
+            };
+            out.push(Token {
+                tok,
+                span: self.span_from(start),
+            });
+        }
+    }
+
+    fn numeral(&mut self, start: Span) -> Result<Tok, ParseError> {
+        if self.peek() == Some('-') {
+            self.bump();
+        }
+        let mut digits = 0;
+        let mut dot = false;
+        while let Some(c) = self.peek() {
+            if c.is_ascii_digit() {
+                digits += 1;
+            } else if c == '.' && !dot {
+                dot = true;
+            } else {
+                break;
+            }
+            self.bump();
+        }
+        if digits == 0 {
+            return Err(self.error(start, "Invalid numeral"));
+        }
+        let text = self.src.get(start.start..self.pos).unwrap_or_default();
+        Ok(Tok::Id(Id {
+            text: text.to_string(),
+            raw: text.to_string(),
+        }))
+    }
+
+    /// A double-quoted string, the only escape is `\"`, and a backslash-newline continues the line
+    fn quoted(&mut self, start: Span) -> Result<Tok, ParseError> {
+        self.bump();
+        let mut text = String::new();
+        let mut raw = String::from('"');
+        loop {
+            match self.bump() {
+                Some('"') => break,
+                Some('\\') => match self.peek() {
+                    Some('"') => {
+                        self.bump();
+                        text.push('"');
+                        raw.push_str("\\\"");
+                    }
+                    Some('\n') => {
+                        self.bump();
+                    }
+                    Some('\r') if self.peek_second() == Some('\n') => {
+                        self.bump();
+                        self.bump();
+                    }
+                    _ => {
+                        text.push('\\');
+                        raw.push('\\');
+                    }
+                },
+                Some(c) => {
+                    text.push(c);
+                    raw.push(c);
+                }
+                None => return Err(self.error(start, "Unterminated string")),
+            }
+        }
+        raw.push('"');
+        Ok(Tok::Id(Id { text, raw }))
+    }
+
+    /// An HTML-like string, delimited by balanced angle brackets
+    fn html(&mut self, start: Span) -> Result<Tok, ParseError> {
+        let mut depth = 0_usize;
+        loop {
+            match self.bump() {
+                Some('<') => depth += 1,
+                Some('>') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                Some(_) => {}
+                None => return Err(self.error(start, "Unterminated HTML string")),
+            }
+        }
+        let raw = self.src.get(start.start..self.pos).unwrap_or_default();
+        Ok(Tok::Id(Id {
+            text: raw.to_string(),
+            raw: raw.to_string(),
+        }))
+    }
+}
+
+/// One side of an edge
+enum Endpoint {
+    Node {
+        id: String,
+        port: Option<String>,
+    },
+    /// All the nodes within a subgraph
+    Subgraph(Vec<String>),
+}
+
+impl Endpoint {
+    fn nodes(&self) -> Vec<(&str, Option<&str>)> {
+        match self {
+            Endpoint::Node { id, port } => vec![(id.as_str(), port.as_deref())],
+            Endpoint::Subgraph(nodes) => nodes.iter().map(|n| (n.as_str(), None)).collect(),
+        }
+    }
+}
+
+/// Returns the DOT source form of the text, quoting it if required
+pub(crate) fn quote(text: &str) -> String {
+    let mut chars = text.chars();
+    let plain = chars.next().is_some_and(is_id_start) && chars.all(is_id_char);
+    if plain {
+        text.to_string()
+    } else {
+        format!("\"{}\"", text.replace('"', "\\\""))
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    graph_type: GraphType,
+    known_nodes: HashSet<String>,
+}
+
+impl Parser {
+    fn peek(&self) -> &Tok {
+        self.tokens.get(self.pos).map_or(&Tok::Eof, |t| &t.tok)
+    }
+
+    fn peek_second(&self) -> &Tok {
+        self.tokens.get(self.pos + 1).map_or(&Tok::Eof, |t| &t.tok)
+    }
+
+    fn span(&self) -> Span {
+        self.tokens
+            .get(self.pos)
+            .or_else(|| self.tokens.last())
+            .map(|t| t.span)
+            .unwrap_or_default()
+    }
+
+    fn next(&mut self) -> Tok {
+        let tok = self.peek().clone();
+        if self.pos < self.tokens.len() {
+            self.pos += 1;
+        }
+        tok
+    }
+
+    fn eat(&mut self, tok: &Tok) -> bool {
+        if self.peek() == tok {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+
+    fn unexpected(&self, expected: &str) -> ParseError {
+        ParseError {
+            message: format!("Expected {expected}, found {}", self.peek()),
+            span: self.span(),
+        }
+    }
+
+    fn expect(&mut self, tok: &Tok) -> Result<(), ParseError> {
+        if self.eat(tok) {
+            return Ok(());
+        }
+        Err(self.unexpected(&tok.to_string()))
+    }
+
+    /// An ID, including any `"a" + "b"` concatenation of quoted strings
+    fn id(&mut self) -> Result<Id, ParseError> {
+        let Tok::Id(mut id) = self.peek().clone() else {
+            return Err(self.unexpected("an ID"));
+        };
+        self.pos += 1;
+        while self.peek() == &Tok::Plus {
+            let (Some(raw), Tok::Id(next)) = (id.raw.strip_suffix('"'), self.peek_second()) else {
+                return Err(self.unexpected("a quoted string"));
+            };
+            let Some(next_raw) = next.raw.strip_prefix('"') else {
+                self.pos += 1;
+                return Err(self.unexpected("a quoted string"));
+            };
+            id = Id {
+                text: format!("{}{}", id.text, next.text),
+                raw: format!("{raw}{next_raw}"),
+            };
+            self.pos += 2;
+        }
+        Ok(id)
+    }
+
+    fn parse_graph(mut self) -> Result<Graph, ParseError> {
+        let is_strict = self.eat(&Tok::Strict);
+        self.graph_type = match self.next() {
+            Tok::Graph => GraphType::Graph,
+            Tok::Digraph => GraphType::Digraph,
+            _ => {
+                self.pos -= 1;
+                return Err(self.unexpected("'graph' or 'digraph'"));
+            }
+        };
+        let id = match self.peek() {
+            Tok::Id(_) => Some(self.id()?.text),
+            _ => None,
+        };
+        self.expect(&Tok::LBrace)?;
+        let mut elements = Vec::new();
+        self.stmt_list(&mut elements, &mut Vec::new())?;
+        if self.peek() != &Tok::Eof {
+            return Err(self.unexpected("end of input"));
+        }
+        Ok(Graph {
+            is_strict,
+            graph_type: self.graph_type,
+            id,
+            elements,
+            known_nodes: self.known_nodes,
+        })
+    }
+
+    /// Parses statements up to and including the closing brace.  The IDs of the nodes within
+    /// are added to `nodes`, for edges to subgraphs.
+    fn stmt_list(
+        &mut self,
+        out: &mut Vec<Element>,
+        nodes: &mut Vec<String>,
+    ) -> Result<(), ParseError> {
+        loop {
+            match self.peek() {
+                Tok::RBrace => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                Tok::Eof => return Err(self.unexpected("'}'")),
+                _ => self.stmt(out, nodes)?,
+            }
+            self.eat(&Tok::Semicolon);
+        }
+    }
+
+    fn stmt(&mut self, out: &mut Vec<Element>, nodes: &mut Vec<String>) -> Result<(), ParseError> {
+        match self.peek() {
+            Tok::Graph | Tok::Node | Tok::Edge => {
+                let kind = self.next();
+                for attr in self.attr_list()?.0 {
+                    out.push(match kind {
+                        Tok::Node => Element::NodeAttr(attr),
+                        Tok::Edge => Element::EdgeAttr(attr),
+                        _ => Element::Attribute(attr),
+                    });
+                }
+                Ok(())
+            }
+            Tok::Subgraph | Tok::LBrace => {
+                let first = self.subgraph(out, nodes)?;
+                if matches!(self.peek(), Tok::EdgeOp(_)) {
+                    self.edge_stmt(first, out, nodes)
+                } else {
+                    Ok(())
+                }
+            }
+            Tok::Id(_) if self.peek_second() == &Tok::Equals => {
+                let name = self.id()?;
+                self.pos += 1;
+                let value = self.id()?;
+                out.push(Element::Attribute(Attribute {
+                    name: name.text,
+                    value: value.raw,
+                }));
+                Ok(())
+            }
+            Tok::Id(_) => {
+                let (id, port) = self.node_id()?;
+                self.add_node(&id, nodes);
+                if matches!(self.peek(), Tok::EdgeOp(_)) {
+                    return self.edge_stmt(Endpoint::Node { id, port }, out, nodes);
+                }
+                let attributes = if self.peek() == &Tok::LBracket {
+                    self.attr_list()?
+                } else {
+                    AttrList::default()
+                };
+                out.push(Element::Node(Node { id, attributes }));
+                Ok(())
+            }
+            _ => Err(self.unexpected("a statement")),
+        }
+    }
+
+    fn add_node(&mut self, id: &str, nodes: &mut Vec<String>) {
+        if !nodes.iter().any(|n| n == id) {
+            nodes.push(id.to_string());
+        }
+        self.known_nodes.insert(id.to_string());
+    }
+
+    /// `ID [':' ID [':' ID]]`, returning the ID and the port
+    fn node_id(&mut self) -> Result<(String, Option<String>), ParseError> {
+        let id = self.id()?.text;
+        if !self.eat(&Tok::Colon) {
+            return Ok((id, None));
+        }
+        let mut port = self.id()?.text;
+        if self.eat(&Tok::Colon) {
+            port = format!("{port}:{}", self.id()?.text);
+        }
+        Ok((id, Some(port)))
+    }
+
+    /// `[subgraph [ID]] '{' stmt_list '}'`, the subgraph is added to `out`
+    fn subgraph(
+        &mut self,
+        out: &mut Vec<Element>,
+        nodes: &mut Vec<String>,
+    ) -> Result<Endpoint, ParseError> {
+        let mut id = None;
+        if self.eat(&Tok::Subgraph) && matches!(self.peek(), Tok::Id(_)) {
+            id = Some(self.id()?.text);
+        }
+        self.expect(&Tok::LBrace)?;
+        let mut elements = Vec::new();
+        let mut inner = Vec::new();
+        self.stmt_list(&mut elements, &mut inner)?;
+        for node in &inner {
+            self.add_node(node, nodes);
+        }
+        out.push(Element::Subgraph(Subgraph { id, elements }));
+        Ok(Endpoint::Subgraph(inner))
+    }
+
+    /// `edgeRHS [attr_list]`, following the first endpoint
+    fn edge_stmt(
+        &mut self,
+        first: Endpoint,
+        out: &mut Vec<Element>,
+        nodes: &mut Vec<String>,
+    ) -> Result<(), ParseError> {
+        let mut endpoints = vec![first];
+        while let Tok::EdgeOp(op) = *self.peek() {
+            if op != self.graph_type {
+                return Err(ParseError {
+                    message: format!(
+                        "Edge operator '{}' is not allowed in a {}",
+                        op.get_arrow(),
+                        self.graph_type.get_name()
+                    ),
+                    span: self.span(),
+                });
+            }
+            self.pos += 1;
+            let endpoint = match self.peek() {
+                Tok::Subgraph | Tok::LBrace => self.subgraph(out, nodes)?,
+                _ => {
+                    let (id, port) = self.node_id()?;
+                    self.add_node(&id, nodes);
+                    Endpoint::Node { id, port }
+                }
+            };
+            endpoints.push(endpoint);
+        }
+        let attributes = if self.peek() == &Tok::LBracket {
+            self.attr_list()?
+        } else {
+            AttrList::default()
+        };
+        for pair in endpoints.windows(2) {
+            let [tail, head] = pair else {
+                continue;
+            };
+            for (first, tail_port) in tail.nodes() {
+                for (second, head_port) in head.nodes() {
+                    let mut attributes = attributes.clone();
+                    for (name, port) in [("tailport", tail_port), ("headport", head_port)] {
+                        if let Some(port) = port {
+                            attributes.0.push(Attribute::new(name, &quote(port)));
+                        }
+                    }
+                    out.push(Element::Edge(Edge {
+                        edge_type: self.graph_type,
+                        first_node: first.to_string(),
+                        second_node: second.to_string(),
+                        attributes,
+                    }));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// `'[' [a_list] ']' [attr_list]`
+    fn attr_list(&mut self) -> Result<AttrList, ParseError> {
+        let mut out = Vec::new();
+        self.expect(&Tok::LBracket)?;
+        loop {
+            if self.eat(&Tok::RBracket) {
+                if self.eat(&Tok::LBracket) {
+                    continue;
+                }
+                return Ok(AttrList(out));
+            }
+            let name = self.id()?;
+            self.expect(&Tok::Equals)?;
+            let value = self.id()?;
+            out.push(Attribute {
+                name: name.text,
+                value: value.raw,
+            });
+            if !self.eat(&Tok::Semicolon) {
+                self.eat(&Tok::Comma);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::ParseError;
+    use crate::{Element, Graph, GraphType};
+
+    const SAMPLE: &str = r#"/* build graph */
+strict digraph "deps" {
+    # preprocessor line
+    graph [rankdir=LR, label=<<b>Deps</b>>]
+    node [shape=box]; edge [color="red"]
+    fontsize = 12
+    a [label="A \"quoted\"" tooltip="multi" + "part"] // the root
+    a -> b -> {c; d} [weight=2]
+    subgraph cluster_0 {
+        label = "Cluster"
+        e:p1:n -> f
+    }
+    "long \
+name" -> a
+}"#;
+
+    fn edges(elements: &[Element]) -> Vec<(String, String)> {
+        elements
+            .iter()
+            .filter_map(|e| match e {
+                Element::Edge(e) => Some((e.first_node.clone(), e.second_node.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_parse() -> Result<(), ParseError> {
+        let graph = Graph::parse(SAMPLE)?;
+        assert!(graph.is_strict);
+        assert_eq!(GraphType::Digraph, graph.graph_type);
+        assert_eq!(Some("deps"), graph.id.as_deref());
+
+        let label = graph.elements.iter().find_map(|e| match e {
+            Element::Attribute(a) if a.name == "label" => Some(a.value.as_str()),
+            _ => None,
+        });
+        assert_eq!(Some("<<b>Deps</b>>"), label);
+
+        let node = graph.elements.iter().find_map(|e| match e {
+            Element::Node(n) => Some(n),
+            _ => None,
+        });
+        let attrs = node.map(|n| n.attributes.0.clone()).unwrap_or_default();
+        let texts: Vec<_> = attrs.iter().map(|a| (a.name.as_str(), a.text())).collect();
+        assert_eq!(
+            vec![
+                ("label", "A \"quoted\"".to_string()),
+                ("tooltip", "multipart".to_string())
+            ],
+            texts
+        );
+
+        let pairs = |v: &[(&str, &str)]| -> Vec<(String, String)> {
+            v.iter()
+                .map(|(a, b)| ((*a).to_string(), (*b).to_string()))
+                .collect()
+        };
+        assert_eq!(
+            pairs(&[("a", "b"), ("b", "c"), ("b", "d"), ("long name", "a")]),
+            edges(&graph.elements)
+        );
+        let cluster = graph.elements.iter().find_map(|e| match e {
+            Element::Subgraph(s) if s.id.as_deref() == Some("cluster_0") => Some(s),
+            _ => None,
+        });
+        let cluster_edges = cluster.map(|s| edges(&s.elements)).unwrap_or_default();
+        assert_eq!(pairs(&[("e", "f")]), cluster_edges);
+        let ports = cluster.and_then(|s| {
+            s.elements.iter().find_map(|e| match e {
+                Element::Edge(e) => Some(e.attributes.0.clone()),
+                _ => None,
+            })
+        });
+        assert_eq!(
+            Some("\"p1:n\"".to_string()),
+            ports.and_then(|p| p.first().map(|a| a.value.clone()))
+        );
+        for node in ["a", "b", "c", "d", "e", "f", "long name"] {
+            assert!(graph.known_nodes.contains(node), "{node}");
+        }
+
+        let mut out = String::new();
+        graph.write_to(&mut out).map_err(|e| ParseError {
+            message: e.to_string(),
+            span: Default::default(),
+        })?;
+        let reparsed = Graph::parse(&out)?;
+        assert_eq!(graph.elements, reparsed.elements);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_errors() {
+        let err = |src: &str| {
+            Graph::parse(src)
+                .err()
+                .map(|e| (e.span.line, e.span.column))
+        };
+        assert_eq!(Some((1, 11)), err("graph { a -> b }"));
+        assert_eq!(Some((2, 8)), err("digraph {\n  a -> [x=1] }"));
+        assert_eq!(Some((1, 11)), err("digraph { \"open }"));
+        assert_eq!(Some((1, 1)), err("node { }"));
+        assert_eq!(Some((1, 13)), err("digraph { a "));
+        assert!(Graph::parse("digraph { a } b").is_err());
+    }
+}
@@ -0,0 +1,297 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use irox_carto::altitude::{Altitude, AltitudeReferenceFrame};
+use irox_carto::coordinate::EllipticalCoordinate;
+use irox_geometry::{LineSegment, Point, Polygon, Rectangle};
+use irox_types::DynamicallySizedValue;
+use irox_units::units::length::Length;
+
+use crate::error::{Error, ErrorType};
+use crate::{Feature, Geometry, Position};
+
+impl From<Point<f64>> for Position {
+    /// `x` is the longitude, `y` the latitude and `z` the altitude
+    fn from(value: Point<f64>) -> Self {
+        Position {
+            longitude: value.x,
+            latitude: value.y,
+            altitude: value.z,
+        }
+    }
+}
+
+impl From<Position> for Point<f64> {
+    fn from(value: Position) -> Self {
+        Point {
+            x: value.longitude,
+            y: value.latitude,
+            z: value.altitude,
+            m: None,
+        }
+    }
+}
+
+impl From<&EllipticalCoordinate> for Position {
+    fn from(value: &EllipticalCoordinate) -> Self {
+        Position {
+            longitude: value.get_longitude().0.as_degrees().value(),
+            latitude: value.get_latitude().0.as_degrees().value(),
+            altitude: value.get_altitude().map(|a| a.value().as_meters().value()),
+        }
+    }
+}
+
+impl From<&Position> for EllipticalCoordinate {
+    /// The coordinate is WGS84, with any altitude relative to the ellipsoid.
+    fn from(value: &Position) -> Self {
+        let coord = EllipticalCoordinate::new_degrees_wgs84(value.latitude, value.longitude);
+        match value.altitude {
+            Some(alt) => coord.with_altitude(Altitude::new(
+                Length::new_meters(alt),
+                AltitudeReferenceFrame::Ellipsoid,
+            )),
+            None => coord,
+        }
+    }
+}
+
+impl From<Point<f64>> for Geometry {
+    fn from(value: Point<f64>) -> Self {
+        Geometry::Point(value.into())
+    }
+}
+
+impl From<&LineSegment<f64>> for Geometry {
+    fn from(value: &LineSegment<f64>) -> Self {
+        Geometry::LineString(vec![value.start.into(), value.end.into()])
+    }
+}
+
+impl From<&Polygon<f64>> for Geometry {
+    /// The polygon becomes the exterior ring, closed back to the first point.
+    fn from(value: &Polygon<f64>) -> Self {
+        let mut ring: Vec<Position> = value.iter_points().map(|p| (*p).into()).collect();
+        if let Some(first) = ring.first().copied() {
+            if ring.last() != Some(&first) {
+                ring.push(first);
+            }
+        }
+        Geometry::Polygon(vec![ring])
+    }
+}
+
+impl From<&Rectangle<f64>> for Geometry {
+    fn from(value: &Rectangle<f64>) -> Self {
+        Geometry::from(&value.to_polygon())
+    }
+}
+
+impl From<&[EllipticalCoordinate]> for Geometry {
+    /// The coordinates become a `LineString`, in order.
+    fn from(value: &[EllipticalCoordinate]) -> Self {
+        Geometry::LineString(value.iter().map(Position::from).collect())
+    }
+}
+
+impl TryFrom<&Geometry> for Point<f64> {
+    type Error = Error;
+
+    fn try_from(value: &Geometry) -> Result<Self, Self::Error> {
+        let Geometry::Point(p) = value else {
+            return Err(not_a("Point"));
+        };
+        Ok((*p).into())
+    }
+}
+
+impl TryFrom<&Geometry> for Polygon<f64> {
+    type Error = Error;
+
+    /// Converts the exterior ring of a `Polygon`, interior rings are dropped.
+    fn try_from(value: &Geometry) -> Result<Self, Self::Error> {
+        let Geometry::Polygon(rings) = value else {
+            return Err(not_a("Polygon"));
+        };
+        let mut out = Polygon::empty();
+        let ring = rings.first().map(Vec::as_slice).unwrap_or_default();
+        // the closing position duplicates the first point.
+        let open = match ring {
+            [rest @ .., last] if ring.first() == Some(last) => rest,
+            _ => ring,
+        };
+        for p in open {
+            out.add_point((*p).into());
+        }
+        Ok(out)
+    }
+}
+
+fn not_a(kind: &str) -> Error {
+    Error::new(ErrorType::ParseError, format!("Geometry is not a {kind}"))
+}
+
+impl Geometry {
+    ///
+    /// Returns every position within the geometry as a WGS84 coordinate, in order.
+    #[must_use]
+    pub fn to_coordinates(&self) -> Vec<EllipticalCoordinate> {
+        let mut out = Vec::new();
+        self.for_each_position(&mut |p| out.push(EllipticalCoordinate::from(p)));
+        out
+    }
+}
+
+impl Feature {
+    ///
+    /// Creates a `LineString` feature from a track of coordinates.  A track crossing the
+    /// antimeridian is split at +/-180 into a `MultiLineString`, per RFC 7946 3.1.9.  If the
+    /// coordinates are timestamped, the first & last times are added as the `start_time` and
+    /// `end_time` properties.
+    #[must_use]
+    pub fn from_track(track: &[EllipticalCoordinate]) -> Feature {
+        let mut feature = Feature::new(split_at_antimeridian(track.iter().map(Position::from)));
+        feature.bbox = feature.geometry.as_ref().and_then(Geometry::bbox);
+        let mut times = track.iter().filter_map(|c| *c.get_timestamp());
+        if let Some(start) = times.next() {
+            let end = times.next_back().unwrap_or(start);
+            for (name, time) in [("start_time", start), ("end_time", end)] {
+                feature.properties.insert(
+                    name.to_string(),
+                    DynamicallySizedValue::str(time.format_iso8601_extended()).into(),
+                );
+            }
+        }
+        feature
+    }
+}
+
+/// Builds a `LineString` from the positions, or a `MultiLineString` if any edge crosses the
+/// antimeridian, with each crossing edge ending and restarting at the interpolated +/-180.
+fn split_at_antimeridian<T: Iterator<Item = Position>>(positions: T) -> Geometry {
+    let mut lines: Vec<Vec<Position>> = Vec::new();
+    let mut line: Vec<Position> = Vec::new();
+    for b in positions {
+        if let Some(a) = line.last().copied() {
+            if (b.longitude - a.longitude).abs() > 180.0 {
+                let side = a.longitude.signum() * 180.0;
+                let b_lon = b.longitude + 2. * side;
+                let t = (side - a.longitude) / (b_lon - a.longitude);
+                let latitude = a.latitude + t * (b.latitude - a.latitude);
+                let altitude = a.altitude.zip(b.altitude).map(|(a, b)| a + t * (b - a));
+                line.push(Position {
+                    longitude: side,
+                    latitude,
+                    altitude,
+                });
+                lines.push(core::mem::take(&mut line));
+                line.push(Position {
+                    longitude: -side,
+                    latitude,
+                    altitude,
+                });
+            }
+        }
+        line.push(b);
+    }
+    if lines.is_empty() {
+        return Geometry::LineString(line);
+    }
+    lines.push(line);
+    Geometry::MultiLineString(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use irox_carto::coordinate::EllipticalCoordinate;
+    use irox_geometry::{LineSegment, Point, Point2D, Polygon, Rectangle, Vector};
+    use irox_time::datetime::UTCDateTime;
+
+    use crate::error::Error;
+    use crate::{Feature, GeoJson, Geometry, Position};
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_geometry() -> Result<(), Error> {
+        let rect = Rectangle {
+            min: Point {
+                x: -71.0,
+                y: 42.0,
+                ..Default::default()
+            },
+            size: Vector { vx: 1.0, vy: 0.5 },
+        };
+        let fence = Geometry::from(&rect);
+        fence.validate()?;
+        let Geometry::Polygon(rings) = &fence else {
+            return Err(super::not_a("Polygon"));
+        };
+        assert_eq!(Some(5), rings.first().map(Vec::len));
+
+        let polygon = Polygon::try_from(&fence)?;
+        assert_eq!(4, polygon.iter_points().count());
+        assert_eq!(fence, Geometry::from(&polygon));
+        assert!(Point::try_from(&fence).is_err());
+
+        let segment = LineSegment {
+            start: Point::<f64>::new_point(1.0, 2.0),
+            end: Point::<f64>::new_point(3.0, 4.0),
+        };
+        let Geometry::LineString(line) = Geometry::from(&segment) else {
+            return Err(super::not_a("LineString"));
+        };
+        assert_eq!(vec![Position::new(1.0, 2.0), Position::new(3.0, 4.0)], line);
+        Ok(())
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_track() -> Result<(), Error> {
+        let start = UTCDateTime::try_from_iso8601("2023-06-01T12:00:00Z")
+            .map_err(|e| Error::new(crate::error::ErrorType::ParseError, e.to_string()))?;
+        let track = [
+            EllipticalCoordinate::new_degrees_wgs84(42.0, 179.5).with_timestamp(start),
+            EllipticalCoordinate::new_degrees_wgs84(42.5, -179.5),
+        ];
+        let feature = Feature::from_track(&track);
+        let bbox = feature.bbox.unwrap_or_default();
+        assert!(bbox.crosses_antimeridian());
+        assert!(bbox.contains(&Position::new(180.0, 42.2)));
+        assert!(!bbox.contains(&Position::new(0.0, 42.2)));
+        assert!(feature.properties.contains_key("start_time"));
+        // the single edge crosses the antimeridian, and is split.
+        feature.validate()?;
+        let Some(Geometry::MultiLineString(lines)) = &feature.geometry else {
+            return Err(super::not_a("MultiLineString"));
+        };
+        assert_eq!(
+            vec![
+                vec![Position::new(179.5, 42.0), Position::new(180.0, 42.25)],
+                vec![Position::new(-180.0, 42.25), Position::new(-179.5, 42.5)],
+            ],
+            *lines
+        );
+
+        let read: GeoJson = GeoJson::Feature(feature).to_string().parse()?;
+        let GeoJson::Feature(feature) = read else {
+            return Err(super::not_a("Feature"));
+        };
+        let coords = feature
+            .geometry
+            .as_ref()
+            .map(Geometry::to_coordinates)
+            .unwrap_or_default();
+        assert_eq!(4, coords.len());
+        let lons: Vec<f64> = coords.iter().map(|c| Position::from(c).longitude).collect();
+        assert_eq!(vec![179.5, 180.0, -180.0, -179.5], lons);
+
+        let track = [
+            EllipticalCoordinate::new_degrees_wgs84(42.0, -71.0),
+            EllipticalCoordinate::new_degrees_wgs84(42.5, -70.0),
+        ];
+        let feature = Feature::from_track(&track);
+        assert!(matches!(feature.geometry, Some(Geometry::LineString(_))));
+        Ok(())
+    }
+}
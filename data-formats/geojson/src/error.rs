@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ErrorType {
+    IOError,
+    /// The document isn't well-formed JSON
+    JsonError,
+    /// The document is well-formed JSON, but isn't a GeoJSON object
+    ParseError,
+    /// The GeoJSON object violates a constraint of RFC 7946
+    ValidationError,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Error {
+    error_type: ErrorType,
+    msg: String,
+    path: Option<String>,
+}
+
+impl Error {
+    pub fn new(error_type: ErrorType, msg: String) -> Error {
+        Error {
+            error_type,
+            msg,
+            path: None,
+        }
+    }
+
+    /// An error of the specified type at the path of a member within the document, like
+    /// `features[2].geometry.coordinates`
+    pub fn at(error_type: ErrorType, path: &str, msg: String) -> Error {
+        Error {
+            error_type,
+            msg,
+            path: Some(path.to_string()),
+        }
+    }
+
+    pub fn error_type(&self) -> ErrorType {
+        self.error_type
+    }
+
+    /// The path of the member within the document where the error occurred, if known
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.path {
+            Some(path) if !path.is_empty() => f.write_fmt(format_args!(
+                "GeoJSONError({:?}) at {path}: {}",
+                self.error_type, self.msg
+            )),
+            _ => f.write_fmt(format_args!(
+                "GeoJSONError({:?}): {}",
+                self.error_type, self.msg
+            )),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Error::new(ErrorType::IOError, value.to_string())
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(value: serde_json::Error) -> Self {
+        if value.is_io() {
+            return Error::new(ErrorType::IOError, value.to_string());
+        }
+        Error::new(ErrorType::JsonError, value.to_string())
+    }
+}
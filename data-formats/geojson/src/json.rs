@@ -0,0 +1,548 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+use std::io::{Read, Write};
+use std::str::FromStr;
+
+use irox_types::{DynamicallySizedValue, PrimitiveValue, VariableValue};
+use serde_json::{Map, Number, Value};
+
+use crate::error::{Error, ErrorType};
+use crate::{
+    index, join, BoundingBox, Feature, FeatureCollection, GeoJson, Geometry, Position, Properties,
+};
+
+impl GeoJson {
+    /// Reads a GeoJSON document from the provided input.
+    pub fn read_from<T: Read>(input: T) -> Result<GeoJson, Error> {
+        let value: Value = serde_json::from_reader(input)?;
+        GeoJson::from_value(&value)
+    }
+
+    /// Converts an already parsed JSON value.
+    pub fn from_value(value: &Value) -> Result<GeoJson, Error> {
+        let object = as_object("", value)?;
+        Ok(match type_of("", object)? {
+            "Feature" => GeoJson::Feature(read_feature("", object)?),
+            "FeatureCollection" => {
+                let features = member("", object, "features")?;
+                let features = as_array("features", features)?
+                    .iter()
+                    .enumerate()
+                    .map(|(i, f)| {
+                        let path = index("features", i);
+                        read_feature(&path, as_object(&path, f)?)
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                GeoJson::FeatureCollection(FeatureCollection {
+                    bbox: read_bbox("", object)?,
+                    features,
+                })
+            }
+            _ => GeoJson::Geometry(read_geometry("", object)?),
+        })
+    }
+
+    /// Writes the document as compact JSON.
+    pub fn write_to<T: Write>(&self, out: &mut T) -> Result<(), Error> {
+        serde_json::to_writer(out, &self.to_value())?;
+        Ok(())
+    }
+
+    /// Converts the document into a JSON value.
+    #[must_use]
+    pub fn to_value(&self) -> Value {
+        match self {
+            GeoJson::Geometry(g) => write_geometry(g),
+            GeoJson::Feature(f) => write_feature(f),
+            GeoJson::FeatureCollection(fc) => {
+                let mut object = Map::new();
+                object.insert("type".into(), "FeatureCollection".into());
+                write_bbox(&mut object, fc.bbox.as_ref());
+                let features = fc.features.iter().map(write_feature).collect();
+                object.insert("features".into(), Value::Array(features));
+                Value::Object(object)
+            }
+        }
+    }
+}
+
+impl FromStr for GeoJson {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        GeoJson::read_from(s.as_bytes())
+    }
+}
+
+impl Display for GeoJson {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_value())
+    }
+}
+
+fn parse_error(path: &str, msg: String) -> Error {
+    Error::at(ErrorType::ParseError, path, msg)
+}
+
+fn as_object<'a>(path: &str, value: &'a Value) -> Result<&'a Map<String, Value>, Error> {
+    value
+        .as_object()
+        .ok_or_else(|| parse_error(path, "Expected an object".to_string()))
+}
+
+fn as_array<'a>(path: &str, value: &'a Value) -> Result<&'a Vec<Value>, Error> {
+    value
+        .as_array()
+        .ok_or_else(|| parse_error(path, "Expected an array".to_string()))
+}
+
+fn member<'a>(path: &str, object: &'a Map<String, Value>, name: &str) -> Result<&'a Value, Error> {
+    object
+        .get(name)
+        .ok_or_else(|| parse_error(path, format!("Missing member '{name}'")))
+}
+
+fn type_of<'a>(path: &str, object: &'a Map<String, Value>) -> Result<&'a str, Error> {
+    member(path, object, "type")?
+        .as_str()
+        .ok_or_else(|| parse_error(&join(path, "type"), "Expected a string".to_string()))
+}
+
+fn read_feature(path: &str, object: &Map<String, Value>) -> Result<Feature, Error> {
+    let kind = type_of(path, object)?;
+    if kind != "Feature" {
+        return Err(parse_error(
+            &join(path, "type"),
+            format!("Expected a Feature, found '{kind}'"),
+        ));
+    }
+    let geom_path = join(path, "geometry");
+    let geometry = match member(path, object, "geometry")? {
+        Value::Null => None,
+        value => Some(read_geometry(&geom_path, as_object(&geom_path, value)?)?),
+    };
+    let mut properties = Properties::new();
+    let mut json_properties = BTreeSet::new();
+    match object.get("properties") {
+        None | Some(Value::Null) => {}
+        Some(value) => {
+            for (k, v) in as_object(&join(path, "properties"), value)? {
+                if v.is_array() || v.is_object() {
+                    json_properties.insert(k.clone());
+                }
+                properties.insert(k.clone(), to_variable(v));
+            }
+        }
+    }
+    let id = match object.get("id") {
+        None | Some(Value::Null) => None,
+        Some(value @ (Value::String(_) | Value::Number(_))) => Some(to_variable(value)),
+        Some(_) => {
+            return Err(parse_error(
+                &join(path, "id"),
+                "Expected a string or a number".to_string(),
+            ))
+        }
+    };
+    Ok(Feature {
+        id,
+        bbox: read_bbox(path, object)?,
+        geometry,
+        properties,
+        json_properties,
+    })
+}
+
+fn read_geometry(path: &str, object: &Map<String, Value>) -> Result<Geometry, Error> {
+    let kind = type_of(path, object)?;
+    if kind == "GeometryCollection" {
+        let geom_path = join(path, "geometries");
+        let geometries = as_array(&geom_path, member(path, object, "geometries")?)?
+            .iter()
+            .enumerate()
+            .map(|(i, g)| {
+                let path = index(&geom_path, i);
+                read_geometry(&path, as_object(&path, g)?)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        return Ok(Geometry::GeometryCollection(geometries));
+    }
+    let coords = member(path, object, "coordinates")?;
+    let path = join(path, "coordinates");
+    Ok(match kind {
+        "Point" => Geometry::Point(read_position(&path, coords)?),
+        "MultiPoint" => Geometry::MultiPoint(read_positions(&path, coords)?),
+        "LineString" => Geometry::LineString(read_positions(&path, coords)?),
+        "MultiLineString" => Geometry::MultiLineString(read_lines(&path, coords)?),
+        "Polygon" => Geometry::Polygon(read_lines(&path, coords)?),
+        "MultiPolygon" => Geometry::MultiPolygon(
+            as_array(&path, coords)?
+                .iter()
+                .enumerate()
+                .map(|(i, v)| read_lines(&index(&path, i), v))
+                .collect::<Result<_, _>>()?,
+        ),
+        _ => {
+            return Err(parse_error(
+                &path,
+                format!("Unknown geometry type '{kind}'"),
+            ))
+        }
+    })
+}
+
+fn read_position(path: &str, value: &Value) -> Result<Position, Error> {
+    let numbers = as_array(path, value)?
+        .iter()
+        .map(Value::as_f64)
+        .collect::<Option<Vec<f64>>>()
+        .ok_or_else(|| parse_error(path, "Position must only contain numbers".to_string()))?;
+    let (longitude, latitude, altitude) = match numbers.as_slice() {
+        [lon, lat] => (*lon, *lat, None),
+        [lon, lat, alt, ..] => (*lon, *lat, Some(*alt)),
+        _ => {
+            return Err(parse_error(
+                path,
+                "Position must have at least two elements".to_string(),
+            ))
+        }
+    };
+    Ok(Position {
+        longitude,
+        latitude,
+        altitude,
+    })
+}
+
+fn read_positions(path: &str, value: &Value) -> Result<Vec<Position>, Error> {
+    as_array(path, value)?
+        .iter()
+        .enumerate()
+        .map(|(i, v)| read_position(&index(path, i), v))
+        .collect()
+}
+
+fn read_lines(path: &str, value: &Value) -> Result<Vec<Vec<Position>>, Error> {
+    as_array(path, value)?
+        .iter()
+        .enumerate()
+        .map(|(i, v)| read_positions(&index(path, i), v))
+        .collect()
+}
+
+fn read_bbox(path: &str, object: &Map<String, Value>) -> Result<Option<BoundingBox>, Error> {
+    let Some(value) = object.get("bbox") else {
+        return Ok(None);
+    };
+    let path = join(path, "bbox");
+    let numbers = as_array(&path, value)?
+        .iter()
+        .map(Value::as_f64)
+        .collect::<Option<Vec<f64>>>()
+        .ok_or_else(|| parse_error(&path, "Bounding box must only contain numbers".to_string()))?;
+    Ok(Some(match numbers.as_slice() {
+        [west, south, east, north] => BoundingBox {
+            west: *west,
+            south: *south,
+            east: *east,
+            north: *north,
+            altitude: None,
+        },
+        [west, south, min_alt, east, north, max_alt] => BoundingBox {
+            west: *west,
+            south: *south,
+            east: *east,
+            north: *north,
+            altitude: Some((*min_alt, *max_alt)),
+        },
+        _ => {
+            return Err(parse_error(
+                &path,
+                "Bounding box must have four or six elements".to_string(),
+            ))
+        }
+    }))
+}
+
+///
+/// Converts a JSON value into a property value.  Arrays & objects are kept as their JSON text.
+fn to_variable(value: &Value) -> VariableValue {
+    match value {
+        Value::Null => PrimitiveValue::null.into(),
+        Value::Bool(b) => PrimitiveValue::bool(*b).into(),
+        Value::Number(n) => {
+            if let Some(v) = n.as_i64() {
+                PrimitiveValue::i64(v).into()
+            } else if let Some(v) = n.as_u64() {
+                PrimitiveValue::u64(v).into()
+            } else {
+                PrimitiveValue::f64(n.as_f64().unwrap_or(f64::NAN)).into()
+            }
+        }
+        Value::String(s) => DynamicallySizedValue::str(s.clone()).into(),
+        Value::Array(_) | Value::Object(_) => DynamicallySizedValue::str(value.to_string()).into(),
+    }
+}
+
+///
+/// Converts a property value into JSON.  Non-finite floats become `null`, and 128-bit integers
+/// that don't fit in 64 bits become strings.
+fn from_variable(value: &VariableValue) -> Value {
+    let VariableValue::Primitive(value) = value else {
+        return Value::String(value.to_string());
+    };
+    match value {
+        PrimitiveValue::u8(v) => (*v).into(),
+        PrimitiveValue::i8(v) => (*v).into(),
+        PrimitiveValue::u16(v) => (*v).into(),
+        PrimitiveValue::i16(v) => (*v).into(),
+        PrimitiveValue::u32(v) => (*v).into(),
+        PrimitiveValue::i32(v) => (*v).into(),
+        PrimitiveValue::u64(v) => (*v).into(),
+        PrimitiveValue::i64(v) => (*v).into(),
+        PrimitiveValue::u128(v) => {
+            u64::try_from(*v).map_or_else(|_| v.to_string().into(), Into::into)
+        }
+        PrimitiveValue::i128(v) => {
+            i64::try_from(*v).map_or_else(|_| v.to_string().into(), Into::into)
+        }
+        PrimitiveValue::f32(v) => number(f64::from(*v)),
+        PrimitiveValue::f64(v) => number(*v),
+        PrimitiveValue::bool(v) => (*v).into(),
+        PrimitiveValue::char(v) => v.to_string().into(),
+        PrimitiveValue::null => Value::Null,
+        _ => Value::String(value.to_string()),
+    }
+}
+
+fn number(value: f64) -> Value {
+    Number::from_f64(value).map_or(Value::Null, Value::Number)
+}
+
+fn write_position(p: &Position) -> Value {
+    let mut out = vec![number(p.longitude), number(p.latitude)];
+    if let Some(alt) = p.altitude {
+        out.push(number(alt));
+    }
+    Value::Array(out)
+}
+
+fn write_positions(line: &[Position]) -> Value {
+    Value::Array(line.iter().map(write_position).collect())
+}
+
+fn write_lines(lines: &[Vec<Position>]) -> Value {
+    Value::Array(lines.iter().map(|l| write_positions(l)).collect())
+}
+
+fn write_bbox(object: &mut Map<String, Value>, bbox: Option<&BoundingBox>) {
+    let Some(b) = bbox else {
+        return;
+    };
+    let values = match b.altitude {
+        Some((min, max)) => vec![b.west, b.south, min, b.east, b.north, max],
+        None => vec![b.west, b.south, b.east, b.north],
+    };
+    object.insert(
+        "bbox".into(),
+        Value::Array(values.into_iter().map(number).collect()),
+    );
+}
+
+fn write_geometry(geometry: &Geometry) -> Value {
+    let (kind, member, value) = match geometry {
+        Geometry::Point(p) => ("Point", "coordinates", write_position(p)),
+        Geometry::MultiPoint(points) => ("MultiPoint", "coordinates", write_positions(points)),
+        Geometry::LineString(line) => ("LineString", "coordinates", write_positions(line)),
+        Geometry::MultiLineString(lines) => ("MultiLineString", "coordinates", write_lines(lines)),
+        Geometry::Polygon(rings) => ("Polygon", "coordinates", write_lines(rings)),
+        Geometry::MultiPolygon(polygons) => (
+            "MultiPolygon",
+            "coordinates",
+            Value::Array(polygons.iter().map(|p| write_lines(p)).collect()),
+        ),
+        Geometry::GeometryCollection(geometries) => (
+            "GeometryCollection",
+            "geometries",
+            Value::Array(geometries.iter().map(write_geometry).collect()),
+        ),
+    };
+    let mut object = Map::new();
+    object.insert("type".into(), kind.into());
+    object.insert(member.into(), value);
+    Value::Object(object)
+}
+
+fn write_feature(feature: &Feature) -> Value {
+    let mut object = Map::new();
+    object.insert("type".into(), "Feature".into());
+    if let Some(id) = &feature.id {
+        object.insert("id".into(), from_variable(id));
+    }
+    write_bbox(&mut object, feature.bbox.as_ref());
+    object.insert(
+        "geometry".into(),
+        feature
+            .geometry
+            .as_ref()
+            .map_or(Value::Null, write_geometry),
+    );
+    let properties = feature
+        .properties
+        .iter()
+        .map(|(k, v)| {
+            let json = match v {
+                VariableValue::DynamicallySized(DynamicallySizedValue::str(s))
+                    if feature.json_properties.contains(k) =>
+                {
+                    serde_json::from_str(s).ok()
+                }
+                _ => None,
+            };
+            (k.clone(), json.unwrap_or_else(|| from_variable(v)))
+        })
+        .collect();
+    object.insert("properties".into(), Value::Object(properties));
+    Value::Object(object)
+}
+
+#[cfg(test)]
+mod tests {
+    use irox_types::{DynamicallySizedValue, PrimitiveValue, VariableValue};
+
+    use crate::error::{Error, ErrorType};
+    use crate::{GeoJson, Geometry, Position};
+
+    const SAMPLE: &str = r#"{
+        "type": "FeatureCollection",
+        "bbox": [170, -10, -170, 10],
+        "features": [
+            {
+                "type": "Feature",
+                "id": 7,
+                "geometry": {"type": "Point", "coordinates": [175.5, 0.5, 12.25]},
+                "properties": {"name": "A", "count": -3, "ratio": 0.5, "ok": true,
+                    "none": null, "tags": ["x", "y"]}
+            },
+            {
+                "type": "Feature",
+                "id": "fence",
+                "geometry": {"type": "Polygon", "coordinates": [
+                    [[171, -5], [179, -5], [179, 5], [171, 5], [171, -5]]
+                ]},
+                "properties": null
+            },
+            {
+                "type": "Feature",
+                "geometry": {"type": "GeometryCollection", "geometries": [
+                    {"type": "MultiPoint", "coordinates": [[-175, 1], [-172, 2]]},
+                    {"type": "MultiLineString", "coordinates": [[[-179, 0], [-175, 1]]]},
+                    {"type": "MultiPolygon", "coordinates": [
+                        [[[-179, -1], [-178, -1], [-178, 1], [-179, -1]]]
+                    ]}
+                ]},
+                "properties": {}
+            },
+            {"type": "Feature", "geometry": null, "properties": {}}
+        ]
+    }"#;
+
+    fn missing() -> Error {
+        Error::new(ErrorType::ParseError, "missing".to_string())
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_read() -> Result<(), Error> {
+        let doc: GeoJson = SAMPLE.parse()?;
+        doc.validate()?;
+        let GeoJson::FeatureCollection(fc) = &doc else {
+            return Err(missing());
+        };
+        assert!(fc.bbox.is_some_and(|b| b.crosses_antimeridian()));
+        let [point, fence, collection, empty] = fc.features.as_slice() else {
+            return Err(missing());
+        };
+        assert_eq!(Some(PrimitiveValue::i64(7).into()), point.id);
+        assert_eq!(
+            Some(Geometry::Point(
+                Position::new(175.5, 0.5).with_altitude(12.25)
+            )),
+            point.geometry
+        );
+        let props = &point.properties;
+        assert_eq!(
+            Some(&VariableValue::from(DynamicallySizedValue::str(
+                "A".to_string()
+            ))),
+            props.get("name")
+        );
+        assert_eq!(Some(&PrimitiveValue::i64(-3).into()), props.get("count"));
+        assert_eq!(Some(&PrimitiveValue::f64(0.5).into()), props.get("ratio"));
+        assert_eq!(Some(&PrimitiveValue::bool(true).into()), props.get("ok"));
+        assert_eq!(Some(&PrimitiveValue::null.into()), props.get("none"));
+        assert_eq!(
+            Some(&DynamicallySizedValue::str(r#"["x","y"]"#.to_string()).into()),
+            props.get("tags")
+        );
+        assert!(point.json_properties.contains("tags"));
+        assert!(fence.properties.is_empty());
+        assert!(matches!(
+            collection.geometry,
+            Some(Geometry::GeometryCollection(ref g)) if g.len() == 3
+        ));
+        assert!(empty.geometry.is_none());
+
+        let written = doc.to_string();
+        assert_eq!(doc, written.parse()?);
+        // nested values are written back as JSON, not as strings of JSON.
+        let json: serde_json::Value = serde_json::from_str(&written)?;
+        assert_eq!(
+            Some(&serde_json::json!(["x", "y"])),
+            json.pointer("/features/0/properties/tags")
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_errors() {
+        let err = |doc: &str| doc.parse::<GeoJson>().err();
+        assert_eq!(
+            Some(ErrorType::JsonError),
+            err("{\"type\": ").map(|e| e.error_type())
+        );
+        let bad = err(r#"{"type": "LineString", "coordinates": [[1, 2], [3]]}"#);
+        assert_eq!(
+            Some(ErrorType::ParseError),
+            bad.as_ref().map(Error::error_type)
+        );
+        assert_eq!(Some("coordinates[1]"), bad.as_ref().and_then(Error::path));
+        let bad = err(r#"{"type": "Feature", "properties": {}}"#);
+        assert_eq!(Some(ErrorType::ParseError), bad.map(|e| e.error_type()));
+
+        let invalid = |doc: &str| -> Option<String> {
+            let err = doc.parse::<GeoJson>().ok()?.validate().err()?;
+            err.path().map(ToString::to_string)
+        };
+        assert_eq!(
+            Some("features[1].geometry.coordinates[0]".to_string()),
+            invalid(&SAMPLE.replace("[171, 5], [171, -5]]", "[171, 5], [172, -5]]"))
+        );
+        assert_eq!(
+            Some("features[0].geometry.coordinates".to_string()),
+            invalid(&SAMPLE.replace("[175.5, 0.5, 12.25]", "[175.5, 91]"))
+        );
+        assert_eq!(
+            Some("bbox".to_string()),
+            invalid(&SAMPLE.replace("[170, -10, -170, 10]", "[170, -10, 179, 10]"))
+        );
+        assert_eq!(
+            Some("coordinates[1]".to_string()),
+            invalid(r#"{"type": "LineString", "coordinates": [[179, 0], [-179, 0]]}"#)
+        );
+    }
+}
@@ -0,0 +1,444 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! GeoJSON ([RFC 7946](https://datatracker.ietf.org/doc/html/rfc7946)) encoders and decoders.
+//!
+//! Documents are read with [`GeoJson::read_from`] or [`str::parse`], and written with
+//! [`GeoJson::write_to`] or [`ToString::to_string`].  Feature properties are held as
+//! [`VariableValue`]s: JSON strings, numbers, booleans and nulls map to the matching value, and
+//! nested arrays & objects are kept as their JSON text, named in [`Feature::json_properties`] so
+//! they're written back as JSON.
+//!
+//! Reading only checks the structure of the document, [`GeoJson::validate`] additionally checks
+//! the constraints of the RFC, including that no edge crosses the antimeridian.  Bounding boxes
+//! that cross the antimeridian have a `west` greater than their `east`.
+
+#![forbid(unsafe_code)]
+
+use std::collections::{BTreeMap, BTreeSet};
+
+pub use irox_types::VariableValue;
+
+use crate::error::{Error, ErrorType};
+
+mod convert;
+pub mod error;
+mod json;
+
+/// The properties of a [`Feature`]
+pub type Properties = BTreeMap<String, VariableValue>;
+
+///
+/// A single position, in decimal degrees of WGS84 longitude & latitude
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Position {
+    pub longitude: f64,
+    pub latitude: f64,
+    /// Height in meters above or below the WGS84 ellipsoid
+    pub altitude: Option<f64>,
+}
+
+impl Position {
+    #[must_use]
+    pub fn new(longitude: f64, latitude: f64) -> Position {
+        Position {
+            longitude,
+            latitude,
+            altitude: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_altitude(self, altitude: f64) -> Position {
+        Position {
+            altitude: Some(altitude),
+            ..self
+        }
+    }
+
+    fn validate(&self, path: &str) -> Result<(), Error> {
+        let valid = self.longitude.is_finite()
+            && self.latitude.is_finite()
+            && self.altitude.is_none_or(f64::is_finite);
+        if !valid {
+            return Err(invalid(path, "Position must be finite".to_string()));
+        }
+        if !(-180.0..=180.0).contains(&self.longitude) {
+            return Err(invalid(
+                path,
+                format!("Longitude {} is outside [-180, 180]", self.longitude),
+            ));
+        }
+        if !(-90.0..=90.0).contains(&self.latitude) {
+            return Err(invalid(
+                path,
+                format!("Latitude {} is outside [-90, 90]", self.latitude),
+            ));
+        }
+        Ok(())
+    }
+}
+
+///
+/// The GeoJSON geometry types
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry {
+    Point(Position),
+    MultiPoint(Vec<Position>),
+    LineString(Vec<Position>),
+    MultiLineString(Vec<Vec<Position>>),
+    /// The exterior ring, followed by any interior rings (holes)
+    Polygon(Vec<Vec<Position>>),
+    MultiPolygon(Vec<Vec<Vec<Position>>>),
+    GeometryCollection(Vec<Geometry>),
+}
+
+impl Geometry {
+    /// Calls the function on every position within the geometry
+    pub fn for_each_position<F: FnMut(&Position)>(&self, func: &mut F) {
+        match self {
+            Geometry::Point(p) => func(p),
+            Geometry::MultiPoint(line) | Geometry::LineString(line) => line.iter().for_each(func),
+            Geometry::MultiLineString(lines) | Geometry::Polygon(lines) => {
+                lines.iter().flatten().for_each(func);
+            }
+            Geometry::MultiPolygon(polygons) => polygons.iter().flatten().flatten().for_each(func),
+            Geometry::GeometryCollection(geometries) => {
+                for geometry in geometries {
+                    geometry.for_each_position(func);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Computes the smallest bounding box around the geometry, which crosses the antimeridian
+    /// if that is smaller.  Returns [`None`] if the geometry has no positions.
+    #[must_use]
+    pub fn bbox(&self) -> Option<BoundingBox> {
+        let mut positions = Vec::new();
+        self.for_each_position(&mut |p| positions.push(*p));
+        BoundingBox::around(&positions)
+    }
+
+    ///
+    /// Checks the geometry against RFC 7946: positions are in range, line strings have at least
+    /// two positions, polygon rings are closed with at least four positions, and no edge crosses
+    /// the antimeridian.
+    pub fn validate(&self) -> Result<(), Error> {
+        self.validate_at("")
+    }
+
+    fn validate_at(&self, path: &str) -> Result<(), Error> {
+        let coords = join(path, "coordinates");
+        match self {
+            Geometry::Point(p) => p.validate(&coords),
+            Geometry::MultiPoint(points) => points
+                .iter()
+                .enumerate()
+                .try_for_each(|(i, p)| p.validate(&index(&coords, i))),
+            Geometry::LineString(line) => validate_line(&coords, line),
+            Geometry::MultiLineString(lines) => lines
+                .iter()
+                .enumerate()
+                .try_for_each(|(i, line)| validate_line(&index(&coords, i), line)),
+            Geometry::Polygon(rings) => validate_polygon(&coords, rings),
+            Geometry::MultiPolygon(polygons) => polygons
+                .iter()
+                .enumerate()
+                .try_for_each(|(i, rings)| validate_polygon(&index(&coords, i), rings)),
+            Geometry::GeometryCollection(geometries) => {
+                let path = join(path, "geometries");
+                geometries
+                    .iter()
+                    .enumerate()
+                    .try_for_each(|(i, g)| g.validate_at(&index(&path, i)))
+            }
+        }
+    }
+}
+
+fn join(path: &str, member: &str) -> String {
+    if path.is_empty() {
+        member.to_string()
+    } else {
+        format!("{path}.{member}")
+    }
+}
+
+fn index(path: &str, idx: usize) -> String {
+    format!("{path}[{idx}]")
+}
+
+fn invalid(path: &str, msg: String) -> Error {
+    Error::at(ErrorType::ValidationError, path, msg)
+}
+
+fn validate_line(path: &str, line: &[Position]) -> Result<(), Error> {
+    if line.len() < 2 {
+        return Err(invalid(
+            path,
+            "LineString must have at least two positions".to_string(),
+        ));
+    }
+    for (i, p) in line.iter().enumerate() {
+        p.validate(&index(path, i))?;
+    }
+    // RFC 7946 3.1.9: geometries crossing the antimeridian SHOULD be split into two.
+    for (i, pair) in line.windows(2).enumerate() {
+        if let [a, b] = pair {
+            if (b.longitude - a.longitude).abs() > 180.0 {
+                return Err(invalid(
+                    &index(path, i + 1),
+                    "Edge crosses the antimeridian, it must be split at +/-180".to_string(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_polygon(path: &str, rings: &[Vec<Position>]) -> Result<(), Error> {
+    if rings.is_empty() {
+        return Err(invalid(
+            path,
+            "Polygon must have an exterior ring".to_string(),
+        ));
+    }
+    for (i, ring) in rings.iter().enumerate() {
+        let path = index(path, i);
+        if ring.len() < 4 {
+            return Err(invalid(
+                &path,
+                "Linear ring must have at least four positions".to_string(),
+            ));
+        }
+        if ring.first() != ring.last() {
+            return Err(invalid(
+                &path,
+                "Linear ring must start and end with the same position".to_string(),
+            ));
+        }
+        validate_line(&path, ring)?;
+    }
+    Ok(())
+}
+
+///
+/// A bounding box.  If `west` is greater than `east`, the box crosses the antimeridian.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct BoundingBox {
+    pub west: f64,
+    pub south: f64,
+    pub east: f64,
+    pub north: f64,
+    /// The minimum & maximum altitudes, for a 3D bounding box
+    pub altitude: Option<(f64, f64)>,
+}
+
+impl BoundingBox {
+    ///
+    /// Returns the smallest box around the positions, which crosses the antimeridian if that
+    /// is smaller.  Returns [`None`] if there are no positions.
+    #[must_use]
+    pub fn around(positions: &[Position]) -> Option<BoundingBox> {
+        let first = positions.first()?;
+        let mut south = first.latitude;
+        let mut north = first.latitude;
+        let mut west = first.longitude;
+        let mut east = first.longitude;
+        // the same extents with longitudes shifted to [0, 360)
+        let shift = |lon: f64| if lon < 0.0 { lon + 360.0 } else { lon };
+        let mut west_shifted = shift(first.longitude);
+        let mut east_shifted = west_shifted;
+        let mut altitude: Option<(f64, f64)> = None;
+        let mut all_altitudes = true;
+        for p in positions {
+            south = south.min(p.latitude);
+            north = north.max(p.latitude);
+            west = west.min(p.longitude);
+            east = east.max(p.longitude);
+            west_shifted = west_shifted.min(shift(p.longitude));
+            east_shifted = east_shifted.max(shift(p.longitude));
+            match p.altitude {
+                Some(alt) => {
+                    let (min, max) = altitude.unwrap_or((alt, alt));
+                    altitude = Some((min.min(alt), max.max(alt)));
+                }
+                None => all_altitudes = false,
+            }
+        }
+        if east_shifted - west_shifted < east - west {
+            let unshift = |lon: f64| if lon > 180.0 { lon - 360.0 } else { lon };
+            west = unshift(west_shifted);
+            east = unshift(east_shifted);
+        }
+        Some(BoundingBox {
+            west,
+            south,
+            east,
+            north,
+            altitude: altitude.filter(|_| all_altitudes),
+        })
+    }
+
+    #[must_use]
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.west > self.east
+    }
+
+    /// Returns true if the position is within the box, ignoring altitude
+    #[must_use]
+    pub fn contains(&self, position: &Position) -> bool {
+        let lon = position.longitude;
+        let in_lon = if self.crosses_antimeridian() {
+            lon >= self.west || lon <= self.east
+        } else {
+            (self.west..=self.east).contains(&lon)
+        };
+        in_lon && (self.south..=self.north).contains(&position.latitude)
+    }
+
+    fn validate(&self, path: &str) -> Result<(), Error> {
+        Position::new(self.west, self.south).validate(path)?;
+        Position::new(self.east, self.north).validate(path)?;
+        if self.south > self.north {
+            return Err(invalid(
+                path,
+                "Bounding box south is greater than north".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks that every position of the geometry is within this box
+    fn validate_contains(&self, path: &str, geometry: &Geometry) -> Result<(), Error> {
+        self.validate(path)?;
+        let mut outside = None;
+        geometry.for_each_position(&mut |p| {
+            if outside.is_none() && !self.contains(p) {
+                outside = Some(*p);
+            }
+        });
+        if let Some(p) = outside {
+            return Err(invalid(
+                path,
+                format!(
+                    "Position [{}, {}] is outside the bounding box",
+                    p.longitude, p.latitude
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+///
+/// A spatially bounded thing, with a geometry & properties
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Feature {
+    /// The identifier, a string or a number
+    pub id: Option<VariableValue>,
+    pub bbox: Option<BoundingBox>,
+    pub geometry: Option<Geometry>,
+    pub properties: Properties,
+    /// The properties holding the JSON text of a nested array or object, which are written back
+    /// as JSON rather than as strings.
+    pub json_properties: BTreeSet<String>,
+}
+
+impl Feature {
+    #[must_use]
+    pub fn new(geometry: Geometry) -> Feature {
+        Feature {
+            geometry: Some(geometry),
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// Validates the geometry, and that the bounding box contains it.
+    pub fn validate(&self) -> Result<(), Error> {
+        self.validate_at("")
+    }
+
+    fn validate_at(&self, path: &str) -> Result<(), Error> {
+        if let Some(geometry) = &self.geometry {
+            let geom_path = join(path, "geometry");
+            geometry.validate_at(&geom_path)?;
+            if let Some(bbox) = &self.bbox {
+                bbox.validate_contains(&join(path, "bbox"), geometry)?;
+            }
+        } else if let Some(bbox) = &self.bbox {
+            bbox.validate(&join(path, "bbox"))?;
+        }
+        Ok(())
+    }
+}
+
+///
+/// A list of features
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FeatureCollection {
+    pub bbox: Option<BoundingBox>,
+    pub features: Vec<Feature>,
+}
+
+impl FeatureCollection {
+    ///
+    /// Validates every feature, and that the bounding box contains all of them.
+    pub fn validate(&self) -> Result<(), Error> {
+        for (i, feature) in self.features.iter().enumerate() {
+            feature.validate_at(&index("features", i))?;
+        }
+        if let Some(bbox) = &self.bbox {
+            let all = Geometry::GeometryCollection(
+                self.features
+                    .iter()
+                    .filter_map(|f| f.geometry.clone())
+                    .collect(),
+            );
+            bbox.validate_contains("bbox", &all)?;
+        }
+        Ok(())
+    }
+}
+
+///
+/// A GeoJSON document
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeoJson {
+    Geometry(Geometry),
+    Feature(Feature),
+    FeatureCollection(FeatureCollection),
+}
+
+impl GeoJson {
+    pub fn validate(&self) -> Result<(), Error> {
+        match self {
+            GeoJson::Geometry(g) => g.validate(),
+            GeoJson::Feature(f) => f.validate(),
+            GeoJson::FeatureCollection(fc) => fc.validate(),
+        }
+    }
+}
+
+impl From<Geometry> for GeoJson {
+    fn from(value: Geometry) -> Self {
+        GeoJson::Geometry(value)
+    }
+}
+
+impl From<Feature> for GeoJson {
+    fn from(value: Feature) -> Self {
+        GeoJson::Feature(value)
+    }
+}
+
+impl From<FeatureCollection> for GeoJson {
+    fn from(value: FeatureCollection) -> Self {
+        GeoJson::FeatureCollection(value)
+    }
+}
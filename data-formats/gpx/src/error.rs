@@ -3,29 +3,67 @@
 
 use std::fmt::{Display, Formatter};
 
+use xml::common::{Position, TextPosition};
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum ErrorType {
     IOError,
     XMLError,
+    /// The document is well-formed XML, but isn't valid GPX
+    ParseError,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Error {
     error_type: ErrorType,
     msg: String,
+    position: Option<TextPosition>,
 }
 
 impl Error {
     pub fn new(error_type: ErrorType, msg: String) -> Error {
-        Error { error_type, msg }
+        Error {
+            error_type,
+            msg,
+            position: None,
+        }
     }
     pub fn ioe<T>(msg: &'static str) -> Result<T, Error> {
         Err(Error::new(ErrorType::IOError, msg.to_string()))
     }
+
+    /// A [`ErrorType::ParseError`] at the specified position within the document
+    pub fn parse_at(msg: String, position: TextPosition) -> Error {
+        Error {
+            error_type: ErrorType::ParseError,
+            msg,
+            position: Some(position),
+        }
+    }
+
+    pub fn error_type(&self) -> ErrorType {
+        self.error_type
+    }
+
+    /// The 1-based line number in the document where the error occurred, if known
+    pub fn line(&self) -> Option<u64> {
+        self.position.map(|p| p.row + 1)
+    }
+
+    /// The 1-based column number in the document where the error occurred, if known
+    pub fn column(&self) -> Option<u64> {
+        self.position.map(|p| p.column + 1)
+    }
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let (Some(line), Some(column)) = (self.line(), self.column()) {
+            return f.write_fmt(format_args!(
+                "GPXError({:?}) at {line}:{column}: {}",
+                self.error_type, self.msg
+            ));
+        }
         f.write_fmt(format_args!(
             "GPXError({:?}): {}",
             self.error_type, self.msg
@@ -37,19 +75,13 @@ impl std::error::Error for Error {}
 
 impl From<std::io::Error> for Error {
     fn from(value: std::io::Error) -> Self {
-        Error {
-            error_type: ErrorType::IOError,
-            msg: value.to_string(),
-        }
+        Error::new(ErrorType::IOError, value.to_string())
     }
 }
 
 impl From<xml::writer::Error> for Error {
     fn from(value: xml::writer::Error) -> Self {
-        Error {
-            error_type: ErrorType::XMLError,
-            msg: value.to_string(),
-        }
+        Error::new(ErrorType::XMLError, value.to_string())
     }
 }
 
@@ -57,7 +89,8 @@ impl From<xml::reader::Error> for Error {
     fn from(value: xml::reader::Error) -> Self {
         Error {
             error_type: ErrorType::XMLError,
-            msg: value.to_string(),
+            msg: value.msg().to_string(),
+            position: Some(value.position()),
         }
     }
 }
@@ -12,6 +12,9 @@ use irox_time::datetime::UTCDateTime;
 use irox_time::epoch::UnixTimestamp;
 use irox_units::units::angle::Angle;
 use irox_units::units::duration::Duration;
+use irox_units::units::length::Length;
+use irox_units::units::speed::Speed;
+use irox_units::units::temperature::Temperature;
 pub use writer::*;
 
 pub mod error;
@@ -19,6 +22,12 @@ mod reader;
 mod writer;
 
 pub const NAMESPACE: &str = "http://www.topografix.com/GPX/1/1";
+/// Namespace of the older GPX 1.0 schema, still produced by many devices
+pub const NAMESPACE_1_0: &str = "http://www.topografix.com/GPX/1/0";
+/// Namespace of version 1 of the Garmin TrackPointExtension
+pub const GARMIN_TRACK_POINT_V1: &str = "http://www.garmin.com/xmlschemas/TrackPointExtension/v1";
+/// Namespace of version 2 of the Garmin TrackPointExtension
+pub const GARMIN_TRACK_POINT_V2: &str = "http://www.garmin.com/xmlschemas/TrackPointExtension/v2";
 
 ///
 /// Main top-level file element
@@ -74,6 +83,7 @@ impl GPX {
 /// Information about the GPX file, author, and copyright restrictions goes in
 /// the metadata section.  Providing rich, meaningful information about your
 /// GPX files allows others to search for and use your GPS data.
+#[derive(Default)]
 pub struct Metadata {
     /// The name of the GPX file.
     pub name: Option<String>,
@@ -226,6 +236,7 @@ impl From<EllipticalCoordinate> for Waypoint {
 ///
 /// rte represents route - an ordered list of waypoints representing a series of
 /// turn points leading to a destination.
+#[derive(Default)]
 pub struct Route {
     /// GPS name of route.
     pub name: Option<String>,
@@ -298,7 +309,46 @@ impl Track {
     }
 }
 
-pub struct Extensions;
+///
+/// Elements from other schemas.  The children of the `extensions` element are kept as raw XML,
+/// and well-known extensions are decoded where present.
+#[derive(Default)]
+pub struct Extensions {
+    /// The extension elements, re-serialized as an XML fragment
+    pub raw: String,
+
+    /// The Garmin TrackPointExtension (v1 or v2) of a track point, if present
+    pub track_point: Option<TrackPointExtension>,
+}
+
+///
+/// Garmin TrackPointExtension values, as written by most fitness devices.
+#[derive(Default, Copy, Clone)]
+pub struct TrackPointExtension {
+    /// Air temperature
+    pub atemp: Option<Temperature>,
+
+    /// Water temperature
+    pub wtemp: Option<Temperature>,
+
+    /// Depth below the water surface
+    pub depth: Option<Length>,
+
+    /// Heart rate in beats per minute
+    pub hr: Option<u8>,
+
+    /// Cadence in revolutions per minute
+    pub cad: Option<u8>,
+
+    /// Speed over ground
+    pub speed: Option<Speed>,
+
+    /// Course over ground, from true north
+    pub course: Option<Angle>,
+
+    /// Bearing to the next point, from true north
+    pub bearing: Option<Angle>,
+}
 
 /// A person or organization.
 pub struct Person {
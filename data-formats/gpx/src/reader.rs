@@ -1,26 +1,904 @@
 // SPDX-License-Identifier: MIT
 // Copyright 2023 IROX Contributors
 
-use log::info;
+use std::fmt::Display;
 use std::io::Read;
+use std::str::FromStr;
 
-use crate::error::Error;
-use xml::reader::XmlEvent;
+use log::warn;
+use xml::attribute::OwnedAttribute;
+use xml::common::{Position, TextPosition};
+use xml::name::OwnedName;
+use xml::namespace::Namespace;
+use xml::reader::{EventReader, ParserConfig, XmlEvent};
+use xml::EmitterConfig;
+
+use irox_carto::altitude::{Altitude, AltitudeReferenceFrame};
+use irox_carto::coordinate::{Latitude, Longitude};
+use irox_carto::gps::DilutionOfPrecision;
+use irox_time::datetime::UTCDateTime;
+use irox_units::units::angle::Angle;
+use irox_units::units::duration::Duration;
+use irox_units::units::length::Length;
+use irox_units::units::speed::Speed;
+use irox_units::units::temperature::Temperature;
 
-use crate::GPX;
+use crate::error::Error;
+use crate::{
+    Bounds, Copyright, DGPSStationType, Email, Extensions, Fix, Link, Metadata, Person, Route,
+    Track, TrackPointExtension, TrackSegment, Waypoint, GARMIN_TRACK_POINT_V1,
+    GARMIN_TRACK_POINT_V2, GPX, NAMESPACE, NAMESPACE_1_0,
+};
 
 impl crate::GPX {
+    ///
+    /// Reads a GPX 1.0 or 1.1 document from the provided input.  GPX 1.0 documents are mapped
+    /// onto the 1.1 structure: the header fields become the [`Metadata`], `url`/`urlname` pairs
+    /// become [`Link`]s, and track point `course`/`speed` values become a
+    /// [`TrackPointExtension`].
     pub fn read_from<T: Read>(input: T) -> Result<GPX, Error> {
-        let mut reader = xml::reader::EventReader::new(input);
+        let config = ParserConfig::new()
+            .trim_whitespace(true)
+            .cdata_to_characters(true);
+        let mut reader = GPXReader {
+            events: EventReader::new_with_config(input, config),
+            version_1_0: false,
+        };
+        loop {
+            match reader.next()? {
+                XmlEvent::StartElement {
+                    name,
+                    attributes,
+                    namespace,
+                } => {
+                    let root = reader.element(name, attributes, namespace);
+                    if root.local() != "gpx" {
+                        return Err(root.error(format!(
+                            "Expected a <gpx> root element, found <{}>",
+                            root.local()
+                        )));
+                    }
+                    return reader.read_gpx(&root);
+                }
+                XmlEvent::EndDocument => {
+                    return Err(Error::parse_at(
+                        "Document has no <gpx> root element".to_string(),
+                        reader.events.position(),
+                    ))
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+///
+/// A started element, and where it started in the document.
+struct Element {
+    name: OwnedName,
+    attributes: Vec<OwnedAttribute>,
+    namespace: Namespace,
+    position: TextPosition,
+}
+
+impl Element {
+    fn local(&self) -> &str {
+        self.name.local_name.as_str()
+    }
+
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|a| a.name.local_name == name)
+            .map(|a| a.value.as_str())
+    }
+
+    /// GPX elements are either in one of the GPX namespaces, or not namespaced at all.
+    fn is_gpx(&self) -> bool {
+        match self.name.namespace.as_deref() {
+            None => true,
+            Some(ns) => ns == NAMESPACE || ns == NAMESPACE_1_0,
+        }
+    }
+
+    fn error(&self, msg: String) -> Error {
+        Error::parse_at(msg, self.position)
+    }
+
+    fn parse_attr<T: FromStr>(&self, name: &str) -> Result<T, Error>
+    where
+        T::Err: Display,
+    {
+        let Some(value) = self.attr(name) else {
+            return Err(self.error(format!(
+                "Missing required attribute '{name}' on <{}>",
+                self.local()
+            )));
+        };
+        value.trim().parse().map_err(|e| {
+            self.error(format!(
+                "Invalid value '{value}' for attribute '{name}' on <{}>: {e}",
+                self.local()
+            ))
+        })
+    }
+
+    fn degrees_attr(&self, name: &str, limit: f64) -> Result<Angle, Error> {
+        let value: f64 = self.parse_attr(name)?;
+        if !(-limit..=limit).contains(&value) {
+            return Err(self.error(format!(
+                "Attribute '{name}' on <{}> must be within [-{limit}, {limit}], was {value}",
+                self.local()
+            )));
+        }
+        Ok(Angle::new_degrees(value))
+    }
+
+    fn start_event(&self) -> XmlEvent {
+        XmlEvent::StartElement {
+            name: self.name.clone(),
+            attributes: self.attributes.clone(),
+            namespace: self.namespace.clone(),
+        }
+    }
+}
+
+struct GPXReader<T: Read> {
+    events: EventReader<T>,
+    version_1_0: bool,
+}
+
+impl<T: Read> GPXReader<T> {
+    fn next(&mut self) -> Result<XmlEvent, Error> {
+        Ok(self.events.next()?)
+    }
+
+    fn element(
+        &self,
+        name: OwnedName,
+        attributes: Vec<OwnedAttribute>,
+        namespace: Namespace,
+    ) -> Element {
+        Element {
+            name,
+            attributes,
+            namespace,
+            position: self.events.position(),
+        }
+    }
+
+    ///
+    /// Returns the next child element of the current element, or [`None`] once the current
+    /// element has ended.  Text between child elements is ignored.
+    fn next_child(&mut self) -> Result<Option<Element>, Error> {
+        loop {
+            match self.next()? {
+                XmlEvent::StartElement {
+                    name,
+                    attributes,
+                    namespace,
+                } => return Ok(Some(self.element(name, attributes, namespace))),
+                XmlEvent::EndElement { .. } => return Ok(None),
+                XmlEvent::EndDocument => {
+                    return Err(Error::parse_at(
+                        "Unexpected end of document".to_string(),
+                        self.events.position(),
+                    ))
+                }
+                _ => {}
+            }
+        }
+    }
 
-        let gpx = GPX::new();
-        while let Ok(elem) = reader.next() {
-            match elem {
-                XmlEvent::EndDocument => break,
-                XmlEvent::StartElement { .. } => info!("start_element"),
+    ///
+    /// Returns all the events up to the end of the current element, not including the end.
+    fn capture(&mut self) -> Result<Vec<XmlEvent>, Error> {
+        let mut out = Vec::new();
+        let mut depth = 0_usize;
+        loop {
+            let event = self.next()?;
+            match &event {
+                XmlEvent::StartElement { .. } => depth += 1,
+                XmlEvent::EndElement { .. } => {
+                    if depth == 0 {
+                        return Ok(out);
+                    }
+                    depth -= 1;
+                }
+                XmlEvent::EndDocument => {
+                    return Err(Error::parse_at(
+                        "Unexpected end of document".to_string(),
+                        self.events.position(),
+                    ))
+                }
                 _ => {}
             }
+            out.push(event);
+        }
+    }
+
+    fn skip(&mut self) -> Result<(), Error> {
+        self.capture().map(|_| ())
+    }
+
+    ///
+    /// Reads the text content of the current element, skipping any nested elements.
+    fn text(&mut self) -> Result<String, Error> {
+        let mut out = String::new();
+        for event in self.capture()? {
+            if let XmlEvent::Characters(text) = event {
+                out.push_str(&text);
+            }
+        }
+        Ok(out)
+    }
+
+    fn value<V: FromStr>(&mut self, element: &Element) -> Result<V, Error>
+    where
+        V::Err: Display,
+    {
+        let text = self.text()?;
+        text.parse().map_err(|e| {
+            element.error(format!(
+                "Invalid value '{text}' for element <{}>: {e}",
+                element.local()
+            ))
+        })
+    }
+
+    fn time(&mut self, element: &Element) -> Result<UTCDateTime, Error> {
+        let text = self.text()?;
+        UTCDateTime::try_from_iso8601(&text).map_err(|e| {
+            element.error(format!(
+                "Invalid ISO 8601 time '{text}' for element <{}>: {e}",
+                element.local()
+            ))
+        })
+    }
+
+    fn read_gpx(&mut self, root: &Element) -> Result<GPX, Error> {
+        let mut gpx = GPX::new();
+        let default_ns = root.namespace.get(xml::namespace::NS_NO_PREFIX);
+        if let Some(version) = root.attr("version") {
+            gpx.version = version.to_string();
+        } else if default_ns == Some(NAMESPACE_1_0) {
+            gpx.version = "1.0".to_string();
+        }
+        if let Some(creator) = root.attr("creator") {
+            gpx.creator = creator.to_string();
+        }
+        self.version_1_0 = gpx.version == "1.0" || default_ns == Some(NAMESPACE_1_0);
+
+        // GPX 1.0 puts the metadata fields directly into the root element.
+        let mut header = Metadata::default();
+        let mut has_header = false;
+        while let Some(child) = self.next_child()? {
+            match child.local() {
+                "metadata" => gpx.metadata = Some(self.read_metadata()?),
+                "wpt" => gpx.wpt.push(self.read_wpt(&child)?),
+                "rte" => gpx.rte.push(self.read_rte()?),
+                "trk" => gpx.trk.push(self.read_trk()?),
+                "extensions" => self.read_extensions(&child, &mut gpx.extensions)?,
+                _ => {
+                    if self.read_metadata_field(&child, &mut header)? {
+                        has_header = true;
+                    } else {
+                        self.unknown(&child, &mut gpx.extensions)?;
+                    }
+                }
+            }
+        }
+        if has_header && gpx.metadata.is_none() {
+            gpx.metadata = Some(header);
         }
         Ok(gpx)
     }
+
+    fn read_metadata(&mut self) -> Result<Metadata, Error> {
+        let mut metadata = Metadata::default();
+        while let Some(child) = self.next_child()? {
+            if child.local() == "extensions" {
+                self.read_extensions(&child, &mut metadata.extensions)?;
+            } else if !self.read_metadata_field(&child, &mut metadata)? {
+                self.unknown(&child, &mut metadata.extensions)?;
+            }
+        }
+        Ok(metadata)
+    }
+
+    ///
+    /// Reads a single field of the metadata, in either the 1.1 `metadata` element or the 1.0
+    /// header.  Returns false if the element isn't a metadata field.
+    fn read_metadata_field(
+        &mut self,
+        child: &Element,
+        metadata: &mut Metadata,
+    ) -> Result<bool, Error> {
+        if !child.is_gpx() {
+            return Ok(false);
+        }
+        match child.local() {
+            "name" => metadata.name = Some(self.text()?),
+            "desc" => metadata.desc = Some(self.text()?),
+            "author" if self.version_1_0 => {
+                let name = self.text()?;
+                person_or_default(&mut metadata.author).name = Some(name);
+            }
+            "author" => metadata.author = Some(self.read_person()?),
+            "email" => {
+                let email = self.text()?;
+                person_or_default(&mut metadata.author).email = Some(split_email(&email));
+            }
+            "copyright" => metadata.copyright = Some(self.read_copyright(child)?),
+            "link" => metadata.link.push(self.read_link(child)?),
+            "url" | "urlname" => self.read_legacy_link(child, &mut metadata.link)?,
+            "time" => metadata.time = Some(self.time(child)?.into()),
+            "keywords" => {
+                metadata.keywords = self
+                    .text()?
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|k| !k.is_empty())
+                    .map(ToString::to_string)
+                    .collect();
+            }
+            "bounds" => {
+                metadata.bounds = Some(read_bounds(child)?);
+                self.skip()?;
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn read_person(&mut self) -> Result<Person, Error> {
+        let mut person = Person {
+            name: None,
+            email: None,
+            link: None,
+        };
+        while let Some(child) = self.next_child()? {
+            match child.local() {
+                "name" => person.name = Some(self.text()?),
+                "email" => {
+                    person.email = Some(Email {
+                        id: child.attr("id").unwrap_or_default().to_string(),
+                        domain: child.attr("domain").unwrap_or_default().to_string(),
+                    });
+                    self.skip()?;
+                }
+                "link" => person.link = Some(self.read_link(&child)?),
+                _ => self.skip()?,
+            }
+        }
+        Ok(person)
+    }
+
+    fn read_copyright(&mut self, element: &Element) -> Result<Copyright, Error> {
+        let mut copyright = Copyright {
+            year: None,
+            license: None,
+            author: element.attr("author").unwrap_or_default().to_string(),
+        };
+        while let Some(child) = self.next_child()? {
+            match child.local() {
+                "year" => copyright.year = Some(self.value(&child)?),
+                "license" => copyright.license = Some(self.text()?),
+                _ => self.skip()?,
+            }
+        }
+        Ok(copyright)
+    }
+
+    fn read_link(&mut self, element: &Element) -> Result<Link, Error> {
+        let mut link = Link {
+            text: None,
+            link_type: None,
+            href: element.attr("href").unwrap_or_default().to_string(),
+        };
+        while let Some(child) = self.next_child()? {
+            match child.local() {
+                "text" => link.text = Some(self.text()?),
+                "type" => link.link_type = Some(self.text()?),
+                _ => self.skip()?,
+            }
+        }
+        Ok(link)
+    }
+
+    ///
+    /// GPX 1.0 uses a `url` element, optionally followed by its `urlname`.
+    fn read_legacy_link(&mut self, element: &Element, links: &mut Vec<Link>) -> Result<(), Error> {
+        let text = self.text()?;
+        if element.local() == "url" {
+            links.push(Link {
+                text: None,
+                link_type: None,
+                href: text,
+            });
+        } else if let Some(link) = links.last_mut().filter(|l| l.text.is_none()) {
+            link.text = Some(text);
+        } else {
+            links.push(Link {
+                text: Some(text),
+                link_type: None,
+                href: String::new(),
+            });
+        }
+        Ok(())
+    }
+
+    fn read_wpt(&mut self, element: &Element) -> Result<Waypoint, Error> {
+        let lat = Latitude(element.degrees_attr("lat", 90.)?);
+        let lon = Longitude(element.degrees_attr("lon", 180.)?);
+        let mut wpt = Waypoint::new(lat, lon);
+        while let Some(child) = self.next_child()? {
+            if !child.is_gpx() {
+                self.unknown(&child, &mut wpt.extensions)?;
+                continue;
+            }
+            match child.local() {
+                "ele" => wpt.ele = Some(self.value(&child)?),
+                "time" => wpt.time = Some(self.time(&child)?),
+                "magvar" => wpt.magvar = Some(Angle::new_degrees(self.value(&child)?)),
+                "geoidheight" => {
+                    let height = Length::new_meters(self.value(&child)?);
+                    wpt.geoidheight =
+                        Some(Altitude::new(height, AltitudeReferenceFrame::Ellipsoid));
+                }
+                "name" => wpt.name = Some(self.text()?),
+                "cmt" => wpt.cmt = Some(self.text()?),
+                "desc" => wpt.desc = Some(self.text()?),
+                "src" => wpt.src = Some(self.text()?),
+                "link" => wpt.link.push(self.read_link(&child)?),
+                "url" | "urlname" => self.read_legacy_link(&child, &mut wpt.link)?,
+                "sym" => wpt.sym = Some(self.text()?),
+                "type" => wpt.wpt_type = Some(self.text()?),
+                "fix" => wpt.fix = Some(self.fix(&child)?),
+                "sat" => wpt.sat = Some(self.value(&child)?),
+                "hdop" => wpt.hdop = Some(DilutionOfPrecision(self.value(&child)?)),
+                "vdop" => wpt.vdop = Some(DilutionOfPrecision(self.value(&child)?)),
+                "pdop" => wpt.pdop = Some(DilutionOfPrecision(self.value(&child)?)),
+                "ageofdgpsdata" => {
+                    wpt.ageofdgpsdata = Some(Duration::new_seconds(self.value(&child)?));
+                }
+                "dgpsid" => {
+                    let id: u16 = self.value(&child)?;
+                    if id > 1023 {
+                        return Err(child.error(format!(
+                            "DGPS station id must be within [0, 1023], was {id}"
+                        )));
+                    }
+                    wpt.dgpsid = Some(DGPSStationType(id));
+                }
+                "course" if self.version_1_0 => {
+                    let course = Angle::new_degrees(self.value(&child)?);
+                    track_point_or_default(&mut wpt.extensions).course = Some(course);
+                }
+                "speed" if self.version_1_0 => {
+                    let speed = Speed::new_meters_per_second(self.value(&child)?);
+                    track_point_or_default(&mut wpt.extensions).speed = Some(speed);
+                }
+                "extensions" => self.read_extensions(&child, &mut wpt.extensions)?,
+                _ => self.unknown(&child, &mut wpt.extensions)?,
+            }
+        }
+        Ok(wpt)
+    }
+
+    fn fix(&mut self, element: &Element) -> Result<Fix, Error> {
+        let text = self.text()?;
+        Ok(match text.as_str() {
+            "none" => Fix::None,
+            "2d" => Fix::TwoD,
+            "3d" => Fix::ThreeD,
+            "dgps" => Fix::DGPS,
+            "pps" => Fix::PPS,
+            _ => {
+                return Err(element.error(format!(
+                    "Invalid fix '{text}', expected one of none, 2d, 3d, dgps, pps"
+                )))
+            }
+        })
+    }
+
+    fn read_rte(&mut self) -> Result<Route, Error> {
+        let mut rte = Route::default();
+        while let Some(child) = self.next_child()? {
+            if !child.is_gpx() {
+                self.unknown(&child, &mut rte.extensions)?;
+                continue;
+            }
+            match child.local() {
+                "name" => rte.name = Some(self.text()?),
+                "cmt" => rte.cmt = Some(self.text()?),
+                "desc" => rte.desc = Some(self.text()?),
+                "src" => rte.src = Some(self.text()?),
+                "link" => rte.link.push(self.read_link(&child)?),
+                "url" | "urlname" => self.read_legacy_link(&child, &mut rte.link)?,
+                "number" => rte.number = Some(self.value(&child)?),
+                "type" => rte.rte_type = Some(self.text()?),
+                "rtept" => rte.waypoints.push(self.read_wpt(&child)?),
+                "extensions" => self.read_extensions(&child, &mut rte.extensions)?,
+                _ => self.unknown(&child, &mut rte.extensions)?,
+            }
+        }
+        Ok(rte)
+    }
+
+    fn read_trk(&mut self) -> Result<Track, Error> {
+        let mut trk = Track::new();
+        while let Some(child) = self.next_child()? {
+            if !child.is_gpx() {
+                self.unknown(&child, &mut trk.extensions)?;
+                continue;
+            }
+            match child.local() {
+                "name" => trk.name = Some(self.text()?),
+                "cmt" => trk.cmt = Some(self.text()?),
+                "desc" => trk.desc = Some(self.text()?),
+                "src" => trk.src = Some(self.text()?),
+                "link" => trk.link.push(self.read_link(&child)?),
+                "url" | "urlname" => self.read_legacy_link(&child, &mut trk.link)?,
+                "number" => trk.number = Some(self.value(&child)?),
+                "type" => trk.trk_type = Some(self.text()?),
+                "trkseg" => trk.trkseg.push(self.read_trkseg()?),
+                "extensions" => self.read_extensions(&child, &mut trk.extensions)?,
+                _ => self.unknown(&child, &mut trk.extensions)?,
+            }
+        }
+        Ok(trk)
+    }
+
+    fn read_trkseg(&mut self) -> Result<TrackSegment, Error> {
+        let mut seg = TrackSegment::new();
+        while let Some(child) = self.next_child()? {
+            match child.local() {
+                "trkpt" if child.is_gpx() => seg.track_point.push(self.read_wpt(&child)?),
+                "extensions" if child.is_gpx() => {
+                    self.read_extensions(&child, &mut seg.extensions)?;
+                }
+                _ => self.unknown(&child, &mut seg.extensions)?,
+            }
+        }
+        Ok(seg)
+    }
+
+    fn read_extensions(
+        &mut self,
+        element: &Element,
+        extensions: &mut Option<Extensions>,
+    ) -> Result<(), Error> {
+        let events = self.capture()?;
+        append_extensions(element, &events, extensions)
+    }
+
+    ///
+    /// Elements from other schemas (as GPX 1.0 permits anywhere) are kept as extensions, unknown
+    /// GPX elements are skipped.
+    fn unknown(
+        &mut self,
+        element: &Element,
+        extensions: &mut Option<Extensions>,
+    ) -> Result<(), Error> {
+        if element.is_gpx() {
+            warn!(
+                "Skipping unknown GPX element <{}> at {}",
+                element.local(),
+                element.position
+            );
+            return self.skip();
+        }
+        let mut events = vec![element.start_event()];
+        events.append(&mut self.capture()?);
+        events.push(XmlEvent::EndElement {
+            name: element.name.clone(),
+        });
+        append_extensions(element, &events, extensions)
+    }
+}
+
+fn person_or_default(person: &mut Option<Person>) -> &mut Person {
+    person.get_or_insert_with(|| Person {
+        name: None,
+        email: None,
+        link: None,
+    })
+}
+
+fn track_point_or_default(extensions: &mut Option<Extensions>) -> &mut TrackPointExtension {
+    extensions
+        .get_or_insert_with(Extensions::default)
+        .track_point
+        .get_or_insert_with(TrackPointExtension::default)
+}
+
+fn split_email(email: &str) -> Email {
+    let (id, domain) = email.split_once('@').unwrap_or((email, ""));
+    Email {
+        id: id.to_string(),
+        domain: domain.to_string(),
+    }
+}
+
+fn read_bounds(element: &Element) -> Result<Bounds, Error> {
+    Ok(Bounds {
+        min_lat: Latitude(element.degrees_attr("minlat", 90.)?),
+        min_lon: Longitude(element.degrees_attr("minlon", 180.)?),
+        max_lat: Latitude(element.degrees_attr("maxlat", 90.)?),
+        max_lon: Longitude(element.degrees_attr("maxlon", 180.)?),
+    })
+}
+
+///
+/// Appends the events to the raw XML of the extensions, and decodes any well-known extensions.
+fn append_extensions(
+    element: &Element,
+    events: &[XmlEvent],
+    extensions: &mut Option<Extensions>,
+) -> Result<(), Error> {
+    let mut raw = Vec::new();
+    {
+        let mut writer = EmitterConfig::new()
+            .write_document_declaration(false)
+            .create_writer(&mut raw);
+        for event in events.iter().filter_map(XmlEvent::as_writer_event) {
+            writer.write(event)?;
+        }
+    }
+    let extensions = extensions.get_or_insert_with(Extensions::default);
+    extensions.raw.push_str(&String::from_utf8_lossy(&raw));
+
+    let mut in_track_point = false;
+    let mut field: Option<&str> = None;
+    let mut text = String::new();
+    for event in events {
+        match event {
+            XmlEvent::StartElement { name, .. } if is_track_point_extension(name) => {
+                in_track_point = true;
+                extensions
+                    .track_point
+                    .get_or_insert_with(TrackPointExtension::default);
+            }
+            XmlEvent::StartElement { name, .. } if in_track_point => {
+                field = Some(name.local_name.as_str());
+                text.clear();
+            }
+            XmlEvent::Characters(chars) if field.is_some() => text.push_str(chars),
+            XmlEvent::EndElement { name } if is_track_point_extension(name) => {
+                in_track_point = false;
+            }
+            XmlEvent::EndElement { .. } => {
+                if let (Some(field), Some(track_point)) =
+                    (field.take(), extensions.track_point.as_mut())
+                {
+                    set_track_point_field(element, track_point, field, text.trim())?;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn is_track_point_extension(name: &OwnedName) -> bool {
+    name.local_name == "TrackPointExtension"
+        && matches!(
+            name.namespace.as_deref(),
+            Some(GARMIN_TRACK_POINT_V1 | GARMIN_TRACK_POINT_V2)
+        )
+}
+
+fn set_track_point_field(
+    element: &Element,
+    track_point: &mut TrackPointExtension,
+    field: &str,
+    text: &str,
+) -> Result<(), Error> {
+    fn parse<V: FromStr>(element: &Element, field: &str, text: &str) -> Result<V, Error>
+    where
+        V::Err: Display,
+    {
+        text.parse().map_err(|e| {
+            element.error(format!(
+                "Invalid value '{text}' for TrackPointExtension <{field}>: {e}"
+            ))
+        })
+    }
+    match field {
+        "atemp" => track_point.atemp = Some(Temperature::new_celsius(parse(element, field, text)?)),
+        "wtemp" => track_point.wtemp = Some(Temperature::new_celsius(parse(element, field, text)?)),
+        "depth" => track_point.depth = Some(Length::new_meters(parse(element, field, text)?)),
+        "hr" => track_point.hr = Some(parse(element, field, text)?),
+        "cad" => track_point.cad = Some(parse(element, field, text)?),
+        "speed" => {
+            track_point.speed = Some(Speed::new_meters_per_second(parse(element, field, text)?));
+        }
+        "course" => track_point.course = Some(Angle::new_degrees(parse(element, field, text)?)),
+        "bearing" => track_point.bearing = Some(Angle::new_degrees(parse(element, field, text)?)),
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{Error, ErrorType};
+    use crate::{Fix, GPXWriter, GPX};
+    use irox_tools::assert_eq_eps;
+
+    const GPX_1_1: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" creator="Garmin Connect" xmlns="http://www.topografix.com/GPX/1/1"
+    xmlns:gpxtpx="http://www.garmin.com/xmlschemas/TrackPointExtension/v1">
+  <metadata>
+    <name>Morning Ride</name>
+    <author><name>Jane</name><email id="jane" domain="example.com"/></author>
+    <link href="https://example.com/ride"><text>Ride</text><type>text/html</type></link>
+    <time>2023-06-01T12:00:00Z</time>
+    <keywords>bike, morning</keywords>
+    <bounds minlat="42.1" minlon="-71.5" maxlat="42.2" maxlon="-71.4"/>
+  </metadata>
+  <wpt lat="42.15" lon="-71.45"><name>Start</name><sym>Flag</sym><fix>3d</fix><sat>9</sat></wpt>
+  <rte><name>Loop</name><rtept lat="42.1" lon="-71.5"/><rtept lat="42.2" lon="-71.4"/></rte>
+  <trk>
+    <name>Morning Ride</name>
+    <type>cycling</type>
+    <trkseg>
+      <trkpt lat="42.123456" lon="-71.456789">
+        <ele>52.4</ele>
+        <time>2023-06-01T12:00:01.500Z</time>
+        <extensions>
+          <gpxtpx:TrackPointExtension>
+            <gpxtpx:atemp>21.5</gpxtpx:atemp>
+            <gpxtpx:hr>142</gpxtpx:hr>
+            <gpxtpx:cad>88</gpxtpx:cad>
+          </gpxtpx:TrackPointExtension>
+        </extensions>
+      </trkpt>
+      <trkpt lat="42.1235" lon="-71.4568"><ele>53.0</ele></trkpt>
+    </trkseg>
+  </trk>
+</gpx>"#;
+
+    fn missing(what: &str) -> Error {
+        Error::new(ErrorType::ParseError, format!("Missing {what}"))
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_read_1_1() -> Result<(), Error> {
+        let gpx = GPX::read_from(GPX_1_1.as_bytes())?;
+        assert_eq!("1.1", gpx.version);
+        assert_eq!("Garmin Connect", gpx.creator);
+
+        let metadata = gpx.metadata.as_ref().ok_or_else(|| missing("metadata"))?;
+        assert_eq!(Some("Morning Ride"), metadata.name.as_deref());
+        let author = metadata.author.as_ref().and_then(|a| a.email.as_ref());
+        assert_eq!(Some("example.com"), author.map(|e| e.domain.as_str()));
+        assert_eq!(vec!["bike", "morning"], metadata.keywords);
+        let link = metadata.link.first().ok_or_else(|| missing("link"))?;
+        assert_eq!(Some("text/html"), link.link_type.as_deref());
+        assert!(metadata.time.is_some());
+        assert!(metadata.bounds.is_some());
+
+        let wpt = gpx.wpt.first().ok_or_else(|| missing("wpt"))?;
+        assert!(matches!(wpt.fix, Some(Fix::ThreeD)));
+        assert_eq!(Some(9), wpt.sat);
+        let rte = gpx.rte.first().ok_or_else(|| missing("rte"))?;
+        assert_eq!(2, rte.waypoints.len());
+
+        let trk = gpx.trk.first().ok_or_else(|| missing("trk"))?;
+        assert_eq!(Some("cycling"), trk.trk_type.as_deref());
+        let seg = trk.trkseg.first().ok_or_else(|| missing("trkseg"))?;
+        let [first, second] = seg.track_point.as_slice() else {
+            return Err(missing("track points"));
+        };
+        assert_eq_eps!(42.123_456, first.lat.0.as_degrees().value(), 1e-12);
+        assert_eq_eps!(-71.456_789, first.lon.0.as_degrees().value(), 1e-12);
+        assert_eq!(Some(52.4), first.ele);
+        let time = first.time.map(|t| t.format_iso8601_extended());
+        assert_eq!(Some("2023-06-01T12:00:01.500000000Z"), time.as_deref());
+
+        let ext = first
+            .extensions
+            .as_ref()
+            .ok_or_else(|| missing("extensions"))?;
+        assert!(ext.raw.contains("<gpxtpx:hr>142</gpxtpx:hr>"));
+        let tpe = ext
+            .track_point
+            .ok_or_else(|| missing("TrackPointExtension"))?;
+        assert_eq!(Some(142), tpe.hr);
+        assert_eq!(Some(88), tpe.cad);
+        assert_eq_eps!(
+            21.5,
+            tpe.atemp.map(|t| t.value()).unwrap_or_default(),
+            1e-12
+        );
+        assert!(second.extensions.is_none());
+        Ok(())
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_read_1_0() -> Result<(), Error> {
+        let doc = r#"<?xml version="1.0"?>
+<gpx version="1.0" creator="GPSBabel" xmlns="http://www.topografix.com/GPX/1/0">
+  <name>Hike</name>
+  <author>John</author>
+  <email>john@example.com</email>
+  <url>https://example.com</url>
+  <urlname>Example</urlname>
+  <time>2004-03-01T10:00:00Z</time>
+  <trk><trkseg>
+    <trkpt lat="45.5" lon="-122.6"><ele>10</ele><course>270.5</course><speed>1.25</speed></trkpt>
+  </trkseg></trk>
+</gpx>"#;
+        let gpx = GPX::read_from(doc.as_bytes())?;
+        assert_eq!("1.0", gpx.version);
+        let metadata = gpx.metadata.as_ref().ok_or_else(|| missing("metadata"))?;
+        assert_eq!(Some("Hike"), metadata.name.as_deref());
+        let author = metadata.author.as_ref();
+        assert_eq!(Some("John"), author.and_then(|a| a.name.as_deref()));
+        let email = author.and_then(|a| a.email.as_ref());
+        assert_eq!(Some("john"), email.map(|e| e.id.as_str()));
+        let link = metadata.link.first().ok_or_else(|| missing("link"))?;
+        assert_eq!("https://example.com", link.href);
+        assert_eq!(Some("Example"), link.text.as_deref());
+
+        let point = gpx
+            .trk
+            .first()
+            .and_then(|t| t.trkseg.first())
+            .and_then(|s| s.track_point.first())
+            .ok_or_else(|| missing("trkpt"))?;
+        let tpe = point.extensions.as_ref().and_then(|e| e.track_point);
+        let course = tpe.and_then(|t| t.course).map(|c| c.as_degrees().value());
+        assert_eq!(Some(270.5), course);
+        let speed = tpe
+            .and_then(|t| t.speed)
+            .map(|s| s.as_meters_per_second().value());
+        assert_eq!(Some(1.25), speed);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_errors() {
+        let doc = "<gpx version=\"1.1\">\n  <wpt lat=\"95.0\" lon=\"10\"/>\n</gpx>";
+        let err = GPX::read_from(doc.as_bytes()).err();
+        assert_eq!(
+            Some(ErrorType::ParseError),
+            err.as_ref().map(Error::error_type)
+        );
+        assert_eq!(Some(2), err.as_ref().and_then(Error::line));
+        assert_eq!(Some(3), err.as_ref().and_then(Error::column));
+
+        let doc =
+            "<gpx version=\"1.1\">\n<trk><trkseg><trkpt lat=\"1\" lon=\"2\"><time>bogus</time>";
+        let err = GPX::read_from(doc.as_bytes()).err();
+        assert_eq!(Some(2), err.as_ref().and_then(Error::line));
+
+        let err = GPX::read_from("<kml/>".as_bytes()).err();
+        assert_eq!(
+            Some(ErrorType::ParseError),
+            err.as_ref().map(Error::error_type)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_round_trip() -> Result<(), Error> {
+        let gpx = GPX::read_from(GPX_1_1.as_bytes())?;
+        let mut out = Vec::new();
+        GPXWriter::new().write_to(&gpx, &mut out)?;
+        let again = GPX::read_from(out.as_slice())?;
+        let points = again
+            .trk
+            .first()
+            .and_then(|t| t.trkseg.first())
+            .map(|s| s.track_point.as_slice())
+            .unwrap_or_default();
+        assert_eq!(2, points.len());
+        assert_eq!(Some(52.4), points.first().and_then(|p| p.ele));
+        Ok(())
+    }
 }
@@ -33,7 +33,7 @@ macro_rules! write_link {
         for link in $value {
             $writer.write(XmlEvent::start_element("link").attr("href", &link.href))?;
             maybe_write_val!($writer, "text", &link.text);
-            maybe_write_val!($writer, "link_type", &link.link_type);
+            maybe_write_val!($writer, "type", &link.link_type);
             $writer.write(XmlEvent::end_element())?;
         }
     };
@@ -0,0 +1,339 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use irox_gpx::{Metadata, Route, Track, TrackSegment, Waypoint, GPX};
+
+use crate::{Coordinate, Geometry, Kml, Placemark, Style, TrackPoint};
+
+fn coordinate(wpt: &Waypoint) -> Coordinate {
+    Coordinate::new(wpt.lat, wpt.lon, wpt.ele)
+}
+
+fn waypoint(coordinate: &Coordinate) -> Waypoint {
+    let mut wpt = Waypoint::new(coordinate.latitude, coordinate.longitude);
+    wpt.ele = coordinate.altitude;
+    wpt
+}
+
+fn track_point(point: &TrackPoint) -> Waypoint {
+    let mut wpt = waypoint(&point.coordinate);
+    wpt.time = point.when;
+    wpt
+}
+
+impl From<&GPX> for Kml {
+    fn from(gpx: &GPX) -> Self {
+        let mut kml = Kml {
+            name: gpx.metadata.as_ref().and_then(|m| m.name.clone()),
+            description: gpx.metadata.as_ref().and_then(|m| m.desc.clone()),
+            ..Default::default()
+        };
+        for wpt in &gpx.wpt {
+            // the symbol becomes a style reference, with an empty style to resolve it.
+            if let Some(sym) = &wpt.sym {
+                if !kml.styles.iter().any(|s| &s.id == sym) {
+                    kml.styles.push(Style {
+                        id: sym.clone(),
+                        ..Default::default()
+                    });
+                }
+            }
+            kml.placemarks.push(Placemark {
+                name: wpt.name.clone(),
+                description: wpt.desc.clone(),
+                time: wpt.time,
+                style_url: wpt.sym.as_ref().map(|s| format!("#{s}")),
+                geometry: Some(Geometry::Point(coordinate(wpt))),
+            });
+        }
+        for rte in &gpx.rte {
+            kml.placemarks.push(Placemark {
+                name: rte.name.clone(),
+                description: rte.desc.clone(),
+                geometry: Some(Geometry::LineString(
+                    rte.waypoints.iter().map(coordinate).collect(),
+                )),
+                ..Default::default()
+            });
+        }
+        for trk in &gpx.trk {
+            let mut segments: Vec<Vec<TrackPoint>> = trk
+                .trkseg
+                .iter()
+                .map(|seg| {
+                    seg.track_point
+                        .iter()
+                        .map(|wpt| TrackPoint {
+                            coordinate: coordinate(wpt),
+                            when: wpt.time,
+                        })
+                        .collect()
+                })
+                .collect();
+            let geometry = if segments.len() == 1 {
+                Geometry::Track(segments.pop().unwrap_or_default())
+            } else {
+                Geometry::MultiTrack(segments)
+            };
+            kml.placemarks.push(Placemark {
+                name: trk.name.clone(),
+                description: trk.desc.clone(),
+                geometry: Some(geometry),
+                ..Default::default()
+            });
+        }
+        kml
+    }
+}
+
+impl Kml {
+    ///
+    /// Converts the placemarks into GPX waypoints, routes and tracks.  Placemarks without a
+    /// geometry are dropped.
+    #[must_use]
+    pub fn to_gpx(&self) -> GPX {
+        let mut gpx = GPX::new();
+        if self.name.is_some() || self.description.is_some() {
+            gpx.metadata = Some(Metadata {
+                name: self.name.clone(),
+                desc: self.description.clone(),
+                ..Default::default()
+            });
+        }
+        for placemark in &self.placemarks {
+            if let Some(geometry) = &placemark.geometry {
+                add_geometry(&mut gpx, placemark, geometry);
+            }
+        }
+        gpx
+    }
+}
+
+fn add_geometry(gpx: &mut GPX, placemark: &Placemark, geometry: &Geometry) {
+    let route = |points: &[Coordinate]| Route {
+        name: placemark.name.clone(),
+        desc: placemark.description.clone(),
+        waypoints: points.iter().map(waypoint).collect(),
+        ..Default::default()
+    };
+    let track = |segments: &[Vec<TrackPoint>]| Track {
+        name: placemark.name.clone(),
+        desc: placemark.description.clone(),
+        trkseg: segments
+            .iter()
+            .map(|points| TrackSegment {
+                track_point: points.iter().map(track_point).collect(),
+                ..Default::default()
+            })
+            .collect(),
+        ..Default::default()
+    };
+    match geometry {
+        Geometry::Point(point) => {
+            let mut wpt = waypoint(point);
+            wpt.name.clone_from(&placemark.name);
+            wpt.desc.clone_from(&placemark.description);
+            wpt.time = placemark.time;
+            wpt.sym = placemark
+                .style_url
+                .as_ref()
+                .map(|url| url.trim_start_matches('#').to_string());
+            gpx.wpt.push(wpt);
+        }
+        Geometry::LineString(points) => gpx.rte.push(route(points)),
+        Geometry::Polygon { outer, .. } => gpx.rte.push(route(outer)),
+        Geometry::MultiGeometry(geometries) => {
+            for geometry in geometries {
+                add_geometry(gpx, placemark, geometry);
+            }
+        }
+        Geometry::Track(points) => gpx.trk.push(track(std::slice::from_ref(points))),
+        Geometry::MultiTrack(segments) => gpx.trk.push(track(segments)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use irox_carto::coordinate::{Latitude, Longitude};
+    use irox_gpx::{Route, Track, TrackSegment, Waypoint, GPX};
+    use irox_time::datetime::UTCDateTime;
+    use irox_units::units::angle::Angle;
+
+    use crate::error::Error;
+    use crate::{Color, Geometry, Kml, KmlWriter};
+
+    const SAMPLE: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<kml xmlns="http://www.opengis.net/kml/2.2" xmlns:gx="http://www.google.com/kml/ext/2.2">
+<Document>
+    <name>Sample</name>
+    <Style id="flag">
+        <IconStyle><color>ff0000ff</color><Icon><href>flag.png</href></Icon></IconStyle>
+        <LineStyle><color>7f00ff00</color><width>4</width></LineStyle>
+    </Style>
+    <Folder>
+        <name>Nested</name>
+        <Placemark>
+            <name>Summit</name>
+            <TimeStamp><when>2023-06-01T12:00:00Z</when></TimeStamp>
+            <styleUrl>#flag</styleUrl>
+            <Point><coordinates>-71.5,42.25,120.5</coordinates></Point>
+        </Placemark>
+        <Placemark>
+            <name>Walk</name>
+            <gx:Track>
+                <when>2023-06-01T12:00:00Z</when>
+                <when>2023-06-01T12:00:01.5Z</when>
+                <gx:coord>-71.5 42.25 120.5</gx:coord>
+                <gx:coord>-71.25 42.5 121</gx:coord>
+            </gx:Track>
+        </Placemark>
+        <Placemark>
+            <name>Field</name>
+            <Polygon><outerBoundaryIs><LinearRing>
+                <coordinates>0,0 1,0 1,1 0,0</coordinates>
+            </LinearRing></outerBoundaryIs></Polygon>
+        </Placemark>
+    </Folder>
+</Document>
+</kml>"##;
+
+    fn point(lat: f64, lon: f64, ele: f64) -> Waypoint {
+        let mut wpt = Waypoint::new(
+            Latitude(Angle::new_degrees(lat)),
+            Longitude(Angle::new_degrees(lon)),
+        );
+        wpt.ele = Some(ele);
+        wpt
+    }
+
+    fn missing() -> Error {
+        Error::new(crate::error::ErrorType::ParseError, "missing".to_string())
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_read() -> Result<(), Error> {
+        let kml = Kml::read_from(SAMPLE.as_bytes())?;
+        assert_eq!(Some("Sample"), kml.name.as_deref());
+
+        let [style] = kml.styles.as_slice() else {
+            return Err(missing());
+        };
+        assert_eq!("flag", style.id);
+        assert_eq!(Some("flag.png"), style.icon_href.as_deref());
+        assert_eq!(
+            Some(Color {
+                alpha: 0xFF,
+                blue: 0,
+                green: 0,
+                red: 0xFF
+            }),
+            style.icon_color
+        );
+        assert_eq!(Some(4.0), style.line_width);
+
+        let [summit, walk, field] = kml.placemarks.as_slice() else {
+            return Err(missing());
+        };
+        assert_eq!(Some("#flag"), summit.style_url.as_deref());
+        let Some(Geometry::Point(coord)) = &summit.geometry else {
+            return Err(missing());
+        };
+        assert_eq!(Some(120.5), coord.altitude);
+        assert!((coord.longitude.0.as_degrees().value() + 71.5).abs() < 1e-9);
+
+        let Some(Geometry::Track(points)) = &walk.geometry else {
+            return Err(missing());
+        };
+        assert_eq!(2, points.len());
+        assert!(points.iter().all(|p| p.when.is_some()));
+        assert!(matches!(field.geometry, Some(Geometry::Polygon { .. })));
+
+        let gpx = kml.to_gpx();
+        assert_eq!(1, gpx.wpt.len());
+        assert_eq!(1, gpx.trk.len());
+        assert_eq!(1, gpx.rte.len());
+        assert_eq!(Some("flag"), gpx.wpt.first().and_then(|w| w.sym.as_deref()));
+        Ok(())
+    }
+
+    fn time(text: &str) -> Result<UTCDateTime, Error> {
+        UTCDateTime::try_from_iso8601(text)
+            .map_err(|e| Error::new(crate::error::ErrorType::ParseError, e.to_string()))
+    }
+
+    #[test]
+    pub fn test_errors() {
+        let bad = SAMPLE.replace("-71.5,42.25,120.5", "-71.5;42.25");
+        let err = Kml::read_from(bad.as_bytes()).err();
+        assert_eq!(Some(15), err.and_then(|e| e.line()));
+        assert!(Kml::read_from("<gpx/>".as_bytes()).is_err());
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_round_trip() -> Result<(), Error> {
+        let t0 = time("2023-06-01T12:00:00Z")?;
+        let t1 = time("2023-06-01T12:00:01.5Z")?;
+        let mut gpx = GPX::new();
+        let mut wpt = point(42.25, -71.5, 120.5);
+        wpt.name = Some("Summit".to_string());
+        wpt.time = Some(t0);
+        wpt.sym = Some("Flag".to_string());
+        gpx.wpt.push(wpt);
+        gpx.rte.push(Route {
+            name: Some("Route".to_string()),
+            waypoints: vec![point(1.0, 2.0, 3.0), point(4.0, 5.0, 6.0)],
+            ..Default::default()
+        });
+        let mut p0 = point(42.25, -71.5, 120.5);
+        p0.time = Some(t0);
+        let mut p1 = point(42.5, -71.25, 121.0);
+        p1.time = Some(t1);
+        gpx.trk.push(Track {
+            name: Some("Walk".to_string()),
+            trkseg: vec![TrackSegment {
+                track_point: vec![p0, p1],
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        let kml = Kml::from(&gpx);
+        let mut xml = Vec::new();
+        KmlWriter::new().write_to(&kml, &mut xml)?;
+        let kmz = KmlWriter::new().write_kmz(&kml)?;
+
+        for read in [Kml::read_from(xml.as_slice())?, Kml::read_kmz(&kmz)?] {
+            let out = read.to_gpx();
+            let [wpt] = out.wpt.as_slice() else {
+                return Err(missing());
+            };
+            assert_eq!(Some("Summit"), wpt.name.as_deref());
+            assert_eq!(Some(t0), wpt.time);
+            assert_eq!(Some(120.5), wpt.ele);
+            assert_eq!(Some("Flag"), wpt.sym.as_deref());
+
+            let [rte] = out.rte.as_slice() else {
+                return Err(missing());
+            };
+            assert_eq!(Some("Route"), rte.name.as_deref());
+            let elevations: Vec<_> = rte.waypoints.iter().map(|w| w.ele).collect();
+            assert_eq!(vec![Some(3.0), Some(6.0)], elevations);
+
+            let [trk] = out.trk.as_slice() else {
+                return Err(missing());
+            };
+            assert_eq!(Some("Walk"), trk.name.as_deref());
+            let [seg] = trk.trkseg.as_slice() else {
+                return Err(missing());
+            };
+            let times: Vec<_> = seg.track_point.iter().map(|w| w.time).collect();
+            assert_eq!(vec![Some(t0), Some(t1)], times);
+            let elevations: Vec<_> = seg.track_point.iter().map(|w| w.ele).collect();
+            assert_eq!(vec![Some(120.5), Some(121.0)], elevations);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use std::fmt::{Display, Formatter};
+
+use irox_bits::BitsError;
+use xml::common::{Position, TextPosition};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ErrorType {
+    IOError,
+    XMLError,
+    /// The document is well-formed XML, but isn't valid KML
+    ParseError,
+    /// The KMZ archive couldn't be read or written
+    ZipError,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Error {
+    error_type: ErrorType,
+    msg: String,
+    position: Option<TextPosition>,
+}
+
+impl Error {
+    pub fn new(error_type: ErrorType, msg: String) -> Error {
+        Error {
+            error_type,
+            msg,
+            position: None,
+        }
+    }
+
+    /// A [`ErrorType::ParseError`] at the specified position within the document
+    pub fn parse_at(msg: String, position: TextPosition) -> Error {
+        Error {
+            error_type: ErrorType::ParseError,
+            msg,
+            position: Some(position),
+        }
+    }
+
+    pub fn error_type(&self) -> ErrorType {
+        self.error_type
+    }
+
+    /// The 1-based line number in the document where the error occurred, if known
+    pub fn line(&self) -> Option<u64> {
+        self.position.map(|p| p.row + 1)
+    }
+
+    /// The 1-based column number in the document where the error occurred, if known
+    pub fn column(&self) -> Option<u64> {
+        self.position.map(|p| p.column + 1)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let (Some(line), Some(column)) = (self.line(), self.column()) {
+            return f.write_fmt(format_args!(
+                "KMLError({:?}) at {line}:{column}: {}",
+                self.error_type, self.msg
+            ));
+        }
+        f.write_fmt(format_args!(
+            "KMLError({:?}): {}",
+            self.error_type, self.msg
+        ))
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Error::new(ErrorType::IOError, value.to_string())
+    }
+}
+
+impl From<xml::writer::Error> for Error {
+    fn from(value: xml::writer::Error) -> Self {
+        Error::new(ErrorType::XMLError, value.to_string())
+    }
+}
+
+impl From<xml::reader::Error> for Error {
+    fn from(value: xml::reader::Error) -> Self {
+        Error {
+            error_type: ErrorType::XMLError,
+            msg: value.msg().to_string(),
+            position: Some(value.position()),
+        }
+    }
+}
+
+impl From<BitsError> for Error {
+    fn from(value: BitsError) -> Self {
+        Error::new(ErrorType::ZipError, value.to_string())
+    }
+}
@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! KML 2.2 & KMZ encoders and decoders.
+//!
+//! Documents are read into a flat [`Kml`] model of [`Placemark`]s and [`Style`]s (placemarks
+//! nested in `Folder`s are collected in document order), which converts to & from the
+//! [`irox_gpx::GPX`] waypoint/route/track model:
+//!
+//! | GPX           | KML                                                  |
+//! |---------------|------------------------------------------------------|
+//! | `wpt`         | `Placemark` with a `Point`, `sym` as the `styleUrl`  |
+//! | `rte`         | `Placemark` with a `LineString`                      |
+//! | `trk`         | `Placemark` with a `gx:Track`, or a `gx:MultiTrack`  |
+//!
+//! Polygons convert to a route around their outer boundary, and each geometry of a
+//! `MultiGeometry` converts separately.
+
+#![forbid(unsafe_code)]
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use irox_carto::coordinate::{Latitude, Longitude};
+use irox_time::datetime::UTCDateTime;
+use irox_units::units::angle::Angle;
+
+pub use writer::*;
+
+mod convert;
+pub mod error;
+mod reader;
+mod writer;
+
+/// The KML 2.2 namespace
+pub const NAMESPACE: &str = "http://www.opengis.net/kml/2.2";
+/// The Google extensions namespace, for `gx:Track`
+pub const GX_NAMESPACE: &str = "http://www.google.com/kml/ext/2.2";
+
+///
+/// A KML document
+#[derive(Default)]
+pub struct Kml {
+    /// The name of the document
+    pub name: Option<String>,
+
+    /// A description of the document
+    pub description: Option<String>,
+
+    /// The shared styles, referenced by [`Placemark::style_url`]
+    pub styles: Vec<Style>,
+
+    /// The placemarks of the document and any folders within it
+    pub placemarks: Vec<Placemark>,
+}
+
+///
+/// A feature with an associated geometry
+#[derive(Default)]
+pub struct Placemark {
+    pub name: Option<String>,
+
+    pub description: Option<String>,
+
+    /// The `TimeStamp` of the feature
+    pub time: Option<UTCDateTime>,
+
+    /// Reference to a [`Style`], usually `#<style id>`
+    pub style_url: Option<String>,
+
+    pub geometry: Option<Geometry>,
+}
+
+///
+/// The geometries a [`Placemark`] can hold
+pub enum Geometry {
+    Point(Coordinate),
+    LineString(Vec<Coordinate>),
+    Polygon {
+        /// The outer boundary
+        outer: Vec<Coordinate>,
+        /// Any holes within the outer boundary
+        inner: Vec<Vec<Coordinate>>,
+    },
+    MultiGeometry(Vec<Geometry>),
+    /// `gx:Track`, a series of timestamped positions
+    Track(Vec<TrackPoint>),
+    /// `gx:MultiTrack`, a set of tracks
+    MultiTrack(Vec<Vec<TrackPoint>>),
+}
+
+///
+/// A single position, always in WGS84
+#[derive(Copy, Clone)]
+pub struct Coordinate {
+    pub latitude: Latitude,
+    pub longitude: Longitude,
+    /// Altitude in meters
+    pub altitude: Option<f64>,
+}
+
+impl Coordinate {
+    #[must_use]
+    pub fn new(latitude: Latitude, longitude: Longitude, altitude: Option<f64>) -> Coordinate {
+        Coordinate {
+            latitude,
+            longitude,
+            altitude,
+        }
+    }
+
+    /// Parses a single `lon,lat[,alt]` tuple, as found in `coordinates` elements
+    pub(crate) fn parse_tuple(tuple: &str) -> Option<Coordinate> {
+        let mut parts = tuple.split(',');
+        let lon = f64::from_str(parts.next()?.trim()).ok()?;
+        let lat = f64::from_str(parts.next()?.trim()).ok()?;
+        let alt = match parts.next() {
+            Some(alt) => Some(f64::from_str(alt.trim()).ok()?),
+            None => None,
+        };
+        Some(Coordinate::new(
+            Latitude(Angle::new_degrees(lat)),
+            Longitude(Angle::new_degrees(lon)),
+            alt,
+        ))
+    }
+}
+
+impl Display for Coordinate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let lon = self.longitude.0.as_degrees().value();
+        let lat = self.latitude.0.as_degrees().value();
+        match self.altitude {
+            Some(alt) => write!(f, "{lon},{lat},{alt}"),
+            None => write!(f, "{lon},{lat}"),
+        }
+    }
+}
+
+///
+/// A position in a `gx:Track`
+#[derive(Copy, Clone)]
+pub struct TrackPoint {
+    pub coordinate: Coordinate,
+    pub when: Option<UTCDateTime>,
+}
+
+///
+/// A shared style, referenced from placemarks by `#id`
+#[derive(Default, Clone)]
+pub struct Style {
+    pub id: String,
+
+    /// `IconStyle/Icon/href`
+    pub icon_href: Option<String>,
+
+    /// `IconStyle/color`
+    pub icon_color: Option<Color>,
+
+    /// `LineStyle/color`
+    pub line_color: Option<Color>,
+
+    /// `LineStyle/width`, in pixels
+    pub line_width: Option<f64>,
+
+    /// `PolyStyle/color`
+    pub poly_color: Option<Color>,
+}
+
+///
+/// A KML color, written as hex `aabbggrr`
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct Color {
+    pub alpha: u8,
+    pub blue: u8,
+    pub green: u8,
+    pub red: u8,
+}
+
+impl FromStr for Color {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [alpha, blue, green, red] = u32::from_str_radix(s.trim(), 16)?.to_be_bytes();
+        Ok(Color {
+            alpha,
+            blue,
+            green,
+            red,
+        })
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:02x}{:02x}{:02x}{:02x}",
+            self.alpha, self.blue, self.green, self.red
+        )
+    }
+}
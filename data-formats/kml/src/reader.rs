@@ -0,0 +1,410 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use std::io::Read;
+
+use log::warn;
+use xml::attribute::OwnedAttribute;
+use xml::common::{Position, TextPosition};
+use xml::name::OwnedName;
+use xml::reader::{EventReader, ParserConfig, XmlEvent};
+
+use irox_carto::coordinate::{Latitude, Longitude};
+use irox_time::datetime::UTCDateTime;
+use irox_units::units::angle::Angle;
+use irox_zip::archive::ZipArchive;
+
+use crate::error::{Error, ErrorType};
+use crate::{Color, Coordinate, Geometry, Kml, Placemark, Style, TrackPoint};
+
+impl Kml {
+    ///
+    /// Reads a KML document from the provided input.
+    pub fn read_from<T: Read>(input: T) -> Result<Kml, Error> {
+        let config = ParserConfig::new()
+            .trim_whitespace(true)
+            .cdata_to_characters(true);
+        let mut reader = KmlReader {
+            events: EventReader::new_with_config(input, config),
+        };
+        loop {
+            match reader.events.next()? {
+                XmlEvent::StartElement {
+                    name, attributes, ..
+                } => {
+                    let root = reader.element(name, attributes);
+                    if root.local() != "kml" {
+                        return Err(root.error(format!(
+                            "Expected a <kml> root element, found <{}>",
+                            root.local()
+                        )));
+                    }
+                    let mut kml = Kml::default();
+                    reader.read_container(&mut kml, true)?;
+                    return Ok(kml);
+                }
+                XmlEvent::EndDocument => {
+                    return Err(Error::parse_at(
+                        "Document has no <kml> root element".to_string(),
+                        reader.events.position(),
+                    ))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    ///
+    /// Reads a KMZ archive.  The document is `doc.kml` if present, otherwise the first `.kml`
+    /// file in the archive.
+    pub fn read_kmz(data: &[u8]) -> Result<Kml, Error> {
+        let archive = ZipArchive::new(data)?;
+        let entry = archive.find("doc.kml").or_else(|| {
+            archive
+                .entries()
+                .iter()
+                .find(|e| e.name.to_ascii_lowercase().ends_with(".kml"))
+        });
+        let Some(entry) = entry else {
+            return Err(Error::new(
+                ErrorType::ZipError,
+                "KMZ archive contains no .kml document".to_string(),
+            ));
+        };
+        let doc = archive.read_entry(entry)?;
+        Kml::read_from(doc.as_slice())
+    }
+}
+
+///
+/// A started element, and where it started in the document.
+struct Element {
+    name: OwnedName,
+    attributes: Vec<OwnedAttribute>,
+    position: TextPosition,
+}
+
+impl Element {
+    fn local(&self) -> &str {
+        self.name.local_name.as_str()
+    }
+
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|a| a.name.local_name == name)
+            .map(|a| a.value.as_str())
+    }
+
+    fn error(&self, msg: String) -> Error {
+        Error::parse_at(msg, self.position)
+    }
+}
+
+struct KmlReader<T: Read> {
+    events: EventReader<T>,
+}
+
+impl<T: Read> KmlReader<T> {
+    fn element(&self, name: OwnedName, attributes: Vec<OwnedAttribute>) -> Element {
+        Element {
+            name,
+            attributes,
+            position: self.events.position(),
+        }
+    }
+
+    ///
+    /// Returns the next child element of the current element, or [`None`] once the current
+    /// element has ended.  Text between child elements is ignored.
+    fn next_child(&mut self) -> Result<Option<Element>, Error> {
+        loop {
+            match self.events.next()? {
+                XmlEvent::StartElement {
+                    name, attributes, ..
+                } => return Ok(Some(self.element(name, attributes))),
+                XmlEvent::EndElement { .. } => return Ok(None),
+                XmlEvent::EndDocument => return Err(self.unexpected_end()),
+                _ => {}
+            }
+        }
+    }
+
+    fn unexpected_end(&self) -> Error {
+        Error::parse_at(
+            "Unexpected end of document".to_string(),
+            self.events.position(),
+        )
+    }
+
+    ///
+    /// Reads the text content of the current element, skipping any nested elements.
+    fn text(&mut self) -> Result<String, Error> {
+        let mut out = String::new();
+        let mut depth = 0_usize;
+        loop {
+            match self.events.next()? {
+                XmlEvent::StartElement { .. } => depth += 1,
+                XmlEvent::EndElement { .. } => {
+                    if depth == 0 {
+                        return Ok(out);
+                    }
+                    depth -= 1;
+                }
+                XmlEvent::Characters(text) if depth == 0 => out.push_str(&text),
+                XmlEvent::EndDocument => return Err(self.unexpected_end()),
+                _ => {}
+            }
+        }
+    }
+
+    fn skip(&mut self) -> Result<(), Error> {
+        self.text().map(|_| ())
+    }
+
+    fn time(&mut self) -> Result<Option<UTCDateTime>, Error> {
+        let text = self.text()?;
+        // KML permits reduced precision times like `2007-01`, which can't be represented.
+        match UTCDateTime::try_from_iso8601(&text) {
+            Ok(time) => Ok(Some(time)),
+            Err(e) => {
+                warn!("Ignoring unsupported KML time '{text}': {e}");
+                Ok(None)
+            }
+        }
+    }
+
+    ///
+    /// Reads the contents of a `kml`, `Document` or `Folder` element.  Only the outermost
+    /// name and description are kept.
+    fn read_container(&mut self, kml: &mut Kml, outermost: bool) -> Result<(), Error> {
+        while let Some(child) = self.next_child()? {
+            match child.local() {
+                "Document" | "Folder" => {
+                    self.read_container(kml, outermost && kml.name.is_none())?
+                }
+                "name" if outermost && kml.name.is_none() => kml.name = Some(self.text()?),
+                "description" if outermost && kml.description.is_none() => {
+                    kml.description = Some(self.text()?);
+                }
+                "Style" => kml.styles.push(self.read_style(&child)?),
+                "Placemark" => kml.placemarks.push(self.read_placemark()?),
+                _ => self.skip()?,
+            }
+        }
+        Ok(())
+    }
+
+    fn read_style(&mut self, element: &Element) -> Result<Style, Error> {
+        let mut style = Style {
+            id: element.attr("id").unwrap_or_default().to_string(),
+            ..Default::default()
+        };
+        while let Some(child) = self.next_child()? {
+            match child.local() {
+                "IconStyle" => {
+                    while let Some(icon) = self.next_child()? {
+                        match icon.local() {
+                            "color" => style.icon_color = Some(self.color(&icon)?),
+                            "Icon" => {
+                                while let Some(href) = self.next_child()? {
+                                    if href.local() == "href" {
+                                        style.icon_href = Some(self.text()?);
+                                    } else {
+                                        self.skip()?;
+                                    }
+                                }
+                            }
+                            _ => self.skip()?,
+                        }
+                    }
+                }
+                "LineStyle" => {
+                    while let Some(line) = self.next_child()? {
+                        match line.local() {
+                            "color" => style.line_color = Some(self.color(&line)?),
+                            "width" => {
+                                let text = self.text()?;
+                                style.line_width = Some(text.trim().parse().map_err(|e| {
+                                    line.error(format!("Invalid line width '{text}': {e}"))
+                                })?);
+                            }
+                            _ => self.skip()?,
+                        }
+                    }
+                }
+                "PolyStyle" => {
+                    while let Some(poly) = self.next_child()? {
+                        if poly.local() == "color" {
+                            style.poly_color = Some(self.color(&poly)?);
+                        } else {
+                            self.skip()?;
+                        }
+                    }
+                }
+                _ => self.skip()?,
+            }
+        }
+        Ok(style)
+    }
+
+    fn color(&mut self, element: &Element) -> Result<Color, Error> {
+        let text = self.text()?;
+        text.parse()
+            .map_err(|e| element.error(format!("Invalid color '{text}': {e}")))
+    }
+
+    fn read_placemark(&mut self) -> Result<Placemark, Error> {
+        let mut placemark = Placemark::default();
+        while let Some(child) = self.next_child()? {
+            match child.local() {
+                "name" => placemark.name = Some(self.text()?),
+                "description" => placemark.description = Some(self.text()?),
+                "styleUrl" => placemark.style_url = Some(self.text()?),
+                "TimeStamp" => {
+                    while let Some(when) = self.next_child()? {
+                        if when.local() == "when" {
+                            placemark.time = self.time()?;
+                        } else {
+                            self.skip()?;
+                        }
+                    }
+                }
+                _ => {
+                    if let Some(geometry) = self.read_geometry(&child)? {
+                        placemark.geometry = Some(geometry);
+                    }
+                }
+            }
+        }
+        Ok(placemark)
+    }
+
+    ///
+    /// Reads the geometry element, or skips it and returns [`None`] if it isn't a geometry.
+    fn read_geometry(&mut self, element: &Element) -> Result<Option<Geometry>, Error> {
+        Ok(Some(match element.local() {
+            "Point" => {
+                let coordinates = self.read_coordinates_of()?;
+                let Some(point) = coordinates.first() else {
+                    return Err(element.error("Point has no coordinates".to_string()));
+                };
+                Geometry::Point(*point)
+            }
+            "LineString" | "LinearRing" => Geometry::LineString(self.read_coordinates_of()?),
+            "Polygon" => {
+                let mut outer = Vec::new();
+                let mut inner = Vec::new();
+                while let Some(child) = self.next_child()? {
+                    match child.local() {
+                        "outerBoundaryIs" => outer = self.read_ring()?,
+                        "innerBoundaryIs" => inner.push(self.read_ring()?),
+                        _ => self.skip()?,
+                    }
+                }
+                Geometry::Polygon { outer, inner }
+            }
+            "MultiGeometry" => {
+                let mut geometries = Vec::new();
+                while let Some(child) = self.next_child()? {
+                    if let Some(geometry) = self.read_geometry(&child)? {
+                        geometries.push(geometry);
+                    }
+                }
+                Geometry::MultiGeometry(geometries)
+            }
+            "Track" => Geometry::Track(self.read_track(element)?),
+            "MultiTrack" => {
+                let mut tracks = Vec::new();
+                while let Some(child) = self.next_child()? {
+                    if child.local() == "Track" {
+                        tracks.push(self.read_track(&child)?);
+                    } else {
+                        self.skip()?;
+                    }
+                }
+                Geometry::MultiTrack(tracks)
+            }
+            _ => {
+                self.skip()?;
+                return Ok(None);
+            }
+        }))
+    }
+
+    /// Reads the `coordinates` child of the current element
+    fn read_coordinates_of(&mut self) -> Result<Vec<Coordinate>, Error> {
+        let mut out = Vec::new();
+        while let Some(child) = self.next_child()? {
+            if child.local() != "coordinates" {
+                self.skip()?;
+                continue;
+            }
+            for tuple in self.text()?.split_whitespace() {
+                let Some(coord) = Coordinate::parse_tuple(tuple) else {
+                    return Err(child.error(format!("Invalid coordinate '{tuple}'")));
+                };
+                out.push(coord);
+            }
+        }
+        Ok(out)
+    }
+
+    /// Reads the `LinearRing` within a polygon boundary
+    fn read_ring(&mut self) -> Result<Vec<Coordinate>, Error> {
+        let mut out = Vec::new();
+        while let Some(child) = self.next_child()? {
+            if child.local() == "LinearRing" {
+                out = self.read_coordinates_of()?;
+            } else {
+                self.skip()?;
+            }
+        }
+        Ok(out)
+    }
+
+    ///
+    /// Reads a `gx:Track`.  The `when` elements are paired with the `gx:coord` elements in
+    /// order, times are dropped if the counts differ.
+    fn read_track(&mut self, element: &Element) -> Result<Vec<TrackPoint>, Error> {
+        let mut whens = Vec::new();
+        let mut coords = Vec::new();
+        while let Some(child) = self.next_child()? {
+            match child.local() {
+                "when" => whens.push(self.time()?),
+                "coord" => {
+                    let text = self.text()?;
+                    let mut parts = text.split_whitespace().map(str::parse::<f64>);
+                    let (Some(Ok(lon)), Some(Ok(lat))) = (parts.next(), parts.next()) else {
+                        return Err(child.error(format!("Invalid gx:coord '{text}'")));
+                    };
+                    let alt = parts.next().and_then(Result::ok);
+                    coords.push(Coordinate::new(
+                        Latitude(Angle::new_degrees(lat)),
+                        Longitude(Angle::new_degrees(lon)),
+                        alt,
+                    ));
+                }
+                _ => self.skip()?,
+            }
+        }
+        if !whens.is_empty() && whens.len() != coords.len() {
+            warn!(
+                "gx:Track at {} has {} times for {} coordinates, ignoring the times",
+                element.position,
+                whens.len(),
+                coords.len()
+            );
+            whens.clear();
+        }
+        let mut whens = whens.into_iter();
+        Ok(coords
+            .into_iter()
+            .map(|coordinate| TrackPoint {
+                coordinate,
+                when: whens.next().flatten(),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use std::borrow::Cow;
+use std::io::Write;
+
+use xml::common::XmlVersion;
+use xml::writer::XmlEvent;
+use xml::{EmitterConfig, EventWriter};
+
+use irox_zip::archive::ZipWriter;
+
+use crate::error::Error;
+use crate::{Coordinate, Geometry, Kml, Placemark, Style, TrackPoint};
+
+#[derive(Default, Copy, Clone)]
+pub struct KmlWriter {
+    pub pretty_print: bool,
+}
+
+macro_rules! maybe_write_val {
+    ($writer:ident, $name:literal, $value:expr) => {
+        if let Some(value) = $value {
+            $writer.write(XmlEvent::start_element($name))?;
+            $writer.write(XmlEvent::characters(format!("{}", value).as_str()))?;
+            $writer.write(XmlEvent::end_element())?;
+        }
+    };
+}
+
+impl KmlWriter {
+    pub fn new() -> KmlWriter {
+        KmlWriter::default()
+    }
+
+    pub fn write_to<T: Write>(&self, data: &Kml, out: &mut T) -> Result<(), Error> {
+        let config = EmitterConfig {
+            perform_indent: self.pretty_print,
+            indent_string: Cow::Borrowed("\t"),
+            ..EmitterConfig::new()
+        };
+        let mut writer = config.create_writer(out);
+
+        writer.write(XmlEvent::StartDocument {
+            version: XmlVersion::Version10,
+            encoding: Some("UTF-8"),
+            standalone: None,
+        })?;
+        writer.write(
+            XmlEvent::start_element("kml")
+                .default_ns(crate::NAMESPACE)
+                .ns("gx", crate::GX_NAMESPACE),
+        )?;
+        writer.write(XmlEvent::start_element("Document"))?;
+        maybe_write_val!(writer, "name", &data.name);
+        maybe_write_val!(writer, "description", &data.description);
+        for style in &data.styles {
+            KmlWriter::write_style(&mut writer, style)?;
+        }
+        for placemark in &data.placemarks {
+            KmlWriter::write_placemark(&mut writer, placemark)?;
+        }
+        writer.write(XmlEvent::end_element())?;
+        writer.write(XmlEvent::end_element())?;
+        Ok(())
+    }
+
+    ///
+    /// Writes the document as a KMZ archive, containing a single `doc.kml`
+    pub fn write_kmz(&self, data: &Kml) -> Result<Vec<u8>, Error> {
+        let mut doc = Vec::new();
+        self.write_to(data, &mut doc)?;
+        let mut zip = ZipWriter::new(Vec::new());
+        zip.add_file("doc.kml", &doc)?;
+        Ok(zip.finish()?)
+    }
+
+    fn write_style<T: Write>(writer: &mut EventWriter<T>, style: &Style) -> Result<(), Error> {
+        writer.write(XmlEvent::start_element("Style").attr("id", &style.id))?;
+        if style.icon_color.is_some() || style.icon_href.is_some() {
+            writer.write(XmlEvent::start_element("IconStyle"))?;
+            maybe_write_val!(writer, "color", &style.icon_color);
+            if let Some(href) = &style.icon_href {
+                writer.write(XmlEvent::start_element("Icon"))?;
+                maybe_write_val!(writer, "href", Some(href));
+                writer.write(XmlEvent::end_element())?;
+            }
+            writer.write(XmlEvent::end_element())?;
+        }
+        if style.line_color.is_some() || style.line_width.is_some() {
+            writer.write(XmlEvent::start_element("LineStyle"))?;
+            maybe_write_val!(writer, "color", &style.line_color);
+            maybe_write_val!(writer, "width", &style.line_width);
+            writer.write(XmlEvent::end_element())?;
+        }
+        if style.poly_color.is_some() {
+            writer.write(XmlEvent::start_element("PolyStyle"))?;
+            maybe_write_val!(writer, "color", &style.poly_color);
+            writer.write(XmlEvent::end_element())?;
+        }
+        writer.write(XmlEvent::end_element())?;
+        Ok(())
+    }
+
+    fn write_placemark<T: Write>(
+        writer: &mut EventWriter<T>,
+        placemark: &Placemark,
+    ) -> Result<(), Error> {
+        writer.write(XmlEvent::start_element("Placemark"))?;
+        maybe_write_val!(writer, "name", &placemark.name);
+        maybe_write_val!(writer, "description", &placemark.description);
+        if let Some(time) = &placemark.time {
+            writer.write(XmlEvent::start_element("TimeStamp"))?;
+            maybe_write_val!(writer, "when", Some(time.format_iso8601_extended()));
+            writer.write(XmlEvent::end_element())?;
+        }
+        maybe_write_val!(writer, "styleUrl", &placemark.style_url);
+        if let Some(geometry) = &placemark.geometry {
+            KmlWriter::write_geometry(writer, geometry)?;
+        }
+        writer.write(XmlEvent::end_element())?;
+        Ok(())
+    }
+
+    fn write_geometry<T: Write>(
+        writer: &mut EventWriter<T>,
+        geometry: &Geometry,
+    ) -> Result<(), Error> {
+        match geometry {
+            Geometry::Point(point) => {
+                writer.write(XmlEvent::start_element("Point"))?;
+                KmlWriter::write_coordinates(writer, std::slice::from_ref(point))?;
+                writer.write(XmlEvent::end_element())?;
+            }
+            Geometry::LineString(points) => {
+                writer.write(XmlEvent::start_element("LineString"))?;
+                KmlWriter::write_coordinates(writer, points)?;
+                writer.write(XmlEvent::end_element())?;
+            }
+            Geometry::Polygon { outer, inner } => {
+                writer.write(XmlEvent::start_element("Polygon"))?;
+                KmlWriter::write_ring(writer, "outerBoundaryIs", outer)?;
+                for ring in inner {
+                    KmlWriter::write_ring(writer, "innerBoundaryIs", ring)?;
+                }
+                writer.write(XmlEvent::end_element())?;
+            }
+            Geometry::MultiGeometry(geometries) => {
+                writer.write(XmlEvent::start_element("MultiGeometry"))?;
+                for geometry in geometries {
+                    KmlWriter::write_geometry(writer, geometry)?;
+                }
+                writer.write(XmlEvent::end_element())?;
+            }
+            Geometry::Track(points) => KmlWriter::write_track(writer, points)?,
+            Geometry::MultiTrack(tracks) => {
+                writer.write(XmlEvent::start_element("gx:MultiTrack"))?;
+                for points in tracks {
+                    KmlWriter::write_track(writer, points)?;
+                }
+                writer.write(XmlEvent::end_element())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_coordinates<T: Write>(
+        writer: &mut EventWriter<T>,
+        points: &[Coordinate],
+    ) -> Result<(), Error> {
+        let text = points
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" ");
+        maybe_write_val!(writer, "coordinates", Some(text));
+        Ok(())
+    }
+
+    fn write_ring<T: Write>(
+        writer: &mut EventWriter<T>,
+        boundary: &str,
+        points: &[Coordinate],
+    ) -> Result<(), Error> {
+        writer.write(XmlEvent::start_element(boundary))?;
+        writer.write(XmlEvent::start_element("LinearRing"))?;
+        KmlWriter::write_coordinates(writer, points)?;
+        writer.write(XmlEvent::end_element())?;
+        writer.write(XmlEvent::end_element())?;
+        Ok(())
+    }
+
+    ///
+    /// Writes a `gx:Track`.  The `when` elements are only written if every point has a time, as
+    /// readers pair them with the coordinates by position.
+    fn write_track<T: Write>(
+        writer: &mut EventWriter<T>,
+        points: &[TrackPoint],
+    ) -> Result<(), Error> {
+        writer.write(XmlEvent::start_element("gx:Track"))?;
+        if points.iter().all(|p| p.when.is_some()) {
+            for point in points {
+                maybe_write_val!(
+                    writer,
+                    "when",
+                    point.when.map(|w| w.format_iso8601_extended())
+                );
+            }
+        }
+        for point in points {
+            let coord = &point.coordinate;
+            let lon = coord.longitude.0.as_degrees().value();
+            let lat = coord.latitude.0.as_degrees().value();
+            let text = match coord.altitude {
+                Some(alt) => format!("{lon} {lat} {alt}"),
+                None => format!("{lon} {lat}"),
+            };
+            maybe_write_val!(writer, "gx:coord", Some(text));
+        }
+        writer.write(XmlEvent::end_element())?;
+        Ok(())
+    }
+}
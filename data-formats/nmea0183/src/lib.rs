@@ -11,6 +11,7 @@ use irox_carto::altitude::{Altitude, AltitudeReferenceFrame};
 use irox_carto::coordinate::{Latitude, Longitude};
 use irox_time::gregorian::Date;
 use irox_time::Time;
+use irox_tools::fmt::DecimalFormatF64;
 use irox_tools::options::MaybeInto;
 pub use irox_tools::packetio::{Packet, PacketBuilder, PacketData, Packetization};
 use irox_units::units::angle::Angle;
@@ -19,11 +20,20 @@ use irox_units::units::length::{Length, LengthUnits};
 use irox_units::units::speed::{Speed, SpeedUnits};
 pub use output::*;
 
+use crate::ais::AIVDMBuilder;
+use crate::gbs::GBSBuilder;
 use crate::gga::GGABuilder;
+use crate::gll::GLLBuilder;
 use crate::gns::GNSBuilder;
 use crate::gsa::GSABuilder;
+use crate::gst::GSTBuilder;
 use crate::gsv::GSVBuilder;
+use crate::hdt::{HDTBuilder, THSBuilder};
+use crate::pubx::PUBXBuilder;
 use crate::rmc::RMCBuilder;
+use crate::txt::TXTBuilder;
+use crate::vtg::VTGBuilder;
+use crate::zda::ZDABuilder;
 
 mod error;
 pub mod input;
@@ -40,6 +50,14 @@ pub enum MessageType {
     VTG,
     MSS,
     ZDA,
+    GST,
+    GBS,
+    HDT,
+    THS,
+    TXT,
+    PUBX,
+    VDM,
+    VDO,
     SRF103,
     SRF125,
 }
@@ -63,6 +81,16 @@ pub enum FramePayload {
     GSV(gsv::GSV),
     GNS(gns::GNS),
     RMC(rmc::RMC),
+    VTG(vtg::VTG),
+    GLL(gll::GLL),
+    ZDA(zda::ZDA),
+    GST(gst::GST),
+    GBS(gbs::GBS),
+    HDT(hdt::HDT),
+    THS(hdt::THS),
+    TXT(txt::TXT),
+    PUBX(pubx::PUBX),
+    AIVDM(ais::AIVDM),
     Unknown { key: String, raw_data: String },
 }
 
@@ -74,6 +102,16 @@ impl Display for FramePayload {
             FramePayload::GSV(gsv) => f.write_fmt(format_args!("GSV: {gsv}")),
             FramePayload::GNS(gns) => f.write_fmt(format_args!("GNS: {gns}")),
             FramePayload::RMC(rmc) => f.write_fmt(format_args!("RMC: {rmc}")),
+            FramePayload::VTG(vtg) => f.write_fmt(format_args!("VTG: {vtg}")),
+            FramePayload::GLL(gll) => f.write_fmt(format_args!("GLL: {gll}")),
+            FramePayload::ZDA(zda) => f.write_fmt(format_args!("ZDA: {zda}")),
+            FramePayload::GST(gst) => f.write_fmt(format_args!("GST: {gst}")),
+            FramePayload::GBS(gbs) => f.write_fmt(format_args!("GBS: {gbs}")),
+            FramePayload::HDT(hdt) => f.write_fmt(format_args!("HDT: {hdt}")),
+            FramePayload::THS(ths) => f.write_fmt(format_args!("THS: {ths}")),
+            FramePayload::TXT(txt) => f.write_fmt(format_args!("TXT: {txt}")),
+            FramePayload::PUBX(pubx) => f.write_fmt(format_args!("PUBX: {pubx}")),
+            FramePayload::AIVDM(vdm) => f.write_fmt(format_args!("AIVDM: {vdm}")),
             FramePayload::Unknown { key, raw_data } => {
                 f.write_fmt(format_args!("UNK: {key} : {raw_data}"))
             }
@@ -94,6 +132,16 @@ impl Packet for Frame {
             FramePayload::GSV(gsv) => gsv.get_bytes(),
             FramePayload::GNS(gns) => gns.get_bytes(),
             FramePayload::RMC(rmc) => rmc.get_bytes(),
+            FramePayload::VTG(vtg) => vtg.get_bytes(),
+            FramePayload::GLL(gll) => gll.get_bytes(),
+            FramePayload::ZDA(zda) => zda.get_bytes(),
+            FramePayload::GST(gst) => gst.get_bytes(),
+            FramePayload::GBS(gbs) => gbs.get_bytes(),
+            FramePayload::HDT(hdt) => hdt.get_bytes(),
+            FramePayload::THS(ths) => ths.get_bytes(),
+            FramePayload::TXT(txt) => txt.get_bytes(),
+            FramePayload::PUBX(pubx) => pubx.get_bytes(),
+            FramePayload::AIVDM(vdm) => vdm.get_bytes(),
             FramePayload::Unknown { .. } => Err(ErrorKind::Unsupported.into()),
         }
     }
@@ -123,6 +171,26 @@ impl PacketBuilder<Frame> for NMEAParser {
             FramePayload::GNS(GNSBuilder.build_from(&mut pkt)?)
         } else if key.ends_with("RMC".as_bytes()) {
             FramePayload::RMC(RMCBuilder.build_from(&mut pkt)?)
+        } else if key.ends_with("VTG".as_bytes()) {
+            FramePayload::VTG(VTGBuilder.build_from(&mut pkt)?)
+        } else if key.ends_with("GLL".as_bytes()) {
+            FramePayload::GLL(GLLBuilder.build_from(&mut pkt)?)
+        } else if key.ends_with("ZDA".as_bytes()) {
+            FramePayload::ZDA(ZDABuilder::default().build_from(&mut pkt)?)
+        } else if key.ends_with("GST".as_bytes()) {
+            FramePayload::GST(GSTBuilder.build_from(&mut pkt)?)
+        } else if key.ends_with("GBS".as_bytes()) {
+            FramePayload::GBS(GBSBuilder.build_from(&mut pkt)?)
+        } else if key.ends_with("HDT".as_bytes()) {
+            FramePayload::HDT(HDTBuilder.build_from(&mut pkt)?)
+        } else if key.ends_with("THS".as_bytes()) {
+            FramePayload::THS(THSBuilder.build_from(&mut pkt)?)
+        } else if key.ends_with("TXT".as_bytes()) {
+            FramePayload::TXT(TXTBuilder.build_from(&mut pkt)?)
+        } else if key.ends_with("VDM".as_bytes()) || key.ends_with("VDO".as_bytes()) {
+            FramePayload::AIVDM(AIVDMBuilder.build_from(&mut pkt)?)
+        } else if packet.starts_with(b"$PUBX,00,") || packet.starts_with(b"$PUBX,04,") {
+            FramePayload::PUBX(PUBXBuilder.build_from(&mut pkt)?)
         } else {
             let key = String::from_utf8_lossy(key.as_slice()).to_string();
             FramePayload::Unknown {
@@ -146,15 +214,15 @@ impl NMEAPacketizer {
 }
 impl<T: Bits> Packetization<T> for NMEAPacketizer {
     fn read_next_packet(&mut self, source: &mut T) -> Result<PacketData, irox_bits::Error> {
-        loop {
+        let sof = loop {
             let val = source.read_u8()?;
-            // search for SOF
-            if val == b'$' {
-                break;
+            // search for SOF, '!' starts encapsulation sentences like AIS
+            if val == b'$' || val == b'!' {
+                break val;
             }
-        }
+        };
 
-        let mut packet: Vec<u8> = vec![b'$'];
+        let mut packet: Vec<u8> = vec![sof];
         packet.append(&mut source.read_until(b"\r\n")?);
         Ok(packet)
     }
@@ -162,7 +230,7 @@ impl<T: Bits> Packetization<T> for NMEAPacketizer {
 
 pub fn calculate_checksum<T: AsRef<[u8]>>(data: &T) -> u8 {
     let mut sl = data.as_ref();
-    if sl.starts_with(b"$") {
+    if sl.starts_with(b"$") || sl.starts_with(b"!") {
         (_, sl) = sl.split_at(1);
     }
 
@@ -234,6 +302,123 @@ pub(crate) fn maybe_altitude(
 
 pub struct NMEALatitude(pub Latitude);
 
+///
+/// The two-character talker identifier prefixing a standard sentence, like the `GN` in `$GNGGA`.
+/// Parsed sentences keep their talker so they're written back out the same way.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct TalkerID(pub [u8; 2]);
+
+impl TalkerID {
+    pub const GPS: TalkerID = TalkerID(*b"GP");
+    pub const GLONASS: TalkerID = TalkerID(*b"GL");
+    pub const GALILEO: TalkerID = TalkerID(*b"GA");
+    pub const BEIDOU: TalkerID = TalkerID(*b"GB");
+    pub const QZSS: TalkerID = TalkerID(*b"GQ");
+    pub const MULTI: TalkerID = TalkerID(*b"GN");
+    pub const AIS: TalkerID = TalkerID(*b"AI");
+
+    ///
+    /// Returns the talker of the sentence key, like `$GNVTG`, if it has one.
+    pub fn from_sender(key: &str) -> Option<TalkerID> {
+        let key = key.trim_start_matches(['$', '!']);
+        if key.starts_with('P') {
+            // proprietary sentences don't have a talker.
+            return None;
+        }
+        let [a, b, ..] = key.as_bytes() else {
+            return None;
+        };
+        Some(TalkerID([*a, *b]))
+    }
+
+    pub fn system_id(&self) -> gsa::GNSSSystemID {
+        gsa::GNSSSystemID::from_sender(&format!("${self}"))
+    }
+}
+
+impl Default for TalkerID {
+    fn default() -> Self {
+        TalkerID::GPS
+    }
+}
+
+impl Display for TalkerID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let [a, b] = self.0;
+        write!(f, "{}{}", char::from(a), char::from(b))
+    }
+}
+
+/// Appends the checksum & line ending to the sentence, which must end with the `*`
+pub(crate) fn finish_sentence(mut sentence: String) -> Vec<u8> {
+    use std::fmt::Write;
+    let csh = calculate_checksum(&sentence);
+    let _ = write!(sentence, "{csh:02X}\r\n");
+    Vec::from(sentence)
+}
+
+/// Formats the time as `hhmmss.ss`, or empty
+pub(crate) fn format_timestamp(time: Option<Time>) -> String {
+    time.map(|time| {
+        let (hh, mm, ss) = time.as_hms_f64();
+        format!("{hh:02}{mm:02}{}", DecimalFormatF64(2, 2, ss))
+    })
+    .unwrap_or_default()
+}
+
+/// Formats the latitude as the two fields `ddmm.mmmmm,N`, or `,`
+pub(crate) fn format_latitude(latitude: Option<Latitude>) -> String {
+    latitude.map_or(String::from(","), |lat| {
+        let (deg, min) = lat.0.as_deg_min();
+        let ns = if lat.0.as_degrees().value() < 0. {
+            "S"
+        } else {
+            "N"
+        };
+        format!("{:02}{},{ns}", deg.abs(), DecimalFormatF64(2, 5, min))
+    })
+}
+
+/// Formats the longitude as the two fields `dddmm.mmmmm,E`, or `,`
+pub(crate) fn format_longitude(longitude: Option<Longitude>) -> String {
+    longitude.map_or(String::from(","), |lon| {
+        let (deg, min) = lon.0.as_deg_min();
+        let ew = if lon.0.as_degrees().value() < 0. {
+            "W"
+        } else {
+            "E"
+        };
+        format!("{:03}{},{ew}", deg.abs(), DecimalFormatF64(2, 5, min))
+    })
+}
+
+/// Formats the value with the number of decimal places, or empty
+pub(crate) fn format_f64(value: Option<f64>, precision: usize) -> String {
+    value
+        .map(|v| format!("{v:.precision$}"))
+        .unwrap_or_default()
+}
+
+/// Returns the sentence without the trailing `*hh` checksum
+pub(crate) fn strip_checksum(sentence: &str) -> &str {
+    sentence
+        .split_once('*')
+        .map_or(sentence, |(sentence, _)| sentence)
+}
+
+/// Formats the length in meters with the number of decimal places, or empty
+pub(crate) fn format_meters(length: Option<Length>, precision: usize) -> String {
+    format_f64(length.map(|l| l.as_meters().value()), precision)
+}
+
+pub(crate) fn maybe_f64(val: Option<&str>) -> Option<f64> {
+    f64::from_str(val?).ok()
+}
+
+pub(crate) fn maybe_meters(val: Option<&str>) -> Option<Length> {
+    maybe_f64(val).map(Length::new_meters)
+}
+
 pub(crate) fn maybe_timestamp(val: Option<&str>) -> Option<Time> {
     let time = val?;
 
@@ -246,8 +431,9 @@ pub(crate) fn maybe_timestamp(val: Option<&str>) -> Option<Time> {
 
 pub(crate) fn maybe_date(val: Option<&str>) -> Option<Date> {
     let val = val?;
-    let (dd, rest) = val.split_at(2);
-    let (mm, yy) = rest.split_at(2);
+    let dd = val.get(0..2)?;
+    let mm = val.get(2..4)?;
+    let yy = val.get(4..)?;
 
     let year = i32::from_str(yy).ok()? + 2000;
     let mm = u8::from_str(mm).ok()?;
@@ -290,6 +476,24 @@ pub enum ModeIndicator {
     UnsetUnknown,
 }
 
+impl ModeIndicator {
+    pub const fn as_char(&self) -> Option<char> {
+        Some(match self {
+            ModeIndicator::Autonomous => 'A',
+            ModeIndicator::Differential => 'D',
+            ModeIndicator::Estimated => 'E',
+            ModeIndicator::RTKFloat => 'F',
+            ModeIndicator::ManualInput => 'M',
+            ModeIndicator::NoValidFix => 'N',
+            ModeIndicator::Precise => 'P',
+            ModeIndicator::RTKInteger => 'R',
+            ModeIndicator::Simulator => 'S',
+            ModeIndicator::Valid => 'V',
+            ModeIndicator::UnsetUnknown => return None,
+        })
+    }
+}
+
 impl From<Option<char>> for ModeIndicator {
     fn from(value: Option<char>) -> Self {
         if let Some(value) = value {
@@ -1,9 +1,17 @@
 // SPDX-License-Identifier: MIT
 // Copyright 2023 IROX Contributors
 
+pub mod ais;
+pub mod gbs;
 pub mod gga;
+pub mod gll;
 pub mod gns;
 pub mod gsa;
+pub mod gst;
 pub mod gsv;
+pub mod hdt;
+pub mod pubx;
 pub mod rmc;
+pub mod txt;
+pub mod vtg;
 pub mod zda;
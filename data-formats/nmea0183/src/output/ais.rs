@@ -0,0 +1,511 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! AIS (Automatic Identification System) messages, carried in the `!AIVDM` (other vessels) and
+//! `!AIVDO` (own vessel) encapsulation sentences.
+//!
+//! Each sentence carries a fragment of the 6-bit "armored" binary payload.  Use a
+//! [`AISReassembler`] to collect the fragments of a message and decode it into an [`AISMessage`].
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use irox_bits::{BitStreamDecoder, BitStreamEncoder, Bits, BitsError, BitsWrapper};
+use irox_carto::coordinate::{Latitude, Longitude};
+use irox_tools::options::MaybeInto;
+use irox_tools::packetio::{Packet, PacketBuilder};
+use irox_units::units::angle::Angle;
+use irox_units::units::compass::{CompassReference, Heading, RotationDirection, Track};
+use irox_units::units::length::Length;
+use irox_units::units::speed::{Speed, SpeedUnits};
+
+pub use aton::*;
+pub use position::*;
+pub use static_data::*;
+
+use crate::{finish_sentence, strip_checksum, Error, ErrorType, MessageType, TalkerID};
+
+mod aton;
+mod position;
+mod static_data;
+
+///
+/// A single `!AIVDM` or `!AIVDO` sentence, carrying one fragment of an AIS message.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AIVDM {
+    /// Usually `AI`, but base stations and repeaters use others like `AB` or `BS`
+    pub talker: TalkerID,
+    /// True for `VDO` sentences, reports from own vessel
+    pub own_vessel: bool,
+    /// Total number of fragments in this message, 1-9
+    pub fragment_count: u8,
+    /// Index of this fragment, starting at 1
+    pub fragment_number: u8,
+    /// Sequential message ID, used to group the fragments of multi-sentence messages
+    pub sequence_id: Option<u8>,
+    /// Radio channel, `A` or `B` (sometimes `1` or `2`)
+    pub channel: Option<char>,
+    /// The armored 6-bit payload
+    pub payload: String,
+    /// Number of padding bits at the end of the payload
+    pub fill_bits: u8,
+}
+
+impl AIVDM {
+    ///
+    /// Decodes the payload of this sentence, if it's a single-fragment message.  Multi-fragment
+    /// messages must go through a [`AISReassembler`].
+    pub fn decode(&self) -> Result<AISMessage, Error> {
+        if self.fragment_count > 1 {
+            return Err(Error::new_str(
+                ErrorType::BadValue,
+                "Multi-fragment AIS messages must be reassembled",
+            ));
+        }
+        AISMessage::decode(&self.payload, self.fill_bits)
+    }
+}
+
+impl Display for AIVDM {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Talker[{}] Frag[{}/{}]",
+            self.talker, self.fragment_number, self.fragment_count
+        )?;
+        if let Some(seq) = self.sequence_id {
+            write!(f, " Seq[{seq}]")?;
+        }
+        if let Some(ch) = self.channel {
+            write!(f, " Chan[{ch}]")?;
+        }
+        write!(f, " Payload[{}] Fill[{}]", self.payload, self.fill_bits)
+    }
+}
+
+impl Packet for AIVDM {
+    type PacketType = MessageType;
+
+    fn get_bytes(&self) -> Result<Vec<u8>, BitsError> {
+        let kind = if self.own_vessel { "VDO" } else { "VDM" };
+        let seq = self.sequence_id.map(|s| format!("{s}")).unwrap_or_default();
+        let chan = self.channel.map(String::from).unwrap_or_default();
+        Ok(finish_sentence(format!(
+            "!{}{kind},{},{},{seq},{chan},{},{}*",
+            self.talker, self.fragment_count, self.fragment_number, self.payload, self.fill_bits
+        )))
+    }
+
+    fn get_type(&self) -> Self::PacketType {
+        if self.own_vessel {
+            MessageType::VDO
+        } else {
+            MessageType::VDM
+        }
+    }
+}
+
+pub struct AIVDMBuilder;
+impl PacketBuilder<AIVDM> for AIVDMBuilder {
+    type Error = Error;
+
+    fn build_from<T: Bits>(&self, input: &mut T) -> Result<AIVDM, Self::Error> {
+        let buf = input.read_all_str_lossy()?;
+
+        let mut split = strip_checksum(&buf).split(',');
+        let key = split.next().unwrap_or_default();
+        let talker = TalkerID::from_sender(key).unwrap_or(TalkerID::AIS);
+        let own_vessel = key.ends_with("VDO");
+        let fragment_count = split.next().maybe_into().unwrap_or(1);
+        let fragment_number = split.next().maybe_into().unwrap_or(1);
+        let sequence_id = split.next().maybe_into();
+        let channel = split.next().maybe_into();
+        let payload = split.next().unwrap_or_default().to_string();
+        let fill_bits = split.next().maybe_into().unwrap_or_default();
+        Ok(AIVDM {
+            talker,
+            own_vessel,
+            fragment_count,
+            fragment_number,
+            sequence_id,
+            channel,
+            payload,
+            fill_bits,
+        })
+    }
+}
+
+///
+/// Collects the fragments of multi-sentence AIS messages, decoding each message once all of
+/// its fragments have arrived.  Fragments are grouped by their sequence ID, channel and
+/// VDM/VDO type - a fragment arriving out of order discards the partial message.
+#[derive(Debug, Default, Clone)]
+pub struct AISReassembler {
+    pending: BTreeMap<(bool, Option<u8>, Option<char>), Vec<AIVDM>>,
+}
+
+impl AISReassembler {
+    pub fn new() -> AISReassembler {
+        AISReassembler::default()
+    }
+
+    ///
+    /// Adds the fragment, returning the decoded message if this fragment completed one.
+    pub fn push(&mut self, fragment: AIVDM) -> Result<Option<AISMessage>, Error> {
+        if fragment.fragment_count <= 1 {
+            return fragment.decode().map(Some);
+        }
+        let key = (fragment.own_vessel, fragment.sequence_id, fragment.channel);
+        if fragment.fragment_number <= 1 {
+            // start of a new message, replaces anything partial.
+            self.pending.insert(key, vec![fragment]);
+            return Ok(None);
+        }
+        let Some(mut frags) = self.pending.remove(&key) else {
+            // missed the start of this message.
+            return Ok(None);
+        };
+        let expected = frags.len() + 1;
+        let count_matches = frags
+            .first()
+            .is_some_and(|f| f.fragment_count == fragment.fragment_count);
+        if usize::from(fragment.fragment_number) != expected || !count_matches {
+            // missed a fragment, drop the partial message.
+            return Ok(None);
+        }
+        let total = usize::from(fragment.fragment_count);
+        frags.push(fragment);
+        if frags.len() < total {
+            self.pending.insert(key, frags);
+            return Ok(None);
+        }
+        let payload: String = frags.iter().map(|f| f.payload.as_str()).collect();
+        let fill_bits = frags.last().map(|f| f.fill_bits).unwrap_or_default();
+        AISMessage::decode(&payload, fill_bits).map(Some)
+    }
+
+    ///
+    /// Discards any partially received messages.
+    pub fn clear(&mut self) {
+        self.pending.clear();
+    }
+}
+
+///
+/// A decoded AIS message
+#[derive(Debug, Clone, PartialEq)]
+pub enum AISMessage {
+    /// Types 1, 2 & 3 - Class A position report
+    PositionReport(PositionReport),
+    /// Type 5 - Class A static and voyage related data
+    StaticVoyageData(StaticVoyageData),
+    /// Type 18 - Class B position report
+    ClassBPositionReport(ClassBPositionReport),
+    /// Type 19 - Extended class B position report
+    ClassBExtendedReport(ClassBExtendedReport),
+    /// Type 21 - Aid-to-navigation report
+    AidToNavigation(AidToNavigationReport),
+    /// Type 24 - Class B static data report
+    StaticDataReport(StaticDataReport),
+    /// Any other message type, only the common header is decoded.
+    Unsupported {
+        message_type: u8,
+        repeat: u8,
+        mmsi: u32,
+    },
+}
+
+impl AISMessage {
+    ///
+    /// Decodes the armored payload of a complete (reassembled) message.
+    pub fn decode(payload: &str, fill_bits: u8) -> Result<AISMessage, Error> {
+        let mut rdr = PayloadReader::dearmor(payload, fill_bits)?;
+        let message_type = rdr.read_u8(6)?;
+        let repeat = rdr.read_u8(2)?;
+        let mmsi = rdr.read_u32(30)?;
+        Ok(match message_type {
+            1..=3 => AISMessage::PositionReport(PositionReport::decode(
+                message_type,
+                repeat,
+                mmsi,
+                &mut rdr,
+            )?),
+            5 => AISMessage::StaticVoyageData(StaticVoyageData::decode(repeat, mmsi, &mut rdr)?),
+            18 => AISMessage::ClassBPositionReport(ClassBPositionReport::decode(
+                repeat, mmsi, &mut rdr,
+            )?),
+            19 => AISMessage::ClassBExtendedReport(ClassBExtendedReport::decode(
+                repeat, mmsi, &mut rdr,
+            )?),
+            21 => {
+                AISMessage::AidToNavigation(AidToNavigationReport::decode(repeat, mmsi, &mut rdr)?)
+            }
+            24 => AISMessage::StaticDataReport(StaticDataReport::decode(repeat, mmsi, &mut rdr)?),
+            _ => AISMessage::Unsupported {
+                message_type,
+                repeat,
+                mmsi,
+            },
+        })
+    }
+
+    ///
+    /// Returns the AIS message type (1-27)
+    pub fn message_type(&self) -> u8 {
+        match self {
+            AISMessage::PositionReport(p) => p.message_type,
+            AISMessage::StaticVoyageData(_) => 5,
+            AISMessage::ClassBPositionReport(_) => 18,
+            AISMessage::ClassBExtendedReport(_) => 19,
+            AISMessage::AidToNavigation(_) => 21,
+            AISMessage::StaticDataReport(_) => 24,
+            AISMessage::Unsupported { message_type, .. } => *message_type,
+        }
+    }
+
+    ///
+    /// Returns the Maritime Mobile Service Identity of the station that sent this message
+    pub fn mmsi(&self) -> u32 {
+        match self {
+            AISMessage::PositionReport(p) => p.mmsi,
+            AISMessage::StaticVoyageData(s) => s.mmsi,
+            AISMessage::ClassBPositionReport(p) => p.mmsi,
+            AISMessage::ClassBExtendedReport(p) => p.mmsi,
+            AISMessage::AidToNavigation(a) => a.mmsi,
+            AISMessage::StaticDataReport(s) => s.mmsi,
+            AISMessage::Unsupported { mmsi, .. } => *mmsi,
+        }
+    }
+}
+
+impl Display for AISMessage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Type[{}] MMSI[{}]", self.message_type(), self.mmsi())?;
+        match self {
+            AISMessage::PositionReport(p) => write!(f, " {p}"),
+            AISMessage::StaticVoyageData(s) => write!(f, " {s}"),
+            AISMessage::ClassBPositionReport(p) => write!(f, " {p}"),
+            AISMessage::ClassBExtendedReport(p) => write!(f, " {p}"),
+            AISMessage::AidToNavigation(a) => write!(f, " {a}"),
+            AISMessage::StaticDataReport(s) => write!(f, " {s}"),
+            AISMessage::Unsupported { .. } => Ok(()),
+        }
+    }
+}
+
+///
+/// Electronic position fixing device type
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum EPFDType {
+    #[default]
+    Undefined,
+    GPS,
+    GLONASS,
+    CombinedGPSGLONASS,
+    LoranC,
+    Chayka,
+    IntegratedNavigation,
+    Surveyed,
+    Galileo,
+    InternalGNSS,
+    Other(u8),
+}
+
+impl From<u8> for EPFDType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => EPFDType::Undefined,
+            1 => EPFDType::GPS,
+            2 => EPFDType::GLONASS,
+            3 => EPFDType::CombinedGPSGLONASS,
+            4 => EPFDType::LoranC,
+            5 => EPFDType::Chayka,
+            6 => EPFDType::IntegratedNavigation,
+            7 => EPFDType::Surveyed,
+            8 => EPFDType::Galileo,
+            15 => EPFDType::InternalGNSS,
+            v => EPFDType::Other(v),
+        }
+    }
+}
+
+///
+/// Dimensions of the vessel (or aid-to-navigation), in whole meters from the position
+/// reference point.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct ShipDimensions {
+    pub to_bow: u16,
+    pub to_stern: u16,
+    pub to_port: u8,
+    pub to_starboard: u8,
+}
+
+impl ShipDimensions {
+    pub(crate) fn decode(rdr: &mut PayloadReader) -> Result<ShipDimensions, Error> {
+        Ok(ShipDimensions {
+            to_bow: rdr.read_u16(9)?,
+            to_stern: rdr.read_u16(9)?,
+            to_port: rdr.read_u8(6)?,
+            to_starboard: rdr.read_u8(6)?,
+        })
+    }
+
+    /// Overall length of the vessel
+    pub fn length(&self) -> Length {
+        Length::new_meters(f64::from(self.to_bow) + f64::from(self.to_stern))
+    }
+
+    /// Overall width of the vessel
+    pub fn beam(&self) -> Length {
+        Length::new_meters(f64::from(self.to_port) + f64::from(self.to_starboard))
+    }
+}
+
+impl Display for ShipDimensions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{}", self.length(), self.beam())
+    }
+}
+
+///
+/// Reads the fields of a de-armored AIS payload.
+pub(crate) struct PayloadReader {
+    dec: BitStreamDecoder<'static, Vec<u8>>,
+    remaining: usize,
+}
+
+impl PayloadReader {
+    ///
+    /// Converts the 6-bit ASCII armored payload back into the binary message.
+    pub(crate) fn dearmor(payload: &str, fill_bits: u8) -> Result<PayloadReader, Error> {
+        let mut buf = Vec::with_capacity(payload.len() * 6 / 8 + 1);
+        {
+            let mut enc = BitStreamEncoder::new(BitsWrapper::Borrowed(&mut buf));
+            for ch in payload.bytes() {
+                let val = match ch {
+                    b'0'..=b'W' => ch - b'0',
+                    b'`'..=b'w' => ch - b'8',
+                    _ => {
+                        return Err(Error::new(
+                            ErrorType::BadValue,
+                            format!("Invalid AIS payload character: {}", ch as char),
+                        ))
+                    }
+                };
+                enc.write_u8_bits(val, 6)?;
+            }
+        }
+        let remaining = (payload.len() * 6).saturating_sub(usize::from(fill_bits));
+        Ok(PayloadReader {
+            dec: BitStreamDecoder::new(BitsWrapper::Owned(buf)),
+            remaining,
+        })
+    }
+
+    /// Number of payload bits not yet read.
+    pub(crate) fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    pub(crate) fn read_u32(&mut self, num_bits: u8) -> Result<u32, Error> {
+        if usize::from(num_bits) > self.remaining {
+            return Error::missing_err("AIS payload is too short for message type");
+        }
+        self.remaining -= usize::from(num_bits);
+        Ok(self.dec.read_u32_bits(num_bits)?)
+    }
+
+    pub(crate) fn read_u16(&mut self, num_bits: u8) -> Result<u16, Error> {
+        Ok(self.read_u32(num_bits.min(16))? as u16)
+    }
+
+    pub(crate) fn read_u8(&mut self, num_bits: u8) -> Result<u8, Error> {
+        Ok(self.read_u32(num_bits.min(8))? as u8)
+    }
+
+    /// Reads a two's complement signed value
+    pub(crate) fn read_i32(&mut self, num_bits: u8) -> Result<i32, Error> {
+        let num_bits = num_bits.clamp(1, 32);
+        let val = self.read_u32(num_bits)?;
+        let shift = 32 - num_bits;
+        Ok(((val << shift) as i32) >> shift)
+    }
+
+    pub(crate) fn read_bool(&mut self) -> Result<bool, Error> {
+        Ok(self.read_u32(1)? == 1)
+    }
+
+    ///
+    /// Reads a string of 6-bit characters, trimming the trailing `@` padding and spaces.
+    pub(crate) fn read_text(&mut self, num_chars: usize) -> Result<String, Error> {
+        let mut out = String::with_capacity(num_chars);
+        for _ in 0..num_chars {
+            let v = self.read_u8(6)?;
+            let ch = if v < 32 { v + 64 } else { v };
+            out.push(ch as char);
+        }
+        let trimmed = out.trim_end_matches(['@', ' ']);
+        Ok(trimmed.to_string())
+    }
+
+    /// Skips over spare or reserved bits
+    pub(crate) fn skip(&mut self, num_bits: u8) -> Result<(), Error> {
+        self.read_u32(num_bits)?;
+        Ok(())
+    }
+
+    /// Longitude in 1/10000 minutes, 181 degrees is not available
+    pub(crate) fn read_longitude(&mut self) -> Result<Option<Longitude>, Error> {
+        let deg = f64::from(self.read_i32(28)?) / 600_000.;
+        if !(-180.0..=180.0).contains(&deg) {
+            return Ok(None);
+        }
+        Ok(Some(Longitude(Angle::new_degrees(deg))))
+    }
+
+    /// Latitude in 1/10000 minutes, 91 degrees is not available
+    pub(crate) fn read_latitude(&mut self) -> Result<Option<Latitude>, Error> {
+        let deg = f64::from(self.read_i32(27)?) / 600_000.;
+        if !(-90.0..=90.0).contains(&deg) {
+            return Ok(None);
+        }
+        Ok(Some(Latitude(Angle::new_degrees(deg))))
+    }
+
+    /// Speed over ground in 0.1 knots, 1023 is not available
+    pub(crate) fn read_speed(&mut self) -> Result<Option<Speed>, Error> {
+        let sog = self.read_u16(10)?;
+        if sog == 1023 {
+            return Ok(None);
+        }
+        Ok(Some(Speed::new(f64::from(sog) / 10., SpeedUnits::Knots)))
+    }
+
+    /// Course over ground in 0.1 degrees, 3600 is not available
+    pub(crate) fn read_course(&mut self) -> Result<Option<Track>, Error> {
+        let cog = self.read_u16(12)?;
+        if cog >= 3600 {
+            return Ok(None);
+        }
+        Ok(Some(Track::new_track(
+            Angle::new_degrees(f64::from(cog) / 10.),
+            RotationDirection::PositiveClockwise,
+            CompassReference::TrueNorth,
+        )))
+    }
+
+    /// True heading in degrees, 511 is not available
+    pub(crate) fn read_heading(&mut self) -> Result<Option<Heading>, Error> {
+        let hdg = self.read_u16(9)?;
+        if hdg >= 360 {
+            return Ok(None);
+        }
+        Ok(Some(Heading::new_heading(
+            Angle::new_degrees(f64::from(hdg)),
+            RotationDirection::PositiveClockwise,
+            CompassReference::TrueNorth,
+        )))
+    }
+}
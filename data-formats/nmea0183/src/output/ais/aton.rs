@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use std::fmt::{Display, Formatter};
+
+use irox_carto::coordinate::{Latitude, Longitude};
+
+use crate::ais::{EPFDType, PayloadReader, ShipDimensions};
+use crate::Error;
+
+///
+/// Message type 21 - Aid-to-navigation report, sent by buoys, lighthouses and other
+/// navigational aids (or on their behalf, for virtual aids).
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AidToNavigationReport {
+    pub repeat: u8,
+    pub mmsi: u32,
+    /// IALA aid type code, 0 is unspecified, 1-15 are fixed aids, 16-31 are floating aids
+    pub aid_type: u8,
+    /// Name of the aid, including the name extension
+    pub name: String,
+    /// True if the position accuracy is better than 10m (DGPS)
+    pub position_accuracy: bool,
+    pub longitude: Option<Longitude>,
+    pub latitude: Option<Latitude>,
+    pub dimensions: ShipDimensions,
+    pub epfd: EPFDType,
+    /// Second of the UTC minute the report was generated, 60 if not available
+    pub utc_second: u8,
+    /// True if a floating aid is off its assigned position
+    pub off_position: bool,
+    pub raim: bool,
+    /// True if this is a virtual aid, with no physical presence
+    pub virtual_aid: bool,
+    /// True if the unit is in assigned mode
+    pub assigned: bool,
+}
+
+impl AidToNavigationReport {
+    pub(crate) fn decode(
+        repeat: u8,
+        mmsi: u32,
+        rdr: &mut PayloadReader,
+    ) -> Result<AidToNavigationReport, Error> {
+        let aid_type = rdr.read_u8(5)?;
+        let mut name = rdr.read_text(20)?;
+        let position_accuracy = rdr.read_bool()?;
+        let longitude = rdr.read_longitude()?;
+        let latitude = rdr.read_latitude()?;
+        let dimensions = ShipDimensions::decode(rdr)?;
+        let epfd = rdr.read_u8(4)?.into();
+        let utc_second = rdr.read_u8(6)?;
+        let off_position = rdr.read_bool()?;
+        rdr.skip(8)?;
+        let raim = rdr.read_bool()?;
+        let virtual_aid = rdr.read_bool()?;
+        let assigned = rdr.read_bool()?;
+        rdr.skip(1)?;
+        // names longer than 20 characters continue in the optional extension field.
+        let extension = rdr.read_text((rdr.remaining() / 6).min(14))?;
+        name.push_str(&extension);
+        Ok(AidToNavigationReport {
+            repeat,
+            mmsi,
+            aid_type,
+            name,
+            position_accuracy,
+            longitude,
+            latitude,
+            dimensions,
+            epfd,
+            utc_second,
+            off_position,
+            raim,
+            virtual_aid,
+            assigned,
+        })
+    }
+}
+
+impl Display for AidToNavigationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AtoN Type[{}] Name[{}]", self.aid_type, self.name)?;
+        if let Some(lat) = self.latitude {
+            write!(f, " Lat[{lat}]")?;
+        }
+        if let Some(lon) = self.longitude {
+            write!(f, " Lon[{lon}]")?;
+        }
+        if self.virtual_aid {
+            write!(f, " Virtual")?;
+        }
+        if self.off_position {
+            write!(f, " OffPosition")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,360 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use std::fmt::{Display, Formatter};
+
+use irox_carto::coordinate::{Latitude, Longitude};
+use irox_units::units::compass::{Heading, Track};
+use irox_units::units::speed::Speed;
+
+use crate::ais::{EPFDType, PayloadReader, ShipDimensions};
+use crate::Error;
+
+///
+/// Navigational status of a Class A vessel
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum NavigationStatus {
+    UnderWayUsingEngine,
+    AtAnchor,
+    NotUnderCommand,
+    RestrictedManeuverability,
+    ConstrainedByDraught,
+    Moored,
+    Aground,
+    EngagedInFishing,
+    UnderWaySailing,
+    Reserved(u8),
+    AISSARTActive,
+    #[default]
+    NotDefined,
+}
+
+impl From<u8> for NavigationStatus {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => NavigationStatus::UnderWayUsingEngine,
+            1 => NavigationStatus::AtAnchor,
+            2 => NavigationStatus::NotUnderCommand,
+            3 => NavigationStatus::RestrictedManeuverability,
+            4 => NavigationStatus::ConstrainedByDraught,
+            5 => NavigationStatus::Moored,
+            6 => NavigationStatus::Aground,
+            7 => NavigationStatus::EngagedInFishing,
+            8 => NavigationStatus::UnderWaySailing,
+            14 => NavigationStatus::AISSARTActive,
+            15 => NavigationStatus::NotDefined,
+            v => NavigationStatus::Reserved(v),
+        }
+    }
+}
+
+///
+/// Rate of turn of a Class A vessel, as reported by a turn indicator
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub enum RateOfTurn {
+    #[default]
+    NotAvailable,
+    /// Degrees per minute, positive is turning right (clockwise)
+    DegreesPerMinute(f64),
+    /// Turning right at more than 5 degrees per 30 seconds, no turn indicator
+    TurningRight,
+    /// Turning left at more than 5 degrees per 30 seconds, no turn indicator
+    TurningLeft,
+}
+
+impl From<i8> for RateOfTurn {
+    fn from(value: i8) -> Self {
+        match value {
+            -128 => RateOfTurn::NotAvailable,
+            127 => RateOfTurn::TurningRight,
+            -127 => RateOfTurn::TurningLeft,
+            v => {
+                // ROT[AIS] = 4.733 * sqrt(ROT[IND])
+                let rate = (f64::from(v) / 4.733).powi(2);
+                RateOfTurn::DegreesPerMinute(rate.copysign(f64::from(v)))
+            }
+        }
+    }
+}
+
+///
+/// Message types 1, 2 & 3 - Class A position report.  Type 1 is a scheduled report, type 2 is
+/// assigned scheduled, and type 3 is a response to interrogation.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct PositionReport {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub nav_status: NavigationStatus,
+    pub rate_of_turn: RateOfTurn,
+    pub speed: Option<Speed>,
+    /// True if the position accuracy is better than 10m (DGPS)
+    pub position_accuracy: bool,
+    pub longitude: Option<Longitude>,
+    pub latitude: Option<Latitude>,
+    pub course: Option<Track>,
+    pub heading: Option<Heading>,
+    /// Second of the UTC minute the report was generated, 60 if not available, 61-63 if the
+    /// positioning system is in manual, dead-reckoning or inoperative modes.
+    pub utc_second: u8,
+    /// 0 not available, 1 no special maneuver, 2 special maneuver
+    pub maneuver: u8,
+    pub raim: bool,
+    pub radio_status: u32,
+}
+
+impl PositionReport {
+    pub(crate) fn decode(
+        message_type: u8,
+        repeat: u8,
+        mmsi: u32,
+        rdr: &mut PayloadReader,
+    ) -> Result<PositionReport, Error> {
+        let nav_status = rdr.read_u8(4)?.into();
+        let rate_of_turn = (rdr.read_i32(8)? as i8).into();
+        let speed = rdr.read_speed()?;
+        let position_accuracy = rdr.read_bool()?;
+        let longitude = rdr.read_longitude()?;
+        let latitude = rdr.read_latitude()?;
+        let course = rdr.read_course()?;
+        let heading = rdr.read_heading()?;
+        let utc_second = rdr.read_u8(6)?;
+        let maneuver = rdr.read_u8(2)?;
+        rdr.skip(3)?;
+        let raim = rdr.read_bool()?;
+        let radio_status = rdr.read_u32(19)?;
+        Ok(PositionReport {
+            message_type,
+            repeat,
+            mmsi,
+            nav_status,
+            rate_of_turn,
+            speed,
+            position_accuracy,
+            longitude,
+            latitude,
+            course,
+            heading,
+            utc_second,
+            maneuver,
+            raim,
+            radio_status,
+        })
+    }
+}
+
+impl Display for PositionReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Status[{:?}]", self.nav_status)?;
+        write_position(
+            f,
+            self.latitude,
+            self.longitude,
+            self.speed,
+            self.course,
+            self.heading,
+        )
+    }
+}
+
+///
+/// Message type 18 - Standard Class B position report
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct ClassBPositionReport {
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub speed: Option<Speed>,
+    /// True if the position accuracy is better than 10m (DGPS)
+    pub position_accuracy: bool,
+    pub longitude: Option<Longitude>,
+    pub latitude: Option<Latitude>,
+    pub course: Option<Track>,
+    pub heading: Option<Heading>,
+    /// Second of the UTC minute the report was generated, 60 if not available
+    pub utc_second: u8,
+    /// True for a Class B "CS" (carrier-sense) unit, false for a "SOTDMA" unit
+    pub cs_unit: bool,
+    /// True if the unit has a display for messages
+    pub display: bool,
+    /// True if the unit has a DSC function
+    pub dsc: bool,
+    /// True if the unit can use the whole marine band
+    pub band: bool,
+    /// True if the unit accepts channel management via message 22
+    pub message_22: bool,
+    /// True if the unit is in assigned mode
+    pub assigned: bool,
+    pub raim: bool,
+    pub radio_status: u32,
+}
+
+impl ClassBPositionReport {
+    pub(crate) fn decode(
+        repeat: u8,
+        mmsi: u32,
+        rdr: &mut PayloadReader,
+    ) -> Result<ClassBPositionReport, Error> {
+        rdr.skip(8)?;
+        let speed = rdr.read_speed()?;
+        let position_accuracy = rdr.read_bool()?;
+        let longitude = rdr.read_longitude()?;
+        let latitude = rdr.read_latitude()?;
+        let course = rdr.read_course()?;
+        let heading = rdr.read_heading()?;
+        let utc_second = rdr.read_u8(6)?;
+        rdr.skip(2)?;
+        let cs_unit = rdr.read_bool()?;
+        let display = rdr.read_bool()?;
+        let dsc = rdr.read_bool()?;
+        let band = rdr.read_bool()?;
+        let message_22 = rdr.read_bool()?;
+        let assigned = rdr.read_bool()?;
+        let raim = rdr.read_bool()?;
+        let radio_status = rdr.read_u32(20)?;
+        Ok(ClassBPositionReport {
+            repeat,
+            mmsi,
+            speed,
+            position_accuracy,
+            longitude,
+            latitude,
+            course,
+            heading,
+            utc_second,
+            cs_unit,
+            display,
+            dsc,
+            band,
+            message_22,
+            assigned,
+            raim,
+            radio_status,
+        })
+    }
+}
+
+impl Display for ClassBPositionReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ClassB")?;
+        write_position(
+            f,
+            self.latitude,
+            self.longitude,
+            self.speed,
+            self.course,
+            self.heading,
+        )
+    }
+}
+
+///
+/// Message type 19 - Extended Class B position report, a position report with the vessel's
+/// static data.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ClassBExtendedReport {
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub speed: Option<Speed>,
+    /// True if the position accuracy is better than 10m (DGPS)
+    pub position_accuracy: bool,
+    pub longitude: Option<Longitude>,
+    pub latitude: Option<Latitude>,
+    pub course: Option<Track>,
+    pub heading: Option<Heading>,
+    /// Second of the UTC minute the report was generated, 60 if not available
+    pub utc_second: u8,
+    pub name: String,
+    /// ITU-R M.1371 ship and cargo type code
+    pub ship_type: u8,
+    pub dimensions: ShipDimensions,
+    pub epfd: EPFDType,
+    pub raim: bool,
+    /// Data terminal equipment, true if *not* ready
+    pub dte: bool,
+    /// True if the unit is in assigned mode
+    pub assigned: bool,
+}
+
+impl ClassBExtendedReport {
+    pub(crate) fn decode(
+        repeat: u8,
+        mmsi: u32,
+        rdr: &mut PayloadReader,
+    ) -> Result<ClassBExtendedReport, Error> {
+        rdr.skip(8)?;
+        let speed = rdr.read_speed()?;
+        let position_accuracy = rdr.read_bool()?;
+        let longitude = rdr.read_longitude()?;
+        let latitude = rdr.read_latitude()?;
+        let course = rdr.read_course()?;
+        let heading = rdr.read_heading()?;
+        let utc_second = rdr.read_u8(6)?;
+        rdr.skip(4)?;
+        let name = rdr.read_text(20)?;
+        let ship_type = rdr.read_u8(8)?;
+        let dimensions = ShipDimensions::decode(rdr)?;
+        let epfd = rdr.read_u8(4)?.into();
+        let raim = rdr.read_bool()?;
+        let dte = rdr.read_bool()?;
+        let assigned = rdr.read_bool()?;
+        Ok(ClassBExtendedReport {
+            repeat,
+            mmsi,
+            speed,
+            position_accuracy,
+            longitude,
+            latitude,
+            course,
+            heading,
+            utc_second,
+            name,
+            ship_type,
+            dimensions,
+            epfd,
+            raim,
+            dte,
+            assigned,
+        })
+    }
+}
+
+impl Display for ClassBExtendedReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ClassB Name[{}]", self.name)?;
+        write_position(
+            f,
+            self.latitude,
+            self.longitude,
+            self.speed,
+            self.course,
+            self.heading,
+        )
+    }
+}
+
+fn write_position(
+    f: &mut Formatter<'_>,
+    latitude: Option<Latitude>,
+    longitude: Option<Longitude>,
+    speed: Option<Speed>,
+    course: Option<Track>,
+    heading: Option<Heading>,
+) -> std::fmt::Result {
+    if let Some(lat) = latitude {
+        write!(f, " Lat[{lat}]")?;
+    }
+    if let Some(lon) = longitude {
+        write!(f, " Lon[{lon}]")?;
+    }
+    if let Some(sog) = speed {
+        write!(f, " SOG[{sog}]")?;
+    }
+    if let Some(cog) = course {
+        write!(f, " COG[{cog}]")?;
+    }
+    if let Some(hdg) = heading {
+        write!(f, " HDG[{hdg}]")?;
+    }
+    Ok(())
+}
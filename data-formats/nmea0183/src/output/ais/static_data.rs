@@ -0,0 +1,210 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use std::fmt::{Display, Formatter};
+
+use irox_units::units::length::Length;
+
+use crate::ais::{EPFDType, PayloadReader, ShipDimensions};
+use crate::Error;
+
+///
+/// Estimated time of arrival, in UTC.  Zero values (and hour 24, minute 60) mean not available.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct ETA {
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+}
+
+impl Display for ETA {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:02}-{:02} {:02}:{:02}",
+            self.month, self.day, self.hour, self.minute
+        )
+    }
+}
+
+///
+/// Message type 5 - Class A static and voyage related data
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StaticVoyageData {
+    pub repeat: u8,
+    pub mmsi: u32,
+    /// 0 is ITU-R M.1371-1, 1 is M.1371-3, 2 is M.1371-5
+    pub ais_version: u8,
+    pub imo: u32,
+    pub callsign: String,
+    pub name: String,
+    /// ITU-R M.1371 ship and cargo type code
+    pub ship_type: u8,
+    pub dimensions: ShipDimensions,
+    pub epfd: EPFDType,
+    pub eta: ETA,
+    pub draught: Length,
+    pub destination: String,
+    /// Data terminal equipment, true if *not* ready
+    pub dte: bool,
+}
+
+impl StaticVoyageData {
+    pub(crate) fn decode(
+        repeat: u8,
+        mmsi: u32,
+        rdr: &mut PayloadReader,
+    ) -> Result<StaticVoyageData, Error> {
+        let ais_version = rdr.read_u8(2)?;
+        let imo = rdr.read_u32(30)?;
+        let callsign = rdr.read_text(7)?;
+        let name = rdr.read_text(20)?;
+        let ship_type = rdr.read_u8(8)?;
+        let dimensions = ShipDimensions::decode(rdr)?;
+        let epfd = rdr.read_u8(4)?.into();
+        let eta = ETA {
+            month: rdr.read_u8(4)?,
+            day: rdr.read_u8(5)?,
+            hour: rdr.read_u8(5)?,
+            minute: rdr.read_u8(6)?,
+        };
+        let draught = Length::new_meters(f64::from(rdr.read_u8(8)?) / 10.);
+        let destination = rdr.read_text(20)?;
+        let dte = rdr.read_bool()?;
+        Ok(StaticVoyageData {
+            repeat,
+            mmsi,
+            ais_version,
+            imo,
+            callsign,
+            name,
+            ship_type,
+            dimensions,
+            epfd,
+            eta,
+            draught,
+            destination,
+            dte,
+        })
+    }
+}
+
+impl Display for StaticVoyageData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Name[{}] Callsign[{}] IMO[{}] Type[{}] Dims[{}] Dest[{}] ETA[{}]",
+            self.name,
+            self.callsign,
+            self.imo,
+            self.ship_type,
+            self.dimensions,
+            self.destination,
+            self.eta
+        )
+    }
+}
+
+///
+/// Part B of a type 24 static data report
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StaticDataPartB {
+    /// ITU-R M.1371 ship and cargo type code
+    pub ship_type: u8,
+    /// Manufacturer's mnemonic code
+    pub vendor_id: String,
+    pub unit_model: u8,
+    pub serial_number: u32,
+    pub callsign: String,
+    /// Dimensions of the vessel, if this is not an auxiliary craft
+    pub dimensions: Option<ShipDimensions>,
+    /// MMSI of the parent vessel, if this is an auxiliary craft
+    pub mothership_mmsi: Option<u32>,
+}
+
+///
+/// The two parts of a type 24 static data report, sent as separate messages
+#[derive(Debug, Clone, PartialEq)]
+pub enum StaticDataPart {
+    /// Part A, the vessel name
+    A {
+        name: String,
+    },
+    B(StaticDataPartB),
+}
+
+///
+/// Message type 24 - Class B static data report
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticDataReport {
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub part: StaticDataPart,
+}
+
+impl StaticDataReport {
+    pub(crate) fn decode(
+        repeat: u8,
+        mmsi: u32,
+        rdr: &mut PayloadReader,
+    ) -> Result<StaticDataReport, Error> {
+        let part = match rdr.read_u8(2)? {
+            0 => StaticDataPart::A {
+                name: rdr.read_text(20)?,
+            },
+            1 => {
+                let ship_type = rdr.read_u8(8)?;
+                let vendor_id = rdr.read_text(3)?;
+                let unit_model = rdr.read_u8(4)?;
+                let serial_number = rdr.read_u32(20)?;
+                let callsign = rdr.read_text(7)?;
+                // auxiliary craft have MMSIs of the form 98XXXYYYY
+                let (dimensions, mothership_mmsi) = if mmsi / 10_000_000 == 98 {
+                    (None, Some(rdr.read_u32(30)?))
+                } else {
+                    (Some(ShipDimensions::decode(rdr)?), None)
+                };
+                StaticDataPart::B(StaticDataPartB {
+                    ship_type,
+                    vendor_id,
+                    unit_model,
+                    serial_number,
+                    callsign,
+                    dimensions,
+                    mothership_mmsi,
+                })
+            }
+            _ => {
+                return Err(Error::new_str(
+                    crate::ErrorType::BadValue,
+                    "Invalid type 24 part number",
+                ))
+            }
+        };
+        Ok(StaticDataReport { repeat, mmsi, part })
+    }
+}
+
+impl Display for StaticDataReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.part {
+            StaticDataPart::A { name } => write!(f, "PartA Name[{name}]"),
+            StaticDataPart::B(b) => {
+                write!(
+                    f,
+                    "PartB Type[{}] Vendor[{}] Callsign[{}]",
+                    b.ship_type, b.vendor_id, b.callsign
+                )?;
+                if let Some(dims) = b.dimensions {
+                    write!(f, " Dims[{dims}]")?;
+                }
+                if let Some(mmsi) = b.mothership_mmsi {
+                    write!(f, " Mothership[{mmsi}]")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
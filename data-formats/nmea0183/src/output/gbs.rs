@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use std::fmt::{Display, Formatter};
+
+use irox_bits::{Bits, BitsError};
+use irox_time::Time;
+use irox_tools::options::MaybeInto;
+use irox_tools::packetio::{Packet, PacketBuilder};
+use irox_units::units::length::Length;
+
+use crate::gsa::GNSSSystemID;
+use crate::{
+    finish_sentence, format_f64, format_meters, format_timestamp, maybe_f64, maybe_meters,
+    maybe_timestamp, strip_checksum, Error, MessageType, TalkerID,
+};
+
+///
+/// GBS - GNSS Satellite Fault Detection, the results of the receiver autonomous integrity
+/// monitoring (RAIM).
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GBS {
+    pub talker: TalkerID,
+    pub timestamp: Option<Time>,
+    /// Expected error in latitude
+    pub latitude_error: Option<Length>,
+    /// Expected error in longitude
+    pub longitude_error: Option<Length>,
+    /// Expected error in altitude
+    pub altitude_error: Option<Length>,
+    /// ID of the most likely failed satellite
+    pub failed_sat: Option<u8>,
+    /// Probability of missed detection of the most likely failed satellite
+    pub probability: Option<f64>,
+    /// Estimated bias of the most likely failed satellite
+    pub bias: Option<Length>,
+    /// Standard deviation of the bias estimate
+    pub bias_stddev: Option<Length>,
+    /// GNSS system of the failed satellite (NMEA 4.10+)
+    pub system_id: GNSSSystemID,
+    /// GNSS signal of the failed satellite (NMEA 4.10+)
+    pub signal_id: Option<u8>,
+}
+
+impl Display for GBS {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Talker[{}] ", self.talker)?;
+        if let Some(ts) = self.timestamp {
+            write!(f, "Time[{ts}] ")?;
+        }
+        if let Some(lat) = self.latitude_error {
+            write!(f, "LatErr[{lat}] ")?;
+        }
+        if let Some(lon) = self.longitude_error {
+            write!(f, "LonErr[{lon}] ")?;
+        }
+        if let Some(alt) = self.altitude_error {
+            write!(f, "AltErr[{alt}] ")?;
+        }
+        if let Some(sat) = self.failed_sat {
+            write!(f, "FailedSat[{sat}] System[{}] ", self.system_id)?;
+        }
+        if let Some(prob) = self.probability {
+            write!(f, "Prob[{prob}] ")?;
+        }
+        if let Some(bias) = self.bias {
+            write!(f, "Bias[{bias}] ")?;
+        }
+        if let Some(sd) = self.bias_stddev {
+            write!(f, "BiasSD[{sd}]")?;
+        }
+        Ok(())
+    }
+}
+
+impl Packet for GBS {
+    type PacketType = MessageType;
+
+    fn get_bytes(&self) -> Result<Vec<u8>, BitsError> {
+        let timestamp = format_timestamp(self.timestamp);
+        let lat = format_meters(self.latitude_error, 1);
+        let lon = format_meters(self.longitude_error, 1);
+        let alt = format_meters(self.altitude_error, 1);
+        let sat = self
+            .failed_sat
+            .map(|s| format!("{s:02}"))
+            .unwrap_or_default();
+        let prob = format_f64(self.probability, 3);
+        let bias = format_meters(self.bias, 1);
+        let stddev = format_meters(self.bias_stddev, 1);
+        let system = self
+            .system_id
+            .get_id()
+            .map(|id| {
+                let signal = self.signal_id.map(|s| format!("{s}")).unwrap_or_default();
+                format!(",{id},{signal}")
+            })
+            .unwrap_or_default();
+        Ok(finish_sentence(format!(
+            "${}GBS,{timestamp},{lat},{lon},{alt},{sat},{prob},{bias},{stddev}{system}*",
+            self.talker
+        )))
+    }
+
+    fn get_type(&self) -> Self::PacketType {
+        MessageType::GBS
+    }
+}
+
+pub struct GBSBuilder;
+impl PacketBuilder<GBS> for GBSBuilder {
+    type Error = Error;
+
+    fn build_from<T: Bits>(&self, input: &mut T) -> Result<GBS, Self::Error> {
+        let buf = input.read_all_str_lossy()?;
+
+        let mut split = strip_checksum(&buf).split(',');
+        let talker = split
+            .next()
+            .and_then(TalkerID::from_sender)
+            .unwrap_or_default();
+        let timestamp = maybe_timestamp(split.next());
+        let latitude_error = maybe_meters(split.next());
+        let longitude_error = maybe_meters(split.next());
+        let altitude_error = maybe_meters(split.next());
+        let failed_sat = split.next().maybe_into();
+        let probability = maybe_f64(split.next());
+        let bias = maybe_meters(split.next());
+        let bias_stddev = maybe_meters(split.next());
+        let system_id = split.next().into();
+        let signal_id = split.next().maybe_into();
+
+        Ok(GBS {
+            talker,
+            timestamp,
+            latitude_error,
+            longitude_error,
+            altitude_error,
+            failed_sat,
+            probability,
+            bias,
+            bias_stddev,
+            system_id,
+            signal_id,
+        })
+    }
+}
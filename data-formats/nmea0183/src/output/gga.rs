@@ -15,8 +15,8 @@ use irox_tools::packetio::{Packet, PacketBuilder};
 use irox_units::units::length::Length;
 
 use crate::{
-    calculate_checksum, maybe_altitude, maybe_latitude, maybe_length, maybe_longitude, Error,
-    MessageType,
+    calculate_checksum, format_latitude, format_longitude, format_timestamp, maybe_altitude,
+    maybe_latitude, maybe_length, maybe_longitude, Error, MessageType,
 };
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, EnumName)]
@@ -197,37 +197,9 @@ impl Packet for GGA {
         use std::fmt::Write;
         let mut buf = String::new();
 
-        let utctime = self
-            .timestamp
-            .map(|timestamp| {
-                let (hh, mm, ss) = timestamp.as_hms_f64();
-                format!("{hh:02}{mm:02}{}", DecimalFormatF64(2, 2, ss))
-            })
-            .unwrap_or_default();
-
-        let latitude = self.latitude.map_or(String::from(","), |lat| {
-            let (mut lat_deg, lat_min) = lat.0.as_deg_min();
-            let ns = match lat_deg.is_positive() {
-                true => "N",
-                false => {
-                    lat_deg = lat_deg.abs();
-                    "S"
-                }
-            };
-            format!("{lat_deg:02}{},{ns}", DecimalFormatF64(2, 5, lat_min))
-        });
-
-        let longitude = self.longitude.map_or(String::from(","), |lon| {
-            let (mut lon_deg, lon_min) = lon.0.as_deg_min();
-            let ew = match lon_deg.is_positive() {
-                true => "E",
-                false => {
-                    lon_deg = lon_deg.abs();
-                    "W"
-                }
-            };
-            format!("{lon_deg:02}{},{ew}", DecimalFormatF64(2, 5, lon_min))
-        });
+        let utctime = format_timestamp(self.timestamp);
+        let latitude = format_latitude(self.latitude);
+        let longitude = format_longitude(self.longitude);
         let fix = self
             .quality
             .map(|fix| format!("{}", fix.value()))
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use std::fmt::{Display, Formatter};
+
+use irox_bits::{Bits, BitsError};
+use irox_carto::coordinate::{Latitude, Longitude};
+use irox_time::Time;
+use irox_tools::packetio::{Packet, PacketBuilder};
+
+use crate::rmc::RMCStatus;
+use crate::{
+    finish_sentence, format_latitude, format_longitude, format_timestamp, maybe_latitude,
+    maybe_longitude, maybe_timestamp, strip_checksum, Error, MessageType, ModeIndicator, TalkerID,
+};
+
+///
+/// GLL - Geographic Position - Latitude/Longitude
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GLL {
+    pub talker: TalkerID,
+    pub latitude: Option<Latitude>,
+    pub longitude: Option<Longitude>,
+    pub timestamp: Option<Time>,
+    pub status: RMCStatus,
+    pub mode: ModeIndicator,
+}
+
+impl Display for GLL {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Talker[{}] ", self.talker)?;
+        if let Some(lat) = self.latitude {
+            write!(f, "{lat} ")?;
+        }
+        if let Some(lon) = self.longitude {
+            write!(f, "{lon} ")?;
+        }
+        if let Some(ts) = self.timestamp {
+            write!(f, "Time[{ts}] ")?;
+        }
+        write!(f, "Status[{:?}] MODE[{:?}]", self.status, self.mode)
+    }
+}
+
+impl Packet for GLL {
+    type PacketType = MessageType;
+
+    fn get_bytes(&self) -> Result<Vec<u8>, BitsError> {
+        let latitude = format_latitude(self.latitude);
+        let longitude = format_longitude(self.longitude);
+        let timestamp = format_timestamp(self.timestamp);
+        let status = self.status.as_str();
+        let mode = self
+            .mode
+            .as_char()
+            .map(|m| format!(",{m}"))
+            .unwrap_or_default();
+        Ok(finish_sentence(format!(
+            "${}GLL,{latitude},{longitude},{timestamp},{status}{mode}*",
+            self.talker
+        )))
+    }
+
+    fn get_type(&self) -> Self::PacketType {
+        MessageType::GLL
+    }
+}
+
+pub struct GLLBuilder;
+impl PacketBuilder<GLL> for GLLBuilder {
+    type Error = Error;
+
+    fn build_from<T: Bits>(&self, input: &mut T) -> Result<GLL, Self::Error> {
+        let buf = input.read_all_str_lossy()?;
+
+        let mut split = strip_checksum(&buf).split(',');
+        let talker = split
+            .next()
+            .and_then(TalkerID::from_sender)
+            .unwrap_or_default();
+        let latitude = maybe_latitude(split.next(), split.next());
+        let longitude = maybe_longitude(split.next(), split.next());
+        let timestamp = maybe_timestamp(split.next());
+        let status = split.next().into();
+        let mode = split.next().into();
+
+        Ok(GLL {
+            talker,
+            latitude,
+            longitude,
+            timestamp,
+            status,
+            mode,
+        })
+    }
+}
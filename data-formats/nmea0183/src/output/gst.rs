@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use std::fmt::{Display, Formatter};
+
+use irox_bits::{Bits, BitsError};
+use irox_time::Time;
+use irox_tools::packetio::{Packet, PacketBuilder};
+use irox_units::units::angle::Angle;
+use irox_units::units::length::Length;
+
+use crate::{
+    finish_sentence, format_f64, format_meters, format_timestamp, maybe_f64, maybe_meters,
+    maybe_timestamp, strip_checksum, Error, MessageType, TalkerID,
+};
+
+///
+/// GST - GNSS Pseudorange Error Statistics, the 1-sigma error ellipse & position errors.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GST {
+    pub talker: TalkerID,
+    pub timestamp: Option<Time>,
+    /// RMS value of the standard deviation of the range inputs to the navigation process
+    pub range_rms: Option<Length>,
+    /// Standard deviation of the semi-major axis of the error ellipse
+    pub semi_major: Option<Length>,
+    /// Standard deviation of the semi-minor axis of the error ellipse
+    pub semi_minor: Option<Length>,
+    /// Orientation of the semi-major axis of the error ellipse, clockwise from true north
+    pub orientation: Option<Angle>,
+    /// Standard deviation of the latitude error
+    pub latitude_error: Option<Length>,
+    /// Standard deviation of the longitude error
+    pub longitude_error: Option<Length>,
+    /// Standard deviation of the altitude error
+    pub altitude_error: Option<Length>,
+}
+
+impl Display for GST {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Talker[{}] ", self.talker)?;
+        if let Some(ts) = self.timestamp {
+            write!(f, "Time[{ts}] ")?;
+        }
+        if let Some(rms) = self.range_rms {
+            write!(f, "RMS[{rms}] ")?;
+        }
+        if let (Some(major), Some(minor)) = (self.semi_major, self.semi_minor) {
+            write!(f, "Ellipse[{major} x {minor}")?;
+            if let Some(orientation) = self.orientation {
+                write!(f, " @ {orientation}")?;
+            }
+            write!(f, "] ")?;
+        }
+        if let Some(lat) = self.latitude_error {
+            write!(f, "LatErr[{lat}] ")?;
+        }
+        if let Some(lon) = self.longitude_error {
+            write!(f, "LonErr[{lon}] ")?;
+        }
+        if let Some(alt) = self.altitude_error {
+            write!(f, "AltErr[{alt}]")?;
+        }
+        Ok(())
+    }
+}
+
+impl Packet for GST {
+    type PacketType = MessageType;
+
+    fn get_bytes(&self) -> Result<Vec<u8>, BitsError> {
+        let timestamp = format_timestamp(self.timestamp);
+        let rms = format_meters(self.range_rms, 3);
+        let major = format_meters(self.semi_major, 3);
+        let minor = format_meters(self.semi_minor, 3);
+        let orientation = format_f64(self.orientation.map(|o| o.as_degrees().value()), 1);
+        let lat = format_meters(self.latitude_error, 3);
+        let lon = format_meters(self.longitude_error, 3);
+        let alt = format_meters(self.altitude_error, 3);
+        Ok(finish_sentence(format!(
+            "${}GST,{timestamp},{rms},{major},{minor},{orientation},{lat},{lon},{alt}*",
+            self.talker
+        )))
+    }
+
+    fn get_type(&self) -> Self::PacketType {
+        MessageType::GST
+    }
+}
+
+pub struct GSTBuilder;
+impl PacketBuilder<GST> for GSTBuilder {
+    type Error = Error;
+
+    fn build_from<T: Bits>(&self, input: &mut T) -> Result<GST, Self::Error> {
+        let buf = input.read_all_str_lossy()?;
+
+        let mut split = strip_checksum(&buf).split(',');
+        let talker = split
+            .next()
+            .and_then(TalkerID::from_sender)
+            .unwrap_or_default();
+        let timestamp = maybe_timestamp(split.next());
+        let range_rms = maybe_meters(split.next());
+        let semi_major = maybe_meters(split.next());
+        let semi_minor = maybe_meters(split.next());
+        let orientation = maybe_f64(split.next()).map(Angle::new_degrees);
+        let latitude_error = maybe_meters(split.next());
+        let longitude_error = maybe_meters(split.next());
+        let altitude_error = maybe_meters(split.next());
+
+        Ok(GST {
+            talker,
+            timestamp,
+            range_rms,
+            semi_major,
+            semi_minor,
+            orientation,
+            latitude_error,
+            longitude_error,
+            altitude_error,
+        })
+    }
+}
@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use std::fmt::{Display, Formatter};
+
+use irox_bits::{Bits, BitsError};
+use irox_tools::packetio::{Packet, PacketBuilder};
+use irox_units::units::angle::Angle;
+use irox_units::units::compass::{CompassReference, Heading, RotationDirection};
+
+use crate::{
+    finish_sentence, format_f64, maybe_f64, strip_checksum, Error, MessageType, ModeIndicator,
+    TalkerID,
+};
+
+pub(crate) fn maybe_heading(val: Option<&str>) -> Option<Heading> {
+    Some(Heading::new_heading(
+        Angle::new_degrees(maybe_f64(val)?),
+        RotationDirection::PositiveClockwise,
+        CompassReference::TrueNorth,
+    ))
+}
+
+fn format_heading(heading: Option<Heading>) -> String {
+    format_f64(heading.map(|h| h.angle().as_degrees().value()), 2)
+}
+
+///
+/// HDT - Heading, True
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct HDT {
+    pub talker: TalkerID,
+    pub heading: Option<Heading>,
+}
+
+impl Display for HDT {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Talker[{}]", self.talker)?;
+        if let Some(hdg) = self.heading {
+            write!(f, " HDG[{hdg}]")?;
+        }
+        Ok(())
+    }
+}
+
+impl Packet for HDT {
+    type PacketType = MessageType;
+
+    fn get_bytes(&self) -> Result<Vec<u8>, BitsError> {
+        let heading = format_heading(self.heading);
+        Ok(finish_sentence(format!("${}HDT,{heading},T*", self.talker)))
+    }
+
+    fn get_type(&self) -> Self::PacketType {
+        MessageType::HDT
+    }
+}
+
+pub struct HDTBuilder;
+impl PacketBuilder<HDT> for HDTBuilder {
+    type Error = Error;
+
+    fn build_from<T: Bits>(&self, input: &mut T) -> Result<HDT, Self::Error> {
+        let buf = input.read_all_str_lossy()?;
+
+        let mut split = strip_checksum(&buf).split(',');
+        let talker = split
+            .next()
+            .and_then(TalkerID::from_sender)
+            .unwrap_or_default();
+        let heading = maybe_heading(split.next());
+        Ok(HDT { talker, heading })
+    }
+}
+
+///
+/// THS - True Heading and Status
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct THS {
+    pub talker: TalkerID,
+    pub heading: Option<Heading>,
+    /// Autonomous, Estimated (dead reckoning), Manual input, Simulator, or Valid (not valid)
+    pub mode: ModeIndicator,
+}
+
+impl Display for THS {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Talker[{}]", self.talker)?;
+        if let Some(hdg) = self.heading {
+            write!(f, " HDG[{hdg}]")?;
+        }
+        write!(f, " MODE[{:?}]", self.mode)
+    }
+}
+
+impl Packet for THS {
+    type PacketType = MessageType;
+
+    fn get_bytes(&self) -> Result<Vec<u8>, BitsError> {
+        let heading = format_heading(self.heading);
+        let mode = self.mode.as_char().map(String::from).unwrap_or_default();
+        Ok(finish_sentence(format!(
+            "${}THS,{heading},{mode}*",
+            self.talker
+        )))
+    }
+
+    fn get_type(&self) -> Self::PacketType {
+        MessageType::THS
+    }
+}
+
+pub struct THSBuilder;
+impl PacketBuilder<THS> for THSBuilder {
+    type Error = Error;
+
+    fn build_from<T: Bits>(&self, input: &mut T) -> Result<THS, Self::Error> {
+        let buf = input.read_all_str_lossy()?;
+
+        let mut split = strip_checksum(&buf).split(',');
+        let talker = split
+            .next()
+            .and_then(TalkerID::from_sender)
+            .unwrap_or_default();
+        let heading = maybe_heading(split.next());
+        let mode = split.next().into();
+        Ok(THS {
+            talker,
+            heading,
+            mode,
+        })
+    }
+}
@@ -0,0 +1,343 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! u-blox proprietary `$PUBX` sentences
+
+use std::fmt::{Display, Formatter};
+
+use irox_bits::{Bits, BitsError};
+use irox_carto::altitude::{Altitude, AltitudeReferenceFrame};
+use irox_carto::coordinate::{Latitude, Longitude};
+use irox_carto::gps::DilutionOfPrecision;
+use irox_time::gregorian::Date;
+use irox_time::Time;
+use irox_tools::options::MaybeInto;
+use irox_tools::packetio::{Packet, PacketBuilder};
+use irox_units::units::compass::{CompassReference, Track};
+use irox_units::units::duration::Duration;
+use irox_units::units::length::Length;
+use irox_units::units::speed::{Speed, SpeedUnits};
+use irox_units::units::Unit;
+
+use crate::vtg::maybe_track;
+use crate::{
+    finish_sentence, format_f64, format_latitude, format_longitude, format_meters,
+    format_timestamp, maybe_date, maybe_f64, maybe_latitude, maybe_longitude, maybe_meters,
+    maybe_timestamp, strip_checksum, Error, ErrorType, MessageType,
+};
+
+///
+/// The navigation status reported in [`PUBXPosition`]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum PUBXNavStatus {
+    /// `NF` - No Fix
+    NoFix,
+    /// `DR` - Dead reckoning only
+    DeadReckoning,
+    /// `G2` - Stand-alone 2D
+    Standalone2D,
+    /// `G3` - Stand-alone 3D
+    Standalone3D,
+    /// `D2` - Differential 2D
+    Differential2D,
+    /// `D3` - Differential 3D
+    Differential3D,
+    /// `RK` - Combined GNSS + dead reckoning
+    CombinedDR,
+    /// `TT` - Time only
+    TimeOnly,
+    #[default]
+    Unknown,
+}
+
+impl PUBXNavStatus {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            PUBXNavStatus::NoFix => "NF",
+            PUBXNavStatus::DeadReckoning => "DR",
+            PUBXNavStatus::Standalone2D => "G2",
+            PUBXNavStatus::Standalone3D => "G3",
+            PUBXNavStatus::Differential2D => "D2",
+            PUBXNavStatus::Differential3D => "D3",
+            PUBXNavStatus::CombinedDR => "RK",
+            PUBXNavStatus::TimeOnly => "TT",
+            PUBXNavStatus::Unknown => "",
+        }
+    }
+}
+
+impl From<Option<&str>> for PUBXNavStatus {
+    fn from(value: Option<&str>) -> Self {
+        match value {
+            Some("NF") => PUBXNavStatus::NoFix,
+            Some("DR") => PUBXNavStatus::DeadReckoning,
+            Some("G2") => PUBXNavStatus::Standalone2D,
+            Some("G3") => PUBXNavStatus::Standalone3D,
+            Some("D2") => PUBXNavStatus::Differential2D,
+            Some("D3") => PUBXNavStatus::Differential3D,
+            Some("RK") => PUBXNavStatus::CombinedDR,
+            Some("TT") => PUBXNavStatus::TimeOnly,
+            _ => PUBXNavStatus::Unknown,
+        }
+    }
+}
+
+///
+/// `$PUBX,00` - Lat/Long Position Data
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct PUBXPosition {
+    pub timestamp: Option<Time>,
+    pub latitude: Option<Latitude>,
+    pub longitude: Option<Longitude>,
+    /// Altitude above the user datum ellipsoid
+    pub altitude: Option<Altitude>,
+    pub nav_status: PUBXNavStatus,
+    /// Horizontal accuracy estimate
+    pub horizontal_accuracy: Option<Length>,
+    /// Vertical accuracy estimate
+    pub vertical_accuracy: Option<Length>,
+    /// Speed over ground
+    pub speed: Option<Speed>,
+    /// Course over ground
+    pub course: Option<Track>,
+    /// Vertical velocity, positive downwards
+    pub vertical_velocity: Option<Speed>,
+    /// Age of the differential corrections
+    pub dgps_age: Option<Duration>,
+    pub hdop: Option<DilutionOfPrecision>,
+    pub vdop: Option<DilutionOfPrecision>,
+    pub tdop: Option<DilutionOfPrecision>,
+    /// Number of satellites used in the navigation solution
+    pub num_sats: Option<u8>,
+    /// Dead reckoning used
+    pub dead_reckoning: bool,
+}
+
+///
+/// `$PUBX,04` - Time of Day and Clock Information
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct PUBXTime {
+    pub timestamp: Option<Time>,
+    pub date: Option<Date>,
+    /// UTC time of week, in seconds
+    pub time_of_week: Option<f64>,
+    /// UTC week number, continuing beyond 1023
+    pub week: Option<u16>,
+    /// Leap seconds
+    pub leap_seconds: Option<i8>,
+    /// Whether the leap seconds are the firmware default, rather than from the almanac
+    pub leap_seconds_default: bool,
+    /// Receiver clock bias, in nanoseconds
+    pub clock_bias: Option<f64>,
+    /// Receiver clock drift, in nanoseconds per second
+    pub clock_drift: Option<f64>,
+    /// Time pulse granularity, in nanoseconds
+    pub timepulse_granularity: Option<u32>,
+}
+
+///
+/// A u-blox `$PUBX` proprietary sentence
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PUBX {
+    Position(PUBXPosition),
+    Time(PUBXTime),
+}
+
+impl Display for PUBX {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PUBX::Position(pos) => {
+                write!(f, "Position ")?;
+                if let Some(ts) = pos.timestamp {
+                    write!(f, "Time[{ts}] ")?;
+                }
+                if let Some(lat) = pos.latitude {
+                    write!(f, "{lat} ")?;
+                }
+                if let Some(lon) = pos.longitude {
+                    write!(f, "{lon} ")?;
+                }
+                if let Some(alt) = pos.altitude {
+                    write!(f, "Alt[{alt}] ")?;
+                }
+                write!(f, "Status[{:?}]", pos.nav_status)?;
+                if let Some(sats) = pos.num_sats {
+                    write!(f, " Sats[{sats}]")?;
+                }
+                Ok(())
+            }
+            PUBX::Time(time) => {
+                write!(f, "Time ")?;
+                if let Some(ts) = time.timestamp {
+                    write!(f, "Time[{ts}] ")?;
+                }
+                if let Some(date) = time.date {
+                    write!(f, "Date[{date}] ")?;
+                }
+                if let (Some(week), Some(tow)) = (time.week, time.time_of_week) {
+                    write!(f, "Week[{week}] TOW[{tow}] ")?;
+                }
+                if let Some(leap) = time.leap_seconds {
+                    write!(f, "Leap[{leap}]")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Packet for PUBX {
+    type PacketType = MessageType;
+
+    fn get_bytes(&self) -> Result<Vec<u8>, BitsError> {
+        let sentence = match self {
+            PUBX::Position(pos) => {
+                let timestamp = format_timestamp(pos.timestamp);
+                let latitude = format_latitude(pos.latitude);
+                let longitude = format_longitude(pos.longitude);
+                let altitude = format_meters(pos.altitude.map(|a| a.value()), 3);
+                let status = pos.nav_status.as_str();
+                let hacc = format_meters(pos.horizontal_accuracy, 1);
+                let vacc = format_meters(pos.vertical_accuracy, 1);
+                let speed = format_f64(
+                    pos.speed
+                        .map(|s| s.as_unit(SpeedUnits::KilometersPerHour).value()),
+                    3,
+                );
+                let course = format_f64(pos.course.map(|c| c.angle().as_degrees().value()), 2);
+                let vvel = format_f64(
+                    pos.vertical_velocity
+                        .map(|s| s.as_meters_per_second().value()),
+                    3,
+                );
+                let dgps_age = pos
+                    .dgps_age
+                    .map(|age| format!("{:.0}", age.as_seconds_f64()))
+                    .unwrap_or_default();
+                let hdop = format_f64(pos.hdop.map(|d| d.0), 2);
+                let vdop = format_f64(pos.vdop.map(|d| d.0), 2);
+                let tdop = format_f64(pos.tdop.map(|d| d.0), 2);
+                let sats = pos.num_sats.map(|s| format!("{s}")).unwrap_or_default();
+                let dr = u8::from(pos.dead_reckoning);
+                format!("$PUBX,00,{timestamp},{latitude},{longitude},{altitude},{status},{hacc},{vacc},{speed},{course},{vvel},{dgps_age},{hdop},{vdop},{tdop},{sats},0,{dr}*")
+            }
+            PUBX::Time(time) => {
+                let timestamp = format_timestamp(time.timestamp);
+                let date = time
+                    .date
+                    .map(|d| {
+                        format!(
+                            "{:02}{:02}{:02}",
+                            d.day_of_month() + 1,
+                            d.month_of_year() as u8,
+                            d.year().rem_euclid(100)
+                        )
+                    })
+                    .unwrap_or_default();
+                let tow = format_f64(time.time_of_week, 2);
+                let week = time.week.map(|w| format!("{w}")).unwrap_or_default();
+                let leap = time
+                    .leap_seconds
+                    .map(|l| {
+                        let default = if time.leap_seconds_default { "D" } else { "" };
+                        format!("{l}{default}")
+                    })
+                    .unwrap_or_default();
+                let bias = format_f64(time.clock_bias, 0);
+                let drift = format_f64(time.clock_drift, 3);
+                let gran = time
+                    .timepulse_granularity
+                    .map(|g| format!("{g}"))
+                    .unwrap_or_default();
+                format!("$PUBX,04,{timestamp},{date},{tow},{week},{leap},{bias},{drift},{gran},*")
+            }
+        };
+        Ok(finish_sentence(sentence))
+    }
+
+    fn get_type(&self) -> Self::PacketType {
+        MessageType::PUBX
+    }
+}
+
+pub struct PUBXBuilder;
+impl PacketBuilder<PUBX> for PUBXBuilder {
+    type Error = Error;
+
+    fn build_from<T: Bits>(&self, input: &mut T) -> Result<PUBX, Self::Error> {
+        let buf = input.read_all_str_lossy()?;
+
+        let mut split = strip_checksum(&buf).split(',');
+        let _key = split.next();
+        match split.next() {
+            Some("00") => {
+                let timestamp = maybe_timestamp(split.next());
+                let latitude = maybe_latitude(split.next(), split.next());
+                let longitude = maybe_longitude(split.next(), split.next());
+                let altitude = maybe_meters(split.next())
+                    .map(|a| Altitude::new(a, AltitudeReferenceFrame::Ellipsoid));
+                let nav_status = split.next().into();
+                let horizontal_accuracy = maybe_meters(split.next());
+                let vertical_accuracy = maybe_meters(split.next());
+                let speed =
+                    maybe_f64(split.next()).map(|s| Speed::new(s, SpeedUnits::KilometersPerHour));
+                let course = maybe_track(split.next(), CompassReference::TrueNorth);
+                let vertical_velocity = maybe_f64(split.next()).map(Speed::new_meters_per_second);
+                let dgps_age = maybe_f64(split.next()).map(Duration::from_seconds_f64);
+                let hdop = maybe_f64(split.next()).map(DilutionOfPrecision);
+                let vdop = maybe_f64(split.next()).map(DilutionOfPrecision);
+                let tdop = maybe_f64(split.next()).map(DilutionOfPrecision);
+                let num_sats = split.next().maybe_into();
+                let _reserved = split.next();
+                let dead_reckoning = split.next().is_some_and(|dr| dr != "0" && !dr.is_empty());
+                Ok(PUBX::Position(PUBXPosition {
+                    timestamp,
+                    latitude,
+                    longitude,
+                    altitude,
+                    nav_status,
+                    horizontal_accuracy,
+                    vertical_accuracy,
+                    speed,
+                    course,
+                    vertical_velocity,
+                    dgps_age,
+                    hdop,
+                    vdop,
+                    tdop,
+                    num_sats,
+                    dead_reckoning,
+                }))
+            }
+            Some("04") => {
+                let timestamp = maybe_timestamp(split.next());
+                let date = maybe_date(split.next());
+                let time_of_week = maybe_f64(split.next());
+                let week = split.next().maybe_into();
+                let leap = split.next().unwrap_or_default();
+                let leap_seconds_default = leap.ends_with('D');
+                let leap_seconds = leap.trim_end_matches('D').parse().ok();
+                let clock_bias = maybe_f64(split.next());
+                let clock_drift = maybe_f64(split.next());
+                let timepulse_granularity = split.next().maybe_into();
+                Ok(PUBX::Time(PUBXTime {
+                    timestamp,
+                    date,
+                    time_of_week,
+                    week,
+                    leap_seconds,
+                    leap_seconds_default,
+                    clock_bias,
+                    clock_drift,
+                    timepulse_granularity,
+                }))
+            }
+            other => Err(Error::new(
+                ErrorType::BadValue,
+                format!("Unsupported PUBX message: {other:?}"),
+            )),
+        }
+    }
+}
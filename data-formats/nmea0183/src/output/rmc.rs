@@ -24,6 +24,15 @@ pub enum RMCStatus {
     #[default]
     UnknownUnset,
 }
+impl RMCStatus {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            RMCStatus::Valid => "A",
+            RMCStatus::Warning => "V",
+            RMCStatus::UnknownUnset => "",
+        }
+    }
+}
 impl From<Option<&str>> for RMCStatus {
     fn from(value: Option<&str>) -> Self {
         if let Some(val) = value {
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use std::fmt::{Display, Formatter};
+
+use irox_bits::{Bits, BitsError};
+use irox_tools::options::MaybeInto;
+use irox_tools::packetio::{Packet, PacketBuilder};
+
+use crate::{finish_sentence, strip_checksum, Error, MessageType, TalkerID};
+
+///
+/// The severity of a [`TXT`] message
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum TextIdentifier {
+    Error,
+    Warning,
+    Notice,
+    User,
+    #[default]
+    Unknown,
+    Other(u8),
+}
+
+impl TextIdentifier {
+    pub const fn value(&self) -> Option<u8> {
+        Some(match self {
+            TextIdentifier::Error => 0,
+            TextIdentifier::Warning => 1,
+            TextIdentifier::Notice => 2,
+            TextIdentifier::User => 7,
+            TextIdentifier::Other(v) => *v,
+            TextIdentifier::Unknown => return None,
+        })
+    }
+}
+
+impl From<Option<u8>> for TextIdentifier {
+    fn from(value: Option<u8>) -> Self {
+        match value {
+            Some(0) => TextIdentifier::Error,
+            Some(1) => TextIdentifier::Warning,
+            Some(2) => TextIdentifier::Notice,
+            Some(7) => TextIdentifier::User,
+            Some(v) => TextIdentifier::Other(v),
+            None => TextIdentifier::Unknown,
+        }
+    }
+}
+
+///
+/// TXT - Text Transmission, used for receiver startup banners, warnings & errors.  Long messages
+/// are split across multiple sentences.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TXT {
+    pub talker: TalkerID,
+    pub sentence_total: u8,
+    pub sentence_idx: u8,
+    pub identifier: TextIdentifier,
+    pub text: String,
+}
+
+impl Display for TXT {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Talker[{}] [{}/{}] {:?}: {}",
+            self.talker, self.sentence_idx, self.sentence_total, self.identifier, self.text
+        )
+    }
+}
+
+impl Packet for TXT {
+    type PacketType = MessageType;
+
+    fn get_bytes(&self) -> Result<Vec<u8>, BitsError> {
+        let identifier = self
+            .identifier
+            .value()
+            .map(|v| format!("{v:02}"))
+            .unwrap_or_default();
+        // the delimiters aren't allowed in the text.
+        let text: String = self
+            .text
+            .chars()
+            .filter(|c| !matches!(c, '$' | '*' | ',' | '!' | '\r' | '\n'))
+            .collect();
+        Ok(finish_sentence(format!(
+            "${}TXT,{:02},{:02},{identifier},{text}*",
+            self.talker, self.sentence_total, self.sentence_idx
+        )))
+    }
+
+    fn get_type(&self) -> Self::PacketType {
+        MessageType::TXT
+    }
+}
+
+pub struct TXTBuilder;
+impl PacketBuilder<TXT> for TXTBuilder {
+    type Error = Error;
+
+    fn build_from<T: Bits>(&self, input: &mut T) -> Result<TXT, Self::Error> {
+        let buf = input.read_all_str_lossy()?;
+
+        let mut split = strip_checksum(&buf).splitn(5, ',');
+        let talker = split
+            .next()
+            .and_then(TalkerID::from_sender)
+            .unwrap_or_default();
+        let sentence_total = split.next().maybe_into().unwrap_or_default();
+        let sentence_idx = split.next().maybe_into().unwrap_or_default();
+        let identifier = MaybeInto::<u8>::maybe_into(split.next()).into();
+        let text = split.next().unwrap_or_default().to_string();
+        Ok(TXT {
+            talker,
+            sentence_total,
+            sentence_idx,
+            identifier,
+            text,
+        })
+    }
+}
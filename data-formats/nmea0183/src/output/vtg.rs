@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use std::fmt::{Display, Formatter};
+
+use irox_bits::{Bits, BitsError};
+use irox_tools::packetio::{Packet, PacketBuilder};
+use irox_units::units::angle::Angle;
+use irox_units::units::compass::{CompassReference, RotationDirection, Track};
+use irox_units::units::speed::{Speed, SpeedUnits};
+use irox_units::units::Unit;
+
+use crate::{
+    finish_sentence, format_f64, maybe_f64, strip_checksum, Error, MessageType, ModeIndicator,
+    TalkerID,
+};
+
+///
+/// VTG - Track made good and Ground speed
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct VTG {
+    pub talker: TalkerID,
+    /// Track made good, referenced to true north
+    pub true_track: Option<Track>,
+    /// Track made good, referenced to magnetic north
+    pub magnetic_track: Option<Track>,
+    /// Speed over ground, sent in both knots & km/h
+    pub speed: Option<Speed>,
+    pub mode: ModeIndicator,
+}
+
+impl Display for VTG {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Talker[{}] ", self.talker)?;
+        if let Some(trk) = self.true_track {
+            write!(f, "TRK[{trk}] ")?;
+        }
+        if let Some(trk) = self.magnetic_track {
+            write!(f, "MAGTRK[{trk}] ")?;
+        }
+        if let Some(spd) = self.speed {
+            write!(f, "SPD[{spd}] ")?;
+        }
+        write!(f, "MODE[{:?}]", self.mode)
+    }
+}
+
+impl Packet for VTG {
+    type PacketType = MessageType;
+
+    fn get_bytes(&self) -> Result<Vec<u8>, BitsError> {
+        let degrees = |t: Option<Track>| t.map(|t| t.angle().as_degrees().value());
+        let true_track = format_f64(degrees(self.true_track), 2);
+        let magnetic_track = format_f64(degrees(self.magnetic_track), 2);
+        let knots = format_f64(self.speed.map(|s| s.as_unit(SpeedUnits::Knots).value()), 3);
+        let kph = format_f64(
+            self.speed
+                .map(|s| s.as_unit(SpeedUnits::KilometersPerHour).value()),
+            3,
+        );
+        let mode = self
+            .mode
+            .as_char()
+            .map(|m| format!(",{m}"))
+            .unwrap_or_default();
+        Ok(finish_sentence(format!(
+            "${}VTG,{true_track},T,{magnetic_track},M,{knots},N,{kph},K{mode}*",
+            self.talker
+        )))
+    }
+
+    fn get_type(&self) -> Self::PacketType {
+        MessageType::VTG
+    }
+}
+
+pub(crate) fn maybe_track(val: Option<&str>, reference: CompassReference) -> Option<Track> {
+    Some(Track::new_track(
+        Angle::new_degrees(maybe_f64(val)?),
+        RotationDirection::PositiveClockwise,
+        reference,
+    ))
+}
+
+pub struct VTGBuilder;
+impl PacketBuilder<VTG> for VTGBuilder {
+    type Error = Error;
+
+    fn build_from<T: Bits>(&self, input: &mut T) -> Result<VTG, Self::Error> {
+        let buf = input.read_all_str_lossy()?;
+
+        let mut split = strip_checksum(&buf).split(',');
+        let talker = split
+            .next()
+            .and_then(TalkerID::from_sender)
+            .unwrap_or_default();
+        let true_track = maybe_track(split.next(), CompassReference::TrueNorth);
+        let _t = split.next();
+        let magnetic_track = maybe_track(split.next(), CompassReference::MagneticNorth);
+        let _m = split.next();
+        let knots = maybe_f64(split.next()).map(|v| Speed::new(v, SpeedUnits::Knots));
+        let _n = split.next();
+        let kph = maybe_f64(split.next()).map(|v| Speed::new(v, SpeedUnits::KilometersPerHour));
+        let _k = split.next();
+        let mode = split.next().into();
+
+        Ok(VTG {
+            talker,
+            true_track,
+            magnetic_track,
+            speed: knots.or(kph),
+            mode,
+        })
+    }
+}
@@ -2,16 +2,19 @@
 // Copyright 2023 IROX Contributors
 //
 
-use crate::{calculate_checksum, MessageType};
-use irox_bits::Error;
+use crate::{calculate_checksum, maybe_timestamp, strip_checksum, MessageType, TalkerID};
+use irox_bits::{Bits, Error};
 use irox_time::datetime::UTCDateTime;
 use irox_time::gregorian::Date;
 use irox_time::Time;
 use irox_tools::fmt::DecimalFormatF64;
-use irox_tools::packetio::Packet;
+use irox_tools::options::MaybeInto;
+use irox_tools::packetio::{Packet, PacketBuilder};
+use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct ZDA {
+    pub talker: TalkerID,
     pub utc_time: Option<Time>,
     pub utc_day: Option<u8>,
     pub utc_month: Option<u8>,
@@ -20,6 +23,23 @@ pub struct ZDA {
     pub utc_local_zone_minute: Option<u8>,
 }
 
+impl Display for ZDA {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Talker[{}]", self.talker)?;
+        if let Some(ts) = self.utc_time {
+            write!(f, " Time[{ts}]")?;
+        }
+        if let (Some(year), Some(month), Some(day)) = (self.utc_year, self.utc_month, self.utc_day)
+        {
+            write!(f, " Date[{year:04}-{month:02}-{day:02}]")?;
+        }
+        let hours = self.utc_local_zone_hours.unwrap_or_default();
+        let minutes = self.utc_local_zone_minute.unwrap_or_default();
+        let sign = if hours < 0 { "-" } else { "+" };
+        write!(f, " Zone[{sign}{:02}:{minutes:02}]", hours.unsigned_abs())
+    }
+}
+
 impl Packet for ZDA {
     type PacketType = MessageType;
 
@@ -48,14 +68,18 @@ impl Packet for ZDA {
             .unwrap_or_default();
         let lzh = self
             .utc_local_zone_hours
-            .map(|lzh| format!("{lzh:02}"))
+            .map(|lzh| {
+                let sign = if lzh < 0 { "-" } else { "" };
+                format!("{sign}{:02}", lzh.unsigned_abs())
+            })
             .unwrap_or("00".to_string());
         let lzm = self
             .utc_local_zone_minute
             .map(|lzm| format!("{lzm:02}"))
             .unwrap_or("00".to_string());
         buf.write_fmt(format_args!(
-            "$GPZDA,{utctime},{day},{month},{year},{lzh},{lzm}*"
+            "${}ZDA,{utctime},{day},{month},{year},{lzh},{lzm}*",
+            self.talker
         ))?;
 
         let csh = calculate_checksum(&buf);
@@ -75,6 +99,10 @@ pub struct ZDABuilder {
 }
 
 impl ZDABuilder {
+    pub fn with_talker(&mut self, talker: TalkerID) -> &mut Self {
+        self.zda.talker = talker;
+        self
+    }
     pub fn with_utc_time(&mut self, time: Time) -> &mut Self {
         self.zda.utc_time = Some(time);
         self
@@ -104,7 +132,7 @@ impl ZDABuilder {
     }
 
     pub fn with_date(&mut self, date: Date) -> &mut Self {
-        self.with_utc_day(date.day_of_month());
+        self.with_utc_day(date.day_of_month() + 1);
         self.with_utc_month(date.month_of_year() as u8);
         self.with_utc_year(date.year());
         self
@@ -121,13 +149,37 @@ impl ZDABuilder {
     }
 }
 
+impl PacketBuilder<ZDA> for ZDABuilder {
+    type Error = crate::error::Error;
+
+    fn build_from<T: Bits>(&self, input: &mut T) -> Result<ZDA, Self::Error> {
+        let buf = input.read_all_str_lossy()?;
+
+        let mut split = strip_checksum(&buf).split(',');
+        let talker = split
+            .next()
+            .and_then(TalkerID::from_sender)
+            .unwrap_or_default();
+        Ok(ZDA {
+            talker,
+            utc_time: maybe_timestamp(split.next()),
+            utc_day: split.next().maybe_into(),
+            utc_month: split.next().maybe_into(),
+            utc_year: split.next().maybe_into(),
+            utc_local_zone_hours: split.next().maybe_into(),
+            utc_local_zone_minute: split.next().maybe_into(),
+        })
+    }
+}
+
 impl From<UTCDateTime> for ZDA {
     fn from(value: UTCDateTime) -> Self {
         let date = value.get_date();
-        let utc_day = Some(date.day_of_month());
+        let utc_day = Some(date.day_of_month() + 1);
         let utc_month = Some(date.month_of_year() as u8);
         let utc_year = Some(date.year());
         ZDA {
+            talker: TalkerID::default(),
             utc_time: Some(value.get_time()),
             utc_day,
             utc_month,
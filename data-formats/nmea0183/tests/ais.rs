@@ -0,0 +1,212 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use irox_nmea0183::ais::{
+    AISMessage, AISReassembler, EPFDType, NavigationStatus, RateOfTurn, StaticDataPart, AIVDM,
+};
+use irox_nmea0183::{Error, Frame, FramePayload, NMEAParser, TalkerID};
+use irox_tools::packetio::{Packet, PacketBuilder};
+use irox_units::units::length::Length;
+use irox_units::units::speed::SpeedUnits;
+use irox_units::units::Unit;
+
+fn parse(sentence: &str) -> Result<AIVDM, Error> {
+    let frame = NMEAParser.build_from(&mut sentence.as_bytes())?;
+    let FramePayload::AIVDM(vdm) = frame.payload else {
+        panic!("Expecting AIVDM but was {:?}", frame.payload);
+    };
+    Ok(vdm)
+}
+
+fn decode(sentence: &str) -> Result<AISMessage, Error> {
+    parse(sentence)?.decode()
+}
+
+fn assert_near(expected: f64, actual: Option<f64>) {
+    let actual = actual.unwrap_or(f64::NAN);
+    assert!((expected - actual).abs() < 1e-6, "{expected} != {actual}");
+}
+
+#[test]
+pub fn test_sentence() -> Result<(), Error> {
+    let test = "!AIVDM,1,1,,B,15NG6V0P01G?cFhE`R2IU?wn28R>,0*05\r\n";
+    let vdm = parse(test)?;
+    assert_eq!(TalkerID::AIS, vdm.talker);
+    assert!(!vdm.own_vessel);
+    assert_eq!(1, vdm.fragment_count);
+    assert_eq!(1, vdm.fragment_number);
+    assert_eq!(None, vdm.sequence_id);
+    assert_eq!(Some('B'), vdm.channel);
+    assert_eq!(0, vdm.fill_bits);
+
+    let frame = Frame {
+        payload: FramePayload::AIVDM(vdm),
+        raw: None,
+    };
+    assert_eq!(test.as_bytes(), frame.get_bytes()?);
+
+    let vdo = parse("!AIVDO,1,1,,,B5NJ;PP005l4ot5Isbl03wsUkP06,0*35\r\n")?;
+    assert!(vdo.own_vessel);
+    assert_eq!(None, vdo.channel);
+    Ok(())
+}
+
+#[test]
+pub fn test_position_report() -> Result<(), Error> {
+    let AISMessage::PositionReport(pos) =
+        decode("!AIVDM,1,1,,B,15NG6V0P01G?cFhE`R2IU?wn28R>,0*05")?
+    else {
+        panic!("Expecting position report");
+    };
+    assert_eq!(1, pos.message_type);
+    assert_eq!(367_380_120, pos.mmsi);
+    assert_eq!(NavigationStatus::UnderWayUsingEngine, pos.nav_status);
+    assert_eq!(RateOfTurn::NotAvailable, pos.rate_of_turn);
+    assert_near(0.1, pos.speed.map(|s| s.as_unit(SpeedUnits::Knots).value()));
+    assert_near(
+        -122.404_333,
+        pos.longitude.map(|l| l.0.as_degrees().value()),
+    );
+    assert_near(37.806_948, pos.latitude.map(|l| l.0.as_degrees().value()));
+    assert_near(245.2, pos.course.map(|c| c.angle().as_degrees().value()));
+    assert_eq!(None, pos.heading);
+    assert_eq!(59, pos.utc_second);
+    assert!(pos.raim);
+    assert_eq!(34958, pos.radio_status);
+    Ok(())
+}
+
+#[test]
+pub fn test_static_voyage() -> Result<(), Error> {
+    let mut reasm = AISReassembler::new();
+    let first = parse(
+        "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C\r\n",
+    )?;
+    let second = parse("!AIVDM,2,2,1,A,88888888880,2*25\r\n")?;
+    assert!(first.decode().is_err());
+
+    // out of order fragments are dropped.
+    assert_eq!(None, reasm.push(second.clone())?);
+    assert_eq!(None, reasm.push(first)?);
+    let Some(AISMessage::StaticVoyageData(data)) = reasm.push(second)? else {
+        panic!("Expecting static voyage data");
+    };
+    assert_eq!(351_759_000, data.mmsi);
+    assert_eq!(9_134_270, data.imo);
+    assert_eq!("3FOF8", data.callsign);
+    assert_eq!("EVER DIADEM", data.name);
+    assert_eq!(70, data.ship_type);
+    assert_eq!(225, data.dimensions.to_bow);
+    assert_eq!(70, data.dimensions.to_stern);
+    assert_eq!(1, data.dimensions.to_port);
+    assert_eq!(31, data.dimensions.to_starboard);
+    assert_eq!(Length::new_meters(295.), data.dimensions.length());
+    assert_eq!(EPFDType::GPS, data.epfd);
+    assert_eq!(5, data.eta.month);
+    assert_eq!(15, data.eta.day);
+    assert_eq!(14, data.eta.hour);
+    assert_eq!(0, data.eta.minute);
+    assert_near(12.2, Some(data.draught.as_meters().value()));
+    assert_eq!("NEW YORK", data.destination);
+    assert!(!data.dte);
+    Ok(())
+}
+
+#[test]
+pub fn test_class_b() -> Result<(), Error> {
+    let AISMessage::ClassBPositionReport(pos) =
+        decode("!AIVDM,1,1,,B,B5NJ;PP005l4ot5Isbl03wsUkP06,0*75")?
+    else {
+        panic!("Expecting class B position report");
+    };
+    assert_eq!(367_430_530, pos.mmsi);
+    assert_near(0.0, pos.speed.map(|s| s.as_unit(SpeedUnits::Knots).value()));
+    assert_near(-122.267_32, pos.longitude.map(|l| l.0.as_degrees().value()));
+    assert_near(37.785_035, pos.latitude.map(|l| l.0.as_degrees().value()));
+    assert_near(0.0, pos.course.map(|c| c.angle().as_degrees().value()));
+    assert_eq!(None, pos.heading);
+    assert_eq!(55, pos.utc_second);
+    assert!(pos.cs_unit);
+
+    let AISMessage::ClassBExtendedReport(ext) =
+        decode("!AIVDM,1,1,,B,C5N3SRgPEnJGEBT>NhWAwwo862PaLELTBJ:V00000000S0D:R220,0*0B")?
+    else {
+        panic!("Expecting class B extended report");
+    };
+    assert_eq!(367_059_850, ext.mmsi);
+    assert_near(8.7, ext.speed.map(|s| s.as_unit(SpeedUnits::Knots).value()));
+    assert_near(-88.810_392, ext.longitude.map(|l| l.0.as_degrees().value()));
+    assert_near(29.543_695, ext.latitude.map(|l| l.0.as_degrees().value()));
+    assert_near(335.9, ext.course.map(|c| c.angle().as_degrees().value()));
+    assert_eq!("CAPT.J.RIMES", ext.name);
+    assert_eq!(70, ext.ship_type);
+    assert_eq!(5, ext.dimensions.to_bow);
+    assert_eq!(21, ext.dimensions.to_stern);
+    assert_eq!(EPFDType::GPS, ext.epfd);
+    Ok(())
+}
+
+#[test]
+pub fn test_static_data() -> Result<(), Error> {
+    let msg = decode("!AIVDM,1,1,,A,H42O55i18tMET00000000000000,2*6D")?;
+    assert_eq!(24, msg.message_type());
+    assert_eq!(271_041_815, msg.mmsi());
+    let AISMessage::StaticDataReport(a) = msg else {
+        panic!("Expecting static data report");
+    };
+    assert_eq!(
+        StaticDataPart::A {
+            name: "PROGUY".to_string()
+        },
+        a.part
+    );
+
+    let AISMessage::StaticDataReport(b) =
+        decode("!AIVDM,1,1,,A,H42O55lti4hhhilD3nink000?050,0*40")?
+    else {
+        panic!("Expecting static data report");
+    };
+    let StaticDataPart::B(b) = b.part else {
+        panic!("Expecting part B");
+    };
+    assert_eq!(60, b.ship_type);
+    assert_eq!("1D0", b.vendor_id);
+    assert_eq!("TC6163", b.callsign);
+    assert_eq!(None, b.mothership_mmsi);
+    let dims = b.dimensions.unwrap_or_default();
+    assert_eq!(15, dims.to_stern);
+    assert_eq!(5, dims.to_starboard);
+    Ok(())
+}
+
+#[test]
+pub fn test_aid_to_navigation() -> Result<(), Error> {
+    let AISMessage::AidToNavigation(aton) =
+        decode("!AIVDM,1,1,,B,E>kb9O9aS@7PUh10dh19@;0Tah2cWrfP:l?M`00003vP100,0*01")?
+    else {
+        panic!("Expecting aid-to-navigation report");
+    };
+    assert_eq!(993_692_028, aton.mmsi);
+    assert_eq!(19, aton.aid_type);
+    assert_eq!("SF OAK BAY BR VAIS E", aton.name);
+    assert_near(
+        -122.369_867,
+        aton.longitude.map(|l| l.0.as_degrees().value()),
+    );
+    assert_near(37.805_622, aton.latitude.map(|l| l.0.as_degrees().value()));
+    assert_eq!(EPFDType::Surveyed, aton.epfd);
+    assert_eq!(61, aton.utc_second);
+    assert!(!aton.off_position);
+    assert!(aton.virtual_aid);
+    Ok(())
+}
+
+#[test]
+pub fn test_bad_payload() -> Result<(), Error> {
+    // truncated position report
+    assert!(AISMessage::decode("15NG6V0P01G?cFhE", 0).is_err());
+    // invalid armor character
+    assert!(AISMessage::decode("15NG6V0P01G?cFhE~R2IU?wn28R>", 0).is_err());
+    Ok(())
+}
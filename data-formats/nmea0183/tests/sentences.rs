@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use irox_carto::coordinate::Latitude;
+use irox_nmea0183::gsa::GNSSSystemID;
+use irox_nmea0183::pubx::{PUBXNavStatus, PUBX};
+use irox_nmea0183::txt::TextIdentifier;
+use irox_nmea0183::{Error, Frame, FramePayload, ModeIndicator, NMEAParser, TalkerID};
+use irox_time::gregorian::Date;
+use irox_time::Time;
+use irox_tools::packetio::{Packet, PacketBuilder};
+use irox_units::units::angle::Angle;
+use irox_units::units::length::Length;
+
+/// Parses the sentence, and returns it along with it re-encoded from the parsed values.
+fn parse(sentence: &str) -> Result<(FramePayload, String), Error> {
+    let frame = NMEAParser.build_from(&mut sentence.as_bytes())?;
+    let Frame { payload, raw } = frame;
+    assert_eq!(Some(sentence.trim()), raw.as_deref());
+    let frame = Frame {
+        payload: payload.clone(),
+        raw: None,
+    };
+    let bytes = frame.get_bytes()?;
+    Ok((payload, String::from_utf8_lossy(&bytes).to_string()))
+}
+
+#[test]
+pub fn test_vtg() -> Result<(), Error> {
+    let (payload, out) = parse("$GNVTG,054.7,T,034.4,M,005.5,N,010.2,K,A*3B\r\n")?;
+    let FramePayload::VTG(vtg) = payload else {
+        panic!("Expecting VTG but was {payload:?}");
+    };
+    assert_eq!(TalkerID::MULTI, vtg.talker);
+    assert_eq!(
+        Some(54.7),
+        vtg.true_track.map(|t| t.angle().as_degrees().value())
+    );
+    assert_eq!(ModeIndicator::Autonomous, vtg.mode);
+    assert_eq!("$GNVTG,54.70,T,34.40,M,5.500,N,10.186,K,A*06\r\n", out);
+    Ok(())
+}
+
+#[test]
+pub fn test_gll() -> Result<(), Error> {
+    let test = "$GNGLL,4404.14036,N,07118.85961,W,001043.00,A,A*6B\r\n";
+    let (payload, out) = parse(test)?;
+    let FramePayload::GLL(gll) = payload else {
+        panic!("Expecting GLL but was {payload:?}");
+    };
+    assert_eq!(TalkerID::MULTI, gll.talker);
+    assert_eq!(Some(Latitude(Angle::new_degrees(44.069006))), gll.latitude);
+    let lon = gll
+        .longitude
+        .map(|l| l.0.as_degrees().value())
+        .unwrap_or_default();
+    assert!((lon + 71.314_326_833).abs() < 1e-9, "{lon}");
+    assert_eq!(Some(Time::new(643, 0).unwrap()), gll.timestamp);
+    assert_eq!(test, out);
+    Ok(())
+}
+
+#[test]
+pub fn test_zda() -> Result<(), Error> {
+    let test = "$GPZDA,201530.00,04,07,2002,-05,00*48\r\n";
+    let (payload, out) = parse(test)?;
+    let FramePayload::ZDA(zda) = payload else {
+        panic!("Expecting ZDA but was {payload:?}");
+    };
+    assert_eq!(Some(4), zda.utc_day);
+    assert_eq!(Some(7), zda.utc_month);
+    assert_eq!(Some(2002), zda.utc_year);
+    assert_eq!(Some(-5), zda.utc_local_zone_hours);
+    let shown = zda.to_string();
+    assert!(shown.contains("Date[2002-07-04]"), "{shown}");
+    assert!(shown.contains("Zone[-05:00]"), "{shown}");
+    assert_eq!(test, out);
+    Ok(())
+}
+
+#[test]
+pub fn test_gst() -> Result<(), Error> {
+    let (payload, out) = parse("$GPGST,172814.0,0.006,0.023,0.020,273.6,0.023,0.020,0.031*6A\r\n")?;
+    let FramePayload::GST(gst) = payload else {
+        panic!("Expecting GST but was {payload:?}");
+    };
+    assert_eq!(Some(Length::new_meters(0.023)), gst.semi_major);
+    assert_eq!(Some(Length::new_meters(0.020)), gst.semi_minor);
+    assert_eq!(Some(Angle::new_degrees(273.6)), gst.orientation);
+    assert_eq!(Some(Length::new_meters(0.031)), gst.altitude_error);
+    assert_eq!(
+        "$GPGST,172814.00,0.006,0.023,0.020,273.6,0.023,0.020,0.031*5A\r\n",
+        out
+    );
+    Ok(())
+}
+
+#[test]
+pub fn test_gbs() -> Result<(), Error> {
+    let test = "$GNGBS,235458.00,1.4,1.3,3.1,03,,-21.4,3.8,1,0*44\r\n";
+    let (payload, out) = parse(test)?;
+    let FramePayload::GBS(gbs) = payload else {
+        panic!("Expecting GBS but was {payload:?}");
+    };
+    assert_eq!(Some(3), gbs.failed_sat);
+    assert_eq!(None, gbs.probability);
+    assert_eq!(Some(Length::new_meters(-21.4)), gbs.bias);
+    assert_eq!(GNSSSystemID::GPS, gbs.system_id);
+    assert_eq!(Some(0), gbs.signal_id);
+    assert_eq!(test, out);
+    Ok(())
+}
+
+#[test]
+pub fn test_heading() -> Result<(), Error> {
+    let test = "$GPHDT,274.07,T*03\r\n";
+    let (payload, out) = parse(test)?;
+    let FramePayload::HDT(hdt) = payload else {
+        panic!("Expecting HDT but was {payload:?}");
+    };
+    assert_eq!(
+        Some(274.07),
+        hdt.heading.map(|h| h.angle().as_degrees().value())
+    );
+    assert_eq!(test, out);
+
+    let test = "$GNTHS,77.52,A*2E\r\n";
+    let (payload, out) = parse(test)?;
+    let FramePayload::THS(ths) = payload else {
+        panic!("Expecting THS but was {payload:?}");
+    };
+    assert_eq!(ModeIndicator::Autonomous, ths.mode);
+    assert_eq!(test, out);
+    Ok(())
+}
+
+#[test]
+pub fn test_txt() -> Result<(), Error> {
+    let test = "$GPTXT,01,01,02,u-blox ag - www.u-blox.com*50\r\n";
+    let (payload, out) = parse(test)?;
+    let FramePayload::TXT(txt) = payload else {
+        panic!("Expecting TXT but was {payload:?}");
+    };
+    assert_eq!(TextIdentifier::Notice, txt.identifier);
+    assert_eq!("u-blox ag - www.u-blox.com", txt.text);
+    assert_eq!(test, out);
+    Ok(())
+}
+
+#[test]
+pub fn test_pubx() -> Result<(), Error> {
+    let (payload, out) = parse("$PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*5F\r\n")?;
+    let FramePayload::PUBX(PUBX::Position(pos)) = payload else {
+        panic!("Expecting PUBX,00 but was {payload:?}");
+    };
+    assert_eq!(PUBXNavStatus::Standalone3D, pos.nav_status);
+    assert_eq!(Some(Length::new_meters(2.1)), pos.horizontal_accuracy);
+    assert_eq!(Some(9), pos.num_sats);
+    assert_eq!(None, pos.dgps_age);
+    assert_eq!("$PUBX,00,081350.00,4717.11321,N,00833.91519,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*59\r\n", out);
+
+    let test = "$PUBX,04,073731.00,091202,113851.00,1196,15D,1930035,-2660.664,43,*5D\r\n";
+    let (payload, out) = parse(test)?;
+    let FramePayload::PUBX(PUBX::Time(time)) = payload else {
+        panic!("Expecting PUBX,04 but was {payload:?}");
+    };
+    assert_eq!(Some(Date::try_from_values(2002, 12, 9)?), time.date);
+    assert_eq!(Some(1196), time.week);
+    assert_eq!(Some(15), time.leap_seconds);
+    assert!(time.leap_seconds_default);
+    assert_eq!(Some(43), time.timepulse_granularity);
+    assert_eq!(test, out);
+
+    // other PUBX messages aren't supported yet
+    let frame = NMEAParser.build_from(&mut "$PUBX,03,00*1C\r\n".as_bytes())?;
+    assert!(matches!(frame.payload, FramePayload::Unknown { .. }));
+    Ok(())
+}
@@ -0,0 +1,313 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! RTCM 3.x differential GNSS correction message framing & decoding
+//!
+
+#![forbid(unsafe_code)]
+#![warn(clippy::alloc_instead_of_core)]
+#![warn(clippy::std_instead_of_alloc)]
+#![warn(clippy::std_instead_of_core)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+extern crate core;
+
+use crate::msm::MSMMessage;
+use crate::reader::PayloadReader;
+use crate::station::{AntennaDescriptor, ReceiverAntennaDescriptor, StationARP};
+use core::fmt::{Debug, Display, Formatter};
+use irox_bits::{Bits, BitsError, BitsErrorKind, Error, MutBits};
+use irox_tools::packetio::{Packet, PacketBuilder};
+
+pub mod msm;
+mod reader;
+pub mod station;
+
+/// First byte of every RTCM3 frame
+pub const PREAMBLE: u8 = 0xD3;
+/// Maximum length of a frame's payload
+pub const MAX_PAYLOAD_LEN: usize = 1023;
+
+static CRC24Q_TABLE: [u32; 256] = crc24q_init();
+#[allow(clippy::indexing_slicing)]
+const fn crc24q_init() -> [u32; 256] {
+    let mut out = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = (i as u32) << 16;
+        let mut k = 0;
+        while k < 8 {
+            c <<= 1;
+            if c & 0x100_0000 != 0 {
+                c ^= 0x186_4CFB;
+            }
+            k += 1;
+        }
+        out[i] = c & 0xFF_FFFF;
+        i += 1;
+    }
+    out
+}
+
+///
+/// Calculates the CRC-24Q (Qualcomm) checksum used by RTCM3, over the header and payload.
+pub fn crc24q(data: &[u8]) -> u32 {
+    let mut crc = 0u32;
+    for d in data {
+        let idx = ((crc >> 16) ^ u32::from(*d)) & 0xFF;
+        let t = CRC24Q_TABLE.get(idx as usize).copied().unwrap_or_default();
+        crc = ((crc << 8) ^ t) & 0xFF_FFFF;
+    }
+    crc
+}
+
+///
+/// A raw RTCM3 frame with the exact payload bytes, able to be re-encoded.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RTCM3Frame {
+    pub payload: Box<[u8]>,
+    pub crc: u32,
+}
+impl RTCM3Frame {
+    ///
+    /// Creates a new frame for the payload, calculating the checksum.
+    pub fn new(payload: Box<[u8]>) -> Result<Self, Error> {
+        if payload.len() > MAX_PAYLOAD_LEN {
+            return Err(BitsError::new(
+                BitsErrorKind::InvalidInput,
+                "RTCM3 payload is longer than 1023 bytes",
+            ));
+        }
+        let mut frame = Self { payload, crc: 0 };
+        frame.crc = frame.calculate_crc();
+        Ok(frame)
+    }
+    ///
+    /// The 12-bit message number at the start of the payload
+    pub fn message_number(&self) -> Option<u16> {
+        let [a, b, ..] = *self.payload else {
+            return None;
+        };
+        Some((u16::from(a) << 4) | (u16::from(b) >> 4))
+    }
+    pub fn calculate_crc(&self) -> u32 {
+        let len = self.payload.len() as u16;
+        let mut data = Vec::with_capacity(self.payload.len() + 3);
+        data.push(PREAMBLE);
+        data.extend_from_slice(&(len & 0x3FF).to_be_bytes());
+        data.extend_from_slice(&self.payload);
+        crc24q(&data)
+    }
+    ///
+    /// Decodes the payload of this frame.
+    pub fn decode(&self) -> Result<RTCM3Payload, Error> {
+        RTCM3Payload::decode(&self.payload)
+    }
+}
+impl Debug for RTCM3Frame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RTCM3Frame")
+            .field("message_number", &self.message_number())
+            .field("payload", &irox_tools::hex::to_hex_str_upper(&self.payload))
+            .field("crc", &self.crc)
+            .finish()
+    }
+}
+impl Packet for RTCM3Frame {
+    type PacketType = Option<u16>;
+
+    fn get_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut out = Vec::with_capacity(self.payload.len() + 6);
+        self.write_to(&mut out)?;
+        Ok(out)
+    }
+
+    fn write_to<T: MutBits>(&self, out: &mut T) -> Result<(), Error> {
+        out.write_u8(PREAMBLE)?;
+        out.write_be_u16(self.payload.len() as u16 & 0x3FF)?;
+        out.write_all_bytes(&self.payload)?;
+        let [_, a, b, c] = self.crc.to_be_bytes();
+        out.write_all_bytes(&[a, b, c])
+    }
+
+    fn get_type(&self) -> Self::PacketType {
+        self.message_number()
+    }
+}
+
+///
+/// Reads raw frames, validating the checksum.
+#[derive(Default, Debug, Copy, Clone)]
+pub struct RTCM3FrameParser {
+    pub ignore_crc_mismatch: bool,
+}
+impl PacketBuilder<RTCM3Frame> for RTCM3FrameParser {
+    type Error = Error;
+
+    fn build_from<T: Bits>(&self, source: &mut T) -> Result<RTCM3Frame, Self::Error> {
+        let mut next = source.read_u8()?;
+        let len = loop {
+            if next != PREAMBLE {
+                next = source.read_u8()?;
+                continue;
+            }
+            next = source.read_u8()?;
+            // the top 6 bits are reserved and always zero, otherwise this wasn't a preamble
+            // and the byte may be the start of the real frame.
+            if next & 0xFC == 0 {
+                break u16::from_be_bytes([next, source.read_u8()?]);
+            }
+        };
+        let mut payload = Vec::<u8>::new();
+        source.read_exact_into(len as usize, &mut payload)?;
+        let [a, b, c] = source.read_exact::<3>()?;
+        let crc = u32::from_be_bytes([0, a, b, c]);
+        let frame = RTCM3Frame {
+            payload: payload.into_boxed_slice(),
+            crc,
+        };
+        if !self.ignore_crc_mismatch && frame.calculate_crc() != crc {
+            return Err(BitsError::new(
+                BitsErrorKind::InvalidData,
+                "RTCM3 CRC-24Q mismatch",
+            ));
+        }
+        Ok(frame)
+    }
+}
+
+///
+/// Decoded RTCM3 message
+#[derive(Debug, Clone, PartialEq)]
+pub struct RTCM3Message {
+    pub message_number: u16,
+    pub payload: RTCM3Payload,
+    pub crc: u32,
+}
+impl Display for RTCM3Message {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "RTCM3 {} {}", self.message_number, self.payload)
+    }
+}
+
+///
+/// Reads and decodes RTCM3 messages.  Messages that can't be decoded are returned as
+/// [`RTCM3Payload::Unknown`] rather than failing.
+#[derive(Default, Debug, Copy, Clone)]
+pub struct RTCM3Parser {
+    pub frames: RTCM3FrameParser,
+}
+impl PacketBuilder<RTCM3Message> for RTCM3Parser {
+    type Error = Error;
+
+    fn build_from<T: Bits>(&self, source: &mut T) -> Result<RTCM3Message, Self::Error> {
+        let frame = self.frames.build_from(source)?;
+        let message_number = frame.message_number().unwrap_or_default();
+        let payload = match frame.decode() {
+            Ok(p) => p,
+            Err(_e) => RTCM3Payload::Unknown {
+                message_number,
+                payload: frame.payload,
+            },
+        };
+        Ok(RTCM3Message {
+            message_number,
+            payload,
+            crc: frame.crc,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RTCM3Payload {
+    /// 1005 & 1006 - Stationary reference station antenna reference point
+    StationARP(StationARP),
+    /// 1007 & 1008 - Antenna descriptor and serial number
+    AntennaDescriptor(AntennaDescriptor),
+    /// 1033 - Receiver and antenna descriptors
+    ReceiverAntennaDescriptor(ReceiverAntennaDescriptor),
+    /// Multiple signal message observables
+    MSM(MSMMessage),
+    Unknown {
+        message_number: u16,
+        payload: Box<[u8]>,
+    },
+}
+impl RTCM3Payload {
+    pub fn decode(payload: &[u8]) -> Result<RTCM3Payload, Error> {
+        let mut rdr = PayloadReader::new(payload);
+        let message_number = rdr.read_u16(12)?;
+        Ok(match message_number {
+            1005 | 1006 => RTCM3Payload::StationARP(StationARP::decode(message_number, &mut rdr)?),
+            1007 | 1008 => RTCM3Payload::AntennaDescriptor(AntennaDescriptor::decode(
+                message_number,
+                &mut rdr,
+            )?),
+            1033 => RTCM3Payload::ReceiverAntennaDescriptor(ReceiverAntennaDescriptor::decode(
+                &mut rdr,
+            )?),
+            _ => match MSMMessage::decode(message_number, &mut rdr)? {
+                Some(msm) => RTCM3Payload::MSM(msm),
+                None => RTCM3Payload::Unknown {
+                    message_number,
+                    payload: payload.into(),
+                },
+            },
+        })
+    }
+}
+impl Display for RTCM3Payload {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RTCM3Payload::StationARP(s) => Display::fmt(s, f),
+            RTCM3Payload::AntennaDescriptor(a) => Display::fmt(a, f),
+            RTCM3Payload::ReceiverAntennaDescriptor(r) => Display::fmt(r, f),
+            RTCM3Payload::MSM(m) => Display::fmt(m, f),
+            RTCM3Payload::Unknown { payload, .. } => {
+                write!(f, "Unknown {}", irox_tools::hex::to_hex_str_upper(payload))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{crc24q, RTCM3Frame, RTCM3FrameParser, RTCM3Parser, RTCM3Payload};
+    use irox_tools::packetio::{Packet, PacketBuilder};
+
+    #[test]
+    pub fn test_crc24q() {
+        assert_eq!(0xCD_E703, crc24q(b"123456789"));
+        assert_eq!(0, crc24q(&[]));
+    }
+
+    #[test]
+    pub fn test_framing() {
+        // message 1230, station 0, with no code-phase biases
+        let frame = RTCM3Frame::new(Box::new([0x4C, 0xE0, 0x00, 0x80])).unwrap();
+        let mut bytes = frame.get_bytes().unwrap();
+        assert_eq!(Some(1230), frame.message_number());
+        assert_eq!(10, bytes.len());
+
+        let mut input = vec![b'$', b'G', 0xD3, 0xFF];
+        input.extend_from_slice(&bytes);
+        let parsed = RTCM3FrameParser::default()
+            .build_from(&mut input.as_slice())
+            .unwrap();
+        assert_eq!(frame, parsed);
+
+        let msg = RTCM3Parser::default()
+            .build_from(&mut bytes.as_slice())
+            .unwrap();
+        assert_eq!(1230, msg.message_number);
+        assert!(matches!(msg.payload, RTCM3Payload::Unknown { .. }));
+
+        if let Some(last) = bytes.last_mut() {
+            *last ^= 0x01;
+        }
+        assert!(RTCM3FrameParser::default()
+            .build_from(&mut bytes.as_slice())
+            .is_err());
+    }
+}
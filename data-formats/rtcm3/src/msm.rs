@@ -0,0 +1,551 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! Multiple Signal Messages (MSM) - the observables of every tracked signal of a single
+//! constellation for one epoch.
+
+use core::fmt::{Display, Formatter};
+
+use irox_bits::{BitsError, BitsErrorKind, Error};
+use irox_enums::EnumName;
+use irox_units::units::length::Length;
+use irox_units::units::speed::Speed;
+
+use crate::reader::PayloadReader;
+
+/// Distance light travels in one millisecond, in meters
+pub const LIGHT_MILLISECOND: f64 = 299_792.458;
+
+/// 2^-10 milliseconds
+const P2_10: f64 = 1. / 1024.;
+/// 2^-24 milliseconds
+const P2_24: f64 = 1. / 16_777_216.;
+/// 2^-29 milliseconds
+const P2_29: f64 = 1. / 536_870_912.;
+/// 2^-31 milliseconds
+const P2_31: f64 = 1. / 2_147_483_648.;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumName)]
+pub enum GNSSSystem {
+    GPS,
+    GLONASS,
+    Galileo,
+    SBAS,
+    QZSS,
+    BeiDou,
+}
+
+impl GNSSSystem {
+    ///
+    /// The constellation and MSM number (1-7) of the message number
+    pub fn from_message_number(message_number: u16) -> Option<(GNSSSystem, u8)> {
+        let system = match message_number {
+            1071..=1077 => GNSSSystem::GPS,
+            1081..=1087 => GNSSSystem::GLONASS,
+            1091..=1097 => GNSSSystem::Galileo,
+            1101..=1107 => GNSSSystem::SBAS,
+            1111..=1117 => GNSSSystem::QZSS,
+            1121..=1127 => GNSSSystem::BeiDou,
+            _ => return None,
+        };
+        Some((system, (message_number % 10) as u8))
+    }
+
+    ///
+    /// The RINEX observation code of the MSM signal ID (1-32), like `1C` for GPS L1 C/A
+    pub fn signal_code(&self, signal_id: u8) -> Option<&'static str> {
+        let table: &[(u8, &str)] = match self {
+            GNSSSystem::GPS => &[
+                (2, "1C"),
+                (3, "1P"),
+                (4, "1W"),
+                (8, "2C"),
+                (9, "2P"),
+                (10, "2W"),
+                (15, "2S"),
+                (16, "2L"),
+                (17, "2X"),
+                (22, "5I"),
+                (23, "5Q"),
+                (24, "5X"),
+                (30, "1S"),
+                (31, "1L"),
+                (32, "1X"),
+            ],
+            GNSSSystem::SBAS => &[(2, "1C"), (22, "5I"), (23, "5Q"), (24, "5X")],
+            GNSSSystem::QZSS => &[
+                (2, "1C"),
+                (15, "2S"),
+                (16, "2L"),
+                (17, "2X"),
+                (22, "5I"),
+                (23, "5Q"),
+                (24, "5X"),
+                (30, "1S"),
+                (31, "1L"),
+                (32, "1X"),
+            ],
+            GNSSSystem::GLONASS => &[(2, "1C"), (3, "1P"), (8, "2C"), (9, "2P")],
+            GNSSSystem::Galileo => &[
+                (2, "1C"),
+                (3, "1A"),
+                (4, "1B"),
+                (5, "1X"),
+                (6, "1Z"),
+                (8, "6C"),
+                (9, "6A"),
+                (10, "6B"),
+                (11, "6X"),
+                (12, "6Z"),
+                (14, "7I"),
+                (15, "7Q"),
+                (16, "7X"),
+                (18, "8I"),
+                (19, "8Q"),
+                (20, "8X"),
+                (22, "5I"),
+                (23, "5Q"),
+                (24, "5X"),
+            ],
+            GNSSSystem::BeiDou => &[
+                (2, "2I"),
+                (3, "2Q"),
+                (4, "2X"),
+                (8, "6I"),
+                (9, "6Q"),
+                (10, "6X"),
+                (14, "7I"),
+                (15, "7Q"),
+                (16, "7X"),
+                (22, "5D"),
+                (23, "5P"),
+                (24, "5X"),
+                (25, "7D"),
+                (30, "1D"),
+                (31, "1P"),
+                (32, "1X"),
+            ],
+        };
+        table
+            .iter()
+            .find(|(id, _)| *id == signal_id)
+            .map(|(_, code)| *code)
+    }
+}
+
+///
+/// The MSM variants that are decoded.  MSM4 has full pseudorange and phase range, MSM7
+/// adds phase range rate (doppler) and extended resolution.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumName)]
+pub enum MSMType {
+    MSM4,
+    MSM7,
+}
+
+///
+/// Fields common to every MSM
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MSMHeader {
+    pub station_id: u16,
+    /// GNSS epoch time.  Milliseconds of the week for most systems, for GLONASS the top
+    /// 3 bits are the day of the week and the rest are milliseconds of the day.
+    pub epoch_time: u32,
+    /// More MSMs follow for this epoch & station
+    pub multiple_message: bool,
+    /// Issue of data station
+    pub iods: u8,
+    pub clock_steering: u8,
+    pub external_clock: u8,
+    pub divergence_free_smoothing: bool,
+    pub smoothing_interval: u8,
+    /// Satellite IDs (1-64) with data in this message
+    pub satellites: Vec<u8>,
+    /// Signal IDs (1-32) with data in this message
+    pub signals: Vec<u8>,
+}
+
+impl MSMHeader {
+    ///
+    /// Milliseconds into the week, or into the day for GLONASS
+    pub fn epoch_time_ms(&self, system: GNSSSystem) -> u32 {
+        match system {
+            GNSSSystem::GLONASS => self.epoch_time & 0x07FF_FFFF,
+            _ => self.epoch_time,
+        }
+    }
+
+    ///
+    /// The GLONASS day of the week, 0 is Sunday, 7 is unknown.
+    pub fn glonass_day_of_week(&self) -> u8 {
+        (self.epoch_time >> 27) as u8
+    }
+}
+
+///
+/// Per-satellite data of an MSM
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct MSMSatellite {
+    pub satellite_id: u8,
+    /// Range to the satellite in milliseconds, with 1/1024 ms resolution
+    pub rough_range_ms: Option<f64>,
+    /// MSM7 only, for GLONASS this is the frequency channel number + 7
+    pub extended_info: Option<u8>,
+    /// MSM7 only, with 1 m/s resolution
+    pub rough_phase_range_rate: Option<Speed>,
+}
+
+///
+/// Per-signal observables of an MSM
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct MSMSignal {
+    pub satellite_id: u8,
+    pub signal_id: u8,
+    pub pseudorange: Option<Length>,
+    /// Carrier phase, as a distance.  Divide by the wavelength of the signal for cycles.
+    pub phase_range: Option<Length>,
+    /// Increments when the receiver loses lock on the carrier, see DF402 & DF407
+    pub lock_time_indicator: u16,
+    pub half_cycle_ambiguity: bool,
+    /// Carrier to noise ratio, in dB-Hz.  Zero if not available.
+    pub cnr_dbhz: f64,
+    /// MSM7 only, the doppler as a velocity
+    pub phase_range_rate: Option<Speed>,
+}
+
+///
+/// A decoded MSM4 or MSM7 message
+#[derive(Debug, Clone, PartialEq)]
+pub struct MSMMessage {
+    pub message_number: u16,
+    pub system: GNSSSystem,
+    pub msm_type: MSMType,
+    pub header: MSMHeader,
+    pub satellites: Vec<MSMSatellite>,
+    pub signals: Vec<MSMSignal>,
+}
+
+impl MSMMessage {
+    ///
+    /// Decodes the message, returning `None` if it's not an MSM4 or MSM7
+    pub(crate) fn decode(
+        message_number: u16,
+        rdr: &mut PayloadReader,
+    ) -> Result<Option<MSMMessage>, Error> {
+        let Some((system, msm)) = GNSSSystem::from_message_number(message_number) else {
+            return Ok(None);
+        };
+        let msm_type = match msm {
+            4 => MSMType::MSM4,
+            7 => MSMType::MSM7,
+            _ => return Ok(None),
+        };
+        let extended = msm_type == MSMType::MSM7;
+
+        let station_id = rdr.read_u16(12)?;
+        let epoch_time = rdr.read_u32(30)?;
+        let multiple_message = rdr.read_bool()?;
+        let iods = rdr.read_u8(3)?;
+        rdr.skip(7)?;
+        let clock_steering = rdr.read_u8(2)?;
+        let external_clock = rdr.read_u8(2)?;
+        let divergence_free_smoothing = rdr.read_bool()?;
+        let smoothing_interval = rdr.read_u8(3)?;
+        let sat_mask = rdr.read_u64(64)?;
+        let sig_mask = rdr.read_u32(32)?;
+        let satellites = (0..64u8)
+            .filter(|i| sat_mask & (1 << (63 - i)) != 0)
+            .map(|i| i + 1)
+            .collect::<Vec<_>>();
+        let signals = (0..32u8)
+            .filter(|i| sig_mask & (1 << (31 - i)) != 0)
+            .map(|i| i + 1)
+            .collect::<Vec<_>>();
+        if satellites.len() * signals.len() > 64 {
+            return Err(BitsError::new(
+                BitsErrorKind::InvalidData,
+                "MSM cell mask is larger than 64 bits",
+            ));
+        }
+        let mut cells = Vec::new();
+        for sat in &satellites {
+            for sig in &signals {
+                if rdr.read_bool()? {
+                    cells.push((*sat, *sig));
+                }
+            }
+        }
+
+        let mut sats = satellites
+            .iter()
+            .map(|satellite_id| MSMSatellite {
+                satellite_id: *satellite_id,
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        let mut rough_ms = Vec::with_capacity(sats.len());
+        for _ in &sats {
+            let ms = rdr.read_u8(8)?;
+            rough_ms.push((ms != 0xFF).then_some(f64::from(ms)));
+        }
+        if extended {
+            for sat in &mut sats {
+                sat.extended_info = Some(rdr.read_u8(4)?);
+            }
+        }
+        for (sat, ms) in sats.iter_mut().zip(&rough_ms) {
+            let frac = f64::from(rdr.read_u16(10)?) * P2_10;
+            sat.rough_range_ms = ms.map(|ms| ms + frac);
+        }
+        if extended {
+            for sat in &mut sats {
+                sat.rough_phase_range_rate = rdr
+                    .read_i32_valid(14)?
+                    .map(|v| Speed::new_meters_per_second(f64::from(v)));
+            }
+        }
+
+        let (pr_bits, pr_scale, cp_bits, cp_scale, lock_bits, cnr_bits, cnr_scale) = if extended {
+            (20, P2_29, 24, P2_31, 10, 10, 1. / 16.)
+        } else {
+            (15, P2_24, 22, P2_29, 4, 6, 1.)
+        };
+        let mut sigs = cells
+            .iter()
+            .map(|(satellite_id, signal_id)| MSMSignal {
+                satellite_id: *satellite_id,
+                signal_id: *signal_id,
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        let rough = |sat: u8| {
+            sats.iter()
+                .find(|s| s.satellite_id == sat)
+                .and_then(|s| s.rough_range_ms)
+        };
+        for sig in &mut sigs {
+            let fine = rdr.read_i32_valid(pr_bits)?;
+            sig.pseudorange = rough(sig.satellite_id).zip(fine).map(|(r, f)| {
+                Length::new_meters((r + f64::from(f) * pr_scale) * LIGHT_MILLISECOND)
+            });
+        }
+        for sig in &mut sigs {
+            let fine = rdr.read_i32_valid(cp_bits)?;
+            sig.phase_range = rough(sig.satellite_id).zip(fine).map(|(r, f)| {
+                Length::new_meters((r + f64::from(f) * cp_scale) * LIGHT_MILLISECOND)
+            });
+        }
+        for sig in &mut sigs {
+            sig.lock_time_indicator = rdr.read_u16(lock_bits)?;
+        }
+        for sig in &mut sigs {
+            sig.half_cycle_ambiguity = rdr.read_bool()?;
+        }
+        for sig in &mut sigs {
+            sig.cnr_dbhz = f64::from(rdr.read_u16(cnr_bits)?) * cnr_scale;
+        }
+        if extended {
+            for sig in &mut sigs {
+                let fine = rdr.read_i32_valid(15)?;
+                let rough = sats
+                    .iter()
+                    .find(|s| s.satellite_id == sig.satellite_id)
+                    .and_then(|s| s.rough_phase_range_rate);
+                sig.phase_range_rate = rough.zip(fine).map(|(r, f)| {
+                    Speed::new_meters_per_second(
+                        r.as_meters_per_second().value() + f64::from(f) * 0.0001,
+                    )
+                });
+            }
+        }
+
+        Ok(Some(MSMMessage {
+            message_number,
+            system,
+            msm_type,
+            header: MSMHeader {
+                station_id,
+                epoch_time,
+                multiple_message,
+                iods,
+                clock_steering,
+                external_clock,
+                divergence_free_smoothing,
+                smoothing_interval,
+                satellites,
+                signals,
+            },
+            satellites: sats,
+            signals: sigs,
+        }))
+    }
+}
+
+impl Display for MSMMessage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} {} Station[{}] Epoch[{}] Sats[{}] Signals[{}]",
+            self.system.name(),
+            self.msm_type.name(),
+            self.header.station_id,
+            self.header.epoch_time_ms(self.system),
+            self.satellites.len(),
+            self.signals.len()
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::panic)]
+mod test {
+    use crate::msm::{GNSSSystem, MSMType, LIGHT_MILLISECOND, P2_24, P2_29, P2_31};
+    use crate::reader::pack_bits;
+    use crate::RTCM3Payload;
+
+    fn assert_near(expected: f64, actual: Option<f64>) {
+        let actual = actual.unwrap_or(f64::NAN);
+        assert!((expected - actual).abs() < 1e-6, "{expected} != {actual}");
+    }
+
+    /// Header for two satellites (3 & 17) and two signals (1C & 2W), with three cells.
+    fn header(message_number: i64, epoch: i64) -> Vec<(i64, u8)> {
+        vec![
+            (message_number, 12),
+            (42, 12),
+            (epoch, 30),
+            (0, 1),
+            (0, 3),
+            (0, 7),
+            (0, 2),
+            (0, 2),
+            (0, 1),
+            (0, 3),
+            ((1 << (64 - 3)) | (1 << (64 - 17)), 64),
+            ((1 << (32 - 2)) | (1 << (32 - 10)), 32),
+            (0b1110, 4),
+        ]
+    }
+
+    #[test]
+    pub fn test_msm4() {
+        let mut fields = header(1074, 345_600_000);
+        // satellite rough ranges, integer ms then modulo 1ms
+        fields.extend([(70, 8), (0xFF, 8), (512, 10), (0, 10)]);
+        // fine pseudoranges
+        fields.extend([(1000, 15), (-1000, 15), (5, 15)]);
+        // fine phase ranges, the second one invalid
+        fields.extend([(4000, 22), (-(1 << 21), 22), (0, 22)]);
+        // lock time, half cycle, cnr
+        fields.extend([(15, 4), (3, 4), (0, 4), (0, 1), (1, 1), (0, 1)]);
+        fields.extend([(45, 6), (38, 6), (0, 6)]);
+        let RTCM3Payload::MSM(msm) = RTCM3Payload::decode(&pack_bits(&fields)).unwrap() else {
+            panic!("Expected MSM");
+        };
+        assert_eq!(GNSSSystem::GPS, msm.system);
+        assert_eq!(MSMType::MSM4, msm.msm_type);
+        assert_eq!(42, msm.header.station_id);
+        assert_eq!(345_600_000, msm.header.epoch_time_ms(msm.system));
+        assert_eq!(vec![3, 17], msm.header.satellites);
+        assert_eq!(vec![2, 10], msm.header.signals);
+        assert_eq!(
+            "GPS MSM4 Station[42] Epoch[345600000] Sats[2] Signals[3]",
+            msm.to_string()
+        );
+
+        let [s3, s17] = msm.satellites.as_slice() else {
+            panic!("Expected two satellites");
+        };
+        assert_eq!(Some(70.5), s3.rough_range_ms);
+        assert_eq!(None, s17.rough_range_ms);
+        assert_eq!(None, s3.extended_info);
+
+        let [l1, l2, l1_17] = msm.signals.as_slice() else {
+            panic!("Expected three signals");
+        };
+        assert_eq!((3, 2), (l1.satellite_id, l1.signal_id));
+        assert_eq!((3, 10), (l2.satellite_id, l2.signal_id));
+        assert_eq!((17, 2), (l1_17.satellite_id, l1_17.signal_id));
+        assert_eq!(Some("2W"), msm.system.signal_code(l2.signal_id));
+        assert_near(
+            (70.5 + 1000. * P2_24) * LIGHT_MILLISECOND,
+            l1.pseudorange.map(|p| p.as_meters().value()),
+        );
+        assert_near(
+            (70.5 + 4000. * P2_29) * LIGHT_MILLISECOND,
+            l1.phase_range.map(|p| p.as_meters().value()),
+        );
+        assert_eq!(None, l2.phase_range);
+        assert_eq!(None, l1_17.pseudorange);
+        assert_eq!(15, l1.lock_time_indicator);
+        assert!(l2.half_cycle_ambiguity);
+        assert_near(45., Some(l1.cnr_dbhz));
+        assert_eq!(None, l1.phase_range_rate);
+    }
+
+    #[test]
+    pub fn test_msm7() {
+        // GLONASS, day 2, 1 hour into the day
+        let mut fields = header(1087, (2 << 27) | 3_600_000);
+        fields.extend([(70, 8), (65, 8), (8, 4), (13, 4), (256, 10), (0, 10)]);
+        // rough phase range rates, the second one invalid
+        fields.extend([(-512, 14), (-(1 << 13), 14)]);
+        fields.extend([(-3000, 20), (0, 20), (0, 20)]);
+        fields.extend([(10_000, 24), (0, 24), (0, 24)]);
+        fields.extend([(700, 10), (0, 10), (0, 10), (0, 1), (0, 1), (0, 1)]);
+        fields.extend([(729, 10), (0, 10), (0, 10)]);
+        fields.extend([(2500, 15), (0, 15), (100, 15)]);
+        let RTCM3Payload::MSM(msm) = RTCM3Payload::decode(&pack_bits(&fields)).unwrap() else {
+            panic!("Expected MSM");
+        };
+        assert_eq!(GNSSSystem::GLONASS, msm.system);
+        assert_eq!(MSMType::MSM7, msm.msm_type);
+        assert_eq!(3_600_000, msm.header.epoch_time_ms(msm.system));
+        assert_eq!(2, msm.header.glonass_day_of_week());
+
+        let [s3, s17] = msm.satellites.as_slice() else {
+            panic!("Expected two satellites");
+        };
+        assert_eq!(Some(8), s3.extended_info);
+        assert_eq!(Some(65.), s17.rough_range_ms);
+        assert_near(
+            -512.,
+            s3.rough_phase_range_rate
+                .map(|s| s.as_meters_per_second().value()),
+        );
+        assert_eq!(None, s17.rough_phase_range_rate);
+
+        let [l1, _l2, l1_17] = msm.signals.as_slice() else {
+            panic!("Expected three signals");
+        };
+        assert_near(
+            (70.25 - 3000. * P2_29) * LIGHT_MILLISECOND,
+            l1.pseudorange.map(|p| p.as_meters().value()),
+        );
+        assert_near(
+            (70.25 + 10_000. * P2_31) * LIGHT_MILLISECOND,
+            l1.phase_range.map(|p| p.as_meters().value()),
+        );
+        assert_eq!(700, l1.lock_time_indicator);
+        assert_near(45.5625, Some(l1.cnr_dbhz));
+        assert_near(
+            -511.75,
+            l1.phase_range_rate
+                .map(|s| s.as_meters_per_second().value()),
+        );
+        assert_eq!(None, l1_17.phase_range_rate);
+    }
+
+    #[test]
+    pub fn test_unsupported_msm() {
+        // MSM5 is passed through
+        let fields = header(1075, 0);
+        let payload = pack_bits(&fields);
+        let Ok(RTCM3Payload::Unknown { message_number, .. }) = RTCM3Payload::decode(&payload)
+        else {
+            panic!("Expected Unknown");
+        };
+        assert_eq!(1075, message_number);
+    }
+}
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use irox_bits::{BitStreamDecoder, BitsError, BitsErrorKind, BitsWrapper, Error};
+
+///
+/// Reads the big-endian, MSB-first bit fields of an RTCM3 payload.
+pub(crate) struct PayloadReader<'a> {
+    dec: BitStreamDecoder<'a, &'a [u8]>,
+    remaining: usize,
+}
+
+impl<'a> PayloadReader<'a> {
+    pub(crate) fn new(payload: &'a [u8]) -> PayloadReader<'a> {
+        PayloadReader {
+            dec: BitStreamDecoder::new(BitsWrapper::Owned(payload)),
+            remaining: payload.len() * 8,
+        }
+    }
+
+    pub(crate) fn read_u32(&mut self, num_bits: u8) -> Result<u32, Error> {
+        if usize::from(num_bits) > self.remaining {
+            return Err(BitsError::new(
+                BitsErrorKind::UnexpectedEof,
+                "RTCM3 payload is too short for message type",
+            ));
+        }
+        self.remaining -= usize::from(num_bits);
+        self.dec.read_u32_bits(num_bits)
+    }
+
+    pub(crate) fn read_u64(&mut self, num_bits: u8) -> Result<u64, Error> {
+        if num_bits <= 32 {
+            return Ok(u64::from(self.read_u32(num_bits)?));
+        }
+        let high = u64::from(self.read_u32(num_bits - 32)?);
+        let low = u64::from(self.read_u32(32)?);
+        Ok((high << 32) | low)
+    }
+
+    pub(crate) fn read_u16(&mut self, num_bits: u8) -> Result<u16, Error> {
+        Ok(self.read_u32(num_bits.min(16))? as u16)
+    }
+
+    pub(crate) fn read_u8(&mut self, num_bits: u8) -> Result<u8, Error> {
+        Ok(self.read_u32(num_bits.min(8))? as u8)
+    }
+
+    pub(crate) fn read_bool(&mut self) -> Result<bool, Error> {
+        Ok(self.read_u32(1)? == 1)
+    }
+
+    /// Reads a two's complement signed value
+    pub(crate) fn read_i32(&mut self, num_bits: u8) -> Result<i32, Error> {
+        let num_bits = num_bits.clamp(1, 32);
+        let val = self.read_u32(num_bits)?;
+        let shift = 32 - num_bits;
+        Ok(((val << shift) as i32) >> shift)
+    }
+
+    /// Reads a two's complement signed value
+    pub(crate) fn read_i64(&mut self, num_bits: u8) -> Result<i64, Error> {
+        let num_bits = num_bits.clamp(1, 64);
+        let val = self.read_u64(num_bits)?;
+        let shift = 64 - num_bits;
+        Ok(((val << shift) as i64) >> shift)
+    }
+
+    ///
+    /// Reads a two's complement signed value, returning `None` if it is the most negative
+    /// value, which RTCM uses to indicate an invalid field.
+    pub(crate) fn read_i32_valid(&mut self, num_bits: u8) -> Result<Option<i32>, Error> {
+        let val = self.read_i32(num_bits)?;
+        let invalid = -1i64 << (num_bits.clamp(1, 32) - 1);
+        Ok((i64::from(val) != invalid).then_some(val))
+    }
+
+    ///
+    /// Reads a length-prefixed string of 8-bit characters.
+    pub(crate) fn read_string(&mut self) -> Result<String, Error> {
+        let len = self.read_u8(8)?;
+        let mut out = String::with_capacity(usize::from(len));
+        for _ in 0..len {
+            out.push(char::from(self.read_u8(8)?));
+        }
+        Ok(out)
+    }
+
+    pub(crate) fn skip(&mut self, num_bits: u8) -> Result<(), Error> {
+        self.read_u64(num_bits)?;
+        Ok(())
+    }
+}
+
+///
+/// Packs the `(value, num_bits)` fields MSB-first, for building test payloads.
+#[cfg(test)]
+pub(crate) fn pack_bits(fields: &[(i64, u8)]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut used = 0usize;
+    for (value, num_bits) in fields {
+        for bit in (0..*num_bits).rev() {
+            if used.is_multiple_of(8) {
+                out.push(0);
+            }
+            if (value >> bit) & 0x01 == 1 {
+                if let Some(last) = out.last_mut() {
+                    *last |= 0x80 >> (used % 8);
+                }
+            }
+            used += 1;
+        }
+    }
+    out
+}
@@ -0,0 +1,299 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use core::fmt::{Display, Formatter};
+
+use irox_bits::Error;
+use irox_carto::coordinate::CartesianCoordinate;
+use irox_carto::position_type::ECEFPosition;
+use irox_units::units::length::Length;
+
+use crate::reader::PayloadReader;
+
+/// Scale of the ECEF coordinates & antenna height, in meters.
+const ARP_SCALE: f64 = 0.0001;
+
+///
+/// Message types 1005 & 1006 - the antenna reference point (ARP) of a stationary
+/// reference station, and its height above the marker for 1006.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StationARP {
+    pub message_number: u16,
+    pub station_id: u16,
+    /// ITRF realization year, reserved for future use
+    pub itrf_year: u8,
+    /// The station provides GPS observations
+    pub gps: bool,
+    /// The station provides GLONASS observations
+    pub glonass: bool,
+    /// The station provides Galileo observations
+    pub galileo: bool,
+    /// False if this is a physical station, true if non-physical or computed
+    pub reference_station: bool,
+    pub ecef_x: Length,
+    pub ecef_y: Length,
+    pub ecef_z: Length,
+    /// All raw data are derived from the same receiver oscillator
+    pub single_receiver_oscillator: bool,
+    /// Phase range correction alignment, 0 is unknown
+    pub quarter_cycle_indicator: u8,
+    /// Height of the ARP above the survey marker (1006 only)
+    pub antenna_height: Option<Length>,
+}
+
+impl StationARP {
+    pub(crate) fn decode(
+        message_number: u16,
+        rdr: &mut PayloadReader,
+    ) -> Result<StationARP, Error> {
+        let station_id = rdr.read_u16(12)?;
+        let itrf_year = rdr.read_u8(6)?;
+        let gps = rdr.read_bool()?;
+        let glonass = rdr.read_bool()?;
+        let galileo = rdr.read_bool()?;
+        let reference_station = rdr.read_bool()?;
+        let ecef_x = Length::new_meters(rdr.read_i64(38)? as f64 * ARP_SCALE);
+        let single_receiver_oscillator = rdr.read_bool()?;
+        rdr.skip(1)?;
+        let ecef_y = Length::new_meters(rdr.read_i64(38)? as f64 * ARP_SCALE);
+        let quarter_cycle_indicator = rdr.read_u8(2)?;
+        let ecef_z = Length::new_meters(rdr.read_i64(38)? as f64 * ARP_SCALE);
+        let antenna_height = if message_number == 1006 {
+            Some(Length::new_meters(f64::from(rdr.read_u16(16)?) * ARP_SCALE))
+        } else {
+            None
+        };
+        Ok(StationARP {
+            message_number,
+            station_id,
+            itrf_year,
+            gps,
+            glonass,
+            galileo,
+            reference_station,
+            ecef_x,
+            ecef_y,
+            ecef_z,
+            single_receiver_oscillator,
+            quarter_cycle_indicator,
+            antenna_height,
+        })
+    }
+
+    ///
+    /// The antenna reference point as an ECEF position
+    pub fn position(&self) -> ECEFPosition {
+        ECEFPosition(CartesianCoordinate::new(
+            self.ecef_x,
+            self.ecef_y,
+            self.ecef_z,
+        ))
+    }
+}
+
+impl Display for StationARP {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Station[{}] ARP[{:.4}, {:.4}, {:.4}]",
+            self.station_id,
+            self.ecef_x.as_meters().value(),
+            self.ecef_y.as_meters().value(),
+            self.ecef_z.as_meters().value()
+        )?;
+        if let Some(height) = self.antenna_height {
+            write!(f, " Height[{:.4}]", height.as_meters().value())?;
+        }
+        Ok(())
+    }
+}
+
+///
+/// Message types 1007 & 1008 - the antenna type of the reference station, and its
+/// serial number for 1008.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AntennaDescriptor {
+    pub message_number: u16,
+    pub station_id: u16,
+    /// IGS antenna name, like `TRM59800.00     SCIS`
+    pub descriptor: String,
+    /// 0 uses the standard IGS model, otherwise a station specific setup
+    pub setup_id: u8,
+    pub serial_number: Option<String>,
+}
+
+impl AntennaDescriptor {
+    pub(crate) fn decode(
+        message_number: u16,
+        rdr: &mut PayloadReader,
+    ) -> Result<AntennaDescriptor, Error> {
+        let station_id = rdr.read_u16(12)?;
+        let descriptor = rdr.read_string()?;
+        let setup_id = rdr.read_u8(8)?;
+        let serial_number = if message_number == 1008 {
+            Some(rdr.read_string()?)
+        } else {
+            None
+        };
+        Ok(AntennaDescriptor {
+            message_number,
+            station_id,
+            descriptor,
+            setup_id,
+            serial_number,
+        })
+    }
+}
+
+impl Display for AntennaDescriptor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Station[{}] Antenna[{}] Setup[{}]",
+            self.station_id, self.descriptor, self.setup_id
+        )?;
+        if let Some(serial) = &self.serial_number {
+            write!(f, " Serial[{serial}]")?;
+        }
+        Ok(())
+    }
+}
+
+///
+/// Message type 1033 - the antenna and receiver types of the reference station.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiverAntennaDescriptor {
+    pub station_id: u16,
+    pub antenna_descriptor: String,
+    pub antenna_setup_id: u8,
+    pub antenna_serial_number: String,
+    pub receiver_type: String,
+    pub receiver_firmware_version: String,
+    pub receiver_serial_number: String,
+}
+
+impl ReceiverAntennaDescriptor {
+    pub(crate) fn decode(rdr: &mut PayloadReader) -> Result<ReceiverAntennaDescriptor, Error> {
+        Ok(ReceiverAntennaDescriptor {
+            station_id: rdr.read_u16(12)?,
+            antenna_descriptor: rdr.read_string()?,
+            antenna_setup_id: rdr.read_u8(8)?,
+            antenna_serial_number: rdr.read_string()?,
+            receiver_type: rdr.read_string()?,
+            receiver_firmware_version: rdr.read_string()?,
+            receiver_serial_number: rdr.read_string()?,
+        })
+    }
+}
+
+impl Display for ReceiverAntennaDescriptor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Station[{}] Antenna[{}] Receiver[{}] Firmware[{}]",
+            self.station_id,
+            self.antenna_descriptor,
+            self.receiver_type,
+            self.receiver_firmware_version
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::panic)]
+mod test {
+    use crate::reader::pack_bits;
+    use crate::{RTCM3Frame, RTCM3Parser, RTCM3Payload};
+    use irox_tools::packetio::{Packet, PacketBuilder};
+
+    fn decode(fields: &[(i64, u8)]) -> RTCM3Payload {
+        let frame = RTCM3Frame::new(pack_bits(fields).into_boxed_slice()).unwrap();
+        let bytes = frame.get_bytes().unwrap();
+        RTCM3Parser::default()
+            .build_from(&mut bytes.as_slice())
+            .unwrap()
+            .payload
+    }
+
+    fn string_fields(val: &str) -> Vec<(i64, u8)> {
+        let mut out = vec![(val.len() as i64, 8)];
+        out.extend(val.bytes().map(|b| (i64::from(b), 8)));
+        out
+    }
+
+    #[test]
+    pub fn test_station_arp() {
+        let RTCM3Payload::StationARP(arp) = decode(&[
+            (1006, 12),
+            (2003, 12),
+            (0, 6),
+            (1, 1),
+            (1, 1),
+            (0, 1),
+            (0, 1),
+            (11_141_045_999, 38),
+            (1, 1),
+            (0, 1),
+            (-48_507_297_108, 38),
+            (0, 2),
+            (39_755_214_643, 38),
+            (15_432, 16),
+        ]) else {
+            panic!("Expected 1006");
+        };
+        assert_eq!(2003, arp.station_id);
+        assert!(arp.gps && arp.glonass && !arp.galileo);
+        assert!(arp.single_receiver_oscillator);
+        assert_eq!(
+            "1114104.5999",
+            format!("{:.4}", arp.ecef_x.as_meters().value())
+        );
+        assert_eq!(
+            "-4850729.7108",
+            format!("{:.4}", arp.ecef_y.as_meters().value())
+        );
+        assert_eq!(
+            "3975521.4643",
+            format!("{:.4}", arp.ecef_z.as_meters().value())
+        );
+        assert_eq!(
+            Some("1.5432".to_string()),
+            arp.antenna_height
+                .map(|h| format!("{:.4}", h.as_meters().value()))
+        );
+        assert_eq!(
+            "Station[2003] ARP[1114104.5999, -4850729.7108, 3975521.4643] Height[1.5432]",
+            arp.to_string()
+        );
+    }
+
+    #[test]
+    pub fn test_descriptors() {
+        let mut fields = vec![(1008, 12), (17, 12)];
+        fields.extend(string_fields("TRM59800.00     SCIS"));
+        fields.push((0, 8));
+        fields.extend(string_fields("5000118716"));
+        let RTCM3Payload::AntennaDescriptor(ant) = decode(&fields) else {
+            panic!("Expected 1008");
+        };
+        assert_eq!(17, ant.station_id);
+        assert_eq!("TRM59800.00     SCIS", ant.descriptor);
+        assert_eq!(Some("5000118716".to_string()), ant.serial_number);
+
+        let mut fields = vec![(1033, 12), (17, 12)];
+        fields.extend(string_fields("TRM59800.00     SCIS"));
+        fields.push((0, 8));
+        fields.extend(string_fields(""));
+        fields.extend(string_fields("TRIMBLE NETR9"));
+        fields.extend(string_fields("5.45"));
+        fields.extend(string_fields("5036K69747"));
+        let RTCM3Payload::ReceiverAntennaDescriptor(rcv) = decode(&fields) else {
+            panic!("Expected 1033");
+        };
+        assert_eq!("", rcv.antenna_serial_number);
+        assert_eq!("TRIMBLE NETR9", rcv.receiver_type);
+        assert_eq!("5.45", rcv.receiver_firmware_version);
+        assert_eq!("5036K69747", rcv.receiver_serial_number);
+    }
+}
@@ -6,11 +6,14 @@ use irox_bits::{Bits, Error};
 use irox_structs::Struct;
 use irox_tools::packetio::{Packet, PacketBuilder};
 
+///
+/// A raw GPS LNAV subframe.  Each word holds the 30-bit navigation word in the low bits, with the
+/// last two parity bits of the previous word (D29*, D30*) above it.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Struct)]
 pub struct FiftyBPSData {
-    channel: u8,
-    sv_id: u8,
-    word: [u32; 10],
+    pub channel: u8,
+    pub sv_id: u8,
+    pub word: [u32; 10],
 }
 
 impl Packet for FiftyBPSData {
@@ -5,4 +5,5 @@
 #![allow(clippy::indexing_slicing)]
 pub mod error;
 pub mod input;
+pub mod output;
 pub mod packet;
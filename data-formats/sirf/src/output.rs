@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! Messages sent from the host to the SiRF device.  [`irox_tools::packetio::Packet::get_bytes`]
+//! returns the complete frame, ready to be written to the device.
+pub mod x80_initdatasource;
+pub mod x81_switchnmea;
+pub mod x84_pollswversion;
+pub mod x86_setbinaryport;
+pub mod xa6_setmsgrate;
@@ -0,0 +1,207 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use irox_bits::{Error, MutBits};
+use irox_tools::packetio::Packet;
+
+use crate::packet::{encode_frame, PacketType};
+
+pub const MESSAGE_ID: u8 = 0x80;
+
+/// Default number of channels to use
+pub const DEFAULT_CHANNELS: u8 = 12;
+
+///
+/// The reset configuration bits of [`InitializeDataSource`]
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ResetConfiguration {
+    /// Use the position, clock drift and time seeds in the message
+    pub data_valid: bool,
+    /// Clear the ephemeris from memory, a warm start
+    pub clear_ephemeris: bool,
+    /// Clear all history (except the clock drift) from memory, a cold start
+    pub clear_memory: bool,
+    /// Reset to the factory defaults
+    pub factory_reset: bool,
+    /// Enable the raw track data, navigation library messages (0x1C, 0x1D, 0x1E, 0x1F)
+    pub enable_nav_lib: bool,
+    /// Enable the debug data messages
+    pub enable_debug: bool,
+    /// The real time clock is not precise, and shouldn't be trusted
+    pub rtc_not_precise: bool,
+    /// Perform a full system reset
+    pub system_reset: bool,
+}
+
+impl ResetConfiguration {
+    pub fn as_u8(&self) -> u8 {
+        u8::from(self.data_valid)
+            | (u8::from(self.clear_ephemeris) << 1)
+            | (u8::from(self.clear_memory) << 2)
+            | (u8::from(self.factory_reset) << 3)
+            | (u8::from(self.enable_nav_lib) << 4)
+            | (u8::from(self.enable_debug) << 5)
+            | (u8::from(self.rtc_not_precise) << 6)
+            | (u8::from(self.system_reset) << 7)
+    }
+}
+
+impl From<u8> for ResetConfiguration {
+    fn from(value: u8) -> Self {
+        ResetConfiguration {
+            data_valid: value & 0x01 != 0,
+            clear_ephemeris: value & 0x02 != 0,
+            clear_memory: value & 0x04 != 0,
+            factory_reset: value & 0x08 != 0,
+            enable_nav_lib: value & 0x10 != 0,
+            enable_debug: value & 0x20 != 0,
+            rtc_not_precise: value & 0x40 != 0,
+            system_reset: value & 0x80 != 0,
+        }
+    }
+}
+
+///
+/// Message 0x80 - Initialize Data Source.  Restarts the receiver, optionally seeding it with an
+/// approximate position, clock drift and time.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct InitializeDataSource {
+    /// ECEF X position, meters
+    pub ecef_x: i32,
+    /// ECEF Y position, meters
+    pub ecef_y: i32,
+    /// ECEF Z position, meters
+    pub ecef_z: i32,
+    /// Clock drift, Hz
+    pub clock_drift: i32,
+    /// GPS time of week, seconds
+    pub gps_tow: f64,
+    /// Extended GPS week number
+    pub gps_week: u16,
+    /// Number of channels to use
+    pub channels: u8,
+    pub reset: ResetConfiguration,
+}
+
+impl InitializeDataSource {
+    ///
+    /// Restart using all the data in memory.
+    pub fn hot_start() -> Self {
+        InitializeDataSource {
+            channels: DEFAULT_CHANNELS,
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// Restart after clearing the ephemeris.
+    pub fn warm_start() -> Self {
+        let mut out = Self::hot_start();
+        out.reset.clear_ephemeris = true;
+        out
+    }
+
+    ///
+    /// Restart after clearing the ephemeris and all the history.
+    pub fn cold_start() -> Self {
+        let mut out = Self::warm_start();
+        out.reset.clear_memory = true;
+        out
+    }
+
+    ///
+    /// Restart after resetting to the factory defaults.
+    pub fn factory_reset() -> Self {
+        let mut out = Self::cold_start();
+        out.reset.factory_reset = true;
+        out
+    }
+
+    ///
+    /// Seeds the receiver with the approximate ECEF position (meters), clock drift (Hz), and GPS
+    /// time.
+    #[must_use]
+    pub fn with_seed(
+        mut self,
+        ecef: [i32; 3],
+        clock_drift: i32,
+        gps_week: u16,
+        gps_tow: f64,
+    ) -> Self {
+        let [x, y, z] = ecef;
+        self.ecef_x = x;
+        self.ecef_y = y;
+        self.ecef_z = z;
+        self.clock_drift = clock_drift;
+        self.gps_week = gps_week;
+        self.gps_tow = gps_tow;
+        self.reset.data_valid = true;
+        self
+    }
+
+    ///
+    /// The message ID and body, without the framing.
+    pub fn payload(&self) -> Result<Vec<u8>, Error> {
+        let mut out: Vec<u8> = Vec::with_capacity(25);
+        out.write_u8(MESSAGE_ID)?;
+        out.write_be_i32(self.ecef_x)?;
+        out.write_be_i32(self.ecef_y)?;
+        out.write_be_i32(self.ecef_z)?;
+        out.write_be_i32(self.clock_drift)?;
+        out.write_be_u32((self.gps_tow * 100.).round() as u32)?;
+        out.write_be_u16(self.gps_week)?;
+        out.write_u8(self.channels)?;
+        out.write_u8(self.reset.as_u8())?;
+        Ok(out)
+    }
+}
+
+impl Packet for InitializeDataSource {
+    type PacketType = PacketType;
+
+    fn get_bytes(&self) -> Result<Vec<u8>, Error> {
+        encode_frame(&self.payload()?)
+    }
+
+    fn get_type(&self) -> Self::PacketType {
+        PacketType::InitializeDataSource(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use irox_bits::Error;
+    use irox_tools::hex;
+    use irox_tools::packetio::Packet;
+
+    use crate::output::x80_initdatasource::{InitializeDataSource, ResetConfiguration};
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_example() -> Result<(), Error> {
+        let mut msg = InitializeDataSource::warm_start().with_seed(
+            [-2686727, -4304282, 3851642],
+            75000,
+            924,
+            86400.,
+        );
+        msg.reset.enable_nav_lib = true;
+        msg.reset.enable_debug = true;
+        assert_eq!(0x33, msg.reset.as_u8());
+        assert_eq!(msg.reset, ResetConfiguration::from(0x33));
+
+        let expected =
+            hex!("A0A20019" "80FFD700F9FFBE5266003AC57A000124F80083D600039C0C33" "0A91B0B3");
+        assert_eq!(expected.as_slice(), msg.get_bytes()?);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_resets() {
+        assert_eq!(0x00, InitializeDataSource::hot_start().reset.as_u8());
+        assert_eq!(0x02, InitializeDataSource::warm_start().reset.as_u8());
+        assert_eq!(0x06, InitializeDataSource::cold_start().reset.as_u8());
+        assert_eq!(0x0E, InitializeDataSource::factory_reset().reset.as_u8());
+    }
+}
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use irox_bits::{Error, MutBits};
+use irox_tools::packetio::Packet;
+
+use crate::packet::{encode_frame, PacketType};
+
+pub const MESSAGE_ID: u8 = 0x81;
+
+///
+/// Controls the NMEA debug messages after the switch
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NMEADebugMode {
+    Enable = 0,
+    Disable = 1,
+    #[default]
+    NoChange = 2,
+}
+
+///
+/// Message 0x81 - Switch To NMEA Protocol.  Switches the device to NMEA output at the bit rate,
+/// with each sentence output every `N` seconds, or not at all for 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwitchToNMEA {
+    pub debug: NMEADebugMode,
+    pub gga_rate: u8,
+    pub gll_rate: u8,
+    pub gsa_rate: u8,
+    pub gsv_rate: u8,
+    pub rmc_rate: u8,
+    pub vtg_rate: u8,
+    pub mss_rate: u8,
+    pub epe_rate: u8,
+    pub zda_rate: u8,
+    pub bit_rate: u16,
+}
+
+impl Default for SwitchToNMEA {
+    /// GGA, GSA & RMC every second, GSV every 5 seconds at 9600 baud.
+    fn default() -> Self {
+        SwitchToNMEA {
+            debug: NMEADebugMode::default(),
+            gga_rate: 1,
+            gll_rate: 0,
+            gsa_rate: 1,
+            gsv_rate: 5,
+            rmc_rate: 1,
+            vtg_rate: 0,
+            mss_rate: 0,
+            epe_rate: 0,
+            zda_rate: 0,
+            bit_rate: 9600,
+        }
+    }
+}
+
+impl SwitchToNMEA {
+    ///
+    /// The message ID and body, without the framing.
+    pub fn payload(&self) -> Result<Vec<u8>, Error> {
+        let mut out: Vec<u8> = Vec::with_capacity(24);
+        out.write_u8(MESSAGE_ID)?;
+        out.write_u8(self.debug as u8)?;
+        for rate in [
+            self.gga_rate,
+            self.gll_rate,
+            self.gsa_rate,
+            self.gsv_rate,
+            self.rmc_rate,
+            self.vtg_rate,
+            self.mss_rate,
+            self.epe_rate,
+            self.zda_rate,
+            // unused field
+            0,
+        ] {
+            out.write_u8(rate)?;
+            // always send the sentence checksums
+            out.write_u8(1)?;
+        }
+        out.write_be_u16(self.bit_rate)?;
+        Ok(out)
+    }
+}
+
+impl Packet for SwitchToNMEA {
+    type PacketType = PacketType;
+
+    fn get_bytes(&self) -> Result<Vec<u8>, Error> {
+        encode_frame(&self.payload()?)
+    }
+
+    fn get_type(&self) -> Self::PacketType {
+        PacketType::SwitchToNMEA(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use irox_bits::Error;
+    use irox_tools::hex;
+    use irox_tools::packetio::Packet;
+
+    use crate::output::x81_switchnmea::SwitchToNMEA;
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_example() -> Result<(), Error> {
+        let expected =
+            hex!("A0A20018" "810201010001010105010101000100010001000100012580" "013AB0B3");
+        assert_eq!(expected.as_slice(), SwitchToNMEA::default().get_bytes()?);
+        Ok(())
+    }
+}
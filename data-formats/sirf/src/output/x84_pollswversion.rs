@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use irox_bits::Error;
+use irox_tools::packetio::Packet;
+
+use crate::packet::{encode_frame, PacketType};
+
+pub const MESSAGE_ID: u8 = 0x84;
+
+///
+/// Message 0x84 - Poll Software Version.  The device responds with message 0x06.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PollSoftwareVersion;
+
+impl PollSoftwareVersion {
+    ///
+    /// The message ID and body, without the framing.
+    pub fn payload(&self) -> [u8; 2] {
+        // control byte is unused.
+        [MESSAGE_ID, 0x00]
+    }
+}
+
+impl Packet for PollSoftwareVersion {
+    type PacketType = PacketType;
+
+    fn get_bytes(&self) -> Result<Vec<u8>, Error> {
+        encode_frame(&self.payload())
+    }
+
+    fn get_type(&self) -> Self::PacketType {
+        PacketType::PollSoftwareVersion
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use irox_bits::Error;
+    use irox_tools::hex;
+    use irox_tools::packetio::Packet;
+
+    use crate::output::x84_pollswversion::PollSoftwareVersion;
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_example() -> Result<(), Error> {
+        let expected = hex!("A0A20002" "8400" "0084B0B3");
+        assert_eq!(expected.as_slice(), PollSoftwareVersion.get_bytes()?);
+        Ok(())
+    }
+}
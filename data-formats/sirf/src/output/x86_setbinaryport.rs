@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use irox_bits::{Error, MutBits};
+use irox_tools::packetio::Packet;
+
+use crate::packet::{encode_frame, PacketType};
+
+pub const MESSAGE_ID: u8 = 0x86;
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Parity {
+    #[default]
+    None = 0,
+    Odd = 1,
+    Even = 2,
+}
+
+///
+/// Message 0x86 - Set Binary Serial Port.  Sets the serial settings of the port using the binary
+/// protocol, taking effect immediately.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SetBinarySerialPort {
+    /// One of 1200, 2400, 4800, 9600, 19200, 38400, 57600 or 115200
+    pub bit_rate: u32,
+    pub data_bits: u8,
+    pub stop_bits: u8,
+    pub parity: Parity,
+}
+
+impl SetBinarySerialPort {
+    ///
+    /// Sets the bit rate, with 8 data bits, no parity and 1 stop bit.
+    pub fn new(bit_rate: u32) -> Self {
+        SetBinarySerialPort {
+            bit_rate,
+            data_bits: 8,
+            stop_bits: 1,
+            parity: Parity::None,
+        }
+    }
+
+    ///
+    /// The message ID and body, without the framing.
+    pub fn payload(&self) -> Result<Vec<u8>, Error> {
+        let mut out: Vec<u8> = Vec::with_capacity(9);
+        out.write_u8(MESSAGE_ID)?;
+        out.write_be_u32(self.bit_rate)?;
+        out.write_u8(self.data_bits)?;
+        out.write_u8(self.stop_bits)?;
+        out.write_u8(self.parity as u8)?;
+        // pad
+        out.write_u8(0)?;
+        Ok(out)
+    }
+}
+
+impl Packet for SetBinarySerialPort {
+    type PacketType = PacketType;
+
+    fn get_bytes(&self) -> Result<Vec<u8>, Error> {
+        encode_frame(&self.payload()?)
+    }
+
+    fn get_type(&self) -> Self::PacketType {
+        PacketType::SetBinarySerialPort(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use irox_bits::Error;
+    use irox_tools::hex;
+    use irox_tools::packetio::Packet;
+
+    use crate::output::x86_setbinaryport::SetBinarySerialPort;
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_example() -> Result<(), Error> {
+        let expected = hex!("A0A20009" "860000258008010000" "0134B0B3");
+        assert_eq!(
+            expected.as_slice(),
+            SetBinarySerialPort::new(9600).get_bytes()?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use irox_bits::Error;
+use irox_tools::packetio::Packet;
+
+use crate::packet::{encode_frame, PacketType};
+
+pub const MESSAGE_ID: u8 = 0xA6;
+
+///
+/// Which messages a [`SetMessageRate`] applies to.  All but the first two are only available on
+/// GSW3 and SLC3 software.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MessageRateMode {
+    /// Enable or disable the single message
+    #[default]
+    OneMessage = 0,
+    /// Poll the single message once, immediately
+    PollOne = 1,
+    /// Enable or disable all messages
+    AllMessages = 2,
+    /// Enable or disable the default navigation messages (0x02, 0x04)
+    NavigationMessages = 3,
+    /// Enable or disable the default debug messages (0x09, 0xFF)
+    DebugMessages = 4,
+    /// Enable or disable the navigation debug messages (0x07, 0x1C - 0x1F)
+    NavigationDebugMessages = 5,
+}
+
+///
+/// Message 0xA6 - Set Message Rate.  Sets the output period of binary messages.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SetMessageRate {
+    pub mode: MessageRateMode,
+    pub message_id: u8,
+    /// Output period in seconds, 0 - 30, 0 to stop sending the message
+    pub update_rate: u8,
+}
+
+impl SetMessageRate {
+    ///
+    /// Outputs the message every `update_rate` seconds.
+    pub fn enable(message_id: u8, update_rate: u8) -> Self {
+        SetMessageRate {
+            mode: MessageRateMode::OneMessage,
+            message_id,
+            update_rate,
+        }
+    }
+
+    ///
+    /// Stops outputting the message.
+    pub fn disable(message_id: u8) -> Self {
+        Self::enable(message_id, 0)
+    }
+
+    ///
+    /// Requests the message be output once, immediately.
+    pub fn poll(message_id: u8) -> Self {
+        SetMessageRate {
+            mode: MessageRateMode::PollOne,
+            message_id,
+            update_rate: 0,
+        }
+    }
+
+    ///
+    /// The message ID and body, without the framing.
+    pub fn payload(&self) -> [u8; 8] {
+        [
+            MESSAGE_ID,
+            self.mode as u8,
+            self.message_id,
+            self.update_rate,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+
+impl Packet for SetMessageRate {
+    type PacketType = PacketType;
+
+    fn get_bytes(&self) -> Result<Vec<u8>, Error> {
+        encode_frame(&self.payload())
+    }
+
+    fn get_type(&self) -> Self::PacketType {
+        PacketType::SetMessageRate(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use irox_bits::Error;
+    use irox_tools::hex;
+    use irox_tools::packetio::Packet;
+
+    use crate::output::xa6_setmsgrate::SetMessageRate;
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_example() -> Result<(), Error> {
+        let expected = hex!("A0A20008" "A600020500000000" "00ADB0B3");
+        assert_eq!(
+            expected.as_slice(),
+            SetMessageRate::enable(0x02, 5).get_bytes()?
+        );
+        Ok(())
+    }
+}
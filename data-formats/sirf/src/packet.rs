@@ -25,6 +25,10 @@ use crate::input::{
     x1c_navmeasure, x1e_navsvstate, x29_geonavdata, x32_sbasparams, x33x6_trackerload,
     xff_asciidata,
 };
+use crate::output::x80_initdatasource::InitializeDataSource;
+use crate::output::x81_switchnmea::SwitchToNMEA;
+use crate::output::x86_setbinaryport::SetBinarySerialPort;
+use crate::output::xa6_setmsgrate::SetMessageRate;
 
 pub const START_LEN: usize = 2;
 pub const START_SEQ: [u8; 2] = [0xA0, 0xA2];
@@ -43,8 +47,11 @@ pub const MAX_PACKET_SIZE: usize =
 #[derive(Debug, Clone, PartialEq)]
 pub enum PacketType {
     // Messages to the SiRF device
-    // AdvancedPowerManagement = 0x35,
-    // InitializeDataSource = 0x80,
+    InitializeDataSource(InitializeDataSource),
+    SwitchToNMEA(SwitchToNMEA),
+    PollSoftwareVersion,
+    SetBinarySerialPort(SetBinarySerialPort),
+    SetMessageRate(SetMessageRate),
 
     // Messages from the SiRF device
     ReferenceNavigationData,
@@ -221,11 +228,41 @@ fn read_start<T: Bits>(input: &mut T) -> Result<(), BitsError> {
     Err(BitsErrorKind::InvalidData.into())
 }
 
-fn check_checksum(payload: &[u8], checksum: u16) -> bool {
+///
+/// The SiRF checksum is the 15-bit sum of the payload bytes.
+pub fn calculate_checksum(payload: &[u8]) -> u16 {
     let mut calc: u16 = 0;
     for val in payload {
         calc = calc.wrapping_add(u16::from(*val));
     }
+    calc & 0x7FFF
+}
+
+fn check_checksum(payload: &[u8], checksum: u16) -> bool {
+    calculate_checksum(payload) == checksum
+}
+
+///
+/// Writes the payload (starting with the message ID) to the output, wrapped in the start
+/// sequence, length, checksum and end sequence.
+pub fn write_frame<T: MutBits + ?Sized>(out: &mut T, payload: &[u8]) -> Result<(), BitsError> {
+    if payload.len() > MAX_PAYLOAD_LEN {
+        return Err(BitsError::new(
+            BitsErrorKind::InvalidInput,
+            "SiRF payload too long",
+        ));
+    }
+    out.write_all_bytes(&START_SEQ)?;
+    out.write_be_u16(payload.len() as u16)?;
+    out.write_all_bytes(payload)?;
+    out.write_be_u16(calculate_checksum(payload))?;
+    out.write_all_bytes(&END_SEQ)
+}
 
-    calc == checksum
+///
+/// Returns the payload (starting with the message ID) wrapped into a complete frame.
+pub fn encode_frame(payload: &[u8]) -> Result<Vec<u8>, BitsError> {
+    let mut out: Vec<u8> = Vec::with_capacity(payload.len() + 8);
+    write_frame(&mut out, payload)?;
+    Ok(out)
 }
@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use crate::{UBXClass, UBXRawMessage};
+use core::fmt::{Display, Formatter};
+use irox_bits::{Bits, Error};
+use irox_enums::{EnumName, EnumTryFromRepr, EnumTryFromStr};
+
+#[repr(u8)]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    Ord,
+    PartialOrd,
+    EnumName,
+    EnumTryFromRepr,
+    EnumTryFromStr,
+)]
+pub enum UBXAck {
+    NAK = 0x00,
+    ACK = 0x01,
+}
+impl UBXAck {
+    pub fn try_parse_payload(&self, mut pld: &[u8]) -> Result<UBXAckPayload, Error> {
+        let class = pld.read_u8()?;
+        let id = pld.read_u8()?;
+        Ok(match self {
+            UBXAck::NAK => UBXAckPayload::Nak { class, id },
+            UBXAck::ACK => UBXAckPayload::Ack { class, id },
+        })
+    }
+    pub fn try_parse(id: u8, pld: &[u8]) -> Result<UBXAckPayload, Error> {
+        let Ok(msg) = UBXAck::try_from(id) else {
+            return Ok(UBXAckPayload::Unknown {
+                id,
+                payload: pld.to_vec().into_boxed_slice(),
+            });
+        };
+        msg.try_parse_payload(pld)
+    }
+}
+
+///
+/// Acknowledgement of an input message, carrying the class and id of the
+/// message that was accepted or rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UBXAckPayload {
+    Ack { class: u8, id: u8 },
+    Nak { class: u8, id: u8 },
+    Unknown { id: u8, payload: Box<[u8]> },
+}
+impl UBXAckPayload {
+    ///
+    /// Returns `Some(true)` if this acknowledges the specified message, `Some(false)`
+    /// if it rejects it, and `None` if it refers to a different message.
+    pub fn acknowledges(&self, msg_class: u8, msg_id: u8) -> Option<bool> {
+        match self {
+            UBXAckPayload::Ack { class, id } if *class == msg_class && *id == msg_id => Some(true),
+            UBXAckPayload::Nak { class, id } if *class == msg_class && *id == msg_id => Some(false),
+            _ => None,
+        }
+    }
+}
+impl Display for UBXAckPayload {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self, f)
+    }
+}
+impl TryFrom<UBXRawMessage> for UBXAckPayload {
+    type Error = UBXRawMessage;
+
+    fn try_from(value: UBXRawMessage) -> Result<Self, Self::Error> {
+        if value.class != UBXClass::ACK as u8 {
+            return Err(value);
+        }
+        match UBXAck::try_parse(value.id, &value.payload) {
+            Ok(v) => Ok(v),
+            Err(_e) => Err(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ack::{UBXAck, UBXAckPayload};
+    use crate::{UBXClass, UBXRawMessage};
+
+    #[test]
+    pub fn test_parse() {
+        let ack = UBXAck::try_parse(0x01, &[0x06, 0x8A]).unwrap();
+        assert_eq!(
+            UBXAckPayload::Ack {
+                class: 0x06,
+                id: 0x8A
+            },
+            ack
+        );
+        let nak = UBXAck::try_parse(0x00, &[0x06, 0x8A]).unwrap();
+        assert_eq!(
+            UBXAckPayload::Nak {
+                class: 0x06,
+                id: 0x8A
+            },
+            nak
+        );
+        let unk = UBXAck::try_parse(0x02, &[0x01]).unwrap();
+        assert_eq!(
+            UBXAckPayload::Unknown {
+                id: 0x02,
+                payload: Box::new([0x01])
+            },
+            unk
+        );
+        assert!(
+            UBXAck::try_parse(0x01, &[0x06]).is_err(),
+            "short payload accepted"
+        );
+    }
+
+    #[test]
+    pub fn test_acknowledges() {
+        let ack = UBXAckPayload::Ack {
+            class: 0x06,
+            id: 0x8A,
+        };
+        assert_eq!(Some(true), ack.acknowledges(0x06, 0x8A));
+        assert_eq!(None, ack.acknowledges(0x06, 0x8B));
+        let nak = UBXAckPayload::Nak {
+            class: 0x06,
+            id: 0x8A,
+        };
+        assert_eq!(Some(false), nak.acknowledges(0x06, 0x8A));
+        assert_eq!(None, nak.acknowledges(0x05, 0x8A));
+        let unk = UBXAckPayload::Unknown {
+            id: 0x02,
+            payload: Box::new([0x06, 0x8A]),
+        };
+        assert_eq!(None, unk.acknowledges(0x06, 0x8A));
+    }
+
+    #[test]
+    pub fn test_from_raw() {
+        let raw = UBXRawMessage::new(UBXClass::ACK, 0x00, Box::new([0x06, 0x8A]));
+        assert_eq!(
+            Ok(UBXAckPayload::Nak {
+                class: 0x06,
+                id: 0x8A
+            }),
+            UBXAckPayload::try_from(raw)
+        );
+        let raw = UBXRawMessage::new(UBXClass::CFG, 0x00, Box::new([0x06, 0x8A]));
+        assert!(
+            UBXAckPayload::try_from(raw).is_err(),
+            "non-ACK class accepted"
+        );
+    }
+}
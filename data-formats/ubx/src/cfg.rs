@@ -2,11 +2,14 @@
 // Copyright 2025 IROX Contributors
 //
 
+use crate::cfg::keys::{ConfigKeyDef, ConfigMsgOut, ConfigValue};
 use crate::{UBXClass, UBXRawMessage};
 use core::fmt::{Debug, Display, Formatter};
-use irox_bits::{Bits, BitsError, BitsErrorKind, Error};
+use irox_bits::{Bits, BitsError, BitsErrorKind, Error, MutBits};
 use irox_enums::{EnumName, EnumTryFromRepr, EnumTryFromStr};
 
+pub mod keys;
+
 #[repr(u8)]
 #[derive(
     Debug,
@@ -72,6 +75,10 @@ impl UBXCfg {
     pub fn try_parse_payload(&self, pld: &[u8]) -> Result<UBXCfgPayload, Error> {
         Ok(match self {
             UBXCfg::VALGET => UBXCfgPayload::ValGet(UBXValGet::parse_from(pld)?),
+            UBXCfg::VALSET => UBXCfgPayload::ValSet(UBXValSet::parse_from(pld)?),
+            UBXCfg::VALDEL => UBXCfgPayload::ValDel(UBXValDel::parse_from(pld)?),
+            UBXCfg::RST => UBXCfgPayload::Rst(UBXCfgRst::parse_from(pld)?),
+            UBXCfg::MSG => UBXCfgPayload::Msg(UBXCfgMsg::parse_from(pld)?),
             UBXCfg::OTP => UBXCfgPayload::OTP(UBXConfigOTP::parse_from(pld)?),
             _ => UBXCfgPayload::Unknown {
                 id: *self as u8,
@@ -93,6 +100,10 @@ impl UBXCfg {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum UBXCfgPayload {
     ValGet(UBXValGet),
+    ValSet(UBXValSet),
+    ValDel(UBXValDel),
+    Rst(UBXCfgRst),
+    Msg(UBXCfgMsg),
     OTP(UBXConfigOTP),
     Unknown { id: u8, payload: Box<[u8]> },
 }
@@ -145,6 +156,403 @@ pub enum UBXCfgLayer {
     /// Default for the particular chip
     DEFAULT = 0x07,
 }
+
+///
+/// Set of the layers a CFG-VALSET or CFG-VALDEL applies to.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct UBXCfgLayers(pub u8);
+impl UBXCfgLayers {
+    pub const RAM: UBXCfgLayers = UBXCfgLayers(0x01);
+    pub const BBR: UBXCfgLayers = UBXCfgLayers(0x02);
+    pub const FLASH: UBXCfgLayers = UBXCfgLayers(0x04);
+    /// RAM, BBR & Flash - applies immediately and persists across power cycles.
+    pub const ALL: UBXCfgLayers = UBXCfgLayers(0x07);
+
+    #[must_use]
+    pub const fn with(self, other: UBXCfgLayers) -> UBXCfgLayers {
+        UBXCfgLayers(self.0 | other.0)
+    }
+    pub const fn contains(&self, layer: UBXCfgLayer) -> bool {
+        match layer {
+            UBXCfgLayer::DEFAULT => false,
+            _ => self.0 & (1 << layer as u8) != 0,
+        }
+    }
+}
+impl From<UBXCfgLayer> for UBXCfgLayers {
+    fn from(value: UBXCfgLayer) -> Self {
+        match value {
+            UBXCfgLayer::DEFAULT => UBXCfgLayers(0),
+            _ => UBXCfgLayers(1 << value as u8),
+        }
+    }
+}
+
+///
+/// Transactions allow a configuration spread across several CFG-VALSET or CFG-VALDEL
+/// messages to be applied at once.  Only available with message version 1.
+#[repr(u8)]
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Hash, EnumName, EnumTryFromRepr, EnumTryFromStr,
+)]
+pub enum UBXCfgTransaction {
+    /// Transactionless, the message is applied immediately
+    #[default]
+    None = 0,
+    /// Restart a transaction, dropping any pending values
+    Begin = 1,
+    /// Add to the current transaction
+    Continue = 2,
+    /// Add to and apply the current transaction
+    Apply = 3,
+}
+
+///
+/// CFG-VALSET: Sets the values of configuration keys in one or more layers.  At most
+/// 64 items may be sent in a single message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UBXValSet {
+    pub version: u8,
+    pub layers: UBXCfgLayers,
+    pub transaction: UBXCfgTransaction,
+    pub cfg_data: Vec<ConfigData>,
+}
+impl UBXValSet {
+    pub const MAX_ITEMS: usize = 64;
+
+    pub fn new(layers: UBXCfgLayers) -> UBXValSet {
+        UBXValSet {
+            version: 0,
+            layers,
+            transaction: UBXCfgTransaction::None,
+            cfg_data: Vec::new(),
+        }
+    }
+    ///
+    /// Makes this part of a transaction, switching to message version 1
+    #[must_use]
+    pub fn with_transaction(mut self, transaction: UBXCfgTransaction) -> UBXValSet {
+        self.version = 1;
+        self.transaction = transaction;
+        self
+    }
+    #[must_use]
+    pub fn with_item(mut self, data: ConfigData) -> UBXValSet {
+        self.cfg_data.push(data);
+        self
+    }
+    ///
+    /// Adds the value for the key, failing if the value is the wrong type for the key.
+    pub fn with_value(self, key: &ConfigKeyDef, value: ConfigValue) -> Result<UBXValSet, Error> {
+        Ok(self.with_item(key.with_value(value)?))
+    }
+
+    pub fn parse_from(mut source: &[u8]) -> Result<UBXValSet, Error> {
+        let version = source.read_u8()?;
+        let layers = UBXCfgLayers(source.read_u8()?);
+        let transaction = match version {
+            0 => {
+                source.read_le_u16()?;
+                UBXCfgTransaction::None
+            }
+            _ => {
+                let transaction =
+                    UBXCfgTransaction::try_from(source.read_u8()? & 0x03).unwrap_or_default();
+                source.read_u8()?;
+                transaction
+            }
+        };
+        let cfg_data = ConfigData::read_config_items(source)?;
+        Ok(UBXValSet {
+            version,
+            layers,
+            transaction,
+            cfg_data,
+        })
+    }
+
+    pub fn to_message(&self) -> Result<UBXRawMessage, Error> {
+        if self.cfg_data.len() > Self::MAX_ITEMS {
+            return Err(BitsError::new(
+                BitsErrorKind::InvalidInput,
+                "too many items for a single CFG-VALSET",
+            ));
+        }
+        let mut pld = Vec::<u8>::new();
+        pld.write_u8(self.version)?;
+        pld.write_u8(self.layers.0)?;
+        pld.write_u8(self.transaction as u8)?;
+        pld.write_u8(0)?;
+        for data in &self.cfg_data {
+            data.write_to(&mut pld)?;
+        }
+        Ok(UBXRawMessage::new(
+            UBXClass::CFG,
+            UBXCfg::VALSET as u8,
+            pld.into_boxed_slice(),
+        ))
+    }
+}
+
+///
+/// CFG-VALGET poll request: Requests the values of the keys from a single layer.  The
+/// receiver responds with a [`UBXValGet`] followed by an ACK.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UBXValGetRequest {
+    pub layer: UBXCfgLayer,
+    /// Number of values to skip in the response, for wildcard requests that would
+    /// return more than 64 values.
+    pub position: u16,
+    pub keys: Vec<ConfigKey>,
+}
+impl UBXValGetRequest {
+    pub fn new(layer: UBXCfgLayer) -> UBXValGetRequest {
+        UBXValGetRequest {
+            layer,
+            position: 0,
+            keys: Vec::new(),
+        }
+    }
+    #[must_use]
+    pub fn with_key(mut self, key: ConfigKey) -> UBXValGetRequest {
+        self.keys.push(key);
+        self
+    }
+    #[must_use]
+    pub fn with_position(mut self, position: u16) -> UBXValGetRequest {
+        self.position = position;
+        self
+    }
+
+    pub fn to_message(&self) -> Result<UBXRawMessage, Error> {
+        let mut pld = Vec::<u8>::new();
+        pld.write_u8(0)?;
+        pld.write_u8(self.layer as u8)?;
+        pld.write_le_u16(self.position)?;
+        for key in &self.keys {
+            pld.write_le_u32(key.as_raw())?;
+        }
+        Ok(UBXRawMessage::new(
+            UBXClass::CFG,
+            UBXCfg::VALGET as u8,
+            pld.into_boxed_slice(),
+        ))
+    }
+}
+
+///
+/// CFG-VALDEL: Deletes the values of the keys from the BBR and/or Flash layers, reverting
+/// them to the defaults on the next startup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UBXValDel {
+    pub version: u8,
+    pub layers: UBXCfgLayers,
+    pub transaction: UBXCfgTransaction,
+    pub keys: Vec<ConfigKey>,
+}
+impl UBXValDel {
+    pub fn new(layers: UBXCfgLayers) -> UBXValDel {
+        UBXValDel {
+            version: 0,
+            layers,
+            transaction: UBXCfgTransaction::None,
+            keys: Vec::new(),
+        }
+    }
+    ///
+    /// Makes this part of a transaction, switching to message version 1
+    #[must_use]
+    pub fn with_transaction(mut self, transaction: UBXCfgTransaction) -> UBXValDel {
+        self.version = 1;
+        self.transaction = transaction;
+        self
+    }
+    #[must_use]
+    pub fn with_key(mut self, key: ConfigKey) -> UBXValDel {
+        self.keys.push(key);
+        self
+    }
+
+    pub fn parse_from(mut source: &[u8]) -> Result<UBXValDel, Error> {
+        let version = source.read_u8()?;
+        let layers = UBXCfgLayers(source.read_u8()?);
+        let transaction = UBXCfgTransaction::try_from(source.read_u8()? & 0x03).unwrap_or_default();
+        source.read_u8()?;
+        let mut keys = Vec::new();
+        while !source.is_empty() {
+            keys.push(source.read_le_u32()?.into());
+        }
+        Ok(UBXValDel {
+            version,
+            layers,
+            transaction,
+            keys,
+        })
+    }
+
+    pub fn to_message(&self) -> Result<UBXRawMessage, Error> {
+        if self.layers.contains(UBXCfgLayer::RAM) {
+            return Err(BitsError::new(
+                BitsErrorKind::InvalidInput,
+                "values can only be deleted from the BBR and Flash layers",
+            ));
+        }
+        let mut pld = Vec::<u8>::new();
+        pld.write_u8(self.version)?;
+        pld.write_u8(self.layers.0)?;
+        pld.write_u8(self.transaction as u8)?;
+        pld.write_u8(0)?;
+        for key in &self.keys {
+            pld.write_le_u32(key.as_raw())?;
+        }
+        Ok(UBXRawMessage::new(
+            UBXClass::CFG,
+            UBXCfg::VALDEL as u8,
+            pld.into_boxed_slice(),
+        ))
+    }
+}
+
+///
+/// Type of reset performed by CFG-RST
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumName, EnumTryFromRepr, EnumTryFromStr)]
+pub enum UBXResetMode {
+    /// Hardware reset (watchdog), immediately
+    HardwareWatchdog = 0x00,
+    /// Controlled software reset
+    Software = 0x01,
+    /// Controlled software reset of the GNSS only
+    SoftwareGNSSOnly = 0x02,
+    /// Hardware reset (watchdog) after shutdown
+    HardwareAfterShutdown = 0x04,
+    /// Controlled GNSS stop
+    GNSSStop = 0x08,
+    /// Controlled GNSS start
+    GNSSStart = 0x09,
+}
+
+///
+/// CFG-RST: Resets the receiver, clearing the selected parts of the battery backed RAM.
+/// The receiver does not acknowledge this message.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UBXCfgRst {
+    /// BBR sections to clear, see [`UBXCfgRst::HOT_START`] and friends.
+    pub nav_bbr_mask: u16,
+    pub reset_mode: UBXResetMode,
+}
+impl UBXCfgRst {
+    /// Keeps all of the BBR
+    pub const HOT_START: u16 = 0x0000;
+    /// Clears the ephemeris
+    pub const WARM_START: u16 = 0x0001;
+    /// Clears everything
+    pub const COLD_START: u16 = 0xFFFF;
+
+    pub const fn new(nav_bbr_mask: u16, reset_mode: UBXResetMode) -> UBXCfgRst {
+        UBXCfgRst {
+            nav_bbr_mask,
+            reset_mode,
+        }
+    }
+    pub const fn hot_start() -> UBXCfgRst {
+        UBXCfgRst::new(Self::HOT_START, UBXResetMode::SoftwareGNSSOnly)
+    }
+    pub const fn warm_start() -> UBXCfgRst {
+        UBXCfgRst::new(Self::WARM_START, UBXResetMode::SoftwareGNSSOnly)
+    }
+    pub const fn cold_start() -> UBXCfgRst {
+        UBXCfgRst::new(Self::COLD_START, UBXResetMode::SoftwareGNSSOnly)
+    }
+
+    pub fn parse_from(mut source: &[u8]) -> Result<UBXCfgRst, Error> {
+        let nav_bbr_mask = source.read_le_u16()?;
+        let Ok(reset_mode) = UBXResetMode::try_from(source.read_u8()?) else {
+            return Err(BitsError::new(
+                BitsErrorKind::Unsupported,
+                "unsupported UBXResetMode encountered",
+            ));
+        };
+        Ok(UBXCfgRst {
+            nav_bbr_mask,
+            reset_mode,
+        })
+    }
+
+    pub fn to_message(&self) -> Result<UBXRawMessage, Error> {
+        let mut pld = Vec::<u8>::new();
+        pld.write_le_u16(self.nav_bbr_mask)?;
+        pld.write_u8(self.reset_mode as u8)?;
+        pld.write_u8(0)?;
+        Ok(UBXRawMessage::new(
+            UBXClass::CFG,
+            UBXCfg::RST as u8,
+            pld.into_boxed_slice(),
+        ))
+    }
+}
+
+///
+/// The three forms of a CFG-MSG message
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum UBXMsgRate {
+    /// Poll the rates of the message on all ports
+    Poll,
+    /// Sets the rate on the port the message is received on
+    CurrentPort(u8),
+    /// Sets the rates on all six ports - I2C, UART1, UART2, USB, SPI, reserved
+    AllPorts([u8; 6]),
+}
+
+///
+/// CFG-MSG: Sets or polls the output rate of a message, in navigation solutions per
+/// output.  Superseded by the CFG-MSGOUT keys on generation 9+ receivers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UBXCfgMsg {
+    pub msg_class: u8,
+    pub msg_id: u8,
+    pub rate: UBXMsgRate,
+}
+impl UBXCfgMsg {
+    pub fn new(msg_class: UBXClass, msg_id: u8, rate: UBXMsgRate) -> UBXCfgMsg {
+        UBXCfgMsg {
+            msg_class: msg_class as u8,
+            msg_id,
+            rate,
+        }
+    }
+
+    pub fn parse_from(mut source: &[u8]) -> Result<UBXCfgMsg, Error> {
+        let msg_class = source.read_u8()?;
+        let msg_id = source.read_u8()?;
+        let rate = match source.len() {
+            0 => UBXMsgRate::Poll,
+            1 => UBXMsgRate::CurrentPort(source.read_u8()?),
+            _ => UBXMsgRate::AllPorts(source.read_exact::<6>()?),
+        };
+        Ok(UBXCfgMsg {
+            msg_class,
+            msg_id,
+            rate,
+        })
+    }
+
+    pub fn to_message(&self) -> Result<UBXRawMessage, Error> {
+        let mut pld = Vec::<u8>::new();
+        pld.write_u8(self.msg_class)?;
+        pld.write_u8(self.msg_id)?;
+        match self.rate {
+            UBXMsgRate::Poll => {}
+            UBXMsgRate::CurrentPort(rate) => pld.write_u8(rate)?,
+            UBXMsgRate::AllPorts(rates) => pld.write_all_bytes(&rates)?,
+        }
+        Ok(UBXRawMessage::new(
+            UBXClass::CFG,
+            UBXCfg::MSG as u8,
+            pld.into_boxed_slice(),
+        ))
+    }
+}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UBXValGet {
     pub version: u8,
@@ -177,6 +585,20 @@ pub struct ConfigData {
     pub raw_value: ConfigRawValue,
 }
 impl ConfigData {
+    ///
+    /// The typed value, if the key is in the [`keys`] catalog.
+    pub fn value(&self) -> Option<ConfigValue> {
+        if self.key.group == ConfigMsgOut::GROUP {
+            if let ConfigRawValue::OneByte(v) = self.raw_value {
+                return Some(ConfigValue::U1(v));
+            }
+        }
+        ConfigKeyDef::find_by_key(self.key.as_raw())?.decode(&self.raw_value)
+    }
+    pub fn write_to<T: MutBits>(&self, out: &mut T) -> Result<(), Error> {
+        out.write_le_u32(self.key.as_raw())?;
+        self.raw_value.write_to(out)
+    }
     pub fn read_config_items(mut source: &[u8]) -> Result<Vec<Self>, Error> {
         let mut cfg_data = Vec::<ConfigData>::new();
         while !source.is_empty() {
@@ -200,6 +622,9 @@ pub struct ConfigKey {
 }
 impl Display for ConfigKey {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if let Some(def) = ConfigKeyDef::find_by_key(self.as_raw()) {
+            return f.write_str(def.name);
+        }
         let grp = ConfigGroup::try_from(self.group);
         let (grp, id) = match grp {
             Ok(group) => (group.name().to_string(), group.item_from_u16(self.id)),
@@ -221,6 +646,18 @@ impl From<u32> for ConfigKey {
     }
 }
 impl ConfigKey {
+    /// Item ID that matches every item in the group
+    pub const WILDCARD_ITEM: u16 = 0xFFF;
+
+    ///
+    /// A key that matches every item in the group, for use with CFG-VALGET and CFG-VALDEL.
+    pub const fn all_in_group(group: ConfigGroup) -> ConfigKey {
+        ConfigKey {
+            size: 0,
+            group: group as u8,
+            id: Self::WILDCARD_ITEM,
+        }
+    }
     pub fn as_raw(&self) -> u32 {
         (self.id as u32) | ((self.group as u32) << 16) | ((self.size as u32) << 28)
     }
@@ -252,6 +689,17 @@ pub enum ConfigRawValue {
     FourBytes([u8; 4]),
     EightBytes([u8; 8]),
 }
+impl ConfigRawValue {
+    pub fn write_to<T: MutBits>(&self, out: &mut T) -> Result<(), Error> {
+        match self {
+            ConfigRawValue::Bool(v) => out.write_bool(*v),
+            ConfigRawValue::OneByte(v) => out.write_u8(*v),
+            ConfigRawValue::TwoBytes(v) => out.write_all_bytes(v),
+            ConfigRawValue::FourBytes(v) => out.write_all_bytes(v),
+            ConfigRawValue::EightBytes(v) => out.write_all_bytes(v),
+        }
+    }
+}
 #[repr(u8)]
 #[derive(
     Debug,
@@ -327,6 +775,10 @@ impl ConfigGroup {
     pub fn item_from_u16(&self, item: u16) -> String {
         match self {
             ConfigGroup::TMODE => ConfigTmode::name_from_u16(item).to_string(),
+            ConfigGroup::MSGOUT => match ConfigMsgOut::from_item(item) {
+                Some((msg, iface)) => format!("{}_{}", msg.name(), iface.name()),
+                None => format!("UNK(0x{item:0X})"),
+            },
             _ => format!("UNK(0x{item:0X})"),
         }
     }
@@ -387,10 +839,94 @@ impl UBXConfigOTP {
 }
 
 #[cfg(test)]
+#[allow(clippy::panic)]
 mod test {
-    use crate::UBXParser;
+    use crate::cfg::keys::{ConfigMsgOut, ConfigValue, UBXInterface, CFG_RATE_MEAS};
+    use crate::cfg::{
+        ConfigGroup, ConfigKey, UBXCfgLayer, UBXCfgLayers, UBXCfgMsg, UBXCfgPayload, UBXCfgRst,
+        UBXCfgTransaction, UBXMsgRate, UBXValDel, UBXValGetRequest, UBXValSet,
+    };
+    use crate::mon::UBXMonVer;
+    use crate::nav::UBXNav;
+    use crate::{UBXClass, UBXParser, UBXPayload};
     use irox_tools::hex;
-    use irox_tools::packetio::PacketBuilder;
+    use irox_tools::packetio::{Packet, PacketBuilder};
+
+    #[test]
+    pub fn test_valset() {
+        let msg = UBXValSet::new(UBXCfgLayers::RAM)
+            .with_value(&CFG_RATE_MEAS, ConfigValue::U2(100))
+            .unwrap()
+            .to_message()
+            .unwrap();
+        assert_eq!(
+            Some(hex!("B562068A0A0000010000010021306400").as_slice()),
+            msg.get_bytes().unwrap().get(..16)
+        );
+        assert_eq!(msg.calculate_checksum(), msg.get_checksum());
+
+        let valset = UBXValSet::new(UBXCfgLayers::RAM.with(UBXCfgLayers::FLASH))
+            .with_transaction(UBXCfgTransaction::Begin)
+            .with_item(ConfigMsgOut::UBX_NAV_PVT.with_rate(UBXInterface::USB, 1));
+        let bytes = valset.to_message().unwrap().get_bytes().unwrap();
+        let parsed = UBXParser::default()
+            .build_from(&mut bytes.as_slice())
+            .unwrap();
+        assert_eq!(
+            UBXPayload::Cfg(UBXCfgPayload::ValSet(valset)),
+            parsed.payload
+        );
+        assert!(UBXCfgLayers::ALL.contains(UBXCfgLayer::BBR));
+        assert!(!UBXCfgLayers::RAM.contains(UBXCfgLayer::FLASH));
+    }
+
+    #[test]
+    pub fn test_valget_valdel() {
+        let msg = UBXValGetRequest::new(UBXCfgLayer::RAM)
+            .with_key(CFG_RATE_MEAS.key())
+            .with_key(ConfigKey::all_in_group(ConfigGroup::TMODE))
+            .to_message()
+            .unwrap();
+        assert_eq!(
+            Some(hex!("B562068B0C000000000001002130FF0F0300").as_slice()),
+            msg.get_bytes().unwrap().get(..18)
+        );
+
+        let valdel = UBXValDel::new(UBXCfgLayers::BBR.with(UBXCfgLayers::FLASH))
+            .with_key(CFG_RATE_MEAS.key());
+        let bytes = valdel.to_message().unwrap().get_bytes().unwrap();
+        let parsed = UBXParser::default()
+            .build_from(&mut bytes.as_slice())
+            .unwrap();
+        assert_eq!(
+            UBXPayload::Cfg(UBXCfgPayload::ValDel(valdel)),
+            parsed.payload
+        );
+        assert!(UBXValDel::new(UBXCfgLayers::ALL).to_message().is_err());
+    }
+
+    #[test]
+    pub fn test_rst_msg_ver() {
+        let msg = UBXCfgRst::cold_start().to_message().unwrap();
+        assert_eq!(
+            hex!("B56206040400FFFF02000E61"),
+            msg.get_bytes().unwrap().as_slice()
+        );
+
+        let msg = UBXCfgMsg::new(UBXClass::NAV, UBXNav::PVT as u8, UBXMsgRate::CurrentPort(1))
+            .to_message()
+            .unwrap();
+        assert_eq!(
+            hex!("B562060103000107011351"),
+            msg.get_bytes().unwrap().as_slice()
+        );
+
+        let msg = UBXMonVer::poll();
+        assert_eq!(
+            hex!("B5620A0400000E34"),
+            msg.get_bytes().unwrap().as_slice()
+        );
+    }
 
     #[test]
     #[ignore]
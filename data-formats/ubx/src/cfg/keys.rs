@@ -0,0 +1,558 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! Catalog of the named configuration keys of the generation 9+ configuration interface
+//! (CFG-VALSET, CFG-VALGET, CFG-VALDEL), and the types of their values.
+
+use crate::cfg::{ConfigData, ConfigKey, ConfigRawValue};
+use irox_bits::{BitsError, BitsErrorKind, Error};
+use irox_enums::{EnumIterItem, EnumName, EnumTryFromRepr, EnumTryFromStr};
+
+///
+/// Storage type of a configuration value, as listed in the interface description.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumName)]
+pub enum ConfigValueType {
+    /// Single bit boolean, stored as one byte
+    L,
+    U1,
+    I1,
+    /// Bitfield
+    X1,
+    /// Enumeration
+    E1,
+    U2,
+    I2,
+    X2,
+    E2,
+    U4,
+    I4,
+    X4,
+    E4,
+    R4,
+    U8,
+    I8,
+    X8,
+    R8,
+}
+
+impl ConfigValueType {
+    ///
+    /// The size code stored in bits 28-30 of a key with this value type
+    pub const fn size(&self) -> u8 {
+        match self {
+            ConfigValueType::L => 0x01,
+            ConfigValueType::U1
+            | ConfigValueType::I1
+            | ConfigValueType::X1
+            | ConfigValueType::E1 => 0x02,
+            ConfigValueType::U2
+            | ConfigValueType::I2
+            | ConfigValueType::X2
+            | ConfigValueType::E2 => 0x03,
+            ConfigValueType::U4
+            | ConfigValueType::I4
+            | ConfigValueType::X4
+            | ConfigValueType::E4
+            | ConfigValueType::R4 => 0x04,
+            ConfigValueType::U8
+            | ConfigValueType::I8
+            | ConfigValueType::X8
+            | ConfigValueType::R8 => 0x05,
+        }
+    }
+
+    ///
+    /// Converts the raw value into a typed value, if the raw value has the right size.
+    pub fn decode(&self, raw: &ConfigRawValue) -> Option<ConfigValue> {
+        Some(match (self, raw) {
+            (ConfigValueType::L, ConfigRawValue::Bool(v)) => ConfigValue::Bool(*v),
+            (ConfigValueType::I1, ConfigRawValue::OneByte(v)) => {
+                ConfigValue::I1(i8::from_le_bytes([*v]))
+            }
+            (_, ConfigRawValue::OneByte(v)) => ConfigValue::U1(*v),
+            (ConfigValueType::I2, ConfigRawValue::TwoBytes(v)) => {
+                ConfigValue::I2(i16::from_le_bytes(*v))
+            }
+            (_, ConfigRawValue::TwoBytes(v)) => ConfigValue::U2(u16::from_le_bytes(*v)),
+            (ConfigValueType::I4, ConfigRawValue::FourBytes(v)) => {
+                ConfigValue::I4(i32::from_le_bytes(*v))
+            }
+            (ConfigValueType::R4, ConfigRawValue::FourBytes(v)) => {
+                ConfigValue::R4(f32::from_le_bytes(*v))
+            }
+            (_, ConfigRawValue::FourBytes(v)) => ConfigValue::U4(u32::from_le_bytes(*v)),
+            (ConfigValueType::I8, ConfigRawValue::EightBytes(v)) => {
+                ConfigValue::I8(i64::from_le_bytes(*v))
+            }
+            (ConfigValueType::R8, ConfigRawValue::EightBytes(v)) => {
+                ConfigValue::R8(f64::from_le_bytes(*v))
+            }
+            (_, ConfigRawValue::EightBytes(v)) => ConfigValue::U8(u64::from_le_bytes(*v)),
+            _ => return None,
+        })
+        .filter(|v| self.accepts(v))
+    }
+
+    ///
+    /// Returns true if the typed value can be stored in a key of this type.  Bitfields and
+    /// enumerations are stored as the unsigned value of the same size.
+    pub const fn accepts(&self, value: &ConfigValue) -> bool {
+        matches!(
+            (self, value),
+            (ConfigValueType::L, ConfigValue::Bool(_))
+                | (
+                    ConfigValueType::U1 | ConfigValueType::X1 | ConfigValueType::E1,
+                    ConfigValue::U1(_)
+                )
+                | (ConfigValueType::I1, ConfigValue::I1(_))
+                | (
+                    ConfigValueType::U2 | ConfigValueType::X2 | ConfigValueType::E2,
+                    ConfigValue::U2(_)
+                )
+                | (ConfigValueType::I2, ConfigValue::I2(_))
+                | (
+                    ConfigValueType::U4 | ConfigValueType::X4 | ConfigValueType::E4,
+                    ConfigValue::U4(_)
+                )
+                | (ConfigValueType::I4, ConfigValue::I4(_))
+                | (ConfigValueType::R4, ConfigValue::R4(_))
+                | (
+                    ConfigValueType::U8 | ConfigValueType::X8,
+                    ConfigValue::U8(_)
+                )
+                | (ConfigValueType::I8, ConfigValue::I8(_))
+                | (ConfigValueType::R8, ConfigValue::R8(_))
+        )
+    }
+}
+
+///
+/// A typed configuration value
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ConfigValue {
+    Bool(bool),
+    U1(u8),
+    I1(i8),
+    U2(u16),
+    I2(i16),
+    U4(u32),
+    I4(i32),
+    U8(u64),
+    I8(i64),
+    R4(f32),
+    R8(f64),
+}
+
+impl From<ConfigValue> for ConfigRawValue {
+    fn from(value: ConfigValue) -> Self {
+        match value {
+            ConfigValue::Bool(v) => ConfigRawValue::Bool(v),
+            ConfigValue::U1(v) => ConfigRawValue::OneByte(v),
+            ConfigValue::I1(v) => ConfigRawValue::OneByte(v.to_le_bytes()[0]),
+            ConfigValue::U2(v) => ConfigRawValue::TwoBytes(v.to_le_bytes()),
+            ConfigValue::I2(v) => ConfigRawValue::TwoBytes(v.to_le_bytes()),
+            ConfigValue::U4(v) => ConfigRawValue::FourBytes(v.to_le_bytes()),
+            ConfigValue::I4(v) => ConfigRawValue::FourBytes(v.to_le_bytes()),
+            ConfigValue::R4(v) => ConfigRawValue::FourBytes(v.to_le_bytes()),
+            ConfigValue::U8(v) => ConfigRawValue::EightBytes(v.to_le_bytes()),
+            ConfigValue::I8(v) => ConfigRawValue::EightBytes(v.to_le_bytes()),
+            ConfigValue::R8(v) => ConfigRawValue::EightBytes(v.to_le_bytes()),
+        }
+    }
+}
+
+///
+/// A named configuration key and the type of its value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ConfigKeyDef {
+    pub name: &'static str,
+    pub key: u32,
+    pub value_type: ConfigValueType,
+}
+
+impl ConfigKeyDef {
+    pub const fn new(name: &'static str, key: u32, value_type: ConfigValueType) -> Self {
+        Self {
+            name,
+            key,
+            value_type,
+        }
+    }
+
+    pub fn key(&self) -> ConfigKey {
+        self.key.into()
+    }
+
+    ///
+    /// Pairs this key with the value, failing if the value is the wrong type for the key.
+    pub fn with_value(&self, value: ConfigValue) -> Result<ConfigData, Error> {
+        if !self.value_type.accepts(&value) {
+            return Err(BitsError::new(
+                BitsErrorKind::InvalidInput,
+                "value type does not match the config key type",
+            ));
+        }
+        Ok(ConfigData {
+            key: self.key(),
+            raw_value: value.into(),
+        })
+    }
+
+    ///
+    /// Decodes the raw value of this key into a typed value
+    pub fn decode(&self, raw: &ConfigRawValue) -> Option<ConfigValue> {
+        self.value_type.decode(raw)
+    }
+
+    ///
+    /// Looks up the catalog entry for the raw key value
+    pub fn find_by_key(key: u32) -> Option<&'static ConfigKeyDef> {
+        ALL_KEYS.iter().find(|k| k.key == key)
+    }
+
+    ///
+    /// Looks up the catalog entry for the key name, like `CFG-RATE-MEAS`
+    pub fn find_by_name(name: &str) -> Option<&'static ConfigKeyDef> {
+        ALL_KEYS.iter().find(|k| k.name == name)
+    }
+}
+
+macro_rules! config_keys {
+    ($($(#[$meta:meta])* $ident:ident = ($key:literal, $name:literal, $ty:ident);)*) => {
+        $(
+            $(#[$meta])*
+            pub const $ident: ConfigKeyDef = ConfigKeyDef::new($name, $key, ConfigValueType::$ty);
+        )*
+
+        /// Every named key in the catalog
+        pub const ALL_KEYS: &[ConfigKeyDef] = &[$($ident),*];
+    };
+}
+
+config_keys! {
+    /// Nominal time between GNSS measurements, in milliseconds
+    CFG_RATE_MEAS = (0x3021_0001, "CFG-RATE-MEAS", U2);
+    /// Ratio of the number of measurements to the number of navigation solutions
+    CFG_RATE_NAV = (0x3021_0002, "CFG-RATE-NAV", U2);
+    /// Time system to which measurements are aligned
+    CFG_RATE_TIMEREF = (0x2021_0003, "CFG-RATE-TIMEREF", E1);
+
+    /// Position fix mode, 1 is 2D only, 2 is 3D only, 3 is auto
+    CFG_NAVSPG_FIXMODE = (0x2011_0011, "CFG-NAVSPG-FIXMODE", E1);
+    /// Initial fix must be 3D
+    CFG_NAVSPG_INIFIX3D = (0x1011_0013, "CFG-NAVSPG-INIFIX3D", L);
+    /// UTC standard to be used
+    CFG_NAVSPG_UTCSTANDARD = (0x2011_001C, "CFG-NAVSPG-UTCSTANDARD", E1);
+    /// Dynamic platform model
+    CFG_NAVSPG_DYNMODEL = (0x2011_0021, "CFG-NAVSPG-DYNMODEL", E1);
+    /// Minimum number of satellites for navigation
+    CFG_NAVSPG_INFIL_MINSVS = (0x2011_00A1, "CFG-NAVSPG-INFIL_MINSVS", U1);
+    /// Maximum number of satellites for navigation
+    CFG_NAVSPG_INFIL_MAXSVS = (0x2011_00A2, "CFG-NAVSPG-INFIL_MAXSVS", U1);
+    /// Minimum satellite signal level for navigation, in dBHz
+    CFG_NAVSPG_INFIL_MINCNO = (0x2011_00A3, "CFG-NAVSPG-INFIL_MINCNO", U1);
+    /// Minimum elevation for a GNSS satellite to be used in navigation, in degrees
+    CFG_NAVSPG_INFIL_MINELEV = (0x2011_00A4, "CFG-NAVSPG-INFIL_MINELEV", I1);
+    /// Position DOP mask, scaled by 0.1
+    CFG_NAVSPG_OUTFIL_PDOP = (0x3011_00B1, "CFG-NAVSPG-OUTFIL_PDOP", U2);
+    /// Time DOP mask, scaled by 0.1
+    CFG_NAVSPG_OUTFIL_TDOP = (0x3011_00B2, "CFG-NAVSPG-OUTFIL_TDOP", U2);
+    /// Position accuracy mask, in meters
+    CFG_NAVSPG_OUTFIL_PACC = (0x3011_00B3, "CFG-NAVSPG-OUTFIL_PACC", U2);
+    /// Time accuracy mask, in meters
+    CFG_NAVSPG_OUTFIL_TACC = (0x3011_00B4, "CFG-NAVSPG-OUTFIL_TACC", U2);
+    /// Fixed altitude (mean sea level) for 2D fix mode, in centimeters
+    CFG_NAVSPG_CONSTR_ALT = (0x4011_00C1, "CFG-NAVSPG-CONSTR_ALT", I4);
+    /// Fixed altitude variance for 2D mode, in square centimeters
+    CFG_NAVSPG_CONSTR_ALTVAR = (0x4011_00C2, "CFG-NAVSPG-CONSTR_ALTVAR", U4);
+    /// DGNSS timeout, in seconds
+    CFG_NAVSPG_CONSTR_DGNSSTO = (0x2011_00C4, "CFG-NAVSPG-CONSTR_DGNSSTO", U1);
+
+    /// Differential corrections mode, 2 is RTK float, 3 is RTK fixed
+    CFG_NAVHPG_DGNSSMODE = (0x2014_0011, "CFG-NAVHPG-DGNSSMODE", E1);
+
+    /// Receiver mode, 0 is disabled, 1 is survey in, 2 is fixed
+    CFG_TMODE_MODE = (0x2003_0001, "CFG-TMODE-MODE", E1);
+    /// Determines whether the ARP position is given in ECEF or LAT/LON/HEIGHT
+    CFG_TMODE_POS_TYPE = (0x2003_0002, "CFG-TMODE-POS_TYPE", E1);
+    /// ECEF X coordinate of the ARP position, in centimeters
+    CFG_TMODE_ECEF_X = (0x4003_0003, "CFG-TMODE-ECEF_X", I4);
+    /// ECEF Y coordinate of the ARP position, in centimeters
+    CFG_TMODE_ECEF_Y = (0x4003_0004, "CFG-TMODE-ECEF_Y", I4);
+    /// ECEF Z coordinate of the ARP position, in centimeters
+    CFG_TMODE_ECEF_Z = (0x4003_0005, "CFG-TMODE-ECEF_Z", I4);
+    /// High-precision ECEF X coordinate of the ARP position, in 0.1 millimeters
+    CFG_TMODE_ECEF_X_HP = (0x2003_0006, "CFG-TMODE-ECEF_X_HP", I1);
+    /// High-precision ECEF Y coordinate of the ARP position, in 0.1 millimeters
+    CFG_TMODE_ECEF_Y_HP = (0x2003_0007, "CFG-TMODE-ECEF_Y_HP", I1);
+    /// High-precision ECEF Z coordinate of the ARP position, in 0.1 millimeters
+    CFG_TMODE_ECEF_Z_HP = (0x2003_0008, "CFG-TMODE-ECEF_Z_HP", I1);
+    /// Latitude of the ARP position, in 1e-7 degrees
+    CFG_TMODE_LAT = (0x4003_0009, "CFG-TMODE-LAT", I4);
+    /// Longitude of the ARP position, in 1e-7 degrees
+    CFG_TMODE_LON = (0x4003_000A, "CFG-TMODE-LON", I4);
+    /// Height of the ARP position, in centimeters
+    CFG_TMODE_HEIGHT = (0x4003_000B, "CFG-TMODE-HEIGHT", I4);
+    /// High-precision latitude of the ARP position, in 1e-9 degrees
+    CFG_TMODE_LAT_HP = (0x2003_000C, "CFG-TMODE-LAT_HP", I1);
+    /// High-precision longitude of the ARP position, in 1e-9 degrees
+    CFG_TMODE_LON_HP = (0x2003_000D, "CFG-TMODE-LON_HP", I1);
+    /// High-precision height of the ARP position, in 0.1 millimeters
+    CFG_TMODE_HEIGHT_HP = (0x2003_000E, "CFG-TMODE-HEIGHT_HP", I1);
+    /// Fixed position 3D accuracy, in 0.1 millimeters
+    CFG_TMODE_FIXED_POS_ACC = (0x4003_000F, "CFG-TMODE-FIXED_POS_ACC", U4);
+    /// Survey-in minimum duration, in seconds
+    CFG_TMODE_SVIN_MIN_DUR = (0x4003_0010, "CFG-TMODE-SVIN_MIN_DUR", U4);
+    /// Survey-in position accuracy limit, in 0.1 millimeters
+    CFG_TMODE_SVIN_ACC_LIMIT = (0x4003_0011, "CFG-TMODE-SVIN_ACC_LIMIT", U4);
+
+    /// Antenna cable delay, in nanoseconds
+    CFG_TP_ANT_CABLEDELAY = (0x3005_0001, "CFG-TP-ANT_CABLEDELAY", I2);
+    /// Time pulse period (TP1), in microseconds
+    CFG_TP_PERIOD_TP1 = (0x4005_0002, "CFG-TP-PERIOD_TP1", U4);
+    /// Time pulse period when locked to GNSS time (TP1), in microseconds
+    CFG_TP_PERIOD_LOCK_TP1 = (0x4005_0003, "CFG-TP-PERIOD_LOCK_TP1", U4);
+    /// Time pulse length (TP1), in microseconds
+    CFG_TP_LEN_TP1 = (0x4005_0004, "CFG-TP-LEN_TP1", U4);
+    /// Time pulse length when locked to GNSS time (TP1), in microseconds
+    CFG_TP_LEN_LOCK_TP1 = (0x4005_0005, "CFG-TP-LEN_LOCK_TP1", U4);
+    /// Enable the first timepulse
+    CFG_TP_TP1_ENA = (0x1005_0007, "CFG-TP-TP1_ENA", L);
+
+    /// Enable the GPS system
+    CFG_SIGNAL_GPS_ENA = (0x1031_001F, "CFG-SIGNAL-GPS_ENA", L);
+    /// Enable the GPS L1C/A signal
+    CFG_SIGNAL_GPS_L1CA_ENA = (0x1031_0001, "CFG-SIGNAL-GPS_L1CA_ENA", L);
+    /// Enable the GPS L2C signal
+    CFG_SIGNAL_GPS_L2C_ENA = (0x1031_0003, "CFG-SIGNAL-GPS_L2C_ENA", L);
+    /// Enable the SBAS system
+    CFG_SIGNAL_SBAS_ENA = (0x1031_0020, "CFG-SIGNAL-SBAS_ENA", L);
+    /// Enable the Galileo system
+    CFG_SIGNAL_GAL_ENA = (0x1031_0021, "CFG-SIGNAL-GAL_ENA", L);
+    /// Enable the Galileo E1 signal
+    CFG_SIGNAL_GAL_E1_ENA = (0x1031_0007, "CFG-SIGNAL-GAL_E1_ENA", L);
+    /// Enable the Galileo E5b signal
+    CFG_SIGNAL_GAL_E5B_ENA = (0x1031_000A, "CFG-SIGNAL-GAL_E5B_ENA", L);
+    /// Enable the BeiDou system
+    CFG_SIGNAL_BDS_ENA = (0x1031_0022, "CFG-SIGNAL-BDS_ENA", L);
+    /// Enable the BeiDou B1I signal
+    CFG_SIGNAL_BDS_B1_ENA = (0x1031_000D, "CFG-SIGNAL-BDS_B1_ENA", L);
+    /// Enable the BeiDou B2I signal
+    CFG_SIGNAL_BDS_B2_ENA = (0x1031_000E, "CFG-SIGNAL-BDS_B2_ENA", L);
+    /// Enable the QZSS system
+    CFG_SIGNAL_QZSS_ENA = (0x1031_0024, "CFG-SIGNAL-QZSS_ENA", L);
+    /// Enable the GLONASS system
+    CFG_SIGNAL_GLO_ENA = (0x1031_0025, "CFG-SIGNAL-GLO_ENA", L);
+    /// Enable the GLONASS L1 signal
+    CFG_SIGNAL_GLO_L1_ENA = (0x1031_0018, "CFG-SIGNAL-GLO_L1_ENA", L);
+    /// Enable the GLONASS L2 signal
+    CFG_SIGNAL_GLO_L2_ENA = (0x1031_001A, "CFG-SIGNAL-GLO_L2_ENA", L);
+
+    /// Use SBAS data when it is in test mode
+    CFG_SBAS_USE_TESTMODE = (0x1036_0002, "CFG-SBAS-USE_TESTMODE", L);
+    /// Use SBAS GEOs as a ranging source
+    CFG_SBAS_USE_RANGING = (0x1036_0003, "CFG-SBAS-USE_RANGING", L);
+    /// Use SBAS differential corrections
+    CFG_SBAS_USE_DIFFCORR = (0x1036_0004, "CFG-SBAS-USE_DIFFCORR", L);
+    /// Use SBAS integrity information
+    CFG_SBAS_USE_INTEGRITY = (0x1036_0005, "CFG-SBAS-USE_INTEGRITY", L);
+    /// SBAS PRN numbers to search for
+    CFG_SBAS_PRNSCANMASK = (0x5036_0006, "CFG-SBAS-PRNSCANMASK", X8);
+
+    /// NMEA protocol version
+    CFG_NMEA_PROTVER = (0x2093_0001, "CFG-NMEA-PROTVER", E1);
+    /// Maximum number of SVs to report per talker ID
+    CFG_NMEA_MAXSVS = (0x2093_0002, "CFG-NMEA-MAXSVS", E1);
+    /// Enable compatibility mode
+    CFG_NMEA_COMPAT = (0x1093_0003, "CFG-NMEA-COMPAT", L);
+    /// Enable considering mode
+    CFG_NMEA_CONSIDER = (0x1093_0004, "CFG-NMEA-CONSIDER", L);
+    /// Enable strict limit to 82 characters maximum NMEA message length
+    CFG_NMEA_LIMIT82 = (0x1093_0005, "CFG-NMEA-LIMIT82", L);
+    /// Enable high precision mode
+    CFG_NMEA_HIGHPREC = (0x1093_0006, "CFG-NMEA-HIGHPREC", L);
+    /// Main talker ID
+    CFG_NMEA_MAINTALKERID = (0x2093_0031, "CFG-NMEA-MAINTALKERID", E1);
+    /// Talker ID for GSV NMEA messages
+    CFG_NMEA_GSVTALKERID = (0x2093_0032, "CFG-NMEA-GSVTALKERID", E1);
+
+    /// Enable the odometer
+    CFG_ODO_USE_ODO = (0x1022_0001, "CFG-ODO-USE_ODO", L);
+    /// Enable interference detection
+    CFG_ITFM_ENABLE = (0x1041_000D, "CFG-ITFM-ENABLE", L);
+    /// Power management mode, 0 is full power, 1 is balanced, 2 is interval, 3 is aggressive
+    CFG_PM_OPERATEMODE = (0x20D0_0001, "CFG-PM-OPERATEMODE", E1);
+    /// Active antenna voltage control
+    CFG_HW_ANT_CFG_VOLTCTRL = (0x10A3_002E, "CFG-HW-ANT_CFG_VOLTCTRL", L);
+
+    /// The baud rate that should be configured on UART1
+    CFG_UART1_BAUDRATE = (0x4052_0001, "CFG-UART1-BAUDRATE", U4);
+    /// Number of stop bits on UART1
+    CFG_UART1_STOPBITS = (0x2052_0002, "CFG-UART1-STOPBITS", E1);
+    /// Number of data bits on UART1
+    CFG_UART1_DATABITS = (0x2052_0003, "CFG-UART1-DATABITS", E1);
+    /// Parity mode on UART1
+    CFG_UART1_PARITY = (0x2052_0004, "CFG-UART1-PARITY", E1);
+    /// Enable UART1
+    CFG_UART1_ENABLED = (0x1052_0005, "CFG-UART1-ENABLED", L);
+    /// The baud rate that should be configured on UART2
+    CFG_UART2_BAUDRATE = (0x4053_0001, "CFG-UART2-BAUDRATE", U4);
+    /// Enable UART2
+    CFG_UART2_ENABLED = (0x1053_0005, "CFG-UART2-ENABLED", L);
+
+    /// Accept UBX as an input protocol on UART1
+    CFG_UART1INPROT_UBX = (0x1073_0001, "CFG-UART1INPROT-UBX", L);
+    /// Accept NMEA as an input protocol on UART1
+    CFG_UART1INPROT_NMEA = (0x1073_0002, "CFG-UART1INPROT-NMEA", L);
+    /// Accept RTCM3 as an input protocol on UART1
+    CFG_UART1INPROT_RTCM3X = (0x1073_0004, "CFG-UART1INPROT-RTCM3X", L);
+    /// Output UBX on UART1
+    CFG_UART1OUTPROT_UBX = (0x1074_0001, "CFG-UART1OUTPROT-UBX", L);
+    /// Output NMEA on UART1
+    CFG_UART1OUTPROT_NMEA = (0x1074_0002, "CFG-UART1OUTPROT-NMEA", L);
+    /// Output RTCM3 on UART1
+    CFG_UART1OUTPROT_RTCM3X = (0x1074_0004, "CFG-UART1OUTPROT-RTCM3X", L);
+    /// Accept UBX as an input protocol on USB
+    CFG_USBINPROT_UBX = (0x1077_0001, "CFG-USBINPROT-UBX", L);
+    /// Accept NMEA as an input protocol on USB
+    CFG_USBINPROT_NMEA = (0x1077_0002, "CFG-USBINPROT-NMEA", L);
+    /// Accept RTCM3 as an input protocol on USB
+    CFG_USBINPROT_RTCM3X = (0x1077_0004, "CFG-USBINPROT-RTCM3X", L);
+    /// Output UBX on USB
+    CFG_USBOUTPROT_UBX = (0x1078_0001, "CFG-USBOUTPROT-UBX", L);
+    /// Output NMEA on USB
+    CFG_USBOUTPROT_NMEA = (0x1078_0002, "CFG-USBOUTPROT-NMEA", L);
+    /// Output RTCM3 on USB
+    CFG_USBOUTPROT_RTCM3X = (0x1078_0004, "CFG-USBOUTPROT-RTCM3X", L);
+}
+
+///
+/// The physical interfaces of the receiver, in the order the per-interface keys are
+/// numbered.
+#[repr(u8)]
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, Hash, EnumName, EnumIterItem, EnumTryFromRepr, EnumTryFromStr,
+)]
+pub enum UBXInterface {
+    I2C = 0,
+    UART1 = 1,
+    UART2 = 2,
+    USB = 3,
+    SPI = 4,
+}
+
+///
+/// Output rate keys for the CFG-MSGOUT group.  Each message has one key per
+/// [`UBXInterface`], and the discriminant is the item ID of the I2C key.
+#[allow(non_camel_case_types)]
+#[repr(u16)]
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, Hash, EnumName, EnumIterItem, EnumTryFromRepr, EnumTryFromStr,
+)]
+pub enum ConfigMsgOut {
+    UBX_NAV_PVT = 0x0006,
+    UBX_NAV_SAT = 0x0015,
+    UBX_NAV_STATUS = 0x001A,
+    UBX_NAV_POSLLH = 0x0029,
+    UBX_NAV_HPPOSLLH = 0x0033,
+    UBX_NAV_DOP = 0x0038,
+    UBX_NAV_VELNED = 0x0042,
+    UBX_NAV_TIMEUTC = 0x005B,
+    UBX_NAV_CLOCK = 0x0065,
+    UBX_NAV_RELPOSNED = 0x008D,
+    UBX_TIM_TP = 0x017D,
+    UBX_RXM_SFRBX = 0x0231,
+    UBX_RXM_RAWX = 0x02A4,
+    UBX_MON_COMMS = 0x034F,
+    UBX_MON_RF = 0x0359,
+    NMEA_ID_RMC = 0x00AB,
+    NMEA_ID_VTG = 0x00B0,
+    NMEA_ID_GGA = 0x00BA,
+    NMEA_ID_GSA = 0x00BF,
+    NMEA_ID_GSV = 0x00C4,
+    NMEA_ID_GLL = 0x00C9,
+    NMEA_ID_GST = 0x00D3,
+    NMEA_ID_ZDA = 0x00D8,
+    RTCM_3X_TYPE1005 = 0x02BD,
+    RTCM_3X_TYPE1077 = 0x02CC,
+    RTCM_3X_TYPE1087 = 0x02D1,
+    RTCM_3X_TYPE1127 = 0x02D6,
+    RTCM_3X_TYPE1230 = 0x0303,
+    RTCM_3X_TYPE1097 = 0x0318,
+    RTCM_3X_TYPE1074 = 0x035E,
+    RTCM_3X_TYPE1084 = 0x0363,
+    RTCM_3X_TYPE1094 = 0x0368,
+    RTCM_3X_TYPE1124 = 0x036D,
+}
+
+impl ConfigMsgOut {
+    /// Group ID of CFG-MSGOUT
+    pub const GROUP: u8 = 0x91;
+
+    ///
+    /// The output rate key of this message on the interface.  Values are U1, the number
+    /// of navigation solutions between each output, with 0 disabling the message.
+    pub fn key(&self, interface: UBXInterface) -> ConfigKey {
+        ConfigKey {
+            size: ConfigValueType::U1.size(),
+            group: Self::GROUP,
+            id: *self as u16 + interface as u16,
+        }
+    }
+
+    ///
+    /// Sets the output rate of this message on the interface.
+    pub fn with_rate(&self, interface: UBXInterface, rate: u8) -> ConfigData {
+        ConfigData {
+            key: self.key(interface),
+            raw_value: ConfigRawValue::OneByte(rate),
+        }
+    }
+
+    ///
+    /// Finds the message and interface of the CFG-MSGOUT item ID
+    pub fn from_item(item: u16) -> Option<(ConfigMsgOut, UBXInterface)> {
+        ConfigMsgOut::iter_items().into_iter().find_map(|msg| {
+            let offset = u8::try_from(item.checked_sub(msg as u16)?).ok()?;
+            Some((msg, UBXInterface::try_from(offset).ok()?))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::cfg::keys::*;
+    use crate::cfg::ConfigKey;
+
+    #[test]
+    pub fn test_catalog() {
+        for (idx, def) in ALL_KEYS.iter().enumerate() {
+            assert_eq!(def.value_type.size(), def.key().size, "{}", def.name);
+            assert_eq!(def.key, def.key().as_raw(), "{}", def.name);
+            let dupe = ALL_KEYS.iter().skip(idx + 1).find(|k| k.key == def.key);
+            assert_eq!(None, dupe, "{}", def.name);
+            assert_eq!(Some(def), ConfigKeyDef::find_by_name(def.name));
+        }
+        let key = ConfigMsgOut::NMEA_ID_GGA.key(UBXInterface::UART1);
+        assert_eq!(0x2091_00BB, key.as_raw());
+        assert_eq!("CFG-MSGOUT-NMEA_ID_GGA_UART1", key.to_string());
+        assert_eq!("CFG-RATE-MEAS", ConfigKey::from(0x3021_0001).to_string());
+    }
+
+    #[test]
+    pub fn test_values() {
+        let data = CFG_NAVSPG_INFIL_MINELEV
+            .with_value(ConfigValue::I1(-5))
+            .unwrap();
+        assert_eq!(Some(ConfigValue::I1(-5)), data.value());
+        assert!(CFG_NAVSPG_INFIL_MINELEV
+            .with_value(ConfigValue::U1(5))
+            .is_err());
+        let data = CFG_UART1_BAUDRATE
+            .with_value(ConfigValue::U4(115_200))
+            .unwrap();
+        assert_eq!(Some(ConfigValue::U4(115_200)), data.value());
+    }
+}
@@ -0,0 +1,291 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! Synchronous request/response handling with a receiver over any byte transport.
+
+use crate::cfg::{UBXCfgPayload, UBXValDel, UBXValGet, UBXValGetRequest, UBXValSet};
+use crate::mon::{UBXMonPayload, UBXMonVer};
+use crate::{UBXClass, UBXMessage, UBXParser, UBXPayload, UBXRawMessage};
+use irox_bits::{Bits, BitsError, BitsErrorKind, Error, MutBits};
+use irox_tools::packetio::{Packet, PacketBuilder};
+use log::warn;
+
+///
+/// A connection to a receiver.  Each request writes a message and then reads incoming
+/// messages until the response arrives, discarding any unrelated periodic output and any
+/// frames that fail to parse.
+pub struct UBXConnection<T: Bits + MutBits> {
+    transport: T,
+    parser: UBXParser,
+    max_messages: usize,
+}
+
+impl<T: Bits + MutBits> UBXConnection<T> {
+    /// Default number of messages to read while waiting for a response
+    pub const DEFAULT_MAX_MESSAGES: usize = 64;
+    /// Error message returned when the receiver rejects (NAKs) a request
+    pub const NAK_ERROR: &'static str = "message was rejected by the receiver (NAK)";
+
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport,
+            parser: UBXParser::default(),
+            max_messages: Self::DEFAULT_MAX_MESSAGES,
+        }
+    }
+
+    ///
+    /// Sets the number of incoming messages to read while waiting for a response
+    /// before giving up with [`BitsErrorKind::TimedOut`].
+    #[must_use]
+    pub fn with_max_messages(mut self, max_messages: usize) -> Self {
+        self.max_messages = max_messages;
+        self
+    }
+
+    pub fn into_inner(self) -> T {
+        self.transport
+    }
+
+    ///
+    /// Writes the message without waiting for a response.
+    pub fn send(&mut self, msg: &UBXRawMessage) -> Result<(), Error> {
+        msg.write_to(&mut self.transport)?;
+        self.transport.flush()
+    }
+
+    ///
+    /// Reads the next message from the receiver.
+    pub fn read_message(&mut self) -> Result<UBXMessage, Error> {
+        self.parser.build_from(&mut self.transport)
+    }
+
+    ///
+    /// Writes the message, and waits for the receiver to ACK it.  A NAK is returned as an
+    /// [`BitsErrorKind::Unsupported`] error with the message [`UBXConnection::NAK_ERROR`].
+    pub fn request(&mut self, msg: &UBXRawMessage) -> Result<(), Error> {
+        self.send(msg)?;
+        self.wait_for_ack(msg.class, msg.id)
+    }
+
+    ///
+    /// Writes a poll request for the message, and returns the first matching response.
+    pub fn poll(&mut self, class: UBXClass, id: u8) -> Result<UBXMessage, Error> {
+        self.send(&UBXRawMessage::poll(class, id))?;
+        self.wait_for(|msg| {
+            if msg.class == class as u8 && msg.id == id {
+                return Some(Ok(msg));
+            }
+            Self::check_nak(&msg, class as u8, id).map(Err)
+        })
+    }
+
+    ///
+    /// Sets the configuration values, waiting for the receiver to ACK them.
+    pub fn set_config(&mut self, valset: &UBXValSet) -> Result<(), Error> {
+        self.request(&valset.to_message()?)
+    }
+
+    ///
+    /// Deletes the configuration values, waiting for the receiver to ACK them.
+    pub fn delete_config(&mut self, valdel: &UBXValDel) -> Result<(), Error> {
+        self.request(&valdel.to_message()?)
+    }
+
+    ///
+    /// Requests the configuration values, returning the receiver's response.
+    pub fn get_config(&mut self, request: &UBXValGetRequest) -> Result<UBXValGet, Error> {
+        let msg = request.to_message()?;
+        self.send(&msg)?;
+        let resp = self.wait_for(|resp| {
+            if let UBXPayload::Cfg(UBXCfgPayload::ValGet(valget)) = resp.payload {
+                return Some(Ok(valget));
+            }
+            Self::check_nak(&resp, msg.class, msg.id).map(Err)
+        })?;
+        self.wait_for_ack(msg.class, msg.id)?;
+        Ok(resp)
+    }
+
+    ///
+    /// Polls the receiver's software and hardware versions
+    pub fn version(&mut self) -> Result<UBXMonVer, Error> {
+        self.send(&UBXMonVer::poll())?;
+        self.wait_for(|msg| match msg.payload {
+            UBXPayload::Mon(UBXMonPayload::Ver(ver)) => Some(Ok(ver)),
+            _ => None,
+        })
+    }
+
+    fn wait_for_ack(&mut self, class: u8, id: u8) -> Result<(), Error> {
+        self.wait_for(|msg| match &msg.payload {
+            UBXPayload::Ack(ack) => match ack.acknowledges(class, id)? {
+                true => Some(Ok(())),
+                false => Some(Err(Self::nak_error())),
+            },
+            _ => None,
+        })
+    }
+
+    fn check_nak(msg: &UBXMessage, class: u8, id: u8) -> Option<Error> {
+        let UBXPayload::Ack(ack) = &msg.payload else {
+            return None;
+        };
+        match ack.acknowledges(class, id)? {
+            true => None,
+            false => Some(Self::nak_error()),
+        }
+    }
+
+    fn nak_error() -> Error {
+        BitsError::new(BitsErrorKind::Unsupported, Self::NAK_ERROR)
+    }
+
+    fn wait_for<R, F: FnMut(UBXMessage) -> Option<Result<R, Error>>>(
+        &mut self,
+        mut matcher: F,
+    ) -> Result<R, Error> {
+        for _ in 0..self.max_messages {
+            let raw = self.parser.read_raw(&mut self.transport)?;
+            let (class, id) = (raw.class, raw.id);
+            let msg = match self.parser.parse_raw(raw) {
+                Ok(msg) => msg,
+                Err(e) => {
+                    warn!("Skipping unparseable message {class:02X}:{id:02X}: {e}");
+                    continue;
+                }
+            };
+            if let Some(res) = matcher(msg) {
+                return res;
+            }
+        }
+        Err(BitsError::new(
+            BitsErrorKind::TimedOut,
+            "no response received from the receiver",
+        ))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::panic)]
+mod test {
+    use crate::cfg::keys::{ConfigValue, CFG_RATE_MEAS, CFG_UART1_BAUDRATE};
+    use crate::cfg::{UBXCfg, UBXCfgLayer, UBXCfgLayers, UBXValGetRequest, UBXValSet};
+    use crate::connection::UBXConnection;
+    use crate::mon::UBXMon;
+    use crate::{UBXClass, UBXRawMessage};
+    use irox_bits::{Bits, BitsErrorKind, Error, MutBits};
+    use irox_tools::packetio::Packet;
+
+    /// Replays canned receiver output, and records everything written to it.
+    struct MockReceiver {
+        rx: Vec<u8>,
+        pos: usize,
+        tx: Vec<u8>,
+    }
+    impl MockReceiver {
+        fn new(responses: &[UBXRawMessage]) -> Self {
+            let mut rx = Vec::new();
+            for resp in responses {
+                resp.write_to(&mut rx).unwrap();
+            }
+            Self {
+                rx,
+                pos: 0,
+                tx: Vec::new(),
+            }
+        }
+    }
+    impl Bits for MockReceiver {
+        fn next_u8(&mut self) -> Result<Option<u8>, Error> {
+            let val = self.rx.get(self.pos).copied();
+            self.pos += 1;
+            Ok(val)
+        }
+    }
+    impl MutBits for MockReceiver {
+        fn write_u8(&mut self, val: u8) -> Result<(), Error> {
+            self.tx.push(val);
+            Ok(())
+        }
+    }
+
+    fn ack(ack: bool, class: UBXClass, id: u8) -> UBXRawMessage {
+        UBXRawMessage::new(UBXClass::ACK, ack as u8, Box::new([class as u8, id]))
+    }
+
+    #[test]
+    pub fn test_request_ack_nak() {
+        let valset = UBXValSet::new(UBXCfgLayers::RAM)
+            .with_value(&CFG_RATE_MEAS, ConfigValue::U2(200))
+            .unwrap();
+        let unrelated = UBXRawMessage::new(UBXClass::NAV, 0x60, Box::new([0; 4]));
+        let mut conn = UBXConnection::new(MockReceiver::new(&[
+            unrelated.clone(),
+            ack(true, UBXClass::CFG, UBXCfg::RST as u8),
+            ack(true, UBXClass::CFG, UBXCfg::VALSET as u8),
+            ack(false, UBXClass::CFG, UBXCfg::VALSET as u8),
+        ]));
+        conn.set_config(&valset).unwrap();
+        let err = conn.set_config(&valset).unwrap_err();
+        assert_eq!(BitsErrorKind::Unsupported, err.kind());
+        assert_eq!(UBXConnection::<MockReceiver>::NAK_ERROR, err.msg());
+
+        let mut conn = UBXConnection::new(MockReceiver::new(&[unrelated.clone(), unrelated]))
+            .with_max_messages(2);
+        let err = conn.set_config(&valset).unwrap_err();
+        assert_eq!(BitsErrorKind::TimedOut, err.kind());
+        let expected = valset.to_message().unwrap().get_bytes().unwrap();
+        assert_eq!(expected, conn.into_inner().tx);
+    }
+
+    #[test]
+    pub fn test_skips_bad_frames() {
+        let valset = UBXValSet::new(UBXCfgLayers::RAM)
+            .with_value(&CFG_RATE_MEAS, ConfigValue::U2(200))
+            .unwrap();
+        let mut corrupt = ack(false, UBXClass::CFG, UBXCfg::VALSET as u8);
+        corrupt.checksum ^= 0xFFFF;
+        let truncated = UBXRawMessage::new(UBXClass::ACK, 0x01, Box::new([UBXClass::CFG as u8]));
+        let mut conn = UBXConnection::new(MockReceiver::new(&[
+            corrupt,
+            truncated,
+            ack(true, UBXClass::CFG, UBXCfg::VALSET as u8),
+        ]));
+        conn.set_config(&valset).unwrap();
+
+        // running out of input is still an error.
+        let err = conn.set_config(&valset).unwrap_err();
+        assert_eq!(BitsErrorKind::UnexpectedEof, err.kind());
+    }
+
+    #[test]
+    pub fn test_get_config_version() {
+        let mut pld = vec![0x01, 0x00, 0x00, 0x00];
+        pld.extend_from_slice(&CFG_UART1_BAUDRATE.key.to_le_bytes());
+        pld.extend_from_slice(&38_400u32.to_le_bytes());
+        let mut ver = [0u8; 70];
+        ver[..8].copy_from_slice(b"EXT CORE");
+        ver[30..38].copy_from_slice(b"00190000");
+        ver[40..49].copy_from_slice(b"PROTVER=1");
+        let mut conn = UBXConnection::new(MockReceiver::new(&[
+            UBXRawMessage::new(UBXClass::CFG, UBXCfg::VALGET as u8, pld.into()),
+            ack(true, UBXClass::CFG, UBXCfg::VALGET as u8),
+            UBXRawMessage::new(UBXClass::MON, UBXMon::VER as u8, Box::new(ver)),
+        ]));
+        let valget = conn
+            .get_config(&UBXValGetRequest::new(UBXCfgLayer::RAM).with_key(CFG_UART1_BAUDRATE.key()))
+            .unwrap();
+        let [baud] = valget.cfg_data.as_slice() else {
+            panic!("Expected one value, was {valget:?}");
+        };
+        assert_eq!(Some(ConfigValue::U4(38_400)), baud.value());
+
+        let ver = conn.version().unwrap();
+        assert_eq!("EXT CORE", ver.sw_version);
+        assert_eq!("00190000", ver.hw_version);
+        assert_eq!(vec!["PROTVER=1".to_string()], ver.extensions);
+    }
+}
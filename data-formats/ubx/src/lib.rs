@@ -13,20 +13,23 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 extern crate core;
 
+pub mod ack;
 pub mod cfg;
+pub mod connection;
 pub mod mon;
 pub mod nav;
 mod tim;
 pub mod ubx_dumptxtfile;
 
+use crate::ack::{UBXAck, UBXAckPayload};
 use crate::cfg::{UBXCfg, UBXCfgPayload};
 use crate::mon::{UBXMon, UBXMonPayload};
 use crate::nav::{UBXNav, UBXNavPayload};
 use crate::tim::{UBXTim, UBXTimPayload};
 use core::fmt::{Debug, Display, Formatter};
-use irox_bits::{Bits, BitsErrorKind};
+use irox_bits::{Bits, BitsErrorKind, MutBits};
 use irox_enums::{EnumName, EnumTryFromRepr, EnumTryFromStr};
-use irox_tools::packetio::PacketBuilder;
+use irox_tools::packetio::{Packet, PacketBuilder};
 
 ///
 /// Class of ubx message - the third byte of the message
@@ -69,6 +72,7 @@ impl UBXClass {
             UBXClass::MON => Ok(UBXPayload::Mon(UBXMon::try_parse(id, pld)?)),
             UBXClass::CFG => Ok(UBXPayload::Cfg(UBXCfg::try_parse(id, pld)?)),
             UBXClass::TIM => Ok(UBXPayload::Tim(UBXTim::try_parse(id, pld)?)),
+            UBXClass::ACK => Ok(UBXPayload::Ack(UBXAck::try_parse(id, pld)?)),
             //TODO add the rest of the classes
             _ => Ok(UBXPayload::Unknown(pld.into())),
         }
@@ -78,6 +82,7 @@ impl UBXClass {
             UBXClass::NAV => UBXNav::try_from(msgname).ok().map(|v| v as u8),
             UBXClass::MON => UBXMon::try_from(msgname).ok().map(|v| v as u8),
             UBXClass::CFG => UBXCfg::try_from(msgname).ok().map(|v| v as u8),
+            UBXClass::ACK => UBXAck::try_from(msgname).ok().map(|v| v as u8),
             //TODO add the rest of the classes
             _ => None,
         }
@@ -90,6 +95,7 @@ pub enum UBXPayload {
     Mon(UBXMonPayload),
     Cfg(UBXCfgPayload),
     Tim(UBXTimPayload),
+    Ack(UBXAckPayload),
     // TODO add the rest of the classes
     Unknown(Box<[u8]>),
 }
@@ -118,6 +124,9 @@ impl Display for UBXMessage {
             UBXPayload::Tim(m) => {
                 write!(f, "UBXPayload::TIM {m:?}")
             }
+            UBXPayload::Ack(m) => {
+                write!(f, "UBXPayload::ACK {m:?}")
+            }
             UBXPayload::Unknown(p) => {
                 write!(
                     f,
@@ -151,6 +160,23 @@ impl Debug for UBXRawMessage {
     }
 }
 impl UBXRawMessage {
+    ///
+    /// Creates a new outgoing message, calculating the checksum of the payload.
+    pub fn new(class: UBXClass, id: u8, payload: Box<[u8]>) -> Self {
+        let checksum = calculate_checksum(class as u8, id, payload.len() as u16, &payload);
+        Self {
+            class: class as u8,
+            id,
+            payload,
+            checksum,
+            payload_hex: None,
+        }
+    }
+    ///
+    /// Creates a poll request for the specified message - a message with an empty payload.
+    pub fn poll(class: UBXClass, id: u8) -> Self {
+        Self::new(class, id, Box::default())
+    }
     pub const fn get_checksum(&self) -> u16 {
         self.checksum
     }
@@ -163,6 +189,26 @@ impl UBXRawMessage {
         )
     }
 }
+impl Packet for UBXRawMessage {
+    type PacketType = (u8, u8);
+
+    fn get_bytes(&self) -> Result<Vec<u8>, irox_bits::Error> {
+        let mut out = Vec::with_capacity(self.payload.len() + 8);
+        self.write_to(&mut out)?;
+        Ok(out)
+    }
+
+    fn write_to<T: MutBits>(&self, out: &mut T) -> Result<(), irox_bits::Error> {
+        out.write_all_bytes(&[0xb5, 0x62, self.class, self.id])?;
+        out.write_le_u16(self.payload.len() as u16)?;
+        out.write_all_bytes(&self.payload)?;
+        out.write_le_u16(self.checksum)
+    }
+
+    fn get_type(&self) -> Self::PacketType {
+        (self.class, self.id)
+    }
+}
 
 ///
 /// A parser for ubx messages.
@@ -170,10 +216,11 @@ impl UBXRawMessage {
 pub struct UBXParser {
     pub ignore_checksum_mismatch: bool,
 }
-impl PacketBuilder<UBXMessage> for UBXParser {
-    type Error = irox_bits::Error;
-
-    fn build_from<T: Bits>(&self, source: &mut T) -> Result<UBXMessage, Self::Error> {
+impl UBXParser {
+    ///
+    /// Reads the next frame from the source without validating or parsing it.  Any error
+    /// returned is from the source itself.
+    pub fn read_raw<T: Bits>(&self, source: &mut T) -> Result<UBXRawMessage, irox_bits::Error> {
         loop {
             let val = source.read_u8()?;
             if val == 0xb5 && source.read_u8()? == 0x62 {
@@ -186,7 +233,26 @@ impl PacketBuilder<UBXMessage> for UBXParser {
         let payload_len = source.read_le_u16()?;
         source.read_exact_into(payload_len as usize, &mut payload)?;
         let checksum = source.read_le_u16()?;
-        let calc_checksum = calculate_checksum(class, id, payload_len, &payload);
+        Ok(UBXRawMessage {
+            class,
+            id,
+            payload: payload.into_boxed_slice(),
+            checksum,
+            payload_hex: None,
+        })
+    }
+
+    ///
+    /// Validates the checksum of the frame and parses the payload.
+    pub fn parse_raw(&self, raw: UBXRawMessage) -> Result<UBXMessage, irox_bits::Error> {
+        let UBXRawMessage {
+            class,
+            id,
+            payload,
+            checksum,
+            ..
+        } = raw;
+        let calc_checksum = calculate_checksum(class, id, payload.len() as u16, &payload);
         if !self.ignore_checksum_mismatch && calc_checksum != checksum {
             return Err(BitsErrorKind::InvalidInput.into());
         }
@@ -194,7 +260,7 @@ impl PacketBuilder<UBXMessage> for UBXParser {
             return Ok(UBXMessage {
                 class,
                 id,
-                payload: UBXPayload::Unknown(payload.into_boxed_slice()),
+                payload: UBXPayload::Unknown(payload),
                 checksum,
             });
         };
@@ -209,6 +275,14 @@ impl PacketBuilder<UBXMessage> for UBXParser {
         })
     }
 }
+impl PacketBuilder<UBXMessage> for UBXParser {
+    type Error = irox_bits::Error;
+
+    fn build_from<T: Bits>(&self, source: &mut T) -> Result<UBXMessage, Self::Error> {
+        let raw = self.read_raw(source)?;
+        self.parse_raw(raw)
+    }
+}
 
 ///
 /// Calculates the expected checksum from the
@@ -96,6 +96,13 @@ pub struct UBXMonVer {
     pub hw_version: String,
     pub extensions: Vec<String>,
 }
+impl UBXMonVer {
+    ///
+    /// A poll request for the receiver and software versions
+    pub fn poll() -> UBXRawMessage {
+        UBXRawMessage::poll(UBXClass::MON, UBXMon::VER as u8)
+    }
+}
 impl TryFrom<&[u8]> for UBXMonVer {
     type Error = Error;
 
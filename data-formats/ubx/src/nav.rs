@@ -16,8 +16,17 @@
 use crate::{UBXClass, UBXRawMessage};
 use core::fmt::{Display, Formatter};
 use irox_bits::Error;
+use irox_carto::altitude::{Altitude, AltitudeReferenceFrame};
+use irox_carto::coordinate::{Latitude, Longitude};
 use irox_enums::{EnumName, EnumTryFromRepr, EnumTryFromStr};
 use irox_structs::Struct;
+use irox_time::datetime::UTCDateTime;
+use irox_time::gregorian::Date;
+use irox_time::Time;
+use irox_units::units::angle::Angle;
+use irox_units::units::duration::Duration;
+use irox_units::units::length::Length;
+use irox_units::units::speed::Speed;
 
 #[repr(u8)]
 #[derive(
@@ -73,6 +82,18 @@ impl UBXNav {
         let mut pld = pld;
         Ok(match self {
             UBXNav::CLOCK => UBXNavPayload::Clock(UBXNavClockRaw::parse_from(&mut pld)?),
+            UBXNav::DOP => UBXNavPayload::DOP(UBXNavDOPRaw::parse_from(&mut pld)?),
+            UBXNav::HPPOSLLH => UBXNavPayload::HPPosLLH(UBXNavHPPosLLHRaw::parse_from(&mut pld)?),
+            UBXNav::POSLLH => UBXNavPayload::PosLLH(UBXNavPosLLHRaw::parse_from(&mut pld)?),
+            UBXNav::PVT => UBXNavPayload::PVT(UBXNavPVTRaw::parse_from(&mut pld)?),
+            // only version 1 (the 64 byte variant) of RELPOSNED is supported
+            UBXNav::RELPOSNED if pld.first() == Some(&1) => {
+                UBXNavPayload::RelPosNED(UBXNavRelPosNEDRaw::parse_from(&mut pld)?)
+            }
+            UBXNav::SAT => UBXNavPayload::SAT(UBXNavSatRaw::parse_from(&mut pld)?),
+            UBXNav::STATUS => UBXNavPayload::Status(UBXNavStatusRaw::parse_from(&mut pld)?),
+            UBXNav::TIMEUTC => UBXNavPayload::TimeUTC(UBXNavTimeUTCRaw::parse_from(&mut pld)?),
+            UBXNav::VELNED => UBXNavPayload::VelNED(UBXNavVelNEDRaw::parse_from(&mut pld)?),
             _ => UBXNavPayload::Unknown {
                 id: *self as u8,
                 payload: pld.to_vec().into_boxed_slice(),
@@ -90,8 +111,18 @@ impl UBXNav {
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum UBXNavPayload {
     Clock(UBXNavClockRaw),
+    DOP(UBXNavDOPRaw),
+    HPPosLLH(UBXNavHPPosLLHRaw),
+    PosLLH(UBXNavPosLLHRaw),
+    PVT(UBXNavPVTRaw),
+    RelPosNED(UBXNavRelPosNEDRaw),
+    SAT(UBXNavSatRaw),
+    Status(UBXNavStatusRaw),
+    TimeUTC(UBXNavTimeUTCRaw),
+    VelNED(UBXNavVelNEDRaw),
     Unknown { id: u8, payload: Box<[u8]> },
 }
 impl Display for UBXNavPayload {
@@ -135,13 +166,655 @@ pub struct UBXNavClockRaw {
     pub freq_accuracy_ps_sec: u32,
 }
 
+///
+/// The type of the navigation solution, as reported in NAV-PVT and NAV-STATUS
+#[repr(u8)]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    Ord,
+    PartialOrd,
+    EnumName,
+    EnumTryFromRepr,
+    EnumTryFromStr,
+)]
+pub enum UBXFixType {
+    NoFix = 0,
+    DeadReckoning = 1,
+    Fix2D = 2,
+    Fix3D = 3,
+    GNSSDeadReckoning = 4,
+    TimeOnly = 5,
+}
+
+fn degrees_e7(val: i32) -> Angle {
+    Angle::new_degrees(f64::from(val) * 1e-7)
+}
+fn degrees_e5(val: i32) -> Angle {
+    Angle::new_degrees(f64::from(val) * 1e-5)
+}
+fn millimeters(val: i32) -> Length {
+    Length::new_meters(f64::from(val) * 1e-3)
+}
+fn millimeters_sec(val: i32) -> Speed {
+    Speed::new_meters_per_second(f64::from(val) * 1e-3)
+}
+fn centimeters_sec(val: i32) -> Speed {
+    Speed::new_meters_per_second(f64::from(val) * 1e-2)
+}
+
+///
+/// Assembles the UTC date and time, applying the signed nanoseconds fraction.
+fn utc_datetime(
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    min: u8,
+    sec: u8,
+    nano: i32,
+) -> Option<UTCDateTime> {
+    let date = Date::try_from_values(i32::from(year), month, day).ok()?;
+    let second_of_day = i64::from(hour) * 3600 + i64::from(min) * 60 + i64::from(sec);
+    let nanos = second_of_day * 1_000_000_000 + i64::from(nano);
+    if nanos < 0 {
+        return None;
+    }
+    let time = Time::new(
+        u32::try_from(nanos / 1_000_000_000).ok()?,
+        u32::try_from(nanos % 1_000_000_000).ok()?,
+    )
+    .ok()?;
+    Some(UTCDateTime::new(date, time))
+}
+
+///
+/// NAV-PVT: Navigation position velocity time solution.  The primary output of the receiver.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Struct)]
+#[little_endian]
+pub struct UBXNavPVTRaw {
+    /// Time of week in milliseconds of the navigation epoch.
+    pub itow_ms: u32,
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub min: u8,
+    pub sec: u8,
+    /// Validity flags: validDate, validTime, fullyResolved, validMag
+    pub valid: u8,
+    /// Time accuracy estimate in nanoseconds
+    pub time_accuracy_ns: u32,
+    /// Fraction of second in nanoseconds, range -1e9..1e9
+    pub nano: i32,
+    /// See [`UBXFixType`]
+    pub fix_type: u8,
+    /// Fix status flags: gnssFixOK, diffSoln, psmState, headVehValid, carrSoln
+    pub flags: u8,
+    /// Additional flags: confirmedAvai, confirmedDate, confirmedTime
+    pub flags2: u8,
+    pub num_sv: u8,
+    pub lon_e7_deg: i32,
+    pub lat_e7_deg: i32,
+    /// Height above the ellipsoid
+    pub height_mm: i32,
+    /// Height above mean sea level
+    pub height_msl_mm: i32,
+    pub horiz_accuracy_mm: u32,
+    pub vert_accuracy_mm: u32,
+    pub vel_north_mm_sec: i32,
+    pub vel_east_mm_sec: i32,
+    pub vel_down_mm_sec: i32,
+    /// Ground speed (2D)
+    pub ground_speed_mm_sec: i32,
+    /// Heading of motion (2D)
+    pub heading_motion_e5_deg: i32,
+    pub speed_accuracy_mm_sec: u32,
+    pub heading_accuracy_e5_deg: u32,
+    pub pdop_e2: u16,
+    /// Additional flags: invalidLlh, lastCorrectionAge
+    pub flags3: u16,
+    pub reserved0: [u8; 4],
+    /// Heading of the vehicle, only valid if headVehValid is set
+    pub heading_vehicle_e5_deg: i32,
+    pub mag_declination_e2_deg: i16,
+    pub mag_declination_accuracy_e2_deg: u16,
+}
+
+impl UBXNavPVTRaw {
+    pub fn fix(&self) -> Option<UBXFixType> {
+        UBXFixType::try_from(self.fix_type).ok()
+    }
+    /// True if this is a valid fix, within the DOP & accuracy masks
+    pub fn gnss_fix_ok(&self) -> bool {
+        self.flags & 0x01 != 0
+    }
+    /// True if the latitude, longitude & heights are valid
+    pub fn position_valid(&self) -> bool {
+        self.gnss_fix_ok() && self.flags3 & 0x01 == 0
+    }
+    /// The UTC date & time of the solution, if both the date and time are valid.
+    pub fn timestamp(&self) -> Option<UTCDateTime> {
+        if self.valid & 0x03 != 0x03 {
+            return None;
+        }
+        utc_datetime(
+            self.year, self.month, self.day, self.hour, self.min, self.sec, self.nano,
+        )
+    }
+    pub fn latitude(&self) -> Latitude {
+        Latitude(degrees_e7(self.lat_e7_deg))
+    }
+    pub fn longitude(&self) -> Longitude {
+        Longitude(degrees_e7(self.lon_e7_deg))
+    }
+    pub fn altitude_ellipsoid(&self) -> Altitude {
+        Altitude::new(
+            millimeters(self.height_mm),
+            AltitudeReferenceFrame::Ellipsoid,
+        )
+    }
+    pub fn altitude_msl(&self) -> Altitude {
+        Altitude::new(
+            millimeters(self.height_msl_mm),
+            AltitudeReferenceFrame::Geoid,
+        )
+    }
+    pub fn horizontal_accuracy(&self) -> Length {
+        Length::new_meters(f64::from(self.horiz_accuracy_mm) * 1e-3)
+    }
+    pub fn vertical_accuracy(&self) -> Length {
+        Length::new_meters(f64::from(self.vert_accuracy_mm) * 1e-3)
+    }
+    pub fn velocity_north(&self) -> Speed {
+        millimeters_sec(self.vel_north_mm_sec)
+    }
+    pub fn velocity_east(&self) -> Speed {
+        millimeters_sec(self.vel_east_mm_sec)
+    }
+    pub fn velocity_down(&self) -> Speed {
+        millimeters_sec(self.vel_down_mm_sec)
+    }
+    pub fn ground_speed(&self) -> Speed {
+        millimeters_sec(self.ground_speed_mm_sec)
+    }
+    pub fn heading_of_motion(&self) -> Angle {
+        degrees_e5(self.heading_motion_e5_deg)
+    }
+    pub fn speed_accuracy(&self) -> Speed {
+        Speed::new_meters_per_second(f64::from(self.speed_accuracy_mm_sec) * 1e-3)
+    }
+    pub fn heading_accuracy(&self) -> Angle {
+        Angle::new_degrees(f64::from(self.heading_accuracy_e5_deg) * 1e-5)
+    }
+    pub fn pdop(&self) -> f64 {
+        f64::from(self.pdop_e2) * 1e-2
+    }
+    /// Heading of the vehicle, if headVehValid is set.
+    pub fn heading_of_vehicle(&self) -> Option<Angle> {
+        if self.flags & 0x20 == 0 {
+            return None;
+        }
+        Some(degrees_e5(self.heading_vehicle_e5_deg))
+    }
+    /// Magnetic declination, if validMag is set.
+    pub fn magnetic_declination(&self) -> Option<Angle> {
+        if self.valid & 0x08 == 0 {
+            return None;
+        }
+        Some(Angle::new_degrees(
+            f64::from(self.mag_declination_e2_deg) * 1e-2,
+        ))
+    }
+}
+
+///
+/// NAV-STATUS: Receiver navigation status
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Struct)]
+#[little_endian]
+pub struct UBXNavStatusRaw {
+    /// Time of week in milliseconds of the navigation epoch.
+    pub itow_ms: u32,
+    /// See [`UBXFixType`]
+    pub gps_fix: u8,
+    /// Navigation status flags: gpsFixOk, diffSoln, wknSet, towSet
+    pub flags: u8,
+    /// Fix status: diffCorr, carrSolnValid, mapMatching
+    pub fix_stat: u8,
+    /// Further information: psmState, spoofDetState, carrSoln
+    pub flags2: u8,
+    /// Time to first fix
+    pub ttff_ms: u32,
+    /// Milliseconds since startup or reset
+    pub msss_ms: u32,
+}
+
+impl UBXNavStatusRaw {
+    pub fn fix(&self) -> Option<UBXFixType> {
+        UBXFixType::try_from(self.gps_fix).ok()
+    }
+    pub fn gps_fix_ok(&self) -> bool {
+        self.flags & 0x01 != 0
+    }
+    /// True if differential corrections were applied
+    pub fn diff_soln(&self) -> bool {
+        self.flags & 0x02 != 0
+    }
+    pub fn week_number_valid(&self) -> bool {
+        self.flags & 0x04 != 0
+    }
+    pub fn time_of_week_valid(&self) -> bool {
+        self.flags & 0x08 != 0
+    }
+    pub fn time_to_first_fix(&self) -> Duration {
+        Duration::from_millis(u64::from(self.ttff_ms))
+    }
+    pub fn time_since_startup(&self) -> Duration {
+        Duration::from_millis(u64::from(self.msss_ms))
+    }
+}
+
+///
+/// NAV-DOP: Dilution of precision.  All values are scaled by 100.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Struct)]
+#[little_endian]
+pub struct UBXNavDOPRaw {
+    /// Time of week in milliseconds of the navigation epoch.
+    pub itow_ms: u32,
+    pub gdop_e2: u16,
+    pub pdop_e2: u16,
+    pub tdop_e2: u16,
+    pub vdop_e2: u16,
+    pub hdop_e2: u16,
+    pub ndop_e2: u16,
+    pub edop_e2: u16,
+}
+
+impl UBXNavDOPRaw {
+    /// Geometric DOP
+    pub fn gdop(&self) -> f64 {
+        f64::from(self.gdop_e2) * 1e-2
+    }
+    /// Position DOP
+    pub fn pdop(&self) -> f64 {
+        f64::from(self.pdop_e2) * 1e-2
+    }
+    /// Time DOP
+    pub fn tdop(&self) -> f64 {
+        f64::from(self.tdop_e2) * 1e-2
+    }
+    /// Vertical DOP
+    pub fn vdop(&self) -> f64 {
+        f64::from(self.vdop_e2) * 1e-2
+    }
+    /// Horizontal DOP
+    pub fn hdop(&self) -> f64 {
+        f64::from(self.hdop_e2) * 1e-2
+    }
+    /// Northing DOP
+    pub fn ndop(&self) -> f64 {
+        f64::from(self.ndop_e2) * 1e-2
+    }
+    /// Easting DOP
+    pub fn edop(&self) -> f64 {
+        f64::from(self.edop_e2) * 1e-2
+    }
+}
+
+///
+/// NAV-POSLLH: Geodetic position solution
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Struct)]
+#[little_endian]
+pub struct UBXNavPosLLHRaw {
+    /// Time of week in milliseconds of the navigation epoch.
+    pub itow_ms: u32,
+    pub lon_e7_deg: i32,
+    pub lat_e7_deg: i32,
+    /// Height above the ellipsoid
+    pub height_mm: i32,
+    /// Height above mean sea level
+    pub height_msl_mm: i32,
+    pub horiz_accuracy_mm: u32,
+    pub vert_accuracy_mm: u32,
+}
+
+impl UBXNavPosLLHRaw {
+    pub fn latitude(&self) -> Latitude {
+        Latitude(degrees_e7(self.lat_e7_deg))
+    }
+    pub fn longitude(&self) -> Longitude {
+        Longitude(degrees_e7(self.lon_e7_deg))
+    }
+    pub fn altitude_ellipsoid(&self) -> Altitude {
+        Altitude::new(
+            millimeters(self.height_mm),
+            AltitudeReferenceFrame::Ellipsoid,
+        )
+    }
+    pub fn altitude_msl(&self) -> Altitude {
+        Altitude::new(
+            millimeters(self.height_msl_mm),
+            AltitudeReferenceFrame::Geoid,
+        )
+    }
+    pub fn horizontal_accuracy(&self) -> Length {
+        Length::new_meters(f64::from(self.horiz_accuracy_mm) * 1e-3)
+    }
+    pub fn vertical_accuracy(&self) -> Length {
+        Length::new_meters(f64::from(self.vert_accuracy_mm) * 1e-3)
+    }
+}
+
+///
+/// NAV-HPPOSLLH: High precision geodetic position solution.  The high-precision components
+/// extend the standard precision values.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Struct)]
+#[little_endian]
+pub struct UBXNavHPPosLLHRaw {
+    pub version: u8,
+    pub reserved0: [u8; 2],
+    /// Flags: invalidLlh
+    pub flags: u8,
+    /// Time of week in milliseconds of the navigation epoch.
+    pub itow_ms: u32,
+    pub lon_e7_deg: i32,
+    pub lat_e7_deg: i32,
+    /// Height above the ellipsoid
+    pub height_mm: i32,
+    /// Height above mean sea level
+    pub height_msl_mm: i32,
+    pub lon_hp_e9_deg: i8,
+    pub lat_hp_e9_deg: i8,
+    pub height_hp_e1_mm: i8,
+    pub height_msl_hp_e1_mm: i8,
+    pub horiz_accuracy_e1_mm: u32,
+    pub vert_accuracy_e1_mm: u32,
+}
+
+impl UBXNavHPPosLLHRaw {
+    /// True if the latitude, longitude & heights are valid
+    pub fn position_valid(&self) -> bool {
+        self.flags & 0x01 == 0
+    }
+    pub fn latitude(&self) -> Latitude {
+        Latitude(Angle::new_degrees(
+            f64::from(self.lat_e7_deg) * 1e-7 + f64::from(self.lat_hp_e9_deg) * 1e-9,
+        ))
+    }
+    pub fn longitude(&self) -> Longitude {
+        Longitude(Angle::new_degrees(
+            f64::from(self.lon_e7_deg) * 1e-7 + f64::from(self.lon_hp_e9_deg) * 1e-9,
+        ))
+    }
+    pub fn altitude_ellipsoid(&self) -> Altitude {
+        let mm = f64::from(self.height_mm) + f64::from(self.height_hp_e1_mm) * 1e-1;
+        Altitude::new(
+            Length::new_meters(mm * 1e-3),
+            AltitudeReferenceFrame::Ellipsoid,
+        )
+    }
+    pub fn altitude_msl(&self) -> Altitude {
+        let mm = f64::from(self.height_msl_mm) + f64::from(self.height_msl_hp_e1_mm) * 1e-1;
+        Altitude::new(Length::new_meters(mm * 1e-3), AltitudeReferenceFrame::Geoid)
+    }
+    pub fn horizontal_accuracy(&self) -> Length {
+        Length::new_meters(f64::from(self.horiz_accuracy_e1_mm) * 1e-4)
+    }
+    pub fn vertical_accuracy(&self) -> Length {
+        Length::new_meters(f64::from(self.vert_accuracy_e1_mm) * 1e-4)
+    }
+}
+
+///
+/// NAV-VELNED: Velocity solution in the local north, east, down frame
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Struct)]
+#[little_endian]
+pub struct UBXNavVelNEDRaw {
+    /// Time of week in milliseconds of the navigation epoch.
+    pub itow_ms: u32,
+    pub vel_north_cm_sec: i32,
+    pub vel_east_cm_sec: i32,
+    pub vel_down_cm_sec: i32,
+    /// Speed (3D)
+    pub speed_cm_sec: u32,
+    /// Ground speed (2D)
+    pub ground_speed_cm_sec: u32,
+    /// Heading of motion (2D)
+    pub heading_e5_deg: i32,
+    pub speed_accuracy_cm_sec: u32,
+    pub course_accuracy_e5_deg: u32,
+}
+
+impl UBXNavVelNEDRaw {
+    pub fn velocity_north(&self) -> Speed {
+        centimeters_sec(self.vel_north_cm_sec)
+    }
+    pub fn velocity_east(&self) -> Speed {
+        centimeters_sec(self.vel_east_cm_sec)
+    }
+    pub fn velocity_down(&self) -> Speed {
+        centimeters_sec(self.vel_down_cm_sec)
+    }
+    pub fn speed(&self) -> Speed {
+        Speed::new_meters_per_second(f64::from(self.speed_cm_sec) * 1e-2)
+    }
+    pub fn ground_speed(&self) -> Speed {
+        Speed::new_meters_per_second(f64::from(self.ground_speed_cm_sec) * 1e-2)
+    }
+    pub fn heading(&self) -> Angle {
+        degrees_e5(self.heading_e5_deg)
+    }
+    pub fn speed_accuracy(&self) -> Speed {
+        Speed::new_meters_per_second(f64::from(self.speed_accuracy_cm_sec) * 1e-2)
+    }
+    pub fn course_accuracy(&self) -> Angle {
+        Angle::new_degrees(f64::from(self.course_accuracy_e5_deg) * 1e-5)
+    }
+}
+
+///
+/// NAV-TIMEUTC: UTC time solution
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Struct)]
+#[little_endian]
+pub struct UBXNavTimeUTCRaw {
+    /// Time of week in milliseconds of the navigation epoch.
+    pub itow_ms: u32,
+    /// Time accuracy estimate in nanoseconds
+    pub time_accuracy_ns: u32,
+    /// Fraction of second in nanoseconds, range -1e9..1e9
+    pub nano: i32,
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub min: u8,
+    pub sec: u8,
+    /// Validity flags: validTOW, validWKN, validUTC, and the UTC standard in the upper 4 bits
+    pub valid: u8,
+}
+
+impl UBXNavTimeUTCRaw {
+    /// The UTC date & time, if validUTC is set
+    pub fn timestamp(&self) -> Option<UTCDateTime> {
+        if self.valid & 0x04 == 0 {
+            return None;
+        }
+        utc_datetime(
+            self.year, self.month, self.day, self.hour, self.min, self.sec, self.nano,
+        )
+    }
+    pub fn time_accuracy(&self) -> Duration {
+        Duration::new_seconds(f64::from(self.time_accuracy_ns) * 1e-9)
+    }
+}
+
+///
+/// NAV-RELPOSNED (version 1): Relative position of the rover to the reference station in the
+/// local north, east, down frame.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Struct)]
+#[little_endian]
+pub struct UBXNavRelPosNEDRaw {
+    pub version: u8,
+    pub reserved0: u8,
+    pub ref_station_id: u16,
+    /// Time of week in milliseconds of the navigation epoch.
+    pub itow_ms: u32,
+    pub rel_pos_north_cm: i32,
+    pub rel_pos_east_cm: i32,
+    pub rel_pos_down_cm: i32,
+    pub rel_pos_length_cm: i32,
+    pub rel_pos_heading_e5_deg: i32,
+    pub reserved1: [u8; 4],
+    pub rel_pos_hp_north_e1_mm: i8,
+    pub rel_pos_hp_east_e1_mm: i8,
+    pub rel_pos_hp_down_e1_mm: i8,
+    pub rel_pos_hp_length_e1_mm: i8,
+    pub accuracy_north_e1_mm: u32,
+    pub accuracy_east_e1_mm: u32,
+    pub accuracy_down_e1_mm: u32,
+    pub accuracy_length_e1_mm: u32,
+    pub accuracy_heading_e5_deg: u32,
+    pub reserved2: [u8; 4],
+    /// Flags: gnssFixOK, diffSoln, relPosValid, carrSoln, isMoving, refPosMiss, refObsMiss,
+    /// relPosHeadingValid, relPosNormalized
+    pub flags: u32,
+}
+
+fn relpos(cm: i32, hp_e1_mm: i8) -> Length {
+    Length::new_meters(f64::from(cm) * 1e-2 + f64::from(hp_e1_mm) * 1e-4)
+}
+
+impl UBXNavRelPosNEDRaw {
+    pub fn gnss_fix_ok(&self) -> bool {
+        self.flags & 0x01 != 0
+    }
+    pub fn rel_pos_valid(&self) -> bool {
+        self.flags & 0x04 != 0
+    }
+    /// Carrier phase range solution status: 0 none, 1 float, 2 fixed
+    pub fn carrier_solution(&self) -> u8 {
+        ((self.flags >> 3) & 0x03) as u8
+    }
+    pub fn rel_pos_heading_valid(&self) -> bool {
+        self.flags & 0x100 != 0
+    }
+    pub fn north(&self) -> Length {
+        relpos(self.rel_pos_north_cm, self.rel_pos_hp_north_e1_mm)
+    }
+    pub fn east(&self) -> Length {
+        relpos(self.rel_pos_east_cm, self.rel_pos_hp_east_e1_mm)
+    }
+    pub fn down(&self) -> Length {
+        relpos(self.rel_pos_down_cm, self.rel_pos_hp_down_e1_mm)
+    }
+    pub fn length(&self) -> Length {
+        relpos(self.rel_pos_length_cm, self.rel_pos_hp_length_e1_mm)
+    }
+    /// Heading of the relative position vector, if relPosHeadingValid is set
+    pub fn heading(&self) -> Option<Angle> {
+        if !self.rel_pos_heading_valid() {
+            return None;
+        }
+        Some(degrees_e5(self.rel_pos_heading_e5_deg))
+    }
+    pub fn accuracy_north(&self) -> Length {
+        Length::new_meters(f64::from(self.accuracy_north_e1_mm) * 1e-4)
+    }
+    pub fn accuracy_east(&self) -> Length {
+        Length::new_meters(f64::from(self.accuracy_east_e1_mm) * 1e-4)
+    }
+    pub fn accuracy_down(&self) -> Length {
+        Length::new_meters(f64::from(self.accuracy_down_e1_mm) * 1e-4)
+    }
+    pub fn accuracy_length(&self) -> Length {
+        Length::new_meters(f64::from(self.accuracy_length_e1_mm) * 1e-4)
+    }
+    pub fn accuracy_heading(&self) -> Angle {
+        Angle::new_degrees(f64::from(self.accuracy_heading_e5_deg) * 1e-5)
+    }
+}
+
+///
+/// NAV-SAT: Satellite information, one [`UBXNavSatSv`] per tracked satellite
+#[derive(Debug, Clone, PartialEq, Eq, Struct)]
+#[little_endian]
+pub struct UBXNavSatRaw {
+    /// Time of week in milliseconds of the navigation epoch.
+    pub itow_ms: u32,
+    pub version: u8,
+    pub num_svs: u8,
+    pub reserved0: [u8; 2],
+    pub svs: Vec<UBXNavSatSv>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Struct)]
+#[little_endian]
+pub struct UBXNavSatSv {
+    /// GNSS identifier: 0 GPS, 1 SBAS, 2 Galileo, 3 BeiDou, 5 QZSS, 6 GLONASS
+    pub gnss_id: u8,
+    pub sv_id: u8,
+    /// Carrier to noise ratio (signal strength) in dBHz
+    pub cno_dbhz: u8,
+    /// Elevation, range -90..90, unknown if out of range
+    pub elev_deg: i8,
+    /// Azimuth, range 0..360, unknown if elevation is out of range
+    pub azim_deg: i16,
+    pub pseudorange_residual_dm: i16,
+    /// Flags: qualityInd, svUsed, health, diffCorr, smoothed, orbitSource, ephAvail, almAvail,
+    /// and the correction usage flags
+    pub flags: u32,
+}
+
+impl UBXNavSatSv {
+    /// The elevation of the satellite, if known
+    pub fn elevation(&self) -> Option<Angle> {
+        if !(-90..=90).contains(&self.elev_deg) {
+            return None;
+        }
+        Some(Angle::new_degrees(f64::from(self.elev_deg)))
+    }
+    /// The azimuth of the satellite, if known
+    pub fn azimuth(&self) -> Option<Angle> {
+        self.elevation()?;
+        Some(Angle::new_degrees(f64::from(self.azim_deg)))
+    }
+    pub fn pseudorange_residual(&self) -> Length {
+        Length::new_meters(f64::from(self.pseudorange_residual_dm) * 1e-1)
+    }
+    /// Signal quality indicator, 0 no signal through 7 code & carrier locked
+    pub fn quality(&self) -> u8 {
+        (self.flags & 0x07) as u8
+    }
+    /// True if the signal is used in the navigation solution
+    pub fn used(&self) -> bool {
+        self.flags & 0x08 != 0
+    }
+    /// 0 unknown, 1 healthy, 2 unhealthy
+    pub fn health(&self) -> u8 {
+        ((self.flags >> 4) & 0x03) as u8
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::panic)]
 mod tests {
-    use crate::nav::{UBXNavClockRaw, UBXNavPayload};
+    use crate::nav::{UBXFixType, UBXNav, UBXNavClockRaw, UBXNavPayload};
     use crate::{UBXMessage, UBXParser, UBXPayload};
+    use irox_structs::Struct;
+    use irox_time::datetime::UTCDateTime;
+    use irox_time::gregorian::Date;
+    use irox_time::Time;
     use irox_tools::hex;
     use irox_tools::packetio::PacketBuilder;
 
+    fn assert_near(exp: f64, val: f64) {
+        assert!((exp - val).abs() < 1e-9, "{exp} != {val}");
+    }
+
     #[test]
     pub fn test_parse_nav_clock() {
         let inp = &hex!("B56201221400F80B1401E4F80800000000001A0000001E0300006E11");
@@ -163,4 +836,138 @@ mod tests {
         };
         assert_eq!(parsed, exp);
     }
+
+    #[test]
+    pub fn test_parse_nav_pvt() {
+        let inp = &hex!("B56201075C00F80B1401E907030E0C22380714000000804D19F10301E00CF96B9BD5B17C7D196EB2000035340100DC050000C40900006400000038FFFFFF32000000E0000000CDCBC4012C01000087D6120091000000000000000000000000000000C928");
+        let parsed = UBXParser::default().build_from(&mut inp.as_ref()).unwrap();
+        let UBXPayload::Nav(UBXNavPayload::PVT(pvt)) = parsed.payload else {
+            panic!("Expected NAV-PVT, was {parsed:?}");
+        };
+        assert_eq!(Some(UBXFixType::Fix3D), pvt.fix());
+        assert!(pvt.gnss_fix_ok());
+        assert!(pvt.position_valid());
+        assert_eq!(12, pvt.num_sv);
+        let exp = UTCDateTime::new(
+            Date::try_from_values(2025, 3, 14).unwrap(),
+            Time::new(45295, 750_000_000).unwrap(),
+        );
+        assert_eq!(Some(exp), pvt.timestamp());
+        assert_near(42.765_432_1, pvt.latitude().0.as_degrees().value());
+        assert_near(-71.123_456_7, pvt.longitude().0.as_degrees().value());
+        assert_near(45.678, pvt.altitude_ellipsoid().value().as_meters().value());
+        assert_near(78.901, pvt.altitude_msl().value().as_meters().value());
+        assert_near(1.5, pvt.horizontal_accuracy().as_meters().value());
+        assert_near(-0.2, pvt.velocity_east().as_meters_per_second().value());
+        assert_near(0.224, pvt.ground_speed().as_meters_per_second().value());
+        assert_near(296.744_45, pvt.heading_of_motion().as_degrees().value());
+        assert_near(1.45, pvt.pdop());
+        assert_eq!(None, pvt.heading_of_vehicle());
+        assert_eq!(None, pvt.magnetic_declination());
+        assert_eq!(inp.get(6..98), Some(pvt.as_bytes().unwrap().as_slice()));
+    }
+
+    #[test]
+    pub fn test_parse_nav_sat() {
+        let pld = hex!("F80B14010102000000052A2D7B00F4FF1F000000060C009C0000000001000000");
+        let Ok(UBXNavPayload::SAT(sat)) = UBXNav::try_parse(UBXNav::SAT as u8, &pld) else {
+            panic!("Expected NAV-SAT");
+        };
+        assert_eq!(2, sat.num_svs);
+        let [gps, glo] = sat.svs.as_slice() else {
+            panic!("Expected two satellites, was {:?}", sat.svs);
+        };
+        assert_eq!((0, 5, 42), (gps.gnss_id, gps.sv_id, gps.cno_dbhz));
+        assert_near(45., gps.elevation().unwrap().as_degrees().value());
+        assert_near(123., gps.azimuth().unwrap().as_degrees().value());
+        assert_near(-1.2, gps.pseudorange_residual().as_meters().value());
+        assert_eq!(7, gps.quality());
+        assert!(gps.used());
+        assert_eq!(1, gps.health());
+        assert_eq!((6, 12), (glo.gnss_id, glo.sv_id));
+        assert_eq!(None, glo.elevation());
+        assert_eq!(None, glo.azimuth());
+        assert!(!glo.used());
+    }
+
+    #[test]
+    pub fn test_parse_nav_status_dop() {
+        let pld = hex!("F80B1401030D0000F47E000087D61200");
+        let Ok(UBXNavPayload::Status(status)) = UBXNav::try_parse(UBXNav::STATUS as u8, &pld)
+        else {
+            panic!("Expected NAV-STATUS");
+        };
+        assert_eq!(Some(UBXFixType::Fix3D), status.fix());
+        assert!(status.gps_fix_ok());
+        assert!(!status.diff_soln());
+        assert!(status.week_number_valid());
+        assert!(status.time_of_week_valid());
+        assert_near(32.5, status.time_to_first_fix().as_seconds_f64());
+
+        let pld = hex!("F80B1401FA00D2007800B4005F0046003C00");
+        let Ok(UBXNavPayload::DOP(dop)) = UBXNav::try_parse(UBXNav::DOP as u8, &pld) else {
+            panic!("Expected NAV-DOP");
+        };
+        assert_near(2.5, dop.gdop());
+        assert_near(2.1, dop.pdop());
+        assert_near(0.95, dop.hdop());
+        assert_near(0.6, dop.edop());
+    }
+
+    #[test]
+    pub fn test_parse_nav_hp_relpos() {
+        let pld = hex!("00000000F80B1401F96B9BD5B17C7D196EB2000035340100D611FD0596000000FA000000");
+        let Ok(UBXNavPayload::HPPosLLH(hp)) = UBXNav::try_parse(UBXNav::HPPOSLLH as u8, &pld)
+        else {
+            panic!("Expected NAV-HPPOSLLH");
+        };
+        assert!(hp.position_valid());
+        assert_near(42.765_432_117, hp.latitude().0.as_degrees().value());
+        assert_near(-71.123_456_742, hp.longitude().0.as_degrees().value());
+        assert_near(45.6777, hp.altitude_ellipsoid().value().as_meters().value());
+        assert_near(0.015, hp.horizontal_accuracy().as_meters().value());
+
+        let pld = hex!("01000000F80B14019600000006FFFFFF0A00000024010000CDCBC401000000000CDE05006400000078000000C80000009600000090D003000000000017010000");
+        let Ok(UBXNavPayload::RelPosNED(rel)) = UBXNav::try_parse(UBXNav::RELPOSNED as u8, &pld)
+        else {
+            panic!("Expected NAV-RELPOSNED");
+        };
+        assert!(rel.gnss_fix_ok());
+        assert!(rel.rel_pos_valid());
+        assert_eq!(2, rel.carrier_solution());
+        assert_near(1.5012, rel.north().as_meters().value());
+        assert_near(-2.5034, rel.east().as_meters().value());
+        assert_near(0.1005, rel.down().as_meters().value());
+        assert_near(296.744_45, rel.heading().unwrap().as_degrees().value());
+        assert_near(0.01, rel.accuracy_north().as_meters().value());
+
+        // version 0 isn't supported
+        let Ok(UBXNavPayload::Unknown { .. }) =
+            UBXNav::try_parse(UBXNav::RELPOSNED as u8, &[0; 40])
+        else {
+            panic!("Expected unknown");
+        };
+    }
+
+    #[test]
+    pub fn test_parse_nav_velned_timeutc() {
+        let pld = hex!("F80B14010A000000ECFFFFFF050000001700000016000000CDCBC4011E00000087D61200");
+        let Ok(UBXNavPayload::VelNED(vel)) = UBXNav::try_parse(UBXNav::VELNED as u8, &pld) else {
+            panic!("Expected NAV-VELNED");
+        };
+        assert_near(0.1, vel.velocity_north().as_meters_per_second().value());
+        assert_near(0.23, vel.speed().as_meters_per_second().value());
+        assert_near(0.22, vel.ground_speed().as_meters_per_second().value());
+        assert_near(296.744_45, vel.heading().as_degrees().value());
+
+        let pld = hex!("F80B1401140000000065CD1DE907030E173B3B37");
+        let Ok(UBXNavPayload::TimeUTC(utc)) = UBXNav::try_parse(UBXNav::TIMEUTC as u8, &pld) else {
+            panic!("Expected NAV-TIMEUTC");
+        };
+        let exp = UTCDateTime::new(
+            Date::try_from_values(2025, 3, 14).unwrap(),
+            Time::new(86399, 500_000_000).unwrap(),
+        );
+        assert_eq!(Some(exp), utc.timestamp());
+    }
 }
@@ -0,0 +1,284 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! Reading and writing of whole zip archives held in memory.
+
+extern crate alloc;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use irox_bits::{BitsError, BitsErrorKind, BitsWrapper, MutBits};
+use irox_compression::deflate::Inflater;
+use irox_tools::hash::crc32::CRC32;
+
+use crate::types::{
+    CentralDirectoryHeader, EndOfCentral64Directory, EndOfCentral64DirectoryLocator,
+    EndOfCentralDirectory, LocalFileHeader,
+};
+
+/// Entry data is stored as-is
+pub const METHOD_STORED: u16 = 0;
+/// Entry data is compressed with raw deflate
+pub const METHOD_DEFLATED: u16 = 8;
+
+///
+/// A file within a [`ZipArchive`]
+#[derive(Debug, Clone)]
+pub struct ZipEntry {
+    /// The file name, as stored in the archive
+    pub name: String,
+    pub compressed_size: u64,
+    pub uncompressed_size: u64,
+    pub local_header_offset: u64,
+    pub header: CentralDirectoryHeader,
+}
+
+///
+/// A zip archive, read from an in-memory buffer using the central directory.
+pub struct ZipArchive<'a> {
+    data: &'a [u8],
+    entries: Vec<ZipEntry>,
+}
+
+impl<'a> ZipArchive<'a> {
+    ///
+    /// Reads the central directory of the archive.  Zip64 archives are supported, multi-disk
+    /// archives are not.
+    pub fn new(data: &'a [u8]) -> Result<Self, BitsError> {
+        let eocd_offset = find_end_of_central_directory(data)?;
+        let mut eocd_data = data.get(eocd_offset..).unwrap_or_default();
+        let eocd = EndOfCentralDirectory::read_from(&mut eocd_data)?;
+
+        let mut num_entries = u64::from(eocd.total_num_entries);
+        let mut cd_offset = u64::from(eocd.central_directory_start_disk_offset);
+        if eocd.total_num_entries == u16::MAX
+            || eocd.central_directory_start_disk_offset == u32::MAX
+        {
+            let Some(mut locator) = eocd_offset
+                .checked_sub(EndOfCentral64DirectoryLocator::LENGTH)
+                .and_then(|o| data.get(o..))
+            else {
+                return BitsErrorKind::InvalidData.err("Missing zip64 end of directory locator");
+            };
+            let locator = EndOfCentral64DirectoryLocator::read_from(&mut locator)?;
+            let mut eocd64 = slice_from(data, locator.relative_offset_64central)?;
+            let eocd64 = EndOfCentral64Directory::read_from(&mut eocd64)?;
+            num_entries = eocd64.total_num_entries;
+            cd_offset = eocd64.central_directory_start_disk_offset;
+        }
+
+        let mut cd = slice_from(data, cd_offset)?;
+        let mut entries = Vec::new();
+        for _ in 0..num_entries {
+            let header = CentralDirectoryHeader::read_from(&mut cd)?;
+            let (uncompressed_size, compressed_size, local_header_offset) = header.zip64_sizes()?;
+            entries.push(ZipEntry {
+                name: String::from_utf8_lossy(&header.filename).to_string(),
+                compressed_size,
+                uncompressed_size,
+                local_header_offset,
+                header,
+            });
+        }
+        Ok(Self { data, entries })
+    }
+
+    pub fn entries(&self) -> &[ZipEntry] {
+        &self.entries
+    }
+
+    /// Returns the entry with the exact name provided
+    pub fn find(&self, name: &str) -> Option<&ZipEntry> {
+        self.entries.iter().find(|e| e.name == name)
+    }
+
+    ///
+    /// Reads and decompresses the contents of the entry, verifying the CRC.  Only the stored and
+    /// deflate compression methods are supported.
+    pub fn read_entry(&self, entry: &ZipEntry) -> Result<Vec<u8>, BitsError> {
+        let mut local = slice_from(self.data, entry.local_header_offset)?;
+        LocalFileHeader::read_from(&mut local)?;
+        let Some(compressed) = usize::try_from(entry.compressed_size)
+            .ok()
+            .and_then(|len| local.get(..len))
+        else {
+            return BitsErrorKind::UnexpectedEof.err("Zip entry data is truncated");
+        };
+        let out = match entry.header.compression_method {
+            METHOD_STORED => compressed.to_vec(),
+            METHOD_DEFLATED => {
+                let mut out = Vec::new();
+                let mut inflater = Inflater::new_raw(BitsWrapper::Owned(compressed));
+                while let Some(block) = inflater.read_deflate_block()? {
+                    out.extend_from_slice(block);
+                }
+                out
+            }
+            _ => {
+                return BitsErrorKind::Unsupported.err("Unsupported zip compression method");
+            }
+        };
+        if CRC32::crc32(&out) != entry.header.crc32 {
+            return BitsErrorKind::InvalidData.err("Zip entry CRC mismatch");
+        }
+        Ok(out)
+    }
+}
+
+fn slice_from(data: &[u8], offset: u64) -> Result<&[u8], BitsError> {
+    usize::try_from(offset)
+        .ok()
+        .and_then(|o| data.get(o..))
+        .ok_or_else(|| BitsError::new(BitsErrorKind::UnexpectedEof, "Zip offset out of range"))
+}
+
+/// The end of central directory record is at the end of the file, followed by up to 64k comment.
+fn find_end_of_central_directory(data: &[u8]) -> Result<usize, BitsError> {
+    let Some(last) = data.len().checked_sub(EndOfCentralDirectory::MIN_LENGTH) else {
+        return BitsErrorKind::UnexpectedEof.err("Data is too short to be a zip archive");
+    };
+    let first = last.saturating_sub(u16::MAX as usize);
+    (first..=last)
+        .rev()
+        .find(|idx| data.get(*idx..*idx + 4) == Some(&EndOfCentralDirectory::SIGNATURE))
+        .ok_or_else(|| BitsError::new(BitsErrorKind::InvalidData, "Not a zip archive"))
+}
+
+///
+/// Writes a zip archive.  Entries are stored uncompressed.
+pub struct ZipWriter<T: MutBits> {
+    out: T,
+    offset: u64,
+    central_directory: Vec<CentralDirectoryHeader>,
+}
+
+impl<T: MutBits> ZipWriter<T> {
+    pub fn new(out: T) -> Self {
+        Self {
+            out,
+            offset: 0,
+            central_directory: Vec::new(),
+        }
+    }
+
+    /// Appends a file to the archive
+    pub fn add_file(&mut self, name: &str, data: &[u8]) -> Result<(), BitsError> {
+        let (Ok(size), Ok(offset), Ok(filename_length)) = (
+            u32::try_from(data.len()),
+            u32::try_from(self.offset),
+            u16::try_from(name.len()),
+        ) else {
+            return BitsErrorKind::Unsupported.err("Zip64 archives can't be written");
+        };
+        let header = LocalFileHeader {
+            version_needed_to_extract: 10,
+            // bit 11: the filename is UTF-8
+            gp_bit_flag: 1 << 11,
+            compression_method: METHOD_STORED,
+            last_mod_file_time: 0,
+            // 1980-01-01, the earliest representable date
+            last_mod_file_date: 0x21,
+            crc32: CRC32::crc32(data),
+            compressed_size: size,
+            uncompressed_size: size,
+            filename_length,
+            extra_field_length: 0,
+            filename: name.as_bytes().into(),
+            extra_field: None,
+        };
+        header.write_to(&mut self.out)?;
+        self.out.write_all_bytes(data)?;
+        self.offset += 30 + u64::from(filename_length) + u64::from(size);
+        self.central_directory.push(CentralDirectoryHeader {
+            version_made_by: 20,
+            version_needed_to_extract: header.version_needed_to_extract,
+            gp_bit_flag: header.gp_bit_flag,
+            compression_method: header.compression_method,
+            last_mod_file_time: header.last_mod_file_time,
+            last_mod_file_date: header.last_mod_file_date,
+            crc32: header.crc32,
+            compressed_size: size,
+            uncompressed_size: size,
+            filename_length,
+            relative_localheader_offset: offset,
+            filename: header.filename,
+            ..Default::default()
+        });
+        Ok(())
+    }
+
+    /// Writes the central directory, and returns the underlying writer
+    pub fn finish(mut self) -> Result<T, BitsError> {
+        let start = self.offset;
+        let mut length = 0;
+        for header in &self.central_directory {
+            header.write_to(&mut self.out)?;
+            length += 46 + u64::from(header.filename_length);
+        }
+        let (Ok(num_entries), Ok(start), Ok(length)) = (
+            u16::try_from(self.central_directory.len()),
+            u32::try_from(start),
+            u32::try_from(length),
+        ) else {
+            return BitsErrorKind::Unsupported.err("Zip64 archives can't be written");
+        };
+        EndOfCentralDirectory {
+            this_disk_num_entries: num_entries,
+            total_num_entries: num_entries,
+            central_directory_length: length,
+            central_directory_start_disk_offset: start,
+            ..Default::default()
+        }
+        .write_to(&mut self.out)?;
+        Ok(self.out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::archive::{ZipArchive, ZipWriter, METHOD_DEFLATED};
+    use irox_bits::BitsError;
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_read() -> Result<(), BitsError> {
+        let expected = std::fs::read("data/ozzy.txt")?;
+        for file in [
+            "ozzy.win.zip",
+            "ozzy.7za.zip",
+            "ozzy.7zw.zip",
+            "ozzy.izip.zip",
+            "ozzy.z64.zip",
+            "ozzy.txt.uz64.zip",
+        ] {
+            let data = std::fs::read(format!("data/{file}"))?;
+            let archive = ZipArchive::new(&data)?;
+            assert_eq!(1, archive.entries().len(), "{file}");
+            for entry in archive.entries() {
+                assert_eq!(METHOD_DEFLATED, entry.header.compression_method);
+                assert_eq!(expected, archive.read_entry(entry)?, "{file}");
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_round_trip() -> Result<(), BitsError> {
+        let mut writer = ZipWriter::new(Vec::new());
+        writer.add_file("doc.kml", b"<kml/>")?;
+        writer.add_file("files/empty.txt", b"")?;
+        let data = writer.finish()?;
+
+        let archive = ZipArchive::new(&data)?;
+        assert_eq!(2, archive.entries().len());
+        let Some(doc) = archive.find("doc.kml") else {
+            return Err(irox_bits::BitsErrorKind::NotFound.into());
+        };
+        assert_eq!(b"<kml/>".as_slice(), archive.read_entry(doc)?);
+        assert!(archive.find("files/empty.txt").is_some());
+        Ok(())
+    }
+}
@@ -2,9 +2,25 @@
 // Copyright 2025 IROX Contributors
 //
 
+use irox_bits::{Bits, BitsError, BitsErrorKind, MutBits};
 use irox_tools::hex;
 extern crate alloc;
 use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+fn optional_blob<T: Bits>(input: &mut T, len: u16) -> Result<Option<Box<[u8]>>, BitsError> {
+    if len == 0 {
+        return Ok(None);
+    }
+    Ok(Some(input.read_exact_vec(len as usize)?.into_boxed_slice()))
+}
+
+fn check_signature<T: Bits>(input: &mut T, signature: [u8; 4]) -> Result<(), BitsError> {
+    if input.read_exact::<4>()? != signature {
+        return BitsErrorKind::InvalidData.err("Invalid zip record signature");
+    }
+    Ok(())
+}
 
 #[derive(Default, Debug, Clone)]
 pub struct Headers {
@@ -30,6 +46,59 @@ pub struct LocalFileHeader {
 }
 impl LocalFileHeader {
     pub const SIGNATURE: [u8; 4] = hex!("504B0304");
+
+    /// Reads the header, including the signature
+    pub fn read_from<T: Bits>(input: &mut T) -> Result<Self, BitsError> {
+        check_signature(input, Self::SIGNATURE)?;
+        let version_needed_to_extract = input.read_le_u16()?;
+        let gp_bit_flag = input.read_le_u16()?;
+        let compression_method = input.read_le_u16()?;
+        let last_mod_file_time = input.read_le_u16()?;
+        let last_mod_file_date = input.read_le_u16()?;
+        let crc32 = input.read_le_u32()?;
+        let compressed_size = input.read_le_u32()?;
+        let uncompressed_size = input.read_le_u32()?;
+        let filename_length = input.read_le_u16()?;
+        let extra_field_length = input.read_le_u16()?;
+        let filename = input
+            .read_exact_vec(filename_length as usize)?
+            .into_boxed_slice();
+        let extra_field = optional_blob(input, extra_field_length)?;
+        Ok(Self {
+            version_needed_to_extract,
+            gp_bit_flag,
+            compression_method,
+            last_mod_file_time,
+            last_mod_file_date,
+            crc32,
+            compressed_size,
+            uncompressed_size,
+            filename_length,
+            extra_field_length,
+            filename,
+            extra_field,
+        })
+    }
+
+    /// Writes the header, including the signature
+    pub fn write_to<T: MutBits>(&self, out: &mut T) -> Result<(), BitsError> {
+        out.write_all_bytes(&Self::SIGNATURE)?;
+        out.write_le_u16(self.version_needed_to_extract)?;
+        out.write_le_u16(self.gp_bit_flag)?;
+        out.write_le_u16(self.compression_method)?;
+        out.write_le_u16(self.last_mod_file_time)?;
+        out.write_le_u16(self.last_mod_file_date)?;
+        out.write_le_u32(self.crc32)?;
+        out.write_le_u32(self.compressed_size)?;
+        out.write_le_u32(self.uncompressed_size)?;
+        out.write_le_u16(self.filename_length)?;
+        out.write_le_u16(self.extra_field_length)?;
+        out.write_all_bytes(&self.filename)?;
+        if let Some(extra) = &self.extra_field {
+            out.write_all_bytes(extra)?;
+        }
+        Ok(())
+    }
 }
 
 #[derive(Default, Debug, Clone)]
@@ -63,8 +132,118 @@ pub struct CentralDirectoryHeader {
 }
 impl CentralDirectoryHeader {
     pub const SIGNATURE: [u8; 4] = hex!("504B0102");
+
+    /// Reads the header, including the signature
+    pub fn read_from<T: Bits>(input: &mut T) -> Result<Self, BitsError> {
+        check_signature(input, Self::SIGNATURE)?;
+        let version_made_by = input.read_le_u16()?;
+        let version_needed_to_extract = input.read_le_u16()?;
+        let gp_bit_flag = input.read_le_u16()?;
+        let compression_method = input.read_le_u16()?;
+        let last_mod_file_time = input.read_le_u16()?;
+        let last_mod_file_date = input.read_le_u16()?;
+        let crc32 = input.read_le_u32()?;
+        let compressed_size = input.read_le_u32()?;
+        let uncompressed_size = input.read_le_u32()?;
+        let filename_length = input.read_le_u16()?;
+        let extra_field_length = input.read_le_u16()?;
+        let file_comment_length = input.read_le_u16()?;
+        let disk_number_start = input.read_le_u16()?;
+        let internal_file_attributes = input.read_le_u16()?;
+        let external_file_attributes = input.read_le_u32()?;
+        let relative_localheader_offset = input.read_le_u32()?;
+        let filename = input
+            .read_exact_vec(filename_length as usize)?
+            .into_boxed_slice();
+        let extra_field = optional_blob(input, extra_field_length)?;
+        let file_comment = optional_blob(input, file_comment_length)?;
+        Ok(Self {
+            version_made_by,
+            version_needed_to_extract,
+            gp_bit_flag,
+            compression_method,
+            last_mod_file_time,
+            last_mod_file_date,
+            crc32,
+            compressed_size,
+            uncompressed_size,
+            filename_length,
+            extra_field_length,
+            file_comment_length,
+            disk_number_start,
+            internal_file_attributes,
+            external_file_attributes,
+            relative_localheader_offset,
+            filename,
+            extra_field,
+            file_comment,
+        })
+    }
+
+    /// Writes the header, including the signature
+    pub fn write_to<T: MutBits>(&self, out: &mut T) -> Result<(), BitsError> {
+        out.write_all_bytes(&Self::SIGNATURE)?;
+        out.write_le_u16(self.version_made_by)?;
+        out.write_le_u16(self.version_needed_to_extract)?;
+        out.write_le_u16(self.gp_bit_flag)?;
+        out.write_le_u16(self.compression_method)?;
+        out.write_le_u16(self.last_mod_file_time)?;
+        out.write_le_u16(self.last_mod_file_date)?;
+        out.write_le_u32(self.crc32)?;
+        out.write_le_u32(self.compressed_size)?;
+        out.write_le_u32(self.uncompressed_size)?;
+        out.write_le_u16(self.filename_length)?;
+        out.write_le_u16(self.extra_field_length)?;
+        out.write_le_u16(self.file_comment_length)?;
+        out.write_le_u16(self.disk_number_start)?;
+        out.write_le_u16(self.internal_file_attributes)?;
+        out.write_le_u32(self.external_file_attributes)?;
+        out.write_le_u32(self.relative_localheader_offset)?;
+        out.write_all_bytes(&self.filename)?;
+        if let Some(extra) = &self.extra_field {
+            out.write_all_bytes(extra)?;
+        }
+        if let Some(comment) = &self.file_comment {
+            out.write_all_bytes(comment)?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the `(uncompressed_size, compressed_size, local_header_offset)`, using the Zip64
+    /// extended information extra field for any values that overflowed their 32 bit fields.
+    pub fn zip64_sizes(&self) -> Result<(u64, u64, u64), BitsError> {
+        let mut uncompressed = u64::from(self.uncompressed_size);
+        let mut compressed = u64::from(self.compressed_size);
+        let mut offset = u64::from(self.relative_localheader_offset);
+        let mut extra: &[u8] = self.extra_field.as_deref().unwrap_or_default();
+        while extra.len() >= 4 {
+            let id = extra.read_le_u16()?;
+            let len = extra.read_le_u16()? as usize;
+            let Some((mut field, rest)) = extra.split_at_checked(len) else {
+                return BitsErrorKind::InvalidData.err("Truncated zip extra field");
+            };
+            extra = rest;
+            if id != ZIP64_EXTRA_FIELD_ID {
+                continue;
+            }
+            if self.uncompressed_size == u32::MAX {
+                uncompressed = field.read_le_u64()?;
+            }
+            if self.compressed_size == u32::MAX {
+                compressed = field.read_le_u64()?;
+            }
+            if self.relative_localheader_offset == u32::MAX {
+                offset = field.read_le_u64()?;
+            }
+        }
+        Ok((uncompressed, compressed, offset))
+    }
 }
 
+/// Header ID of the Zip64 extended information extra field
+pub const ZIP64_EXTRA_FIELD_ID: u16 = 0x0001;
+
 #[derive(Default, Debug, Clone)]
 pub struct EndOfCentralDirectory {
     pub this_disk_number: u16,
@@ -78,6 +257,47 @@ pub struct EndOfCentralDirectory {
 }
 impl EndOfCentralDirectory {
     pub const SIGNATURE: [u8; 4] = hex!("504B0506");
+    /// Length of the record, not including the trailing comment
+    pub const MIN_LENGTH: usize = 22;
+
+    /// Reads the record, including the signature
+    pub fn read_from<T: Bits>(input: &mut T) -> Result<Self, BitsError> {
+        check_signature(input, Self::SIGNATURE)?;
+        let this_disk_number = input.read_le_u16()?;
+        let disk_with_central_directory = input.read_le_u16()?;
+        let this_disk_num_entries = input.read_le_u16()?;
+        let total_num_entries = input.read_le_u16()?;
+        let central_directory_length = input.read_le_u32()?;
+        let central_directory_start_disk_offset = input.read_le_u32()?;
+        let file_comment_length = input.read_le_u16()?;
+        let file_comment = optional_blob(input, file_comment_length)?;
+        Ok(Self {
+            this_disk_number,
+            disk_with_central_directory,
+            this_disk_num_entries,
+            total_num_entries,
+            central_directory_length,
+            central_directory_start_disk_offset,
+            file_comment_length,
+            file_comment,
+        })
+    }
+
+    /// Writes the record, including the signature
+    pub fn write_to<T: MutBits>(&self, out: &mut T) -> Result<(), BitsError> {
+        out.write_all_bytes(&Self::SIGNATURE)?;
+        out.write_le_u16(self.this_disk_number)?;
+        out.write_le_u16(self.disk_with_central_directory)?;
+        out.write_le_u16(self.this_disk_num_entries)?;
+        out.write_le_u16(self.total_num_entries)?;
+        out.write_le_u32(self.central_directory_length)?;
+        out.write_le_u32(self.central_directory_start_disk_offset)?;
+        out.write_le_u16(self.file_comment_length)?;
+        if let Some(comment) = &self.file_comment {
+            out.write_all_bytes(comment)?;
+        }
+        Ok(())
+    }
 }
 
 #[derive(Default, Debug, Clone)]
@@ -88,6 +308,46 @@ pub struct EndOfCentral64DirectoryLocator {
 }
 impl EndOfCentral64DirectoryLocator {
     pub const SIGNATURE: [u8; 4] = hex!("504B0607");
+    /// Length of the record
+    pub const LENGTH: usize = 20;
+
+    /// Reads the record, including the signature
+    pub fn read_from<T: Bits>(input: &mut T) -> Result<Self, BitsError> {
+        check_signature(input, Self::SIGNATURE)?;
+        Ok(Self {
+            num_disk_with_64central_directory: input.read_le_u32()?,
+            relative_offset_64central: input.read_le_u64()?,
+            total_num_disks: input.read_le_u32()?,
+        })
+    }
+}
+
+///
+/// The fields of the Zip64 end of central directory record needed to locate the entries.
+#[derive(Default, Debug, Clone)]
+pub struct EndOfCentral64Directory {
+    pub total_num_entries: u64,
+    pub central_directory_length: u64,
+    pub central_directory_start_disk_offset: u64,
+}
+impl EndOfCentral64Directory {
+    pub const SIGNATURE: [u8; 4] = hex!("504B0606");
+
+    /// Reads the record, including the signature.  The extensible data sector is skipped.
+    pub fn read_from<T: Bits>(input: &mut T) -> Result<Self, BitsError> {
+        check_signature(input, Self::SIGNATURE)?;
+        let _record_size = input.read_le_u64()?;
+        let _version_made_by = input.read_le_u16()?;
+        let _version_needed = input.read_le_u16()?;
+        let _this_disk_number = input.read_le_u32()?;
+        let _disk_with_central_directory = input.read_le_u32()?;
+        let _this_disk_num_entries = input.read_le_u64()?;
+        Ok(Self {
+            total_num_entries: input.read_le_u64()?,
+            central_directory_length: input.read_le_u64()?,
+            central_directory_start_disk_offset: input.read_le_u64()?,
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
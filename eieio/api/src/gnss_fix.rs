@@ -8,6 +8,8 @@
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
+use irox_carto::error::ConvertError;
+use irox_carto::kalman::{FilteredPosition, PositionKalmanFilter};
 use irox_carto::position_type::Positions;
 use irox_time::datetime::UTCDateTime;
 
@@ -83,6 +85,19 @@ impl Debug for dyn GNSSFix {
     }
 }
 
+///
+/// Feeds [`GNSSFix`] messages into a [`PositionKalmanFilter`]
+pub trait GNSSFixFilter {
+    /// Filters the fix at its timestamp.
+    fn update_fix(&mut self, fix: &dyn GNSSFix) -> Result<FilteredPosition, ConvertError>;
+}
+
+impl GNSSFixFilter for PositionKalmanFilter {
+    fn update_fix(&mut self, fix: &dyn GNSSFix) -> Result<FilteredPosition, ConvertError> {
+        self.update_positions(fix.get_timestamp(), &fix.get_positions(), None)
+    }
+}
+
 ///
 /// A builder to create a new [`GNSSFix`] message.
 pub trait GNSSFixBuilder {
@@ -44,5 +44,5 @@ pub fn test() {
         .unwrap()
         .write_to_string()
         .unwrap();
-    assert_eq!("$GPGGA,123456.00,4100.00000,N,7100.00000,W,,,,,,,,,*65\r\n$GPZDA,123456.00,11,01,2023,00,00*63\r\n", string);
+    assert_eq!("$GPGGA,123456.00,4100.00000,N,07100.00000,W,,,,,,,,,*55\r\n$GPZDA,123456.00,12,01,2023,00,00*60\r\n", string);
 }
@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use std::sync::Arc;
+
+use irox_eieio_api::carto::coordinate::EllipticalCoordinateBuilder;
+use irox_eieio_api::carto::geo::standards::wgs84::WGS84_SHAPE;
+use irox_eieio_api::carto::irox_units::units::angle::AngleUnits;
+use irox_eieio_api::carto::irox_units::units::length::LengthUnits;
+use irox_eieio_api::carto::irox_units::units::Unit;
+use irox_eieio_api::carto::position_type::{Positions, WGS84Position};
+use irox_eieio_api::codec::Codec;
+use irox_eieio_api::error::Error;
+use irox_eieio_api::gnss_fix::{GNSSFix, GNSSFixBuilder, OwnedGNSSFix};
+use irox_eieio_api::io::{SupportedWriters, SupportedWritersBuilder, WriteToBytes, WriteToString};
+use irox_eieio_api::time::datetime::UTCDateTime;
+use irox_eieio_api::{BaseMessage, Message, MessageType};
+use irox_structs::Struct;
+use irox_ubx::nav::{UBXFixType, UBXNavPVTRaw};
+use irox_ubx::{calculate_checksum, UBXClass};
+
+use crate::UBXCodec;
+
+/// UBX message ID of NAV-PVT within the NAV class
+const NAV_PVT_ID: u8 = 0x07;
+
+///
+/// A GNSS Fix backed by a single UBX NAV-PVT message.
+#[derive(Clone)]
+pub struct GNSSFixImpl {
+    pub pvt: UBXNavPVTRaw,
+    pub codec: Arc<UBXCodec>,
+}
+
+impl WriteToBytes for GNSSFixImpl {
+    fn get_bytes(&self) -> Result<Vec<u8>, Error> {
+        let class = UBXClass::NAV as u8;
+        let payload = self.pvt.as_bytes()?;
+        let len = payload.len() as u16;
+        let mut buf: Vec<u8> = Vec::with_capacity(payload.len() + 8);
+        buf.extend_from_slice(&[0xB5, 0x62, class, NAV_PVT_ID]);
+        buf.extend_from_slice(&len.to_le_bytes());
+        buf.extend_from_slice(&payload);
+        let checksum = calculate_checksum(class, NAV_PVT_ID, len, &payload);
+        buf.extend_from_slice(&checksum.to_le_bytes());
+        Ok(buf)
+    }
+}
+
+impl WriteToString for GNSSFixImpl {
+    fn write_to_string(&self) -> Result<String, Error> {
+        Ok(irox_tools::hex::to_hex_str_upper(&self.get_bytes()?))
+    }
+}
+
+impl BaseMessage for GNSSFixImpl {
+    fn get_supported_writers(&self) -> SupportedWriters<'_> {
+        SupportedWritersBuilder::new()
+            .with_bytes(self)
+            .with_string(self)
+            .build()
+    }
+
+    fn get_message_type(&self) -> MessageType {
+        MessageType::GnssFix
+    }
+
+    fn as_message(&self) -> Message {
+        Message::GnssFix(Box::new(Clone::clone(self)))
+    }
+
+    fn get_codec(&self) -> Arc<dyn Codec> {
+        self.codec.clone()
+    }
+}
+
+impl GNSSFix for GNSSFixImpl {
+    fn get_super(&self) -> &dyn BaseMessage {
+        self
+    }
+
+    fn get_positions(&self) -> Positions {
+        let latlon = if self.pvt.position_valid() {
+            let mut bldr = EllipticalCoordinateBuilder::new();
+            bldr.with_latitude(self.pvt.latitude())
+                .with_longitude(self.pvt.longitude())
+                .with_altitude(self.pvt.altitude_ellipsoid())
+                .with_reference_frame(WGS84_SHAPE);
+            bldr.build().ok().map(WGS84Position)
+        } else {
+            None
+        };
+        Positions {
+            ecef: None,
+            latlon,
+            enu: None,
+            ned: None,
+        }
+    }
+
+    fn get_timestamp(&self) -> UTCDateTime {
+        self.pvt.timestamp().unwrap_or_default()
+    }
+}
+
+///
+/// Builds a NAV-PVT message from the timestamp and position of a fix.
+pub struct UbxGnssFixBuilder {
+    pvt: UBXNavPVTRaw,
+    codec: Arc<UBXCodec>,
+}
+
+impl UbxGnssFixBuilder {
+    pub fn new(codec: Arc<UBXCodec>) -> Self {
+        Self {
+            pvt: Default::default(),
+            codec,
+        }
+    }
+}
+
+impl GNSSFixBuilder for UbxGnssFixBuilder {
+    fn set_timestamp(&mut self, timestamp: UTCDateTime) {
+        let date = timestamp.get_date();
+        let time = timestamp.get_time();
+        let (hour, min, sec) = time.as_hms();
+        self.pvt.year = date.year() as u16;
+        self.pvt.month = date.month_of_year() as u8;
+        self.pvt.day = date.day_of_month() + 1;
+        self.pvt.hour = hour as u8;
+        self.pvt.min = min as u8;
+        self.pvt.sec = sec as u8;
+        self.pvt.nano = time.get_nanoseconds() as i32;
+        // valid date & valid time
+        self.pvt.valid |= 0x03;
+    }
+
+    fn set_positions(&mut self, positions: Positions) {
+        let Some(wgs84) = positions.latlon else {
+            return;
+        };
+        let latlon = wgs84.0;
+        let lat = latlon.get_latitude().0.as_unit(AngleUnits::Degrees).value();
+        let lon = latlon
+            .get_longitude()
+            .0
+            .as_unit(AngleUnits::Degrees)
+            .value();
+        self.pvt.lat_e7_deg = (lat * 1e7).round() as i32;
+        self.pvt.lon_e7_deg = (lon * 1e7).round() as i32;
+        if let Some(alt) = latlon.get_altitude() {
+            let mm = alt.value().as_unit(LengthUnits::Meters).value() * 1e3;
+            self.pvt.height_mm = mm.round() as i32;
+        }
+        self.pvt.fix_type = UBXFixType::Fix3D as u8;
+        // gnssFixOK
+        self.pvt.flags |= 0x01;
+    }
+
+    fn build(&self) -> Result<OwnedGNSSFix, Error> {
+        Ok(Box::new(GNSSFixImpl {
+            pvt: self.pvt,
+            codec: self.codec.clone(),
+        }))
+    }
+}
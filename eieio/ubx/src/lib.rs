@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! Implementation of the EIEIO traits for the u-blox UBX binary protocol.
+//!
+
+#![forbid(unsafe_code)]
+
+use std::sync::Arc;
+
+use irox_eieio_api::codec::{Codec, CodecIdentifier};
+use irox_eieio_api::error::{Error, ErrorType};
+use irox_eieio_api::gnss_fix::OwnedGNSSFixBuilder;
+use irox_eieio_api::io::{ReadFromBytes, SupportedReaders, SupportedReadersBuilder};
+use irox_eieio_api::{BaseMessage, Message, MessageType};
+use irox_tools::packetio::PacketBuilder;
+use irox_ubx::nav::UBXNavPayload;
+use irox_ubx::{UBXParser, UBXPayload};
+
+use crate::gnss_fix::{GNSSFixImpl, UbxGnssFixBuilder};
+
+pub mod gnss_fix;
+
+#[derive(Debug, Default, Clone)]
+pub struct UBXCodec {
+    pub parser: UBXParser,
+}
+
+impl UBXCodec {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> Arc<dyn Codec> {
+        Arc::new(Self::default())
+    }
+}
+
+impl Codec for UBXCodec {
+    #[allow(clippy::new_ret_no_self)]
+    fn new() -> Arc<dyn Codec>
+    where
+        Self: Sized,
+    {
+        UBXCodec::new()
+    }
+
+    fn get_codec_id(&self) -> CodecIdentifier<'_> {
+        CodecIdentifier::new_desc("UBX", "u-blox UBX binary protocol")
+    }
+
+    fn get_supported_builders(&self) -> Vec<MessageType> {
+        vec![MessageType::GnssFix]
+    }
+
+    fn get_supported_readers(&self) -> SupportedReaders<'_> {
+        SupportedReadersBuilder::new().with_bytes(self).build()
+    }
+
+    fn get_gnss_fix_builder(self: Arc<Self>) -> Option<OwnedGNSSFixBuilder> {
+        Some(Box::new(UbxGnssFixBuilder::new(self)))
+    }
+}
+
+impl ReadFromBytes for UBXCodec {
+    fn read_from_bytes(&mut self, mut bytes: &[u8]) -> Result<Vec<Message>, Error> {
+        let msg = match self.parser.build_from(&mut bytes) {
+            Ok(m) => m,
+            Err(e) => {
+                return ErrorType::ParserError("Error").source(Box::new(e));
+            }
+        };
+
+        if let UBXPayload::Nav(UBXNavPayload::PVT(pvt)) = msg.payload {
+            let fix = GNSSFixImpl {
+                pvt,
+                codec: Arc::new(Clone::clone(self)),
+            };
+            return Ok(vec![fix.as_message()]);
+        }
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use irox_eieio_api::carto::coordinate::{Latitude, Longitude};
+use irox_eieio_api::carto::irox_units::units::angle::Angle;
+use irox_eieio_api::carto::position_type::{PositionsBuilder, WGS84PositionBuilder};
+use irox_eieio_api::io::ReadFromBytes;
+use irox_eieio_api::time::datetime::UTCDateTime;
+use irox_eieio_api::time::gregorian::Date;
+use irox_eieio_api::time::Time;
+use irox_eieio_api::{BaseMessage, Message};
+use irox_eieio_ubx::UBXCodec;
+
+#[test]
+pub fn test() {
+    let codec = UBXCodec::new();
+
+    let Some(mut fix_bldr) = codec.get_gnss_fix_builder() else {
+        panic!("Expecting a GNSS fix builder");
+    };
+
+    let timestamp = UTCDateTime::new(
+        Date::try_from_values(2023, 11, 4).unwrap(),
+        Time::from_hms(12, 34, 56).unwrap(),
+    );
+    fix_bldr.set_timestamp(timestamp);
+    fix_bldr.set_positions(
+        PositionsBuilder::new()
+            .with_latlon(
+                WGS84PositionBuilder::new()
+                    .with_latitude(Latitude(Angle::new_degrees(41.)))
+                    .with_longitude(Longitude(Angle::new_degrees(-71.)))
+                    .build()
+                    .unwrap(),
+            )
+            .build(),
+    );
+
+    let res = fix_bldr.build().unwrap();
+    let bytes = res
+        .get_supported_writers()
+        .bytes()
+        .unwrap()
+        .get_bytes()
+        .unwrap();
+    assert_eq!(100, bytes.len());
+    assert_eq!(&[0xB5, 0x62, 0x01, 0x07, 92, 0], &bytes[..6]);
+
+    let mut reader = UBXCodec::default();
+    let mut msgs = reader.read_from_bytes(&bytes).unwrap();
+    assert_eq!(1, msgs.len());
+    let Some(Message::GnssFix(fix)) = msgs.pop() else {
+        panic!("Expecting a GNSS fix");
+    };
+    assert_eq!(timestamp, fix.get_timestamp());
+    let latlon = fix.get_positions().latlon.unwrap().0;
+    assert_eq!(41., latlon.get_latitude().0.as_degrees().value());
+    assert_eq!(-71., latlon.get_longitude().0.as_degrees().value());
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use irox_bits::BitsWrapper;
+use irox_gnss::ntrip::NtripClient;
+use irox_gnss::GNSSPacketStream;
+use irox_log::log;
+use irox_log::log::Level;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+///
+/// Usage: `ntrip <host> <mountpoint> [username] [password]`, lists the sourcetable if the
+/// mountpoint is omitted.
+fn main() -> Result<(), irox_bits::Error> {
+    irox_log::init_console_level(Level::Info);
+    let mut args = std::env::args().skip(1);
+    let host = args.next().unwrap_or("rtk2go.com".to_string());
+    let mut client = NtripClient::new_default_port(host);
+    let Some(mountpoint) = args.next() else {
+        for stream in client.sourcetable()?.streams {
+            log::info!("{stream}");
+        }
+        return Ok(());
+    };
+    if let (Some(username), Some(password)) = (args.next(), args.next()) {
+        client = client.with_credentials(username, password);
+    }
+    let stream = client.connect(mountpoint)?;
+    let mut packets =
+        GNSSPacketStream::new(Arc::new(AtomicBool::new(true)), BitsWrapper::Owned(stream));
+    while let Ok(pkt) = packets.read_next() {
+        log::info!("read packet: {pkt}");
+    }
+    Ok(())
+}
@@ -2,15 +2,96 @@
 // Copyright 2025 IROX Contributors
 //
 
+use irox_bits::BitsWrapper;
+use irox_gnss::record::{Recorder, Recording, ReplaySpeed};
+use irox_gnss::GNSSPacketStream;
 use irox_log::log;
 use irox_log::log::Level;
-use std::net::{IpAddr, Ipv4Addr};
+use irox_networking::pool::TCPConnectionManager;
+use std::net::{IpAddr, Ipv4Addr, TcpStream};
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::Duration;
 
+///
+/// Usage:
+/// * `tcp` - prints the packets from the default receiver
+/// * `tcp record <host:port> <file>` - prints the packets from the receiver, recording them to the file
+/// * `tcp serve <file> <listen:port> [speed|max]` - serves the recording (or a u-center `.ubx` log)
+///   to every client, at the speed multiplier (default 1x)
 fn main() -> Result<(), irox_bits::Error> {
     irox_log::init_console_level(Level::Info);
-    let client = irox_gnss::tcp::TCPClient::new(IpAddr::V4(Ipv4Addr::new(10, 169, 0, 13)), 8089)?;
-    while let Some(pkt) = client.recv() {
+    let mut args = std::env::args().skip(1);
+    match args.next().as_deref() {
+        Some("record") => {
+            let addr = args.next().unwrap_or("127.0.0.1:8089".to_string());
+            let file = args.next().unwrap_or("gnss.rec".to_string());
+            record(&addr, &file)
+        }
+        Some("serve") => {
+            let file = args.next().unwrap_or("gnss.rec".to_string());
+            let addr = args.next().unwrap_or("127.0.0.1:8089".to_string());
+            let speed = match args.next().as_deref() {
+                Some("max") => ReplaySpeed::Max,
+                Some(speed) => ReplaySpeed::Multiplier(speed.parse().unwrap_or(1.)),
+                None => ReplaySpeed::RealTime,
+            };
+            serve(&file, &addr, speed)
+        }
+        _ => {
+            let client =
+                irox_gnss::tcp::TCPClient::new(IpAddr::V4(Ipv4Addr::new(10, 169, 0, 13)), 8089)?;
+            while let Some(pkt) = client.recv() {
+                log::info!("read packet: {pkt}");
+            }
+            Ok(())
+        }
+    }
+}
+
+fn record(addr: &str, file: &str) -> Result<(), irox_bits::Error> {
+    let recorder = Recorder::new();
+    let source = recorder.wrap(TcpStream::connect(addr)?);
+    let mut stream =
+        GNSSPacketStream::new(Arc::new(AtomicBool::new(true)), BitsWrapper::Owned(source));
+    let mut count = 0;
+    while let Ok(pkt) = recorder.read_next(&mut stream) {
         log::info!("read packet: {pkt}");
+        count += 1;
+        if count % 100 == 0 {
+            recorder.recording().save(file)?;
+        }
+    }
+    recorder.recording().save(file)
+}
+
+fn serve(file: &str, addr: &str, speed: ReplaySpeed) -> Result<(), irox_bits::Error> {
+    let is_ubx = Path::new(file)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("ubx"));
+    let recording = if is_ubx {
+        Recording::import_ubx_log(std::fs::File::open(file)?)?
+    } else {
+        Recording::load(file)?
+    };
+    log::info!(
+        "Serving {} bytes over {:?} on {addr}",
+        recording.len_bytes(),
+        recording.duration()
+    );
+    let mut server = TCPConnectionManager::start(addr, Arc::new(AtomicBool::new(false)))?;
+    while server.num_connected() == 0 {
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    let mut replay = recording.replay(speed);
+    let mut buf = [0u8; 4096];
+    loop {
+        let read = std::io::Read::read(&mut replay, &mut buf)?;
+        if read == 0 {
+            break;
+        }
+        server.write_to_all_connected(buf.get(..read).unwrap_or_default());
     }
     Ok(())
 }
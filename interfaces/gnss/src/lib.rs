@@ -20,7 +20,9 @@ use core::fmt::{Display, Formatter};
 use core::sync::atomic::AtomicBool;
 use irox_bits::{Bits, BitsBuffer, BitsWrapper, BufBits};
 use irox_log::log;
-use irox_nmea0183::{Frame as NMEAFrame, NMEAParser};
+use irox_nmea0183::ais::{AISMessage, AISReassembler};
+use irox_nmea0183::{Frame as NMEAFrame, FramePayload, NMEAParser};
+use irox_rtcm3::{RTCM3Message, RTCM3Parser};
 use irox_sirf::packet::PacketParser as SIRFParser;
 use irox_sirf::packet::PacketType as SIRFPacket;
 use irox_tools::cfg_feature_std;
@@ -28,6 +30,9 @@ use irox_tools::packetio::PacketBuilder;
 use irox_ubx::{UBXMessage, UBXParser};
 
 cfg_feature_std! {
+    pub mod ntrip;
+    pub mod orbit;
+    pub mod record;
     pub mod tcp;
 }
 
@@ -36,6 +41,10 @@ pub enum GNSSFrame {
     UBX(UBXMessage),
     NMEA(NMEAFrame),
     SIRF(SIRFPacket),
+    /// A complete, decoded AIS message, reassembled from its `!AIVDM`/`!AIVDO` sentences.
+    AIS(AISMessage),
+    /// RTCM 3.x differential correction message
+    RTCM3(RTCM3Message),
 }
 impl Display for GNSSFrame {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
@@ -43,6 +52,8 @@ impl Display for GNSSFrame {
             GNSSFrame::UBX(a) => core::fmt::Display::fmt(a, f),
             GNSSFrame::NMEA(a) => core::fmt::Display::fmt(a, f),
             GNSSFrame::SIRF(a) => core::fmt::Display::fmt(a, f),
+            GNSSFrame::AIS(a) => core::fmt::Display::fmt(a, f),
+            GNSSFrame::RTCM3(a) => core::fmt::Display::fmt(a, f),
         }
     }
 }
@@ -50,6 +61,7 @@ impl Display for GNSSFrame {
 pub struct GNSSPacketStream<'a, T: Bits> {
     run: Arc<AtomicBool>,
     buf: BitsBuffer<'a, T>,
+    ais: AISReassembler,
 }
 impl<'a, T: Bits> GNSSPacketStream<'a, T>
 where
@@ -59,6 +71,7 @@ where
         Self {
             run,
             buf: BitsBuffer::new(buf),
+            ais: AISReassembler::new(),
         }
     }
     pub fn read_next(&mut self) -> Result<GNSSFrame, irox_bits::Error> {
@@ -89,6 +102,29 @@ where
                     log::debug!("read NMEA: {pkt:?}");
                     return Ok(GNSSFrame::NMEA(pkt));
                 }
+                (b'!', _) => {
+                    // NMEA encapsulation, AIS fragments are held until the message is complete.
+                    let Ok(pkt) = NMEAParser.build_from(&mut self.buf) else {
+                        log::info!("Failed to read NMEA");
+                        break;
+                    };
+                    if skipped > 0 {
+                        log::warn!("skipped {skipped} unused bytes");
+                        skipped = 0;
+                    }
+                    log::debug!("read NMEA: {pkt:?}");
+                    let FramePayload::AIVDM(vdm) = pkt.payload else {
+                        return Ok(GNSSFrame::NMEA(pkt));
+                    };
+                    match self.ais.push(vdm) {
+                        Ok(Some(msg)) => return Ok(GNSSFrame::AIS(msg)),
+                        Ok(None) => continue,
+                        Err(e) => {
+                            log::info!("Failed to decode AIS: {e}");
+                            continue;
+                        }
+                    }
+                }
                 (0xA0, 0xA2) => {
                     // assuming SIRF, must read from buf to consume.
                     let Ok(pkt) = SIRFParser.build_from(&mut self.buf) else {
@@ -113,6 +149,32 @@ where
                     log::debug!("read UBX: {pkt:?}");
                     return Ok(GNSSFrame::UBX(pkt));
                 }
+                (irox_rtcm3::PREAMBLE, b) if b & 0xFC == 0 => {
+                    // assuming RTCM3.  The preamble is a common byte in binary streams, so the
+                    // whole frame is checked before consuming anything, and a false preamble
+                    // only skips the one byte.
+                    match self.peek_rtcm3_frame(b) {
+                        Ok(true) => {}
+                        Ok(false) => {
+                            self.buf.consume(1);
+                            skipped += 1;
+                            continue;
+                        }
+                        Err(e) => {
+                            log::info!("Failed to peek RTCM3: {e:?}");
+                            break;
+                        }
+                    }
+                    let Ok(pkt) = RTCM3Parser::default().build_from(&mut self.buf) else {
+                        log::info!("Failed to read RTCM3");
+                        break;
+                    };
+                    if skipped > 0 {
+                        log::warn!("skipped {skipped} unused bytes");
+                    }
+                    log::debug!("read RTCM3: {pkt:?}");
+                    return Ok(GNSSFrame::RTCM3(pkt));
+                }
                 _ => {
                     // unknown, skip.
                     self.buf.consume(1);
@@ -123,4 +185,20 @@ where
         }
         Err(irox_bits::ErrorKind::UnexpectedEof.into())
     }
+
+    ///
+    /// Returns true if a complete RTCM3 frame with a valid CRC is buffered, false if the
+    /// preamble was a false match or the stream ends before the claimed end of the frame.
+    fn peek_rtcm3_frame(&mut self, len_hi: u8) -> Result<bool, irox_bits::Error> {
+        let Some(len_lo) = self.buf.fill_to(3)?.get(2).copied() else {
+            return Ok(false);
+        };
+        let len = u16::from_be_bytes([len_hi, len_lo]) as usize;
+        let frame = self.buf.fill_to(len + 6)?;
+        let (Some(body), Some([a, b, c])) = (frame.get(..len + 3), frame.get(len + 3..len + 6))
+        else {
+            return Ok(false);
+        };
+        Ok(irox_rtcm3::crc24q(body) == u32::from_be_bytes([0, *a, *b, *c]))
+    }
 }
@@ -0,0 +1,366 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! Networked Transport of RTCM via Internet Protocol (NTRIP) v1 & v2 client and caster.
+//!
+
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+pub use caster::*;
+pub use client::*;
+
+mod caster;
+mod client;
+
+/// Default NTRIP caster port
+pub const DEFAULT_PORT: u16 = 2101;
+/// Line terminating the sourcetable
+pub const END_SOURCETABLE: &str = "ENDSOURCETABLE";
+
+///
+/// NTRIP protocol version.  V1 is HTTP/1.0-like with `ICY` responses, V2 is proper HTTP/1.1.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum NtripVersion {
+    V1,
+    #[default]
+    V2,
+}
+
+///
+/// The authentication required by a stream or network
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum NtripAuthentication {
+    #[default]
+    None,
+    Basic,
+    Digest,
+}
+impl NtripAuthentication {
+    fn parse(val: &str) -> Self {
+        match val {
+            "B" => NtripAuthentication::Basic,
+            "D" => NtripAuthentication::Digest,
+            _ => NtripAuthentication::None,
+        }
+    }
+    fn code(self) -> &'static str {
+        match self {
+            NtripAuthentication::None => "N",
+            NtripAuthentication::Basic => "B",
+            NtripAuthentication::Digest => "D",
+        }
+    }
+}
+
+/// Splits a sourcetable record, handling missing trailing fields.
+struct Fields<'a> {
+    iter: core::str::Split<'a, char>,
+}
+impl<'a> Fields<'a> {
+    fn new(line: &'a str) -> Self {
+        Fields {
+            iter: line.split(';'),
+        }
+    }
+    fn string(&mut self) -> String {
+        self.iter.next().unwrap_or_default().to_string()
+    }
+    fn parse<T: FromStr + Default>(&mut self) -> T {
+        T::from_str(self.iter.next().unwrap_or_default().trim()).unwrap_or_default()
+    }
+    fn flag(&mut self) -> bool {
+        matches!(self.iter.next(), Some("1" | "Y"))
+    }
+    fn rest(&mut self) -> String {
+        self.iter.by_ref().collect::<Vec<_>>().join(";")
+    }
+}
+
+///
+/// A `STR` record - a data stream (mountpoint) available from the caster
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StreamRecord {
+    pub mountpoint: String,
+    /// Source identifier, usually the nearest town
+    pub identifier: String,
+    /// Data format, like `RTCM 3.2`
+    pub format: String,
+    /// Message types and rates, like `1005(10),1077(1)`
+    pub format_details: String,
+    /// 0 - no carrier phase, 1 - L1, 2 - L1 & L2
+    pub carrier: u8,
+    /// Constellations, like `GPS+GLO+GAL+BDS`
+    pub nav_system: String,
+    pub network: String,
+    /// ISO 3166 country code
+    pub country: String,
+    /// Approximate position, in degrees
+    pub latitude: f64,
+    pub longitude: f64,
+    /// The client must send a GGA sentence with its position, as for a VRS
+    pub nmea: bool,
+    /// The stream is generated from a network of stations rather than a single station
+    pub network_solution: bool,
+    /// Hard or software generating the stream
+    pub generator: String,
+    pub compression: String,
+    pub authentication: NtripAuthentication,
+    pub fee: bool,
+    /// Bits per second
+    pub bitrate: u32,
+    pub misc: String,
+}
+impl StreamRecord {
+    pub fn new<T: AsRef<str>>(mountpoint: T) -> Self {
+        StreamRecord {
+            mountpoint: mountpoint.as_ref().to_string(),
+            format: "RTCM 3".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn parse(mut fields: Fields) -> Self {
+        StreamRecord {
+            mountpoint: fields.string(),
+            identifier: fields.string(),
+            format: fields.string(),
+            format_details: fields.string(),
+            carrier: fields.parse(),
+            nav_system: fields.string(),
+            network: fields.string(),
+            country: fields.string(),
+            latitude: fields.parse(),
+            longitude: fields.parse(),
+            nmea: fields.flag(),
+            network_solution: fields.flag(),
+            generator: fields.string(),
+            compression: fields.string(),
+            authentication: NtripAuthentication::parse(&fields.string()),
+            fee: fields.flag(),
+            bitrate: fields.parse(),
+            misc: fields.rest(),
+        }
+    }
+}
+impl Display for StreamRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "STR;{};{};{};{};{};{};{};{};{:.2};{:.2};{};{};{};{};{};{};{};{}",
+            self.mountpoint,
+            self.identifier,
+            self.format,
+            self.format_details,
+            self.carrier,
+            self.nav_system,
+            self.network,
+            self.country,
+            self.latitude,
+            self.longitude,
+            u8::from(self.nmea),
+            u8::from(self.network_solution),
+            self.generator,
+            self.compression,
+            self.authentication.code(),
+            if self.fee { "Y" } else { "N" },
+            self.bitrate,
+            self.misc
+        )
+    }
+}
+
+///
+/// A `CAS` record - another known caster
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CasterRecord {
+    pub host: String,
+    pub port: u16,
+    pub identifier: String,
+    pub operator: String,
+    pub nmea: bool,
+    pub country: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub fallback_host: String,
+    pub fallback_port: u16,
+    pub misc: String,
+}
+impl CasterRecord {
+    fn parse(mut fields: Fields) -> Self {
+        CasterRecord {
+            host: fields.string(),
+            port: fields.parse(),
+            identifier: fields.string(),
+            operator: fields.string(),
+            nmea: fields.flag(),
+            country: fields.string(),
+            latitude: fields.parse(),
+            longitude: fields.parse(),
+            fallback_host: fields.string(),
+            fallback_port: fields.parse(),
+            misc: fields.rest(),
+        }
+    }
+}
+impl Display for CasterRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "CAS;{};{};{};{};{};{};{:.2};{:.2};{};{};{}",
+            self.host,
+            self.port,
+            self.identifier,
+            self.operator,
+            u8::from(self.nmea),
+            self.country,
+            self.latitude,
+            self.longitude,
+            self.fallback_host,
+            self.fallback_port,
+            self.misc
+        )
+    }
+}
+
+///
+/// A `NET` record - a network of streams
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct NetworkRecord {
+    pub identifier: String,
+    pub operator: String,
+    pub authentication: NtripAuthentication,
+    pub fee: bool,
+    pub web_network: String,
+    pub web_stream: String,
+    pub web_registration: String,
+    pub misc: String,
+}
+impl NetworkRecord {
+    fn parse(mut fields: Fields) -> Self {
+        NetworkRecord {
+            identifier: fields.string(),
+            operator: fields.string(),
+            authentication: NtripAuthentication::parse(&fields.string()),
+            fee: fields.flag(),
+            web_network: fields.string(),
+            web_stream: fields.string(),
+            web_registration: fields.string(),
+            misc: fields.rest(),
+        }
+    }
+}
+impl Display for NetworkRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "NET;{};{};{};{};{};{};{};{}",
+            self.identifier,
+            self.operator,
+            self.authentication.code(),
+            if self.fee { "Y" } else { "N" },
+            self.web_network,
+            self.web_stream,
+            self.web_registration,
+            self.misc
+        )
+    }
+}
+
+///
+/// The list of streams, casters and networks provided by a caster.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SourceTable {
+    pub streams: Vec<StreamRecord>,
+    pub casters: Vec<CasterRecord>,
+    pub networks: Vec<NetworkRecord>,
+}
+impl SourceTable {
+    ///
+    /// Parses the records of the table, up to the `ENDSOURCETABLE` line.  Unknown records are
+    /// skipped.
+    pub fn parse(table: &str) -> SourceTable {
+        let mut out = SourceTable::default();
+        for line in table.lines() {
+            let line = line.trim_end();
+            if line == END_SOURCETABLE {
+                break;
+            }
+            let mut fields = Fields::new(line);
+            match fields.iter.next() {
+                Some("STR") => out.streams.push(StreamRecord::parse(fields)),
+                Some("CAS") => out.casters.push(CasterRecord::parse(fields)),
+                Some("NET") => out.networks.push(NetworkRecord::parse(fields)),
+                _ => {}
+            }
+        }
+        out
+    }
+
+    pub fn find_stream(&self, mountpoint: &str) -> Option<&StreamRecord> {
+        self.streams.iter().find(|s| s.mountpoint == mountpoint)
+    }
+}
+impl Display for SourceTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for cas in &self.casters {
+            write!(f, "{cas}\r\n")?;
+        }
+        for net in &self.networks {
+            write!(f, "{net}\r\n")?;
+        }
+        for str in &self.streams {
+            write!(f, "{str}\r\n")?;
+        }
+        write!(f, "{END_SOURCETABLE}\r\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ntrip::{NtripAuthentication, SourceTable};
+    use irox_tools::assert_eq_eps;
+
+    #[test]
+    pub fn test_sourcetable() {
+        let input = "CAS;rtk2go.com;2101;RTK2go;SNIP;0;USA;39.18;-76.86;0.0.0.0;0;http://rtk2go.com\r\n\
+            NET;SNIP;RTK2go;N;N;http://rtk2go.com;none;none;none\r\n\
+            STR;ACACU;Acacu;RTCM 3.2;1005(10),1077(1),1087(1);2;GPS+GLO;SNIP;BRA;-22.41;-47.64;1;0;sNTRIP;none;B;N;7360;\r\n\
+            STR;Short;Short;RTCM 3\r\n\
+            ENDSOURCETABLE\r\n\
+            STR;Ignored\r\n";
+        let table = SourceTable::parse(input);
+        assert_eq!(2, table.streams.len());
+        assert_eq!(1, table.casters.len());
+        assert_eq!(1, table.networks.len());
+
+        let Some(acacu) = table.find_stream("ACACU") else {
+            panic!("Missing ACACU");
+        };
+        assert_eq!("RTCM 3.2", acacu.format);
+        assert_eq!("1005(10),1077(1),1087(1)", acacu.format_details);
+        assert_eq!(2, acacu.carrier);
+        assert_eq_eps!(-22.41, acacu.latitude, 1e-9);
+        assert!(acacu.nmea);
+        assert!(!acacu.network_solution);
+        assert_eq!(NtripAuthentication::Basic, acacu.authentication);
+        assert_eq!(7360, acacu.bitrate);
+        assert_eq!(
+            "STR;ACACU;Acacu;RTCM 3.2;1005(10),1077(1),1087(1);2;GPS+GLO;SNIP;BRA;-22.41;-47.64;1;0;sNTRIP;none;B;N;7360;",
+            acacu.to_string()
+        );
+
+        let Some(short) = table.find_stream("Short") else {
+            panic!("Missing Short");
+        };
+        assert_eq!(0, short.bitrate);
+        assert_eq!(
+            2101,
+            table.casters.first().map(|c| c.port).unwrap_or_default()
+        );
+
+        assert_eq!(table, SourceTable::parse(&table.to_string()));
+    }
+}
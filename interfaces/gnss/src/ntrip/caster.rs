@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use crate::ntrip::{NtripAuthentication, SourceTable, StreamRecord};
+use alloc::sync::Arc;
+use core::fmt::Debug;
+use core::net::SocketAddr;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::time::Duration;
+use irox_bits::Error;
+use irox_log::log;
+use irox_networking::http::HttpHeaders;
+use irox_networking::pool::TCPConnectionManager;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+
+/// Server header sent by the caster
+pub const CASTER_SERVER: &str = "NTRIP irox-gnss/0.1.0";
+
+/// How long a client has to send its request before it's dropped.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+///
+/// A minimal NTRIP caster serving a single mountpoint, relaying the data from one local source
+/// to every connected client.  Requests for the root or unknown mountpoints are answered with the
+/// sourcetable.  Data sent upstream by the clients, like GGA sentences, is ignored.
+pub struct NtripCaster {
+    manager: TCPConnectionManager,
+    close: Arc<AtomicBool>,
+}
+
+impl NtripCaster {
+    ///
+    /// Starts listening for clients on the address.  If credentials are provided, clients must
+    /// use HTTP basic authentication to connect to the stream.
+    pub fn start<A: ToSocketAddrs + Debug>(
+        addr: A,
+        mut stream: StreamRecord,
+        credentials: Option<(&str, &str)>,
+        close: Arc<AtomicBool>,
+    ) -> Result<NtripCaster, Error> {
+        let authorization = match credentials {
+            Some((username, password)) => {
+                stream.authentication = NtripAuthentication::Basic;
+                let creds = irox_tools::base64::base64_encode_to_str(
+                    format!("{username}:{password}").as_bytes(),
+                )?;
+                Some(format!("Basic {creds}"))
+            }
+            None => None,
+        };
+        let table = SourceTable {
+            streams: vec![stream],
+            ..Default::default()
+        };
+        let manager =
+            TCPConnectionManager::start_with_callback(addr, close.clone(), move |client, addr| {
+                match handshake(client, &table, authorization.as_deref()) {
+                    Ok(accepted) => accepted,
+                    Err(e) => {
+                        log::info!("NTRIP handshake with {addr} failed: {e:?}");
+                        false
+                    }
+                }
+            })?;
+        Ok(NtripCaster { manager, close })
+    }
+
+    /// The address the caster is listening on
+    pub fn local_addr(&self) -> SocketAddr {
+        self.manager.local_addr()
+    }
+
+    /// The number of clients currently receiving the stream
+    pub fn num_connected(&self) -> usize {
+        self.manager.num_connected()
+    }
+
+    ///
+    /// Sends the data to every connected client, dropping any that fail.
+    pub fn write(&mut self, data: &[u8]) {
+        self.manager.write_to_all_connected(data);
+    }
+
+    ///
+    /// Relays the source to the connected clients until it ends or the caster is closed.
+    pub fn relay<T: Read>(&mut self, mut source: T) -> Result<(), Error> {
+        let mut buf = [0u8; 4096];
+        while !self.close.load(Ordering::Relaxed) {
+            let read = source.read(&mut buf)?;
+            if read == 0 {
+                break;
+            }
+            self.write(buf.get(..read).unwrap_or_default());
+        }
+        Ok(())
+    }
+
+    ///
+    /// Stops accepting clients and waits for the listener to finish.
+    pub fn stop(self) {
+        self.close.store(true, Ordering::Relaxed);
+        // wake the listener blocked in accept.
+        let _ = TcpStream::connect(self.manager.local_addr());
+        let _ = self.manager.join();
+    }
+}
+
+fn handshake(
+    client: &mut TcpStream,
+    table: &SourceTable,
+    authorization: Option<&str>,
+) -> Result<bool, std::io::Error> {
+    client.set_read_timeout(Some(HANDSHAKE_TIMEOUT))?;
+    let mut rdr = BufReader::new(&*client);
+    let mut line = String::new();
+    if rdr.read_line(&mut line)? == 0 {
+        return Ok(false);
+    }
+    let mut request = line.split_whitespace();
+    let method = request.next().unwrap_or_default();
+    let path = request.next().unwrap_or_default().trim_start_matches('/');
+    let headers = HttpHeaders::create_from(rdr)?.headers;
+    let v2 = headers
+        .get_header("Ntrip-Version")
+        .is_some_and(|v| v.contains("Ntrip/2.0"));
+    log::debug!("NTRIP request: {} v2: {v2}", line.trim_end());
+
+    if method != "GET" {
+        respond(
+            client,
+            v2,
+            "405 Method Not Allowed",
+            HttpHeaders::new_empty(),
+        )?;
+        return Ok(false);
+    }
+    let Some(stream) = table.find_stream(path) else {
+        if v2 && !path.is_empty() {
+            respond(client, v2, "404 Not Found", HttpHeaders::new_empty())?;
+        } else {
+            send_sourcetable(client, v2, table)?;
+        }
+        return Ok(false);
+    };
+    if let Some(expected) = authorization {
+        if headers.get_header("Authorization").map(String::as_str) != Some(expected) {
+            let mut hdrs = HttpHeaders::new_empty();
+            hdrs.add_header(
+                "WWW-Authenticate",
+                format!("Basic realm=\"/{}\"", stream.mountpoint),
+            );
+            respond(client, v2, "401 Unauthorized", hdrs)?;
+            return Ok(false);
+        }
+    }
+    if v2 {
+        let mut hdrs = HttpHeaders::new_empty();
+        hdrs.add_header("Content-Type", "gnss/data");
+        hdrs.add_header("Cache-Control", "no-store, no-cache, max-age=0");
+        respond(client, v2, "200 OK", hdrs)?;
+    } else {
+        write!(client, "ICY 200 OK\r\n")?;
+    }
+    client.set_read_timeout(None)?;
+    log::info!("NTRIP client connected to {}", stream.mountpoint);
+    Ok(true)
+}
+
+fn respond(
+    client: &mut TcpStream,
+    v2: bool,
+    status: &str,
+    mut headers: HttpHeaders,
+) -> Result<(), std::io::Error> {
+    headers.add_header("Server", CASTER_SERVER);
+    headers.add_header("Connection", "close");
+    if v2 {
+        headers.add_header("Ntrip-Version", "Ntrip/2.0");
+        write!(client, "HTTP/1.1 {status}\r\n")?;
+    } else {
+        write!(client, "HTTP/1.0 {status}\r\n")?;
+    }
+    headers.write_to(client)?;
+    client.flush()
+}
+
+fn send_sourcetable(
+    client: &mut TcpStream,
+    v2: bool,
+    table: &SourceTable,
+) -> Result<(), std::io::Error> {
+    let body = table.to_string();
+    let mut headers = HttpHeaders::new_empty();
+    headers.add_header("Content-Length", body.len().to_string());
+    if v2 {
+        headers.add_header("Content-Type", "gnss/sourcetable");
+        respond(client, v2, "200 OK", headers)?;
+    } else {
+        headers.add_header("Server", CASTER_SERVER);
+        headers.add_header("Content-Type", "text/plain");
+        write!(client, "SOURCETABLE 200 OK\r\n")?;
+        headers.write_to(client)?;
+    }
+    client.write_all(body.as_bytes())?;
+    client.flush()
+}
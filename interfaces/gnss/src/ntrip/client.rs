@@ -0,0 +1,222 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use crate::ntrip::{NtripVersion, SourceTable, DEFAULT_PORT};
+use core::time::Duration;
+use irox_bits::{Bits, BitsError, BitsErrorKind, BitsWrapper, Error, MutBits};
+use irox_log::log;
+use irox_networking::http::{HttpCodes, HttpRequest, HttpResponse, HttpVersion};
+use irox_networking::url::URLBuilder;
+use irox_nmea0183::gga::GGA;
+use irox_tools::packetio::Packet;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+/// Default user agent, casters require it to start with `NTRIP`
+pub const DEFAULT_USER_AGENT: &str = "NTRIP irox-gnss/0.1.0";
+
+///
+/// Connects to an NTRIP caster to retrieve the sourcetable or stream corrections from a
+/// mountpoint.
+#[derive(Debug, Clone)]
+pub struct NtripClient {
+    host: String,
+    port: u16,
+    credentials: Option<(String, String)>,
+    version: NtripVersion,
+    user_agent: String,
+}
+
+impl NtripClient {
+    pub fn new<T: AsRef<str>>(host: T, port: u16) -> Self {
+        NtripClient {
+            host: host.as_ref().to_string(),
+            port,
+            credentials: None,
+            version: NtripVersion::default(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+        }
+    }
+
+    ///
+    /// Client for the caster on the [`DEFAULT_PORT`]
+    pub fn new_default_port<T: AsRef<str>>(host: T) -> Self {
+        Self::new(host, DEFAULT_PORT)
+    }
+
+    /// Username & password for HTTP basic authentication
+    #[must_use]
+    pub fn with_credentials<U: AsRef<str>, P: AsRef<str>>(
+        mut self,
+        username: U,
+        password: P,
+    ) -> Self {
+        self.credentials = Some((username.as_ref().to_string(), password.as_ref().to_string()));
+        self
+    }
+
+    #[must_use]
+    pub fn with_version(mut self, version: NtripVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    #[must_use]
+    pub fn with_user_agent<T: AsRef<str>>(mut self, user_agent: T) -> Self {
+        self.user_agent = user_agent.as_ref().to_string();
+        self
+    }
+
+    fn request(&self, path: &str) -> Result<(HttpResponse, TcpStream), Error> {
+        let stream = TcpStream::connect((self.host.as_str(), self.port))?;
+        let mut upstream = stream.try_clone()?;
+
+        let mut url = URLBuilder::new("http", &self.host);
+        url.with_port(self.port).with_path(path);
+        let mut req = HttpRequest::new(url.build());
+        let headers = req.headers_mut();
+        headers.add_header("User-Agent", &self.user_agent);
+        if let Some((username, password)) = &self.credentials {
+            let creds = irox_tools::base64::base64_encode_to_str(
+                format!("{username}:{password}").as_bytes(),
+            )?;
+            headers.add_header("Authorization", format!("Basic {creds}"));
+        }
+        match self.version {
+            NtripVersion::V1 => req.set_version(HttpVersion::Http1_0),
+            NtripVersion::V2 => {
+                req.headers_mut().add_header("Ntrip-Version", "Ntrip/2.0");
+                req.set_version(HttpVersion::Http1_1);
+            }
+        }
+        req.write_to(&mut upstream)?;
+
+        let resp = HttpResponse::create_streaming_from(stream)?;
+        log::debug!("NTRIP response: {} {}", resp.code(), resp.status());
+        Ok((resp, upstream))
+    }
+
+    ///
+    /// Retrieves the list of streams available from the caster.
+    pub fn sourcetable(&self) -> Result<SourceTable, Error> {
+        let (resp, _upstream) = self.request("/")?;
+        let is_table = match resp.version() {
+            "SOURCETABLE" => resp.code() == "200",
+            _ => {
+                resp.http_code() == Some(HttpCodes::Success_200_Ok)
+                    && resp.headers().get_header("Content-Type").is_some_and(|c| {
+                        c.starts_with("gnss/sourcetable") || c.starts_with("text/")
+                    })
+            }
+        };
+        if !is_table {
+            return Err(status_error(&resp));
+        }
+        let mut table = String::new();
+        resp.body_read().read_to_string(&mut table)?;
+        Ok(SourceTable::parse(&table))
+    }
+
+    ///
+    /// Connects to the mountpoint, returning the stream of corrections.
+    pub fn connect<T: AsRef<str>>(&self, mountpoint: T) -> Result<NtripStream, Error> {
+        let mountpoint = mountpoint.as_ref().trim_start_matches('/');
+        let (resp, upstream) = self.request(&format!("/{mountpoint}"))?;
+        let is_data = match resp.version() {
+            "ICY" => resp.code() == "200",
+            "SOURCETABLE" => false,
+            _ => {
+                resp.http_code() == Some(HttpCodes::Success_200_Ok)
+                    && !resp
+                        .headers()
+                        .get_header("Content-Type")
+                        .is_some_and(|c| c.starts_with("gnss/sourcetable"))
+            }
+        };
+        if !is_data {
+            return Err(status_error(&resp));
+        }
+        log::info!("Connected to NTRIP mountpoint {mountpoint}");
+        Ok(NtripStream {
+            body: resp.body_read(),
+            upstream,
+        })
+    }
+}
+
+fn status_error(resp: &HttpResponse) -> BitsError {
+    let (kind, msg) = match (resp.version(), resp.http_code()) {
+        // v1 casters respond with the sourcetable for unknown mountpoints.
+        ("SOURCETABLE", _) | (_, Some(HttpCodes::ClientError_404_NotFound)) => (
+            BitsErrorKind::NotFound,
+            "Mountpoint not found on the NTRIP caster",
+        ),
+        (
+            _,
+            Some(HttpCodes::ClientError_401_Unauthorized | HttpCodes::ClientError_403_Forbidden),
+        ) => (
+            BitsErrorKind::PermissionDenied,
+            "NTRIP caster rejected the credentials",
+        ),
+        _ => (
+            BitsErrorKind::InvalidData,
+            "Unexpected response from NTRIP caster",
+        ),
+    };
+    log::info!(
+        "NTRIP request failed: {} {} {}",
+        resp.version(),
+        resp.code(),
+        resp.status()
+    );
+    BitsError::new(kind, msg)
+}
+
+///
+/// An open stream of corrections from a mountpoint.  Reading returns the raw correction data,
+/// usually RTCM3, with any HTTP transfer encoding removed.
+pub struct NtripStream {
+    body: Box<dyn Read>,
+    upstream: TcpStream,
+}
+
+impl NtripStream {
+    ///
+    /// Sends the position of the rover back to the caster, required for network (VRS)
+    /// mountpoints, usually every 10 seconds or so.
+    pub fn send_gga(&mut self, gga: &GGA) -> Result<(), Error> {
+        self.send_nmea(&gga.get_bytes()?)
+    }
+
+    ///
+    /// Sends a raw NMEA sentence back to the caster, adding the line ending if missing.
+    pub fn send_nmea(&mut self, sentence: &[u8]) -> Result<(), Error> {
+        self.upstream.write_all(sentence)?;
+        if !sentence.ends_with(b"\r\n") {
+            self.upstream.write_all(b"\r\n")?;
+        }
+        Write::flush(&mut self.upstream)?;
+        Ok(())
+    }
+
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<(), Error> {
+        Ok(self.upstream.set_read_timeout(timeout)?)
+    }
+}
+
+impl Read for NtripStream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.body.read(buf)
+    }
+}
+
+impl Bits for NtripStream {
+    fn next_u8(&mut self) -> Result<Option<u8>, Error> {
+        BitsWrapper::Borrowed(self).next_u8()
+    }
+
+    fn read_some_into<T: MutBits>(&mut self, buf: &mut T) -> Result<usize, Error> {
+        BitsWrapper::Borrowed(self).read_some_into(buf)
+    }
+}
@@ -0,0 +1,503 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! GPS satellite orbit propagation from the broadcast ephemeris and almanac (IS-GPS-200
+//! section 20.3.3), and satellite look angles from a receiver position, for sky plots and
+//! predicting coverage.
+//!
+
+use alloc::vec::Vec;
+use irox_carto::coordinate::{AbsoluteCoordinateType, CartesianCoordinate, EllipticalCoordinate};
+use irox_carto::error::ConvertError;
+use irox_carto::local::LocalENU;
+use irox_carto::position_type::{AERPosition, ECEFPosition};
+use irox_time::epoch::GPSTimestamp;
+use irox_units::units::angle::Angle;
+
+pub use lnav::*;
+
+mod lnav;
+
+/// WGS84 value of the earth's gravitational constant for GPS, m³/s²
+pub const GM: f64 = 3.986_005e14;
+/// WGS84 value of the earth's rotation rate, radians/second
+pub const EARTH_ROTATION_RATE: f64 = 7.292_115_146_7e-5;
+/// The value of pi used by the GPS control segment, the number of radians in a semi-circle
+#[allow(clippy::approx_constant)]
+pub const SEMI_CIRCLE: f64 = 3.141_592_653_589_8;
+/// Relativistic clock correction constant, `-2 sqrt(GM) / c²`, seconds/sqrt(meters)
+pub const RELATIVISTIC_F: f64 = -4.442_807_633e-10;
+/// Reference inclination of the almanac orbits, 0.30 semi-circles
+pub const ALMANAC_INCLINATION: f64 = 0.3 * SEMI_CIRCLE;
+pub const SECONDS_PER_WEEK: f64 = 604_800.;
+
+///
+/// Creates a GPS timestamp from the full week number and time-of-week seconds
+#[must_use]
+pub fn gps_time(week: u16, time_of_week: f64) -> GPSTimestamp {
+    GPSTimestamp::from_seconds_f64(f64::from(week) * SECONDS_PER_WEEK + time_of_week)
+}
+
+///
+/// Returns the full week number and time-of-week seconds of the GPS timestamp
+#[must_use]
+pub fn week_and_tow(time: &GPSTimestamp) -> (u16, f64) {
+    let seconds = time.get_offset().as_seconds_f64();
+    let week = (seconds / SECONDS_PER_WEEK).floor();
+    (week as u16, seconds - week * SECONDS_PER_WEEK)
+}
+
+///
+/// Resolves a week number transmitted modulo `modulus` to the full week closest to `reference`
+#[must_use]
+pub fn resolve_week(truncated: u16, modulus: u16, reference: u16) -> u16 {
+    let modulus = i32::from(modulus);
+    let reference = i32::from(reference);
+    let base = reference - reference.rem_euclid(modulus) + i32::from(truncated) % modulus;
+    [base - modulus, base, base + modulus]
+        .into_iter()
+        .filter(|w| *w >= 0)
+        .min_by_key(|w| (w - reference).abs())
+        .unwrap_or(base) as u16
+}
+
+/// Seconds from the reference time-of-week in the week to the time
+fn seconds_from(time: &GPSTimestamp, week: u16, time_of_week: f64) -> f64 {
+    time.get_offset().as_seconds_f64() - (f64::from(week) * SECONDS_PER_WEEK + time_of_week)
+}
+
+/// Keplerian elements with harmonic corrections, common to the ephemeris and almanac.
+struct Elements {
+    sqrt_a: f64,
+    eccentricity: f64,
+    m0: f64,
+    delta_n: f64,
+    omega: f64,
+    omega0: f64,
+    omega_dot: f64,
+    i0: f64,
+    idot: f64,
+    toe: f64,
+    cuc: f64,
+    cus: f64,
+    crc: f64,
+    crs: f64,
+    cic: f64,
+    cis: f64,
+}
+
+impl Elements {
+    /// Returns the ECEF position `tk` seconds from the reference time, and the eccentric anomaly
+    fn position(&self, tk: f64) -> ([f64; 3], f64) {
+        let a = self.sqrt_a * self.sqrt_a;
+        let n = (GM / (a * a * a)).sqrt() + self.delta_n;
+        let mk = self.m0 + n * tk;
+        let e = self.eccentricity;
+        let mut ek = mk;
+        for _ in 0..20 {
+            let delta = (ek - e * ek.sin() - mk) / (1. - e * ek.cos());
+            ek -= delta;
+            if delta.abs() < 1e-14 {
+                break;
+            }
+        }
+        let (sin_e, cos_e) = ek.sin_cos();
+        let vk = ((1. - e * e).sqrt() * sin_e).atan2(cos_e - e);
+        let phik = vk + self.omega;
+        let (sin2p, cos2p) = (2. * phik).sin_cos();
+        let uk = phik + self.cus * sin2p + self.cuc * cos2p;
+        let rk = a * (1. - e * cos_e) + self.crs * sin2p + self.crc * cos2p;
+        let ik = self.i0 + self.idot * tk + self.cis * sin2p + self.cic * cos2p;
+        let (sin_u, cos_u) = uk.sin_cos();
+        let (xp, yp) = (rk * cos_u, rk * sin_u);
+        let omegak = self.omega0 + (self.omega_dot - EARTH_ROTATION_RATE) * tk
+            - EARTH_ROTATION_RATE * self.toe;
+        let (sin_o, cos_o) = omegak.sin_cos();
+        let (sin_i, cos_i) = ik.sin_cos();
+        (
+            [
+                xp * cos_o - yp * cos_i * sin_o,
+                xp * sin_o + yp * cos_i * cos_o,
+                yp * sin_i,
+            ],
+            ek,
+        )
+    }
+}
+
+///
+/// The position and clock of a satellite at a time
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SatelliteState {
+    pub prn: u8,
+    pub time: GPSTimestamp,
+    /// WGS84 ECEF position of the satellite
+    pub position: ECEFPosition,
+    /// Offset of the satellite clock from GPS time, seconds, including the relativistic
+    /// correction and, for single-frequency L1 users, the group delay.  Subtract from the
+    /// satellite transmit time to get GPS time.
+    pub clock_correction: f64,
+}
+
+///
+/// A source of satellite positions
+pub trait SatelliteOrbit {
+    fn prn(&self) -> u8;
+
+    fn is_healthy(&self) -> bool;
+
+    /// Propagates the orbit to the GPS time
+    fn satellite_state(&self, time: &GPSTimestamp) -> SatelliteState;
+}
+
+///
+/// The complete broadcast ephemeris and clock correction of a satellite, from subframes 1-3.
+/// Angles are radians.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Ephemeris {
+    pub prn: u8,
+    /// Full GPS week of the reference times `toe` and `toc`
+    pub week: u16,
+    pub ura_index: u8,
+    pub health: u8,
+    pub iodc: u16,
+    pub iode: u8,
+    /// L1-L2 group delay, seconds
+    pub tgd: f64,
+    /// Clock data reference time-of-week, seconds
+    pub toc: f64,
+    pub af0: f64,
+    pub af1: f64,
+    pub af2: f64,
+    /// meters
+    pub crs: f64,
+    /// radians/second
+    pub delta_n: f64,
+    pub m0: f64,
+    pub cuc: f64,
+    pub eccentricity: f64,
+    pub cus: f64,
+    /// sqrt(meters)
+    pub sqrt_a: f64,
+    /// Ephemeris reference time-of-week, seconds
+    pub toe: f64,
+    /// False for the standard 4 hour fit interval, true if longer.
+    pub fit_interval: bool,
+    pub cic: f64,
+    pub omega0: f64,
+    pub cis: f64,
+    pub i0: f64,
+    /// meters
+    pub crc: f64,
+    pub omega: f64,
+    /// radians/second
+    pub omega_dot: f64,
+    /// radians/second
+    pub idot: f64,
+}
+
+impl Ephemeris {
+    ///
+    /// The time the ephemeris parameters refer to
+    #[must_use]
+    pub fn reference_time(&self) -> GPSTimestamp {
+        gps_time(self.week, self.toe)
+    }
+
+    ///
+    /// Returns true if the time is within the curve fit interval of the ephemeris, centered on
+    /// the reference time.
+    #[must_use]
+    pub fn is_valid_at(&self, time: &GPSTimestamp) -> bool {
+        let hours: f64 = if self.fit_interval { 6. } else { 4. };
+        seconds_from(time, self.week, self.toe).abs() <= hours * 1800.
+    }
+
+    fn elements(&self) -> Elements {
+        Elements {
+            sqrt_a: self.sqrt_a,
+            eccentricity: self.eccentricity,
+            m0: self.m0,
+            delta_n: self.delta_n,
+            omega: self.omega,
+            omega0: self.omega0,
+            omega_dot: self.omega_dot,
+            i0: self.i0,
+            idot: self.idot,
+            toe: self.toe,
+            cuc: self.cuc,
+            cus: self.cus,
+            crc: self.crc,
+            crs: self.crs,
+            cic: self.cic,
+            cis: self.cis,
+        }
+    }
+}
+
+impl SatelliteOrbit for Ephemeris {
+    fn prn(&self) -> u8 {
+        self.prn
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.health == 0
+    }
+
+    fn satellite_state(&self, time: &GPSTimestamp) -> SatelliteState {
+        let tk = seconds_from(time, self.week, self.toe);
+        let ([x, y, z], ek) = self.elements().position(tk);
+        let dt = seconds_from(time, self.week, self.toc);
+        let relativistic = RELATIVISTIC_F * self.eccentricity * self.sqrt_a * ek.sin();
+        SatelliteState {
+            prn: self.prn,
+            time: *time,
+            position: ECEFPosition(CartesianCoordinate::new_meters(x, y, z)),
+            clock_correction: self.af0 + self.af1 * dt + self.af2 * dt * dt + relativistic
+                - self.tgd,
+        }
+    }
+}
+
+///
+/// The reduced-precision almanac of a satellite, from subframes 4 and 5.  Angles are radians.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Almanac {
+    pub prn: u8,
+    pub health: u8,
+    /// Full GPS week of the reference time `toa`
+    pub week: u16,
+    /// Almanac reference time-of-week, seconds
+    pub toa: f64,
+    pub eccentricity: f64,
+    /// Inclination offset from [`ALMANAC_INCLINATION`]
+    pub delta_i: f64,
+    /// radians/second
+    pub omega_dot: f64,
+    /// sqrt(meters)
+    pub sqrt_a: f64,
+    pub omega0: f64,
+    pub omega: f64,
+    pub m0: f64,
+    /// seconds
+    pub af0: f64,
+    /// seconds/second
+    pub af1: f64,
+}
+
+impl Almanac {
+    ///
+    /// The time the almanac parameters refer to
+    #[must_use]
+    pub fn reference_time(&self) -> GPSTimestamp {
+        gps_time(self.week, self.toa)
+    }
+}
+
+impl SatelliteOrbit for Almanac {
+    fn prn(&self) -> u8 {
+        self.prn
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.health == 0
+    }
+
+    fn satellite_state(&self, time: &GPSTimestamp) -> SatelliteState {
+        let tk = seconds_from(time, self.week, self.toa);
+        let elements = Elements {
+            sqrt_a: self.sqrt_a,
+            eccentricity: self.eccentricity,
+            m0: self.m0,
+            delta_n: 0.,
+            omega: self.omega,
+            omega0: self.omega0,
+            omega_dot: self.omega_dot,
+            i0: ALMANAC_INCLINATION + self.delta_i,
+            idot: 0.,
+            toe: self.toa,
+            cuc: 0.,
+            cus: 0.,
+            crc: 0.,
+            crs: 0.,
+            cic: 0.,
+            cis: 0.,
+        };
+        let ([x, y, z], _) = elements.position(tk);
+        SatelliteState {
+            prn: self.prn,
+            time: *time,
+            position: ECEFPosition(CartesianCoordinate::new_meters(x, y, z)),
+            clock_correction: self.af0 + self.af1 * tk,
+        }
+    }
+}
+
+///
+/// A satellite as seen from the receiver
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SkyPosition {
+    pub state: SatelliteState,
+    pub look_angles: AERPosition,
+}
+
+///
+/// The sky as seen from a receiver position, in the local ENU frame of the receiver.
+pub struct SkyView {
+    frame: LocalENU,
+}
+
+impl SkyView {
+    ///
+    /// Creates a view from the receiver position.  The altitude, if any, must be relative to the
+    /// ellipsoid.
+    pub fn new(receiver: &EllipticalCoordinate) -> Result<SkyView, ConvertError> {
+        Ok(SkyView {
+            frame: LocalENU::new(AbsoluteCoordinateType::Elliptical(*receiver))?,
+        })
+    }
+
+    ///
+    /// The azimuth, elevation and range from the receiver to the satellite
+    pub fn look_angles(&self, satellite: &ECEFPosition) -> Result<AERPosition, ConvertError> {
+        Ok(self.frame.ecef_to_enu(satellite)?.into())
+    }
+
+    ///
+    /// Returns the healthy satellites above the elevation mask at the time, in the order given.
+    pub fn visible<'a, O: SatelliteOrbit + 'a, I: IntoIterator<Item = &'a O>>(
+        &self,
+        orbits: I,
+        time: &GPSTimestamp,
+        elevation_mask: Angle,
+    ) -> Result<Vec<SkyPosition>, ConvertError> {
+        let mask = elevation_mask.as_radians().value();
+        let mut out = Vec::new();
+        for orbit in orbits {
+            if !orbit.is_healthy() {
+                continue;
+            }
+            let state = orbit.satellite_state(time);
+            let look_angles = self.look_angles(&state.position)?;
+            if look_angles.elevation().0.as_radians().value() >= mask {
+                out.push(SkyPosition { state, look_angles });
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::orbit::{
+        gps_time, resolve_week, week_and_tow, Almanac, Ephemeris, SatelliteOrbit, SkyView,
+        ALMANAC_INCLINATION, EARTH_ROTATION_RATE, SEMI_CIRCLE,
+    };
+    use irox_carto::coordinate::EllipticalCoordinate;
+    use irox_carto::error::ConvertError;
+    use irox_tools::assert_eq_eps;
+    use irox_units::units::angle::Angle;
+
+    fn magnitude(state: &crate::orbit::SatelliteState) -> f64 {
+        let p = &state.position.0;
+        let x = p.get_x().as_meters().value();
+        let y = p.get_y().as_meters().value();
+        let z = p.get_z().as_meters().value();
+        (x * x + y * y + z * z).sqrt()
+    }
+
+    #[test]
+    pub fn test_weeks() {
+        assert_eq!(2046, resolve_week(1022, 1024, 2000));
+        assert_eq!(2048, resolve_week(0, 1024, 2040));
+        assert_eq!(1023, resolve_week(1023, 1024, 1030));
+        assert_eq!(2256, resolve_week(208, 256, 2250));
+        let time = gps_time(2360, 345_600.5);
+        assert_eq!((2360, 345_600.5), week_and_tow(&time));
+    }
+
+    #[test]
+    pub fn test_overhead() -> Result<(), ConvertError> {
+        // an equatorial orbit, over 0,0 at the reference time
+        let toe = 7200.;
+        let eph = Ephemeris {
+            prn: 1,
+            week: 2360,
+            sqrt_a: 5153.7,
+            toe,
+            toc: toe,
+            omega0: EARTH_ROTATION_RATE * toe,
+            af0: 1e-5,
+            af1: 1e-11,
+            ..Default::default()
+        };
+        let a = 5153.7f64 * 5153.7;
+        let time = eph.reference_time();
+        let state = eph.satellite_state(&time);
+        assert_eq_eps!(a, state.position.0.get_x().as_meters().value(), 1e-6);
+        assert_eq_eps!(0., state.position.0.get_y().as_meters().value(), 1e-6);
+        assert_eq_eps!(1e-5, state.clock_correction, 1e-15);
+
+        let view = SkyView::new(&EllipticalCoordinate::new_degrees_wgs84(0., 0.))?;
+        let look = view.look_angles(&state.position)?;
+        assert_eq_eps!(90., look.elevation().0.as_degrees().value(), 1e-6);
+        assert_eq_eps!(a - 6_378_137., look.range().as_meters().value(), 1e-3);
+
+        // half an orbit later, it's well below the horizon.
+        let period = core::f64::consts::TAU * (a * a * a / crate::orbit::GM).sqrt();
+        let later = gps_time(2360, toe + period / 2.);
+        // ~6 hours is outside the 4 hour fit interval.
+        assert!(!eph.is_valid_at(&later));
+        assert!(eph.is_valid_at(&gps_time(2360, toe + 7200.)));
+        let state = eph.satellite_state(&later);
+        assert_eq_eps!(a, magnitude(&state), 1e-3);
+        assert_eq_eps!(1e-5 + 1e-11 * period / 2., state.clock_correction, 1e-15);
+        let visible = view.visible([eph].iter(), &later, Angle::new_degrees(5.))?;
+        assert!(visible.is_empty());
+        let visible = view.visible([eph].iter(), &time, Angle::new_degrees(5.))?;
+        assert_eq!(1, visible.len());
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_almanac_matches_ephemeris() {
+        let alm = Almanac {
+            prn: 7,
+            week: 2360,
+            toa: 61440.,
+            eccentricity: 0.01,
+            delta_i: 0.02 * SEMI_CIRCLE,
+            omega_dot: -8e-9,
+            sqrt_a: 5153.6,
+            omega0: 1.2,
+            omega: -2.1,
+            m0: 0.7,
+            ..Default::default()
+        };
+        let eph = Ephemeris {
+            prn: 7,
+            week: 2360,
+            toe: alm.toa,
+            toc: alm.toa,
+            eccentricity: alm.eccentricity,
+            i0: ALMANAC_INCLINATION + alm.delta_i,
+            omega_dot: alm.omega_dot,
+            sqrt_a: alm.sqrt_a,
+            omega0: alm.omega0,
+            omega: alm.omega,
+            m0: alm.m0,
+            ..Default::default()
+        };
+        let a = alm.sqrt_a * alm.sqrt_a;
+        for hours in [-3., 0., 1., 2.5, 24., 168.] {
+            let time = gps_time(2360, alm.toa + hours * 3600.);
+            let from_alm = alm.satellite_state(&time);
+            let from_eph = eph.satellite_state(&time);
+            assert_eq!(from_alm.position, from_eph.position);
+            let r = magnitude(&from_alm);
+            assert!(r >= a * 0.99 - 1e-3 && r <= a * 1.01 + 1e-3, "{r}");
+        }
+    }
+}
@@ -0,0 +1,720 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! GPS LNAV (legacy navigation message) subframe decoding, IS-GPS-200 section 20.3.
+
+use alloc::collections::BTreeMap;
+use irox_bits::{BitsError, BitsErrorKind};
+use irox_sirf::input::x08_50bpsdata::FiftyBPSData;
+use irox_sirf::input::x38_extephemeris::GPSDataEphemerisMask;
+
+use crate::orbit::{resolve_week, Almanac, Ephemeris, SECONDS_PER_WEEK, SEMI_CIRCLE};
+
+/// The TLM word preamble, `0b1000_1011`
+pub const PREAMBLE: u32 = 0x8B;
+
+/// Weeks are resolved to within 512 weeks of this week (2025-04-06) until a full week is known
+pub const DEFAULT_REFERENCE_WEEK: u16 = 2360;
+
+/// Data bits of each parity bit D25..D30, as a mask of `d1..d24` with `d1` the MSB.
+const PARITY_MASKS: [u32; 6] = [
+    0xEC7CD2, // D25: 1 2 3 5 6 10 11 12 13 14 17 18 20 23
+    0x763E69, // D26: 2 3 4 6 7 11 12 13 14 15 18 19 21 24
+    0xBB1F34, // D27: 1 3 4 5 7 8 12 13 14 15 16 19 20 22
+    0x5D8F9A, // D28: 2 4 5 6 8 9 13 14 15 16 17 20 21 23
+    0xAEC7CD, // D29: 1 3 5 6 7 9 10 14 15 16 17 18 21 22 24
+    0x2DEA27, // D30: 3 5 6 8 9 10 11 13 15 19 22 23 24
+];
+
+fn parity(data: u32, d29_star: bool, d30_star: bool) -> u32 {
+    let mut out = 0;
+    for (idx, mask) in PARITY_MASKS.iter().enumerate() {
+        let star = match idx {
+            0 | 2 | 5 => d29_star,
+            _ => d30_star,
+        };
+        let bit = ((data & mask).count_ones() & 0x1) ^ u32::from(star);
+        out = (out << 1) | bit;
+    }
+    out
+}
+
+///
+/// Decodes a raw navigation word with `D29*` and `D30*` of the previous word in bits 31 and 30,
+/// and the 30-bit word in bits 29..0, returning the 24 data bits `d1..d24`, with `d1` the MSB.
+pub fn decode_word(raw: u32) -> Result<u32, BitsError> {
+    let d29_star = raw & 0x8000_0000 != 0;
+    let d30_star = raw & 0x4000_0000 != 0;
+    let mut data = (raw >> 6) & 0xFF_FFFF;
+    if d30_star {
+        data ^= 0xFF_FFFF;
+    }
+    if parity(data, d29_star, d30_star) != raw & 0x3F {
+        return Err(BitsError::new(
+            BitsErrorKind::InvalidData,
+            "LNAV word parity mismatch",
+        ));
+    }
+    Ok(data)
+}
+
+///
+/// Encodes the 24 data bits into a raw navigation word following the `previous` raw word, in the
+/// format accepted by [`decode_word`].
+#[must_use]
+pub fn encode_word(data: u32, previous: u32) -> u32 {
+    let d29_star = previous & 0x2 != 0;
+    let d30_star = previous & 0x1 != 0;
+    let data = data & 0xFF_FFFF;
+    let mut out = parity(data, d29_star, d30_star);
+    out |= if d30_star { data ^ 0xFF_FFFF } else { data } << 6;
+    if d29_star {
+        out |= 0x8000_0000;
+    }
+    if d30_star {
+        out |= 0x4000_0000;
+    }
+    out
+}
+
+/// The data bits `d[start]..d[start+len-1]` (1-based) of the word
+fn bits(word: u32, start: u32, len: u32) -> u32 {
+    (word >> (25 - start - len)) & ((1 << len) - 1)
+}
+
+/// Sign-extends the lowest `len` bits
+fn signed(value: u32, len: u32) -> f64 {
+    let shift = 32 - len;
+    f64::from(((value << shift) as i32) >> shift)
+}
+
+fn scaled(value: u32, exp: i32) -> f64 {
+    f64::from(value) * 2f64.powi(exp)
+}
+
+fn scaled_signed(value: u32, len: u32, exp: i32) -> f64 {
+    signed(value, len) * 2f64.powi(exp)
+}
+
+/// Joins the last 8 bits of the first word with the 24 bits of the second
+fn join(msb: u32, lsb: u32) -> u32 {
+    (bits(msb, 17, 8) << 24) | lsb
+}
+
+///
+/// Telemetry (TLM) and Handover (HOW) words, common to each subframe
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct SubframeHeader {
+    pub tlm_message: u16,
+    pub integrity_status: bool,
+    /// Truncated time-of-week count, 6 second units, of the start of the next subframe
+    pub tow_count: u32,
+    pub alert: bool,
+    pub anti_spoof: bool,
+    pub subframe_id: u8,
+}
+
+impl SubframeHeader {
+    /// The time-of-week of the start of this subframe, seconds
+    #[must_use]
+    pub fn time_of_week(&self) -> f64 {
+        let tow = f64::from(self.tow_count) * 6. - 6.;
+        if tow < 0. {
+            tow + SECONDS_PER_WEEK
+        } else {
+            tow
+        }
+    }
+}
+
+///
+/// Subframe 1 - week number, health, and the satellite clock correction
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct ClockParameters {
+    /// Week number, modulo 1024
+    pub week: u16,
+    pub l2_codes: u8,
+    pub ura_index: u8,
+    pub health: u8,
+    pub l2p_data: bool,
+    pub iodc: u16,
+    /// L1-L2 group delay, seconds
+    pub tgd: f64,
+    /// Clock data reference time-of-week, seconds
+    pub toc: f64,
+    /// seconds/second²
+    pub af2: f64,
+    /// seconds/second
+    pub af1: f64,
+    /// seconds
+    pub af0: f64,
+}
+
+///
+/// Subframe 2 - the first half of the ephemeris.  Angles are radians.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct EphemerisPartOne {
+    pub iode: u8,
+    /// meters
+    pub crs: f64,
+    /// radians/second
+    pub delta_n: f64,
+    pub m0: f64,
+    /// radians
+    pub cuc: f64,
+    pub eccentricity: f64,
+    /// radians
+    pub cus: f64,
+    /// sqrt(meters)
+    pub sqrt_a: f64,
+    /// Ephemeris reference time-of-week, seconds
+    pub toe: f64,
+    pub fit_interval: bool,
+    pub aodo: u8,
+}
+
+///
+/// Subframe 3 - the second half of the ephemeris.  Angles are radians.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct EphemerisPartTwo {
+    pub cic: f64,
+    pub omega0: f64,
+    pub cis: f64,
+    pub i0: f64,
+    /// meters
+    pub crc: f64,
+    pub omega: f64,
+    /// radians/second
+    pub omega_dot: f64,
+    pub iode: u8,
+    /// radians/second
+    pub idot: f64,
+}
+
+///
+/// Subframe 5 page 25 - the almanac reference time and week
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct AlmanacReference {
+    /// Almanac reference time-of-week, seconds
+    pub toa: f64,
+    /// Almanac week number, modulo 256
+    pub week: u8,
+}
+
+///
+/// The decoded contents of a subframe
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SubframeData {
+    Clock(ClockParameters),
+    EphemerisPartOne(EphemerisPartOne),
+    EphemerisPartTwo(EphemerisPartTwo),
+    /// An almanac page of subframes 4 or 5, with the week not yet resolved
+    Almanac(Almanac),
+    AlmanacReference(AlmanacReference),
+    /// A page of subframes 4 or 5 that isn't decoded
+    OtherPage {
+        data_id: u8,
+        sv_id: u8,
+    },
+}
+
+///
+/// A decoded LNAV subframe
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Subframe {
+    pub header: SubframeHeader,
+    pub data: SubframeData,
+}
+
+impl Subframe {
+    ///
+    /// Decodes the 10 raw words of a subframe, see [`decode_word`] for the format of each word.
+    pub fn decode(raw: &[u32; 10]) -> Result<Subframe, BitsError> {
+        let mut words = [0u32; 10];
+        for (word, raw) in words.iter_mut().zip(raw) {
+            *word = decode_word(*raw)?;
+        }
+        Self::decode_data(&words)
+    }
+
+    ///
+    /// Decodes the 10 words of 24 data bits of a subframe, after parity has been checked and
+    /// removed.
+    pub fn decode_data(words: &[u32; 10]) -> Result<Subframe, BitsError> {
+        let [w1, w2, w3, w4, w5, w6, w7, w8, w9, w10] = *words;
+        if bits(w1, 1, 8) != PREAMBLE {
+            return Err(BitsError::new(
+                BitsErrorKind::InvalidData,
+                "Missing LNAV preamble",
+            ));
+        }
+        let header = SubframeHeader {
+            tlm_message: bits(w1, 9, 14) as u16,
+            integrity_status: bits(w1, 23, 1) == 1,
+            tow_count: bits(w2, 1, 17),
+            alert: bits(w2, 18, 1) == 1,
+            anti_spoof: bits(w2, 19, 1) == 1,
+            subframe_id: bits(w2, 20, 3) as u8,
+        };
+        let data = match header.subframe_id {
+            1 => SubframeData::Clock(ClockParameters {
+                week: bits(w3, 1, 10) as u16,
+                l2_codes: bits(w3, 11, 2) as u8,
+                ura_index: bits(w3, 13, 4) as u8,
+                health: bits(w3, 17, 6) as u8,
+                l2p_data: bits(w4, 1, 1) == 1,
+                iodc: ((bits(w3, 23, 2) << 8) | bits(w8, 1, 8)) as u16,
+                tgd: scaled_signed(bits(w7, 17, 8), 8, -31),
+                toc: scaled(bits(w8, 9, 16), 4),
+                af2: scaled_signed(bits(w9, 1, 8), 8, -55),
+                af1: scaled_signed(bits(w9, 9, 16), 16, -43),
+                af0: scaled_signed(bits(w10, 1, 22), 22, -31),
+            }),
+            2 => SubframeData::EphemerisPartOne(EphemerisPartOne {
+                iode: bits(w3, 1, 8) as u8,
+                crs: scaled_signed(bits(w3, 9, 16), 16, -5),
+                delta_n: scaled_signed(bits(w4, 1, 16), 16, -43) * SEMI_CIRCLE,
+                m0: scaled_signed(join(w4, w5), 32, -31) * SEMI_CIRCLE,
+                cuc: scaled_signed(bits(w6, 1, 16), 16, -29),
+                eccentricity: scaled(join(w6, w7), -33),
+                cus: scaled_signed(bits(w8, 1, 16), 16, -29),
+                sqrt_a: scaled(join(w8, w9), -19),
+                toe: scaled(bits(w10, 1, 16), 4),
+                fit_interval: bits(w10, 17, 1) == 1,
+                aodo: bits(w10, 18, 5) as u8,
+            }),
+            3 => SubframeData::EphemerisPartTwo(EphemerisPartTwo {
+                cic: scaled_signed(bits(w3, 1, 16), 16, -29),
+                omega0: scaled_signed(join(w3, w4), 32, -31) * SEMI_CIRCLE,
+                cis: scaled_signed(bits(w5, 1, 16), 16, -29),
+                i0: scaled_signed(join(w5, w6), 32, -31) * SEMI_CIRCLE,
+                crc: scaled_signed(bits(w7, 1, 16), 16, -5),
+                omega: scaled_signed(join(w7, w8), 32, -31) * SEMI_CIRCLE,
+                omega_dot: scaled_signed(w9, 24, -43) * SEMI_CIRCLE,
+                iode: bits(w10, 1, 8) as u8,
+                idot: scaled_signed(bits(w10, 9, 14), 14, -43) * SEMI_CIRCLE,
+            }),
+            4 | 5 => {
+                let data_id = bits(w3, 1, 2) as u8;
+                let sv_id = bits(w3, 3, 6) as u8;
+                match sv_id {
+                    1..=32 => SubframeData::Almanac(Almanac {
+                        prn: sv_id,
+                        eccentricity: scaled(bits(w3, 9, 16), -21),
+                        toa: scaled(bits(w4, 1, 8), 12),
+                        week: 0,
+                        delta_i: scaled_signed(bits(w4, 9, 16), 16, -19) * SEMI_CIRCLE,
+                        omega_dot: scaled_signed(bits(w5, 1, 16), 16, -38) * SEMI_CIRCLE,
+                        health: bits(w5, 17, 8) as u8,
+                        sqrt_a: scaled(w6, -11),
+                        omega0: scaled_signed(w7, 24, -23) * SEMI_CIRCLE,
+                        omega: scaled_signed(w8, 24, -23) * SEMI_CIRCLE,
+                        m0: scaled_signed(w9, 24, -23) * SEMI_CIRCLE,
+                        af0: scaled_signed((bits(w10, 1, 8) << 3) | bits(w10, 20, 3), 11, -20),
+                        af1: scaled_signed(bits(w10, 9, 11), 11, -38),
+                    }),
+                    51 if header.subframe_id == 5 => {
+                        SubframeData::AlmanacReference(AlmanacReference {
+                            toa: scaled(bits(w3, 9, 8), 12),
+                            week: bits(w3, 17, 8) as u8,
+                        })
+                    }
+                    _ => SubframeData::OtherPage { data_id, sv_id },
+                }
+            }
+            _ => {
+                return Err(BitsError::new(
+                    BitsErrorKind::InvalidData,
+                    "Invalid LNAV subframe ID",
+                ))
+            }
+        };
+        Ok(Subframe { header, data })
+    }
+}
+
+///
+/// A complete record decoded from the navigation message
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum NavigationRecord {
+    Ephemeris(Ephemeris),
+    Almanac(Almanac),
+}
+
+#[derive(Default)]
+struct PartialEphemeris {
+    clock: Option<ClockParameters>,
+    /// with the time-of-week it was transmitted
+    one: Option<(EphemerisPartOne, f64)>,
+    two: Option<EphemerisPartTwo>,
+    last: Option<Ephemeris>,
+}
+
+///
+/// Assembles subframes from each satellite into complete [`Ephemeris`] and [`Almanac`] records.
+/// An ephemeris is emitted once subframes 1-3 with a matching issue of data are received, and
+/// again each time it's updated.
+pub struct LNAVDecoder {
+    reference_week: u16,
+    almanac_week: Option<u8>,
+    satellites: BTreeMap<u8, PartialEphemeris>,
+}
+
+impl Default for LNAVDecoder {
+    fn default() -> Self {
+        LNAVDecoder {
+            reference_week: DEFAULT_REFERENCE_WEEK,
+            almanac_week: None,
+            satellites: BTreeMap::new(),
+        }
+    }
+}
+
+impl LNAVDecoder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Sets the full GPS week used to resolve the truncated week numbers in the message.
+    pub fn set_reference_week(&mut self, week: u16) {
+        self.reference_week = week;
+    }
+
+    #[must_use]
+    pub fn reference_week(&self) -> u16 {
+        self.reference_week
+    }
+
+    ///
+    /// Updates the reference week from the receiver time reported in a SiRF extended
+    /// ephemeris (`0x38`) message.
+    pub fn update_from_sirf_time(&mut self, time: &GPSDataEphemerisMask) {
+        if time.gps_time_valid_flag & 0x1 == 0x1 {
+            self.reference_week = time.gps_week;
+        }
+    }
+
+    ///
+    /// Decodes and assembles a subframe from a SiRF 50 BPS data (`0x08`) message.
+    pub fn push_sirf(
+        &mut self,
+        data: &FiftyBPSData,
+    ) -> Result<Option<NavigationRecord>, BitsError> {
+        self.push_raw(data.sv_id, &data.word)
+    }
+
+    ///
+    /// Decodes and assembles the raw subframe from the satellite, see [`decode_word`] for the
+    /// format of each word.
+    pub fn push_raw(
+        &mut self,
+        prn: u8,
+        words: &[u32; 10],
+    ) -> Result<Option<NavigationRecord>, BitsError> {
+        let subframe = Subframe::decode(words)?;
+        Ok(self.push(prn, &subframe))
+    }
+
+    ///
+    /// Assembles the decoded subframe from the satellite
+    pub fn push(&mut self, prn: u8, subframe: &Subframe) -> Option<NavigationRecord> {
+        match subframe.data {
+            SubframeData::Almanac(mut almanac) => {
+                almanac.week = match self.almanac_week {
+                    Some(week) => resolve_week(u16::from(week), 256, self.reference_week),
+                    None => self.reference_week,
+                };
+                return Some(NavigationRecord::Almanac(almanac));
+            }
+            SubframeData::AlmanacReference(reference) => {
+                self.almanac_week = Some(reference.week);
+                return None;
+            }
+            SubframeData::OtherPage { .. } => return None,
+            _ => {}
+        }
+        let sat = self.satellites.entry(prn).or_default();
+        match subframe.data {
+            SubframeData::Clock(clock) => {
+                self.reference_week = resolve_week(clock.week, 1024, self.reference_week);
+                sat.clock = Some(clock);
+            }
+            SubframeData::EphemerisPartOne(one) => {
+                sat.one = Some((one, subframe.header.time_of_week()));
+            }
+            SubframeData::EphemerisPartTwo(two) => sat.two = Some(two),
+            _ => {}
+        }
+        let (Some(clock), Some((one, tow)), Some(two)) = (sat.clock, sat.one, sat.two) else {
+            return None;
+        };
+        // the 8 LSBs of the IODC match the IODE of the same data set.
+        if one.iode != two.iode || (clock.iodc & 0xFF) as u8 != one.iode {
+            return None;
+        }
+        // the week number is of transmission, near the end of the week the reference time may
+        // be in the next week.
+        let mut week = resolve_week(clock.week, 1024, self.reference_week);
+        if one.toe - tow < -SECONDS_PER_WEEK / 2. {
+            week += 1;
+        }
+        let ephemeris = Ephemeris {
+            prn,
+            week,
+            ura_index: clock.ura_index,
+            health: clock.health,
+            iodc: clock.iodc,
+            iode: one.iode,
+            tgd: clock.tgd,
+            toc: clock.toc,
+            af0: clock.af0,
+            af1: clock.af1,
+            af2: clock.af2,
+            crs: one.crs,
+            delta_n: one.delta_n,
+            m0: one.m0,
+            cuc: one.cuc,
+            eccentricity: one.eccentricity,
+            cus: one.cus,
+            sqrt_a: one.sqrt_a,
+            toe: one.toe,
+            fit_interval: one.fit_interval,
+            cic: two.cic,
+            omega0: two.omega0,
+            cis: two.cis,
+            i0: two.i0,
+            crc: two.crc,
+            omega: two.omega,
+            omega_dot: two.omega_dot,
+            idot: two.idot,
+        };
+        if sat.last == Some(ephemeris) {
+            return None;
+        }
+        sat.last = Some(ephemeris);
+        Some(NavigationRecord::Ephemeris(ephemeris))
+    }
+
+    ///
+    /// The most recent complete ephemeris of the satellite
+    #[must_use]
+    pub fn ephemeris(&self, prn: u8) -> Option<&Ephemeris> {
+        self.satellites.get(&prn)?.last.as_ref()
+    }
+
+    ///
+    /// The most recent complete ephemeris of each satellite
+    pub fn ephemerides(&self) -> impl Iterator<Item = &Ephemeris> {
+        self.satellites.values().filter_map(|s| s.last.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::orbit::lnav::{
+        decode_word, encode_word, LNAVDecoder, NavigationRecord, Subframe, SubframeData,
+    };
+    use crate::orbit::SEMI_CIRCLE;
+    use irox_bits::BitsError;
+
+    /// Packs the `(value, length)` fields into 24-bit data words, starting with the HOW.
+    fn pack(fields: &[(u32, u32)]) -> [u32; 10] {
+        let mut out = [0u32; 10];
+        out[0] = 0x8B << 16;
+        let mut bit = 24;
+        for (value, len) in fields {
+            for b in (0..*len).rev() {
+                let idx = bit / 24;
+                let shift = 23 - (bit % 24);
+                out[idx] |= ((value >> b) & 0x1) << shift;
+                bit += 1;
+            }
+        }
+        out
+    }
+
+    fn encode(words: &[u32; 10]) -> [u32; 10] {
+        let mut out = [0u32; 10];
+        let mut prev = 0;
+        for (raw, data) in out.iter_mut().zip(words) {
+            *raw = encode_word(*data, prev);
+            prev = *raw;
+        }
+        out
+    }
+
+    fn sf(id: u32, words: &[(u32, u32)]) -> [u32; 10] {
+        let mut fields = vec![(1000, 17), (0, 2), (id, 3), (0, 2)];
+        fields.extend_from_slice(words);
+        encode(&pack(&fields))
+    }
+
+    #[test]
+    pub fn test_parity() -> Result<(), BitsError> {
+        for (data, prev) in [
+            (0x8B0000, 0),
+            (0x123456, 0x1),
+            (0xFFFFFF, 0x2),
+            (0xABCDEF, 0x3),
+        ] {
+            let raw = encode_word(data, prev);
+            assert_eq!(data, decode_word(raw)?);
+            assert!(decode_word(raw ^ 0x100).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn, clippy::float_cmp)]
+    pub fn test_ephemeris() -> Result<(), BitsError> {
+        let sf1 = sf(
+            1,
+            &[
+                (0x3FE, 10),
+                (1, 2),
+                (2, 4),
+                (0, 6),
+                (0x1, 2),
+                (0, 24),
+                (0, 24),
+                (0, 24),
+                (0, 16),
+                ((-11i32) as u32 & 0xFF, 8),
+                (0x23, 8),
+                (7200 / 16, 16),
+                (0, 8),
+                ((-3i32) as u32 & 0xFFFF, 16),
+                (12345, 22),
+                (0, 2),
+            ],
+        );
+        let sf2 = sf(
+            2,
+            &[
+                (0x23, 8),
+                (320, 16),
+                (100, 16),
+                (0x4000_0000, 32),
+                (20, 16),
+                (0x0100_0000, 32),
+                ((-20i32) as u32 & 0xFFFF, 16),
+                (5153 << 19, 32),
+                (7200 / 16, 16),
+                (0, 1),
+                (0, 5),
+                (0, 2),
+            ],
+        );
+        let sf3 = sf(
+            3,
+            &[
+                (10, 16),
+                (0x2000_0000, 32),
+                ((-10i32) as u32 & 0xFFFF, 16),
+                (0x2000_0000, 32),
+                (160, 16),
+                (0xC000_0000, 32),
+                ((-2000i32) as u32 & 0xFF_FFFF, 24),
+                (0x23, 8),
+                (50, 14),
+                (0, 2),
+            ],
+        );
+
+        let Ok(Subframe {
+            header,
+            data: SubframeData::Clock(clock),
+        }) = Subframe::decode(&sf1)
+        else {
+            panic!("Invalid subframe 1");
+        };
+        assert_eq!(1, header.subframe_id);
+        assert_eq!(1000, header.tow_count);
+        assert_eq!(5994., header.time_of_week());
+        assert_eq!(0x3FE, clock.week);
+        assert_eq!(0x123, clock.iodc);
+        assert_eq!(2, clock.ura_index);
+        assert_eq!(-11. * 2f64.powi(-31), clock.tgd);
+        assert_eq!(7200., clock.toc);
+        assert_eq!(-3. * 2f64.powi(-43), clock.af1);
+        assert_eq!(12345. * 2f64.powi(-31), clock.af0);
+
+        let mut decoder = LNAVDecoder::new();
+        decoder.set_reference_week(2000);
+        assert_eq!(None, decoder.push_raw(5, &sf1)?);
+        assert_eq!(None, decoder.push_raw(5, &sf2)?);
+        let Some(NavigationRecord::Ephemeris(eph)) = decoder.push_raw(5, &sf3)? else {
+            panic!("No ephemeris");
+        };
+        // 1022 mod 1024, closest to week 2000
+        assert_eq!(2046, eph.week);
+        assert_eq!(5, eph.prn);
+        assert_eq!(0x23, eph.iode);
+        assert_eq!(10., eph.crs);
+        assert_eq!(0.5 * SEMI_CIRCLE, eph.m0);
+        assert_eq!(2f64.powi(-9), eph.eccentricity);
+        assert_eq!(-20. * 2f64.powi(-29), eph.cus);
+        assert_eq!(5153., eph.sqrt_a);
+        assert_eq!(7200., eph.toe);
+        assert_eq!(0.25 * SEMI_CIRCLE, eph.omega0);
+        assert_eq!(0.25 * SEMI_CIRCLE, eph.i0);
+        assert_eq!(5., eph.crc);
+        assert_eq!(-0.5 * SEMI_CIRCLE, eph.omega);
+        assert_eq!(-2000. * 2f64.powi(-43) * SEMI_CIRCLE, eph.omega_dot);
+        assert_eq!(50. * 2f64.powi(-43) * SEMI_CIRCLE, eph.idot);
+        assert_eq!(Some(&eph), decoder.ephemeris(5));
+
+        // repeated subframes don't emit the same ephemeris again
+        assert_eq!(None, decoder.push_raw(5, &sf1)?);
+        Ok(())
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn, clippy::float_cmp)]
+    pub fn test_almanac() -> Result<(), BitsError> {
+        let reference = sf(5, &[(1, 2), (51, 6), (147456 >> 12, 8), (0xD0, 8)]);
+        let page = sf(
+            5,
+            &[
+                (1, 2),
+                (12, 6),
+                (0x2000, 16),
+                (147456 >> 12, 8),
+                (0x1000, 16),
+                ((-100i32) as u32 & 0xFFFF, 16),
+                (0, 8),
+                (5153 << 11, 24),
+                (0x40_0000, 24),
+                (0x20_0000, 24),
+                ((-0x20_0000i32) as u32 & 0xFF_FFFF, 24),
+                (0xFF, 8),
+                (0x7FF, 11),
+                (0x4, 3),
+                (0, 2),
+            ],
+        );
+        let mut decoder = LNAVDecoder::new();
+        decoder.set_reference_week(2250);
+        assert_eq!(None, decoder.push_raw(12, &reference)?);
+        let Some(NavigationRecord::Almanac(alm)) = decoder.push_raw(3, &page)? else {
+            panic!("No almanac");
+        };
+        assert_eq!(12, alm.prn);
+        // 0xD0 = 208 mod 256, closest to week 2250
+        assert_eq!(2256, alm.week);
+        assert_eq!(147456., alm.toa);
+        assert_eq!(2f64.powi(-8), alm.eccentricity);
+        assert_eq!(0.0078125 * SEMI_CIRCLE, alm.delta_i);
+        assert_eq!(5153., alm.sqrt_a);
+        assert_eq!(0.5 * SEMI_CIRCLE, alm.omega0);
+        assert_eq!(0.25 * SEMI_CIRCLE, alm.omega);
+        assert_eq!(-0.25 * SEMI_CIRCLE, alm.m0);
+        assert_eq!(-4. * 2f64.powi(-20), alm.af0);
+        assert_eq!(-(2f64.powi(-38)), alm.af1);
+        Ok(())
+    }
+}
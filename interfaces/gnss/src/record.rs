@@ -0,0 +1,812 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! Recording and replay of raw GNSS streams, with the arrival time of the data, for regression
+//! testing of everything downstream of a [`GNSSPacketStream`].
+//!
+
+use crate::{GNSSFrame, GNSSPacketStream};
+use alloc::sync::Arc;
+use core::time::Duration;
+use irox_bits::{Bits, BitsError, BitsErrorKind, BitsWrapper, Error, MutBits};
+use std::io::{BufReader, BufWriter, Read};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Magic bytes at the start of a recording file
+pub const MAGIC: [u8; 8] = *b"IROXGNSS";
+/// Current version of the recording file format
+pub const VERSION: u8 = 1;
+
+const CHUNK_TAG: u8 = 0x01;
+const FRAME_TAG: u8 = 0x02;
+
+/// GPS week length in milliseconds, for iTOW rollover
+const WEEK_MILLIS: u32 = 604_800_000;
+
+///
+/// The type of a frame decoded from a recording.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FrameKind {
+    UBX {
+        class: u8,
+        id: u8,
+    },
+    NMEA,
+    SIRF,
+    AIS,
+    /// RTCM3, with the message number
+    RTCM3(u16),
+}
+
+impl From<&GNSSFrame> for FrameKind {
+    fn from(value: &GNSSFrame) -> Self {
+        match value {
+            GNSSFrame::UBX(m) => FrameKind::UBX {
+                class: m.class,
+                id: m.id,
+            },
+            GNSSFrame::NMEA(_) => FrameKind::NMEA,
+            GNSSFrame::SIRF(_) => FrameKind::SIRF,
+            GNSSFrame::AIS(_) => FrameKind::AIS,
+            GNSSFrame::RTCM3(m) => FrameKind::RTCM3(m.message_number),
+        }
+    }
+}
+
+impl FrameKind {
+    fn encode(self) -> (u8, u16) {
+        match self {
+            FrameKind::UBX { class, id } => (1, u16::from_be_bytes([class, id])),
+            FrameKind::NMEA => (2, 0),
+            FrameKind::SIRF => (3, 0),
+            FrameKind::AIS => (4, 0),
+            FrameKind::RTCM3(num) => (5, num),
+        }
+    }
+
+    fn decode(tag: u8, value: u16) -> Result<Self, Error> {
+        Ok(match tag {
+            1 => {
+                let [class, id] = value.to_be_bytes();
+                FrameKind::UBX { class, id }
+            }
+            2 => FrameKind::NMEA,
+            3 => FrameKind::SIRF,
+            4 => FrameKind::AIS,
+            5 => FrameKind::RTCM3(value),
+            _ => {
+                return Err(BitsError::new(
+                    BitsErrorKind::InvalidData,
+                    "Unknown frame kind in recording",
+                ))
+            }
+        })
+    }
+}
+
+///
+/// A block of raw bytes, as read from the source.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RecordedChunk {
+    /// Arrival time, since the start of the recording
+    pub offset: Duration,
+    pub data: Vec<u8>,
+}
+
+///
+/// An entry in the index of the frames decoded from the recording.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IndexedFrame {
+    /// Time the frame was decoded, since the start of the recording
+    pub offset: Duration,
+    /// Byte position in the raw stream where the frame ends.  When recorded live, this is how far
+    /// the stream had read when the frame was decoded, which may be past the end of the frame.
+    pub position: u64,
+    pub kind: FrameKind,
+}
+
+///
+/// A recorded raw GNSS stream: the bytes with their arrival times, and an optional index of
+/// the frames decoded from them.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Recording {
+    pub chunks: Vec<RecordedChunk>,
+    pub frames: Vec<IndexedFrame>,
+}
+
+impl Recording {
+    /// Arrival time of the last chunk
+    pub fn duration(&self) -> Duration {
+        self.chunks.last().map(|c| c.offset).unwrap_or_default()
+    }
+
+    /// Total number of raw bytes recorded
+    pub fn len_bytes(&self) -> u64 {
+        self.chunks.iter().map(|c| c.data.len() as u64).sum()
+    }
+
+    fn push_chunk(&mut self, offset: Duration, data: &[u8]) {
+        if !data.is_empty() {
+            self.chunks.push(RecordedChunk {
+                offset,
+                data: data.to_vec(),
+            });
+        }
+    }
+
+    ///
+    /// Writes the recording in the binary recording format.
+    pub fn write_to<T: MutBits>(&self, out: &mut T) -> Result<(), Error> {
+        out.write_all_bytes(&MAGIC)?;
+        out.write_u8(VERSION)?;
+        for chunk in &self.chunks {
+            out.write_u8(CHUNK_TAG)?;
+            out.write_be_u64(chunk.offset.as_micros() as u64)?;
+            out.write_be_u32(chunk.data.len() as u32)?;
+            out.write_all_bytes(&chunk.data)?;
+        }
+        for frame in &self.frames {
+            let (tag, value) = frame.kind.encode();
+            out.write_u8(FRAME_TAG)?;
+            out.write_be_u64(frame.offset.as_micros() as u64)?;
+            out.write_be_u64(frame.position)?;
+            out.write_u8(tag)?;
+            out.write_be_u16(value)?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Reads a recording in the binary recording format.
+    pub fn read_from<T: Bits>(input: &mut T) -> Result<Recording, Error> {
+        let magic: [u8; 8] = input.read_exact()?;
+        if magic != MAGIC {
+            return Err(BitsError::new(
+                BitsErrorKind::InvalidData,
+                "Not a GNSS recording",
+            ));
+        }
+        if input.read_u8()? != VERSION {
+            return Err(BitsError::new(
+                BitsErrorKind::Unsupported,
+                "Unsupported GNSS recording version",
+            ));
+        }
+        let mut out = Recording::default();
+        while let Some(tag) = input.next_u8()? {
+            let offset = Duration::from_micros(input.read_be_u64()?);
+            match tag {
+                CHUNK_TAG => {
+                    let len = input.read_be_u32()? as usize;
+                    let data = input.read_exact_vec(len)?;
+                    out.chunks.push(RecordedChunk { offset, data });
+                }
+                FRAME_TAG => {
+                    let position = input.read_be_u64()?;
+                    let kind = input.read_u8()?;
+                    let value = input.read_be_u16()?;
+                    out.frames.push(IndexedFrame {
+                        offset,
+                        position,
+                        kind: FrameKind::decode(kind, value)?,
+                    });
+                }
+                _ => {
+                    return Err(BitsError::new(
+                        BitsErrorKind::InvalidData,
+                        "Unknown record in GNSS recording",
+                    ))
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    ///
+    /// Saves the recording to the file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let mut out = BitsWrapper::Owned(BufWriter::new(std::fs::File::create(path)?));
+        self.write_to(&mut out)?;
+        out.flush()?;
+        Ok(())
+    }
+
+    ///
+    /// Loads a recording from the file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Recording, Error> {
+        let mut input = BitsWrapper::Owned(BufReader::new(std::fs::File::open(path)?));
+        Self::read_from(&mut input)
+    }
+
+    ///
+    /// Imports a u-center `.ubx` log, which is the raw output of the receiver without any
+    /// timing.  The arrival times are reconstructed from the iTOW of the NAV messages, and
+    /// anything before the first NAV message, like interleaved NMEA, arrives at the start.
+    pub fn import_ubx_log<T: Read>(mut source: T) -> Result<Recording, Error> {
+        let mut data = Vec::new();
+        source.read_to_end(&mut data)?;
+        Ok(Self::from_ubx_bytes(&data))
+    }
+
+    ///
+    /// Imports a `ubxdumpfile`-style text capture, one message per line as
+    /// `CLASS-ID - <hex bytes>`, with or without the sync chars and checksum.  The arrival
+    /// times are reconstructed as in [`Recording::import_ubx_log`].
+    pub fn import_ubx_dump_txt<T: Bits>(mut source: T) -> Result<Recording, Error> {
+        let mut data: Vec<u8> = Vec::new();
+        while let Some(line) = source.read_line_str_lossy()? {
+            let Some((_name, hex)) = line.split_once(" - ") else {
+                continue;
+            };
+            let hex: String = hex.chars().filter(|c| !c.is_whitespace()).collect();
+            let bytes = irox_tools::hex::from_hex_str(&hex)?;
+            let body = bytes.strip_prefix(&[0xB5, 0x62]).unwrap_or(&bytes);
+            let [class, id, l0, l1, rest @ ..] = body else {
+                return Err(BitsError::new(
+                    BitsErrorKind::InvalidData,
+                    "UBX dump line is too short",
+                ));
+            };
+            let len = u16::from_le_bytes([*l0, *l1]);
+            let Some(payload) = rest.get(..len as usize) else {
+                return Err(BitsError::new(
+                    BitsErrorKind::InvalidData,
+                    "UBX dump line is shorter than its length",
+                ));
+            };
+            data.write_all_bytes(&[0xB5, 0x62, *class, *id, *l0, *l1])?;
+            data.write_all_bytes(payload)?;
+            data.write_le_u16(irox_ubx::calculate_checksum(*class, *id, len, payload))?;
+        }
+        Ok(Self::from_ubx_bytes(&data))
+    }
+
+    fn from_ubx_bytes(data: &[u8]) -> Recording {
+        let mut out = Recording::default();
+        let mut first_itow: Option<u32> = None;
+        let mut offset = Duration::ZERO;
+        let mut pending = 0;
+        let mut idx = 0;
+        while let Some(remaining) = data.get(idx..) {
+            if remaining.is_empty() {
+                break;
+            }
+            let Some((class, id, frame)) = ubx_frame(remaining) else {
+                idx += 1;
+                continue;
+            };
+            // anything between the frames, like interleaved NMEA.
+            out.push_chunk(offset, data.get(pending..idx).unwrap_or_default());
+            if class == 0x01 {
+                let start = 6 + nav_itow_offset(id);
+                if let Some(itow) = frame.get(start..start + 4).and_then(|v| v.try_into().ok()) {
+                    let itow = u32::from_le_bytes(itow);
+                    let first = *first_itow.get_or_insert(itow);
+                    let millis = if itow >= first {
+                        itow - first
+                    } else {
+                        itow.wrapping_add(WEEK_MILLIS).wrapping_sub(first)
+                    };
+                    offset = offset.max(Duration::from_millis(u64::from(millis)));
+                }
+            }
+            idx += frame.len();
+            pending = idx;
+            out.push_chunk(offset, frame);
+            out.frames.push(IndexedFrame {
+                offset,
+                position: idx as u64,
+                kind: FrameKind::UBX { class, id },
+            });
+        }
+        out.push_chunk(offset, data.get(pending..).unwrap_or_default());
+        out
+    }
+
+    ///
+    /// Replays the recording at the speed.
+    pub fn replay(self, speed: ReplaySpeed) -> Replay {
+        Replay::new(self, speed)
+    }
+}
+
+/// Offset of the iTOW in the payload of a NAV message, most messages start with it.
+fn nav_itow_offset(id: u8) -> usize {
+    match id {
+        // ODO, HPPOSECEF, HPPOSLLH, SVIN & RELPOSNED start with a version and reserved bytes.
+        0x09 | 0x13 | 0x14 | 0x3B | 0x3C => 4,
+        _ => 0,
+    }
+}
+
+/// Returns the class, id and bytes of the valid UBX frame at the start of the buffer
+fn ubx_frame(buf: &[u8]) -> Option<(u8, u8, &[u8])> {
+    let [0xB5, 0x62, class, id, l0, l1, ..] = *buf else {
+        return None;
+    };
+    let len = u16::from_le_bytes([l0, l1]);
+    let frame = buf.get(..len as usize + 8)?;
+    let payload = frame.get(6..len as usize + 6)?;
+    let checksum = u16::from_le_bytes(frame.get(len as usize + 6..)?.try_into().ok()?);
+    if checksum != irox_ubx::calculate_checksum(class, id, len, payload) {
+        return None;
+    }
+    Some((class, id, frame))
+}
+
+struct RecorderState {
+    start: Instant,
+    position: u64,
+    recording: Recording,
+}
+
+///
+/// Records everything read from a source, and optionally the frames decoded from it.  Clones
+/// share the same recording.
+#[derive(Clone)]
+pub struct Recorder {
+    state: Arc<Mutex<RecorderState>>,
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Recorder {
+    /// Starts a new recording, arrival times are relative to now.
+    pub fn new() -> Self {
+        Recorder {
+            state: Arc::new(Mutex::new(RecorderState {
+                start: Instant::now(),
+                position: 0,
+                recording: Recording::default(),
+            })),
+        }
+    }
+
+    ///
+    /// Wraps the source, recording everything read from it.
+    pub fn wrap<T: Read>(&self, source: T) -> RecordingSource<T> {
+        RecordingSource {
+            source,
+            recorder: self.clone(),
+        }
+    }
+
+    fn record(&self, data: &[u8]) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        let offset = state.start.elapsed();
+        state.position += data.len() as u64;
+        state.recording.push_chunk(offset, data);
+    }
+
+    ///
+    /// Adds the frame to the index of the recording.
+    pub fn index_frame(&self, frame: &GNSSFrame) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        let entry = IndexedFrame {
+            offset: state.start.elapsed(),
+            position: state.position,
+            kind: frame.into(),
+        };
+        state.recording.frames.push(entry);
+    }
+
+    ///
+    /// Reads the next frame from the stream, adding it to the index.
+    pub fn read_next<'a, T: Bits>(
+        &self,
+        stream: &mut GNSSPacketStream<'a, T>,
+    ) -> Result<GNSSFrame, Error>
+    where
+        BitsWrapper<'a, T>: Bits,
+    {
+        let frame = stream.read_next()?;
+        self.index_frame(&frame);
+        Ok(frame)
+    }
+
+    /// A copy of everything recorded so far
+    pub fn recording(&self) -> Recording {
+        self.state
+            .lock()
+            .map(|s| s.recording.clone())
+            .unwrap_or_default()
+    }
+}
+
+///
+/// A source being recorded by a [`Recorder`].
+pub struct RecordingSource<T> {
+    source: T,
+    recorder: Recorder,
+}
+
+impl<T: Read> Read for RecordingSource<T> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.source.read(buf)?;
+        self.recorder.record(buf.get(..read).unwrap_or_default());
+        Ok(read)
+    }
+}
+
+impl<T: Read> Bits for RecordingSource<T> {
+    fn next_u8(&mut self) -> Result<Option<u8>, Error> {
+        BitsWrapper::Borrowed(self).next_u8()
+    }
+
+    fn read_some_into<B: MutBits>(&mut self, buf: &mut B) -> Result<usize, Error> {
+        BitsWrapper::Borrowed(self).read_some_into(buf)
+    }
+}
+
+///
+/// How fast to replay a recording.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub enum ReplaySpeed {
+    /// At the recorded arrival times
+    #[default]
+    RealTime,
+    /// N times faster than recorded
+    Multiplier(f64),
+    /// As fast as it's read
+    Max,
+}
+
+///
+/// The time source a [`Replay`] waits on.
+pub trait ReplayClock {
+    /// The time elapsed since an arbitrary, fixed start
+    fn now(&self) -> Duration;
+    /// Blocks for the duration
+    fn sleep(&mut self, duration: Duration);
+}
+
+///
+/// The monotonic system clock, sleeping the current thread.
+#[derive(Debug, Copy, Clone)]
+pub struct SystemClock(Instant);
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        SystemClock(Instant::now())
+    }
+}
+
+impl ReplayClock for SystemClock {
+    fn now(&self) -> Duration {
+        self.0.elapsed()
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+///
+/// A recording being replayed as a source.  Each chunk is held back until its arrival time,
+/// scaled by the speed, relative to the first read.
+pub struct Replay {
+    recording: Recording,
+    speed: ReplaySpeed,
+    clock: Box<dyn ReplayClock + Send>,
+    start: Option<Duration>,
+    chunk: usize,
+    consumed: usize,
+}
+
+impl Replay {
+    pub fn new(recording: Recording, speed: ReplaySpeed) -> Self {
+        Replay {
+            recording,
+            speed,
+            clock: Box::new(SystemClock::default()),
+            start: None,
+            chunk: 0,
+            consumed: 0,
+        }
+    }
+
+    ///
+    /// Waits on the provided clock instead of the [`SystemClock`]
+    #[must_use]
+    pub fn with_clock<C: ReplayClock + Send + 'static>(mut self, clock: C) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    fn wait_for(&mut self, offset: Duration) {
+        let scale = match self.speed {
+            ReplaySpeed::RealTime => 1.,
+            ReplaySpeed::Multiplier(m) if m.is_finite() && m > 0. => m,
+            ReplaySpeed::Multiplier(_) | ReplaySpeed::Max => return,
+        };
+        let now = self.clock.now();
+        let start = *self.start.get_or_insert(now);
+        let due = offset.div_f64(scale);
+        if let Some(wait) = due.checked_sub(now.saturating_sub(start)) {
+            if !wait.is_zero() {
+                self.clock.sleep(wait);
+            }
+        }
+    }
+}
+
+impl Read for Replay {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            let Some(chunk) = self.recording.chunks.get(self.chunk) else {
+                return Ok(0);
+            };
+            let offset = chunk.offset;
+            let Some(remaining) = chunk.data.get(self.consumed..) else {
+                return Ok(0);
+            };
+            if remaining.is_empty() {
+                self.chunk += 1;
+                self.consumed = 0;
+                continue;
+            }
+            let len = remaining.len().min(buf.len());
+            if let (Some(out), Some(data)) = (buf.get_mut(..len), remaining.get(..len)) {
+                out.copy_from_slice(data);
+            }
+            if self.consumed == 0 {
+                self.wait_for(offset);
+            }
+            self.consumed += len;
+            return Ok(len);
+        }
+    }
+}
+
+impl Bits for Replay {
+    fn next_u8(&mut self) -> Result<Option<u8>, Error> {
+        BitsWrapper::Borrowed(self).next_u8()
+    }
+
+    fn read_some_into<B: MutBits>(&mut self, buf: &mut B) -> Result<usize, Error> {
+        BitsWrapper::Borrowed(self).read_some_into(buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::record::{
+        FrameKind, IndexedFrame, RecordedChunk, Recorder, Recording, ReplayClock, ReplaySpeed,
+    };
+    use crate::{GNSSFrame, GNSSPacketStream};
+    use alloc::sync::Arc;
+    use core::sync::atomic::AtomicBool;
+    use core::time::Duration;
+    use irox_bits::{BitsWrapper, Error};
+    use irox_ubx::calculate_checksum;
+    use std::io::Read;
+    use std::sync::Mutex;
+
+    fn nav_frame(id: u8, payload: &[u8]) -> Vec<u8> {
+        let len = payload.len() as u16;
+        let mut out = vec![0xB5, 0x62, 0x01, id];
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(payload);
+        out.extend_from_slice(&calculate_checksum(0x01, id, len, payload).to_le_bytes());
+        out
+    }
+
+    /// A clock that only advances when slept, recording each sleep
+    #[derive(Clone, Default)]
+    struct TestClock(Arc<Mutex<Vec<Duration>>>);
+    impl TestClock {
+        fn sleeps(&self) -> Vec<Duration> {
+            self.0.lock().map(|s| s.clone()).unwrap_or_default()
+        }
+    }
+    impl ReplayClock for TestClock {
+        fn now(&self) -> Duration {
+            self.sleeps().iter().sum()
+        }
+
+        fn sleep(&mut self, duration: Duration) {
+            if let Ok(mut sleeps) = self.0.lock() {
+                sleeps.push(duration);
+            }
+        }
+    }
+
+    /// NAV-EOE, a NAV message with only the iTOW
+    fn nav_eoe(itow: u32) -> Vec<u8> {
+        nav_frame(0x61, &itow.to_le_bytes())
+    }
+
+    /// NAV-PVT, iTOW first
+    fn nav_pvt(itow: u32) -> Vec<u8> {
+        let mut payload = itow.to_le_bytes().to_vec();
+        payload.resize(92, 0);
+        nav_frame(0x07, &payload)
+    }
+
+    /// NAV-HPPOSLLH, iTOW after the version & reserved bytes
+    fn nav_hpposllh(itow: u32) -> Vec<u8> {
+        let mut payload = vec![0; 4];
+        payload.extend_from_slice(&itow.to_le_bytes());
+        payload.resize(36, 0);
+        nav_frame(0x14, &payload)
+    }
+
+    fn sample() -> Recording {
+        Recording {
+            chunks: vec![
+                RecordedChunk {
+                    offset: Duration::ZERO,
+                    data: b"$GPTXT,01,01,02,hello*2F\r\n".to_vec(),
+                },
+                RecordedChunk {
+                    offset: Duration::from_millis(100),
+                    data: nav_eoe(1000),
+                },
+            ],
+            frames: vec![IndexedFrame {
+                offset: Duration::from_millis(100),
+                position: 42,
+                kind: FrameKind::UBX {
+                    class: 0x01,
+                    id: 0x61,
+                },
+            }],
+        }
+    }
+
+    #[test]
+    pub fn test_roundtrip() -> Result<(), Error> {
+        let recording = sample();
+        let mut buf: Vec<u8> = Vec::new();
+        recording.write_to(&mut buf)?;
+        assert_eq!(recording, Recording::read_from(&mut buf.as_slice())?);
+
+        if let Some(first) = buf.first_mut() {
+            *first = b'X';
+        }
+        assert!(Recording::read_from(&mut buf.as_slice()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_replay() -> Result<(), Error> {
+        let recording = sample();
+        let expected: Vec<u8> = recording
+            .chunks
+            .iter()
+            .flat_map(|c| c.data.clone())
+            .collect();
+        for (speed, sleeps) in [
+            (ReplaySpeed::Max, vec![]),
+            (ReplaySpeed::Multiplier(4.), vec![Duration::from_millis(25)]),
+            (ReplaySpeed::RealTime, vec![Duration::from_millis(100)]),
+        ] {
+            let clock = TestClock::default();
+            let mut out = Vec::new();
+            recording
+                .clone()
+                .replay(speed)
+                .with_clock(clock.clone())
+                .read_to_end(&mut out)?;
+            assert_eq!(expected, out);
+            assert_eq!(sleeps, clock.sleeps(), "{speed:?}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_record() {
+        let recorder = Recorder::new();
+        let data = sample().replay(ReplaySpeed::Max);
+        let mut stream = GNSSPacketStream::new(
+            Arc::new(AtomicBool::new(true)),
+            BitsWrapper::Owned(recorder.wrap(data)),
+        );
+        let Ok(GNSSFrame::NMEA(_)) = recorder.read_next(&mut stream) else {
+            panic!("Expected NMEA");
+        };
+        let Ok(GNSSFrame::UBX(_)) = recorder.read_next(&mut stream) else {
+            panic!("Expected UBX");
+        };
+        let recording = recorder.recording();
+        assert_eq!(38, recording.len_bytes());
+        assert_eq!(
+            vec![
+                FrameKind::NMEA,
+                FrameKind::UBX {
+                    class: 0x01,
+                    id: 0x61
+                }
+            ],
+            recording.frames.iter().map(|f| f.kind).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    pub fn test_import_ubx_log() -> Result<(), Error> {
+        let mut log = nav_eoe(604_799_000);
+        log.extend_from_slice(b"$GPTXT,01,01,02,hello*2F\r\n");
+        // corrupt frame, kept as raw bytes
+        let mut bad = nav_eoe(2000);
+        if let Some(b) = bad.get_mut(7) {
+            *b = 0xFF;
+        }
+        log.extend_from_slice(&bad);
+        log.extend_from_slice(&nav_eoe(500));
+
+        let recording = Recording::import_ubx_log(log.as_slice())?;
+        assert_eq!(log.len() as u64, recording.len_bytes());
+        assert_eq!(3, recording.chunks.len());
+        assert_eq!(
+            vec![Duration::ZERO, Duration::from_millis(1500)],
+            recording
+                .frames
+                .iter()
+                .map(|f| f.offset)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(log.len() as u64),
+            recording.frames.last().map(|f| f.position)
+        );
+        assert_eq!(Duration::from_millis(1500), recording.duration());
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_import_ubx_log_itow_offsets() -> Result<(), Error> {
+        let mut log = nav_pvt(1000);
+        log.extend(nav_hpposllh(1000));
+        log.extend(nav_pvt(2000));
+        log.extend(nav_hpposllh(2000));
+
+        let recording = Recording::import_ubx_log(log.as_slice())?;
+        assert_eq!(
+            vec![
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::from_secs(1),
+                Duration::from_secs(1)
+            ],
+            recording
+                .frames
+                .iter()
+                .map(|f| f.offset)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Duration::from_secs(1), recording.duration());
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_import_ubx_dump_txt() -> Result<(), Error> {
+        let dump = "NAV-EOE - 01 61 04 00 E8 03 00 00\n\
+            \n\
+            NAV-EOE - B5 62 01 61 04 00 D0 07 00 00 3F 2D\n";
+        let recording = Recording::import_ubx_dump_txt(dump.as_bytes())?;
+        let raw: Vec<u8> = recording
+            .chunks
+            .iter()
+            .flat_map(|c| c.data.clone())
+            .collect();
+        let mut expected = nav_eoe(1000);
+        expected.extend(nav_eoe(2000));
+        assert_eq!(expected, raw);
+        assert_eq!(Duration::from_secs(1), recording.duration());
+
+        assert!(Recording::import_ubx_dump_txt("NAV-EOE - 01 61 04 00 E8".as_bytes()).is_err());
+        Ok(())
+    }
+}
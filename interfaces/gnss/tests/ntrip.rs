@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use irox_bits::{BitsErrorKind, BitsWrapper, Error};
+use irox_gnss::ntrip::{
+    NtripAuthentication, NtripCaster, NtripClient, NtripStream, NtripVersion, StreamRecord,
+};
+use irox_gnss::{GNSSFrame, GNSSPacketStream};
+use irox_nmea0183::gga::GGABuilder;
+use irox_rtcm3::RTCM3Frame;
+use irox_tools::packetio::Packet;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// message 1230, station 0, with no code-phase biases
+fn rtcm_frame() -> Result<Vec<u8>, Error> {
+    RTCM3Frame::new(Box::new([0x4C, 0xE0, 0x00, 0x80]))?.get_bytes()
+}
+
+fn read_rtcm(stream: NtripStream) -> Result<u16, Error> {
+    let run = Arc::new(AtomicBool::new(true));
+    let mut packets = GNSSPacketStream::new(run, BitsWrapper::Owned(stream));
+    match packets.read_next()? {
+        GNSSFrame::RTCM3(msg) => Ok(msg.message_number),
+        e => panic!("Expected RTCM3 but was {e:?}"),
+    }
+}
+
+fn client(addr: SocketAddr) -> NtripClient {
+    NtripClient::new(addr.ip().to_string(), addr.port())
+}
+
+#[test]
+pub fn test_caster() -> Result<(), Error> {
+    let close = Arc::new(AtomicBool::new(false));
+    let mut record = StreamRecord::new("IROX");
+    record.nav_system = "GPS+GLO".to_string();
+    let mut caster = NtripCaster::start("127.0.0.1:0", record, Some(("user", "pass")), close)?;
+    let addr = caster.local_addr();
+
+    for version in [NtripVersion::V1, NtripVersion::V2] {
+        let table = client(addr).with_version(version).sourcetable()?;
+        assert_eq!(1, table.streams.len());
+        let Some(stream) = table.find_stream("IROX") else {
+            panic!("Missing IROX stream");
+        };
+        assert_eq!("GPS+GLO", stream.nav_system);
+        assert_eq!(NtripAuthentication::Basic, stream.authentication);
+
+        let Err(e) = client(addr).with_version(version).connect("IROX") else {
+            panic!("Expected unauthorized");
+        };
+        assert_eq!(BitsErrorKind::PermissionDenied, e.kind());
+
+        let Err(e) = client(addr)
+            .with_version(version)
+            .with_credentials("user", "pass")
+            .connect("OTHER")
+        else {
+            panic!("Expected not found");
+        };
+        assert_eq!(BitsErrorKind::NotFound, e.kind());
+    }
+
+    let v1 = client(addr)
+        .with_version(NtripVersion::V1)
+        .with_credentials("user", "pass")
+        .connect("IROX")?;
+    let v2 = client(addr)
+        .with_credentials("user", "pass")
+        .connect("/IROX")?;
+    v1.set_read_timeout(Some(Duration::from_secs(5)))?;
+    v2.set_read_timeout(Some(Duration::from_secs(5)))?;
+
+    let start = Instant::now();
+    while caster.num_connected() < 2 && start.elapsed() < Duration::from_secs(5) {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(2, caster.num_connected());
+    caster.relay(rtcm_frame()?.as_slice())?;
+
+    assert_eq!(1230, read_rtcm(v1)?);
+    assert_eq!(1230, read_rtcm(v2)?);
+    caster.stop();
+    Ok(())
+}
+
+#[test]
+pub fn test_silent_client_does_not_block() -> Result<(), Error> {
+    let close = Arc::new(AtomicBool::new(false));
+    let caster = NtripCaster::start("127.0.0.1:0", StreamRecord::new("IROX"), None, close)?;
+    let addr = caster.local_addr();
+
+    // connects, but never sends a request.
+    let _silent = std::net::TcpStream::connect(addr)?;
+    let start = Instant::now();
+    let table = client(addr).sourcetable()?;
+    assert_eq!(1, table.streams.len());
+    assert!(
+        start.elapsed() < Duration::from_secs(2),
+        "{:?}",
+        start.elapsed()
+    );
+    caster.stop();
+    Ok(())
+}
+
+#[test]
+pub fn test_chunked_stream_with_gga() -> Result<(), Error> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?;
+    // stand-in for a v2 network caster, which sends the data chunked and expects GGA.
+    let caster = std::thread::spawn(move || -> Result<(Vec<String>, String), Error> {
+        let (mut conn, _) = listener.accept()?;
+        let mut rdr = BufReader::new(conn.try_clone()?);
+        let mut request = Vec::new();
+        loop {
+            let mut line = String::new();
+            rdr.read_line(&mut line)?;
+            let line = line.trim_end().to_string();
+            if line.is_empty() {
+                break;
+            }
+            request.push(line);
+        }
+        let frame = rtcm_frame()?;
+        let (first, second) = frame.split_at(4);
+        write!(
+            conn,
+            "HTTP/1.1 200 OK\r\nNtrip-Version: Ntrip/2.0\r\nContent-Type: gnss/data\r\nTransfer-Encoding: chunked\r\n\r\n"
+        )?;
+        write!(conn, "{:X}\r\n", first.len())?;
+        conn.write_all(first)?;
+        write!(conn, "\r\n{:X}\r\n", second.len())?;
+        conn.write_all(second)?;
+        write!(conn, "\r\n")?;
+        conn.flush()?;
+
+        let mut gga = String::new();
+        rdr.read_line(&mut gga)?;
+        write!(conn, "0\r\n\r\n")?;
+        Ok((request, gga))
+    });
+
+    let mut stream = client(addr)
+        .with_credentials("user", "pass")
+        .connect("VRS")?;
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    stream.send_gga(&GGABuilder::new().with_num_sats(12).build())?;
+    assert_eq!(1230, read_rtcm(stream)?);
+
+    let Ok(Ok((request, gga))) = caster.join() else {
+        panic!("Stand-in caster failed");
+    };
+    assert_eq!(
+        Some("GET /VRS HTTP/1.1"),
+        request.first().map(String::as_str)
+    );
+    assert!(request.contains(&"Ntrip-Version: Ntrip/2.0".to_string()));
+    assert!(request.contains(&"Authorization: Basic dXNlcjpwYXNz".to_string()));
+    assert!(request.contains(&format!("Host: 127.0.0.1:{}", addr.port())));
+    assert!(gga.starts_with("$GPGGA,"), "{gga}");
+    assert!(gga.contains(",12,"), "{gga}");
+    Ok(())
+}
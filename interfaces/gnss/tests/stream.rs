@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use irox_bits::{BitsWrapper, Error};
+use irox_gnss::{GNSSFrame, GNSSPacketStream};
+use irox_rtcm3::RTCM3Frame;
+use irox_tools::packetio::Packet;
+use irox_ubx::UBXRawMessage;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+const ZDA: &[u8] = b"$GPZDA,201530.00,04,07,2002,-05,00*48\r\n";
+
+/// An unknown UBX message, with a payload that looks like the start of an RTCM3 frame.
+fn ubx_frame() -> Result<Vec<u8>, Error> {
+    let payload: Box<[u8]> = Box::new([0xD3, 0x00, 0x02, 0xB5, 0x62]);
+    let checksum = irox_ubx::calculate_checksum(0x77, 0x01, payload.len() as u16, &payload);
+    UBXRawMessage {
+        class: 0x77,
+        id: 0x01,
+        payload,
+        checksum,
+        payload_hex: None,
+    }
+    .get_bytes()
+}
+
+#[test]
+pub fn test_false_rtcm3_preambles() -> Result<(), Error> {
+    let mut data = Vec::new();
+    // a false preamble claiming the following 36 bytes ('$'), which holds the NMEA sentence.
+    data.extend_from_slice(&[0xD3, 0x00]);
+    data.extend_from_slice(ZDA);
+    data.extend_from_slice(&ubx_frame()?);
+    // a false preamble with a UBX sync right behind it
+    data.extend_from_slice(&[0xD3, 0x00, 0x01]);
+    data.extend_from_slice(&ubx_frame()?);
+    data.extend_from_slice(&RTCM3Frame::new(Box::new([0x4C, 0xE0, 0x00, 0x80]))?.get_bytes()?);
+    // a false preamble claiming more bytes than remain in the stream
+    data.extend_from_slice(&[0xD3, 0x03, 0xFF]);
+    data.extend_from_slice(ZDA);
+
+    let run = Arc::new(AtomicBool::new(true));
+    let mut stream = GNSSPacketStream::new(run, BitsWrapper::Owned(data.as_slice()));
+    let mut frames = Vec::new();
+    while let Ok(frame) = stream.read_next() {
+        frames.push(frame);
+    }
+    let [GNSSFrame::NMEA(_), GNSSFrame::UBX(a), GNSSFrame::UBX(b), GNSSFrame::RTCM3(rtcm), GNSSFrame::NMEA(_)] =
+        frames.as_slice()
+    else {
+        panic!("Unexpected frames: {frames:?}");
+    };
+    assert_eq!(0x77, a.class);
+    assert_eq!(0x77, b.class);
+    assert_eq!(1230, rtcm.message_number);
+    Ok(())
+}
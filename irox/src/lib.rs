@@ -24,6 +24,8 @@ pub extern crate irox_egui_extras;
 pub extern crate irox_enums;
 #[cfg(feature = "fixedmath")]
 pub extern crate irox_fixedmath;
+#[cfg(feature = "geojson")]
+pub extern crate irox_geojson;
 #[cfg(feature = "geometry")]
 pub extern crate irox_geometry;
 #[cfg(feature = "git-tools")]
@@ -36,6 +38,8 @@ pub extern crate irox_graphing;
 pub extern crate irox_imagery;
 #[cfg(feature = "influxdb_v1")]
 pub extern crate irox_influxdb_v1;
+#[cfg(feature = "kml")]
+pub extern crate irox_kml;
 #[cfg(feature = "log")]
 pub extern crate irox_log;
 #[cfg(feature = "metrics")]
@@ -52,6 +56,8 @@ pub extern crate irox_progress;
 pub extern crate irox_protobuf;
 #[cfg(feature = "raymarine-sonar")]
 pub extern crate irox_raymarine_sonar;
+#[cfg(feature = "rtcm3")]
+pub extern crate irox_rtcm3;
 #[cfg(feature = "sirf")]
 pub extern crate irox_sirf;
 #[cfg(feature = "stats")]
@@ -115,6 +121,8 @@ pub use irox_git_tools as git_tools;
 #[cfg(feature = "graphing")]
 pub use irox_graphing as graphing;
 
+#[cfg(feature = "geojson")]
+pub use irox_geojson as geojson;
 #[cfg(feature = "gpx")]
 pub use irox_gpx as gpx;
 
@@ -124,6 +132,9 @@ pub use irox_imagery as imagery;
 #[cfg(feature = "influxdb_v1")]
 pub use irox_influxdb_v1 as influxdb_v1;
 
+#[cfg(feature = "kml")]
+pub use irox_kml as kml;
+
 #[cfg(feature = "log")]
 pub use irox_log as log;
 
@@ -148,6 +159,9 @@ pub use irox_protobuf as protobuf;
 #[cfg(feature = "raymarine-sonar")]
 pub use irox_raymarine_sonar as raymarine_sonar;
 
+#[cfg(feature = "rtcm3")]
+pub use irox_rtcm3 as rtcm3;
+
 #[cfg(feature = "sirf")]
 pub use irox_sirf as sirf;
 
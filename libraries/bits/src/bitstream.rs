@@ -81,7 +81,7 @@ impl<'a, T: MutBits> BitStreamEncoder<'a, T> {
 
 pub struct BitStreamDecoder<'a, T: Bits> {
     delegate: BitsWrapper<'a, T>,
-    buf: u32,
+    buf: u64,
     used: u8,
 }
 impl<'a, T: Bits> BitStreamDecoder<'a, T> {
@@ -101,22 +101,22 @@ impl<'a, T: Bits> BitStreamDecoder<'a, T> {
                 Ordering::Less => {
                     // used < numbits - add more.
                     let v = self.delegate.read_u8()?;
-                    self.buf = (self.buf << 8) | v as u32;
+                    self.buf = (self.buf << 8) | v as u64;
                     self.used += 8;
                 }
                 Ordering::Equal => {
-                    let mask = (1u32 << num_bits) - 1;
+                    let mask = (1u64 << num_bits) - 1;
                     self.used = 0;
                     let b = self.buf & mask;
                     self.buf = 0;
-                    return Ok(b);
+                    return Ok(b as u32);
                 }
                 Ordering::Greater => {
                     let rem = self.used - num_bits;
-                    let mask = (1u32 << num_bits) - 1;
+                    let mask = (1u64 << num_bits) - 1;
                     let b = (self.buf >> rem) & mask;
                     self.used -= num_bits;
-                    return Ok(b);
+                    return Ok(b as u32);
                 }
             }
         }
@@ -130,22 +130,22 @@ impl<'a, T: Bits> BitStreamDecoder<'a, T> {
                 Ordering::Less => {
                     // used < numbits - add more.
                     let v = self.delegate.read_u8()?;
-                    self.buf |= (v as u32) << self.used;
+                    self.buf |= (v as u64) << self.used;
                     self.used += 8;
                 }
                 Ordering::Equal => {
-                    let mask = (1u32 << num_bits) - 1;
+                    let mask = (1u64 << num_bits) - 1;
                     self.used = 0;
                     let b = self.buf & mask;
                     self.buf = 0;
-                    return Ok(b);
+                    return Ok(b as u32);
                 }
                 Ordering::Greater => {
-                    let mask = (1u32 << num_bits) - 1;
+                    let mask = (1u64 << num_bits) - 1;
                     let b = self.buf & mask;
                     self.buf >>= num_bits;
                     self.used -= num_bits;
-                    return Ok(b);
+                    return Ok(b as u32);
                 }
             }
         }
@@ -159,13 +159,13 @@ impl<'a, T: Bits> BitStreamDecoder<'a, T> {
                 Ordering::Less => {
                     // used < numbits - add more.
                     let v = self.delegate.read_u8()?;
-                    self.buf |= (v as u32) << self.used;
+                    self.buf |= (v as u64) << self.used;
                     self.used += 8;
                 }
                 _ => {
-                    let mask = (1u32 << num_bits) - 1;
+                    let mask = (1u64 << num_bits) - 1;
                     let b = self.buf & mask;
-                    return Ok(b);
+                    return Ok(b as u32);
                 }
             }
         }
@@ -207,6 +207,20 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    pub fn test_dec_wide() -> Result<(), BitsError> {
+        let buf = vec![0xAB, 0xCD, 0xEF, 0x12, 0x34, 0x56, 0x78, 0x9A];
+        let mut dec = BitStreamDecoder::new(BitsWrapper::Owned(buf));
+        assert_eq!(0x5, dec.read_u32_bits(3)?);
+        assert_eq!(0x179B_DE24, dec.read_u32_bits(30)?);
+        assert_eq!(0x3456_789A, dec.read_u32_bits(31)?);
+        let buf = vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+        let mut dec = BitStreamDecoder::new(BitsWrapper::Owned(buf));
+        assert_eq!(0x1, dec.read_u32_bits(1)?);
+        assert_eq!(0xFFFF_FFFF, dec.read_u32_bits(32)?);
+        Ok(())
+    }
+
     #[test]
     pub fn test_enc() -> Result<(), BitsError> {
         let mut buf = Vec::<u8>::new();
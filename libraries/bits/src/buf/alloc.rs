@@ -62,6 +62,23 @@ impl<T> BitsBuffer<'_, T> {
         self.flush_every_n = Some(n);
     }
 }
+impl<'a, T: 'a> BitsBuffer<'a, T>
+where
+    BitsWrapper<'a, T>: Bits,
+{
+    ///
+    /// Reads from the underlying source until at least `len` bytes are buffered, returning all
+    /// the buffered bytes without consuming them.  If the source runs out first, the returned
+    /// slice is shorter than `len`.
+    pub fn fill_to(&mut self, len: usize) -> Result<&[u8], Error> {
+        while self.buf.len() < len {
+            if self.inner.read_some_into(&mut self.buf)? == 0 {
+                break;
+            }
+        }
+        Ok(self.buf.make_contiguous())
+    }
+}
 impl<'a, T: 'a> MutBits for BitsBuffer<'a, T>
 where
     BitsWrapper<'a, T>: MutBits,
@@ -622,6 +622,36 @@ pub struct HorizontalCoordinate {
     elevation: Elevation,
 }
 
+impl HorizontalCoordinate {
+    #[must_use]
+    pub const fn new(
+        reference: AbsoluteCoordinateType,
+        azimuth: Azimuth,
+        elevation: Elevation,
+    ) -> HorizontalCoordinate {
+        HorizontalCoordinate {
+            reference,
+            azimuth,
+            elevation,
+        }
+    }
+
+    #[must_use]
+    pub fn reference(&self) -> &AbsoluteCoordinateType {
+        &self.reference
+    }
+
+    #[must_use]
+    pub fn azimuth(&self) -> &Azimuth {
+        &self.azimuth
+    }
+
+    #[must_use]
+    pub fn elevation(&self) -> &Elevation {
+        &self.elevation
+    }
+}
+
 #[cfg(all(target_os = "windows", feature = "windows"))]
 pub mod windows_conv {
     extern crate alloc;
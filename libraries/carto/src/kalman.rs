@@ -0,0 +1,656 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! Kalman filter smoothing a series of GNSS fixes into a position, velocity and (optionally)
+//! acceleration, using the uncertainty reported with each fix.  The filter runs in a local ENU
+//! frame centered on the first fix.
+
+#![allow(clippy::indexing_slicing)]
+
+use crate::altitude::{Altitude, AltitudeReferenceFrame};
+use crate::coordinate::{
+    AbsoluteCoordinateType, CartesianCoordinate, EllipticalCoordinate, EllipticalCoordinateBuilder,
+    PositionUncertainty,
+};
+use crate::ecef::{ECEF, WGS84ECEF};
+use crate::error::ConvertError;
+use crate::geo::standards::wgs84::WGS84_SHAPE;
+use crate::gps::DOPs;
+use crate::local::{LocalENU, VelocityVector};
+use crate::position_type::{ENUPosition, Positions};
+use irox_time::datetime::UTCDateTime;
+use irox_tools::math::Matrix;
+use irox_units::shapes::CircularDimension;
+use irox_units::units::compass::{CompassDirection, CompassReference, RotationDirection};
+use irox_units::units::length::Length;
+
+/// Variance used for the vertical axis of a fix without an altitude, effectively ignoring it.
+const NO_ALTITUDE_VARIANCE: f64 = 1e10;
+
+///
+/// The motion model of the filter
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum MotionModel {
+    /// Position and velocity, driven by white-noise acceleration
+    #[default]
+    ConstantVelocity,
+    /// Position, velocity and acceleration, driven by white-noise jerk
+    ConstantAcceleration,
+}
+
+///
+/// Tuning for a [`PositionKalmanFilter`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct KalmanConfig {
+    pub model: MotionModel,
+    /// Spectral density of the process noise - of the acceleration for
+    /// [`MotionModel::ConstantVelocity`] (m²/s³), of the jerk for
+    /// [`MotionModel::ConstantAcceleration`] (m²/s⁵)
+    pub process_noise: f64,
+    /// User equivalent range error, meters (1-sigma), scaled by the DOPs for fixes without a
+    /// reported uncertainty
+    pub uere: f64,
+    /// Error per axis, meters (1-sigma), for fixes with neither an uncertainty nor DOPs
+    pub default_sigma: f64,
+    /// Uncertainty of the velocity when the filter starts, m/s (1-sigma)
+    pub initial_velocity_sigma: f64,
+    /// Uncertainty of the acceleration when the filter starts, m/s² (1-sigma)
+    pub initial_acceleration_sigma: f64,
+    /// Fixes with a normalized innovation squared above this are rejected as outliers.  The NIS
+    /// is chi-squared with 3 degrees of freedom, the default of 16.27 passes 99.9% of good fixes.
+    pub gate: f64,
+    /// After this many consecutive rejected fixes, the filter restarts from the latest fix
+    pub max_rejections: usize,
+}
+
+impl Default for KalmanConfig {
+    fn default() -> Self {
+        KalmanConfig {
+            model: MotionModel::default(),
+            process_noise: 1.,
+            uere: 5.,
+            default_sigma: 10.,
+            initial_velocity_sigma: 10.,
+            initial_acceleration_sigma: 1.,
+            gate: 16.27,
+            max_rejections: 5,
+        }
+    }
+}
+
+///
+/// The output of the filter after each fix.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FilteredPosition {
+    pub timestamp: UTCDateTime,
+    /// Filtered position in the local frame of the filter
+    pub enu: ENUPosition,
+    /// Filtered position with the horizontal (DRMS) and vertical 1-sigma uncertainties, with the
+    /// altitude in the same reference frame as the fixes.
+    pub coordinate: EllipticalCoordinate,
+    /// Velocity in (East, North, Up)
+    pub velocity: VelocityVector,
+    /// Acceleration in (East, North, Up), m/s², always zero for
+    /// [`MotionModel::ConstantVelocity`]
+    pub acceleration: [f64; 3],
+    /// (East, North, Up) position covariance, m²
+    pub position_covariance: Matrix<3, 3, f64>,
+    /// (East, North, Up) velocity covariance, (m/s)²
+    pub velocity_covariance: Matrix<3, 3, f64>,
+    /// Normalized innovation squared of the fix, zero when the filter (re)started on it
+    pub innovation: f64,
+    /// False if the fix was rejected as an outlier, and the output is only the prediction
+    pub accepted: bool,
+}
+
+struct FilterState {
+    frame: LocalENU,
+    base: AbsoluteCoordinateType,
+    altitude_frame: AltitudeReferenceFrame,
+    timestamp: UTCDateTime,
+    /// `[east, north, up, v_east, v_north, v_up, a_east, a_north, a_up]`
+    x: Matrix<9, 1, f64>,
+    p: Matrix<9, 9, f64>,
+    rejections: usize,
+}
+
+struct Measurement {
+    ecef: crate::position_type::ECEFPosition,
+    covariance: Matrix<3, 3, f64>,
+    altitude_frame: AltitudeReferenceFrame,
+}
+
+///
+/// Position/velocity Kalman filter for GNSS fixes.  The measurement noise of each fix comes
+/// from its [`PositionUncertainty`] (circular radii are DRMS, ellipse axes are 1-sigma) and
+/// altitude uncertainty, falling back to the DOPs scaled by the UERE.
+#[derive(Default)]
+pub struct PositionKalmanFilter {
+    config: KalmanConfig,
+    state: Option<FilterState>,
+}
+
+impl PositionKalmanFilter {
+    #[must_use]
+    pub fn new(config: KalmanConfig) -> Self {
+        PositionKalmanFilter {
+            config,
+            state: None,
+        }
+    }
+
+    #[must_use]
+    pub fn config(&self) -> &KalmanConfig {
+        &self.config
+    }
+
+    /// Discards the state, the next fix restarts the filter.
+    pub fn reset(&mut self) {
+        self.state = None;
+    }
+
+    ///
+    /// Filters the fix at the time.  Altitudes in any reference frame are accepted, the small
+    /// offset between the frames doesn't matter within the local frame.
+    pub fn update(
+        &mut self,
+        timestamp: UTCDateTime,
+        coordinate: &EllipticalCoordinate,
+        dops: Option<&DOPs>,
+    ) -> Result<FilteredPosition, ConvertError> {
+        let meas = self.measurement(coordinate, dops)?;
+        let Some(state) = self.state.as_mut() else {
+            return self.restart(timestamp, &meas);
+        };
+        let dt = (timestamp - state.timestamp).as_seconds_f64().max(0.);
+        let (x, p) = predict(&self.config, state, dt);
+
+        let z = state.frame.ecef_to_enu(&meas.ecef)?;
+        let z = z.coordinate();
+        let y = [
+            z.get_x().as_meters().value() - x[0][0],
+            z.get_y().as_meters().value() - x[1][0],
+            z.get_z().as_meters().value() - x[2][0],
+        ];
+        let mut s = meas.covariance;
+        for (i, row) in s.iter_mut().enumerate() {
+            for (j, val) in row.iter_mut().enumerate() {
+                *val += p[i][j];
+            }
+        }
+        let Some(s_inv) = s.inverse() else {
+            return self.restart(timestamp, &meas);
+        };
+        let y = Matrix::new([[y[0]], [y[1]], [y[2]]]);
+        let innovation = (y.transposed() * s_inv * y)[0][0];
+
+        state.timestamp = timestamp;
+        if !innovation.is_finite() || innovation > self.config.gate {
+            state.rejections += 1;
+            if state.rejections > self.config.max_rejections {
+                return self.restart(timestamp, &meas);
+            }
+            state.x = x;
+            state.p = p;
+            return output(state, innovation, false);
+        }
+
+        // K = P H^T S^-1, where H selects the position.
+        let mut pht = Matrix::<9, 3, f64>::new([[0.; 3]; 9]);
+        for (i, row) in pht.iter_mut().enumerate() {
+            for (j, val) in row.iter_mut().enumerate() {
+                *val = p[i][j];
+            }
+        }
+        let k = pht * s_inv;
+        let mut ikh = Matrix::<9, 9, f64>::identity();
+        for (i, row) in ikh.iter_mut().enumerate() {
+            for (j, val) in row.iter_mut().take(3).enumerate() {
+                *val -= k[i][j];
+            }
+        }
+        // Joseph form, to keep the covariance symmetric and positive.
+        state.x = x + k * y;
+        state.p = ikh * p * ikh.transpose() + k * meas.covariance * k.transposed();
+        state.rejections = 0;
+        output(state, innovation, true)
+    }
+
+    ///
+    /// Filters the fix, at the timestamp of the coordinate.
+    pub fn update_coordinate(
+        &mut self,
+        coordinate: &EllipticalCoordinate,
+        dops: Option<&DOPs>,
+    ) -> Result<FilteredPosition, ConvertError> {
+        let Some(timestamp) = coordinate.get_timestamp() else {
+            return Err(ConvertError::MissingValue(
+                "Fix is missing a timestamp".to_string(),
+            ));
+        };
+        self.update(*timestamp, coordinate, dops)
+    }
+
+    ///
+    /// Filters the fix, using the WGS84 position if present, or the ECEF position.
+    pub fn update_positions(
+        &mut self,
+        timestamp: UTCDateTime,
+        positions: &Positions,
+        dops: Option<&DOPs>,
+    ) -> Result<FilteredPosition, ConvertError> {
+        let coordinate = match (positions.latlon, positions.ecef) {
+            (Some(latlon), _) => latlon.0,
+            (None, Some(ecef)) => WGS84ECEF::ecef_to_coord(&ecef).0,
+            (None, None) => {
+                return Err(ConvertError::MissingValue(
+                    "Fix is missing a position".to_string(),
+                ))
+            }
+        };
+        self.update(timestamp, &coordinate, dops)
+    }
+
+    ///
+    /// Extrapolates the filter to the time, without changing it.
+    pub fn predict(
+        &self,
+        timestamp: UTCDateTime,
+    ) -> Option<Result<FilteredPosition, ConvertError>> {
+        let state = self.state.as_ref()?;
+        let dt = (timestamp - state.timestamp).as_seconds_f64().max(0.);
+        let (x, p) = predict(&self.config, state, dt);
+        let predicted = FilterState {
+            frame: LocalENU::new(state.base).ok()?,
+            base: state.base,
+            altitude_frame: state.altitude_frame,
+            timestamp,
+            x,
+            p,
+            rejections: state.rejections,
+        };
+        Some(output(&predicted, 0., false))
+    }
+
+    fn restart(
+        &mut self,
+        timestamp: UTCDateTime,
+        meas: &Measurement,
+    ) -> Result<FilteredPosition, ConvertError> {
+        let frame = LocalENU::new(AbsoluteCoordinateType::ECEF(meas.ecef))?;
+        let base = *frame.ecef_to_enu(&meas.ecef)?.base_position();
+        let vel = self.config.initial_velocity_sigma.powi(2);
+        let acc = match self.config.model {
+            MotionModel::ConstantVelocity => 0.,
+            MotionModel::ConstantAcceleration => self.config.initial_acceleration_sigma.powi(2),
+        };
+        let mut p = Matrix::<9, 9, f64>::empty();
+        for i in 0..3 {
+            for j in 0..3 {
+                p[i][j] = meas.covariance[i][j];
+            }
+            p[i + 3][i + 3] = vel;
+            p[i + 6][i + 6] = acc;
+        }
+        let state = self.state.insert(FilterState {
+            frame,
+            base,
+            altitude_frame: meas.altitude_frame,
+            timestamp,
+            x: Matrix::new([[0.]; 9]),
+            p,
+            rejections: 0,
+        });
+        output(state, 0., true)
+    }
+
+    fn measurement(
+        &self,
+        coordinate: &EllipticalCoordinate,
+        dops: Option<&DOPs>,
+    ) -> Result<Measurement, ConvertError> {
+        let dop = |f: fn(&DOPs) -> Option<f64>| dops.and_then(f).map(|d| d * self.config.uere);
+        let pdop_axis = dop(|d| d.position.map(|v| v.0)).map(|v| v / 3f64.sqrt());
+
+        let mut covariance = Matrix::<3, 3, f64>::empty();
+        match coordinate.position_uncertainty() {
+            Some(PositionUncertainty::CircularUncertainty(c)) => {
+                let var = radius_meters(*c).powi(2) / 2.;
+                covariance[0][0] = var;
+                covariance[1][1] = var;
+            }
+            Some(PositionUncertainty::EllipticalUncertainty(e)) => {
+                let a = radius_meters(e.first_axis()).powi(2);
+                let b = radius_meters(e.second_axis()).powi(2);
+                let theta = e
+                    .orientation()
+                    .map(clockwise_from_north)
+                    .unwrap_or_default();
+                // first axis along (sin, cos) in (east, north), the second orthogonal to it.
+                let (sin, cos) = theta.sin_cos();
+                covariance[0][0] = a * sin * sin + b * cos * cos;
+                covariance[1][1] = a * cos * cos + b * sin * sin;
+                covariance[0][1] = (a - b) * sin * cos;
+                covariance[1][0] = covariance[0][1];
+            }
+            None => {
+                let sigma = dop(|d| d.horizontal.map(|v| v.0))
+                    .map(|v| v / 2f64.sqrt())
+                    .or(pdop_axis)
+                    .unwrap_or(self.config.default_sigma);
+                covariance[0][0] = sigma * sigma;
+                covariance[1][1] = sigma * sigma;
+            }
+        }
+        covariance[2][2] = match (
+            coordinate.get_altitude(),
+            coordinate.get_altitude_uncertainty(),
+        ) {
+            (None, _) => NO_ALTITUDE_VARIANCE,
+            (Some(_), Some(unc)) => unc.as_meters().value().powi(2),
+            (Some(_), None) => dop(|d| d.vertical.map(|v| v.0))
+                .or(pdop_axis)
+                .unwrap_or(self.config.default_sigma)
+                .powi(2),
+        };
+
+        let altitude_frame = coordinate
+            .get_altitude()
+            .map(|a| a.reference_frame())
+            .unwrap_or(AltitudeReferenceFrame::Ellipsoid);
+        let mut bldr = builder(coordinate);
+        if let Some(alt) = coordinate.get_altitude() {
+            bldr.with_altitude(Altitude::new(
+                alt.value(),
+                AltitudeReferenceFrame::Ellipsoid,
+            ));
+        }
+        let ecef = ECEF::coord_to_ecef(&bldr.build()?)?;
+        Ok(Measurement {
+            ecef,
+            covariance,
+            altitude_frame,
+        })
+    }
+}
+
+fn radius_meters(dim: CircularDimension) -> f64 {
+    dim.as_radius().get_dimension().as_meters().value()
+}
+
+/// Angle of the direction clockwise from true north, radians
+fn clockwise_from_north(dir: CompassDirection) -> f64 {
+    let (rot, re) = (
+        RotationDirection::PositiveClockwise,
+        CompassReference::TrueNorth,
+    );
+    let angle = match dir {
+        CompassDirection::Heading(c) => *c.as_direction_reference(rot, re).angle(),
+        CompassDirection::Track(c) => *c.as_direction_reference(rot, re).angle(),
+        CompassDirection::Bearing(c) => *c.as_direction_reference(rot, re).angle(),
+        CompassDirection::Course(c) => *c.as_direction_reference(rot, re).angle(),
+        CompassDirection::Azimuth(c) => *c.as_direction_reference(rot, re).angle(),
+    };
+    angle.as_radians().value()
+}
+
+fn builder(coord: &EllipticalCoordinate) -> EllipticalCoordinateBuilder {
+    let mut bldr = EllipticalCoordinateBuilder::new();
+    bldr.with_latitude(*coord.get_latitude())
+        .with_longitude(*coord.get_longitude())
+        .with_reference_frame(*coord.get_reference_frame());
+    if let Some(alt) = coord.get_altitude() {
+        bldr.with_altitude(*alt);
+    }
+    bldr
+}
+
+/// Returns the predicted state and covariance after `dt` seconds.
+fn predict(
+    config: &KalmanConfig,
+    state: &FilterState,
+    dt: f64,
+) -> (Matrix<9, 1, f64>, Matrix<9, 9, f64>) {
+    let q = config.process_noise;
+    let mut f = Matrix::<9, 9, f64>::identity();
+    let mut noise = Matrix::<9, 9, f64>::empty();
+    let dt2 = dt * dt;
+    let dt3 = dt2 * dt;
+    for i in 0..3 {
+        f[i][i + 3] = dt;
+        match config.model {
+            MotionModel::ConstantVelocity => {
+                noise[i][i] = q * dt3 / 3.;
+                noise[i][i + 3] = q * dt2 / 2.;
+                noise[i + 3][i] = q * dt2 / 2.;
+                noise[i + 3][i + 3] = q * dt;
+            }
+            MotionModel::ConstantAcceleration => {
+                f[i][i + 6] = dt2 / 2.;
+                f[i + 3][i + 6] = dt;
+                let terms = [
+                    [dt3 * dt2 / 20., dt2 * dt2 / 8., dt3 / 6.],
+                    [dt2 * dt2 / 8., dt3 / 3., dt2 / 2.],
+                    [dt3 / 6., dt2 / 2., dt],
+                ];
+                for (r, row) in terms.iter().enumerate() {
+                    for (c, val) in row.iter().enumerate() {
+                        noise[i + r * 3][i + c * 3] = q * val;
+                    }
+                }
+            }
+        }
+    }
+    let x = f * state.x;
+    let p = f * state.p * f.transpose() + noise;
+    (x, p)
+}
+
+fn output(
+    state: &FilterState,
+    innovation: f64,
+    accepted: bool,
+) -> Result<FilteredPosition, ConvertError> {
+    let x = &state.x;
+    let enu = ENUPosition::new(
+        state.base,
+        CartesianCoordinate::new_meters(x[0][0], x[1][0], x[2][0]),
+    );
+    let mut position_covariance = Matrix::<3, 3, f64>::empty();
+    let mut velocity_covariance = Matrix::<3, 3, f64>::empty();
+    for i in 0..3 {
+        for j in 0..3 {
+            position_covariance[i][j] = state.p[i][j];
+            velocity_covariance[i][j] = state.p[i + 3][j + 3];
+        }
+    }
+    let ecef = state.frame.enu_to_ecef(&enu)?;
+    let coord = WGS84ECEF::ecef_to_coord(&ecef).0;
+    let mut bldr = EllipticalCoordinateBuilder::new();
+    bldr.with_latitude(*coord.get_latitude())
+        .with_longitude(*coord.get_longitude())
+        .with_reference_frame(WGS84_SHAPE)
+        .with_position_uncertainty(PositionUncertainty::CircularUncertainty(
+            CircularDimension::new_radius(Length::new_meters(
+                (position_covariance[0][0] + position_covariance[1][1]).sqrt(),
+            )),
+        ))
+        .with_altitude_uncertainty(Length::new_meters(position_covariance[2][2].sqrt()))
+        .with_timestamp(state.timestamp);
+    if let Some(alt) = coord.get_altitude() {
+        bldr.with_altitude(Altitude::new(alt.value(), state.altitude_frame));
+    }
+    Ok(FilteredPosition {
+        timestamp: state.timestamp,
+        enu,
+        coordinate: bldr.build()?,
+        velocity: VelocityVector::new_meters_per_second(x[3][0], x[4][0], x[5][0]),
+        acceleration: [x[6][0], x[7][0], x[8][0]],
+        position_covariance,
+        velocity_covariance,
+        innovation,
+        accepted,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::coordinate::{AbsoluteCoordinateType, CartesianCoordinate, EllipticalCoordinate};
+    use crate::ecef::WGS84ECEF;
+    use crate::error::ConvertError;
+    use crate::gps::{DOPs, DilutionOfPrecision};
+    use crate::kalman::{KalmanConfig, MotionModel, PositionKalmanFilter};
+    use crate::local::LocalENU;
+    use crate::position_type::ENUPosition;
+    use irox_time::datetime::UTCDateTime;
+    use irox_time::epoch::UnixTimestamp;
+    use irox_units::units::duration::Duration;
+
+    /// Deterministic noise in `[-1, 1]`
+    fn noise(i: usize, axis: usize) -> f64 {
+        ((i * 7 + axis * 13) as f64 * 1.618).sin()
+    }
+
+    struct Track {
+        frame: LocalENU,
+        base: AbsoluteCoordinateType,
+        start: UTCDateTime,
+    }
+    impl Track {
+        fn new() -> Result<Track, ConvertError> {
+            let base = EllipticalCoordinate::new_degrees_wgs84(40., -75.);
+            let base = AbsoluteCoordinateType::Elliptical(base);
+            Ok(Track {
+                frame: LocalENU::new(base)?,
+                base,
+                start: UTCDateTime::from(UnixTimestamp::from_seconds(1_700_000_000)),
+            })
+        }
+        fn fix(&self, e: f64, n: f64, u: f64) -> Result<EllipticalCoordinate, ConvertError> {
+            let enu = ENUPosition::new(self.base, CartesianCoordinate::new_meters(e, n, u));
+            Ok(WGS84ECEF::ecef_to_coord(&self.frame.enu_to_ecef(&enu)?).0)
+        }
+        fn time(&self, secs: f64) -> UTCDateTime {
+            self.start + Duration::new_seconds(secs)
+        }
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_constant_velocity() -> Result<(), ConvertError> {
+        let track = Track::new()?;
+        let dops = DOPs {
+            horizontal: Some(DilutionOfPrecision(0.8)),
+            vertical: Some(DilutionOfPrecision(1.2)),
+            ..Default::default()
+        };
+        let mut filter = PositionKalmanFilter::new(KalmanConfig {
+            process_noise: 0.05,
+            ..Default::default()
+        });
+        let mut last = None;
+        let mut raw_err = 0.;
+        let mut filtered_err = 0.;
+        for i in 0..60 {
+            let t = i as f64;
+            let truth = [10. * t, 5., 100.];
+            let fix = track.fix(
+                truth[0] + 3. * noise(i, 0),
+                truth[1] + 3. * noise(i, 1),
+                truth[2] + 4. * noise(i, 2),
+            )?;
+            let out = filter.update(track.time(t), &fix, Some(&dops))?;
+            assert!(out.accepted);
+            if i >= 20 {
+                // the filter frame is centered on the first fix, convert back to the track frame.
+                let est = track
+                    .frame
+                    .ecef_to_enu(&track.frame.enu_to_ecef(&out.enu)?)?;
+                let est = est.coordinate();
+                filtered_err += (est.get_x().as_meters().value() - truth[0]).powi(2)
+                    + (est.get_y().as_meters().value() - truth[1]).powi(2);
+                raw_err += (3. * noise(i, 0)).powi(2) + (3. * noise(i, 1)).powi(2);
+            }
+            last = Some(out);
+        }
+        assert!(filtered_err < raw_err / 2., "{filtered_err} vs {raw_err}");
+        let Some(last) = last else {
+            return Err(ConvertError::MissingValue("No output".to_string()));
+        };
+        let v = last.velocity;
+        assert!(
+            (v.x.as_meters_per_second().value() - 10.).abs() < 0.5,
+            "{v:?}"
+        );
+        assert!(v.y.as_meters_per_second().value().abs() < 0.5, "{v:?}");
+        assert!(last.position_covariance[0][0] < 4.);
+
+        // an outlier 500m away is rejected, and the filter coasts.
+        let t = 60.;
+        let fix = track.fix(10. * t + 500., 5., 100.)?;
+        let out = filter.update(track.time(t), &fix, Some(&dops))?;
+        assert!(!out.accepted);
+        assert!(out.innovation > filter.config().gate);
+        let e = out.enu.coordinate().get_x().as_meters().value();
+        assert!((e - 600.).abs() < 5., "{e}");
+        Ok(())
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_restart_after_rejections() -> Result<(), ConvertError> {
+        let track = Track::new()?;
+        let mut filter = PositionKalmanFilter::new(KalmanConfig {
+            max_rejections: 2,
+            ..Default::default()
+        });
+        for i in 0..10 {
+            filter.update(track.time(i as f64), &track.fix(0., 0., 0.)?, None)?;
+        }
+        // the receiver really did jump, after 2 rejections it restarts there.
+        let mut accepted = Vec::new();
+        for i in 10..13 {
+            let out = filter.update(track.time(i as f64), &track.fix(1000., 0., 0.)?, None)?;
+            accepted.push(out.accepted);
+        }
+        assert_eq!(vec![false, false, true], accepted);
+        let out = filter.update(track.time(13.), &track.fix(1000., 0., 0.)?, None)?;
+        assert!(out.accepted);
+        Ok(())
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_constant_acceleration() -> Result<(), ConvertError> {
+        let track = Track::new()?;
+        let mut filter = PositionKalmanFilter::new(KalmanConfig {
+            model: MotionModel::ConstantAcceleration,
+            process_noise: 0.01,
+            ..Default::default()
+        });
+        let mut last = None;
+        for i in 0..40 {
+            let t = i as f64 * 0.5;
+            let fix = track.fix(t * t + noise(i, 0), 2. * t + noise(i, 1), noise(i, 2))?;
+            last = Some(filter.update(track.time(t), &fix, None)?);
+        }
+        let Some(last) = last else {
+            return Err(ConvertError::MissingValue("No output".to_string()));
+        };
+        let [ae, an, _] = last.acceleration;
+        assert!((ae - 2.).abs() < 0.5, "{ae}");
+        assert!(an.abs() < 0.5, "{an}");
+        let ve = last.velocity.x.as_meters_per_second().value();
+        assert!((ve - 39.).abs() < 2., "{ve}");
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_missing_timestamp() {
+        let mut filter = PositionKalmanFilter::default();
+        let fix = EllipticalCoordinate::new_degrees_wgs84(40., -75.);
+        assert!(filter.update_coordinate(&fix, None).is_err());
+    }
+}
@@ -4,7 +4,7 @@
 
 use crate::coordinate::{CartesianCoordinate, EllipticalCoordinate, Latitude, Longitude};
 use crate::geo::ellipsoid::Ellipsoid;
-use crate::proj::Projection;
+use crate::proj::{GridDistortion, Projection};
 use core::f64::consts::FRAC_PI_4;
 use irox_units::units::angle::Angle;
 use irox_units::units::length::Length;
@@ -234,6 +234,30 @@ impl Projection for LambertConformalConicElliptical {
     }
 }
 
+impl GridDistortion for LambertConformalConicElliptical {
+    ///
+    /// Point scale factor, from Snyder's 'Map Projections - A Working Manual', eq 15-4
+    fn grid_scale_factor(&self, coord: &EllipticalCoordinate) -> f64 {
+        let phi = coord.get_latitude();
+        let t = if self.use_old_table_math {
+            t_tables(&self.shape, phi)
+        } else {
+            t(&self.shape, phi)
+        };
+        let m = m(&self.shape, phi);
+        self.f * t.powf(self.n) * self.n / m
+    }
+
+    ///
+    /// Meridian convergence, which for the LCC is simply the cone constant times the longitude
+    /// offset from the central meridian.
+    fn convergence_angle(&self, coord: &EllipticalCoordinate) -> Angle {
+        let lam0 = self.center.get_longitude();
+        let lam = coord.get_longitude();
+        Angle::new_radians(self.n * (lam.as_radians() - lam0.as_radians()).value())
+    }
+}
+
 pub struct LambertConformalConicSpherical {
     k: Length,
     rb: Length,
@@ -278,7 +302,7 @@ impl Projection for LambertConformalConicSpherical {
             self.center.get_longitude().as_radians() + Angle::new_radians(gamma / self.sphi0),
         );
         let r = (rp * rp + ep * ep).sqrt();
-        let q = (self.k / r).as_meters().value().ln() / self.sphi0;
+        let q = (self.k / r).ln() / self.sphi0;
         let e2q = (2.0 * q).exp();
         let mut sphi = (e2q - 1.) / (e2q + 1.);
 
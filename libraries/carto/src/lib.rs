@@ -25,8 +25,10 @@ cfg_feature_std! {
     pub mod ecef;
     pub mod epsg2249;
     pub mod epsg3857;
+    pub mod kalman;
     pub mod lcc;
     pub mod local;
+    pub mod omerc;
     pub mod spcs;
     pub mod tm;
 }
@@ -2,29 +2,128 @@
 // Copyright 2025 IROX Contributors
 //
 
+//!
+//! Local tangent-plane coordinate frames (ENU, NED, AER) and the rotation of positions,
+//! velocities, covariances and attitudes between them and ECEF.
+
 use crate::coordinate::{AbsoluteCoordinateType, CartesianCoordinate};
 use crate::ecef::ECEF;
 use crate::error::ConvertError;
 use crate::geo::standards::StandardShapes;
-use crate::position_type::{ECEFPosition, ENUPosition, WGS84Position};
+use crate::position_type::{AERPosition, ECEFPosition, ENUPosition, NEDPosition, WGS84Position};
 use irox_tools::math::{AsMatrix, Matrix};
+use irox_units::units::angle::Angle;
 use irox_units::units::length::Length;
+use irox_units::units::speed::Speed;
 
 ///
-/// Local East-North-Up coordinate frame (X=East, Y=North, Z=Up)
-pub struct LocalENU {
+/// A 3-axis velocity vector.  The meaning of each axis is defined by the frame it is used in -
+/// (X, Y, Z) for ECEF, (East, North, Up) for ENU and (North, East, Down) for NED.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct VelocityVector {
+    pub x: Speed,
+    pub y: Speed,
+    pub z: Speed,
+}
+
+impl VelocityVector {
+    #[must_use]
+    pub const fn new(x: Speed, y: Speed, z: Speed) -> Self {
+        Self { x, y, z }
+    }
+
+    #[must_use]
+    pub fn new_meters_per_second(x: f64, y: f64, z: f64) -> Self {
+        Self {
+            x: Speed::new_meters_per_second(x),
+            y: Speed::new_meters_per_second(y),
+            z: Speed::new_meters_per_second(z),
+        }
+    }
+
+    /// Returns the magnitude of this vector
+    #[must_use]
+    pub fn magnitude(&self) -> Speed {
+        let [x, y, z] = self.as_mps();
+        Speed::new_meters_per_second((x * x + y * y + z * z).sqrt())
+    }
+
+    fn as_mps(&self) -> [f64; 3] {
+        [
+            self.x.as_meters_per_second().value(),
+            self.y.as_meters_per_second().value(),
+            self.z.as_meters_per_second().value(),
+        ]
+    }
+
+    fn rotate(&self, rot: &Matrix<3, 3, f64>) -> VelocityVector {
+        let [x, y, z] = self.as_mps();
+        let [[x], [y], [z]] = *rot.mul([[x], [y], [z]].as_matrix());
+        VelocityVector::new_meters_per_second(x, y, z)
+    }
+}
+
+///
+/// Vehicle attitude as Tait-Bryan (yaw, pitch, roll) angles, rotating the local NED frame into the
+/// body (forward, right, down) frame in the aerospace `Z-Y-X` order.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Attitude {
+    /// Rotation about the down axis, also the true heading of the body
+    pub yaw: Angle,
+    /// Rotation about the (yawed) right axis, positive nose up
+    pub pitch: Angle,
+    /// Rotation about the forward axis, positive right wing down
+    pub roll: Angle,
+}
+
+impl Attitude {
+    #[must_use]
+    pub const fn new(yaw: Angle, pitch: Angle, roll: Angle) -> Self {
+        Self { yaw, pitch, roll }
+    }
+
+    ///
+    /// Returns the direction cosine matrix that rotates body-frame vectors into the NED frame.
+    #[must_use]
+    pub fn body_to_ned(&self) -> Matrix<3, 3, f64> {
+        let (sy, cy) = self.yaw.as_radians().value().sin_cos();
+        let (sp, cp) = self.pitch.as_radians().value().sin_cos();
+        let (sr, cr) = self.roll.as_radians().value().sin_cos();
+        Matrix::new([
+            [cp * cy, -cr * sy + sr * sp * cy, sr * sy + cr * sp * cy],
+            [cp * sy, cr * cy + sr * sp * sy, -sr * cy + cr * sp * sy],
+            [-sp, sr * cp, cr * cp],
+        ])
+    }
+
+    ///
+    /// Decomposes a body-to-NED direction cosine matrix back into the attitude angles.
+    #[must_use]
+    pub fn from_body_to_ned(dcm: &Matrix<3, 3, f64>) -> Attitude {
+        let [[c00, _, _], [c10, _, _], [c20, c21, c22]] = dcm.values;
+        Attitude {
+            yaw: Angle::new_radians(c10.atan2(c00)),
+            pitch: Angle::new_radians(-c20.clamp(-1., 1.).asin()),
+            roll: Angle::new_radians(c21.atan2(c22)),
+        }
+    }
+}
+
+///
+/// The common rotation math for a tangent plane at a specific base position.  `to_enu` rotates
+/// ECEF deltas into (East, North, Up).
+struct TangentPlane {
     to_enu: Matrix<3, 3, f64>,
     to_ecef: Matrix<3, 3, f64>,
     base_wgs84: WGS84Position,
     base_ecef: ECEFPosition,
 }
 
-///
-/// Local North-East-Down coordinate frame (X=North, Y=East, Z=Down)
-pub struct LocalNED;
+/// Swaps ENU axes into NED axes (and back - it's its own inverse)
+const ENU_NED: Matrix<3, 3, f64> = Matrix::new([[0., 1., 0.], [1., 0., 0.], [0., 0., -1.]]);
 
-impl LocalENU {
-    pub fn new(base_position: AbsoluteCoordinateType) -> Result<LocalENU, ConvertError> {
+impl TangentPlane {
+    fn new(base_position: AbsoluteCoordinateType) -> Result<TangentPlane, ConvertError> {
         let (base, base_ecef) = match base_position {
             AbsoluteCoordinateType::Elliptical(e) => (e, ECEF::coord_to_ecef(&e)?),
             AbsoluteCoordinateType::ECEF(e) => {
@@ -32,64 +131,241 @@ impl LocalENU {
             }
         };
         let base_wgs84 = WGS84Position(base);
-        let lat = base.get_latitude().0.as_radians().value();
-        let lon = base.get_longitude().0.as_radians().value();
-        let to_enu = Matrix::<3, 3, f64>::rotated_y(lat).rotate_z(lon);
+        let (slat, clat) = base.get_latitude().0.as_radians().value().sin_cos();
+        let (slon, clon) = base.get_longitude().0.as_radians().value().sin_cos();
+        let to_enu = Matrix::new([
+            [-slon, clon, 0.],
+            [-slat * clon, -slat * slon, clat],
+            [clat * clon, clat * slon, slat],
+        ]);
         let to_ecef = to_enu.transpose();
         Ok(Self {
-            to_ecef,
             to_enu,
+            to_ecef,
             base_wgs84,
             base_ecef,
         })
     }
 
-    pub fn enu_to_ecef(&self, enu: &ENUPosition) -> Result<ECEFPosition, ConvertError> {
-        let coord = enu.coordinate();
-        let base = *enu.base_position();
-        if base != AbsoluteCoordinateType::ECEF(self.base_ecef)
-            && base != AbsoluteCoordinateType::Elliptical(self.base_wgs84.0)
-        {
-            // can't use cached because it's not in this local ENU reference frame.
-            return LocalENU::new(base)?.enu_to_ecef(enu);
-        }
+    fn is_base(&self, base: &AbsoluteCoordinateType) -> bool {
+        *base == AbsoluteCoordinateType::ECEF(self.base_ecef)
+            || *base == AbsoluteCoordinateType::Elliptical(self.base_wgs84.0)
+    }
 
+    fn base(&self) -> AbsoluteCoordinateType {
+        AbsoluteCoordinateType::Elliptical(self.base_wgs84.0)
+    }
+
+    fn local_to_ecef(&self, rot: &Matrix<3, 3, f64>, coord: &CartesianCoordinate) -> ECEFPosition {
         let x = coord.get_x().as_meters().value();
         let y = coord.get_y().as_meters().value();
         let z = coord.get_z().as_meters().value();
 
-        let [[x], [y], [z]] = *self.to_ecef.mul([[z], [x], [y]].as_matrix());
+        let [[x], [y], [z]] = *rot.mul([[x], [y], [z]].as_matrix());
         let base = self.base_ecef.0;
         let x = Length::new_meters(x) + base.get_x();
         let y = Length::new_meters(y) + base.get_y();
         let z = Length::new_meters(z) + base.get_z();
-
-        Ok(ECEFPosition(CartesianCoordinate::new(x, y, z)))
+        ECEFPosition(CartesianCoordinate::new(x, y, z))
     }
 
-    pub fn ecef_to_enu(&self, ecef: &ECEFPosition) -> Result<ENUPosition, ConvertError> {
+    fn ecef_to_local(&self, rot: &Matrix<3, 3, f64>, ecef: &ECEFPosition) -> CartesianCoordinate {
         let coord = ecef.0;
         let base = self.base_ecef.0;
         let x = coord.get_x().as_meters().value() - base.get_x().as_meters().value();
         let y = coord.get_y().as_meters().value() - base.get_y().as_meters().value();
         let z = coord.get_z().as_meters().value() - base.get_z().as_meters().value();
-        let [[z], [x], [y]] = *self.to_enu.mul([[x], [y], [z]].as_matrix());
+        let [[x], [y], [z]] = *rot.mul([[x], [y], [z]].as_matrix());
+        CartesianCoordinate::new_meters(x, y, z)
+    }
+}
+
+/// Rotates a covariance matrix: `R * C * R^T`
+fn rotate_covariance(rot: &Matrix<3, 3, f64>, cov: &Matrix<3, 3, f64>) -> Matrix<3, 3, f64> {
+    rot.mul(*cov).mul(rot.transpose())
+}
 
+///
+/// Local East-North-Up coordinate frame (X=East, Y=North, Z=Up)
+pub struct LocalENU {
+    plane: TangentPlane,
+}
+
+///
+/// Local North-East-Down coordinate frame (X=North, Y=East, Z=Down)
+pub struct LocalNED {
+    plane: TangentPlane,
+    to_ned: Matrix<3, 3, f64>,
+    to_ecef: Matrix<3, 3, f64>,
+}
+
+impl LocalENU {
+    pub fn new(base_position: AbsoluteCoordinateType) -> Result<LocalENU, ConvertError> {
+        Ok(Self {
+            plane: TangentPlane::new(base_position)?,
+        })
+    }
+
+    pub fn enu_to_ecef(&self, enu: &ENUPosition) -> Result<ECEFPosition, ConvertError> {
+        if !self.plane.is_base(enu.base_position()) {
+            // can't use cached because it's not in this local ENU reference frame.
+            return LocalENU::new(*enu.base_position())?.enu_to_ecef(enu);
+        }
+        Ok(self
+            .plane
+            .local_to_ecef(&self.plane.to_ecef, enu.coordinate()))
+    }
+
+    pub fn ecef_to_enu(&self, ecef: &ECEFPosition) -> Result<ENUPosition, ConvertError> {
         Ok(ENUPosition::new(
-            AbsoluteCoordinateType::Elliptical(self.base_wgs84.0),
-            CartesianCoordinate::new_meters(x, y, z),
+            self.plane.base(),
+            self.plane.ecef_to_local(&self.plane.to_enu, ecef),
+        ))
+    }
+
+    /// Returns the azimuth, elevation and range from the base position to the provided position
+    pub fn ecef_to_aer(&self, ecef: &ECEFPosition) -> Result<AERPosition, ConvertError> {
+        Ok(self.ecef_to_enu(ecef)?.into())
+    }
+
+    pub fn aer_to_ecef(&self, aer: &AERPosition) -> Result<ECEFPosition, ConvertError> {
+        self.enu_to_ecef(&(*aer).into())
+    }
+
+    /// Rotates an ECEF velocity into (East, North, Up)
+    #[must_use]
+    pub fn ecef_to_enu_velocity(&self, ecef: &VelocityVector) -> VelocityVector {
+        ecef.rotate(&self.plane.to_enu)
+    }
+
+    /// Rotates an (East, North, Up) velocity into ECEF
+    #[must_use]
+    pub fn enu_to_ecef_velocity(&self, enu: &VelocityVector) -> VelocityVector {
+        enu.rotate(&self.plane.to_ecef)
+    }
+
+    /// Rotates a 3x3 ECEF position or velocity covariance matrix into (East, North, Up)
+    #[must_use]
+    pub fn ecef_to_enu_covariance(&self, ecef: &Matrix<3, 3, f64>) -> Matrix<3, 3, f64> {
+        rotate_covariance(&self.plane.to_enu, ecef)
+    }
+
+    /// Rotates a 3x3 (East, North, Up) covariance matrix into ECEF
+    #[must_use]
+    pub fn enu_to_ecef_covariance(&self, enu: &Matrix<3, 3, f64>) -> Matrix<3, 3, f64> {
+        rotate_covariance(&self.plane.to_ecef, enu)
+    }
+}
+
+impl LocalNED {
+    pub fn new(base_position: AbsoluteCoordinateType) -> Result<LocalNED, ConvertError> {
+        let plane = TangentPlane::new(base_position)?;
+        let to_ned = ENU_NED.mul(plane.to_enu);
+        let to_ecef = to_ned.transpose();
+        Ok(Self {
+            plane,
+            to_ned,
+            to_ecef,
+        })
+    }
+
+    pub fn ned_to_ecef(&self, ned: &NEDPosition) -> Result<ECEFPosition, ConvertError> {
+        if !self.plane.is_base(ned.base_position()) {
+            // can't use cached because it's not in this local NED reference frame.
+            return LocalNED::new(*ned.base_position())?.ned_to_ecef(ned);
+        }
+        Ok(self.plane.local_to_ecef(&self.to_ecef, ned.coordinate()))
+    }
+
+    pub fn ecef_to_ned(&self, ecef: &ECEFPosition) -> Result<NEDPosition, ConvertError> {
+        Ok(NEDPosition::new(
+            self.plane.base(),
+            self.plane.ecef_to_local(&self.to_ned, ecef),
         ))
     }
+
+    /// Returns the azimuth, elevation and range from the base position to the provided position
+    pub fn ecef_to_aer(&self, ecef: &ECEFPosition) -> Result<AERPosition, ConvertError> {
+        let enu: ENUPosition = self.ecef_to_ned(ecef)?.into();
+        Ok(enu.into())
+    }
+
+    pub fn aer_to_ecef(&self, aer: &AERPosition) -> Result<ECEFPosition, ConvertError> {
+        let enu: ENUPosition = (*aer).into();
+        self.ned_to_ecef(&enu.into())
+    }
+
+    /// Rotates an ECEF velocity into (North, East, Down)
+    #[must_use]
+    pub fn ecef_to_ned_velocity(&self, ecef: &VelocityVector) -> VelocityVector {
+        ecef.rotate(&self.to_ned)
+    }
+
+    /// Rotates a (North, East, Down) velocity into ECEF
+    #[must_use]
+    pub fn ned_to_ecef_velocity(&self, ned: &VelocityVector) -> VelocityVector {
+        ned.rotate(&self.to_ecef)
+    }
+
+    /// Rotates a 3x3 ECEF position or velocity covariance matrix into (North, East, Down)
+    #[must_use]
+    pub fn ecef_to_ned_covariance(&self, ecef: &Matrix<3, 3, f64>) -> Matrix<3, 3, f64> {
+        rotate_covariance(&self.to_ned, ecef)
+    }
+
+    /// Rotates a 3x3 (North, East, Down) covariance matrix into ECEF
+    #[must_use]
+    pub fn ned_to_ecef_covariance(&self, ned: &Matrix<3, 3, f64>) -> Matrix<3, 3, f64> {
+        rotate_covariance(&self.to_ecef, ned)
+    }
+
+    ///
+    /// Returns the direction cosine matrix that rotates body-frame vectors into ECEF, for a body
+    /// with the provided attitude relative to this NED frame.
+    #[must_use]
+    pub fn attitude_to_ecef(&self, attitude: &Attitude) -> Matrix<3, 3, f64> {
+        self.to_ecef.mul(attitude.body_to_ned())
+    }
+
+    ///
+    /// Returns the attitude relative to this NED frame of a body with the provided body-to-ECEF
+    /// direction cosine matrix.  Use with [`LocalNED::attitude_to_ecef`] to move an attitude
+    /// between two different base positions.
+    #[must_use]
+    pub fn attitude_from_ecef(&self, body_to_ecef: &Matrix<3, 3, f64>) -> Attitude {
+        Attitude::from_body_to_ned(&self.to_ned.mul(*body_to_ecef))
+    }
+}
+
+/// Rotates an (East, North, Up) velocity into (North, East, Down)
+#[must_use]
+pub fn enu_to_ned_velocity(enu: &VelocityVector) -> VelocityVector {
+    enu.rotate(&ENU_NED)
+}
+
+/// Rotates a (North, East, Down) velocity into (East, North, Up)
+#[must_use]
+pub fn ned_to_enu_velocity(ned: &VelocityVector) -> VelocityVector {
+    ned.rotate(&ENU_NED)
+}
+
+/// Rotates a 3x3 covariance matrix between ENU and NED, the same swap works in both directions.
+#[must_use]
+pub fn swap_enu_ned_covariance(cov: &Matrix<3, 3, f64>) -> Matrix<3, 3, f64> {
+    rotate_covariance(&ENU_NED, cov)
 }
 
 #[cfg(test)]
 mod test {
     use crate::assert_coordinate_eq_eps;
-    use crate::coordinate::{AbsoluteCoordinateType, CartesianCoordinate};
+    use crate::coordinate::{AbsoluteCoordinateType, CartesianCoordinate, EllipticalCoordinate};
     use crate::ecef::WGS84ECEF;
     use crate::error::ConvertError;
-    use crate::local::LocalENU;
-    use crate::position_type::{ECEFPosition, ENUPosition};
+    use crate::local::{Attitude, LocalENU, LocalNED, VelocityVector};
+    use crate::position_type::{AERPosition, ECEFPosition, ENUPosition, NEDPosition};
+    use irox_tools::assert_eq_eps;
+    use irox_tools::math::Matrix;
+    use irox_units::units::angle::Angle;
     use irox_units::units::length::Length;
 
     #[test]
@@ -124,6 +400,130 @@ mod test {
 
         let res = enu.enu_to_ecef(&res)?;
         assert_coordinate_eq_eps!(test, res, 1e-10);
+
+        let ned = LocalNED::new(absbase)?;
+        let res = ned.ecef_to_ned(&test)?;
+        let exp: NEDPosition = exp.into();
+        assert_coordinate_eq_eps!(*exp.coordinate(), *res.coordinate(), 2e-3);
+        let res = ned.ned_to_ecef(&res)?;
+        assert_coordinate_eq_eps!(test, res, 1e-10);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_aer() -> Result<(), ConvertError> {
+        // Base at the equator/prime meridian, so ECEF X=Up, Y=East, Z=North.
+        let base =
+            AbsoluteCoordinateType::Elliptical(EllipticalCoordinate::new_degrees_wgs84(0., 0.));
+        let enu = LocalENU::new(base)?;
+        let ned = LocalNED::new(base)?;
+
+        let target = ECEFPosition(CartesianCoordinate::new_meters(
+            6_378_137. + 1000.,
+            1000.,
+            0.,
+        ));
+        let aer = enu.ecef_to_aer(&target)?;
+        assert_eq_eps!(90., aer.azimuth().angle().as_degrees().value(), 1e-9);
+        assert_eq_eps!(45., aer.elevation().0.as_degrees().value(), 1e-9);
+        assert_eq_eps!(2f64.sqrt() * 1000., aer.range().as_meters().value(), 1e-6);
+
+        let aer2 = ned.ecef_to_aer(&target)?;
+        assert_eq_eps!(
+            aer.azimuth().angle().as_degrees().value(),
+            aer2.azimuth().angle().as_degrees().value(),
+            1e-9
+        );
+        assert_coordinate_eq_eps!(target, enu.aer_to_ecef(&aer)?, 1e-6);
+        assert_coordinate_eq_eps!(target, ned.aer_to_ecef(&aer2)?, 1e-6);
+
+        // same look angle, unit range
+        let enu_pt: ENUPosition = AERPosition::new(
+            base,
+            *aer.azimuth(),
+            aer.elevation().to_owned(),
+            Length::new_meters(1.),
+        )
+        .into();
+        assert_eq_eps!(1. / 2f64.sqrt(), enu_pt.coordinate().get_x().value(), 1e-12);
+        assert_eq_eps!(0., enu_pt.coordinate().get_y().value(), 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_velocity_covariance() -> Result<(), ConvertError> {
+        let base =
+            AbsoluteCoordinateType::Elliptical(EllipticalCoordinate::new_degrees_wgs84(0., 90.));
+        // at lon 90, ECEF -X = East, Y = Up, Z = North
+        let enu = LocalENU::new(base)?;
+        let ned = LocalNED::new(base)?;
+        let ecef = VelocityVector::new_meters_per_second(-1., 2., 3.);
+        let v = enu.ecef_to_enu_velocity(&ecef);
+        assert_eq_eps!(1., v.x.value(), 1e-12);
+        assert_eq_eps!(3., v.y.value(), 1e-12);
+        assert_eq_eps!(2., v.z.value(), 1e-12);
+        let v = ned.ecef_to_ned_velocity(&ecef);
+        assert_eq_eps!(3., v.x.value(), 1e-12);
+        assert_eq_eps!(1., v.y.value(), 1e-12);
+        assert_eq_eps!(-2., v.z.value(), 1e-12);
+        let back = ned.ned_to_ecef_velocity(&v);
+        assert_eq_eps!(-1., back.x.value(), 1e-12);
+        assert_eq_eps!(2., back.y.value(), 1e-12);
+        assert_eq_eps!(3., back.z.value(), 1e-12);
+        assert_eq_eps!(14f64.sqrt(), v.magnitude().value(), 1e-12);
+
+        let cov = Matrix::new([[4., 0., 0.], [0., 9., 0.], [0., 0., 16.]]);
+        let c = enu.ecef_to_enu_covariance(&cov);
+        let [[e, _, _], [_, n, _], [_, _, u]] = c.values;
+        assert_eq_eps!(4., e, 1e-12);
+        assert_eq_eps!(16., n, 1e-12);
+        assert_eq_eps!(9., u, 1e-12);
+        let c = ned.ned_to_ecef_covariance(&ned.ecef_to_ned_covariance(&cov));
+        for (row, exp) in c.values.iter().zip(cov.values.iter()) {
+            for (a, b) in row.iter().zip(exp.iter()) {
+                assert_eq_eps!(*b, *a, 1e-12);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_attitude() -> Result<(), ConvertError> {
+        let att = Attitude::new(
+            Angle::new_degrees(30.),
+            Angle::new_degrees(10.),
+            Angle::new_degrees(-5.),
+        );
+        let back = Attitude::from_body_to_ned(&att.body_to_ned());
+        assert_eq_eps!(30., back.yaw.as_degrees().value(), 1e-12);
+        assert_eq_eps!(10., back.pitch.as_degrees().value(), 1e-12);
+        assert_eq_eps!(-5., back.roll.as_degrees().value(), 1e-12);
+
+        // an attitude is preserved through ECEF when the base position doesn't change
+        let a = LocalNED::new(AbsoluteCoordinateType::Elliptical(
+            EllipticalCoordinate::new_degrees_wgs84(42., -71.),
+        ))?;
+        let b = LocalNED::new(AbsoluteCoordinateType::Elliptical(
+            EllipticalCoordinate::new_degrees_wgs84(42., -71.),
+        ))?;
+        let moved = b.attitude_from_ecef(&a.attitude_to_ecef(&att));
+        assert_eq_eps!(30., moved.yaw.as_degrees().value(), 1e-9);
+        assert_eq_eps!(10., moved.pitch.as_degrees().value(), 1e-9);
+        assert_eq_eps!(-5., moved.roll.as_degrees().value(), 1e-9);
+
+        // moving 90 degrees east along the equator tips a north-facing body
+        let a = LocalNED::new(AbsoluteCoordinateType::Elliptical(
+            EllipticalCoordinate::new_degrees_wgs84(0., 0.),
+        ))?;
+        let b = LocalNED::new(AbsoluteCoordinateType::Elliptical(
+            EllipticalCoordinate::new_degrees_wgs84(0., 90.),
+        ))?;
+        let level = Attitude::default();
+        let moved = b.attitude_from_ecef(&a.attitude_to_ecef(&level));
+        assert_eq_eps!(0., moved.yaw.as_degrees().value(), 1e-9);
+        assert_eq_eps!(0., moved.pitch.as_degrees().value(), 1e-9);
+        // the right wing now points up
+        assert_eq_eps!(-90., moved.roll.as_degrees().value(), 1e-9);
         Ok(())
     }
 }
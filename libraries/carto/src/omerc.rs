@@ -0,0 +1,368 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! Hotine Oblique Mercator Map Projection.
+
+use crate::coordinate::{CartesianCoordinate, EllipticalCoordinate, Latitude, Longitude};
+use crate::geo::ellipsoid::Ellipsoid;
+use crate::proj::{GridDistortion, Projection};
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+use irox_units::units::angle::Angle;
+use irox_units::units::length::Length;
+
+///
+/// Where the false easting and northing of a [`HotineObliqueMercator`] are applied.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum ObliqueMercatorVariant {
+    /// EPSG method 9812, at the natural origin, where the initial line crosses the equator of
+    /// the aposphere.  Used by Alaska SPCS Zone 1 (`+no_uoff` in proj).
+    A,
+    /// EPSG method 9815, at the projection center.
+    #[default]
+    B,
+}
+
+///
+/// Builds a [`HotineObliqueMercator`] projection
+#[derive(Debug, Clone)]
+pub struct HotineObliqueMercatorBuilder {
+    variant: ObliqueMercatorVariant,
+    center: EllipticalCoordinate,
+    shape: Ellipsoid,
+    azimuth: Angle,
+    rectified_grid_angle: Option<Angle>,
+    scale_factor: f64,
+    false_easting: Length,
+    false_northing: Length,
+}
+
+impl Default for HotineObliqueMercatorBuilder {
+    fn default() -> Self {
+        Self {
+            variant: ObliqueMercatorVariant::default(),
+            center: EllipticalCoordinate::default(),
+            shape: Ellipsoid::default(),
+            azimuth: Angle::default(),
+            rectified_grid_angle: None,
+            scale_factor: 1.0,
+            false_easting: Length::default(),
+            false_northing: Length::default(),
+        }
+    }
+}
+
+impl HotineObliqueMercatorBuilder {
+    /// Where the false easting & northing are applied, defaults to [`ObliqueMercatorVariant::B`]
+    #[must_use]
+    pub fn with_variant(mut self, variant: ObliqueMercatorVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+    /// The center of the projection, where the initial line crosses the central meridian
+    #[must_use]
+    pub fn with_center(mut self, center: EllipticalCoordinate) -> Self {
+        self.center = center;
+        self
+    }
+    #[must_use]
+    pub fn with_shape(mut self, shape: Ellipsoid) -> Self {
+        self.shape = shape;
+        self
+    }
+    /// The azimuth of the initial (center) line at the projection center
+    #[must_use]
+    pub fn with_azimuth(mut self, azimuth: Angle) -> Self {
+        self.azimuth = azimuth;
+        self
+    }
+    /// The angle from the skew (u,v) grid to the rectified grid, defaults to the azimuth.
+    #[must_use]
+    pub fn with_rectified_grid_angle(mut self, angle: Angle) -> Self {
+        self.rectified_grid_angle = Some(angle);
+        self
+    }
+    /// The scale factor on the initial line
+    #[must_use]
+    pub fn with_scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+    /// The easting at the projection center, or the natural origin for variant A
+    #[must_use]
+    pub fn with_false_easting(mut self, false_easting: Length) -> Self {
+        self.false_easting = false_easting;
+        self
+    }
+    /// The northing at the projection center, or the natural origin for variant A
+    #[must_use]
+    pub fn with_false_northing(mut self, false_northing: Length) -> Self {
+        self.false_northing = false_northing;
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> HotineObliqueMercator {
+        let phic = self.center.get_latitude().0.as_radians().value();
+        let lamc = self.center.get_longitude().0.as_radians().value();
+        let alphac = self.azimuth.as_radians().value();
+        let gammac = self
+            .rectified_grid_angle
+            .unwrap_or(self.azimuth)
+            .as_radians()
+            .value();
+        let a = self.shape.semi_major_axis_a().as_meters().value();
+        let e = self.shape.first_eccentricity();
+        let e2 = self.shape.first_eccentricity_squared();
+        let sinc = phic.sin();
+        let cosc = phic.cos();
+
+        let b = (1. + e2 * cosc.powi(4) / (1. - e2)).sqrt();
+        let big_a = a * b * self.scale_factor * (1. - e2).sqrt() / (1. - e2 * sinc * sinc);
+        let t0 = t(e, phic);
+        let d = (b * (1. - e2).sqrt() / (cosc * (1. - e2 * sinc * sinc).sqrt())).max(1.0);
+        let f = d + (d * d - 1.).sqrt() * phic.signum();
+        let h = f * t0.powf(b);
+        let g = (f - 1. / f) / 2.;
+        let gamma0 = (alphac.sin() / d).asin();
+        let lam0 = lamc - (g * gamma0.tan()).asin() / b;
+        let uc = if (alphac.abs() - FRAC_PI_2).abs() < f64::EPSILON {
+            big_a * (lamc - lam0)
+        } else {
+            (big_a / b) * ((d * d - 1.).sqrt() / alphac.cos()).atan() * phic.signum()
+        };
+        let u_offset = match self.variant {
+            ObliqueMercatorVariant::A => 0.,
+            ObliqueMercatorVariant::B => uc.abs() * phic.signum(),
+        };
+
+        HotineObliqueMercator {
+            center: self.center,
+            shape: self.shape,
+            false_easting: self.false_easting,
+            false_northing: self.false_northing,
+            a,
+            b,
+            big_a,
+            h,
+            gamma0,
+            gammac,
+            lam0,
+            u_offset,
+        }
+    }
+}
+
+/// Isometric latitude function `t`, EPSG Guidance Note 7-2
+fn t(e: f64, phi: f64) -> f64 {
+    let sin = phi.sin();
+    (FRAC_PI_4 - phi / 2.).tan() / ((1. - e * sin) / (1. + e * sin)).powf(e / 2.)
+}
+
+///
+/// An implementation of the Hotine Oblique Mercator projection, with coordinates referenced to
+/// either the natural origin or the projection center, see [`ObliqueMercatorVariant`].
+///
+/// This implementation follows the formulas in EPSG Guidance Note 7-2, section 3.2.4.
+#[derive(Debug, Clone)]
+pub struct HotineObliqueMercator {
+    center: EllipticalCoordinate,
+    shape: Ellipsoid,
+    false_easting: Length,
+    false_northing: Length,
+    a: f64,
+    b: f64,
+    big_a: f64,
+    h: f64,
+    gamma0: f64,
+    gammac: f64,
+    lam0: f64,
+    /// Distance along the initial line from the natural origin to the false origin
+    u_offset: f64,
+}
+
+impl HotineObliqueMercator {
+    #[must_use]
+    pub fn builder() -> HotineObliqueMercatorBuilder {
+        HotineObliqueMercatorBuilder::default()
+    }
+
+    /// Returns the unrectified skew coordinates (u, v) of the provided point, with u measured
+    /// from the natural origin of the projection.
+    fn skew_uv(&self, coord: &EllipticalCoordinate) -> (f64, f64) {
+        let phi = coord.get_latitude().0.as_radians().value();
+        let lam = coord.get_longitude().0.as_radians().value();
+        let dlam = self.b * (lam - self.lam0);
+        let q = self.h / t(self.shape.first_eccentricity(), phi).powf(self.b);
+        let s = (q - 1. / q) / 2.;
+        let tt = f64::midpoint(q, 1. / q);
+        let sin_dlam = dlam.sin();
+        let uu = (-sin_dlam * self.gamma0.cos() + s * self.gamma0.sin()) / tt;
+        let v = self.big_a * ((1. - uu) / (1. + uu)).ln() / (2. * self.b);
+        let u = self.big_a
+            * (s * self.gamma0.cos() + sin_dlam * self.gamma0.sin()).atan2(dlam.cos())
+            / self.b;
+        (u, v)
+    }
+}
+
+impl Projection for HotineObliqueMercator {
+    fn get_center_coords(&self) -> &EllipticalCoordinate {
+        &self.center
+    }
+
+    fn project_to_cartesian(&self, coord: &EllipticalCoordinate) -> CartesianCoordinate {
+        let (u, v) = self.skew_uv(coord);
+        let u = u - self.u_offset;
+        let x = v * self.gammac.cos() + u * self.gammac.sin();
+        let y = u * self.gammac.cos() - v * self.gammac.sin();
+        CartesianCoordinate::new(
+            Length::new_meters(x) + self.false_easting,
+            Length::new_meters(y) + self.false_northing,
+            Length::ZERO,
+        )
+    }
+
+    fn project_to_elliptical(&self, coord: &CartesianCoordinate) -> EllipticalCoordinate {
+        let de = (*coord.get_x() - self.false_easting).as_meters().value();
+        let dn = (*coord.get_y() - self.false_northing).as_meters().value();
+        let v = de * self.gammac.cos() - dn * self.gammac.sin();
+        let u = dn * self.gammac.cos() + de * self.gammac.sin() + self.u_offset;
+
+        let q = (-self.b * v / self.big_a).exp();
+        let s = (q - 1. / q) / 2.;
+        let tt = f64::midpoint(q, 1. / q);
+        let vv = (self.b * u / self.big_a).sin();
+        let uu = (vv * self.gamma0.cos() + s * self.gamma0.sin()) / tt;
+        let tp = (self.h / ((1. + uu) / (1. - uu)).sqrt()).powf(1. / self.b);
+        let chi = FRAC_PI_2 - 2. * tp.atan();
+
+        let e2 = self.shape.first_eccentricity_squared();
+        let e4 = e2 * e2;
+        let e6 = e4 * e2;
+        let e8 = e6 * e2;
+        let phi = chi
+            + (2. * chi).sin() * (e2 / 2. + 5. * e4 / 24. + e6 / 12. + 13. * e8 / 360.)
+            + (4. * chi).sin() * (7. * e4 / 48. + 29. * e6 / 240. + 811. * e8 / 11520.)
+            + (6. * chi).sin() * (7. * e6 / 120. + 81. * e8 / 1120.)
+            + (8. * chi).sin() * (4279. * e8 / 161_280.);
+        let lam = self.lam0
+            - (s * self.gamma0.cos() - vv * self.gamma0.sin())
+                .atan2((self.b * u / self.big_a).cos())
+                / self.b;
+
+        EllipticalCoordinate::new(
+            Latitude(Angle::new_radians(phi)),
+            Longitude(Angle::new_radians(lam)),
+            self.shape.into(),
+        )
+    }
+}
+
+impl GridDistortion for HotineObliqueMercator {
+    ///
+    /// Point scale factor, from Snyder's 'Map Projections - A Working Manual', eq 9-32
+    fn grid_scale_factor(&self, coord: &EllipticalCoordinate) -> f64 {
+        let phi = coord.get_latitude().0.as_radians().value();
+        let lam = coord.get_longitude().0.as_radians().value();
+        let (u, _v) = self.skew_uv(coord);
+        let e2 = self.shape.first_eccentricity_squared();
+        let upper =
+            self.big_a * (self.b * u / self.big_a).cos() * (1. - e2 * phi.sin().powi(2)).sqrt();
+        let lower = self.a * phi.cos() * (self.b * (lam - self.lam0)).cos();
+        upper / lower
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::coordinate::{CartesianCoordinate, EllipticalCoordinate, Latitude, Longitude};
+    use crate::geo::ellipse::Ellipse;
+    use crate::geo::ellipsoid::Ellipsoid;
+    use crate::omerc::{HotineObliqueMercator, ObliqueMercatorVariant};
+    use crate::proj::Projection;
+    use irox_tools::assert_eq_eps;
+    use irox_units::units::angle::Angle;
+    use irox_units::units::length::Length;
+
+    #[test]
+    pub fn test_epsg_example() {
+        // EPSG Guidance Note 7-2, Timbalai 1948 / RSO Borneo (m)
+        let shape: Ellipsoid = Ellipse::new(Length::new_meters(6_377_298.556), 300.8017).into();
+        let proj = HotineObliqueMercator::builder()
+            .with_center(EllipticalCoordinate::new(
+                Latitude(Angle::new_degrees(4.)),
+                Longitude(Angle::new_degrees(115.)),
+                shape.into(),
+            ))
+            .with_shape(shape)
+            .with_azimuth(Angle::new_dms(53, 18, 56.9537))
+            .with_rectified_grid_angle(Angle::new_dms(53, 7, 48.3685))
+            .with_scale_factor(0.99984)
+            .with_false_easting(Length::new_meters(590_476.87))
+            .with_false_northing(Length::new_meters(442_857.65))
+            .build();
+        let pt = EllipticalCoordinate::new(
+            Latitude(Angle::new_dms(5, 23, 14.1129)),
+            Longitude(Angle::new_dms(115, 48, 19.8196)),
+            shape.into(),
+        );
+        let xy = proj.project_to_cartesian(&pt);
+        assert_eq_eps!(679_245.73, xy.get_x().as_meters().value(), 1e-2);
+        assert_eq_eps!(596_562.78, xy.get_y().as_meters().value(), 1e-2);
+
+        let lla = proj
+            .project_to_elliptical(&CartesianCoordinate::new_meters(679_245.73, 596_562.78, 0.));
+        assert_eq_eps!(
+            pt.get_latitude().0.as_degrees().value(),
+            lla.get_latitude().0.as_degrees().value(),
+            1e-7
+        );
+        assert_eq_eps!(
+            pt.get_longitude().0.as_degrees().value(),
+            lla.get_longitude().0.as_degrees().value(),
+            1e-7
+        );
+    }
+
+    #[test]
+    pub fn test_epsg_example_variant_a() {
+        // EPSG Guidance Note 7-2, Timbalai 1948 / RSO Borneo (m), as method 9812 the false
+        // easting and northing are zero at the natural origin.
+        let shape: Ellipsoid = Ellipse::new(Length::new_meters(6_377_298.556), 300.8017).into();
+        let proj = HotineObliqueMercator::builder()
+            .with_variant(ObliqueMercatorVariant::A)
+            .with_center(EllipticalCoordinate::new(
+                Latitude(Angle::new_degrees(4.)),
+                Longitude(Angle::new_degrees(115.)),
+                shape.into(),
+            ))
+            .with_shape(shape)
+            .with_azimuth(Angle::new_dms(53, 18, 56.9537))
+            .with_rectified_grid_angle(Angle::new_dms(53, 7, 48.3685))
+            .with_scale_factor(0.99984)
+            .build();
+        let pt = EllipticalCoordinate::new(
+            Latitude(Angle::new_dms(5, 23, 14.1129)),
+            Longitude(Angle::new_dms(115, 48, 19.8196)),
+            shape.into(),
+        );
+        let xy = proj.project_to_cartesian(&pt);
+        assert_eq_eps!(679_245.73, xy.get_x().as_meters().value(), 1e-2);
+        assert_eq_eps!(596_562.78, xy.get_y().as_meters().value(), 1e-2);
+
+        let lla = proj
+            .project_to_elliptical(&CartesianCoordinate::new_meters(679_245.73, 596_562.78, 0.));
+        assert_eq_eps!(
+            pt.get_latitude().0.as_degrees().value(),
+            lla.get_latitude().0.as_degrees().value(),
+            1e-7
+        );
+        assert_eq_eps!(
+            pt.get_longitude().0.as_degrees().value(),
+            lla.get_longitude().0.as_degrees().value(),
+            1e-7
+        );
+    }
+}
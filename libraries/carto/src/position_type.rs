@@ -7,14 +7,21 @@
 
 use crate::altitude::Altitude;
 use crate::coordinate::{
-    AbsoluteCoordinateType, CartesianCoordinate, EllipticalCoordinate, EllipticalCoordinateBuilder,
-    Latitude, Longitude, PositionUncertainty,
+    AbsoluteCoordinateType, CartesianCoordinate, Elevation, EllipticalCoordinate,
+    EllipticalCoordinateBuilder, HorizontalCoordinate, Latitude, Longitude, PositionUncertainty,
 };
 use crate::error::ConvertError;
 use crate::geo::standards::wgs84::WGS84_SHAPE;
 use core::ops::Deref;
+use irox_tools::cfg_feature_std;
+use irox_units::units::compass::Azimuth;
 use irox_units::units::length::Length;
 
+cfg_feature_std! {
+    use irox_units::units::angle::Angle;
+    use irox_units::units::compass::{CompassReference, RotationDirection};
+}
+
 ///
 /// Different variants of the same position.
 #[non_exhaustive]
@@ -24,6 +31,7 @@ pub enum PositionType {
     WGS84(WGS84Position),
     EastNorthUp(ENUPosition),
     NorthEastDown(NEDPosition),
+    AzimuthElevationRange(AERPosition),
 }
 
 ///
@@ -144,9 +152,127 @@ pub struct NEDPosition {
     coordinate: CartesianCoordinate,
 }
 impl NEDPosition {
+    pub fn new(base_position: AbsoluteCoordinateType, coordinate: CartesianCoordinate) -> Self {
+        Self {
+            base_position,
+            coordinate,
+        }
+    }
     pub fn as_position_type(&self) -> PositionType {
         PositionType::NorthEastDown(*self)
     }
+    pub fn base_position(&self) -> &AbsoluteCoordinateType {
+        &self.base_position
+    }
+    pub fn coordinate(&self) -> &CartesianCoordinate {
+        &self.coordinate
+    }
+}
+
+impl From<ENUPosition> for NEDPosition {
+    fn from(value: ENUPosition) -> Self {
+        let c = value.coordinate;
+        NEDPosition::new(
+            value.base_position,
+            CartesianCoordinate::new(*c.get_y(), *c.get_x(), *c.get_z() * -1.),
+        )
+    }
+}
+impl From<NEDPosition> for ENUPosition {
+    fn from(value: NEDPosition) -> Self {
+        let c = value.coordinate;
+        ENUPosition::new(
+            value.base_position,
+            CartesianCoordinate::new(*c.get_y(), *c.get_x(), *c.get_z() * -1.),
+        )
+    }
+}
+
+///
+/// Represents a position as a look angle and slant range (`AzimuthElevationRange`) from a base
+/// position.  Azimuth is clockwise from true north, elevation is up from the local horizontal
+/// plane.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AERPosition {
+    look_angle: HorizontalCoordinate,
+    range: Length,
+}
+impl AERPosition {
+    pub fn new(
+        base_position: AbsoluteCoordinateType,
+        azimuth: Azimuth,
+        elevation: Elevation,
+        range: Length,
+    ) -> Self {
+        Self {
+            look_angle: HorizontalCoordinate::new(base_position, azimuth, elevation),
+            range,
+        }
+    }
+    pub fn as_position_type(&self) -> PositionType {
+        PositionType::AzimuthElevationRange(*self)
+    }
+    pub fn base_position(&self) -> &AbsoluteCoordinateType {
+        self.look_angle.reference()
+    }
+    pub fn look_angle(&self) -> &HorizontalCoordinate {
+        &self.look_angle
+    }
+    pub fn azimuth(&self) -> &Azimuth {
+        self.look_angle.azimuth()
+    }
+    pub fn elevation(&self) -> &Elevation {
+        self.look_angle.elevation()
+    }
+    pub fn range(&self) -> &Length {
+        &self.range
+    }
+}
+
+cfg_feature_std! {
+    impl From<ENUPosition> for AERPosition {
+        fn from(value: ENUPosition) -> Self {
+            let c = value.coordinate;
+            let e = c.get_x().as_meters().value();
+            let n = c.get_y().as_meters().value();
+            let u = c.get_z().as_meters().value();
+            let horiz = e.hypot(n);
+            let mut az = e.atan2(n);
+            if az < 0. {
+                az += core::f64::consts::TAU;
+            }
+            AERPosition::new(
+                value.base_position,
+                Azimuth::new_azimuth(
+                    Angle::new_radians(az),
+                    RotationDirection::PositiveClockwise,
+                    CompassReference::TrueNorth,
+                ),
+                Elevation(Angle::new_radians(u.atan2(horiz))),
+                Length::new_meters(horiz.hypot(u)),
+            )
+        }
+    }
+    impl From<AERPosition> for ENUPosition {
+        fn from(value: AERPosition) -> Self {
+            let az = value
+                .azimuth()
+                .as_direction_reference(
+                    RotationDirection::PositiveClockwise,
+                    CompassReference::TrueNorth,
+                )
+                .angle()
+                .as_radians()
+                .value();
+            let el = value.elevation().0.as_radians().value();
+            let r = value.range.as_meters().value();
+            let horiz = r * el.cos();
+            ENUPosition::new(
+                *value.base_position(),
+                CartesianCoordinate::new_meters(horiz * az.sin(), horiz * az.cos(), r * el.sin()),
+            )
+        }
+    }
 }
 
 ///
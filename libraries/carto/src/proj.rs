@@ -6,6 +6,12 @@
 
 use crate::coordinate::{CartesianCoordinate, EllipticalCoordinate};
 use crate::range::{CartesianRange, EllipticalRange};
+use irox_tools::cfg_feature_std;
+
+cfg_feature_std! {
+    use crate::coordinate::Latitude;
+    use irox_units::units::angle::Angle;
+}
 
 ///
 /// Allows a projection from Elliptical to Cartesian coordinates
@@ -28,3 +34,63 @@ pub struct ProjectionBounds {
     pub elliptical_bounds: EllipticalRange,
     pub cartesian_range: CartesianRange,
 }
+
+cfg_feature_std! {
+    /// Latitude step used by the numerical [`GridDistortion`] defaults, about 60cm on the ground.
+    const NUMERIC_DPHI: f64 = 1e-7;
+
+    ///
+    /// The local distortion of a conformal projection at a specific point, used to convert
+    /// ground (ellipsoidal) distances and azimuths into grid distances and azimuths.
+    ///
+    /// The default implementations are computed numerically by projecting a second point a short
+    /// distance north along the meridian.  Projections with closed-form expressions override them.
+    pub trait GridDistortion: Projection {
+        ///
+        /// Returns the point scale factor `k` at the provided coordinate - the ratio of a
+        /// grid distance to the equivalent distance on the ellipsoid.
+        fn grid_scale_factor(&self, coord: &EllipticalCoordinate) -> f64 {
+            let Ok(shape) = coord.get_reference_frame().as_ellipsoid() else {
+                return f64::NAN;
+            };
+            let (de, dn) = numeric_meridian_step(self, coord);
+            let grid = (de * de + dn * dn).sqrt();
+            let ground = shape
+                .radius_curvature_meridian(coord.get_latitude())
+                .as_meters()
+                .value()
+                * NUMERIC_DPHI;
+            grid / ground
+        }
+
+        ///
+        /// Returns the meridian convergence angle `γ` at the provided coordinate - the angle
+        /// measured clockwise from true (geodetic) north to grid north.  Following the NGS
+        /// convention, this is positive east of the central meridian in the northern hemisphere,
+        /// so that `grid azimuth = geodetic azimuth - γ`.
+        fn convergence_angle(&self, coord: &EllipticalCoordinate) -> Angle {
+            let (de, dn) = numeric_meridian_step(self, coord);
+            Angle::new_radians(-de.atan2(dn))
+        }
+    }
+
+    /// Projects the coordinate and a second point [`NUMERIC_DPHI`] radians to the north, returning
+    /// the (easting, northing) grid delta in meters.
+    fn numeric_meridian_step<T: Projection + ?Sized>(
+        proj: &T,
+        coord: &EllipticalCoordinate,
+    ) -> (f64, f64) {
+        let north = EllipticalCoordinate::new(
+            Latitude(Angle::new_radians(
+                coord.get_latitude().0.as_radians().value() + NUMERIC_DPHI,
+            )),
+            *coord.get_longitude(),
+            *coord.get_reference_frame(),
+        );
+        let a = proj.project_to_cartesian(coord);
+        let b = proj.project_to_cartesian(&north);
+        let de = (*b.get_x() - *a.get_x()).as_meters().value();
+        let dn = (*b.get_y() - *a.get_y()).as_meters().value();
+        (de, dn)
+    }
+}
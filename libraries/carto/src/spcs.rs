@@ -2,9 +2,16 @@
 // Copyright 2025 IROX Contributors
 //
 
+//!
+//! State Plane Coordinate System (SPCS) projections, and the catalog of NAD83 SPCS zones.
+
 use crate::coordinate::{CartesianCoordinate, EllipticalCoordinate, Latitude, Longitude};
 use crate::geo::standards::wgs84::WGS84_SHAPE;
-use crate::proj::Projection;
+use crate::geo::standards::StandardShapes;
+use crate::lcc::{LambertConformalConicBuilder, LambertConformalConicElliptical};
+use crate::omerc::{HotineObliqueMercator, ObliqueMercatorVariant};
+use crate::proj::{GridDistortion, Projection};
+use crate::tm::TransverseMercator;
 use irox_units::units::angle::Angle;
 use irox_units::units::length::Length;
 
@@ -25,9 +32,8 @@ pub struct SPCS {
 }
 impl Projection for SPCS {
     fn get_center_coords(&self) -> &EllipticalCoordinate {
-        todo!()
+        &self.params.ctr
     }
-
     fn project_to_cartesian(&self, coord: &EllipticalCoordinate) -> CartesianCoordinate {
         let b0 = self.params.ctr.get_latitude();
         let l0 = self.params.ctr.get_longitude();
@@ -90,3 +96,566 @@ pub const MASS_MAINLAND: SPCS = SPCS {
         r0: 7044348.7021,
     },
 };
+impl GridDistortion for SPCS {}
+
+///
+/// The map projection underlying a particular SPCS zone.  All angles are in degrees, on the
+/// GRS80 ellipsoid.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SPCSProjectionParams {
+    TransverseMercator {
+        origin_latitude: Angle,
+        central_meridian: Angle,
+        scale_factor: f64,
+    },
+    LambertConformalConic {
+        first_parallel: Angle,
+        second_parallel: Angle,
+        origin_latitude: Angle,
+        central_meridian: Angle,
+    },
+    ObliqueMercator {
+        center_latitude: Angle,
+        center_longitude: Angle,
+        azimuth: Angle,
+        scale_factor: f64,
+    },
+}
+
+///
+/// A single NAD83 State Plane Coordinate System zone.  Grid coordinates are in meters, matching
+/// the metric EPSG definition of the zone, regardless of the unit legislated by each state.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SPCSZone {
+    /// Human-readable name, like 'Massachusetts Mainland'
+    pub name: &'static str,
+    /// The FIPS zone code, like `2001`
+    pub fips: u16,
+    /// The EPSG code of the metric NAD83 CRS, like `26986`
+    pub epsg: u32,
+    pub false_easting: Length,
+    pub false_northing: Length,
+    pub params: SPCSProjectionParams,
+}
+
+/// Returns the angle `deg` degrees and `min` minutes, where a negative `deg` is west/south.
+const fn dm(deg: f64, min: f64) -> Angle {
+    if deg < 0. {
+        Angle::new_degrees(deg - min / 60.)
+    } else {
+        Angle::new_degrees(deg + min / 60.)
+    }
+}
+
+/// Returns the scale factor `1 - 1/n`, the form in which the NGS publishes TM scale factors.
+const fn k(n: f64) -> f64 {
+    1. - 1. / n
+}
+
+/// Alaska Zone 1's skew axis has an azimuth of `atan(-3/4)`
+const ALASKA_1_AZIMUTH: Angle = Angle::new_degrees(-36.869_897_645_844_02);
+
+impl SPCSZone {
+    #[allow(clippy::too_many_arguments)]
+    const fn tm(
+        fips: u16,
+        epsg: u32,
+        name: &'static str,
+        origin_latitude: Angle,
+        central_meridian: Angle,
+        scale_factor: f64,
+        false_easting: f64,
+        false_northing: f64,
+    ) -> SPCSZone {
+        SPCSZone {
+            name,
+            fips,
+            epsg,
+            false_easting: Length::new_meters(false_easting),
+            false_northing: Length::new_meters(false_northing),
+            params: SPCSProjectionParams::TransverseMercator {
+                origin_latitude,
+                central_meridian,
+                scale_factor,
+            },
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    const fn lcc(
+        fips: u16,
+        epsg: u32,
+        name: &'static str,
+        first_parallel: Angle,
+        second_parallel: Angle,
+        origin_latitude: Angle,
+        central_meridian: Angle,
+        false_easting: f64,
+        false_northing: f64,
+    ) -> SPCSZone {
+        SPCSZone {
+            name,
+            fips,
+            epsg,
+            false_easting: Length::new_meters(false_easting),
+            false_northing: Length::new_meters(false_northing),
+            params: SPCSProjectionParams::LambertConformalConic {
+                first_parallel,
+                second_parallel,
+                origin_latitude,
+                central_meridian,
+            },
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    const fn omerc(
+        fips: u16,
+        epsg: u32,
+        name: &'static str,
+        center_latitude: Angle,
+        center_longitude: Angle,
+        azimuth: Angle,
+        scale_factor: f64,
+        false_easting: f64,
+        false_northing: f64,
+    ) -> SPCSZone {
+        SPCSZone {
+            name,
+            fips,
+            epsg,
+            false_easting: Length::new_meters(false_easting),
+            false_northing: Length::new_meters(false_northing),
+            params: SPCSProjectionParams::ObliqueMercator {
+                center_latitude,
+                center_longitude,
+                azimuth,
+                scale_factor,
+            },
+        }
+    }
+
+    ///
+    /// Finds the zone with the provided FIPS zone code, like `2001` for Massachusetts Mainland.
+    #[must_use]
+    pub fn find_by_fips(fips: u16) -> Option<&'static SPCSZone> {
+        NAD83_SPCS_ZONES.iter().find(|z| z.fips == fips)
+    }
+
+    ///
+    /// Finds the zone with the provided (metric) EPSG code, like `26986` for Massachusetts
+    /// Mainland.
+    #[must_use]
+    pub fn find_by_epsg(epsg: u32) -> Option<&'static SPCSZone> {
+        NAD83_SPCS_ZONES.iter().find(|z| z.epsg == epsg)
+    }
+
+    ///
+    /// Builds the map projection for this zone.
+    #[must_use]
+    pub fn projection(&self) -> SPCSProjection {
+        let shape = StandardShapes::NAD83;
+        match self.params {
+            SPCSProjectionParams::TransverseMercator {
+                origin_latitude,
+                central_meridian,
+                scale_factor,
+            } => SPCSProjection::TransverseMercator(
+                TransverseMercator::builder()
+                    .with_shape(shape.as_ellipsoid())
+                    .with_false_easting(self.false_easting)
+                    .with_false_northing(self.false_northing)
+                    .with_center(EllipticalCoordinate::new(
+                        Latitude(origin_latitude),
+                        Longitude(central_meridian),
+                        shape.into(),
+                    ))
+                    .with_scale_factor(scale_factor)
+                    .build(),
+            ),
+            SPCSProjectionParams::LambertConformalConic {
+                first_parallel,
+                second_parallel,
+                origin_latitude,
+                central_meridian,
+            } => SPCSProjection::LambertConformalConic(
+                LambertConformalConicBuilder::default()
+                    .with_shape(shape.as_ellipsoid())
+                    .with_first_parallel(Latitude(first_parallel))
+                    .with_second_parallel(Latitude(second_parallel))
+                    .with_center(EllipticalCoordinate::new(
+                        Latitude(origin_latitude),
+                        Longitude(central_meridian),
+                        shape.into(),
+                    ))
+                    .with_false_easting(self.false_easting)
+                    .with_false_northing(self.false_northing)
+                    .build_elliptical(),
+            ),
+            SPCSProjectionParams::ObliqueMercator {
+                center_latitude,
+                center_longitude,
+                azimuth,
+                scale_factor,
+            } => SPCSProjection::ObliqueMercator(
+                // Alaska Zone 1, the only oblique zone, references the natural origin.
+                HotineObliqueMercator::builder()
+                    .with_variant(ObliqueMercatorVariant::A)
+                    .with_shape(shape.as_ellipsoid())
+                    .with_center(EllipticalCoordinate::new(
+                        Latitude(center_latitude),
+                        Longitude(center_longitude),
+                        shape.into(),
+                    ))
+                    .with_azimuth(azimuth)
+                    .with_scale_factor(scale_factor)
+                    .with_false_easting(self.false_easting)
+                    .with_false_northing(self.false_northing)
+                    .build(),
+            ),
+        }
+    }
+}
+
+///
+/// A built SPCS zone projection, see [`SPCSZone::projection`]
+pub enum SPCSProjection {
+    TransverseMercator(TransverseMercator),
+    LambertConformalConic(LambertConformalConicElliptical),
+    ObliqueMercator(HotineObliqueMercator),
+}
+
+impl SPCSProjection {
+    fn inner(&self) -> &dyn GridDistortion {
+        match self {
+            SPCSProjection::TransverseMercator(p) => p,
+            SPCSProjection::LambertConformalConic(p) => p,
+            SPCSProjection::ObliqueMercator(p) => p,
+        }
+    }
+}
+
+impl Projection for SPCSProjection {
+    fn get_center_coords(&self) -> &EllipticalCoordinate {
+        self.inner().get_center_coords()
+    }
+
+    fn project_to_cartesian(&self, coord: &EllipticalCoordinate) -> CartesianCoordinate {
+        self.inner().project_to_cartesian(coord)
+    }
+
+    fn project_to_elliptical(&self, coord: &CartesianCoordinate) -> EllipticalCoordinate {
+        self.inner().project_to_elliptical(coord)
+    }
+}
+
+impl GridDistortion for SPCSProjection {
+    fn grid_scale_factor(&self, coord: &EllipticalCoordinate) -> f64 {
+        self.inner().grid_scale_factor(coord)
+    }
+
+    fn convergence_angle(&self, coord: &EllipticalCoordinate) -> Angle {
+        self.inner().convergence_angle(coord)
+    }
+}
+
+///
+/// All NAD83 State Plane Coordinate System zones, from NOAA Manual NOS NGS 5, ordered by
+/// FIPS zone code.
+#[rustfmt::skip]
+pub static NAD83_SPCS_ZONES: &[SPCSZone] = &[
+    SPCSZone::tm(101, 26929, "Alabama East", dm(30., 30.), dm(-85., 50.), k(25_000.), 200_000., 0.),
+    SPCSZone::tm(102, 26930, "Alabama West", dm(30., 0.), dm(-87., 30.), k(15_000.), 600_000., 0.),
+    SPCSZone::tm(201, 26948, "Arizona East", dm(31., 0.), dm(-110., 10.), k(10_000.), 213_360., 0.),
+    SPCSZone::tm(202, 26949, "Arizona Central", dm(31., 0.), dm(-111., 55.), k(10_000.), 213_360., 0.),
+    SPCSZone::tm(203, 26950, "Arizona West", dm(31., 0.), dm(-113., 45.), k(15_000.), 213_360., 0.),
+    SPCSZone::lcc(301, 26951, "Arkansas North", dm(36., 14.), dm(34., 56.), dm(34., 20.), dm(-92., 0.), 400_000., 0.),
+    SPCSZone::lcc(302, 26952, "Arkansas South", dm(34., 46.), dm(33., 18.), dm(32., 40.), dm(-92., 0.), 400_000., 400_000.),
+    SPCSZone::lcc(401, 26941, "California Zone 1", dm(41., 40.), dm(40., 0.), dm(39., 20.), dm(-122., 0.), 2_000_000., 500_000.),
+    SPCSZone::lcc(402, 26942, "California Zone 2", dm(39., 50.), dm(38., 20.), dm(37., 40.), dm(-122., 0.), 2_000_000., 500_000.),
+    SPCSZone::lcc(403, 26943, "California Zone 3", dm(38., 26.), dm(37., 4.), dm(36., 30.), dm(-120., 30.), 2_000_000., 500_000.),
+    SPCSZone::lcc(404, 26944, "California Zone 4", dm(37., 15.), dm(36., 0.), dm(35., 20.), dm(-119., 0.), 2_000_000., 500_000.),
+    SPCSZone::lcc(405, 26945, "California Zone 5", dm(35., 28.), dm(34., 2.), dm(33., 30.), dm(-118., 0.), 2_000_000., 500_000.),
+    SPCSZone::lcc(406, 26946, "California Zone 6", dm(33., 53.), dm(32., 47.), dm(32., 10.), dm(-116., 15.), 2_000_000., 500_000.),
+    SPCSZone::lcc(501, 26953, "Colorado North", dm(40., 47.), dm(39., 43.), dm(39., 20.), dm(-105., 30.), 914_401.828_9, 304_800.609_6),
+    SPCSZone::lcc(502, 26954, "Colorado Central", dm(39., 45.), dm(38., 27.), dm(37., 50.), dm(-105., 30.), 914_401.828_9, 304_800.609_6),
+    SPCSZone::lcc(503, 26955, "Colorado South", dm(38., 26.), dm(37., 14.), dm(36., 40.), dm(-105., 30.), 914_401.828_9, 304_800.609_6),
+    SPCSZone::lcc(600, 26956, "Connecticut", dm(41., 52.), dm(41., 12.), dm(40., 50.), dm(-72., 45.), 304_800.609_6, 152_400.304_8),
+    SPCSZone::tm(700, 26957, "Delaware", dm(38., 0.), dm(-75., 25.), k(200_000.), 200_000., 0.),
+    SPCSZone::tm(901, 26958, "Florida East", dm(24., 20.), dm(-81., 0.), k(17_000.), 200_000., 0.),
+    SPCSZone::tm(902, 26959, "Florida West", dm(24., 20.), dm(-82., 0.), k(17_000.), 200_000., 0.),
+    SPCSZone::lcc(903, 26960, "Florida North", dm(30., 45.), dm(29., 35.), dm(29., 0.), dm(-84., 30.), 600_000., 0.),
+    SPCSZone::tm(1001, 26966, "Georgia East", dm(30., 0.), dm(-82., 10.), k(10_000.), 200_000., 0.),
+    SPCSZone::tm(1002, 26967, "Georgia West", dm(30., 0.), dm(-84., 10.), k(10_000.), 700_000., 0.),
+    SPCSZone::tm(1101, 26968, "Idaho East", dm(41., 40.), dm(-112., 10.), k(19_000.), 200_000., 0.),
+    SPCSZone::tm(1102, 26969, "Idaho Central", dm(41., 40.), dm(-114., 0.), k(19_000.), 500_000., 0.),
+    SPCSZone::tm(1103, 26970, "Idaho West", dm(41., 40.), dm(-115., 45.), k(15_000.), 800_000., 0.),
+    SPCSZone::tm(1201, 26971, "Illinois East", dm(36., 40.), dm(-88., 20.), k(40_000.), 300_000., 0.),
+    SPCSZone::tm(1202, 26972, "Illinois West", dm(36., 40.), dm(-90., 10.), k(17_000.), 700_000., 0.),
+    SPCSZone::tm(1301, 26973, "Indiana East", dm(37., 30.), dm(-85., 40.), k(30_000.), 100_000., 250_000.),
+    SPCSZone::tm(1302, 26974, "Indiana West", dm(37., 30.), dm(-87., 5.), k(30_000.), 900_000., 250_000.),
+    SPCSZone::lcc(1401, 26975, "Iowa North", dm(43., 16.), dm(42., 4.), dm(41., 30.), dm(-93., 30.), 1_500_000., 1_000_000.),
+    SPCSZone::lcc(1402, 26976, "Iowa South", dm(41., 47.), dm(40., 37.), dm(40., 0.), dm(-93., 30.), 500_000., 0.),
+    SPCSZone::lcc(1501, 26977, "Kansas North", dm(39., 47.), dm(38., 43.), dm(38., 20.), dm(-98., 0.), 400_000., 0.),
+    SPCSZone::lcc(1502, 26978, "Kansas South", dm(38., 34.), dm(37., 16.), dm(36., 40.), dm(-98., 30.), 400_000., 400_000.),
+    SPCSZone::lcc(1600, 3088, "Kentucky Single Zone", dm(37., 5.), dm(38., 40.), dm(36., 20.), dm(-85., 45.), 1_500_000., 1_000_000.),
+    SPCSZone::lcc(1601, 2205, "Kentucky North", dm(38., 58.), dm(37., 58.), dm(37., 30.), dm(-84., 15.), 500_000., 0.),
+    SPCSZone::lcc(1602, 26980, "Kentucky South", dm(37., 56.), dm(36., 44.), dm(36., 20.), dm(-85., 45.), 500_000., 500_000.),
+    SPCSZone::lcc(1701, 26981, "Louisiana North", dm(32., 40.), dm(31., 10.), dm(30., 30.), dm(-92., 30.), 1_000_000., 0.),
+    SPCSZone::lcc(1702, 26982, "Louisiana South", dm(30., 42.), dm(29., 18.), dm(28., 30.), dm(-91., 20.), 1_000_000., 0.),
+    SPCSZone::lcc(1703, 32199, "Louisiana Offshore", dm(27., 50.), dm(26., 10.), dm(25., 30.), dm(-91., 20.), 1_000_000., 0.),
+    SPCSZone::tm(1801, 26983, "Maine East", dm(43., 40.), dm(-68., 30.), k(10_000.), 300_000., 0.),
+    SPCSZone::tm(1802, 26984, "Maine West", dm(42., 50.), dm(-70., 10.), k(30_000.), 900_000., 0.),
+    SPCSZone::lcc(1900, 26985, "Maryland", dm(39., 27.), dm(38., 18.), dm(37., 40.), dm(-77., 0.), 400_000., 0.),
+    SPCSZone::lcc(2001, 26986, "Massachusetts Mainland", dm(42., 41.), dm(41., 43.), dm(41., 0.), dm(-71., 30.), 200_000., 750_000.),
+    SPCSZone::lcc(2002, 26987, "Massachusetts Island", dm(41., 29.), dm(41., 17.), dm(41., 0.), dm(-70., 30.), 500_000., 0.),
+    SPCSZone::lcc(2111, 26988, "Michigan North", dm(47., 5.), dm(45., 29.), dm(44., 47.), dm(-87., 0.), 8_000_000., 0.),
+    SPCSZone::lcc(2112, 26989, "Michigan Central", dm(45., 42.), dm(44., 11.), dm(43., 19.), dm(-84., 22.), 6_000_000., 0.),
+    SPCSZone::lcc(2113, 26990, "Michigan South", dm(43., 40.), dm(42., 6.), dm(41., 30.), dm(-84., 22.), 4_000_000., 0.),
+    SPCSZone::lcc(2201, 26991, "Minnesota North", dm(48., 38.), dm(47., 2.), dm(46., 30.), dm(-93., 6.), 800_000., 100_000.),
+    SPCSZone::lcc(2202, 26992, "Minnesota Central", dm(47., 3.), dm(45., 37.), dm(45., 0.), dm(-94., 15.), 800_000., 100_000.),
+    SPCSZone::lcc(2203, 26993, "Minnesota South", dm(45., 13.), dm(43., 47.), dm(43., 0.), dm(-94., 0.), 800_000., 100_000.),
+    SPCSZone::tm(2301, 26994, "Mississippi East", dm(29., 30.), dm(-88., 50.), k(20_000.), 300_000., 0.),
+    SPCSZone::tm(2302, 26995, "Mississippi West", dm(29., 30.), dm(-90., 20.), k(20_000.), 700_000., 0.),
+    SPCSZone::tm(2401, 26996, "Missouri East", dm(35., 50.), dm(-90., 30.), k(15_000.), 250_000., 0.),
+    SPCSZone::tm(2402, 26997, "Missouri Central", dm(35., 50.), dm(-92., 30.), k(15_000.), 500_000., 0.),
+    SPCSZone::tm(2403, 26998, "Missouri West", dm(36., 10.), dm(-94., 30.), k(17_000.), 850_000., 0.),
+    SPCSZone::lcc(2500, 32100, "Montana", dm(49., 0.), dm(45., 0.), dm(44., 15.), dm(-109., 30.), 600_000., 0.),
+    SPCSZone::lcc(2600, 32104, "Nebraska", dm(43., 0.), dm(40., 0.), dm(39., 50.), dm(-100., 0.), 500_000., 0.),
+    SPCSZone::tm(2701, 32107, "Nevada East", dm(34., 45.), dm(-115., 35.), k(10_000.), 200_000., 8_000_000.),
+    SPCSZone::tm(2702, 32108, "Nevada Central", dm(34., 45.), dm(-116., 40.), k(10_000.), 500_000., 6_000_000.),
+    SPCSZone::tm(2703, 32109, "Nevada West", dm(34., 45.), dm(-118., 35.), k(10_000.), 800_000., 4_000_000.),
+    SPCSZone::tm(2800, 32110, "New Hampshire", dm(42., 30.), dm(-71., 40.), k(30_000.), 300_000., 0.),
+    SPCSZone::tm(2900, 32111, "New Jersey", dm(38., 50.), dm(-74., 30.), k(10_000.), 150_000., 0.),
+    SPCSZone::tm(3001, 32112, "New Mexico East", dm(31., 0.), dm(-104., 20.), k(11_000.), 165_000., 0.),
+    SPCSZone::tm(3002, 32113, "New Mexico Central", dm(31., 0.), dm(-106., 15.), k(10_000.), 500_000., 0.),
+    SPCSZone::tm(3003, 32114, "New Mexico West", dm(31., 0.), dm(-107., 50.), k(12_000.), 830_000., 0.),
+    SPCSZone::tm(3101, 32115, "New York East", dm(38., 50.), dm(-74., 30.), k(10_000.), 150_000., 0.),
+    SPCSZone::tm(3102, 32116, "New York Central", dm(40., 0.), dm(-76., 35.), k(16_000.), 250_000., 0.),
+    SPCSZone::tm(3103, 32117, "New York West", dm(40., 0.), dm(-78., 35.), k(16_000.), 350_000., 0.),
+    SPCSZone::lcc(3104, 32118, "New York Long Island", dm(41., 2.), dm(40., 40.), dm(40., 10.), dm(-74., 0.), 300_000., 0.),
+    SPCSZone::lcc(3200, 32119, "North Carolina", dm(36., 10.), dm(34., 20.), dm(33., 45.), dm(-79., 0.), 609_601.22, 0.),
+    SPCSZone::lcc(3301, 32120, "North Dakota North", dm(48., 44.), dm(47., 26.), dm(47., 0.), dm(-100., 30.), 600_000., 0.),
+    SPCSZone::lcc(3302, 32121, "North Dakota South", dm(47., 29.), dm(46., 11.), dm(45., 40.), dm(-100., 30.), 600_000., 0.),
+    SPCSZone::lcc(3401, 32122, "Ohio North", dm(41., 42.), dm(40., 26.), dm(39., 40.), dm(-82., 30.), 600_000., 0.),
+    SPCSZone::lcc(3402, 32123, "Ohio South", dm(40., 2.), dm(38., 44.), dm(38., 0.), dm(-82., 30.), 600_000., 0.),
+    SPCSZone::lcc(3501, 32124, "Oklahoma North", dm(36., 46.), dm(35., 34.), dm(35., 0.), dm(-98., 0.), 600_000., 0.),
+    SPCSZone::lcc(3502, 32125, "Oklahoma South", dm(35., 14.), dm(33., 56.), dm(33., 20.), dm(-98., 0.), 600_000., 0.),
+    SPCSZone::lcc(3601, 32126, "Oregon North", dm(46., 0.), dm(44., 20.), dm(43., 40.), dm(-120., 30.), 2_500_000., 0.),
+    SPCSZone::lcc(3602, 32127, "Oregon South", dm(44., 0.), dm(42., 20.), dm(41., 40.), dm(-120., 30.), 1_500_000., 0.),
+    SPCSZone::lcc(3701, 32128, "Pennsylvania North", dm(41., 57.), dm(40., 53.), dm(40., 10.), dm(-77., 45.), 600_000., 0.),
+    SPCSZone::lcc(3702, 32129, "Pennsylvania South", dm(40., 58.), dm(39., 56.), dm(39., 20.), dm(-77., 45.), 600_000., 0.),
+    SPCSZone::tm(3800, 32130, "Rhode Island", dm(41., 5.), dm(-71., 30.), k(160_000.), 100_000., 0.),
+    SPCSZone::lcc(3900, 32133, "South Carolina", dm(34., 50.), dm(32., 30.), dm(31., 50.), dm(-81., 0.), 609_600., 0.),
+    SPCSZone::lcc(4001, 32134, "South Dakota North", dm(45., 41.), dm(44., 25.), dm(43., 50.), dm(-100., 0.), 600_000., 0.),
+    SPCSZone::lcc(4002, 32135, "South Dakota South", dm(44., 24.), dm(42., 50.), dm(42., 20.), dm(-100., 20.), 600_000., 0.),
+    SPCSZone::lcc(4100, 32136, "Tennessee", dm(36., 25.), dm(35., 15.), dm(34., 20.), dm(-86., 0.), 600_000., 0.),
+    SPCSZone::lcc(4201, 32137, "Texas North", dm(36., 11.), dm(34., 39.), dm(34., 0.), dm(-101., 30.), 200_000., 1_000_000.),
+    SPCSZone::lcc(4202, 32138, "Texas North Central", dm(33., 58.), dm(32., 8.), dm(31., 40.), dm(-98., 30.), 600_000., 2_000_000.),
+    SPCSZone::lcc(4203, 32139, "Texas Central", dm(31., 53.), dm(30., 7.), dm(29., 40.), dm(-100., 20.), 700_000., 3_000_000.),
+    SPCSZone::lcc(4204, 32140, "Texas South Central", dm(30., 17.), dm(28., 23.), dm(27., 50.), dm(-99., 0.), 600_000., 4_000_000.),
+    SPCSZone::lcc(4205, 32141, "Texas South", dm(27., 50.), dm(26., 10.), dm(25., 40.), dm(-98., 30.), 300_000., 5_000_000.),
+    SPCSZone::lcc(4301, 32142, "Utah North", dm(41., 47.), dm(40., 43.), dm(40., 20.), dm(-111., 30.), 500_000., 1_000_000.),
+    SPCSZone::lcc(4302, 32143, "Utah Central", dm(40., 39.), dm(39., 1.), dm(38., 20.), dm(-111., 30.), 500_000., 2_000_000.),
+    SPCSZone::lcc(4303, 32144, "Utah South", dm(38., 21.), dm(37., 13.), dm(36., 40.), dm(-111., 30.), 500_000., 3_000_000.),
+    SPCSZone::tm(4400, 32145, "Vermont", dm(42., 30.), dm(-72., 30.), k(28_000.), 500_000., 0.),
+    SPCSZone::lcc(4501, 32146, "Virginia North", dm(39., 12.), dm(38., 2.), dm(37., 40.), dm(-78., 30.), 3_500_000., 2_000_000.),
+    SPCSZone::lcc(4502, 32147, "Virginia South", dm(37., 58.), dm(36., 46.), dm(36., 20.), dm(-78., 30.), 3_500_000., 1_000_000.),
+    SPCSZone::lcc(4601, 32148, "Washington North", dm(48., 44.), dm(47., 30.), dm(47., 0.), dm(-120., 50.), 500_000., 0.),
+    SPCSZone::lcc(4602, 32149, "Washington South", dm(47., 20.), dm(45., 50.), dm(45., 20.), dm(-120., 30.), 500_000., 0.),
+    SPCSZone::lcc(4701, 32150, "West Virginia North", dm(40., 15.), dm(39., 0.), dm(38., 30.), dm(-79., 30.), 600_000., 0.),
+    SPCSZone::lcc(4702, 32151, "West Virginia South", dm(38., 53.), dm(37., 29.), dm(37., 0.), dm(-81., 0.), 600_000., 0.),
+    SPCSZone::lcc(4801, 32152, "Wisconsin North", dm(46., 46.), dm(45., 34.), dm(45., 10.), dm(-90., 0.), 600_000., 0.),
+    SPCSZone::lcc(4802, 32153, "Wisconsin Central", dm(45., 30.), dm(44., 15.), dm(43., 50.), dm(-90., 0.), 600_000., 0.),
+    SPCSZone::lcc(4803, 32154, "Wisconsin South", dm(44., 4.), dm(42., 44.), dm(42., 0.), dm(-90., 0.), 600_000., 0.),
+    SPCSZone::tm(4901, 32155, "Wyoming East", dm(40., 30.), dm(-105., 10.), k(16_000.), 200_000., 0.),
+    SPCSZone::tm(4902, 32156, "Wyoming East Central", dm(40., 30.), dm(-107., 20.), k(16_000.), 400_000., 100_000.),
+    SPCSZone::tm(4903, 32157, "Wyoming West Central", dm(40., 30.), dm(-108., 45.), k(16_000.), 600_000., 0.),
+    SPCSZone::tm(4904, 32158, "Wyoming West", dm(40., 30.), dm(-110., 5.), k(16_000.), 800_000., 100_000.),
+    SPCSZone::omerc(5001, 26931, "Alaska Zone 1", dm(57., 0.), dm(-133., 40.), ALASKA_1_AZIMUTH, k(10_000.), 5_000_000., -5_000_000.),
+    SPCSZone::tm(5002, 26932, "Alaska Zone 2", dm(54., 0.), dm(-142., 0.), k(10_000.), 500_000., 0.),
+    SPCSZone::tm(5003, 26933, "Alaska Zone 3", dm(54., 0.), dm(-146., 0.), k(10_000.), 500_000., 0.),
+    SPCSZone::tm(5004, 26934, "Alaska Zone 4", dm(54., 0.), dm(-150., 0.), k(10_000.), 500_000., 0.),
+    SPCSZone::tm(5005, 26935, "Alaska Zone 5", dm(54., 0.), dm(-154., 0.), k(10_000.), 500_000., 0.),
+    SPCSZone::tm(5006, 26936, "Alaska Zone 6", dm(54., 0.), dm(-158., 0.), k(10_000.), 500_000., 0.),
+    SPCSZone::tm(5007, 26937, "Alaska Zone 7", dm(54., 0.), dm(-162., 0.), k(10_000.), 500_000., 0.),
+    SPCSZone::tm(5008, 26938, "Alaska Zone 8", dm(54., 0.), dm(-166., 0.), k(10_000.), 500_000., 0.),
+    SPCSZone::tm(5009, 26939, "Alaska Zone 9", dm(54., 0.), dm(-170., 0.), k(10_000.), 500_000., 0.),
+    SPCSZone::lcc(5010, 26940, "Alaska Zone 10", dm(53., 50.), dm(51., 50.), dm(51., 0.), dm(-176., 0.), 1_000_000., 0.),
+    SPCSZone::tm(5101, 26961, "Hawaii Zone 1", dm(18., 50.), dm(-155., 30.), k(30_000.), 500_000., 0.),
+    SPCSZone::tm(5102, 26962, "Hawaii Zone 2", dm(20., 20.), dm(-156., 40.), k(30_000.), 500_000., 0.),
+    SPCSZone::tm(5103, 26963, "Hawaii Zone 3", dm(21., 10.), dm(-158., 0.), k(100_000.), 500_000., 0.),
+    SPCSZone::tm(5104, 26964, "Hawaii Zone 4", dm(21., 50.), dm(-159., 30.), k(100_000.), 500_000., 0.),
+    SPCSZone::tm(5105, 26965, "Hawaii Zone 5", dm(21., 40.), dm(-160., 10.), 1.0, 500_000., 0.),
+    SPCSZone::lcc(5200, 32161, "Puerto Rico and Virgin Islands", dm(18., 26.), dm(18., 2.), dm(17., 50.), dm(-66., 26.), 200_000., 200_000.),
+];
+
+#[cfg(test)]
+mod test {
+    use crate::coordinate::{EllipticalCoordinate, Latitude, Longitude};
+    use crate::geo::standards::StandardShapes;
+    use crate::proj::{GridDistortion, Projection};
+    use crate::spcs::{SPCSProjectionParams, SPCSZone, NAD83_SPCS_ZONES};
+    use irox_tools::assert_eq_eps;
+    use irox_units::units::angle::Angle;
+    use std::collections::HashSet;
+
+    fn coord(lat: Angle, lon: Angle) -> EllipticalCoordinate {
+        EllipticalCoordinate::new(Latitude(lat), Longitude(lon), StandardShapes::NAD83.into())
+    }
+
+    #[test]
+    pub fn test_lookups() {
+        assert_eq!(124, NAD83_SPCS_ZONES.len());
+        let fips: HashSet<u16> = NAD83_SPCS_ZONES.iter().map(|z| z.fips).collect();
+        let epsg: HashSet<u32> = NAD83_SPCS_ZONES.iter().map(|z| z.epsg).collect();
+        assert_eq!(NAD83_SPCS_ZONES.len(), fips.len());
+        assert_eq!(NAD83_SPCS_ZONES.len(), epsg.len());
+
+        let ma = SPCSZone::find_by_fips(2001);
+        assert_eq!(Some(26986), ma.map(|z| z.epsg));
+        let ak = SPCSZone::find_by_epsg(26931);
+        assert_eq!(Some(5001), ak.map(|z| z.fips));
+        assert_eq!(None, SPCSZone::find_by_fips(9999));
+    }
+
+    #[test]
+    pub fn test_all_zones() {
+        for zone in NAD83_SPCS_ZONES {
+            let proj = zone.projection();
+            let (lat0, lon0) = match zone.params {
+                SPCSProjectionParams::TransverseMercator {
+                    origin_latitude,
+                    central_meridian,
+                    ..
+                }
+                | SPCSProjectionParams::LambertConformalConic {
+                    origin_latitude,
+                    central_meridian,
+                    ..
+                } => (origin_latitude, central_meridian),
+                SPCSProjectionParams::ObliqueMercator {
+                    center_latitude,
+                    center_longitude,
+                    ..
+                } => (center_latitude, center_longitude),
+            };
+            // the oblique zone's false origin is the natural origin, not the center.
+            if !matches!(zone.params, SPCSProjectionParams::ObliqueMercator { .. }) {
+                let origin = proj.project_to_cartesian(&coord(lat0, lon0));
+                assert_eq_eps!(
+                    zone.false_easting.as_meters().value(),
+                    origin.get_x().as_meters().value(),
+                    1e-3
+                );
+                assert_eq_eps!(
+                    zone.false_northing.as_meters().value(),
+                    origin.get_y().as_meters().value(),
+                    1e-3
+                );
+            }
+
+            for (dlat, dlon) in [(0.5, 0.5), (1.5, -1.), (0.25, -0.75)] {
+                let pt = coord(
+                    lat0 + Angle::new_degrees(dlat),
+                    lon0 + Angle::new_degrees(dlon),
+                );
+                let xy = proj.project_to_cartesian(&pt);
+                let lla = proj.project_to_elliptical(&xy);
+                assert_eq_eps!(
+                    pt.get_latitude().0.as_degrees().value(),
+                    lla.get_latitude().0.as_degrees().value(),
+                    1e-9
+                );
+                assert_eq_eps!(
+                    pt.get_longitude().0.as_degrees().value(),
+                    lla.get_longitude().0.as_degrees().value(),
+                    1e-9
+                );
+
+                let k = proj.grid_scale_factor(&pt);
+                let gamma = proj.convergence_angle(&pt).as_degrees().value();
+                let inner = proj.inner();
+                assert_eq_eps!(k, numeric_scale(inner, &pt), 1e-6);
+                assert_eq_eps!(gamma, numeric_convergence(inner, &pt), 1e-6);
+            }
+
+            match zone.params {
+                SPCSProjectionParams::TransverseMercator { scale_factor, .. } => {
+                    let k = proj.grid_scale_factor(&coord(lat0 + Angle::new_degrees(1.), lon0));
+                    assert_eq_eps!(scale_factor, k, 1e-12);
+                }
+                SPCSProjectionParams::LambertConformalConic {
+                    first_parallel,
+                    second_parallel,
+                    ..
+                } => {
+                    let k = proj.grid_scale_factor(&coord(first_parallel, lon0));
+                    assert_eq_eps!(1.0, k, 1e-12);
+                    let k = proj.grid_scale_factor(&coord(second_parallel, lon0));
+                    assert_eq_eps!(1.0, k, 1e-12);
+                }
+                SPCSProjectionParams::ObliqueMercator { scale_factor, .. } => {
+                    assert_eq_eps!(
+                        scale_factor,
+                        proj.grid_scale_factor(&coord(lat0, lon0)),
+                        1e-9
+                    );
+                }
+            }
+            let gamma = proj.convergence_angle(&coord(lat0, lon0));
+            if !matches!(zone.params, SPCSProjectionParams::ObliqueMercator { .. }) {
+                assert_eq_eps!(0.0, gamma.value(), 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_alaska_zone_1() {
+        let Some(zone) = SPCSZone::find_by_epsg(26931) else {
+            panic!("Missing Alaska Zone 1");
+        };
+        let proj = zone.projection();
+        // Juneau & Ketchikan.  These are regression values from this implementation, the
+        // variant A math is checked against the EPSG example in the omerc module.  Applying the
+        // variant B center offset instead moves them millions of meters off the zone.
+        for (lat, lon, east, north) in [
+            (58.3, -134.4, 775_672.461, 720_098.329),
+            (55.34, -131.64, 947_260.256, 392_179.897),
+        ] {
+            let pt = coord(Angle::new_degrees(lat), Angle::new_degrees(lon));
+            let xy = proj.project_to_cartesian(&pt);
+            assert_eq_eps!(east, xy.get_x().as_meters().value(), 1e-3);
+            assert_eq_eps!(north, xy.get_y().as_meters().value(), 1e-3);
+        }
+    }
+
+    /// Evaluates the trait-default numerical distortion against a projection that overrides it.
+    struct Numeric<'a>(&'a dyn GridDistortion);
+    impl Projection for Numeric<'_> {
+        fn get_center_coords(&self) -> &EllipticalCoordinate {
+            self.0.get_center_coords()
+        }
+
+        fn project_to_cartesian(
+            &self,
+            coord: &EllipticalCoordinate,
+        ) -> crate::coordinate::CartesianCoordinate {
+            self.0.project_to_cartesian(coord)
+        }
+
+        fn project_to_elliptical(
+            &self,
+            coord: &crate::coordinate::CartesianCoordinate,
+        ) -> EllipticalCoordinate {
+            self.0.project_to_elliptical(coord)
+        }
+    }
+    impl GridDistortion for Numeric<'_> {}
+    fn numeric_scale(proj: &dyn GridDistortion, pt: &EllipticalCoordinate) -> f64 {
+        Numeric(proj).grid_scale_factor(pt)
+    }
+    fn numeric_convergence(proj: &dyn GridDistortion, pt: &EllipticalCoordinate) -> f64 {
+        Numeric(proj).convergence_angle(pt).as_degrees().value()
+    }
+}
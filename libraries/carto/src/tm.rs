@@ -13,7 +13,7 @@ use crate::coordinate::{CartesianCoordinate, EllipticalCoordinate, Latitude, Lon
 use crate::geo::ellipsoid::Ellipsoid;
 use crate::geo::standards::StandardShapes;
 use crate::geo::MeridianCalculators;
-use crate::proj::Projection;
+use crate::proj::{GridDistortion, Projection};
 
 ///
 /// An implementation of the Transverse Mercator map projection.
@@ -50,6 +50,27 @@ impl TransverseMercator {
             ..Default::default()
         }
     }
+
+    /// Returns the scale factor along the central meridian (`k0`)
+    #[must_use]
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    /// Returns the shape of the ellipsoid in use
+    #[must_use]
+    pub fn shape(&self) -> &Ellipsoid {
+        &self.shape
+    }
+
+    ///
+    /// Meridian arc distance from the latitude of the projection center to the provided latitude,
+    /// negative if the latitude is south of the center.
+    fn meridional_arc_from_center(&self, latitude: &Angle) -> Length {
+        let calc = MeridianCalculators::DeakinHunterKarney.get(&self.shape);
+        calc.meridional_arc_distance(latitude)
+            - calc.meridional_arc_distance(&self.center.get_latitude().0)
+    }
 }
 
 ///
@@ -151,7 +172,6 @@ impl Projection for TransverseMercator {
         let w8 = w.powi(8);
 
         let latitude = coord.get_latitude();
-        let delta_lat = latitude.0 - self.center.get_latitude().0;
 
         let v = self.shape.radius_curvature_prime_vertical(latitude);
 
@@ -174,10 +194,7 @@ impl Projection for TransverseMercator {
         let ep6cos6 = ep2cos2.powi(3);
         let ep8cos8 = ep2cos2.powi(4);
 
-        let t1 = self.scale_factor
-            * MeridianCalculators::DeakinHunterKarney
-                .get(&self.shape)
-                .meridional_arc_distance(&delta_lat);
+        let t1 = self.scale_factor * self.meridional_arc_from_center(&latitude.0);
 
         let t2 = v * sin_phi * cos_phi * self.scale_factor / 2.0;
 
@@ -230,11 +247,7 @@ impl Projection for TransverseMercator {
         let phi0 = self.center.get_latitude().0.as_radians().value();
         let mut phi_prime = (northing - self.false_northing) / scaled_axis + phi0;
         loop {
-            let dphi = phi_prime - phi0;
-            let m = MeridianCalculators::DeakinHunterKarney
-                .get(&self.shape)
-                .meridional_arc_distance(&Angle::new_radians(dphi))
-                * k0;
+            let m = self.meridional_arc_from_center(&Angle::new_radians(phi_prime)) * k0;
 
             let phip_eps = northing - self.false_northing - m;
             if phip_eps.as_meters().value().abs() < phi_eps {
@@ -286,8 +299,8 @@ impl Projection for TransverseMercator {
 
         let e2cos2 = self.shape.second_eccentricity_squared * cos2_phip;
         let e4cos4 = e2cos2.powi(2);
-        let e6cos6 = e2cos2.powi(4);
-        let e8cos8 = e2cos2.powi(6);
+        let e6cos6 = e2cos2.powi(3);
+        let e8cos8 = e2cos2.powi(4);
 
         let t10 = tan_phip / (2. * p * v * k02);
 
@@ -336,10 +349,47 @@ impl Projection for TransverseMercator {
     }
 }
 
+impl GridDistortion for TransverseMercator {
+    ///
+    /// Point scale factor, from Snyder's 'Map Projections - A Working Manual', eq 8-11
+    fn grid_scale_factor(&self, coord: &EllipticalCoordinate) -> f64 {
+        let phi = coord.get_latitude().0.as_radians().value();
+        let w = (coord.get_longitude().0 - self.center.get_longitude().0)
+            .as_radians()
+            .value();
+        let ep2 = self.shape.second_eccentricity_squared;
+        let a = w * phi.cos();
+        let t = phi.tan().powi(2);
+        let c = ep2 * phi.cos().powi(2);
+
+        let k2 = (1. + c) * a.powi(2) / 2.;
+        let k4 = (5. - 4. * t + 42. * c + 13. * c.powi(2) - 28. * ep2) * a.powi(4) / 24.;
+        let k6 = (61. - 148. * t + 16. * t.powi(2)) * a.powi(6) / 720.;
+        self.scale_factor * (1. + k2 + k4 + k6)
+    }
+
+    ///
+    /// Meridian convergence, from terms XII, XIII and C5 of `DMA TM 8358.2`
+    fn convergence_angle(&self, coord: &EllipticalCoordinate) -> Angle {
+        let phi = coord.get_latitude().0.as_radians().value();
+        let w = (coord.get_longitude().0 - self.center.get_longitude().0)
+            .as_radians()
+            .value();
+        let cos2 = phi.cos().powi(2);
+        let t = phi.tan().powi(2);
+        let c = self.shape.second_eccentricity_squared * cos2;
+
+        let g3 = w.powi(2) * cos2 / 3. * (1. + 3. * c + 2. * c.powi(2));
+        let g5 = w.powi(4) * cos2.powi(2) / 15. * (2. - t);
+        Angle::new_radians(w * phi.sin() * (1. + g3 + g5))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use irox_tools::assert_eq_eps;
     use irox_units::units::angle::Angle;
+    use irox_units::units::length::Length;
 
     use crate::coordinate::{EllipticalCoordinate, Latitude, Longitude};
     use crate::geo::standards::StandardShapes;
@@ -375,6 +425,35 @@ mod test {
         }
     }
 
+    #[test]
+    pub fn epsg_british_national_grid() {
+        // EPSG Guidance Note 7-2, OSGB 1936 / British National Grid
+        let shape = StandardShapes::Airy;
+        let tm = TransverseMercator::builder()
+            .with_shape(shape.as_ellipsoid())
+            .with_center(EllipticalCoordinate::new(
+                Latitude(Angle::new_degrees(49.)),
+                Longitude(Angle::new_degrees(-2.)),
+                shape.into(),
+            ))
+            .with_scale_factor(0.999_601_271_7)
+            .with_false_easting(Length::new_meters(400_000.))
+            .with_false_northing(Length::new_meters(-100_000.))
+            .build();
+        let pt = EllipticalCoordinate::new(
+            Latitude(Angle::new_degrees(50.5)),
+            Longitude(Angle::new_degrees(0.5)),
+            shape.into(),
+        );
+        let xy = tm.project_to_cartesian(&pt);
+        assert_eq_eps!(577_274.99, xy.get_x().as_meters().value(), 1e-2);
+        assert_eq_eps!(69_740.50, xy.get_y().as_meters().value(), 1e-2);
+
+        let lla = tm.project_to_elliptical(&xy);
+        assert_eq_eps!(50.5, lla.get_latitude().0.as_degrees().value(), 1e-10);
+        assert_eq_eps!(0.5, lla.get_longitude().0.as_degrees().value(), 1e-10);
+    }
+
     #[test]
     pub fn dmatm8358_points() {
         let points = [
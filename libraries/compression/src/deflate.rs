@@ -290,15 +290,20 @@ pub struct Inflater<'a, T: Bits> {
 
 impl<'a, T: Bits> Inflater<'a, T> {
     pub fn new_zlib(stream: BitsWrapper<'a, T>) -> Self {
-        let mut out = Self {
+        let mut out = Self::new_raw(stream);
+        let _ = out.read_zlib_header();
+        out
+    }
+    /// Creates an inflater for a raw deflate stream without the zlib header, as stored in zip
+    /// archives.
+    pub fn new_raw(stream: BitsWrapper<'a, T>) -> Self {
+        Self {
             stream: BitStreamDecoder::new(stream),
             bits_read: 0,
             block: <VecDeque<u8> as ZeroedBuffer>::new_zeroed(32768),
             block_offset: 0,
             complete: false,
-        };
-        let _ = out.read_zlib_header();
-        out
+        }
     }
     fn read_zlib_header(&mut self) -> Result<(), BitsError> {
         let _cm = self.stream.read_le_u32_bits(4)?;
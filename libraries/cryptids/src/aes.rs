@@ -82,72 +82,188 @@ fn subshift(data: &[u32; 4], iter: usize) -> u32 {
     ])
 }
 
-pub struct Aes256Enc {
-    key: [u32; 120],
+/// The inverse of [`AES_SBOX`]
+static AES_INV_SBOX: [u8; 256] = {
+    let mut out = [0u8; 256];
+    let mut idx = 0;
+    while idx < 256 {
+        out[AES_SBOX[idx] as usize] = idx as u8;
+        idx += 1;
+    }
+    out
+};
+
+/// Multiplication in GF(2^8)
+fn gmul(mut a: u8, mut b: u8) -> u8 {
+    let mut out = 0;
+    while b != 0 {
+        if b & 0x1 == 0x1 {
+            out ^= a;
+        }
+        a = mul_by_x(a);
+        b >>= 1;
+    }
+    out
 }
-impl Drop for Aes256Enc {
-    fn drop(&mut self) {
-        for x in &mut self.key {
-            *x = 0;
+fn inv_mix_columns(r: u32) -> u32 {
+    let [a, b, c, d] = r.to_be_bytes();
+    u32::from_be_bytes([
+        gmul(a, 14) ^ gmul(b, 11) ^ gmul(c, 13) ^ gmul(d, 9),
+        gmul(a, 9) ^ gmul(b, 14) ^ gmul(c, 11) ^ gmul(d, 13),
+        gmul(a, 13) ^ gmul(b, 9) ^ gmul(c, 14) ^ gmul(d, 11),
+        gmul(a, 11) ^ gmul(b, 13) ^ gmul(c, 9) ^ gmul(d, 14),
+    ])
+}
+fn inv_subshift(data: &[u32; 4], iter: usize) -> u32 {
+    let a = data[iter];
+    let b = data[(iter + 3) % 4];
+    let c = data[(iter + 2) % 4];
+    let d = data[(iter + 1) % 4];
+    u32::from_be_bytes([
+        AES_INV_SBOX[((a >> 24) & 0xFF) as usize],
+        AES_INV_SBOX[((b >> 16) & 0xFF) as usize],
+        AES_INV_SBOX[((c >> 8) & 0xFF) as usize],
+        AES_INV_SBOX[(d & 0xFF) as usize],
+    ])
+}
+
+///
+/// FIPS-197 key expansion of the `4 * nk` byte key into the round keys
+fn expand_key(key: &[u8], round_keys: &mut [u32]) {
+    let nk = key.len() / 4;
+    let mut rdr = key;
+    for rk in round_keys.iter_mut().take(nk) {
+        *rk = rdr.read_be_u32().unwrap_or_default();
+    }
+    let mut rc = 0x0100_0000;
+    for i in nk..round_keys.len() {
+        let mut temp = round_keys[i - 1];
+        if i % nk == 0 {
+            temp = sub_word(temp.rotate_left(8)).bitxor(rc);
+            rc = mul_by_x32(rc);
+        } else if nk > 6 && i % nk == 4 {
+            temp = sub_word(temp);
         }
+        round_keys[i] = round_keys[i - nk].bitxor(temp);
     }
 }
 
-impl Aes256Enc {
-    pub fn new(in_key: [u8; 32]) -> Aes256Enc {
-        let mut enc = Aes256Enc { key: [0; 120] };
-        let mut rdr = in_key.as_slice();
-        for i in 0..8 {
-            enc.key[i] = rdr.read_be_u32().unwrap_or_default();
+fn encrypt_block(round_keys: &[u32], block: &[u8; 16]) -> [u8; 16] {
+    let mut state: [u32; 4] = FromBEBytes::from_be_bytes(*block);
+    for (s, k) in state.iter_mut().zip(round_keys) {
+        s.bitxor_assign(*k);
+    }
+    let rounds = round_keys.len() / 4 - 1;
+    let mut rkp = 4;
+    for _ in 1..rounds {
+        state = [
+            mix_columns(subshift(&state, 0)).bitxor(round_keys[rkp]),
+            mix_columns(subshift(&state, 1)).bitxor(round_keys[rkp + 1]),
+            mix_columns(subshift(&state, 2)).bitxor(round_keys[rkp + 2]),
+            mix_columns(subshift(&state, 3)).bitxor(round_keys[rkp + 3]),
+        ];
+        rkp += 4;
+    }
+    [
+        subshift(&state, 0).bitxor(round_keys[rkp]),
+        subshift(&state, 1).bitxor(round_keys[rkp + 1]),
+        subshift(&state, 2).bitxor(round_keys[rkp + 2]),
+        subshift(&state, 3).bitxor(round_keys[rkp + 3]),
+    ]
+    .to_be_bytes()
+}
+
+fn decrypt_block(round_keys: &[u32], block: &[u8; 16]) -> [u8; 16] {
+    let mut state: [u32; 4] = FromBEBytes::from_be_bytes(*block);
+    let mut rkp = round_keys.len() - 4;
+    for (s, k) in state.iter_mut().zip(&round_keys[rkp..]) {
+        s.bitxor_assign(*k);
+    }
+    while rkp > 4 {
+        rkp -= 4;
+        state = [
+            inv_mix_columns(inv_subshift(&state, 0).bitxor(round_keys[rkp])),
+            inv_mix_columns(inv_subshift(&state, 1).bitxor(round_keys[rkp + 1])),
+            inv_mix_columns(inv_subshift(&state, 2).bitxor(round_keys[rkp + 2])),
+            inv_mix_columns(inv_subshift(&state, 3).bitxor(round_keys[rkp + 3])),
+        ];
+    }
+    [
+        inv_subshift(&state, 0).bitxor(round_keys[0]),
+        inv_subshift(&state, 1).bitxor(round_keys[1]),
+        inv_subshift(&state, 2).bitxor(round_keys[2]),
+        inv_subshift(&state, 3).bitxor(round_keys[3]),
+    ]
+    .to_be_bytes()
+}
+
+///
+/// A cipher operating on 128-bit blocks, used by the block cipher modes.
+pub trait BlockCipher {
+    type Key;
+
+    /// Creates the cipher, expanding the key.
+    fn from_key(key: &Self::Key) -> Self;
+
+    /// Encrypts a single block
+    fn encrypt_block(&self, block: &[u8; 16]) -> [u8; 16];
+
+    /// Decrypts a single block
+    fn decrypt_block(&self, block: &[u8; 16]) -> [u8; 16];
+}
+
+macro_rules! impl_aes {
+    ($name:ident, $keylen:literal, $words:literal, $doc:literal) => {
+        #[doc = $doc]
+        pub struct $name {
+            key: [u32; $words],
         }
-        let v = &mut enc.key;
-        let mut rc = 1;
-        for i in 0..14 {
-            rc = mul_by_x32(rc);
+        impl Drop for $name {
+            fn drop(&mut self) {
+                for x in &mut self.key {
+                    *x = 0;
+                }
+            }
+        }
+        impl $name {
+            pub fn new(in_key: [u8; $keylen]) -> $name {
+                let mut out = $name { key: [0; $words] };
+                expand_key(&in_key, &mut out.key);
+                out
+            }
+
+            pub fn encrypt(&self, block: &[u8; 16]) -> [u8; 16] {
+                encrypt_block(&self.key, block)
+            }
 
-            let idx = i * 8;
-            let odx = idx + 8;
-            v[odx] = sub_word(v[idx + 7])
-                .rotate_right(8)
-                .bitxor(rc)
-                .bitxor(v[idx]);
-            v[odx + 1] = v[odx].bitxor(v[idx + 1]);
-            v[odx + 2] = v[odx + 1].bitxor(v[idx + 2]);
-            v[odx + 3] = v[odx + 2].bitxor(v[idx + 3]);
-            v[odx + 4] = sub_word(v[idx + 3]).bitxor(v[idx + 4]);
-            v[odx + 5] = v[odx + 4].bitxor(v[idx + 5]);
-            v[odx + 6] = v[odx + 5].bitxor(v[idx + 6]);
-            v[odx + 7] = v[odx + 5].bitxor(v[idx + 7]);
+            pub fn decrypt(&self, block: &[u8; 16]) -> [u8; 16] {
+                decrypt_block(&self.key, block)
+            }
         }
-        enc
-    }
-
-    pub fn encrypt(&self, block: &[u8; 16]) -> [u8; 16] {
-        let mut state: [u32; 4] = FromBEBytes::from_be_bytes(*block);
-        state[0].bitxor_assign(self.key[0]);
-        state[1].bitxor_assign(self.key[1]);
-        state[2].bitxor_assign(self.key[2]);
-        state[3].bitxor_assign(self.key[3]);
-
-        let mut rkp = 4;
-        let mut s2 = state;
-        for _ in 0..13 {
-            s2[0] = mix_columns(subshift(&state, 0)).bitxor(self.key[rkp]);
-            s2[1] = mix_columns(subshift(&state, 1)).bitxor(self.key[rkp + 1]);
-            s2[2] = mix_columns(subshift(&state, 2)).bitxor(self.key[rkp + 2]);
-            s2[3] = mix_columns(subshift(&state, 3)).bitxor(self.key[rkp + 3]);
-            state = s2;
-            rkp += 4;
+        impl BlockCipher for $name {
+            type Key = [u8; $keylen];
+
+            fn from_key(key: &Self::Key) -> Self {
+                $name::new(*key)
+            }
+
+            fn encrypt_block(&self, block: &[u8; 16]) -> [u8; 16] {
+                self.encrypt(block)
+            }
+
+            fn decrypt_block(&self, block: &[u8; 16]) -> [u8; 16] {
+                self.decrypt(block)
+            }
         }
-        [
-            subshift(&state, 0).bitxor(self.key[rkp + 4]),
-            subshift(&state, 1).bitxor(self.key[rkp + 5]),
-            subshift(&state, 2).bitxor(self.key[rkp + 6]),
-            subshift(&state, 3).bitxor(self.key[rkp + 7]),
-        ]
-        .to_be_bytes()
-    }
+    };
 }
+impl_aes!(Aes128, 16, 44, "AES with a 128-bit key, 10 rounds");
+impl_aes!(Aes192, 24, 52, "AES with a 192-bit key, 12 rounds");
+impl_aes!(Aes256, 32, 60, "AES with a 256-bit key, 14 rounds");
+
+/// AES-256 block encryption, see [`Aes256`]
+pub type Aes256Enc = Aes256;
+
 pub struct Aes256EncStream<'a, T> {
     enc: Aes256Enc,
     buf: irox_tools::buf::FixedBuf<16, u8>,
@@ -176,10 +292,10 @@ impl<T: MutBits> MutBits for Aes256EncStream<'_, T> {
 #[cfg(all(test, feature = "std"))]
 mod test {
     use crate::aes::{mix_columns, subshift};
-    use crate::Aes256EncStream;
+    use crate::{Aes128, Aes192, Aes256, Aes256EncStream};
     use core::ops::BitXor;
     use irox_bits::{Bits, BitsWrapper, MutBits, ToBEBytes};
-    use irox_tools::assert_eq_hex;
+    use irox_tools::{assert_eq_hex, assert_eq_hex_slice, hex};
     use std::collections::VecDeque;
 
     #[test]
@@ -211,7 +327,6 @@ mod test {
     }
 
     #[test]
-    #[ignore]
     pub fn test_aes256_enc1() {
         let key: [u8; 32] = [
             0x603DEB10u32,
@@ -243,4 +358,83 @@ mod test {
             assert_eq_hex!(exp, buf.read_be_u32().unwrap());
         }
     }
+
+    macro_rules! check_block {
+        ($cipher:expr, $pt:expr, $ct:expr) => {{
+            let cipher = $cipher;
+            let pt = hex!($pt);
+            let ct = hex!($ct);
+            assert_eq_hex_slice!(&ct, &cipher.encrypt(&pt));
+            assert_eq_hex_slice!(&pt, &cipher.decrypt(&ct));
+        }};
+    }
+
+    #[test]
+    pub fn test_fips197_appendix_c() {
+        check_block!(
+            Aes128::new(hex!("000102030405060708090a0b0c0d0e0f")),
+            "00112233445566778899aabbccddeeff",
+            "69c4e0d86a7b0430d8cdb78070b4c55a"
+        );
+        check_block!(
+            Aes192::new(hex!("000102030405060708090a0b0c0d0e0f1011121314151617")),
+            "00112233445566778899aabbccddeeff",
+            "dda97ca4864cdfe06eaf70a0ec0d7191"
+        );
+        check_block!(
+            Aes256::new(hex!(
+                "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+            )),
+            "00112233445566778899aabbccddeeff",
+            "8ea2b7ca516745bfeafc49904b496089"
+        );
+    }
+
+    #[test]
+    pub fn test_aesavs_gfsbox() {
+        check_block!(
+            Aes128::new([0; 16]),
+            "f34481ec3cc627bacd5dc3fb08f273e6",
+            "0336763e966d92595a567cc9ce537f5e"
+        );
+        check_block!(
+            Aes128::new([0; 16]),
+            "9798c4640bad75c7c3227db910174e72",
+            "a9a1631bf4996954ebc093957b234589"
+        );
+        check_block!(
+            Aes128::new([0; 16]),
+            "96ab5c2ff612d9dfaae8c31f30c42168",
+            "ff4f8391a6a40ca5b25d23bedd44a597"
+        );
+        check_block!(
+            Aes192::new([0; 24]),
+            "1b077a6af4b7f98229de786d7516b639",
+            "275cfc0413d8ccb70513c3859b1d0f72"
+        );
+        check_block!(
+            Aes256::new([0; 32]),
+            "014730f80ac625fe84f026c60bfd547d",
+            "5c9d844ed46f9885085e5d6a4f94c7d7"
+        );
+    }
+
+    #[test]
+    pub fn test_aesavs_varkey_keysbox() {
+        check_block!(
+            Aes128::new(hex!("80000000000000000000000000000000")),
+            "00000000000000000000000000000000",
+            "0edd33d3c621e546455bd8ba1418bec8"
+        );
+        check_block!(
+            Aes128::new(hex!("c0000000000000000000000000000000")),
+            "00000000000000000000000000000000",
+            "4bc3f883450c113c64ca42e1112a9e87"
+        );
+        check_block!(
+            Aes128::new(hex!("10a58869d74be5a374cf867cfb473859")),
+            "00000000000000000000000000000000",
+            "6d251e6944b051e04eaa6fb4dbf78465"
+        );
+    }
 }
@@ -0,0 +1,473 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! The Argon2id memory-hard password hash, [RFC 9106](https://datatracker.ietf.org/doc/html/rfc9106)
+
+#![allow(clippy::indexing_slicing)]
+
+extern crate alloc;
+use crate::KeyDerivationFunction;
+use alloc::vec::Vec;
+use irox_bits::{BitsErrorKind, Error};
+use irox_tools::hash::{BLAKE2b, BLAKE2b512};
+
+/// Argon2 version 1.3
+const VERSION: u32 = 0x13;
+/// Argon2id
+const ARGON2ID: u32 = 2;
+/// Number of u64 words in a 1 KiB memory block
+const BLOCK_WORDS: usize = 128;
+/// Number of slices (synchronization points) per pass
+const SLICES: u32 = 4;
+
+type Block = [u64; BLOCK_WORDS];
+
+///
+/// Argon2id, the hybrid of the data-independent Argon2i and the data-dependent Argon2d.  The
+/// lanes are computed sequentially, the parallelism parameter still changes the output.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Argon2id<'a> {
+    memory_kib: u32,
+    passes: u32,
+    lanes: u32,
+    secret: &'a [u8],
+    associated_data: &'a [u8],
+}
+
+impl Default for Argon2id<'_> {
+    /// The second recommended option from RFC 9106: 64 MiB of memory, 3 passes and 4 lanes.
+    fn default() -> Self {
+        Self {
+            memory_kib: 64 * 1024,
+            passes: 3,
+            lanes: 4,
+            secret: &[],
+            associated_data: &[],
+        }
+    }
+}
+
+impl<'a> Argon2id<'a> {
+    ///
+    /// Creates a new Argon2id with the provided memory size (in KiB), number of passes and number
+    /// of lanes.  `lanes` must be in `1..2^24`, `passes` must be nonzero, and `memory_kib` must be
+    /// at least `8 * lanes`
+    pub fn new(memory_kib: u32, passes: u32, lanes: u32) -> Result<Self, Error> {
+        if lanes == 0 || lanes >= 1 << 24 {
+            return Error::err(
+                BitsErrorKind::InvalidInput,
+                "argon2 lanes must be in 1..2^24",
+            );
+        }
+        if passes == 0 {
+            return Error::err(BitsErrorKind::InvalidInput, "argon2 passes must be nonzero");
+        }
+        if (memory_kib as u64) < 8 * lanes as u64 {
+            return Error::err(
+                BitsErrorKind::InvalidInput,
+                "argon2 memory must be >= 8 * lanes",
+            );
+        }
+        Ok(Self {
+            memory_kib,
+            passes,
+            lanes,
+            secret: &[],
+            associated_data: &[],
+        })
+    }
+
+    /// Keys the hash with the provided secret value (a "pepper")
+    #[must_use]
+    pub fn with_secret(mut self, secret: &'a [u8]) -> Self {
+        self.secret = secret;
+        self
+    }
+
+    /// Binds the provided associated data into the hash
+    #[must_use]
+    pub fn with_associated_data(mut self, associated_data: &'a [u8]) -> Self {
+        self.associated_data = associated_data;
+        self
+    }
+
+    /// The number of bytes of memory required for a single derivation
+    pub fn memory_required(&self) -> u64 {
+        self.block_count() as u64 * 1024
+    }
+
+    /// `m'`, the memory size rounded down to a multiple of `4 * lanes`
+    fn block_count(&self) -> u32 {
+        let align = SLICES * self.lanes;
+        self.memory_kib / align * align
+    }
+
+    /// Derives `OUTPUT_LEN` bytes of key from the password and salt.
+    pub fn argon2<const OUTPUT_LEN: usize>(
+        &self,
+        password: &[u8],
+        salt: &[u8],
+    ) -> Result<[u8; OUTPUT_LEN], Error> {
+        let mut out = [0u8; OUTPUT_LEN];
+        self.derive_key(password, salt, &mut out)?;
+        Ok(out)
+    }
+
+    /// H0, the initial 64-byte digest of all the parameters
+    fn initial_hash(&self, password: &[u8], salt: &[u8], tag_len: u32) -> [u8; 64] {
+        let mut h = BLAKE2b512::default();
+        for v in [
+            self.lanes,
+            tag_len,
+            self.memory_kib,
+            self.passes,
+            VERSION,
+            ARGON2ID,
+        ] {
+            h.write(&v.to_le_bytes());
+        }
+        for v in [password, salt, self.secret, self.associated_data] {
+            h.write(&(v.len() as u32).to_le_bytes());
+            h.write(v);
+        }
+        h.finish()
+    }
+}
+
+impl KeyDerivationFunction for Argon2id<'_> {
+    fn derive_key(&self, password: &[u8], salt: &[u8], output: &mut [u8]) -> Result<(), Error> {
+        let Ok(tag_len) = u32::try_from(output.len()) else {
+            return Error::err(BitsErrorKind::InvalidInput, "argon2 output too long");
+        };
+        if tag_len < 4 {
+            return Error::err(
+                BitsErrorKind::InvalidInput,
+                "argon2 output must be >= 4 bytes",
+            );
+        }
+        if salt.len() < 8 {
+            return Error::err(
+                BitsErrorKind::InvalidInput,
+                "argon2 salt must be >= 8 bytes",
+            );
+        }
+        let h0 = self.initial_hash(password, salt, tag_len);
+        let mut memory = Memory::new(self)?;
+        for lane in 0..self.lanes {
+            for idx in 0..2u32 {
+                let mut buf = [0u8; 1024];
+                h_prime(&[&h0, &idx.to_le_bytes(), &lane.to_le_bytes()], &mut buf);
+                let block = memory.block_mut(lane, idx);
+                for (w, c) in block.iter_mut().zip(buf.chunks_exact(8)) {
+                    *w = u64::from_le_bytes([c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]]);
+                }
+            }
+        }
+        for pass in 0..self.passes {
+            for slice in 0..SLICES {
+                for lane in 0..self.lanes {
+                    memory.fill_segment(pass, slice, lane);
+                }
+            }
+        }
+
+        let mut last = [0u64; BLOCK_WORDS];
+        for lane in 0..self.lanes {
+            let block = memory.block(lane, memory.lane_length - 1);
+            for (a, b) in last.iter_mut().zip(block) {
+                *a ^= *b;
+            }
+        }
+        let mut buf = [0u8; 1024];
+        for (w, c) in last.iter().zip(buf.chunks_exact_mut(8)) {
+            c.copy_from_slice(&w.to_le_bytes());
+        }
+        h_prime(&[&buf], output);
+        Ok(())
+    }
+}
+
+/// The memory matrix, `lanes` rows of `lane_length` 1 KiB blocks.
+struct Memory {
+    blocks: Vec<Block>,
+    lanes: u32,
+    lane_length: u32,
+    segment_length: u32,
+    passes: u32,
+}
+
+impl Memory {
+    fn new(params: &Argon2id) -> Result<Self, Error> {
+        let count = params.block_count();
+        let mut blocks = Vec::new();
+        blocks
+            .try_reserve_exact(count as usize)
+            .map_err(|_| Error::new(BitsErrorKind::OutOfMemory, "argon2 memory too large"))?;
+        blocks.resize(count as usize, [0u64; BLOCK_WORDS]);
+        let lane_length = count / params.lanes;
+        Ok(Self {
+            blocks,
+            lanes: params.lanes,
+            lane_length,
+            segment_length: lane_length / SLICES,
+            passes: params.passes,
+        })
+    }
+
+    fn offset(&self, lane: u32, idx: u32) -> usize {
+        (lane * self.lane_length + idx) as usize
+    }
+
+    fn block(&self, lane: u32, idx: u32) -> &Block {
+        &self.blocks[self.offset(lane, idx)]
+    }
+
+    fn block_mut(&mut self, lane: u32, idx: u32) -> &mut Block {
+        let offset = self.offset(lane, idx);
+        &mut self.blocks[offset]
+    }
+
+    fn fill_segment(&mut self, pass: u32, slice: u32, lane: u32) {
+        // Argon2id uses data-independent addressing for the first half of the first pass.
+        let data_independent = pass == 0 && slice < SLICES / 2;
+        let mut address_input = [0u64; BLOCK_WORDS];
+        address_input[0] = pass as u64;
+        address_input[1] = lane as u64;
+        address_input[2] = slice as u64;
+        address_input[3] = self.blocks.len() as u64;
+        address_input[4] = self.passes as u64;
+        address_input[5] = ARGON2ID as u64;
+        let mut addresses = [0u64; BLOCK_WORDS];
+
+        let start = if pass == 0 && slice == 0 { 2 } else { 0 };
+        for idx in start..self.segment_length {
+            let col = slice * self.segment_length + idx;
+            let prev_col = if col == 0 {
+                self.lane_length - 1
+            } else {
+                col - 1
+            };
+            let prev = *self.block(lane, prev_col);
+
+            let pseudo_rand = if data_independent {
+                if idx == start || (idx as usize).is_multiple_of(BLOCK_WORDS) {
+                    address_input[6] += 1;
+                    let zero = [0u64; BLOCK_WORDS];
+                    addresses = compress(&zero, &compress(&zero, &address_input));
+                }
+                addresses[idx as usize % BLOCK_WORDS]
+            } else {
+                prev[0]
+            };
+
+            let ref_lane = if pass == 0 && slice == 0 {
+                lane
+            } else {
+                ((pseudo_rand >> 32) % self.lanes as u64) as u32
+            };
+            let ref_col = self.reference_index(pass, slice, idx, ref_lane == lane, pseudo_rand);
+            let mut next = compress(&prev, self.block(ref_lane, ref_col));
+            if pass > 0 {
+                for (a, b) in next.iter_mut().zip(self.block(lane, col)) {
+                    *a ^= *b;
+                }
+            }
+            *self.block_mut(lane, col) = next;
+        }
+    }
+
+    /// Maps the low 32 bits of the pseudo-random value onto the set of blocks that may be
+    /// referenced.
+    fn reference_index(
+        &self,
+        pass: u32,
+        slice: u32,
+        idx: u32,
+        same_lane: bool,
+        pseudo_rand: u64,
+    ) -> u32 {
+        let finished = if pass == 0 {
+            slice * self.segment_length
+        } else {
+            self.lane_length - self.segment_length
+        };
+        let area = if same_lane {
+            finished + idx - 1
+        } else if idx == 0 {
+            finished - 1
+        } else {
+            finished
+        } as u64;
+        let x = pseudo_rand & 0xFFFF_FFFF;
+        let y = (x * x) >> 32;
+        let relative = area - 1 - ((area * y) >> 32);
+        let start = if pass == 0 || slice == SLICES - 1 {
+            0
+        } else {
+            (slice + 1) * self.segment_length
+        };
+        ((start as u64 + relative) % self.lane_length as u64) as u32
+    }
+}
+
+/// `a + b + 2 * lo(a) * lo(b)`
+fn blamka(a: u64, b: u64) -> u64 {
+    let m = (a & 0xFFFF_FFFF) * (b & 0xFFFF_FFFF);
+    a.wrapping_add(b).wrapping_add(m.wrapping_mul(2))
+}
+
+macro_rules! gb {
+    ($v:ident, $a:literal, $b:literal, $c:literal, $d:literal) => {
+        $v[$a] = blamka($v[$a], $v[$b]);
+        $v[$d] = ($v[$d] ^ $v[$a]).rotate_right(32);
+        $v[$c] = blamka($v[$c], $v[$d]);
+        $v[$b] = ($v[$b] ^ $v[$c]).rotate_right(24);
+        $v[$a] = blamka($v[$a], $v[$b]);
+        $v[$d] = ($v[$d] ^ $v[$a]).rotate_right(16);
+        $v[$c] = blamka($v[$c], $v[$d]);
+        $v[$b] = ($v[$b] ^ $v[$c]).rotate_right(63);
+    };
+}
+
+/// The permutation P, a BLAKE2b round with the multiplication-hardened G
+fn permute(v: &mut [u64; 16]) {
+    gb!(v, 0, 4, 8, 12);
+    gb!(v, 1, 5, 9, 13);
+    gb!(v, 2, 6, 10, 14);
+    gb!(v, 3, 7, 11, 15);
+    gb!(v, 0, 5, 10, 15);
+    gb!(v, 1, 6, 11, 12);
+    gb!(v, 2, 7, 8, 13);
+    gb!(v, 3, 4, 9, 14);
+}
+
+/// The compression function G
+fn compress(x: &Block, y: &Block) -> Block {
+    let mut r = [0u64; BLOCK_WORDS];
+    for (r, (x, y)) in r.iter_mut().zip(x.iter().zip(y)) {
+        *r = *x ^ *y;
+    }
+    let mut q = r;
+    // rows are 16 consecutive words
+    for row in 0..8 {
+        let mut v = [0u64; 16];
+        v.copy_from_slice(&q[row * 16..row * 16 + 16]);
+        permute(&mut v);
+        q[row * 16..row * 16 + 16].copy_from_slice(&v);
+    }
+    // columns are pairs of words, strided by a row
+    for col in 0..8 {
+        let mut v = [0u64; 16];
+        for (i, v) in v.iter_mut().enumerate() {
+            *v = q[(i / 2) * 16 + col * 2 + i % 2];
+        }
+        permute(&mut v);
+        for (i, v) in v.iter().enumerate() {
+            q[(i / 2) * 16 + col * 2 + i % 2] = *v;
+        }
+    }
+    for (q, r) in q.iter_mut().zip(r) {
+        *q ^= r;
+    }
+    q
+}
+
+/// The variable-length hash function H', hashes the concatenated inputs into the output
+fn h_prime(inputs: &[&[u8]], output: &mut [u8]) {
+    let len = output.len();
+    let mut h = BLAKE2b::<64>::new_truncated(&[], len.min(64));
+    h.write(&(len as u32).to_le_bytes());
+    for v in inputs {
+        h.write(v);
+    }
+    let mut last = h.finish();
+    if len <= 64 {
+        output.copy_from_slice(&last[..len]);
+        return;
+    }
+    // all but the last block contribute their first 32 bytes
+    let r = len.div_ceil(32) - 2;
+    output[..32].copy_from_slice(&last[..32]);
+    for i in 1..r {
+        last = BLAKE2b512::default().hash(&last);
+        output[i * 32..i * 32 + 32].copy_from_slice(&last[..32]);
+    }
+    let rem = len - 32 * r;
+    let last = BLAKE2b::<64>::new_truncated(&[], rem).hash(&last);
+    output[32 * r..].copy_from_slice(&last[..rem]);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Argon2id, KeyDerivationFunction};
+    use irox_bits::Error;
+    use irox_tools::{assert_eq_hex_slice, hex};
+
+    #[test]
+    pub fn test_rfc9106() -> Result<(), Error> {
+        let secret = [0x03u8; 8];
+        let ad = [0x04u8; 12];
+        let out = Argon2id::new(32, 3, 4)?
+            .with_secret(&secret)
+            .with_associated_data(&ad)
+            .argon2::<32>(&[0x01; 32], &[0x02; 16])?;
+        assert_eq_hex_slice!(
+            hex!("0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659"),
+            out
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_long_output() -> Result<(), Error> {
+        // outputs longer than 64 bytes chain BLAKE2b in H'.  Expected output from the
+        // phc-winner-argon2 reference:
+        // `echo -n password | argon2 somesalt -id -t 2 -k 64 -p 1 -l 128`
+        let mut out = [0u8; 128];
+        Argon2id::new(64, 2, 1)?.derive_key(b"password", b"somesalt", &mut out)?;
+        assert_eq_hex_slice!(
+            hex!(
+                "2838c903b4bcfc1720c50837fdcb55b25efbc8df39c05aa08342f92134cea3e3"
+                "d435b656d73043a826c43c7c04ab2d089268c74b1788074ea5ca8cee5ec4c8af"
+                "b6ae5caf476a3912c9db316b7f82caa413092548b9f9d14e868115c6b455d2a3"
+                "586305cc2d56c9612152c9966c13f777d9e6d411eb1c7a125891e9fd7b1ad8dc"
+            ),
+            out
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_data_dependent() -> Result<(), Error> {
+        // 256 KiB across 2 lanes is 32 blocks per segment.  The second pass uses only the
+        // Argon2d-style data-dependent indexing, with references across lanes.  Expected output
+        // from the phc-winner-argon2 reference:
+        // `echo -n password | argon2 somesalt -id -t 2 -k 256 -p 2 -l 32`
+        let out = Argon2id::new(256, 2, 2)?.argon2::<32>(b"password", b"somesalt")?;
+        assert_eq_hex_slice!(
+            hex!("6d093c501fd5999645e0ea3bf620d7b8be7fd2db59c20d9fff9539da2bf57037"),
+            out
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_params() {
+        assert!(
+            Argon2id::new(31, 1, 4).is_err(),
+            "memory < 8 * lanes accepted"
+        );
+        assert!(Argon2id::new(32, 0, 4).is_err(), "zero passes accepted");
+        assert!(Argon2id::new(32, 1, 0).is_err(), "zero lanes accepted");
+        let mut out = [0u8; 32];
+        let res = Argon2id::new(32, 1, 1).and_then(|a| a.derive_key(b"pw", b"short", &mut out));
+        assert!(res.is_err(), "short salt accepted");
+        assert_eq!(
+            64 << 20,
+            Argon2id::default().memory_required(),
+            "wrong memory"
+        );
+    }
+}
@@ -0,0 +1,448 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! Galois/Counter Mode authenticated encryption, NIST SP 800-38D
+
+#![allow(clippy::indexing_slicing)]
+
+use crate::{Aes128, Aes192, Aes256, BlockCipher, Ctr};
+use core::ops::DerefMut;
+use irox_bits::{Bits, BitsWrapper, Error, ErrorKind, MutBits};
+
+/// GHASH universal hash over GF(2^128)
+struct GHash {
+    h: u128,
+    acc: u128,
+    buf: [u8; 16],
+    used: usize,
+}
+impl GHash {
+    fn new(h: [u8; 16]) -> Self {
+        Self {
+            h: u128::from_be_bytes(h),
+            acc: 0,
+            buf: [0; 16],
+            used: 0,
+        }
+    }
+
+    /// Multiplication in GF(2^128) with the GCM bit order and polynomial.
+    fn gf_mul(x: u128, y: u128) -> u128 {
+        const R: u128 = 0xE1 << 120;
+        let mut z = 0;
+        let mut v = y;
+        for i in 0..128 {
+            if (x >> (127 - i)) & 0x1 == 0x1 {
+                z ^= v;
+            }
+            v = if v & 0x1 == 0x1 { (v >> 1) ^ R } else { v >> 1 };
+        }
+        z
+    }
+
+    /// Zero-pads and hashes any partial block
+    fn pad(&mut self) {
+        if self.used > 0 {
+            for v in self.buf.iter_mut().skip(self.used) {
+                *v = 0;
+            }
+            self.block();
+        }
+    }
+
+    fn block(&mut self) {
+        self.acc = Self::gf_mul(self.acc ^ u128::from_be_bytes(self.buf), self.h);
+        self.used = 0;
+    }
+
+    fn finish(mut self, aadlen: u64, cipherlen: u64) -> u128 {
+        self.pad();
+        self.buf[..8].copy_from_slice(&(aadlen * 8).to_be_bytes());
+        self.buf[8..].copy_from_slice(&(cipherlen * 8).to_be_bytes());
+        self.block();
+        self.acc
+    }
+}
+impl MutBits for GHash {
+    fn write_u8(&mut self, val: u8) -> Result<(), Error> {
+        self.buf[self.used] = val;
+        self.used += 1;
+        if self.used == 16 {
+            self.block();
+        }
+        Ok(())
+    }
+}
+
+/// Returns the keystream, the hash, and the mask for the tag
+fn init<C: BlockCipher>(key: &C::Key, nonce: &[u8; 12]) -> (Ctr<C>, GHash, u128) {
+    let cipher = C::from_key(key);
+    let ghash = GHash::new(cipher.encrypt_block(&[0; 16]));
+    let mut j0 = [0u8; 16];
+    j0[..12].copy_from_slice(nonce);
+    j0[15] = 1;
+    let mask = u128::from_be_bytes(cipher.encrypt_block(&j0));
+    j0[15] = 2;
+    (Ctr::new_inc32(cipher, &j0), ghash, mask)
+}
+
+///
+/// AES-GCM encryption with a 96-bit nonce.  The ciphertext is written to the output as the
+/// plaintext is written in, [`AesGcmEncrypt::finish`] returns the 128-bit tag.
+pub struct AesGcmEncrypt<'a, C: BlockCipher, OUT: MutBits> {
+    keystream: Ctr<C>,
+    ghash: GHash,
+    mask: u128,
+    output: BitsWrapper<'a, OUT>,
+    aadlen: u64,
+    cipherlen: u64,
+}
+impl<'a, C: BlockCipher, OUT: MutBits> AesGcmEncrypt<'a, C, OUT> {
+    ///
+    /// Starts the encryption, reading and authenticating all of the additional data first.
+    pub fn new<AAD: Bits>(
+        output: BitsWrapper<'a, OUT>,
+        key: &C::Key,
+        nonce: &[u8; 12],
+        mut aad: BitsWrapper<'_, AAD>,
+    ) -> Result<Self, Error> {
+        let (keystream, mut ghash, mask) = init::<C>(key, nonce);
+        let aadlen = ghash.write_all_into_self_from(aad.deref_mut())?;
+        ghash.pad();
+        Ok(Self {
+            keystream,
+            ghash,
+            mask,
+            output,
+            aadlen,
+            cipherlen: 0,
+        })
+    }
+
+    pub fn write(&mut self, data: &[u8]) -> Result<(), Error> {
+        for v in data {
+            let v = *v ^ self.keystream.next_key();
+            self.ghash.write_u8(v)?;
+            self.output.write_u8(v)?;
+            self.cipherlen += 1;
+        }
+        Ok(())
+    }
+
+    pub fn finish(self) -> Result<[u8; 16], Error> {
+        let tag = self.ghash.finish(self.aadlen, self.cipherlen) ^ self.mask;
+        Ok(tag.to_be_bytes())
+    }
+}
+impl<OUT: MutBits, C: BlockCipher> MutBits for AesGcmEncrypt<'_, C, OUT> {
+    fn write_u8(&mut self, val: u8) -> Result<(), Error> {
+        self.write(&[val])
+    }
+}
+
+///
+/// AES-GCM decryption with a 96-bit nonce.  Reading returns the plaintext as the ciphertext is
+/// read - it MUST be discarded if [`AesGcmDecrypt::finish`] fails to authenticate it.
+pub struct AesGcmDecrypt<'a, C: BlockCipher, IN: Bits> {
+    keystream: Ctr<C>,
+    ghash: GHash,
+    mask: u128,
+    input: BitsWrapper<'a, IN>,
+    aadlen: u64,
+    cipherlen: u64,
+}
+impl<'a, C: BlockCipher, IN: Bits> AesGcmDecrypt<'a, C, IN> {
+    ///
+    /// Starts the decryption, reading and authenticating all of the additional data first.
+    pub fn new<AAD: Bits>(
+        input: BitsWrapper<'a, IN>,
+        key: &C::Key,
+        nonce: &[u8; 12],
+        mut aad: BitsWrapper<'_, AAD>,
+    ) -> Result<Self, Error> {
+        let (keystream, mut ghash, mask) = init::<C>(key, nonce);
+        let aadlen = ghash.write_all_into_self_from(aad.deref_mut())?;
+        ghash.pad();
+        Ok(Self {
+            keystream,
+            ghash,
+            mask,
+            input,
+            aadlen,
+            cipherlen: 0,
+        })
+    }
+
+    pub fn finish(self, tag: &[u8; 16]) -> Result<(), Error> {
+        let calc = self.ghash.finish(self.aadlen, self.cipherlen) ^ self.mask;
+        if calc ^ u128::from_be_bytes(*tag) != 0 {
+            return Err(ErrorKind::InvalidData.into());
+        }
+        Ok(())
+    }
+}
+impl<IN: Bits, C: BlockCipher> Bits for AesGcmDecrypt<'_, C, IN> {
+    fn next_u8(&mut self) -> Result<Option<u8>, Error> {
+        Ok(if let Some(v) = self.input.next_u8()? {
+            self.ghash.write_u8(v)?;
+            self.cipherlen += 1;
+            Some(v ^ self.keystream.next_key())
+        } else {
+            None
+        })
+    }
+}
+
+pub type Aes128GcmEncrypt<'a, OUT> = AesGcmEncrypt<'a, Aes128, OUT>;
+pub type Aes192GcmEncrypt<'a, OUT> = AesGcmEncrypt<'a, Aes192, OUT>;
+pub type Aes256GcmEncrypt<'a, OUT> = AesGcmEncrypt<'a, Aes256, OUT>;
+pub type Aes128GcmDecrypt<'a, IN> = AesGcmDecrypt<'a, Aes128, IN>;
+pub type Aes192GcmDecrypt<'a, IN> = AesGcmDecrypt<'a, Aes192, IN>;
+pub type Aes256GcmDecrypt<'a, IN> = AesGcmDecrypt<'a, Aes256, IN>;
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::{Aes128, Aes192, Aes256, AesGcmDecrypt, AesGcmEncrypt, BlockCipher};
+    use crate::{Aes128GcmDecrypt, Aes128GcmEncrypt, Aes256GcmEncrypt};
+    use irox_bits::{Bits, BitsWrapper, Error, ErrorKind, MutBits};
+    use irox_tools::{assert_eq_hex_slice, hex};
+
+    static KEY: [u8; 16] = hex!("feffe9928665731c6d6a8f9467308308");
+    static NONCE: [u8; 12] = hex!("cafebabefacedbaddecaf888");
+    static AAD: [u8; 20] = hex!("feedfacedeadbeeffeedfacedeadbeefabaddad2");
+    static PLAINTEXT: [u8; 64] = hex!(
+        "d9313225f88406e5a55909c5aff5269a"
+        "86a7a9531534f7da2e4c303d8a318a72"
+        "1c3c0c95956809532fcf0e2449a6b525"
+        "b16aedf5aa0de657ba637b391aafd255"
+    );
+
+    #[allow(clippy::panic_in_result_fn)]
+    fn check<C: BlockCipher>(
+        key: &C::Key,
+        nonce: &[u8; 12],
+        aad: &[u8],
+        pt: &[u8],
+        ct: &[u8],
+        tag: &[u8; 16],
+    ) -> Result<(), Error> {
+        let mut out = Vec::new();
+        let mut enc = AesGcmEncrypt::<C, _>::new(
+            BitsWrapper::Borrowed(&mut out),
+            key,
+            nonce,
+            BitsWrapper::Owned(aad),
+        )?;
+        enc.write(pt)?;
+        let calc = enc.finish()?;
+        assert_eq_hex_slice!(ct, out.as_slice());
+        assert_eq_hex_slice!(tag, &calc);
+
+        let mut dec = AesGcmDecrypt::<C, _>::new(
+            BitsWrapper::Owned(ct),
+            key,
+            nonce,
+            BitsWrapper::Owned(aad),
+        )?;
+        for (idx, v) in pt.iter().enumerate() {
+            assert_eq!(Ok(*v), dec.read_u8(), "idx: {idx}");
+        }
+        assert_eq!(Ok(None), dec.next_u8(), "trailing data");
+        assert_eq!(Ok(()), dec.finish(tag), "valid tag rejected");
+
+        let mut bad = *tag;
+        bad[15] ^= 0x1;
+        let mut dec = AesGcmDecrypt::<C, _>::new(
+            BitsWrapper::Owned(ct),
+            key,
+            nonce,
+            BitsWrapper::Owned(aad),
+        )?;
+        while dec.next_u8()?.is_some() {}
+        assert!(dec.finish(&bad).is_err(), "tampered tag accepted");
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_gcm_zeros() -> Result<(), Error> {
+        let nonce = [0u8; 12];
+        check::<Aes128>(
+            &[0; 16],
+            &nonce,
+            &[],
+            &[],
+            &[],
+            &hex!("58e2fccefa7e3061367f1d57a4e7455a"),
+        )?;
+        check::<Aes128>(
+            &[0; 16],
+            &nonce,
+            &[],
+            &[0; 16],
+            &hex!("0388dace60b6a392f328c2b971b2fe78"),
+            &hex!("ab6e47d42cec13bdf53a67b21257bddf"),
+        )?;
+        check::<Aes192>(
+            &[0; 24],
+            &nonce,
+            &[],
+            &[],
+            &[],
+            &hex!("cd33b28ac773f74ba00ed1f312572435"),
+        )?;
+        check::<Aes256>(
+            &[0; 32],
+            &nonce,
+            &[],
+            &[],
+            &[],
+            &hex!("530f8afbc74536b9a963b4f1c4cb738b"),
+        )?;
+        check::<Aes256>(
+            &[0; 32],
+            &nonce,
+            &[],
+            &[0; 16],
+            &hex!("cea7403d4d606b6e074ec5d3baf39d18"),
+            &hex!("d0d1c8a799996bf0265b98b5d48ab919"),
+        )
+    }
+
+    #[test]
+    pub fn test_gcm_aes128() -> Result<(), Error> {
+        check::<Aes128>(
+            &KEY,
+            &NONCE,
+            &[],
+            &PLAINTEXT,
+            &hex!(
+                "42831ec2217774244b7221b784d0d49c"
+                "e3aa212f2c02a4e035c17e2329aca12e"
+                "21d514b25466931c7d8f6a5aac84aa05"
+                "1ba30b396a0aac973d58e091473f5985"
+            ),
+            &hex!("4d5c2af327cd64a62cf35abd2ba6fab4"),
+        )?;
+        check::<Aes128>(
+            &KEY,
+            &NONCE,
+            &AAD,
+            &PLAINTEXT[..60],
+            &hex!(
+                "42831ec2217774244b7221b784d0d49c"
+                "e3aa212f2c02a4e035c17e2329aca12e"
+                "21d514b25466931c7d8f6a5aac84aa05"
+                "1ba30b396a0aac973d58e091"
+            ),
+            &hex!("5bc94fbc3221a5db94fae95ae7121a47"),
+        )
+    }
+
+    #[test]
+    pub fn test_gcm_aes192() -> Result<(), Error> {
+        check::<Aes192>(
+            &hex!("feffe9928665731c6d6a8f9467308308feffe9928665731c"),
+            &NONCE,
+            &AAD,
+            &PLAINTEXT[..60],
+            &hex!(
+                "3980ca0b3c00e841eb06fac4872a2757"
+                "859e1ceaa6efd984628593b40ca1e19c"
+                "7d773d00c144c525ac619d18c84a3f47"
+                "18e2448b2fe324d9ccda2710"
+            ),
+            &hex!("2519498e80f1478f37ba55bd6d27618c"),
+        )
+    }
+
+    #[test]
+    pub fn test_gcm_aes256() -> Result<(), Error> {
+        check::<Aes256>(
+            &hex!("feffe9928665731c6d6a8f9467308308feffe9928665731c6d6a8f9467308308"),
+            &NONCE,
+            &AAD,
+            &PLAINTEXT[..60],
+            &hex!(
+                "522dc1f099567d07f47f37a32a84427d"
+                "643a8cdcbfe5c0c97598a2bd2555d1aa"
+                "8cb08e48590dbb3da7b08b1056828838"
+                "c5f61e6393ba7a0abcc9f662"
+            ),
+            &hex!("76fc6ece0f4e1768cddf8853bb2d551b"),
+        )
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_gcm_roundtrip() -> Result<(), Error> {
+        let key = [0x11u8; 32];
+        let mut out = Vec::new();
+        let mut enc = Aes256GcmEncrypt::new(
+            BitsWrapper::Borrowed(&mut out),
+            &key,
+            &NONCE,
+            BitsWrapper::Owned(AAD.as_slice()),
+        )?;
+        for v in PLAINTEXT {
+            enc.write_u8(v)?;
+        }
+        let tag = enc.finish()?;
+
+        let mut dec = AesGcmDecrypt::<Aes256, _>::new(
+            BitsWrapper::Owned(out.as_slice()),
+            &key,
+            &NONCE,
+            BitsWrapper::Owned(AAD.as_slice()),
+        )?;
+        let mut pt = Vec::new();
+        while let Some(v) = dec.next_u8()? {
+            pt.push(v);
+        }
+        assert_eq_hex_slice!(&PLAINTEXT, pt.as_slice());
+        dec.finish(&tag)?;
+
+        // the wrong key fails
+        let mut dec = Aes128GcmDecrypt::new(
+            BitsWrapper::Owned(out.as_slice()),
+            &[0x11; 16],
+            &NONCE,
+            BitsWrapper::Owned(AAD.as_slice()),
+        )?;
+        while dec.next_u8()?.is_some() {}
+        assert!(dec.finish(&tag).is_err(), "wrong key accepted");
+        Ok(())
+    }
+
+    /// Additional data that fails partway through reading it
+    struct BrokenAAD(usize);
+    impl Bits for BrokenAAD {
+        fn next_u8(&mut self) -> Result<Option<u8>, Error> {
+            if self.0 == 0 {
+                return Err(ErrorKind::BrokenPipe.into());
+            }
+            self.0 -= 1;
+            Ok(Some(0))
+        }
+    }
+
+    #[test]
+    pub fn test_gcm_aad_error() {
+        let mut out = Vec::new();
+        let enc = Aes128GcmEncrypt::new(
+            BitsWrapper::Borrowed(&mut out),
+            &KEY,
+            &NONCE,
+            BitsWrapper::Owned(BrokenAAD(4)),
+        );
+        assert_eq!(Some(ErrorKind::BrokenPipe), enc.err().map(|e| e.kind()));
+        let dec = Aes128GcmDecrypt::new(
+            BitsWrapper::Owned(PLAINTEXT.as_slice()),
+            &KEY,
+            &NONCE,
+            BitsWrapper::Owned(BrokenAAD(4)),
+        );
+        assert_eq!(Some(ErrorKind::BrokenPipe), dec.err().map(|e| e.kind()));
+    }
+}
@@ -0,0 +1,187 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! HMAC-based Extract-and-Expand Key Derivation Function, [RFC 5869](https://datatracker.ietf.org/doc/html/rfc5869)
+
+use crate::KeyDerivationFunction;
+use core::marker::PhantomData;
+use irox_bits::{BitsErrorKind, Error};
+use irox_tools::hash::{HashDigest, HMAC, SHA1, SHA224, SHA256, SHA384, SHA512};
+
+#[allow(non_camel_case_types)]
+pub type HKDF_SHA1<'a> = HKDF<'a, 64, 20, SHA1>;
+#[allow(non_camel_case_types)]
+pub type HKDF_SHA224<'a> = HKDF<'a, 64, 28, SHA224>;
+#[allow(non_camel_case_types)]
+pub type HKDF_SHA256<'a> = HKDF<'a, 64, 32, SHA256>;
+#[allow(non_camel_case_types)]
+pub type HKDF_SHA384<'a> = HKDF<'a, 128, 48, SHA384>;
+#[allow(non_camel_case_types)]
+pub type HKDF_SHA512<'a> = HKDF<'a, 128, 64, SHA512>;
+
+///
+/// HKDF is NOT a password hash - it has no work factor and should only be used to derive keys
+/// from input keying material that already has plenty of entropy (a DH shared secret, the output
+/// of another KDF, etc).  The `info` context is bound into the expanded output.
+pub struct HKDF<
+    'a,
+    const BLOCK_LEN: usize,
+    const DIGEST_LEN: usize,
+    T: HashDigest<BLOCK_LEN, DIGEST_LEN>,
+> {
+    info: &'a [u8],
+    _phan: PhantomData<T>,
+}
+
+impl<'a, const BLOCK_LEN: usize, const DIGEST_LEN: usize, T: HashDigest<BLOCK_LEN, DIGEST_LEN>>
+    HKDF<'a, BLOCK_LEN, DIGEST_LEN, T>
+{
+    /// Creates a new HKDF that expands with the provided context info
+    pub fn new(info: &'a [u8]) -> Self {
+        Self {
+            info,
+            _phan: PhantomData,
+        }
+    }
+
+    ///
+    /// HKDF-Extract: concentrates the input keying material into a pseudorandom key.  An empty
+    /// salt is equivalent to a salt of `DIGEST_LEN` zeros.
+    pub fn extract(salt: &[u8], ikm: &[u8]) -> [u8; DIGEST_LEN] {
+        let mut hmac = HMAC::<BLOCK_LEN, DIGEST_LEN, T>::new(salt);
+        hmac.write(ikm);
+        hmac.finish()
+    }
+
+    ///
+    /// HKDF-Expand: expands the pseudorandom key into the output buffer.  The output may be at
+    /// most `255 * DIGEST_LEN` bytes long.
+    pub fn expand(prk: &[u8], info: &[u8], output: &mut [u8]) -> Result<(), Error> {
+        if output.len() > 255 * DIGEST_LEN {
+            return Error::err(BitsErrorKind::InvalidInput, "HKDF output too long");
+        }
+        let mut last: Option<[u8; DIGEST_LEN]> = None;
+        for (idx, chunk) in output.chunks_mut(DIGEST_LEN).enumerate() {
+            let mut hmac = HMAC::<BLOCK_LEN, DIGEST_LEN, T>::new(prk);
+            if let Some(last) = &last {
+                hmac.write(last);
+            }
+            hmac.write(info);
+            hmac.write(&[idx as u8 + 1]);
+            let block = hmac.finish();
+            for (a, b) in chunk.iter_mut().zip(block.iter()) {
+                *a = *b;
+            }
+            last = Some(block);
+        }
+        Ok(())
+    }
+}
+
+impl<const BLOCK_LEN: usize, const DIGEST_LEN: usize, T: HashDigest<BLOCK_LEN, DIGEST_LEN>>
+    KeyDerivationFunction for HKDF<'_, BLOCK_LEN, DIGEST_LEN, T>
+{
+    fn derive_key(&self, password: &[u8], salt: &[u8], output: &mut [u8]) -> Result<(), Error> {
+        let prk = Self::extract(salt, password);
+        Self::expand(&prk, self.info, output)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{KeyDerivationFunction, HKDF_SHA1, HKDF_SHA256, HKDF_SHA512};
+    use irox_bits::Error;
+    use irox_tools::{assert_eq_hex_slice, hex};
+
+    static IKM: [u8; 22] = [0x0b; 22];
+    static SALT: [u8; 13] = hex!("000102030405060708090a0b0c");
+    static INFO: [u8; 10] = hex!("f0f1f2f3f4f5f6f7f8f9");
+
+    #[test]
+    pub fn test_case1() -> Result<(), Error> {
+        let prk = HKDF_SHA256::extract(&SALT, &IKM);
+        assert_eq_hex_slice!(
+            hex!("077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5"),
+            prk
+        );
+        let mut okm = [0u8; 42];
+        HKDF_SHA256::expand(&prk, &INFO, &mut okm)?;
+        assert_eq_hex_slice!(
+            hex!(
+                "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db0"
+                "2d56ecc4c5bf34007208d5b887185865"
+            ),
+            okm
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_case2() -> Result<(), Error> {
+        let ikm: [u8; 80] = core::array::from_fn(|i| i as u8);
+        let salt: [u8; 80] = core::array::from_fn(|i| 0x60 + i as u8);
+        let info: [u8; 80] = core::array::from_fn(|i| 0xb0 + i as u8);
+        let mut okm = [0u8; 82];
+        HKDF_SHA256::new(&info).derive_key(&ikm, &salt, &mut okm)?;
+        assert_eq_hex_slice!(
+            hex!(
+                "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c"
+                "59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71"
+                "cc30c58179ec3e87c14c01d5c1f3434f1d87"
+            ),
+            okm
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_case3() -> Result<(), Error> {
+        let mut okm = [0u8; 42];
+        HKDF_SHA256::new(&[]).derive_key(&IKM, &[], &mut okm)?;
+        assert_eq_hex_slice!(
+            hex!(
+                "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec345"
+                "4e5f3c738d2d9d201395faa4b61a96c8"
+            ),
+            okm
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_case4() -> Result<(), Error> {
+        let mut okm = [0u8; 42];
+        HKDF_SHA1::new(&INFO).derive_key(&IKM[..11], &SALT, &mut okm)?;
+        assert_eq_hex_slice!(
+            hex!(
+                "085a01ea1b10f36933068b56efa5ad81a4f14b822f5b091568a9"
+                "cdd4f155fda2c22e422478d305f3f896"
+            ),
+            okm
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_sha512() -> Result<(), Error> {
+        let mut okm = [0u8; 42];
+        HKDF_SHA512::new(&INFO).derive_key(&IKM, &SALT, &mut okm)?;
+        assert_eq_hex_slice!(
+            hex!(
+                "832390086cda71fb47625bb5ceb168e4c8e26a1a16ed34d9fc7f"
+                "e92c1481579338da362cb8d9f925d7cb"
+            ),
+            okm
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_too_long() {
+        let mut okm = [0u8; 255 * 20 + 1];
+        let res = HKDF_SHA1::new(&INFO).derive_key(&IKM, &SALT, &mut okm);
+        assert!(res.is_err(), "output longer than 255 blocks accepted");
+    }
+}
@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! Common interface for the password-based key derivation functions
+
+use irox_bits::Error;
+
+///
+/// A Key Derivation Function (KDF) stretches a (low-entropy) password and a salt into key
+/// material of an arbitrary length.  The cost parameters of each function are fixed when the
+/// function is constructed, so a `&dyn KeyDerivationFunction` can be handed to code that doesn't
+/// care which algorithm is being used.
+pub trait KeyDerivationFunction {
+    ///
+    /// Derives key material from the password and salt, filling the entire output buffer.
+    /// Returns an error if the parameters or the output length are not supported by the function.
+    fn derive_key(&self, password: &[u8], salt: &[u8], output: &mut [u8]) -> Result<(), Error>;
+}
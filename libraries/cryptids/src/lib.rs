@@ -17,22 +17,33 @@ mod aead;
 mod aes;
 mod chacha20;
 pub mod ed25519;
+mod gcm;
+mod hkdf;
+mod kdf;
+mod modes;
 mod pbkdf2;
 mod poly1305;
 mod salsa;
-mod scrypt;
 pub mod x25519;
 
 pub use aead::*;
 pub use aes::*;
 pub use chacha20::*;
+pub use gcm::*;
+pub use hkdf::*;
 pub use irox_tools::hash::sha2;
+pub use kdf::*;
+pub use modes::*;
 pub use pbkdf2::*;
 pub use poly1305::*;
 pub use salsa::*;
 
 use irox_tools::cfg_feature_std;
 cfg_feature_std! {
+    mod argon2;
     mod crng;
+    mod scrypt;
+    pub use argon2::*;
     pub use crng::*;
+    pub use scrypt::*;
 }
@@ -0,0 +1,384 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! Block cipher modes of operation, NIST SP 800-38A
+
+#![allow(clippy::indexing_slicing)]
+
+use crate::BlockCipher;
+use core::ops::DerefMut;
+use irox_bits::{Bits, BitsWrapper, Error, ErrorKind, MutBits};
+
+/// Reads a full block, `None` at the end of the input.
+fn read_block<T: Bits>(input: &mut T) -> Result<Option<[u8; 16]>, Error> {
+    let mut block = [0u8; 16];
+    for (idx, v) in block.iter_mut().enumerate() {
+        match input.next_u8()? {
+            Some(b) => *v = b,
+            None if idx == 0 => return Ok(None),
+            None => return Err(ErrorKind::UnexpectedEof.into()),
+        }
+    }
+    Ok(Some(block))
+}
+
+fn xor_into(block: &mut [u8; 16], other: &[u8; 16]) {
+    for (a, b) in block.iter_mut().zip(other) {
+        *a ^= *b;
+    }
+}
+
+///
+/// Cipher Block Chaining encryption with PKCS#7 padding.  Plaintext written in is encrypted and
+/// written to the output as each block fills, [`CbcEncrypt::finish`] pads and writes the last
+/// block.
+pub struct CbcEncrypt<'a, C: BlockCipher, OUT: MutBits> {
+    cipher: C,
+    prev: [u8; 16],
+    buf: [u8; 16],
+    used: usize,
+    output: BitsWrapper<'a, OUT>,
+}
+impl<'a, C: BlockCipher, OUT: MutBits> CbcEncrypt<'a, C, OUT> {
+    pub fn new(output: BitsWrapper<'a, OUT>, key: &C::Key, iv: &[u8; 16]) -> Self {
+        Self {
+            cipher: C::from_key(key),
+            prev: *iv,
+            buf: [0; 16],
+            used: 0,
+            output,
+        }
+    }
+
+    pub fn write(&mut self, data: &[u8]) -> Result<(), Error> {
+        for v in data {
+            self.buf[self.used] = *v;
+            self.used += 1;
+            if self.used == 16 {
+                self.write_block()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_block(&mut self) -> Result<(), Error> {
+        xor_into(&mut self.buf, &self.prev);
+        self.prev = self.cipher.encrypt_block(&self.buf);
+        self.used = 0;
+        self.output.write_all_bytes(&self.prev)
+    }
+
+    ///
+    /// Pads and writes the final block.  A full block of padding is written if the plaintext
+    /// was a multiple of the block size.
+    pub fn finish(mut self) -> Result<(), Error> {
+        let pad = (16 - self.used) as u8;
+        for v in self.buf.iter_mut().skip(self.used) {
+            *v = pad;
+        }
+        self.write_block()?;
+        self.output.flush()
+    }
+}
+impl<C: BlockCipher, OUT: MutBits> MutBits for CbcEncrypt<'_, C, OUT> {
+    fn write_u8(&mut self, val: u8) -> Result<(), Error> {
+        self.write(&[val])
+    }
+}
+
+///
+/// Cipher Block Chaining decryption with PKCS#7 padding.  Reading returns the plaintext, one
+/// block behind the ciphertext so that the padding can be checked and removed from the final
+/// block.  Invalid padding or a partial final block is an error.
+pub struct CbcDecrypt<'a, C: BlockCipher, IN: Bits> {
+    cipher: C,
+    prev: [u8; 16],
+    next: Option<[u8; 16]>,
+    started: bool,
+    block: [u8; 16],
+    pos: usize,
+    len: usize,
+    input: BitsWrapper<'a, IN>,
+}
+impl<'a, C: BlockCipher, IN: Bits> CbcDecrypt<'a, C, IN> {
+    pub fn new(input: BitsWrapper<'a, IN>, key: &C::Key, iv: &[u8; 16]) -> Self {
+        Self {
+            cipher: C::from_key(key),
+            prev: *iv,
+            next: None,
+            started: false,
+            block: [0; 16],
+            pos: 0,
+            len: 0,
+            input,
+        }
+    }
+
+    /// Decrypts the next block, returns false at the end of the input.
+    fn fill(&mut self) -> Result<bool, Error> {
+        let current = if self.started {
+            self.next.take()
+        } else {
+            self.started = true;
+            read_block(self.input.deref_mut())?
+        };
+        let Some(current) = current else {
+            return Ok(false);
+        };
+        self.next = read_block(self.input.deref_mut())?;
+        self.block = self.cipher.decrypt_block(&current);
+        xor_into(&mut self.block, &self.prev);
+        self.prev = current;
+        self.pos = 0;
+        self.len = 16;
+        if self.next.is_none() {
+            let pad = self.block[15];
+            let mut padding = self.block.iter().rev().take(pad as usize);
+            if pad == 0 || pad > 16 || padding.any(|v| *v != pad) {
+                return Err(ErrorKind::InvalidData.into());
+            }
+            self.len = 16 - pad as usize;
+        }
+        Ok(true)
+    }
+}
+impl<C: BlockCipher, IN: Bits> Bits for CbcDecrypt<'_, C, IN> {
+    fn next_u8(&mut self) -> Result<Option<u8>, Error> {
+        while self.pos == self.len {
+            if !self.fill()? {
+                return Ok(None);
+            }
+        }
+        let v = self.block[self.pos];
+        self.pos += 1;
+        Ok(Some(v))
+    }
+}
+
+///
+/// Counter mode.  The keystream is the encryption of successive counter blocks, the counter
+/// block is incremented as a 128-bit big-endian integer.  Encryption and decryption are the
+/// same operation.
+pub struct Ctr<C: BlockCipher> {
+    cipher: C,
+    counter: [u8; 16],
+    keystream: [u8; 16],
+    pos: usize,
+    /// only increment the lower 32 bits, as GCM does.
+    inc32: bool,
+}
+impl<C: BlockCipher> Ctr<C> {
+    pub fn new(key: &C::Key, initial_counter: &[u8; 16]) -> Self {
+        Self::from_cipher(C::from_key(key), initial_counter)
+    }
+
+    pub(crate) fn from_cipher(cipher: C, initial_counter: &[u8; 16]) -> Self {
+        Self {
+            cipher,
+            counter: *initial_counter,
+            keystream: [0; 16],
+            pos: 16,
+            inc32: false,
+        }
+    }
+
+    pub(crate) fn new_inc32(cipher: C, initial_counter: &[u8; 16]) -> Self {
+        let mut out = Self::from_cipher(cipher, initial_counter);
+        out.inc32 = true;
+        out
+    }
+
+    pub fn next_key(&mut self) -> u8 {
+        if self.pos == 16 {
+            self.keystream = self.cipher.encrypt_block(&self.counter);
+            if self.inc32 {
+                let next = u32::from_be_bytes([
+                    self.counter[12],
+                    self.counter[13],
+                    self.counter[14],
+                    self.counter[15],
+                ])
+                .wrapping_add(1);
+                self.counter[12..].copy_from_slice(&next.to_be_bytes());
+            } else {
+                let next = u128::from_be_bytes(self.counter).wrapping_add(1);
+                self.counter = next.to_be_bytes();
+            }
+            self.pos = 0;
+        }
+        let v = self.keystream[self.pos];
+        self.pos += 1;
+        v
+    }
+
+    ///
+    /// Process (xor) the provided input with the keystream and write it to the output buffer.
+    /// Returns the number of bytes that were consumed from the input buffer and written to the
+    /// output buffer.
+    pub fn process(&mut self, input: &[u8], output: &mut [u8]) -> usize {
+        let mut used = 0;
+        for (o, i) in output.iter_mut().zip(input.iter()) {
+            *o = *i ^ self.next_key();
+            used += 1;
+        }
+        used
+    }
+}
+impl<C: BlockCipher> Bits for Ctr<C> {
+    fn next_u8(&mut self) -> Result<Option<u8>, Error> {
+        Ok(Some(self.next_key()))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::{Aes128, Aes192, Aes256, BlockCipher, CbcDecrypt, CbcEncrypt, Ctr};
+    use irox_bits::{Bits, BitsWrapper, Error};
+    use irox_tools::{assert_eq_hex_slice, hex};
+
+    // NIST SP 800-38A, Appendix F
+    static PLAINTEXT: [u8; 64] = hex!(
+        "6bc1bee22e409f96e93d7e117393172a"
+        "ae2d8a571e03ac9c9eb76fac45af8e51"
+        "30c81c46a35ce411e5fbc1191a0a52ef"
+        "f69f2445df4f9b17ad2b417be66c3710"
+    );
+    static IV: [u8; 16] = hex!("000102030405060708090a0b0c0d0e0f");
+    static COUNTER: [u8; 16] = hex!("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff");
+
+    #[allow(clippy::panic_in_result_fn)]
+    fn check_cbc<C: BlockCipher>(key: &C::Key, expected: &[u8]) -> Result<(), Error> {
+        let mut out = Vec::new();
+        let mut enc = CbcEncrypt::<C, _>::new(BitsWrapper::Borrowed(&mut out), key, &IV);
+        enc.write(&PLAINTEXT)?;
+        enc.finish()?;
+        // the SP 800-38A vectors are unpadded, the last block here is a full block of padding.
+        assert_eq!(80, out.len(), "missing padding block");
+        assert_eq_hex_slice!(expected, &out[..64]);
+
+        let mut dec = CbcDecrypt::<C, _>::new(BitsWrapper::Owned(out.as_slice()), key, &IV);
+        let mut pt = Vec::new();
+        while let Some(v) = dec.next_u8()? {
+            pt.push(v);
+        }
+        assert_eq_hex_slice!(&PLAINTEXT, pt.as_slice());
+        Ok(())
+    }
+
+    fn check_ctr<C: BlockCipher>(key: &C::Key, expected: &[u8]) {
+        let mut out = [0u8; 64];
+        let mut ctr = Ctr::<C>::new(key, &COUNTER);
+        assert_eq!(64, ctr.process(&PLAINTEXT, &mut out), "short keystream");
+        assert_eq_hex_slice!(expected, &out);
+
+        let mut pt = [0u8; 64];
+        let mut ctr = Ctr::<C>::new(key, &COUNTER);
+        ctr.process(&out, &mut pt);
+        assert_eq_hex_slice!(&PLAINTEXT, &pt);
+    }
+
+    #[test]
+    pub fn test_cbc_aes128() -> Result<(), Error> {
+        check_cbc::<Aes128>(
+            &hex!("2b7e151628aed2a6abf7158809cf4f3c"),
+            &hex!(
+                "7649abac8119b246cee98e9b12e9197d"
+                "5086cb9b507219ee95db113a917678b2"
+                "73bed6b8e3c1743b7116e69e22229516"
+                "3ff1caa1681fac09120eca307586e1a7"
+            ),
+        )
+    }
+
+    #[test]
+    pub fn test_cbc_aes192() -> Result<(), Error> {
+        check_cbc::<Aes192>(
+            &hex!("8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b"),
+            &hex!(
+                "4f021db243bc633d7178183a9fa071e8"
+                "b4d9ada9ad7dedf4e5e738763f69145a"
+                "571b242012fb7ae07fa9baac3df102e0"
+                "08b0e27988598881d920a9e64f5615cd"
+            ),
+        )
+    }
+
+    #[test]
+    pub fn test_cbc_aes256() -> Result<(), Error> {
+        check_cbc::<Aes256>(
+            &hex!("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"),
+            &hex!(
+                "f58c4c04d6e5f1ba779eabfb5f7bfbd6"
+                "9cfc4e967edb808d679f777bc6702c7d"
+                "39f23369a9d9bacfa530e26304231461"
+                "b2eb05e2c39be9fcda6c19078c6a9d1b"
+            ),
+        )
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_cbc_padding() -> Result<(), Error> {
+        let key = [0x42u8; 16];
+        for len in 0..40 {
+            let data: Vec<u8> = (0..len).map(|v| v as u8).collect();
+            let mut out = Vec::new();
+            let mut enc = CbcEncrypt::<Aes128, _>::new(BitsWrapper::Borrowed(&mut out), &key, &IV);
+            enc.write(&data)?;
+            enc.finish()?;
+            assert_eq!((len / 16 + 1) * 16, out.len());
+
+            let mut dec =
+                CbcDecrypt::<Aes128, _>::new(BitsWrapper::Owned(out.as_slice()), &key, &IV);
+            let mut pt = Vec::new();
+            while let Some(v) = dec.next_u8()? {
+                pt.push(v);
+            }
+            assert_eq!(data, pt);
+
+            // corrupting the last block breaks the padding
+            if let Some(last) = out.last_mut() {
+                *last ^= 0x80;
+            }
+            let mut dec =
+                CbcDecrypt::<Aes128, _>::new(BitsWrapper::Owned(out.as_slice()), &key, &IV);
+            let res: Result<Vec<u8>, Error> = core::iter::from_fn(|| dec.next_u8().transpose())
+                .collect::<Result<Vec<u8>, Error>>();
+            assert!(res.is_err(), "corrupted padding accepted");
+        }
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_ctr() {
+        check_ctr::<Aes128>(
+            &hex!("2b7e151628aed2a6abf7158809cf4f3c"),
+            &hex!(
+                "874d6191b620e3261bef6864990db6ce"
+                "9806f66b7970fdff8617187bb9fffdff"
+                "5ae4df3edbd5d35e5b4f09020db03eab"
+                "1e031dda2fbe03d1792170a0f3009cee"
+            ),
+        );
+        check_ctr::<Aes192>(
+            &hex!("8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b"),
+            &hex!(
+                "1abc932417521ca24f2b0459fe7e6e0b"
+                "090339ec0aa6faefd5ccc2c6f4ce8e94"
+                "1e36b26bd1ebc670d1bd1d665620abf7"
+                "4f78a7f6d29809585a97daec58c6b050"
+            ),
+        );
+        check_ctr::<Aes256>(
+            &hex!("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"),
+            &hex!(
+                "601ec313775789a5b7a7f504bbf3d228"
+                "f443e3ca4d62b59aca84e990cacaf5c5"
+                "2b0930daa23de94ce87017ba2d84988d"
+                "dfc9c58db67aada613c2dd08457941a6"
+            ),
+        );
+    }
+}
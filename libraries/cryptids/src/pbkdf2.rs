@@ -2,9 +2,9 @@
 // Copyright 2025 IROX Contributors
 //
 
+use crate::KeyDerivationFunction;
 use core::marker::PhantomData;
-use irox_bits::MutBits;
-use irox_tools::buf::FixedU8Buf;
+use irox_bits::Error;
 use irox_tools::hash::{HashDigest, HMAC, SHA1, SHA224, SHA256, SHA384, SHA512};
 
 #[allow(non_camel_case_types)]
@@ -41,46 +41,95 @@ impl<
         T: HashDigest<BLOCK_LEN, DIGEST_LEN>,
     > PBKDF2<ITER, OUTPUT_LEN, BLOCK_LEN, DIGEST_LEN, T>
 {
-    const L: usize = OUTPUT_LEN.div_ceil(BLOCK_LEN);
-    const _R: usize = OUTPUT_LEN - (OUTPUT_LEN.div_ceil(BLOCK_LEN) - 1usize) * BLOCK_LEN;
+    pub fn new() -> Self {
+        Self { _phan: PhantomData }
+    }
 
     pub fn pbkdf2(key: &[u8], salt: &[u8]) -> [u8; OUTPUT_LEN] {
-        let mut out = FixedU8Buf::<OUTPUT_LEN>::new();
-        for l in 0..=Self::L {
-            let mut block = [0u8; DIGEST_LEN];
-            let mut last = {
-                let mut a = HMAC::<BLOCK_LEN, DIGEST_LEN, T>::new(key);
-                a.write(salt);
-                a.write((l as u32 + 1).to_be_bytes().as_slice());
-                let last = a.finish();
-                for (a, b) in block.iter_mut().zip(last.iter()) {
-                    *a ^= *b;
-                }
-                last
-            };
-            for _c in 1..ITER {
-                let mut a = HMAC::<BLOCK_LEN, DIGEST_LEN, T>::new(key);
-                a.write(&last);
-                last = a.finish();
-                for (a, b) in block.iter_mut().zip(last.iter()) {
-                    *a ^= *b;
-                }
+        let mut out = [0u8; OUTPUT_LEN];
+        pbkdf2_hmac::<BLOCK_LEN, DIGEST_LEN, T>(key, salt, ITER, &mut out);
+        out
+    }
+}
+
+impl<
+        const ITER: usize,
+        const OUTPUT_LEN: usize,
+        const BLOCK_LEN: usize,
+        const DIGEST_LEN: usize,
+        T: HashDigest<BLOCK_LEN, DIGEST_LEN>,
+    > Default for PBKDF2<ITER, OUTPUT_LEN, BLOCK_LEN, DIGEST_LEN, T>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///
+/// Fills the entire output buffer, whatever its length.  `OUTPUT_LEN` only sets the size of the
+/// array returned by [`PBKDF2::pbkdf2`], and is ignored here.
+impl<
+        const ITER: usize,
+        const OUTPUT_LEN: usize,
+        const BLOCK_LEN: usize,
+        const DIGEST_LEN: usize,
+        T: HashDigest<BLOCK_LEN, DIGEST_LEN>,
+    > KeyDerivationFunction for PBKDF2<ITER, OUTPUT_LEN, BLOCK_LEN, DIGEST_LEN, T>
+{
+    fn derive_key(&self, password: &[u8], salt: &[u8], output: &mut [u8]) -> Result<(), Error> {
+        pbkdf2_hmac::<BLOCK_LEN, DIGEST_LEN, T>(password, salt, ITER, output);
+        Ok(())
+    }
+}
+
+///
+/// PBKDF2 ([RFC 8018](https://datatracker.ietf.org/doc/html/rfc8018#section-5.2)) using the HMAC
+/// of the provided hash as the PRF, with a runtime iteration count and output length.  Fills the
+/// entire output buffer.
+pub fn pbkdf2_hmac<
+    const BLOCK_LEN: usize,
+    const DIGEST_LEN: usize,
+    T: HashDigest<BLOCK_LEN, DIGEST_LEN>,
+>(
+    key: &[u8],
+    salt: &[u8],
+    iterations: usize,
+    output: &mut [u8],
+) {
+    for (l, chunk) in output.chunks_mut(DIGEST_LEN).enumerate() {
+        let mut block = [0u8; DIGEST_LEN];
+        let mut last = {
+            let mut a = HMAC::<BLOCK_LEN, DIGEST_LEN, T>::new(key);
+            a.write(salt);
+            a.write((l as u32 + 1).to_be_bytes().as_slice());
+            let last = a.finish();
+            for (a, b) in block.iter_mut().zip(last.iter()) {
+                *a ^= *b;
+            }
+            last
+        };
+        for _c in 1..iterations {
+            let mut a = HMAC::<BLOCK_LEN, DIGEST_LEN, T>::new(key);
+            a.write(&last);
+            last = a.finish();
+            for (a, b) in block.iter_mut().zip(last.iter()) {
+                *a ^= *b;
             }
-            let rem = OUTPUT_LEN - out.len();
-            let end = rem.min(DIGEST_LEN);
-            let _ = out.write_all_bytes(&block[0..end]);
         }
-
-        out.as_buf_default()
+        for (a, b) in chunk.iter_mut().zip(block.iter()) {
+            *a = *b;
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
     use crate::{
-        PBKDF_HMAC_SHA1, PBKDF_HMAC_SHA224, PBKDF_HMAC_SHA256, PBKDF_HMAC_SHA384, PBKDF_HMAC_SHA512,
+        KeyDerivationFunction, PBKDF_HMAC_SHA1, PBKDF_HMAC_SHA224, PBKDF_HMAC_SHA256,
+        PBKDF_HMAC_SHA384, PBKDF_HMAC_SHA512,
     };
-    use irox_tools::assert_eq_hex_slice;
+    use irox_bits::Error;
+    use irox_tools::{assert_eq_hex_slice, hex};
 
     #[test]
     pub fn tv1() {
@@ -197,4 +246,20 @@ mod test {
             &out
         );
     }
+
+    #[test]
+    pub fn test_unaligned_output() -> Result<(), Error> {
+        // the KDF fills the buffer, the type's output length doesn't matter.
+        let mut out = [0u8; 77];
+        PBKDF_HMAC_SHA256::<3, 0>::new().derive_key(b"password", b"salt", &mut out)?;
+        assert_eq_hex_slice!(
+            hex!(
+                "ad35240ac683febfaf3cd49d845473fbbbaa2437f5f82d5a415ae00ac76c6bfc"
+                "cf9a9b8d6d2fe4a1e700c4460b040dbed692c1cb85a747f35588c08930fcfc41"
+                "ac48082086069b111a9c752f18"
+            ),
+            out
+        );
+        Ok(())
+    }
 }
@@ -1,3 +1,272 @@
 // SPDX-License-Identifier: MIT
 // Copyright 2025 IROX Contributors
 //
+
+//!
+//! The scrypt memory-hard password-based key derivation function, [RFC 7914](https://datatracker.ietf.org/doc/html/rfc7914)
+
+#![allow(clippy::indexing_slicing)]
+
+extern crate alloc;
+use crate::{pbkdf2_hmac, salsa_core_function_u32, KeyDerivationFunction};
+use alloc::vec;
+use alloc::vec::Vec;
+use irox_bits::{BitsErrorKind, Error};
+use irox_tools::hash::SHA256;
+
+/// Number of u32 words in a single 64-byte salsa block
+const SALSA_WORDS: usize = 16;
+
+///
+/// scrypt with a CPU/Memory cost of `N = 2^log_n`, a block size of `r` and a parallelization
+/// of `p`.  Each derivation needs `128 * r * N` bytes of memory.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Scrypt {
+    log_n: u8,
+    r: u32,
+    p: u32,
+}
+
+impl Default for Scrypt {
+    /// `N = 2^15, r = 8, p = 1` - 32 MiB of memory per derivation
+    fn default() -> Self {
+        Self {
+            log_n: 15,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+impl Scrypt {
+    ///
+    /// Creates a new scrypt with the provided parameters.  `log_n` must be in `1..64` and less
+    /// than `16 * r` (`N < 2^(128 * r / 8)`), `r` and `p` must be nonzero and `r * p < 2^30`
+    pub fn new(log_n: u8, r: u32, p: u32) -> Result<Self, Error> {
+        if log_n == 0 || log_n >= 64 {
+            return Error::err(BitsErrorKind::InvalidInput, "scrypt log_n must be in 1..64");
+        }
+        if r == 0 || p == 0 || (r as u64) * (p as u64) >= 1 << 30 {
+            return Error::err(
+                BitsErrorKind::InvalidInput,
+                "scrypt r * p must be in 1..2^30",
+            );
+        }
+        if log_n as u64 >= 16 * r as u64 {
+            return Error::err(
+                BitsErrorKind::InvalidInput,
+                "scrypt N must be less than 2^(16r)",
+            );
+        }
+        Ok(Self { log_n, r, p })
+    }
+
+    /// The CPU/Memory cost parameter `N`
+    pub fn cost(&self) -> u64 {
+        1 << self.log_n
+    }
+
+    /// The number of bytes of memory required for a single derivation, or an error if it's
+    /// larger than a [`u64`]
+    pub fn memory_required(&self) -> Result<u64, Error> {
+        (128 * self.r as u64)
+            .checked_mul(self.cost())
+            .ok_or_else(too_large)
+    }
+
+    /// Derives `OUTPUT_LEN` bytes of key from the password and salt.
+    pub fn scrypt<const OUTPUT_LEN: usize>(
+        &self,
+        password: &[u8],
+        salt: &[u8],
+    ) -> Result<[u8; OUTPUT_LEN], Error> {
+        let mut out = [0u8; OUTPUT_LEN];
+        self.derive_key(password, salt, &mut out)?;
+        Ok(out)
+    }
+}
+
+impl KeyDerivationFunction for Scrypt {
+    fn derive_key(&self, password: &[u8], salt: &[u8], output: &mut [u8]) -> Result<(), Error> {
+        let n = usize::try_from(self.cost()).map_err(|_| too_large())?;
+        let block_len = usize::try_from(self.r)
+            .ok()
+            .and_then(|r| r.checked_mul(128))
+            .ok_or_else(too_large)?;
+        let b_len = usize::try_from(self.p)
+            .ok()
+            .and_then(|p| p.checked_mul(block_len))
+            .ok_or_else(too_large)?;
+        let v_len = n.checked_mul(block_len / 4).ok_or_else(too_large)?;
+        let mut b = vec![0u8; b_len];
+        pbkdf2_hmac::<64, 32, SHA256>(password, salt, 1, &mut b);
+
+        let mut v = Vec::new();
+        v.try_reserve_exact(v_len).map_err(|_| too_large())?;
+        let mut x = vec![0u32; block_len / 4];
+        let mut y = vec![0u32; block_len / 4];
+        for chunk in b.chunks_exact_mut(block_len) {
+            for (w, c) in x.iter_mut().zip(chunk.chunks_exact(4)) {
+                *w = u32::from_le_bytes([c[0], c[1], c[2], c[3]]);
+            }
+            ro_mix(&mut x, &mut y, &mut v, n);
+            for (w, c) in x.iter().zip(chunk.chunks_exact_mut(4)) {
+                c.copy_from_slice(&w.to_le_bytes());
+            }
+        }
+        pbkdf2_hmac::<64, 32, SHA256>(password, &b, 1, output);
+        Ok(())
+    }
+}
+
+fn too_large() -> Error {
+    Error::new(BitsErrorKind::OutOfMemory, "scrypt N too large")
+}
+
+/// `B = B + salsa20/8(B)`
+fn salsa8(b: &mut [u32]) {
+    let mut x = [0u32; SALSA_WORDS];
+    x.copy_from_slice(b);
+    salsa_core_function_u32::<8>(&mut x);
+    for (a, b) in b.iter_mut().zip(x) {
+        *a = a.wrapping_add(b);
+    }
+}
+
+/// scryptBlockMix, mixes `b` into `y` using the salsa core.
+fn block_mix(b: &[u32], y: &mut [u32]) {
+    let blocks = b.len() / SALSA_WORDS;
+    let mut x = [0u32; SALSA_WORDS];
+    x.copy_from_slice(&b[b.len() - SALSA_WORDS..]);
+    for i in 0..blocks {
+        for (a, b) in x.iter_mut().zip(&b[i * SALSA_WORDS..]) {
+            *a ^= *b;
+        }
+        salsa8(&mut x);
+        // evens to the front half, odds to the back half
+        let dest = (i / 2 + (i % 2) * blocks / 2) * SALSA_WORDS;
+        y[dest..dest + SALSA_WORDS].copy_from_slice(&x);
+    }
+}
+
+/// scryptROMix, the memory-hard portion of the algorithm
+fn ro_mix(x: &mut [u32], y: &mut [u32], v: &mut Vec<u32>, n: usize) {
+    v.clear();
+    for _ in 0..n {
+        v.extend_from_slice(x);
+        block_mix(x, y);
+        x.copy_from_slice(y);
+    }
+    let len = x.len();
+    for _ in 0..n {
+        let last = len - SALSA_WORDS;
+        let j = (((x[last + 1] as u64) << 32) | x[last] as u64) & (n as u64 - 1);
+        let j = j as usize * len;
+        for (a, b) in x.iter_mut().zip(&v[j..j + len]) {
+            *a ^= *b;
+        }
+        block_mix(x, y);
+        x.copy_from_slice(y);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{KeyDerivationFunction, Scrypt};
+    use irox_bits::{BitsErrorKind, Error};
+    use irox_tools::{assert_eq_hex_slice, hex};
+
+    #[test]
+    pub fn test_salsa8() {
+        let mut inp: [u32; 16] = irox_bits::FromLEBytes::from_le_bytes(hex!(
+            "7e879a214f3ec9867ca940e641718f26"
+            "baee555b8c61c1b50df846116dcd3b1d"
+            "ee24f319df9b3d8514121e4b5ac5aa32"
+            "76021d2909c74829edebc68db8b8c25e"
+        ));
+        super::salsa8(&mut inp);
+        let out: [u8; 64] = irox_bits::ToLEBytes::to_le_bytes(&inp);
+        assert_eq_hex_slice!(
+            hex!(
+                "a41f859c6608cc993b81cacb020cef05"
+                "044b2181a2fd337dfd7b1c6396682f29"
+                "b4393168e3c9e6bcfe6bc5b7a06d96ba"
+                "e424cc102c91745c24ad673dc7618f81"
+            ),
+            out
+        );
+    }
+
+    #[test]
+    pub fn test_vector1() -> Result<(), Error> {
+        let out = Scrypt::new(4, 1, 1)?.scrypt::<64>(b"", b"")?;
+        assert_eq_hex_slice!(
+            hex!(
+                "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442"
+                "fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906"
+            ),
+            out
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_vector2() -> Result<(), Error> {
+        let mut out = [0u8; 64];
+        Scrypt::new(10, 8, 16)?.derive_key(b"password", b"NaCl", &mut out)?;
+        assert_eq_hex_slice!(
+            hex!(
+                "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b373162"
+                "2eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640"
+            ),
+            out
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_vector3() -> Result<(), Error> {
+        let out = Scrypt::new(14, 8, 1)?.scrypt::<64>(b"pleaseletmein", b"SodiumChloride")?;
+        assert_eq_hex_slice!(
+            hex!(
+                "7023bdcb3afd7348461c06cd81fd38ebfda8fbba904f8e3ea9b543f6545da1f2"
+                "d5432955613f0fcf62d49705242a9af9e61e85dc0d651e40dfcf017b45575887"
+            ),
+            out
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_params() {
+        assert!(Scrypt::new(0, 8, 1).is_err(), "N = 1 accepted");
+        assert!(Scrypt::new(14, 0, 1).is_err(), "r = 0 accepted");
+        assert!(
+            Scrypt::new(14, 1 << 15, 1 << 15).is_err(),
+            "r * p = 2^30 accepted"
+        );
+        assert!(Scrypt::new(16, 1, 1).is_err(), "N = 2^(16r) accepted");
+        assert!(Scrypt::new(15, 1, 1).is_ok(), "N < 2^(16r) rejected");
+        assert_eq!(
+            Ok(32 << 20),
+            Scrypt::default().memory_required(),
+            "wrong memory"
+        );
+    }
+
+    #[test]
+    pub fn test_too_large() -> Result<(), Error> {
+        let scrypt = Scrypt::new(63, 8, 1)?;
+        assert!(
+            scrypt.memory_required().is_err(),
+            "memory overflow accepted"
+        );
+
+        let mut out = [0u8; 64];
+        let Err(e) = Scrypt::new(55, 8, 1)?.derive_key(b"password", b"NaCl", &mut out) else {
+            panic!("N = 2^55 derived a key");
+        };
+        assert_eq!(BitsErrorKind::OutOfMemory, e.kind());
+        Ok(())
+    }
+}
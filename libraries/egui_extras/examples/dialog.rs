@@ -4,6 +4,7 @@
 
 #![allow(clippy::print_stdout)]
 
+use irox_cryptids::Argon2id;
 use irox_egui_extras::pwdlg::{dialog_options, DialogType, InteractionType};
 
 fn main() {
@@ -15,4 +16,6 @@ fn main() {
     });
 
     println!("{resp:?}");
+    let key = resp.and_then(|r| r.derive_key::<32>(&Argon2id::default(), b"dialog example salt"));
+    println!("{key:02X?}");
 }
@@ -4,6 +4,7 @@
 
 use egui::emath::Align;
 use egui::{Id, Key, Layout, Modifiers, TextEdit, Ui, Vec2, ViewportCommand, Widget};
+use irox_cryptids::KeyDerivationFunction;
 use irox_tools::cfg_feature_eframe;
 use log::error;
 use std::sync::mpsc::Sender;
@@ -206,6 +207,28 @@ pub enum UserResponse {
     Yes,
     No,
 }
+impl UserResponse {
+    ///
+    /// If the user entered a passphrase, derives a `N`-byte key from it using the provided KDF.
+    /// Returns `None` if there was no input or if the key derivation failed.  For operator
+    /// passphrases prefer a memory-hard function like [`irox_cryptids::Argon2id`] or
+    /// [`irox_cryptids::Scrypt`].
+    pub fn derive_key<const N: usize>(
+        &self,
+        kdf: &dyn KeyDerivationFunction,
+        salt: &[u8],
+    ) -> Option<[u8; N]> {
+        let UserResponse::OkInput(passphrase) = self else {
+            return None;
+        };
+        let mut key = [0u8; N];
+        if let Err(e) = kdf.derive_key(passphrase.as_bytes(), salt, &mut key) {
+            error!("{e:?}");
+            return None;
+        }
+        Some(key)
+    }
+}
 ///
 /// Customization details of the dialog widget
 #[derive(Default, Debug, Clone, Eq, PartialEq)]
@@ -7,6 +7,7 @@ use std::num::ParseIntError;
 use std::str::FromStr;
 
 use crate::error::Error;
+pub use chunked::*;
 pub use client::*;
 pub use h2::*;
 pub use headers::*;
@@ -14,6 +15,7 @@ use irox_enums::EnumIterItem;
 pub use request::*;
 pub use response::*;
 
+mod chunked;
 mod client;
 mod h2;
 mod headers;
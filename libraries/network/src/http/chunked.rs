@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+use std::io::{BufRead, ErrorKind, Read};
+
+///
+/// Decodes a body sent with `Transfer-Encoding: chunked`, returning EOF after the final
+/// zero-length chunk.  Chunk extensions and trailers are ignored.
+pub struct ChunkedReader<T: BufRead> {
+    inner: T,
+    remaining: usize,
+    finished: bool,
+}
+
+impl<T: BufRead> ChunkedReader<T> {
+    pub fn new(inner: T) -> Self {
+        ChunkedReader {
+            inner,
+            remaining: 0,
+            finished: false,
+        }
+    }
+
+    fn read_chunk_size(&mut self) -> Result<usize, std::io::Error> {
+        let mut line = String::new();
+        self.inner.read_line(&mut line)?;
+        let size = line.split(';').next().unwrap_or_default().trim();
+        usize::from_str_radix(size, 16).map_err(|_e| ErrorKind::InvalidData.into())
+    }
+
+    fn read_crlf(&mut self) -> Result<(), std::io::Error> {
+        let mut line = String::new();
+        self.inner.read_line(&mut line)?;
+        if !line.trim().is_empty() {
+            return Err(ErrorKind::InvalidData.into());
+        }
+        Ok(())
+    }
+}
+
+impl<T: BufRead> Read for ChunkedReader<T> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.finished || buf.is_empty() {
+            return Ok(0);
+        }
+        if self.remaining == 0 {
+            self.remaining = self.read_chunk_size()?;
+            if self.remaining == 0 {
+                self.finished = true;
+                // consume any trailers up to and including the terminating empty line
+                let mut line = String::new();
+                while self.inner.read_line(&mut line)? > 0 && !line.trim().is_empty() {
+                    line.clear();
+                }
+                return Ok(0);
+            }
+        }
+        let max = buf.len().min(self.remaining);
+        let read = self.inner.read(buf.get_mut(..max).unwrap_or_default())?;
+        if read == 0 {
+            return Err(ErrorKind::UnexpectedEof.into());
+        }
+        self.remaining -= read;
+        if self.remaining == 0 {
+            self.read_crlf()?;
+        }
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::http::ChunkedReader;
+    use std::io::Read;
+
+    #[test]
+    pub fn test_chunked() -> Result<(), std::io::Error> {
+        let input =
+            b"4\r\nWiki\r\n7;ext=1\r\npedia i\r\nB\r\nn \r\nchunks.\r\n0\r\nTrailer: x\r\n\r\n";
+        let mut out = String::new();
+        ChunkedReader::new(input.as_slice()).read_to_string(&mut out)?;
+        assert_eq!("Wikipedia in \r\nchunks.", out);
+
+        let mut out = Vec::new();
+        assert!(ChunkedReader::new(b"4\r\nWi".as_slice())
+            .read_to_end(&mut out)
+            .is_err());
+        Ok(())
+    }
+}
@@ -41,6 +41,20 @@ impl HttpRequest {
         &self.url
     }
 
+    pub fn set_version(&mut self, version: HttpVersion) {
+        self.version = version;
+    }
+    pub fn version(&self) -> &HttpVersion {
+        &self.version
+    }
+
+    pub fn headers(&self) -> &HttpHeaders {
+        &self.headers
+    }
+    pub fn headers_mut(&mut self) -> &mut HttpHeaders {
+        &mut self.headers
+    }
+
     pub fn write_to<T: Write>(mut self, mut out: &mut T) -> Result<(), std::io::Error> {
         if !self.headers.contains_header("Host") {
             let port = if let Some(port) = self.url.port {
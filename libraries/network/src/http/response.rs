@@ -3,7 +3,7 @@
 //
 
 use crate::http::headers::HttpHeaders;
-use crate::http::{HeaderResponse, HttpBody};
+use crate::http::{ChunkedReader, HeaderResponse, HttpBody, HttpCodes};
 use irox_tools::options::MaybeMap;
 use irox_tools::read::{ReadAny, ReadEmpty};
 use std::fmt::{Debug, Formatter};
@@ -19,31 +19,68 @@ pub struct HttpResponse {
 }
 
 impl HttpResponse {
+    ///
+    /// Reads the response status and headers.  A response without a `Content-Length` or chunked
+    /// encoding has an empty body.
     pub fn create_from<T: Read + 'static>(input: T) -> Result<HttpResponse, Error> {
+        Self::create(input, false)
+    }
+
+    ///
+    /// Like [`HttpResponse::create_from`], but a response without a `Content-Length` or chunked
+    /// encoding is a stream whose body is everything until the connection closes, as sent by
+    /// NTRIP casters and other streaming servers.
+    pub fn create_streaming_from<T: Read + 'static>(input: T) -> Result<HttpResponse, Error> {
+        Self::create(input, true)
+    }
+
+    fn create<T: Read + 'static>(input: T, until_close: bool) -> Result<HttpResponse, Error> {
         let mut bufread = BufReader::new(input);
         let mut line = String::new();
         let _read = bufread.read_line(&mut line)?;
-        let mut status = line.split(' ');
+        let mut status = line.trim_end().split(' ');
         let version = status.next().unwrap_or_default().to_string();
         let code = status.next().unwrap_or_default().to_string();
         let status = status.collect::<Vec<&str>>().join(" ");
 
+        if version == "ICY" {
+            // SHOUTcast & NTRIP v1 streams, the data immediately follows the status line.
+            return Ok(HttpResponse {
+                version,
+                code,
+                status,
+                headers: HttpHeaders::new_empty(),
+                body: HttpBody::Read(Box::new(bufread)),
+            });
+        }
+
         let HeaderResponse {
             headers,
             mut remaining,
         } = HttpHeaders::create_from(bufread)?;
 
+        let chunked = headers
+            .get_header("Transfer-Encoding")
+            .is_some_and(|v| v.eq_ignore_ascii_case("chunked"));
         let content_length = headers
             .get_header("Content-Length")
-            .maybe_map(|v| u64::from_str(v).ok())
-            .unwrap_or(0);
-        let body = if content_length == 0 {
+            .maybe_map(|v| u64::from_str(v).ok());
+        let body = if chunked {
+            HttpBody::Read(Box::new(ChunkedReader::new(BufReader::new(remaining))))
+        } else if let Some(content_length) = content_length {
+            if content_length == 0 {
+                HttpBody::Empty
+            } else if content_length < 1_000_000 {
+                let mut buf: Vec<u8> = Vec::with_capacity(1_000_000);
+                remaining.read_to_end(&mut buf)?;
+                HttpBody::Bytes(buf)
+            } else {
+                HttpBody::Read(Box::new(remaining))
+            }
+        } else if !until_close || code.starts_with('1') || code == "204" || code == "304" {
             HttpBody::Empty
-        } else if content_length < 1_000_000 {
-            let mut buf: Vec<u8> = Vec::with_capacity(1_000_000);
-            remaining.read_to_end(&mut buf)?;
-            HttpBody::Bytes(buf)
         } else {
+            // no declared length, the body is everything until the connection closes.
             HttpBody::Read(Box::new(remaining))
         };
 
@@ -55,6 +92,26 @@ impl HttpResponse {
             body,
         })
     }
+
+    /// The protocol version of the status line, like `HTTP/1.1`, or `ICY` for NTRIP v1
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+    /// The numeric status code, as sent
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+    /// The parsed status code
+    pub fn http_code(&self) -> Option<HttpCodes> {
+        HttpCodes::from_str(&self.code).ok()
+    }
+    /// The reason phrase of the status line, like `OK`
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+    pub fn headers(&self) -> &HttpHeaders {
+        &self.headers
+    }
     pub fn body_read(self) -> Box<dyn Read> {
         match self.body {
             HttpBody::Read(r) => r,
@@ -81,3 +138,58 @@ impl Debug for HttpResponse {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::http::HttpResponse;
+    use std::io::{Error, Read};
+
+    fn body(resp: HttpResponse) -> Result<String, Error> {
+        let mut out = String::new();
+        resp.body_read().read_to_string(&mut out)?;
+        Ok(out)
+    }
+
+    #[test]
+    pub fn test_content_length() -> Result<(), Error> {
+        let input = b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nhello";
+        let resp = HttpResponse::create_from(input.as_slice())?;
+        assert_eq!("HTTP/1.1", resp.version());
+        assert_eq!("200", resp.code());
+        assert_eq!("OK", resp.status());
+        assert_eq!("hello", body(resp)?);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_chunked() -> Result<(), Error> {
+        let input = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n0\r\n\r\n";
+        let resp = HttpResponse::create_from(input.as_slice())?;
+        assert_eq!("hello", body(resp)?);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_no_length() -> Result<(), Error> {
+        let input = b"HTTP/1.1 200 OK\r\nServer: irox\r\n\r\nhello";
+        let resp = HttpResponse::create_from(input.as_slice())?;
+        assert_eq!("", body(resp)?);
+
+        let resp = HttpResponse::create_streaming_from(input.as_slice())?;
+        assert_eq!("hello", body(resp)?);
+
+        let input = b"HTTP/1.1 204 No Content\r\n\r\nhello";
+        let resp = HttpResponse::create_streaming_from(input.as_slice())?;
+        assert_eq!("", body(resp)?);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_icy() -> Result<(), Error> {
+        let input = b"ICY 200 OK\r\nhello";
+        let resp = HttpResponse::create_from(input.as_slice())?;
+        assert_eq!("ICY", resp.version());
+        assert_eq!("hello", body(resp)?);
+        Ok(())
+    }
+}
@@ -17,12 +17,29 @@ pub type ConnectionWorker = Box<dyn Fn(&TcpStream)>;
 pub struct TCPConnectionManager {
     active_connections: Arc<Mutex<Vec<TcpStream>>>,
     running_thread: JoinHandle<()>,
+    local_addr: SocketAddr,
 }
 
 impl TCPConnectionManager {
     pub fn start<A: ToSocketAddrs + Debug>(
         addr: A,
         close: Arc<AtomicBool>,
+    ) -> Result<TCPConnectionManager, std::io::Error> {
+        Self::start_with_callback(addr, close, |_, _| true)
+    }
+
+    ///
+    /// Starts listening on the address, calling `on_connect` with each new client before it's
+    /// added to the active connections.  The callback can perform a handshake with the client,
+    /// returning `false` drops and closes the connection.  Each callback runs on its own thread,
+    /// so a slow client doesn't hold up accepting the others.
+    pub fn start_with_callback<
+        A: ToSocketAddrs + Debug,
+        F: Fn(&mut TcpStream, &SocketAddr) -> bool + Send + Sync + 'static,
+    >(
+        addr: A,
+        close: Arc<AtomicBool>,
+        on_connect: F,
     ) -> Result<TCPConnectionManager, std::io::Error> {
         let mut addr: Vec<SocketAddr> = match addr.to_socket_addrs() {
             Ok(a) => a.collect(),
@@ -44,12 +61,14 @@ impl TCPConnectionManager {
             }
         };
 
+        let local_addr = sock.local_addr()?;
         let active_connections = Arc::new(Mutex::new(Vec::new()));
 
         let conns = active_connections.clone();
+        let on_connect = Arc::new(on_connect);
         let handle = thread::spawn(move || {
             while !close.load(Ordering::Relaxed) {
-                let client = match sock.accept() {
+                let mut client = match sock.accept() {
                     Ok(c) => c,
                     Err(e) => {
                         error!("SocketAccept error: {e:?}");
@@ -57,20 +76,42 @@ impl TCPConnectionManager {
                     }
                 };
                 info!("New client connected: {}", client.1);
+                let conns = conns.clone();
+                let on_connect = on_connect.clone();
+                thread::spawn(move || {
+                    if !on_connect(&mut client.0, &client.1) {
+                        info!("Client rejected: {}", client.1);
+                        return;
+                    }
 
-                let Ok(ref mut conns) = conns.lock() else {
-                    continue;
-                };
-                conns.push(client.0);
+                    let Ok(ref mut conns) = conns.lock() else {
+                        return;
+                    };
+                    conns.push(client.0);
+                });
             }
         });
 
         Ok(TCPConnectionManager {
             active_connections,
             running_thread: handle,
+            local_addr,
         })
     }
 
+    /// The address the listener is bound to, useful when binding to port 0.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// The number of currently connected clients
+    pub fn num_connected(&self) -> usize {
+        self.active_connections
+            .lock()
+            .map(|c| c.len())
+            .unwrap_or_default()
+    }
+
     pub fn join(self) -> thread::Result<()> {
         self.running_thread.join()
     }
@@ -106,7 +106,7 @@ fn create_write_to_fn(n: &FieldsNamed, config: &Config, sizing: &mut StructSizin
                         method.add_punc('?');
                         method.add_punc(';');
                     }
-                    PrimitiveType::Array(input, len) => {
+                    PrimitiveType::Array(input, _len) => {
                         if config.strict_sizing && TYPES_STRICT_SIZING_INCOMPATIBLE.contains(&input)
                         {
                             return irox_derive_helpers::compile_error(
@@ -122,18 +122,16 @@ fn create_write_to_fn(n: &FieldsNamed, config: &Config, sizing: &mut StructSizin
                         method.add_ident(&ident.to_string());
                         method.wrap_braces({
                             let mut ts = TokenStream::new();
-                            for _ in 0..len {
-                                ts.add_ident("out");
-                                ts.add_punc('.');
-                                ts.add_ident(&get_endian_method_for_prim(
-                                    input,
-                                    false,
-                                    config.big_endian,
-                                ));
-                                ts.add_parens(TokenStream::create_ident("elem"));
-                                ts.add_punc('?');
-                                ts.add_punc(';');
-                            }
+                            ts.add_ident("out");
+                            ts.add_punc('.');
+                            ts.add_ident(&get_endian_method_for_prim(
+                                input,
+                                false,
+                                config.big_endian,
+                            ));
+                            ts.add_parens(TokenStream::create_ident("elem"));
+                            ts.add_punc('?');
+                            ts.add_punc(';');
                             ts
                         })
                     }
@@ -80,12 +80,19 @@ macro_rules! impl_blake2 {
         }
         impl<const NN: usize> $name<NN> {
             pub fn new(key: &[u8]) -> Self {
+                Self::new_truncated(key, NN)
+            }
+            ///
+            /// Creates a new hasher with a digest length of `outlen` bytes rather than `NN`.  The
+            /// digest is returned in the first `outlen` bytes of [`Self::finish`], the remaining
+            /// bytes should be discarded.  `outlen` should not exceed `NN`.
+            pub fn new_truncated(key: &[u8], outlen: usize) -> Self {
                 let mut out = Self {
                     h: *$iv,
                     written: 0,
                     buf: Default::default(),
                 };
-                let nn = (NN as $prim) & 0xFF;
+                let nn = (outlen as $prim) & 0xFF;
                 let kk = ((key.len() as $prim) & 0xFF) << 8;
                 let p = (0x01010000 as $prim) | nn | kk;
                 out.h[0].bitxor_assign(p);
@@ -134,7 +141,7 @@ macro_rules! impl_blake2 {
             }
             pub fn write(&mut self, mut v: &[u8]) {
                 let align = self.buf.rem_align();
-                if align < 4 && align < v.len() {
+                if align < $nb && align < v.len() {
                     let (a, b) = v.split_at(self.buf.rem_align());
                     v = b;
                     for val in a {
@@ -211,7 +218,7 @@ pub type BLAKE2b512 = BLAKE2b<64>;
 
 #[cfg(test)]
 mod tests {
-    use crate::hash::{BLAKE2b384, BLAKE2b512, BLAKE2s224, BLAKE2s256};
+    use crate::hash::{BLAKE2b, BLAKE2b256, BLAKE2b384, BLAKE2b512, BLAKE2s224, BLAKE2s256};
     use crate::hex;
 
     #[test]
@@ -248,4 +255,29 @@ mod tests {
         let exp = hex!("BA80A53F981C4D0D6A2797B69F12F6E94C212F14685AC4B74B12BB6FDBFFA2D17D87C5392AAB792DC252D5DE4533CC9518D38AA8DBF1925AB92386EDD4009923");
         assert_eq_hex_slice!(exp, h);
     }
+
+    #[test]
+    pub fn test_truncated() {
+        let exp = BLAKE2b256::default().hash(b"abc");
+        let h = BLAKE2b::<64>::new_truncated(&[], 32).hash(b"abc");
+        assert_eq_hex_slice!(exp, h[..32]);
+        let exp = hex!("384264f676f39536840523f284921cdc68b6846b");
+        let h = BLAKE2b::<64>::new_truncated(&[], 20).hash(b"abc");
+        assert_eq_hex_slice!(exp, h[..20]);
+    }
+
+    #[test]
+    pub fn test_unaligned_writes() {
+        let data: [u8; 300] = core::array::from_fn(|i| i as u8);
+        let exp = BLAKE2b512::default().hash(&data);
+        let mut h = BLAKE2b512::default();
+        for c in data.chunks(3) {
+            h.write(c);
+        }
+        assert_eq_hex_slice!(exp, h.finish());
+        let mut h = BLAKE2b512::default();
+        h.write(&data[..4]);
+        h.write(&data[4..]);
+        assert_eq_hex_slice!(exp, h.finish());
+    }
 }
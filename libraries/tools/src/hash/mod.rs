@@ -19,6 +19,10 @@ pub use md5::MD5;
 pub use murmur3::{murmur3_128, murmur3_128_seed};
 pub use sha1::SHA1;
 pub use sha2::{SHA224, SHA256, SHA384, SHA512};
+pub use sha3::{
+    Keccak224, Keccak256, Keccak384, Keccak512, SHA3_224, SHA3_256, SHA3_384, SHA3_512, SHAKE128,
+    SHAKE256,
+};
 
 mod blake2;
 pub mod bytewords;
@@ -28,6 +32,7 @@ pub mod murmur3;
 pub mod sha1;
 pub mod sha2;
 pub mod sha2wordlist;
+pub mod sha3;
 pub mod sixwords;
 pub mod viz;
 
@@ -51,6 +56,18 @@ pub type HMACSHA256 = HMAC<{ sha2::SHA256_BLOCK_SIZE }, { sha2::SHA256_OUTPUT_SI
 pub type HMACSHA384 = HMAC<{ sha2::SHA384_BLOCK_SIZE }, { sha2::SHA384_OUTPUT_SIZE }, sha2::SHA384>;
 /// HMAC using the SHA512 algorithm
 pub type HMACSHA512 = HMAC<{ sha2::SHA512_BLOCK_SIZE }, { sha2::SHA512_OUTPUT_SIZE }, sha2::SHA512>;
+/// HMAC using the SHA3-224 algorithm
+pub type HMACSHA3_224 =
+    HMAC<{ sha3::SHA3_224_BLOCK_SIZE }, { sha3::SHA3_224_OUTPUT_SIZE }, sha3::SHA3_224>;
+/// HMAC using the SHA3-256 algorithm
+pub type HMACSHA3_256 =
+    HMAC<{ sha3::SHA3_256_BLOCK_SIZE }, { sha3::SHA3_256_OUTPUT_SIZE }, sha3::SHA3_256>;
+/// HMAC using the SHA3-384 algorithm
+pub type HMACSHA3_384 =
+    HMAC<{ sha3::SHA3_384_BLOCK_SIZE }, { sha3::SHA3_384_OUTPUT_SIZE }, sha3::SHA3_384>;
+/// HMAC using the SHA3-512 algorithm
+pub type HMACSHA3_512 =
+    HMAC<{ sha3::SHA3_512_BLOCK_SIZE }, { sha3::SHA3_512_OUTPUT_SIZE }, sha3::SHA3_512>;
 /// HMAC using the BLAKE2s algorithm
 pub type HMACBLAKE2s = HMAC<64, 32, BLAKE2s256>;
 /// HMAC using the BLAKE2b algorithm
@@ -139,6 +156,16 @@ pub enum HashAlgorithm {
     BLAKE2b256,
     BLAKE2b384,
     BLAKE2b512,
+    SHA3_224,
+    SHA3_256,
+    SHA3_384,
+    SHA3_512,
+    SHAKE128,
+    SHAKE256,
+    Keccak224,
+    Keccak256,
+    Keccak384,
+    Keccak512,
 }
 impl Display for HashAlgorithm {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
@@ -175,6 +202,16 @@ impl TryFrom<&str> for HashAlgorithm {
             "blake2b160" => Self::BLAKE2b160,
             "blake2b224" => Self::BLAKE2b224,
             "blake2b384" => Self::BLAKE2b384,
+            "sha3_224" | "sha3-224" => Self::SHA3_224,
+            "sha3_256" | "sha3-256" | "sha3" => Self::SHA3_256,
+            "sha3_384" | "sha3-384" => Self::SHA3_384,
+            "sha3_512" | "sha3-512" => Self::SHA3_512,
+            "shake128" => Self::SHAKE128,
+            "shake256" => Self::SHAKE256,
+            "keccak224" | "keccak-224" => Self::Keccak224,
+            "keccak256" | "keccak-256" | "keccak" => Self::Keccak256,
+            "keccak384" | "keccak-384" => Self::Keccak384,
+            "keccak512" | "keccak-512" => Self::Keccak512,
             _ => return Err(MissingAlgError),
         })
     }
@@ -248,6 +285,16 @@ cfg_feature_alloc! {
         BLAKE2b224(BLAKE2b224),
         BLAKE2b256(BLAKE2b256),
         BLAKE2b384(BLAKE2b384),
+        SHA3_224(SHA3_224),
+        SHA3_256(SHA3_256),
+        SHA3_384(SHA3_384),
+        SHA3_512(SHA3_512),
+        SHAKE128(SHAKE128),
+        SHAKE256(SHAKE256),
+        Keccak224(Keccak224),
+        Keccak256(Keccak256),
+        Keccak384(Keccak384),
+        Keccak512(Keccak512),
     }
     macro_rules! impl_hash_from {
         ($value:ident, [$($hash:ident),+]) => {
@@ -275,7 +322,9 @@ cfg_feature_alloc! {
                 [
                     MD5, SHA1, SHA224, SHA256, SHA384, SHA512, Murmur3_128, Murmur3_32,
                     BLAKE2s128, BLAKE2s160, BLAKE2s224, BLAKE2s256,
-                    BLAKE2b160, BLAKE2b224, BLAKE2b256, BLAKE2b384, BLAKE2b512
+                    BLAKE2b160, BLAKE2b224, BLAKE2b256, BLAKE2b384, BLAKE2b512,
+                    SHA3_224, SHA3_256, SHA3_384, SHA3_512, SHAKE128, SHAKE256,
+                    Keccak224, Keccak256, Keccak384, Keccak512
                 ])
         }
     }
@@ -304,7 +353,9 @@ cfg_feature_alloc! {
                 [
                     MD5, SHA1, SHA224, SHA256, SHA384, SHA512, Murmur3_128, Murmur3_32,
                     BLAKE2s128, BLAKE2s160, BLAKE2s224, BLAKE2s256,
-                    BLAKE2b160, BLAKE2b224, BLAKE2b256, BLAKE2b384, BLAKE2b512
+                    BLAKE2b160, BLAKE2b224, BLAKE2b256, BLAKE2b384, BLAKE2b512,
+                    SHA3_224, SHA3_256, SHA3_384, SHA3_512, SHAKE128, SHAKE256,
+                    Keccak224, Keccak256, Keccak384, Keccak512
                 ])
         }
         pub fn finish(self) -> alloc::boxed::Box<[u8]> {
@@ -312,7 +363,9 @@ cfg_feature_alloc! {
                 [
                     MD5, SHA1, SHA224, SHA256, SHA384, SHA512, Murmur3_128, Murmur3_32,
                     BLAKE2s128, BLAKE2s160, BLAKE2s224, BLAKE2s256,
-                    BLAKE2b160, BLAKE2b224, BLAKE2b256, BLAKE2b384, BLAKE2b512
+                    BLAKE2b160, BLAKE2b224, BLAKE2b256, BLAKE2b384, BLAKE2b512,
+                    SHA3_224, SHA3_256, SHA3_384, SHA3_512, SHAKE128, SHAKE256,
+                    Keccak224, Keccak256, Keccak384, Keccak512
                 ])
         }
         crate::cfg_feature_std! {
@@ -352,7 +405,9 @@ cfg_feature_alloc! {
             [
                 MD5, SHA1, SHA224, SHA256, SHA384, SHA512, Murmur3_128, Murmur3_32,
                 BLAKE2s128, BLAKE2s160, BLAKE2s224, BLAKE2s256,
-                BLAKE2b160, BLAKE2b224, BLAKE2b256, BLAKE2b384, BLAKE2b512
+                BLAKE2b160, BLAKE2b224, BLAKE2b256, BLAKE2b384, BLAKE2b512,
+                SHA3_224, SHA3_256, SHA3_384, SHA3_512, SHAKE128, SHAKE256,
+                Keccak224, Keccak256, Keccak384, Keccak512
             ])
         }
     }
@@ -0,0 +1,500 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2026 IROX Contributors
+//
+
+//!
+//! Bog standard implementation of SHA-3 and the SHAKE extendable-output functions / FIPS 202.
+//!
+
+#![allow(clippy::indexing_slicing)]
+
+use crate::hash::{HashAlgorithm, HashDigest};
+use irox_bits::{Bits, Error, MutBits};
+
+/// Block size (bytes) for SHA3-224
+pub const SHA3_224_BLOCK_SIZE: usize = 144;
+/// Block size (bytes) for SHA3-256
+pub const SHA3_256_BLOCK_SIZE: usize = 136;
+/// Block size (bytes) for SHA3-384
+pub const SHA3_384_BLOCK_SIZE: usize = 104;
+/// Block size (bytes) for SHA3-512
+pub const SHA3_512_BLOCK_SIZE: usize = 72;
+/// Block size (bytes) for SHAKE128
+pub const SHAKE128_BLOCK_SIZE: usize = 168;
+/// Block size (bytes) for SHAKE256
+pub const SHAKE256_BLOCK_SIZE: usize = 136;
+/// Output size (bytes) for SHA3-224
+pub const SHA3_224_OUTPUT_SIZE: usize = 28;
+/// Output size (bytes) for SHA3-256
+pub const SHA3_256_OUTPUT_SIZE: usize = 32;
+/// Output size (bytes) for SHA3-384
+pub const SHA3_384_OUTPUT_SIZE: usize = 48;
+/// Output size (bytes) for SHA3-512
+pub const SHA3_512_OUTPUT_SIZE: usize = 64;
+/// Block size (bytes) for Keccak-224
+pub const KECCAK_224_BLOCK_SIZE: usize = SHA3_224_BLOCK_SIZE;
+/// Block size (bytes) for Keccak-256
+pub const KECCAK_256_BLOCK_SIZE: usize = SHA3_256_BLOCK_SIZE;
+/// Block size (bytes) for Keccak-384
+pub const KECCAK_384_BLOCK_SIZE: usize = SHA3_384_BLOCK_SIZE;
+/// Block size (bytes) for Keccak-512
+pub const KECCAK_512_BLOCK_SIZE: usize = SHA3_512_BLOCK_SIZE;
+/// Output size (bytes) for Keccak-224
+pub const KECCAK_224_OUTPUT_SIZE: usize = SHA3_224_OUTPUT_SIZE;
+/// Output size (bytes) for Keccak-256
+pub const KECCAK_256_OUTPUT_SIZE: usize = SHA3_256_OUTPUT_SIZE;
+/// Output size (bytes) for Keccak-384
+pub const KECCAK_384_OUTPUT_SIZE: usize = SHA3_384_OUTPUT_SIZE;
+/// Output size (bytes) for Keccak-512
+pub const KECCAK_512_OUTPUT_SIZE: usize = SHA3_512_OUTPUT_SIZE;
+/// Fixed output size (bytes) for SHAKE128, the full 128-bit security strength
+pub const SHAKE128_OUTPUT_SIZE: usize = 32;
+/// Fixed output size (bytes) for SHAKE256, the full 256-bit security strength
+pub const SHAKE256_OUTPUT_SIZE: usize = 64;
+
+/// Domain separation suffix (with the first padding bit) for the SHA3 hashes
+const SHA3_SUFFIX: u8 = 0x06;
+/// Domain separation suffix (with the first padding bit) for the SHAKE XOFs
+const SHAKE_SUFFIX: u8 = 0x1F;
+/// Original Keccak submission padding, no domain separation bits - just the first padding bit
+const KECCAK_SUFFIX: u8 = 0x01;
+
+static ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+/// Rho rotation offsets, indexed by lane `x + 5y`
+static RHO: [u32; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+/// Pi destination lane, indexed by source lane `x + 5y`
+static PI: [usize; 25] = [
+    0, 10, 20, 5, 15, 16, 1, 11, 21, 6, 7, 17, 2, 12, 22, 23, 8, 18, 3, 13, 14, 24, 9, 19, 4,
+];
+/// `(x + 1) mod 5`
+static NEXT: [usize; 5] = [1, 2, 3, 4, 0];
+/// `(x + 2) mod 5`
+static NEXT2: [usize; 5] = [2, 3, 4, 0, 1];
+/// `(x - 1) mod 5`
+static PREV: [usize; 5] = [4, 0, 1, 2, 3];
+
+/// The Keccak-f\[1600\] permutation
+fn keccak_f(a: &mut [u64; 25]) {
+    for rc in ROUND_CONSTANTS {
+        // theta
+        let mut c = [0u64; 5];
+        for (x, c) in c.iter_mut().enumerate() {
+            *c = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[PREV[x]] ^ c[NEXT[x]].rotate_left(1);
+            for y in [0, 5, 10, 15, 20] {
+                a[x + y] ^= d;
+            }
+        }
+        // rho & pi
+        let mut b = [0u64; 25];
+        for (idx, v) in a.iter().enumerate() {
+            b[PI[idx]] = v.rotate_left(RHO[idx]);
+        }
+        // chi
+        for y in [0, 5, 10, 15, 20] {
+            for x in 0..5 {
+                a[x + y] = b[x + y] ^ (!b[NEXT[x] + y] & b[NEXT2[x] + y]);
+            }
+        }
+        // iota
+        a[0] ^= rc;
+    }
+}
+
+/// The absorbing half of the sponge construction
+#[derive(Clone)]
+struct Sponge<const RATE: usize> {
+    state: [u64; 25],
+    buf: [u8; RATE],
+    used: usize,
+}
+impl<const RATE: usize> Default for Sponge<RATE> {
+    fn default() -> Self {
+        Self {
+            state: [0; 25],
+            buf: [0; RATE],
+            used: 0,
+        }
+    }
+}
+impl<const RATE: usize> Sponge<RATE> {
+    fn absorb(&mut self) {
+        for (s, c) in self.state.iter_mut().zip(self.buf.chunks_exact(8)) {
+            *s ^= u64::from_le_bytes(c.try_into().unwrap_or_default());
+        }
+        keccak_f(&mut self.state);
+        self.used = 0;
+    }
+
+    fn write(&mut self, mut bytes: &[u8]) {
+        while !bytes.is_empty() {
+            let avail = RATE - self.used;
+            let (a, b) = bytes.split_at(avail.min(bytes.len()));
+            self.buf[self.used..self.used + a.len()].copy_from_slice(a);
+            self.used += a.len();
+            bytes = b;
+            if self.used == RATE {
+                self.absorb();
+            }
+        }
+    }
+
+    /// Pads the final block with the domain suffix and switches to squeezing
+    fn finish(mut self, suffix: u8) -> XOFReader<RATE> {
+        self.buf[self.used..].fill(0);
+        self.buf[self.used] ^= suffix;
+        self.buf[RATE - 1] ^= 0x80;
+        self.absorb();
+        let mut out = XOFReader {
+            state: self.state,
+            buf: [0; RATE],
+            used: 0,
+        };
+        out.extract();
+        out
+    }
+}
+
+///
+/// The squeezing half of the sponge construction, returns an unlimited amount of output from a
+/// finished SHAKE.
+#[derive(Clone)]
+pub struct XOFReader<const RATE: usize> {
+    state: [u64; 25],
+    buf: [u8; RATE],
+    used: usize,
+}
+impl<const RATE: usize> XOFReader<RATE> {
+    fn extract(&mut self) {
+        for (s, c) in self.state.iter().zip(self.buf.chunks_exact_mut(8)) {
+            c.copy_from_slice(&s.to_le_bytes());
+        }
+        self.used = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of output
+    pub fn read(&mut self, mut out: &mut [u8]) {
+        while !out.is_empty() {
+            if self.used == RATE {
+                keccak_f(&mut self.state);
+                self.extract();
+            }
+            let avail = RATE - self.used;
+            let (a, b) = out.split_at_mut(avail.min(out.len()));
+            a.copy_from_slice(&self.buf[self.used..self.used + a.len()]);
+            self.used += a.len();
+            out = b;
+        }
+    }
+
+    /// Returns the next `N` bytes of output
+    pub fn read_array<const N: usize>(&mut self) -> [u8; N] {
+        let mut out = [0u8; N];
+        self.read(&mut out);
+        out
+    }
+}
+impl<const RATE: usize> Bits for XOFReader<RATE> {
+    fn next_u8(&mut self) -> Result<Option<u8>, Error> {
+        let mut out = [0u8];
+        self.read(&mut out);
+        Ok(Some(out[0]))
+    }
+}
+
+macro_rules! sha3_impl {
+    ($name:ident, $block_size:expr, $output_size:expr, $suffix:expr, $doc:literal) => {
+        /// Standard implementation of
+        #[doc=stringify!($name)]
+        #[doc=$doc]
+        #[derive(Clone, Default)]
+        pub struct $name {
+            sponge: Sponge<{ $block_size }>,
+        }
+        impl $name {
+            /// Creates a new empty instance of this algorithm
+            pub fn new() -> Self {
+                Self::default()
+            }
+            /// Writes all the data in the slice to this algorithm
+            pub fn write(&mut self, bytes: &[u8]) {
+                self.sponge.write(bytes)
+            }
+            /// Finishes this algorithm and returns the result
+            pub fn finish(self) -> [u8; $output_size] {
+                self.sponge.finish($suffix).read_array()
+            }
+            /// One shot - hashes the data and returns the result.
+            pub fn hash(mut self, bytes: &[u8]) -> [u8; $output_size] {
+                self.write(bytes);
+                self.finish()
+            }
+        }
+        impl HashDigest<{ $block_size }, { $output_size }> for $name {
+            fn write(&mut self, bytes: &[u8]) {
+                $name::write(self, bytes)
+            }
+
+            fn hash(self, bytes: &[u8]) -> [u8; $output_size] {
+                $name::hash(self, bytes)
+            }
+
+            fn finish(self) -> [u8; $output_size] {
+                $name::finish(self)
+            }
+            fn algorithm() -> HashAlgorithm {
+                HashAlgorithm::$name
+            }
+        }
+        impl MutBits for $name {
+            fn write_u8(&mut self, val: u8) -> Result<(), Error> {
+                self.write(&[val]);
+                Ok(())
+            }
+        }
+    };
+}
+
+macro_rules! shake_impl {
+    ($name:ident, $block_size:expr, $output_size:expr) => {
+        /// Standard implementation of the
+        #[doc=stringify!($name)]
+        /// extendable-output function following FIPS 202.  [`Self::finish`] returns a fixed-size
+        /// output for use as a regular hash, [`Self::finish_xof`] returns a reader for any
+        /// amount of output.
+        #[derive(Clone, Default)]
+        pub struct $name {
+            sponge: Sponge<{ $block_size }>,
+        }
+        impl $name {
+            /// Creates a new empty instance of this algorithm
+            pub fn new() -> Self {
+                Self::default()
+            }
+            /// Writes all the data in the slice to this algorithm
+            pub fn write(&mut self, bytes: &[u8]) {
+                self.sponge.write(bytes)
+            }
+            /// Finishes this algorithm and returns the first
+            #[doc=stringify!($output_size)]
+            /// bytes of output
+            pub fn finish(self) -> [u8; $output_size] {
+                self.finish_xof().read_array()
+            }
+            /// Finishes this algorithm and fills the provided buffer with output
+            pub fn finish_into(self, out: &mut [u8]) {
+                self.finish_xof().read(out)
+            }
+            /// Finishes this algorithm and returns a reader for an unlimited amount of output
+            pub fn finish_xof(self) -> XOFReader<{ $block_size }> {
+                self.sponge.finish(SHAKE_SUFFIX)
+            }
+            /// One shot - hashes the data and returns the result.
+            pub fn hash(mut self, bytes: &[u8]) -> [u8; $output_size] {
+                self.write(bytes);
+                self.finish()
+            }
+        }
+        impl HashDigest<{ $block_size }, { $output_size }> for $name {
+            fn write(&mut self, bytes: &[u8]) {
+                $name::write(self, bytes)
+            }
+
+            fn hash(self, bytes: &[u8]) -> [u8; $output_size] {
+                $name::hash(self, bytes)
+            }
+
+            fn finish(self) -> [u8; $output_size] {
+                $name::finish(self)
+            }
+            fn algorithm() -> HashAlgorithm {
+                HashAlgorithm::$name
+            }
+        }
+        impl MutBits for $name {
+            fn write_u8(&mut self, val: u8) -> Result<(), Error> {
+                self.write(&[val]);
+                Ok(())
+            }
+        }
+    };
+}
+
+sha3_impl!(
+    SHA3_224,
+    SHA3_224_BLOCK_SIZE,
+    SHA3_224_OUTPUT_SIZE,
+    SHA3_SUFFIX,
+    "following FIPS 202"
+);
+sha3_impl!(
+    SHA3_256,
+    SHA3_256_BLOCK_SIZE,
+    SHA3_256_OUTPUT_SIZE,
+    SHA3_SUFFIX,
+    "following FIPS 202"
+);
+sha3_impl!(
+    SHA3_384,
+    SHA3_384_BLOCK_SIZE,
+    SHA3_384_OUTPUT_SIZE,
+    SHA3_SUFFIX,
+    "following FIPS 202"
+);
+sha3_impl!(
+    SHA3_512,
+    SHA3_512_BLOCK_SIZE,
+    SHA3_512_OUTPUT_SIZE,
+    SHA3_SUFFIX,
+    "following FIPS 202"
+);
+sha3_impl!(
+    Keccak224,
+    KECCAK_224_BLOCK_SIZE,
+    KECCAK_224_OUTPUT_SIZE,
+    KECCAK_SUFFIX,
+    "with the original Keccak padding, as used by Ethereum (not FIPS 202)"
+);
+sha3_impl!(
+    Keccak256,
+    KECCAK_256_BLOCK_SIZE,
+    KECCAK_256_OUTPUT_SIZE,
+    KECCAK_SUFFIX,
+    "with the original Keccak padding, as used by Ethereum (not FIPS 202)"
+);
+sha3_impl!(
+    Keccak384,
+    KECCAK_384_BLOCK_SIZE,
+    KECCAK_384_OUTPUT_SIZE,
+    KECCAK_SUFFIX,
+    "with the original Keccak padding, as used by Ethereum (not FIPS 202)"
+);
+sha3_impl!(
+    Keccak512,
+    KECCAK_512_BLOCK_SIZE,
+    KECCAK_512_OUTPUT_SIZE,
+    KECCAK_SUFFIX,
+    "with the original Keccak padding, as used by Ethereum (not FIPS 202)"
+);
+shake_impl!(SHAKE128, SHAKE128_BLOCK_SIZE, SHAKE128_OUTPUT_SIZE);
+shake_impl!(SHAKE256, SHAKE256_BLOCK_SIZE, SHAKE256_OUTPUT_SIZE);
+
+#[cfg(test)]
+mod tests {
+    use crate::hash::{
+        Keccak224, Keccak256, Keccak384, Keccak512, SHA3_224, SHA3_256, SHA3_384, SHA3_512,
+        SHAKE128, SHAKE256,
+    };
+    use crate::hex;
+
+    #[test]
+    pub fn test_empty() {
+        let exp = hex!("6b4e03423667dbb73b6e15454f0eb1abd4597f9a1b078e3f5b5a6bc7");
+        assert_eq_hex_slice!(exp, SHA3_224::new().finish());
+        let exp = hex!("a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a");
+        assert_eq_hex_slice!(exp, SHA3_256::new().finish());
+        let exp = hex!(
+            "0c63a75b845e4f7d01107d852e4c2485c51a50aaaa94fc61995e71bbee983a2a"
+            "c3713831264adb47fb6bd1e058d5f004"
+        );
+        assert_eq_hex_slice!(exp, SHA3_384::new().finish());
+        let exp = hex!(
+            "a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a6"
+            "15b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26"
+        );
+        assert_eq_hex_slice!(exp, SHA3_512::new().finish());
+        let exp = hex!("7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26");
+        assert_eq_hex_slice!(exp, SHAKE128::new().finish());
+        let exp = hex!(
+            "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f"
+            "d75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be"
+        );
+        assert_eq_hex_slice!(exp, SHAKE256::new().finish());
+    }
+
+    #[test]
+    pub fn test_abc() {
+        let exp = hex!("3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532");
+        assert_eq_hex_slice!(exp, SHA3_256::new().hash(b"abc"));
+        let exp = hex!(
+            "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e"
+            "10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0"
+        );
+        assert_eq_hex_slice!(exp, SHA3_512::new().hash(b"abc"));
+    }
+
+    #[test]
+    pub fn test_keccak() {
+        let exp = hex!("f71837502ba8e10837bdd8d365adb85591895602fc552b48b7390abd");
+        assert_eq_hex_slice!(exp, Keccak224::new().finish());
+        let exp = hex!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+        assert_eq_hex_slice!(exp, Keccak256::new().finish());
+        let exp = hex!(
+            "2c23146a63a29acf99e73b88f8c24eaa7dc60aa771780ccc006afbfa8fe2479b"
+            "2dd2b21362337441ac12b515911957ff"
+        );
+        assert_eq_hex_slice!(exp, Keccak384::new().finish());
+        let exp = hex!(
+            "0eab42de4c3ceb9235fc91acffe746b29c29a8c366b7c60e4e67c466f36a4304"
+            "c00fa9caf9d87976ba469bcbe06713b435f091ef2769fb160cdab33d3670680e"
+        );
+        assert_eq_hex_slice!(exp, Keccak512::new().finish());
+        let exp = hex!("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45");
+        assert_eq_hex_slice!(exp, Keccak256::new().hash(b"abc"));
+    }
+
+    #[test]
+    pub fn test_split_writes() {
+        let data: [u8; 500] = core::array::from_fn(|i| i as u8);
+        let exp = SHA3_256::new().hash(&data);
+        for step in [1, 7, 135, 136, 137] {
+            let mut h = SHA3_256::new();
+            for c in data.chunks(step) {
+                h.write(c);
+            }
+            assert_eq_hex_slice!(exp, h.finish());
+        }
+    }
+
+    #[test]
+    pub fn test_xof_reads() {
+        let mut exp = [0u8; 500];
+        SHAKE128::new().finish_into(&mut exp);
+        assert_eq_hex_slice!(exp[..32], SHAKE128::new().finish());
+        let mut xof = SHAKE128::new().finish_xof();
+        let mut out = [0u8; 500];
+        for c in out.chunks_mut(13) {
+            xof.read(c);
+        }
+        assert_eq_hex_slice!(exp, out);
+    }
+}
@@ -23,6 +23,18 @@ impl<const M: usize, const N: usize, T: Sized + Copy + Default> Matrix<M, N, T>
         Matrix { values }
     }
 
+    /// Returns the `N x M` transpose of this `M x N` matrix
+    #[must_use]
+    pub fn transposed(&self) -> Matrix<N, M, T> {
+        let mut values = [[T::default(); M]; N];
+        for (i, row) in self.values.iter().enumerate() {
+            for (j, val) in row.iter().enumerate() {
+                values[j][i] = *val;
+            }
+        }
+        Matrix { values }
+    }
+
     /// Returns only a subpart of the matrix
     #[must_use]
     pub fn submatrix(&self, rmin: usize, rmax: usize, cmin: usize, cmax: usize) -> Matrix<M, N, T> {
@@ -150,6 +162,44 @@ macro_rules! impl_square {
             }
         }
         impl Matrix<$N, $N, f64> {
+            ///
+            /// Returns the inverse of the matrix using Gauss-Jordan elimination with partial
+            /// pivoting, or `None` if the matrix is singular.
+            #[must_use]
+            pub fn inverse(&self) -> Option<Self> {
+                let mut a = *self;
+                let mut inv = Self::identity();
+                for k in 0..$N {
+                    let mut pivot = k;
+                    for i in (k + 1)..$N {
+                        if a[i][k].abs() > a[pivot][k].abs() {
+                            pivot = i;
+                        }
+                    }
+                    if a[pivot][k].abs() < f64::EPSILON {
+                        return None;
+                    }
+                    a.swap_rows(k, pivot);
+                    inv.swap_rows(k, pivot);
+                    let div = a[k][k];
+                    for j in 0..$N {
+                        a[k][j] /= div;
+                        inv[k][j] /= div;
+                    }
+                    for i in 0..$N {
+                        if i == k {
+                            continue;
+                        }
+                        let factor = a[i][k];
+                        for j in 0..$N {
+                            a[i][j] -= factor * a[k][j];
+                            inv[i][j] -= factor * inv[k][j];
+                        }
+                    }
+                }
+                Some(inv)
+            }
+
             pub fn lup_decompose(&self) -> LUPDecomposition<$N, $N, f64> {
                 let mut l = Self::identity();
                 let mut u = self.clone();
@@ -589,6 +639,29 @@ mod test {
         assert_eq!(res, Matrix::new([[8, 0], [2, -18]]));
     }
 
+    #[test]
+    pub fn test_transposed() {
+        let m1 = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(m1.transposed(), Matrix::new([[1, 4], [2, 5], [3, 6]]));
+    }
+
+    #[test]
+    pub fn test_inverse() {
+        let a = [[2., 1., -5.], [4., 4., -4.], [1., 3., 1.]].as_matrix();
+        let Some(inv) = a.inverse() else {
+            panic!("Expected an inverse");
+        };
+        let ident = a * inv;
+        for (i, row) in ident.iter().enumerate() {
+            for (j, val) in row.iter().enumerate() {
+                let expected = if i == j { 1. } else { 0. };
+                assert!((val - expected).abs() < 1e-12, "{ident:?}");
+            }
+        }
+        let singular = [[1., 2.], [2., 4.]].as_matrix();
+        assert_eq!(None, singular.inverse());
+    }
+
     #[test]
     pub fn test_product() {
         let m1 = Matrix::new([[1, 2, 3], [4, 5, 6]]);
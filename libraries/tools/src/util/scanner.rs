@@ -164,11 +164,13 @@ impl<'a, T: Clone> TokenWorkingMem<'a, T> {
 
         if let Some(first) = ret {
             self.offset += 1;
+            // an escaped character is never an escape or a quote itself.
+            let escaped = self.found_escape;
             if let Some(esc) = self.token.escape_char {
-                self.found_escape = first == esc;
+                self.found_escape = first == esc && !escaped;
             }
 
-            if let Some(quoted) = self.token.quote_char {
+            if let (Some(quoted), false) = (self.token.quote_char, escaped) {
                 //
                 // the following logic is a little bit complex, because
                 // SingleOrDoubleQuotes can match either ' or ", but we don't
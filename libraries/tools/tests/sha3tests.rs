@@ -0,0 +1,245 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2026 IROX Contributors
+//
+
+use irox_tools::assert_eq_hex_slice;
+use irox_tools::hash::{
+    HMACSHA3_224, HMACSHA3_256, HMACSHA3_384, HMACSHA3_512, SHA3_224, SHA3_256, SHA3_384, SHA3_512,
+    SHAKE128, SHAKE256,
+};
+
+/// The 1600-bit message from the FIPS 202 examples, `0xA3` repeated
+const A3_1600: [u8; 200] = [0xA3; 200];
+/// The 448-bit message from the FIPS 180 & 202 examples
+const MSG_448: &[u8] = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+/// The 896-bit message from the FIPS 180 & 202 examples
+const MSG_896: &[u8] = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmn\
+    hijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+
+macro_rules! impl_test {
+    ($name:ident, $test:ty, $($msg:expr => [$($digest:literal)+],)+) => {
+        #[test]
+        pub fn $name() {
+            $(
+                let exp = irox_tools::hex!($($digest)+);
+                assert_eq_hex_slice!(exp, <$test>::new().hash($msg));
+            )+
+        }
+    };
+}
+
+macro_rules! impl_xof_test {
+    ($name:ident, $test:ty, $($msg:expr => [$($digest:literal)+],)+) => {
+        #[test]
+        pub fn $name() {
+            $(
+                let exp = irox_tools::hex!($($digest)+);
+                let mut hash = <$test>::new();
+                hash.write($msg);
+                let mut res = [0u8; 512];
+                hash.finish_into(&mut res);
+                assert_eq_hex_slice!(exp, res);
+            )+
+        }
+    };
+}
+
+impl_test!(
+    test_sha3_224,
+    SHA3_224,
+    &A3_1600 => ["9376816aba503f72f96ce7eb65ac095deee3be4bf9bbc2a1cb7e11e0"],
+    MSG_448 => ["8a24108b154ada21c9fd5574494479ba5c7e7ab76ef264ead0fcce33"],
+    MSG_896 => ["543e6868e1666c1a643630df77367ae5a62a85070a51c14cbf665cbc"],
+);
+impl_test!(
+    test_sha3_256,
+    SHA3_256,
+    &A3_1600 => ["79f38adec5c20307a98ef76e8324afbfd46cfd81b22e3973c65fa1bd9de31787"],
+    MSG_448 => ["41c0dba2a9d6240849100376a8235e2c82e1b9998a999e21db32dd97496d3376"],
+    MSG_896 => ["916f6061fe879741ca6469b43971dfdb28b1a32dc36cb3254e812be27aad1d18"],
+);
+impl_test!(
+    test_sha3_384,
+    SHA3_384,
+    &A3_1600 => [
+        "1881de2ca7e41ef95dc4732b8f5f002b189cc1e42b74168ed1732649ce1dbcdd"
+        "76197a31fd55ee989f2d7050dd473e8f"
+    ],
+    MSG_448 => [
+        "991c665755eb3a4b6bbdfb75c78a492e8c56a22c5c4d7e429bfdbc32b9d4ad5a"
+        "a04a1f076e62fea19eef51acd0657c22"
+    ],
+    MSG_896 => [
+        "79407d3b5916b59c3e30b09822974791c313fb9ecc849e406f23592d04f625dc"
+        "8c709b98b43b3852b337216179aa7fc7"
+    ],
+);
+impl_test!(
+    test_sha3_512,
+    SHA3_512,
+    &A3_1600 => [
+        "e76dfad22084a8b1467fcf2ffa58361bec7628edf5f3fdc0e4805dc48caeeca8"
+        "1b7c13c30adf52a3659584739a2df46be589c51ca1a4a8416df6545a1ce8ba00"
+    ],
+    MSG_448 => [
+        "04a371e84ecfb5b8b77cb48610fca8182dd457ce6f326a0fd3d7ec2f1e91636d"
+        "ee691fbe0c985302ba1b0d8dc78c086346b533b49c030d99a27daf1139d6e75e"
+    ],
+    MSG_896 => [
+        "afebb2ef542e6579c50cad06d2e578f9f8dd6881d7dc824d26360feebf18a4fa"
+        "73e3261122948efcfd492e74e82e2189ed0fb440d187f382270cb455f21dd185"
+    ],
+);
+impl_test!(
+    test_shake128,
+    SHAKE128,
+    &A3_1600 => ["131ab8d2b594946b9c81333f9bb6e0ce75c3b93104fa3469d3917457385da037"],
+    MSG_448 => ["1a96182b50fb8c7e74e0a707788f55e98209b8d91fade8f32f8dd5cff7bf21f5"],
+    MSG_896 => ["7b6df6ff181173b6d7898d7ff63fb07b7c237daf471a5ae5602adbccef9ccf4b"],
+);
+impl_test!(
+    test_shake256,
+    SHAKE256,
+    &A3_1600 => [
+        "cd8a920ed141aa0407a22d59288652e9d9f1a7ee0c1e7c1ca699424da84a904d"
+        "2d700caae7396ece96604440577da4f3aa22aeb8857f961c4cd8e06f0ae6610b"
+    ],
+    MSG_448 => [
+        "4d8c2dd2435a0128eefbb8c36f6f87133a7911e18d979ee1ae6be5d4fd2e3329"
+        "40d8688a4e6a59aa8060f1f9bc996c05aca3c696a8b66279dc672c740bb224ec"
+    ],
+    MSG_896 => [
+        "98be04516c04cc73593fef3ed0352ea9f6443942d6950e29a372a681c3deaf45"
+        "35423709b02843948684e029010badcc0acd8303fc85fdad3eabf4f78cae1656"
+    ],
+);
+impl_xof_test!(
+    test_shake128_4096,
+    SHAKE128,
+    &A3_1600 => [
+        "131ab8d2b594946b9c81333f9bb6e0ce75c3b93104fa3469d3917457385da037"
+        "cf232ef7164a6d1eb448c8908186ad852d3f85a5cf28da1ab6fe343817197846"
+        "7f1c05d58c7ef38c284c41f6c2221a76f12ab1c04082660250802294fb871802"
+        "13fdef5b0ecb7df50ca1f8555be14d32e10f6edcde892c09424b29f597afc270"
+        "c904556bfcb47a7d40778d390923642b3cbd0579e60908d5a000c1d08b98ef93"
+        "3f806445bf87f8b009ba9e94f7266122ed7ac24e5e266c42a82fa1bbefb7b8db"
+        "0066e16a85e0493f07df4809aec084a593748ac3dde5a6d7aae1e8b6e5352b2d"
+        "71efbb47d4caeed5e6d633805d2d323e6fd81b4684b93a2677d45e7421c2c6ae"
+        "a259b855a698fd7d13477a1fe53e5a4a6197dbec5ce95f505b520bcd9570c4a8"
+        "265a7e01f89c0c002c59bfec6cd4a5c109258953ee5ee70cd577ee217af21fa7"
+        "0178f0946c9bf6ca8751793479f6b537737e40b6ed28511d8a2d7e73eb75f8da"
+        "ac912ff906e0ab955b083bac45a8e5e9b744c8506f37e9b4e749a184b30f43eb"
+        "188d855f1b70d71ff3e50c537ac1b0f8974f0fe1a6ad295ba42f6aec74d123a7"
+        "abedde6e2c0711cab36be5acb1a5a11a4b1db08ba6982efccd716929a7741cfc"
+        "63aa4435e0b69a9063e880795c3dc5ef3272e11c497a91acf699fefee206227a"
+        "44c9fb359fd56ac0a9a75a743cff6862f17d7259ab075216c0699511643b6439"
+    ],
+);
+impl_xof_test!(
+    test_shake256_4096,
+    SHAKE256,
+    &A3_1600 => [
+        "cd8a920ed141aa0407a22d59288652e9d9f1a7ee0c1e7c1ca699424da84a904d"
+        "2d700caae7396ece96604440577da4f3aa22aeb8857f961c4cd8e06f0ae6610b"
+        "1048a7f64e1074cd629e85ad7566048efc4fb500b486a3309a8f26724c0ed628"
+        "001a1099422468de726f1061d99eb9e93604d5aa7467d4b1bd6484582a384317"
+        "d7f47d750b8f5499512bb85a226c4243556e696f6bd072c5aa2d9b69730244b5"
+        "6853d16970ad817e213e470618178001c9fb56c54fefa5fee67d2da524bb3b0b"
+        "61ef0e9114a92cdbb6cccb98615cfe76e3510dd88d1cc28ff99287512f24bfaf"
+        "a1a76877b6f37198e3a641c68a7c42d45fa7acc10dae5f3cefb7b735f12d4e58"
+        "9f7a456e78c0f5e4c4471fffa5e4fa0514ae974d8c2648513b5db494cea84715"
+        "6d277ad0e141c24c7839064cd08851bc2e7ca109fd4e251c35bb0a04fb05b364"
+        "ff8c4d8b59bc303e25328c09a882e952518e1a8ae0ff265d61c465896973d749"
+        "0499dc639fb8502b39456791b1b6ec5bcc5d9ac36a6df622a070d43fed781f5f"
+        "149f7b62675e7d1a4d6dec48c1c7164586eae06a51208c0b791244d307726505"
+        "c3ad4b26b6822377257aa152037560a739714a3ca79bd605547c9b78dd1f596f"
+        "2d4f1791bc689a0e9b799a37339c04275733740143ef5d2b58b96a363d4e0807"
+        "6a1a9d7846436e4dca5728b6f760eef0ca92bf0be5615e96959d767197a0beeb"
+    ],
+);
+
+fn key(len: usize) -> Vec<u8> {
+    (0..len).map(|v| v as u8).collect()
+}
+
+#[test]
+pub fn test_hmac_sha3_224() {
+    assert_eq_hex_slice!(
+        irox_tools::hex!("332cfd59347fdb8e576e77260be4aba2d6dc53117b3bfb52c6d18c04"),
+        HMACSHA3_224::new(&key(28)).hash(b"Sample message for keylen<blocklen")
+    );
+    assert_eq_hex_slice!(
+        irox_tools::hex!("d8b733bcf66c644a12323d564e24dcf3fc75f231f3b67968359100c7"),
+        HMACSHA3_224::new(&key(144)).hash(b"Sample message for keylen=blocklen")
+    );
+    assert_eq_hex_slice!(
+        irox_tools::hex!("078695eecc227c636ad31d063a15dd05a7e819a66ec6d8de1e193e59"),
+        HMACSHA3_224::new(&key(172)).hash(b"Sample message for keylen>blocklen")
+    );
+}
+
+#[test]
+pub fn test_hmac_sha3_256() {
+    assert_eq_hex_slice!(
+        irox_tools::hex!("186b8ee333bd586f944b192934e20d5a7fc8de45bcdde7db392f55c98fdbf2b9"),
+        HMACSHA3_256::new(&key(28)).hash(b"Sample message for keylen<blocklen")
+    );
+    assert_eq_hex_slice!(
+        irox_tools::hex!("68b94e2e538a9be4103bebb5aa016d47961d4d1aa906061313b557f8af2c3faa"),
+        HMACSHA3_256::new(&key(136)).hash(b"Sample message for keylen=blocklen")
+    );
+    assert_eq_hex_slice!(
+        irox_tools::hex!("480d0302a270311925dd43b75f257b81d1df6bfdb648e2ec5c61f148986a5386"),
+        HMACSHA3_256::new(&key(164)).hash(b"Sample message for keylen>blocklen")
+    );
+}
+
+#[test]
+pub fn test_hmac_sha3_384() {
+    assert_eq_hex_slice!(
+        irox_tools::hex!(
+            "0774db636974fb40b9e1730965379e41de323412cc65723b"
+            "4261c9c8f8e5d1a55b4c00e0235a9ad7115e52210572c209"
+        ),
+        HMACSHA3_384::new(&key(28)).hash(b"Sample message for keylen<blocklen")
+    );
+    assert_eq_hex_slice!(
+        irox_tools::hex!(
+            "a27d24b592e8c8cbf6d4ce6fc5bf62d8fc98bf2d486640d9"
+            "eb8099e24047837f5f3bffbe92dcce90b4ed5b1e7e44fa90"
+        ),
+        HMACSHA3_384::new(&key(104)).hash(b"Sample message for keylen=blocklen")
+    );
+    assert_eq_hex_slice!(
+        irox_tools::hex!(
+            "a0c92873ea6ecd9d4326530bd6f55cbae0dc9ff692cf919d"
+            "0edd79e04fd4faa56cc428695c3e2c50e74372d22b5e0694"
+        ),
+        HMACSHA3_384::new(&key(132)).hash(b"Sample message for keylen>blocklen")
+    );
+}
+
+#[test]
+pub fn test_hmac_sha3_512() {
+    assert_eq_hex_slice!(
+        irox_tools::hex!(
+            "f8716384ec3666a01ebc21ea9eea837d0da162e2df4bf1fbb66dadc6be938b95"
+            "a8c6b1e08445aff3a477a60cfecc474d67a4bf9c793e5ae38f9d5a17e625b9fa"
+        ),
+        HMACSHA3_512::new(&key(28)).hash(b"Sample message for keylen<blocklen")
+    );
+    assert_eq_hex_slice!(
+        irox_tools::hex!(
+            "544e257ea2a3e5ea19a590e6a24b724ce6327757723fe2751b75bf007d80f6b3"
+            "60744bf1b7a88ea585f9765b47911976d3191cf83c039f5ffab0d29cc9d9b6da"
+        ),
+        HMACSHA3_512::new(&key(72)).hash(b"Sample message for keylen=blocklen")
+    );
+    assert_eq_hex_slice!(
+        irox_tools::hex!(
+            "b3ac5a87db1ddec68c8325a9096e6967d49b9b5d8f78f7a533174695c1ef4d46"
+            "00f74d29f71ea49671f3c949b6de101199451095d529fc8c9f157cc7d374e4c7"
+        ),
+        HMACSHA3_512::new(&key(100)).hash(b"Sample message for keylen>blocklen")
+    );
+}
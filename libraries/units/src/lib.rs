@@ -15,13 +15,21 @@
 //!     * [`shapes::elliptical`] - `Ellipse` struct, describes an ellipse using two `CircularDimension` axes and an optional
 //!       `CompassDirection` orientation of the first axis
 //! * [`units`] - Physical Quantities
+//!   * [`units::acceleration`] - The SI `Acceleration` quantity, representing `MetersPerSecondSquared`, `StandardGravity`, etc
 //!   * [`units::angle`] -  Angle Types, `Angle`, `AngleUnits` for `Degrees` and `Radians`
+//!   * [`units::angular_rate`] - The `AngularRate` quantity, representing `RadiansPerSecond`, `DegreesPerSecond`, etc
+//!   * [`units::area`] - The SI `Area` quantity, representing `SquareMeters`, `Acres`, etc
 //!   * [`units::compass`] - Compass Types, `Compass`, and the absolute types: `Heading`, `Track`, `Bearing`, `Course`,
 //!     `Azimuth`, `CompassOffest`, and the relative type `RelativeBearing`
 //!   * [`units::datasize`] - Computer Data Sizes, `DataSize` representing `Bytes`, `Kilobytes`, etc
+//!   * [`units::energy`] - The SI `Energy` quantity, representing `Joules`, `WattHours`, etc
 //!   * [`units::length`] - The SI `Length` quantity, representing `Meters`, `Feet`, etc
+//!   * [`units::mass`] - The SI `Mass` quantity, representing `Kilograms`, `Pounds`, etc
+//!   * [`units::power`] - The SI `Power` quantity, representing `Watts`, `Horsepower`, etc
+//!   * [`units::pressure`] - The SI `Pressure` quantity, representing `Pascals`, `Bar`, `InchesOfMercury`, etc
 //!   * [`units::speed`] - The SI `Speed` quantity, representing `MetersPerSecond`, `Knots`, etc
 //!   * [`units::temperature`] - The SI `Temperature` quantity, representing `Celsius`, `Kelvin`, etc
+//!   * [`units::volume`] - The SI `Volume` quantity, representing `CubicMeters`, `Liters`, `USGallons`, etc
 
 #![forbid(unsafe_code)]
 #![allow(clippy::cast_possible_truncation)]
@@ -32,6 +40,7 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod bounds;
+pub mod parse;
 pub mod shapes;
 #[macro_use]
 pub mod units;
@@ -0,0 +1,321 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! Parsing of quantities with units from strings like `12.5 km`, `3kn`, `-40 °F` or `1.5 GiB`.
+//!
+//! Every unit struct in [`crate::units`] implements [`core::str::FromStr`] using the helpers in
+//! this module.  Units can be written as their symbol (case-sensitive: `mm` is not `Mm`) or their
+//! full name (case-insensitive, singular or plural).  Units that accept SI prefixes take any of the
+//! prefixes in [`crate::prefixes`], by symbol (`k`, `M`, `μ` or `u`) or by name (`kilo`, `mega`).
+
+use core::fmt::{Display, Formatter};
+use core::num::ParseFloatError;
+
+use crate::prefixes::ALL_PREFIXES;
+
+///
+/// Errors returned while parsing a quantity from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnitParseError {
+    /// The input was empty, or only whitespace
+    Empty,
+    /// The numeric portion of the input could not be parsed as a number
+    InvalidNumber(ParseFloatError),
+    /// A number was provided without any units
+    MissingUnit { quantity: &'static str },
+    /// The unit text starting at byte `offset` isn't a known unit for this quantity
+    UnknownUnit {
+        quantity: &'static str,
+        offset: usize,
+    },
+    /// The degree-minute-second angle component at byte `offset` is malformed or out of range
+    InvalidAngle { offset: usize },
+}
+
+impl Display for UnitParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            UnitParseError::Empty => write!(f, "Empty input, expected a value with units"),
+            UnitParseError::InvalidNumber(e) => write!(f, "Invalid number: {e}"),
+            UnitParseError::MissingUnit { quantity } => {
+                write!(f, "Missing units for the {quantity} value")
+            }
+            UnitParseError::UnknownUnit { quantity, offset } => {
+                write!(f, "Unknown {quantity} unit at offset {offset}")
+            }
+            UnitParseError::InvalidAngle { offset } => {
+                write!(
+                    f,
+                    "Invalid degree/minute/second angle component at offset {offset}"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for UnitParseError {}
+
+impl From<ParseFloatError> for UnitParseError {
+    fn from(value: ParseFloatError) -> Self {
+        UnitParseError::InvalidNumber(value)
+    }
+}
+
+///
+/// The ways a specific unit can be written.
+pub(crate) struct UnitSpelling<U: 'static> {
+    pub unit: U,
+    /// Multiplied into the parsed value, for spellings that aren't a variant of the units enum
+    pub scale: f64,
+    /// Case-sensitive symbols, like `km` or `°F`
+    pub symbols: &'static [&'static str],
+    /// Case-insensitive full names, like `kilometer` or `kilometres`
+    pub names: &'static [&'static str],
+    /// Whether an SI prefix is permitted in front of the symbols and names
+    pub si_prefixes: bool,
+}
+
+impl<U: Copy> UnitSpelling<U> {
+    pub const fn new(
+        unit: U,
+        symbols: &'static [&'static str],
+        names: &'static [&'static str],
+    ) -> Self {
+        Self {
+            unit,
+            scale: 1.,
+            symbols,
+            names,
+            si_prefixes: false,
+        }
+    }
+    pub const fn prefixed(
+        unit: U,
+        symbols: &'static [&'static str],
+        names: &'static [&'static str],
+    ) -> Self {
+        Self {
+            unit,
+            scale: 1.,
+            symbols,
+            names,
+            si_prefixes: true,
+        }
+    }
+    pub const fn scaled(
+        unit: U,
+        scale: f64,
+        symbols: &'static [&'static str],
+        names: &'static [&'static str],
+    ) -> Self {
+        Self {
+            unit,
+            scale,
+            symbols,
+            names,
+            si_prefixes: false,
+        }
+    }
+
+    fn matches(&self, text: &str) -> bool {
+        self.symbols.contains(&text) || self.names.iter().any(|n| n.eq_ignore_ascii_case(text))
+    }
+}
+
+///
+/// Splits the leading number from the remaining text, returns `(number, rest, rest_offset)`.
+pub(crate) fn split_number(s: &str) -> (&str, &str, usize) {
+    let bytes = s.as_bytes();
+    let mut idx = 0;
+    if matches!(bytes.first(), Some(b'+' | b'-')) {
+        idx += 1;
+    }
+    while matches!(bytes.get(idx), Some(b'0'..=b'9' | b'.')) {
+        idx += 1;
+    }
+    // only treat an 'e' as an exponent if it's followed by digits, so `5eV` is 5 electronvolts
+    if matches!(bytes.get(idx), Some(b'e' | b'E')) {
+        let mut exp = idx + 1;
+        if matches!(bytes.get(exp), Some(b'+' | b'-')) {
+            exp += 1;
+        }
+        if matches!(bytes.get(exp), Some(b'0'..=b'9')) {
+            idx = exp;
+            while matches!(bytes.get(idx), Some(b'0'..=b'9')) {
+                idx += 1;
+            }
+        }
+    }
+    let (num, rest) = s.split_at(idx);
+    (num, rest, idx)
+}
+
+///
+/// Finds the unit for the provided text, returns the unit and the scale to apply to the value.
+pub(crate) fn lookup_unit<U: Copy>(text: &str, table: &[UnitSpelling<U>]) -> Option<(U, f64)> {
+    if let Some(spelling) = table.iter().find(|s| s.matches(text)) {
+        return Some((spelling.unit, spelling.scale));
+    }
+    for prefix in ALL_PREFIXES {
+        let mut candidates = [None, None, None];
+        candidates[0] = text.strip_prefix(prefix.symbol());
+        if prefix.base_exponent() == -6 {
+            // the ascii stand-in for 'μ', and the legacy 'µ' (micro sign) codepoint
+            candidates[1] = text
+                .strip_prefix('u')
+                .or_else(|| text.strip_prefix('\u{00B5}'));
+        }
+        let name = prefix.name();
+        if text.len() > name.len()
+            && text.is_char_boundary(name.len())
+            && text[..name.len()].eq_ignore_ascii_case(name)
+        {
+            candidates[2] = Some(&text[name.len()..]);
+        }
+        for rest in candidates.into_iter().flatten() {
+            if rest.is_empty() {
+                continue;
+            }
+            if let Some(spelling) = table.iter().find(|s| s.si_prefixes && s.matches(rest)) {
+                return Some((spelling.unit, spelling.scale * prefix.scale_factor()));
+            }
+        }
+    }
+    None
+}
+
+///
+/// Parses a `<number> <unit>` string using the provided spelling table.
+pub(crate) fn parse_quantity<U: Copy>(
+    s: &str,
+    quantity: &'static str,
+    table: &[UnitSpelling<U>],
+) -> Result<(f64, U), UnitParseError> {
+    let trimmed = s.trim_start();
+    let lead = s.len() - trimmed.len();
+    let trimmed = trimmed.trim_end();
+    if trimmed.is_empty() {
+        return Err(UnitParseError::Empty);
+    }
+    let (num, rest, rest_offset) = split_number(trimmed);
+    let value: f64 = num.parse()?;
+    let unit = rest.trim_start();
+    if unit.is_empty() {
+        return Err(UnitParseError::MissingUnit { quantity });
+    }
+    let offset = lead + rest_offset + (rest.len() - unit.len());
+    let Some((units, scale)) = lookup_unit(unit, table) else {
+        return Err(UnitParseError::UnknownUnit { quantity, offset });
+    };
+    Ok((value * scale, units))
+}
+
+///
+/// Implements [`core::str::FromStr`] for a unit struct using a spelling table
+macro_rules! impl_from_str {
+    ($struct_type:ident, $quantity:literal, $table:ident) => {
+        impl core::str::FromStr for $struct_type {
+            type Err = $crate::parse::UnitParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let (value, units) = $crate::parse::parse_quantity(s, $quantity, $table)?;
+                Ok($struct_type::new(value, units))
+            }
+        }
+    };
+}
+pub(crate) use impl_from_str;
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{split_number, UnitParseError};
+    use crate::units::datasize::{DataSize, DataSizeUnits};
+    use crate::units::duration::Duration;
+    use crate::units::energy::Energy;
+    use crate::units::freq::Frequency;
+    use crate::units::length::{Length, LengthUnits};
+    use crate::units::mass::Mass;
+    use crate::units::pressure::{Pressure, PressureUnits};
+    use crate::units::speed::{Speed, SpeedUnits};
+    use crate::units::temperature::Temperature;
+    use crate::units::Unit;
+    use irox_tools::assert_eq_eps;
+
+    #[test]
+    pub fn test_split() {
+        assert_eq!(("12.5", " km", 4), split_number("12.5 km"));
+        assert_eq!(("-1.5e-3", "m", 7), split_number("-1.5e-3m"));
+        assert_eq!(("5", "eV", 1), split_number("5eV"));
+        assert_eq!(("", "km", 0), split_number("km"));
+    }
+
+    #[test]
+    pub fn test_errors() {
+        assert_eq!(Err(UnitParseError::Empty), "  ".parse::<Length>());
+        assert_eq!(
+            Err(UnitParseError::MissingUnit { quantity: "length" }),
+            "12.5".parse::<Length>()
+        );
+        assert_eq!(
+            Err(UnitParseError::UnknownUnit {
+                quantity: "length",
+                offset: 6
+            }),
+            " 12.5 parsecs".parse::<Length>()
+        );
+        assert!(matches!(
+            "km".parse::<Length>(),
+            Err(UnitParseError::InvalidNumber(_))
+        ));
+        assert_eq!(
+            Ok(Length::new(12.5, LengthUnits::Kilometers)),
+            "12.5 km".parse()
+        );
+    }
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_quantities() -> Result<(), UnitParseError> {
+        let len: Length = "12.5 km".parse()?;
+        assert_eq_eps!(12_500., len.as_meters().value(), 1e-9);
+        let len: Length = "3 kilometers".parse()?;
+        assert_eq_eps!(3_000., len.as_meters().value(), 1e-9);
+        let len: Length = "250 um".parse()?;
+        assert_eq_eps!(2.5e-4, len.as_meters().value(), 1e-15);
+        let len: Length = "6 in".parse()?;
+        assert_eq_eps!(0.1524, len.as_meters().value(), 1e-12);
+
+        let speed: Speed = "3kn".parse()?;
+        assert_eq!(SpeedUnits::Knots, speed.units());
+        assert_eq_eps!(3., speed.value(), 1e-12);
+
+        let f: Temperature = "-40 \u{00B0}F".parse()?;
+        let c: Temperature = "-40 degC".parse()?;
+        assert_eq_eps!(c.as_kelvin().value(), f.as_kelvin().value(), 1e-9);
+
+        let size: DataSize = "1.5 GiB".parse()?;
+        assert_eq!(DataSizeUnits::Bytes, size.units());
+        assert_eq_eps!(1_610_612_736., size.value(), 1e-3);
+
+        let energy: Energy = "5eV".parse()?;
+        assert_eq_eps!(8.010_883e-19, energy.as_joules().value(), 1e-24);
+        let energy: Energy = "2 kWh".parse()?;
+        assert_eq_eps!(7.2e6, energy.as_joules().value(), 1e-6);
+
+        let dur: Duration = "90 min".parse()?;
+        assert_eq_eps!(5_400., dur.as_seconds_f64(), 1e-9);
+        let freq: Frequency = "1575.42 MHz".parse()?;
+        assert_eq_eps!(1.575_42e9, freq.as_hz().value(), 1e-3);
+        let mass: Mass = "2.5 kilograms".parse()?;
+        assert_eq_eps!(2.5, mass.as_kilograms().value(), 1e-12);
+        let pressure: Pressure = "1013.25 hPa".parse()?;
+        assert_eq_eps!(
+            1.,
+            pressure.as_unit(PressureUnits::Atmospheres).value(),
+            1e-9
+        );
+        Ok(())
+    }
+}
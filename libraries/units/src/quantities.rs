@@ -231,5 +231,12 @@ mod test {
         assert_eq!("10.256nm\u{00B3}", q.to_string());
         let q = Quantity::new(1.0256e-8, Units::MeterPerSecondPerSecond);
         assert_eq!("10.256nm/s\u{00B2}", q.to_string());
+
+        let q = crate::units::mass::Mass::new_kilograms(2.5).as_quantity();
+        assert_eq!("2.500kg", q.to_string());
+        let q = crate::units::pressure::Pressure::new_millibar(1013.25).as_quantity();
+        assert_eq!("101.325kPa", q.to_string());
+        let q = crate::units::power::Power::new_milliwatts(12.5).as_quantity();
+        assert_eq!("12.500mW", q.to_string());
     }
 }
@@ -47,6 +47,18 @@ impl Ellipse {
         }
     }
 
+    /// The axis oriented by [`Ellipse::orientation`]
+    #[must_use]
+    pub fn first_axis(&self) -> CircularDimension {
+        self.first_axis
+    }
+
+    /// The axis orthogonal to [`Ellipse::first_axis`]
+    #[must_use]
+    pub fn second_axis(&self) -> CircularDimension {
+        self.second_axis
+    }
+
     #[must_use]
     pub fn semi_major_axis(&self) -> CircularDimension {
         if self.first_axis > self.second_axis {
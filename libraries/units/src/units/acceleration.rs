@@ -0,0 +1,215 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! This module contains the basic types and conversions for the SI "Acceleration" quantity
+use core::fmt::{Display, Formatter};
+
+use crate::parse::UnitSpelling;
+use crate::prefixes::MILLI;
+use crate::quantities::{Quantity, Units};
+use crate::units::duration::Duration;
+use crate::units::length::FEET_TO_METERS;
+use crate::units::speed::Speed;
+use crate::units::{FromUnits, Unit};
+
+///
+/// Represents a specific acceleration unit - SI or otherwise
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum AccelerationUnits {
+    /// SI Derived Unit for Acceleration - Meters per Second Squared
+    #[default]
+    MetersPerSecondSquared,
+
+    /// Multiples of standard gravity, `g0`
+    StandardGravity,
+
+    /// Thousandths of standard gravity, common for IMU and vibration sensors
+    MilliStandardGravity,
+
+    /// Feet per Second Squared
+    FeetPerSecondSquared,
+
+    /// Gal (Galileo), one centimeter per second squared, used in gravimetry
+    Gal,
+}
+
+impl AccelerationUnits {
+    pub const fn short_name(&self) -> &'static str {
+        match self {
+            AccelerationUnits::MetersPerSecondSquared => "m/s\u{00B2}",
+            AccelerationUnits::StandardGravity => "g",
+            AccelerationUnits::MilliStandardGravity => "mg",
+            AccelerationUnits::FeetPerSecondSquared => "ft/s\u{00B2}",
+            AccelerationUnits::Gal => "Gal",
+        }
+    }
+
+    /// The number of meters per second squared in one of this unit
+    pub const fn si_factor(&self) -> f64 {
+        match self {
+            AccelerationUnits::MetersPerSecondSquared => 1.,
+            AccelerationUnits::StandardGravity => STANDARD_GRAVITY_TO_MPS2,
+            AccelerationUnits::MilliStandardGravity => {
+                STANDARD_GRAVITY_TO_MPS2 * MILLI.scale_factor()
+            }
+            AccelerationUnits::FeetPerSecondSquared => FEET_TO_METERS,
+            AccelerationUnits::Gal => GAL_TO_MPS2,
+        }
+    }
+}
+
+basic_unit!(Acceleration, AccelerationUnits, MetersPerSecondSquared);
+from_units_by_factor!(AccelerationUnits, f32);
+from_units_by_factor!(AccelerationUnits, f64);
+
+///
+/// Represents a discrete quantity of 'Acceleration' as defined in NIST 811.2008
+impl Acceleration {
+    #[must_use]
+    pub const fn new_meters_per_second_squared(value: f64) -> Acceleration {
+        Self {
+            value,
+            units: AccelerationUnits::MetersPerSecondSquared,
+        }
+    }
+
+    #[must_use]
+    pub const fn new_standard_gravity(value: f64) -> Acceleration {
+        Self {
+            value,
+            units: AccelerationUnits::StandardGravity,
+        }
+    }
+
+    #[must_use]
+    pub fn as_meters_per_second_squared(&self) -> Acceleration {
+        self.as_unit(AccelerationUnits::MetersPerSecondSquared)
+    }
+
+    #[must_use]
+    pub fn as_standard_gravity(&self) -> Acceleration {
+        self.as_unit(AccelerationUnits::StandardGravity)
+    }
+
+    /// Returns this acceleration as a generic [`Quantity`] of
+    /// [`Units::MeterPerSecondPerSecond`], for SI-prefixed display
+    #[must_use]
+    pub fn as_quantity(&self) -> Quantity<f64> {
+        Quantity::new(
+            self.as_meters_per_second_squared().value,
+            Units::MeterPerSecondPerSecond,
+        )
+    }
+}
+
+impl Display for Acceleration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_fmt(format_args!(
+            "{:02.3}{}",
+            self.value,
+            self.units.short_name()
+        ))
+    }
+}
+
+impl_dimensional_op!(Div, div, Speed, Duration => Acceleration, |s, d| {
+    Acceleration::new_meters_per_second_squared(s.as_meters_per_second().value() / d.as_seconds_f64())
+});
+impl_dimensional_op!(Mul, mul, Acceleration, Duration => Speed, |a, d| {
+    Speed::new_meters_per_second(a.as_meters_per_second_squared().value() * d.as_seconds_f64())
+});
+impl_dimensional_op!(Mul, mul, Duration, Acceleration => Speed, |d, a| a * d);
+impl_dimensional_op!(Div, div, Speed, Acceleration => Duration, |s, a| {
+    Duration::new_seconds(s.as_meters_per_second().value() / a.as_meters_per_second_squared().value())
+});
+
+static ACCELERATION_SPELLINGS: &[UnitSpelling<AccelerationUnits>] = &[
+    UnitSpelling::new(
+        AccelerationUnits::MetersPerSecondSquared,
+        &["m/s\u{00B2}", "m/s2", "m/s^2"],
+        &[
+            "meter per second squared",
+            "meters per second squared",
+            "metre per second squared",
+            "metres per second squared",
+        ],
+    ),
+    UnitSpelling::new(
+        AccelerationUnits::StandardGravity,
+        &["g", "g0", "gn"],
+        &["standard gravity", "standard gravities"],
+    ),
+    UnitSpelling::new(AccelerationUnits::MilliStandardGravity, &["mg"], &[]),
+    UnitSpelling::new(
+        AccelerationUnits::FeetPerSecondSquared,
+        &["ft/s\u{00B2}", "ft/s2", "ft/s^2"],
+        &["foot per second squared", "feet per second squared"],
+    ),
+    UnitSpelling::prefixed(
+        AccelerationUnits::Gal,
+        &["Gal"],
+        &["gal", "galileo", "galileos"],
+    ),
+];
+crate::parse::impl_from_str!(Acceleration, "acceleration", ACCELERATION_SPELLINGS);
+
+pub const STANDARD_GRAVITY_TO_MPS2: f64 = 9.806_65; // Exact, as per NIST 811.2008
+pub const MPS2_TO_STANDARD_GRAVITY: f64 = 1. / STANDARD_GRAVITY_TO_MPS2;
+pub const GAL_TO_MPS2: f64 = 1.0E-2; // Exact, as per NIST 811.2008
+
+#[cfg(test)]
+mod tests {
+    use crate::units::acceleration::{Acceleration, AccelerationUnits};
+    use crate::units::duration::Duration;
+    use crate::units::speed::Speed;
+    use crate::units::FromUnits;
+    use irox_tools::assert_eq_eps;
+
+    #[test]
+    pub fn test_conversions() {
+        assert_eq_eps!(
+            9.80665,
+            AccelerationUnits::MetersPerSecondSquared
+                .from(1.0_f64, AccelerationUnits::StandardGravity),
+            1e-9
+        );
+        assert_eq_eps!(
+            32.174_05,
+            AccelerationUnits::FeetPerSecondSquared
+                .from(1.0_f64, AccelerationUnits::StandardGravity),
+            1e-5
+        );
+        assert_eq_eps!(
+            1000.,
+            AccelerationUnits::MilliStandardGravity
+                .from(1.0_f64, AccelerationUnits::StandardGravity),
+            1e-9
+        );
+        assert_eq_eps!(
+            1.,
+            Acceleration::new_meters_per_second_squared(9.80665)
+                .as_standard_gravity()
+                .value(),
+            1e-12
+        );
+    }
+
+    #[test]
+    pub fn test_dimensional() {
+        let accel = Speed::new_meters_per_second(9.80665) / Duration::new_seconds(1.);
+        assert_eq_eps!(1., accel.as_standard_gravity().value(), 1e-12);
+
+        let speed = Acceleration::new_standard_gravity(1.) * Duration::new_seconds(2.);
+        assert_eq_eps!(19.6133, speed.as_meters_per_second().value(), 1e-12);
+        let speed = Duration::new_seconds(2.) * Acceleration::new_meters_per_second_squared(2.);
+        assert_eq_eps!(4., speed.as_meters_per_second().value(), 1e-12);
+
+        let dur =
+            Speed::new_meters_per_second(10.) / Acceleration::new_meters_per_second_squared(4.);
+        assert_eq_eps!(2.5, dur.as_seconds_f64(), 1e-12);
+    }
+}
@@ -7,6 +7,7 @@
 //! quantity
 use core::fmt::{Display, Formatter};
 
+use crate::parse::{parse_quantity, split_number, UnitParseError, UnitSpelling};
 use crate::units::{FromUnits, Unit};
 
 ///
@@ -225,6 +226,140 @@ impl Display for Angle {
     }
 }
 
+static ANGLE_SPELLINGS: &[UnitSpelling<AngleUnits>] = &[
+    UnitSpelling::new(
+        AngleUnits::Degrees,
+        &["\u{00B0}", "deg"],
+        &["degree", "degrees"],
+    ),
+    UnitSpelling::prefixed(AngleUnits::Radians, &["rad"], &["radian", "radians"]),
+    UnitSpelling::scaled(
+        AngleUnits::Degrees,
+        1. / DEG_2_MIN,
+        &["arcmin"],
+        &["arcminute", "arcminutes"],
+    ),
+    UnitSpelling::scaled(
+        AngleUnits::Degrees,
+        1. / DEG_2_SEC,
+        &["arcsec"],
+        &["arcsecond", "arcseconds"],
+    ),
+    UnitSpelling::scaled(
+        AngleUnits::Degrees,
+        REV_2_DEG,
+        &["rev"],
+        &["revolution", "revolutions", "turn", "turns"],
+    ),
+    UnitSpelling::scaled(
+        AngleUnits::Degrees,
+        1. / DEG_2_MIL,
+        &["mil"],
+        &["mil", "mils"],
+    ),
+];
+
+const DMS_MARKERS: [char; 5] = ['\u{00B0}', '\'', '\u{2032}', '"', '\u{2033}'];
+
+///
+/// Parses a sexagesimal angle like `45°30'15.5"`, `offset` is the byte offset of `s` in the input.
+/// Minutes and seconds are optional, but must be in order and within `[0, 60)`.
+fn parse_dms(s: &str, offset: usize) -> Result<f64, UnitParseError> {
+    let mut rest = s;
+    let mut total = 0.;
+    let mut negative = false;
+    let mut last_field = 0;
+    while !rest.is_empty() {
+        let trimmed = rest.trim_start();
+        let pos = offset + s.len() - trimmed.len();
+        if trimmed.is_empty() {
+            break;
+        }
+        let (num, after, _) = split_number(trimmed);
+        let invalid = UnitParseError::InvalidAngle { offset: pos };
+        let value: f64 = num.parse().map_err(|_| invalid.clone())?;
+        let after = after.trim_start();
+        let mut chars = after.chars();
+        let field = match chars.next() {
+            Some('\u{00B0}') => 1,
+            Some('\'' | '\u{2032}') => 2,
+            Some('"' | '\u{2033}') => 3,
+            _ => return Err(invalid),
+        };
+        if field <= last_field {
+            return Err(invalid);
+        }
+        if field == 1 {
+            negative = value.is_sign_negative();
+            total += value.abs();
+        } else if last_field == 0 || !(0. ..60.).contains(&value) {
+            return Err(invalid);
+        } else if field == 2 {
+            total += value / DEG_2_MIN;
+        } else {
+            total += value / DEG_2_SEC;
+        }
+        last_field = field;
+        rest = chars.as_str();
+    }
+    if negative {
+        total = -total;
+    }
+    Ok(total)
+}
+
+///
+/// Parses an angle, either as sexagesimal degrees (`45°30'15"N`, `71°W`) with an optional
+/// hemisphere, or as a value with units (`1.5 rad`, `90 deg`, `3200 mil`).  Southern and Western
+/// hemispheres are negative.
+impl core::str::FromStr for Angle {
+    type Err = UnitParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim_start();
+        let mut offset = s.len() - trimmed.len();
+        let mut body = trimmed.trim_end();
+        if body.is_empty() {
+            return Err(UnitParseError::Empty);
+        }
+        let mut hemisphere = None;
+        if let Some(h @ ('N' | 'S' | 'E' | 'W')) = body.chars().next_back() {
+            hemisphere = Some(h);
+            body = body[..body.len() - 1].trim_end();
+        } else if let Some(h @ ('N' | 'S' | 'E' | 'W')) = body.chars().next() {
+            hemisphere = Some(h);
+            let rest = body[1..].trim_start();
+            offset += body.len() - rest.len();
+            body = rest;
+        }
+        let sign = match hemisphere {
+            Some('S' | 'W') => -1.,
+            _ => 1.,
+        };
+        if body.contains(DMS_MARKERS) {
+            let degrees = parse_dms(body, offset)?;
+            return Ok(Angle::new_degrees(sign * degrees));
+        }
+        if hemisphere.is_some() && split_number(body).1.trim().is_empty() {
+            // bare decimal degrees with a hemisphere, like `71.5W`
+            let value: f64 = body.parse()?;
+            return Ok(Angle::new_degrees(sign * value));
+        }
+        let (value, units) =
+            parse_quantity(body, "angle", ANGLE_SPELLINGS).map_err(|e| match e {
+                UnitParseError::UnknownUnit {
+                    quantity,
+                    offset: o,
+                } => UnitParseError::UnknownUnit {
+                    quantity,
+                    offset: o + offset,
+                },
+                e => e,
+            })?;
+        Ok(Angle::new(sign * value, units))
+    }
+}
+
 /// Degree to Radians factor
 pub const DEG_2_RAD: f64 = 0.017_453_292_519_943_295;
 /// Radians to Degrees factor
@@ -247,7 +382,66 @@ pub const RAD_2_MIL: f64 = MIL_2_REV / REV_2_RAD;
 
 #[cfg(test)]
 mod tests {
+    use crate::parse::UnitParseError;
     use crate::units::angle::Angle;
+    use irox_tools::assert_eq_eps;
+
+    #[test]
+    #[allow(clippy::panic_in_result_fn)]
+    pub fn test_parse() -> Result<(), UnitParseError> {
+        let lat: Angle = "45\u{00B0}30'15\"N".parse()?;
+        assert_eq_eps!(45.504_166_666_7, lat.as_degrees().value(), 1e-9);
+        let lon: Angle = "71\u{00B0}W".parse()?;
+        assert_eq_eps!(-71., lon.as_degrees().value(), 1e-12);
+        let lat: Angle = "S 33\u{00B0} 52\u{2032} 4.5\u{2033}".parse()?;
+        assert_eq_eps!(-33.867_916_666_7, lat.as_degrees().value(), 1e-9);
+        let lon: Angle = "-122.5\u{00B0}".parse()?;
+        assert_eq_eps!(-122.5, lon.as_degrees().value(), 1e-12);
+        let lon: Angle = "71.25W".parse()?;
+        assert_eq_eps!(-71.25, lon.as_degrees().value(), 1e-12);
+
+        assert_eq_eps!(
+            core::f64::consts::FRAC_PI_2,
+            "1.5707963267948966 rad"
+                .parse::<Angle>()?
+                .as_radians()
+                .value(),
+            1e-15
+        );
+        assert_eq_eps!(
+            0.5,
+            "500 mrad".parse::<Angle>()?.as_radians().value(),
+            1e-12
+        );
+        assert_eq_eps!(90., "1600 mil".parse::<Angle>()?.as_degrees().value(), 1e-9);
+        assert_eq_eps!(
+            180.,
+            "0.5 turns".parse::<Angle>()?.as_degrees().value(),
+            1e-9
+        );
+        assert_eq_eps!(
+            1.,
+            "60 arcmin".parse::<Angle>()?.as_degrees().value(),
+            1e-12
+        );
+
+        assert_eq!(
+            Err(UnitParseError::InvalidAngle { offset: 4 }),
+            "45\u{00B0}75'".parse::<Angle>()
+        );
+        assert_eq!(
+            Err(UnitParseError::InvalidAngle { offset: 0 }),
+            "30'45\u{00B0}".parse::<Angle>()
+        );
+        assert_eq!(
+            Err(UnitParseError::UnknownUnit {
+                quantity: "angle",
+                offset: 3
+            }),
+            "12 furlongs".parse::<Angle>()
+        );
+        Ok(())
+    }
 
     #[test]
     pub fn test_angle_between() {
@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! This module contains the basic types and conversions for the "Angular Rate" (angular velocity)
+//! quantity
+use core::f64::consts::TAU;
+use core::fmt::{Display, Formatter};
+
+use crate::parse::UnitSpelling;
+use crate::units::angle::Angle;
+use crate::units::duration::Duration;
+use crate::units::{FromUnits, Unit};
+
+///
+/// Represents a specific angular rate unit - SI or otherwise
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum AngularRateUnits {
+    /// SI Derived Unit for Angular Rate - Radians per Second
+    #[default]
+    RadiansPerSecond,
+
+    /// Degrees per Second, common for gyroscopes
+    DegreesPerSecond,
+
+    /// Degrees per Hour, common for gyroscope bias stability
+    DegreesPerHour,
+
+    /// Revolutions per Minute
+    RevolutionsPerMinute,
+}
+
+impl AngularRateUnits {
+    pub const fn short_name(&self) -> &'static str {
+        match self {
+            AngularRateUnits::RadiansPerSecond => "rad/s",
+            AngularRateUnits::DegreesPerSecond => "\u{00B0}/s",
+            AngularRateUnits::DegreesPerHour => "\u{00B0}/h",
+            AngularRateUnits::RevolutionsPerMinute => "rpm",
+        }
+    }
+
+    /// The number of radians per second in one of this unit
+    pub const fn si_factor(&self) -> f64 {
+        match self {
+            AngularRateUnits::RadiansPerSecond => 1.,
+            AngularRateUnits::DegreesPerSecond => DEGREES_TO_RADIANS,
+            AngularRateUnits::DegreesPerHour => DEGREES_TO_RADIANS / 3600.,
+            AngularRateUnits::RevolutionsPerMinute => TAU / 60.,
+        }
+    }
+}
+
+basic_unit!(AngularRate, AngularRateUnits, RadiansPerSecond);
+from_units_by_factor!(AngularRateUnits, f32);
+from_units_by_factor!(AngularRateUnits, f64);
+
+impl AngularRate {
+    #[must_use]
+    pub const fn new_radians_per_second(value: f64) -> AngularRate {
+        Self {
+            value,
+            units: AngularRateUnits::RadiansPerSecond,
+        }
+    }
+
+    #[must_use]
+    pub const fn new_degrees_per_second(value: f64) -> AngularRate {
+        Self {
+            value,
+            units: AngularRateUnits::DegreesPerSecond,
+        }
+    }
+
+    #[must_use]
+    pub fn as_radians_per_second(&self) -> AngularRate {
+        self.as_unit(AngularRateUnits::RadiansPerSecond)
+    }
+
+    #[must_use]
+    pub fn as_degrees_per_second(&self) -> AngularRate {
+        self.as_unit(AngularRateUnits::DegreesPerSecond)
+    }
+}
+
+impl Display for AngularRate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_fmt(format_args!(
+            "{:02.3}{}",
+            self.value,
+            self.units.short_name()
+        ))
+    }
+}
+
+static ANGULAR_RATE_SPELLINGS: &[UnitSpelling<AngularRateUnits>] = &[
+    UnitSpelling::prefixed(
+        AngularRateUnits::RadiansPerSecond,
+        &["rad/s"],
+        &["radian per second", "radians per second"],
+    ),
+    UnitSpelling::new(
+        AngularRateUnits::DegreesPerSecond,
+        &["\u{00B0}/s", "deg/s", "dps"],
+        &["degree per second", "degrees per second"],
+    ),
+    UnitSpelling::new(
+        AngularRateUnits::DegreesPerHour,
+        &["\u{00B0}/h", "\u{00B0}/hr", "deg/h", "deg/hr"],
+        &["degree per hour", "degrees per hour"],
+    ),
+    UnitSpelling::new(
+        AngularRateUnits::RevolutionsPerMinute,
+        &["rpm", "RPM", "r/min"],
+        &["revolution per minute", "revolutions per minute"],
+    ),
+];
+crate::parse::impl_from_str!(AngularRate, "angular rate", ANGULAR_RATE_SPELLINGS);
+
+impl_dimensional_op!(Div, div, Angle, Duration => AngularRate, |a, d| {
+    AngularRate::new_radians_per_second(a.as_radians().value() / d.as_seconds_f64())
+});
+impl_dimensional_op!(Mul, mul, AngularRate, Duration => Angle, |w, d| {
+    Angle::new_radians(w.as_radians_per_second().value() * d.as_seconds_f64())
+});
+impl_dimensional_op!(Mul, mul, Duration, AngularRate => Angle, |d, w| w * d);
+impl_dimensional_op!(Div, div, Angle, AngularRate => Duration, |a, w| {
+    Duration::new_seconds(a.as_radians().value() / w.as_radians_per_second().value())
+});
+
+const DEGREES_TO_RADIANS: f64 = core::f64::consts::PI / 180.;
+
+#[cfg(test)]
+mod tests {
+    use crate::units::angle::Angle;
+    use crate::units::angular_rate::AngularRate;
+    use crate::units::duration::Duration;
+    use irox_tools::assert_eq_eps;
+
+    #[test]
+    pub fn test_dimensional() {
+        let rate = Angle::new_degrees(90.) / Duration::new_seconds(2.);
+        assert_eq_eps!(45., rate.as_degrees_per_second().value(), 1e-12);
+
+        let angle = AngularRate::new_degrees_per_second(10.) * Duration::from_minutes(1);
+        assert_eq_eps!(600., angle.as_degrees().value(), 1e-9);
+
+        let dur = Angle::new_degrees(360.) / AngularRate::new_degrees_per_second(6.);
+        assert_eq_eps!(60., dur.as_seconds_f64(), 1e-9);
+    }
+}
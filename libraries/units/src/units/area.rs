@@ -0,0 +1,250 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! This module contains the basic types and conversions for the SI "Area" quantity
+use core::fmt::{Display, Formatter};
+
+#[allow(unused_imports)]
+use irox_tools::f64::FloatExt;
+
+use crate::parse::UnitSpelling;
+use crate::prefixes::{CENTI, KILO};
+use crate::quantities::{Quantity, Units};
+use crate::units::length::{Length, FEET_TO_METERS, MILES_TO_METERS, NAUTICAL_MILES_TO_METERS};
+use crate::units::{FromUnits, Unit};
+
+///
+/// Represents a specific area unit - SI or otherwise
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum AreaUnits {
+    /// SI Derived Unit for Area - Square Meters
+    #[default]
+    SquareMeters,
+
+    /// SI Derived unit square centimeters
+    SquareCentimeters,
+
+    /// SI Derived unit square kilometers
+    SquareKilometers,
+
+    /// Hectare, a 100m by 100m square
+    Hectares,
+
+    /// US Imperial "Square Foot"
+    SquareFeet,
+
+    /// US Imperial "Acre", 43,560 square feet
+    Acres,
+
+    /// US Imperial "Square Mile"
+    SquareMiles,
+
+    /// Square Nautical Mile
+    SquareNauticalMiles,
+}
+
+impl AreaUnits {
+    pub const fn short_name(&self) -> &'static str {
+        match self {
+            AreaUnits::SquareMeters => "m\u{00B2}",
+            AreaUnits::SquareCentimeters => "cm\u{00B2}",
+            AreaUnits::SquareKilometers => "km\u{00B2}",
+            AreaUnits::Hectares => "ha",
+            AreaUnits::SquareFeet => "ft\u{00B2}",
+            AreaUnits::Acres => "ac",
+            AreaUnits::SquareMiles => "mi\u{00B2}",
+            AreaUnits::SquareNauticalMiles => "nmi\u{00B2}",
+        }
+    }
+
+    /// The number of square meters in one of this unit
+    pub const fn si_factor(&self) -> f64 {
+        match self {
+            AreaUnits::SquareMeters => 1.,
+            AreaUnits::SquareCentimeters => CENTI.scale_factor() * CENTI.scale_factor(),
+            AreaUnits::SquareKilometers => KILO.scale_factor() * KILO.scale_factor(),
+            AreaUnits::Hectares => HECTARES_TO_SQUARE_METERS,
+            AreaUnits::SquareFeet => FEET_TO_METERS * FEET_TO_METERS,
+            AreaUnits::Acres => ACRES_TO_SQUARE_METERS,
+            AreaUnits::SquareMiles => MILES_TO_METERS * MILES_TO_METERS,
+            AreaUnits::SquareNauticalMiles => NAUTICAL_MILES_TO_METERS * NAUTICAL_MILES_TO_METERS,
+        }
+    }
+}
+
+basic_unit!(Area, AreaUnits, SquareMeters);
+from_units_by_factor!(AreaUnits, f32);
+from_units_by_factor!(AreaUnits, f64);
+
+///
+/// Represents a discrete quantity of 'Area' as defined in NIST 811.2008
+impl Area {
+    #[must_use]
+    pub const fn new_square_meters(value: f64) -> Area {
+        Self {
+            value,
+            units: AreaUnits::SquareMeters,
+        }
+    }
+
+    #[must_use]
+    pub const fn new_square_feet(value: f64) -> Area {
+        Self {
+            value,
+            units: AreaUnits::SquareFeet,
+        }
+    }
+
+    #[must_use]
+    pub fn as_square_meters(&self) -> Area {
+        self.as_unit(AreaUnits::SquareMeters)
+    }
+
+    #[must_use]
+    pub fn as_square_feet(&self) -> Area {
+        self.as_unit(AreaUnits::SquareFeet)
+    }
+
+    /// Returns the side length of a square with this area
+    #[must_use]
+    pub fn sqrt(&self) -> Length {
+        Length::new_meters(self.as_square_meters().value.sqrt())
+    }
+
+    /// Returns this area as a generic [`Quantity`] of [`Units::SquareMeter`], for SI-prefixed
+    /// display
+    #[must_use]
+    pub fn as_quantity(&self) -> Quantity<f64> {
+        Quantity::new(self.as_square_meters().value, Units::SquareMeter)
+    }
+}
+
+impl Display for Area {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_fmt(format_args!(
+            "{:02.3}{}",
+            self.value,
+            self.units.short_name()
+        ))
+    }
+}
+
+impl_dimensional_op!(Mul, mul, Length, Length => Area, |a, b| {
+    Area::new_square_meters(a.as_meters().value() * b.as_meters().value())
+});
+impl_dimensional_op!(Div, div, Area, Length => Length, |a, l| {
+    Length::new_meters(a.as_square_meters().value() / l.as_meters().value())
+});
+
+static AREA_SPELLINGS: &[UnitSpelling<AreaUnits>] = &[
+    UnitSpelling::new(
+        AreaUnits::SquareMeters,
+        &["m\u{00B2}", "m2", "m^2", "sqm"],
+        &[
+            "square meter",
+            "square meters",
+            "square metre",
+            "square metres",
+        ],
+    ),
+    UnitSpelling::new(
+        AreaUnits::SquareCentimeters,
+        &["cm\u{00B2}", "cm2", "cm^2"],
+        &[
+            "square centimeter",
+            "square centimeters",
+            "square centimetre",
+            "square centimetres",
+        ],
+    ),
+    UnitSpelling::new(
+        AreaUnits::SquareKilometers,
+        &["km\u{00B2}", "km2", "km^2"],
+        &[
+            "square kilometer",
+            "square kilometers",
+            "square kilometre",
+            "square kilometres",
+        ],
+    ),
+    UnitSpelling::new(AreaUnits::Hectares, &["ha"], &["hectare", "hectares"]),
+    UnitSpelling::new(
+        AreaUnits::SquareFeet,
+        &["ft\u{00B2}", "ft2", "ft^2", "sqft"],
+        &["square foot", "square feet"],
+    ),
+    UnitSpelling::new(AreaUnits::Acres, &["ac"], &["acre", "acres"]),
+    UnitSpelling::new(
+        AreaUnits::SquareMiles,
+        &["mi\u{00B2}", "mi2", "mi^2", "sqmi"],
+        &["square mile", "square miles"],
+    ),
+    UnitSpelling::new(
+        AreaUnits::SquareNauticalMiles,
+        &["nmi\u{00B2}", "nmi2", "nmi^2"],
+        &["square nautical mile", "square nautical miles"],
+    ),
+];
+crate::parse::impl_from_str!(Area, "area", AREA_SPELLINGS);
+
+pub const HECTARES_TO_SQUARE_METERS: f64 = 1.0E4; // Exact, as per NIST 811.2008
+pub const ACRES_TO_SQUARE_METERS: f64 = 43_560. * FEET_TO_METERS * FEET_TO_METERS;
+
+#[cfg(test)]
+mod tests {
+    use crate::units::area::{Area, AreaUnits};
+    use crate::units::length::Length;
+    use crate::units::{FromUnits, Unit};
+    use irox_tools::assert_eq_eps;
+
+    #[test]
+    pub fn test_conversions() {
+        assert_eq_eps!(
+            4_046.856_422_4,
+            AreaUnits::SquareMeters.from(1.0_f64, AreaUnits::Acres),
+            1e-9
+        );
+        assert_eq_eps!(
+            640.,
+            AreaUnits::Acres.from(1.0_f64, AreaUnits::SquareMiles),
+            1e-9
+        );
+        assert_eq_eps!(
+            100.,
+            AreaUnits::Hectares.from(1.0_f64, AreaUnits::SquareKilometers),
+            1e-12
+        );
+        assert_eq_eps!(
+            1e4,
+            AreaUnits::SquareCentimeters.from(1.0_f64, AreaUnits::SquareMeters),
+            1e-9
+        );
+        assert_eq_eps!(
+            1.,
+            Area::new_square_feet(10.763_910_416_7)
+                .as_square_meters()
+                .value(),
+            1e-9
+        );
+    }
+
+    #[test]
+    pub fn test_dimensional() {
+        let area = Length::new_meters(100.) * Length::new_meters(100.);
+        assert_eq_eps!(1., area.as_unit(AreaUnits::Hectares).value(), 1e-12);
+        let area = Length::new_feet(10.) * Length::new_meters(1.);
+        assert_eq_eps!(3.048, area.as_square_meters().value(), 1e-12);
+
+        let len = Area::new_square_meters(50.) / Length::new_meters(5.);
+        assert_eq_eps!(10., len.as_meters().value(), 1e-12);
+        assert_eq_eps!(
+            5.,
+            Area::new_square_meters(25.).sqrt().as_meters().value(),
+            1e-12
+        );
+    }
+}
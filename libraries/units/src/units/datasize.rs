@@ -5,6 +5,7 @@
 //! Contains [`DataSize`] and [`DataSizeUnits`] - Physical Quantities of computer binary storage
 //!
 
+use crate::parse::UnitSpelling;
 use crate::units::Unit;
 use core::fmt::{Display, Formatter};
 
@@ -144,6 +145,66 @@ impl Display for DataSize {
     }
 }
 
+static DATASIZE_SPELLINGS: &[UnitSpelling<DataSizeUnits>] = &[
+    UnitSpelling::new(DataSizeUnits::Bytes, &["B"], &["byte", "bytes"]),
+    UnitSpelling::new(
+        DataSizeUnits::Kilobytes,
+        &["kB", "KB"],
+        &["kilobyte", "kilobytes"],
+    ),
+    UnitSpelling::new(
+        DataSizeUnits::Megabytes,
+        &["MB"],
+        &["megabyte", "megabytes"],
+    ),
+    UnitSpelling::new(
+        DataSizeUnits::Gigabytes,
+        &["GB"],
+        &["gigabyte", "gigabytes"],
+    ),
+    UnitSpelling::new(
+        DataSizeUnits::Terabytes,
+        &["TB"],
+        &["terabyte", "terabytes"],
+    ),
+    UnitSpelling::new(
+        DataSizeUnits::Petabytes,
+        &["PB"],
+        &["petabyte", "petabytes"],
+    ),
+    UnitSpelling::scaled(
+        DataSizeUnits::Bytes,
+        KIB_TO_BYTES as f64,
+        &["KiB"],
+        &["kibibyte", "kibibytes"],
+    ),
+    UnitSpelling::scaled(
+        DataSizeUnits::Bytes,
+        MIB_TO_BYTES as f64,
+        &["MiB"],
+        &["mebibyte", "mebibytes"],
+    ),
+    UnitSpelling::scaled(
+        DataSizeUnits::Bytes,
+        GIB_TO_BYTES as f64,
+        &["GiB"],
+        &["gibibyte", "gibibytes"],
+    ),
+    UnitSpelling::scaled(
+        DataSizeUnits::Bytes,
+        TIB_TO_BYTES as f64,
+        &["TiB"],
+        &["tebibyte", "tebibytes"],
+    ),
+    UnitSpelling::scaled(
+        DataSizeUnits::Bytes,
+        PIB_TO_BYTES as f64,
+        &["PiB"],
+        &["pebibyte", "pebibytes"],
+    ),
+];
+crate::parse::impl_from_str!(DataSize, "data size", DATASIZE_SPELLINGS);
+
 /// Kilobyte to Byte factor
 pub const KB_TO_BYTES: u64 = 1000;
 /// Megabyte to Byte factor
@@ -154,3 +215,14 @@ pub const GB_TO_BYTES: u64 = MB_TO_BYTES * 1000;
 pub const TB_TO_BYTES: u64 = GB_TO_BYTES * 1000;
 /// Petabyte to Byte factor
 pub const PB_TO_BYTES: u64 = TB_TO_BYTES * 1000;
+
+/// Kibibyte to Byte factor
+pub const KIB_TO_BYTES: u64 = 1024;
+/// Mebibyte to Byte factor
+pub const MIB_TO_BYTES: u64 = KIB_TO_BYTES * 1024;
+/// Gibibyte to Byte factor
+pub const GIB_TO_BYTES: u64 = MIB_TO_BYTES * 1024;
+/// Tebibyte to Byte factor
+pub const TIB_TO_BYTES: u64 = GIB_TO_BYTES * 1024;
+/// Pebibyte to Byte factor
+pub const PIB_TO_BYTES: u64 = TIB_TO_BYTES * 1024;
@@ -6,6 +6,7 @@
 //! Contains [`Duration`] and [`DurationUnit`], a Physical Quantity of amount of Time passed.
 //!
 
+use crate::parse::UnitSpelling;
 use crate::units::{FromUnits, Unit};
 use core::fmt::{Display, Formatter, Write};
 use core::num::ParseFloatError;
@@ -798,6 +799,43 @@ impl Duration {
             + Duration::new(years, DurationUnit::Year))
     }
 }
+static DURATION_SPELLINGS: &[UnitSpelling<DurationUnit>] = &[
+    UnitSpelling::new(
+        DurationUnit::Millisecond,
+        &["ms"],
+        &["millisecond", "milliseconds"],
+    ),
+    UnitSpelling::new(
+        DurationUnit::Microsecond,
+        &["us", "\u{03BC}s", "\u{00B5}s"],
+        &["microsecond", "microseconds"],
+    ),
+    UnitSpelling::new(
+        DurationUnit::Nanosecond,
+        &["ns"],
+        &["nanosecond", "nanoseconds"],
+    ),
+    UnitSpelling::new(
+        DurationUnit::Picosecond,
+        &["ps"],
+        &["picosecond", "picoseconds"],
+    ),
+    UnitSpelling::prefixed(
+        DurationUnit::Second,
+        &["s", "sec", "secs"],
+        &["second", "seconds"],
+    ),
+    UnitSpelling::new(
+        DurationUnit::Minute,
+        &["min", "mins"],
+        &["minute", "minutes"],
+    ),
+    UnitSpelling::new(DurationUnit::Hour, &["h", "hr", "hrs"], &["hour", "hours"]),
+    UnitSpelling::new(DurationUnit::Day, &["d", "dy"], &["day", "days"]),
+    UnitSpelling::new(DurationUnit::Year, &["yr", "y"], &["year", "years"]),
+];
+crate::parse::impl_from_str!(Duration, "duration", DURATION_SPELLINGS);
+
 #[derive(Eq, PartialEq, Clone, Debug)]
 pub enum ParseError {
     UTF8(Utf8Error),
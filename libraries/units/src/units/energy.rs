@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! This module contains the basic types and conversions for the SI "Energy" quantity
+use core::fmt::{Display, Formatter};
+
+use crate::parse::UnitSpelling;
+use crate::prefixes::{KILO, MEGA};
+use crate::quantities::{Quantity, Units};
+use crate::units::{FromUnits, Unit};
+
+///
+/// Represents a specific energy unit - SI or otherwise
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum EnergyUnits {
+    /// SI Derived Unit for Energy - Joules, one Newton-meter
+    #[default]
+    Joules,
+
+    /// SI Derived unit kilojoules
+    Kilojoules,
+
+    /// SI Derived unit megajoules
+    Megajoules,
+
+    /// Watt-hour, one Watt for one Hour, common for battery capacity
+    WattHours,
+
+    /// Kilowatt-hour
+    KilowattHours,
+
+    /// Thermochemical calorie
+    Calories,
+
+    /// Thermochemical kilocalorie, the dietary "Calorie"
+    Kilocalories,
+
+    /// British Thermal Unit (International Table)
+    BritishThermalUnits,
+
+    /// Electronvolt
+    ElectronVolts,
+}
+
+impl EnergyUnits {
+    pub const fn short_name(&self) -> &'static str {
+        match self {
+            EnergyUnits::Joules => "J",
+            EnergyUnits::Kilojoules => "kJ",
+            EnergyUnits::Megajoules => "MJ",
+            EnergyUnits::WattHours => "Wh",
+            EnergyUnits::KilowattHours => "kWh",
+            EnergyUnits::Calories => "cal",
+            EnergyUnits::Kilocalories => "kcal",
+            EnergyUnits::BritishThermalUnits => "BTU",
+            EnergyUnits::ElectronVolts => "eV",
+        }
+    }
+
+    /// The number of joules in one of this unit
+    pub const fn si_factor(&self) -> f64 {
+        match self {
+            EnergyUnits::Joules => 1.,
+            EnergyUnits::Kilojoules => KILO.scale_factor(),
+            EnergyUnits::Megajoules => MEGA.scale_factor(),
+            EnergyUnits::WattHours => WATT_HOURS_TO_JOULES,
+            EnergyUnits::KilowattHours => WATT_HOURS_TO_JOULES * KILO.scale_factor(),
+            EnergyUnits::Calories => CALORIES_TO_JOULES,
+            EnergyUnits::Kilocalories => CALORIES_TO_JOULES * KILO.scale_factor(),
+            EnergyUnits::BritishThermalUnits => BTU_TO_JOULES,
+            EnergyUnits::ElectronVolts => ELECTRONVOLTS_TO_JOULES,
+        }
+    }
+}
+
+basic_unit!(Energy, EnergyUnits, Joules);
+from_units_by_factor!(EnergyUnits, f32);
+from_units_by_factor!(EnergyUnits, f64);
+
+///
+/// Represents a discrete quantity of 'Energy' as defined in NIST 811.2008
+impl Energy {
+    #[must_use]
+    pub const fn new_joules(value: f64) -> Energy {
+        Self {
+            value,
+            units: EnergyUnits::Joules,
+        }
+    }
+
+    #[must_use]
+    pub const fn new_watt_hours(value: f64) -> Energy {
+        Self {
+            value,
+            units: EnergyUnits::WattHours,
+        }
+    }
+
+    #[must_use]
+    pub fn as_joules(&self) -> Energy {
+        self.as_unit(EnergyUnits::Joules)
+    }
+
+    #[must_use]
+    pub fn as_watt_hours(&self) -> Energy {
+        self.as_unit(EnergyUnits::WattHours)
+    }
+
+    /// Returns this energy as a generic [`Quantity`] of [`Units::Joule`], for SI-prefixed
+    /// display
+    #[must_use]
+    pub fn as_quantity(&self) -> Quantity<f64> {
+        Quantity::new(self.as_joules().value, Units::Joule)
+    }
+}
+
+impl Display for Energy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_fmt(format_args!(
+            "{:02.3}{}",
+            self.value,
+            self.units.short_name()
+        ))
+    }
+}
+
+static ENERGY_SPELLINGS: &[UnitSpelling<EnergyUnits>] = &[
+    UnitSpelling::new(
+        EnergyUnits::Kilojoules,
+        &["kJ"],
+        &["kilojoule", "kilojoules"],
+    ),
+    UnitSpelling::new(
+        EnergyUnits::Megajoules,
+        &["MJ"],
+        &["megajoule", "megajoules"],
+    ),
+    UnitSpelling::prefixed(EnergyUnits::Joules, &["J"], &["joule", "joules"]),
+    UnitSpelling::new(
+        EnergyUnits::KilowattHours,
+        &["kWh", "kW\u{22C5}h", "kW h"],
+        &[
+            "kilowatt hour",
+            "kilowatt hours",
+            "kilowatt-hour",
+            "kilowatt-hours",
+        ],
+    ),
+    UnitSpelling::prefixed(
+        EnergyUnits::WattHours,
+        &["Wh", "W\u{22C5}h", "W h"],
+        &["watt hour", "watt hours", "watt-hour", "watt-hours"],
+    ),
+    UnitSpelling::new(
+        EnergyUnits::Kilocalories,
+        &["kcal", "Cal"],
+        &["kilocalorie", "kilocalories"],
+    ),
+    UnitSpelling::new(EnergyUnits::Calories, &["cal"], &["calorie", "calories"]),
+    UnitSpelling::new(
+        EnergyUnits::BritishThermalUnits,
+        &["BTU", "Btu"],
+        &["british thermal unit", "british thermal units"],
+    ),
+    UnitSpelling::prefixed(
+        EnergyUnits::ElectronVolts,
+        &["eV"],
+        &[
+            "electronvolt",
+            "electronvolts",
+            "electron volt",
+            "electron volts",
+        ],
+    ),
+];
+crate::parse::impl_from_str!(Energy, "energy", ENERGY_SPELLINGS);
+
+pub const WATT_HOURS_TO_JOULES: f64 = 3.6E3; // Exact
+pub const JOULES_TO_WATT_HOURS: f64 = 1. / WATT_HOURS_TO_JOULES;
+pub const CALORIES_TO_JOULES: f64 = 4.184; // Exact, as per NIST 811.2008
+pub const BTU_TO_JOULES: f64 = 1.055_056E3; // as per NIST 811.2008
+pub const ELECTRONVOLTS_TO_JOULES: f64 = 1.602_176_634E-19; // Exact, as per SI 2019
+
+#[cfg(test)]
+mod tests {
+    use crate::units::energy::{Energy, EnergyUnits};
+    use crate::units::FromUnits;
+    use irox_tools::assert_eq_eps;
+
+    #[test]
+    pub fn test_conversions() {
+        assert_eq_eps!(
+            3.6e6,
+            EnergyUnits::Joules.from(1.0_f64, EnergyUnits::KilowattHours),
+            1e-9
+        );
+        assert_eq_eps!(
+            1000.,
+            EnergyUnits::Calories.from(1.0_f64, EnergyUnits::Kilocalories),
+            1e-9
+        );
+        assert_eq_eps!(
+            3412.14,
+            EnergyUnits::BritishThermalUnits.from(1.0_f64, EnergyUnits::KilowattHours),
+            1e-2
+        );
+        assert_eq_eps!(1., Energy::new_joules(3600.).as_watt_hours().value(), 1e-12);
+    }
+}
@@ -2,6 +2,8 @@
 // Copyright 2025 IROX Contributors
 //
 
+use crate::parse::UnitSpelling;
+use crate::units::duration::Duration;
 use crate::units::{FromUnits, Unit};
 use core::fmt::{Display, Formatter};
 
@@ -92,6 +94,32 @@ impl Display for Frequency {
     }
 }
 
+impl Frequency {
+    #[must_use]
+    pub fn as_hz(&self) -> Frequency {
+        self.as_unit(FrequencyUnit::Hertz)
+    }
+}
+
+impl_dimensional_op!(Mul, mul, Frequency, Duration => f64, |f, d| {
+    f.as_hz().value() * d.as_seconds_f64()
+});
+impl_dimensional_op!(Mul, mul, Duration, Frequency => f64, |d, f| f * d);
+impl_dimensional_op!(Div, div, f64, Duration => Frequency, |n, d| {
+    Frequency::new_hz_f64(n / d.as_seconds_f64())
+});
+impl_dimensional_op!(Div, div, f64, Frequency => Duration, |n, f| {
+    Duration::new_seconds(n / f.as_hz().value())
+});
+
+static FREQUENCY_SPELLINGS: &[UnitSpelling<FrequencyUnit>] = &[
+    UnitSpelling::new(FrequencyUnit::Kilohertz, &["kHz", "KHz"], &["kilohertz"]),
+    UnitSpelling::new(FrequencyUnit::Megahertz, &["MHz"], &["megahertz"]),
+    UnitSpelling::new(FrequencyUnit::Gigahertz, &["GHz"], &["gigahertz"]),
+    UnitSpelling::prefixed(FrequencyUnit::Hertz, &["Hz"], &["hertz"]),
+];
+crate::parse::impl_from_str!(Frequency, "frequency", FREQUENCY_SPELLINGS);
+
 pub const HZ_TO_KHZ: f64 = 1e-3;
 pub const HZ_TO_MHZ: f64 = 1e-6;
 pub const HZ_TO_GHZ: f64 = 1e-9;
@@ -108,3 +136,23 @@ pub const GHZ_TO_MHZ: f64 = 1e3;
 
 pub const GHZ_TO_KHZ: f64 = 1e6;
 pub const KHZ_TO_GHZ: f64 = 1e-6;
+
+#[cfg(test)]
+mod tests {
+    use crate::units::duration::Duration;
+    use crate::units::freq::Frequency;
+    use irox_tools::assert_eq_eps;
+
+    #[test]
+    pub fn test_dimensional() {
+        let cycles = Frequency::new_khz(1.) * Duration::from_millis(250);
+        assert_eq_eps!(250., cycles, 1e-9);
+        let cycles = Duration::new_seconds(2.) * Frequency::new_hz(10);
+        assert_eq_eps!(20., cycles, 1e-12);
+
+        let freq = 1. / Duration::from_millis(100);
+        assert_eq_eps!(10., freq.value(), 1e-9);
+        let period = 1. / Frequency::new_khz(1.);
+        assert_eq_eps!(1e-3, period.as_seconds_f64(), 1e-15);
+    }
+}
@@ -6,6 +6,7 @@
 //! This module contains the basic types and conversions for the SI "Length" quantity
 use core::fmt::{Display, Formatter};
 
+use crate::parse::UnitSpelling;
 use crate::units::{FromUnits, Unit};
 
 ///
@@ -134,7 +135,8 @@ macro_rules! from_units_length {
         }
     };
 }
-basic_unit!(Length, LengthUnits, Meters);
+// Length * Length is an Area, see the dimensional operators in [`crate::units::area`]
+basic_unit!(Length, LengthUnits, Meters, no_self_mul);
 from_units_length!(f32);
 from_units_length!(f64);
 
@@ -193,6 +195,48 @@ impl Display for Length {
     }
 }
 
+static LENGTH_SPELLINGS: &[UnitSpelling<LengthUnits>] = &[
+    UnitSpelling::new(
+        LengthUnits::Kilometers,
+        &["km"],
+        &["kilometer", "kilometers", "kilometre", "kilometres"],
+    ),
+    UnitSpelling::prefixed(
+        LengthUnits::Meters,
+        &["m"],
+        &["meter", "meters", "metre", "metres"],
+    ),
+    UnitSpelling::new(
+        LengthUnits::Feet,
+        &["ft", "'", "\u{2032}"],
+        &["foot", "feet"],
+    ),
+    UnitSpelling::scaled(
+        LengthUnits::Feet,
+        1. / 12.,
+        &["in", "\"", "\u{2033}"],
+        &["inch", "inches"],
+    ),
+    UnitSpelling::scaled(LengthUnits::Feet, 3., &["yd"], &["yard", "yards"]),
+    UnitSpelling::new(LengthUnits::Mile, &["mi"], &["mile", "miles"]),
+    UnitSpelling::new(
+        LengthUnits::NauticalMile,
+        &["nmi", "NM"],
+        &["nautical mile", "nautical miles"],
+    ),
+    UnitSpelling::new(
+        LengthUnits::USSurveyFoot,
+        &["ussft", "ftUS"],
+        &[
+            "survey foot",
+            "survey feet",
+            "us survey foot",
+            "us survey feet",
+        ],
+    ),
+];
+crate::parse::impl_from_str!(Length, "length", LENGTH_SPELLINGS);
+
 pub const FEET_TO_METERS: f64 = 3.048E-01; // Exact, as per NIST 811.2008
 pub const METERS_TO_FEET: f64 = 1. / FEET_TO_METERS;
 pub const MILES_TO_METERS: f64 = 1.609_344E3; // Exact, as per NIST 811.2008
@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! This module contains the basic types and conversions for the SI "Mass" quantity
+use core::fmt::{Display, Formatter};
+
+use crate::parse::UnitSpelling;
+use crate::prefixes::{KILO, MICRO, MILLI};
+use crate::quantities::{Quantity, Units};
+use crate::units::{FromUnits, Unit};
+
+///
+/// Represents a specific mass unit - SI or otherwise
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum MassUnits {
+    /// SI Base Unit for Mass - Kilograms
+    #[default]
+    Kilograms,
+
+    /// SI Derived unit grams
+    Grams,
+
+    /// SI Derived unit milligrams
+    Milligrams,
+
+    /// SI Derived unit micrograms
+    Micrograms,
+
+    /// Metric tonne, 1000 kilograms
+    Tonnes,
+
+    /// US/Imperial avoirdupois "Pound"
+    Pounds,
+
+    /// US/Imperial avoirdupois "Ounce"
+    Ounces,
+}
+
+impl MassUnits {
+    pub const fn short_name(&self) -> &'static str {
+        match self {
+            MassUnits::Kilograms => "kg",
+            MassUnits::Grams => "g",
+            MassUnits::Milligrams => "mg",
+            MassUnits::Micrograms => "\u{03BC}g",
+            MassUnits::Tonnes => "t",
+            MassUnits::Pounds => "lb",
+            MassUnits::Ounces => "oz",
+        }
+    }
+
+    /// The number of kilograms in one of this unit
+    pub const fn si_factor(&self) -> f64 {
+        match self {
+            MassUnits::Kilograms => 1.,
+            MassUnits::Grams => 1. / KILO.scale_factor(),
+            MassUnits::Milligrams => MILLI.scale_factor() / KILO.scale_factor(),
+            MassUnits::Micrograms => MICRO.scale_factor() / KILO.scale_factor(),
+            MassUnits::Tonnes => TONNES_TO_KILOGRAMS,
+            MassUnits::Pounds => POUNDS_TO_KILOGRAMS,
+            MassUnits::Ounces => OUNCES_TO_KILOGRAMS,
+        }
+    }
+}
+
+basic_unit!(Mass, MassUnits, Kilograms);
+from_units_by_factor!(MassUnits, f32);
+from_units_by_factor!(MassUnits, f64);
+
+///
+/// Represents a discrete quantity of 'Mass' as defined in NIST 811.2008
+impl Mass {
+    #[must_use]
+    pub const fn new_kilograms(value: f64) -> Mass {
+        Self {
+            value,
+            units: MassUnits::Kilograms,
+        }
+    }
+
+    #[must_use]
+    pub const fn new_grams(value: f64) -> Mass {
+        Self {
+            value,
+            units: MassUnits::Grams,
+        }
+    }
+
+    #[must_use]
+    pub const fn new_pounds(value: f64) -> Mass {
+        Self {
+            value,
+            units: MassUnits::Pounds,
+        }
+    }
+
+    #[must_use]
+    pub fn as_kilograms(&self) -> Mass {
+        self.as_unit(MassUnits::Kilograms)
+    }
+
+    #[must_use]
+    pub fn as_grams(&self) -> Mass {
+        self.as_unit(MassUnits::Grams)
+    }
+
+    #[must_use]
+    pub fn as_pounds(&self) -> Mass {
+        self.as_unit(MassUnits::Pounds)
+    }
+
+    /// Returns this mass as a generic [`Quantity`] of [`Units::Gram`], for SI-prefixed display
+    #[must_use]
+    pub fn as_quantity(&self) -> Quantity<f64> {
+        Quantity::new(self.as_grams().value, Units::Gram)
+    }
+}
+
+impl Display for Mass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_fmt(format_args!(
+            "{:02.3}{}",
+            self.value,
+            self.units.short_name()
+        ))
+    }
+}
+
+static MASS_SPELLINGS: &[UnitSpelling<MassUnits>] = &[
+    UnitSpelling::new(
+        MassUnits::Kilograms,
+        &["kg"],
+        &["kilogram", "kilograms", "kilogramme", "kilogrammes"],
+    ),
+    UnitSpelling::new(MassUnits::Milligrams, &["mg"], &["milligram", "milligrams"]),
+    UnitSpelling::new(
+        MassUnits::Micrograms,
+        &["\u{03BC}g", "\u{00B5}g", "ug", "mcg"],
+        &["microgram", "micrograms"],
+    ),
+    UnitSpelling::prefixed(
+        MassUnits::Grams,
+        &["g"],
+        &["gram", "grams", "gramme", "grammes"],
+    ),
+    UnitSpelling::new(
+        MassUnits::Tonnes,
+        &["t"],
+        &["tonne", "tonnes", "metric ton", "metric tons"],
+    ),
+    UnitSpelling::new(MassUnits::Pounds, &["lb", "lbs"], &["pound", "pounds"]),
+    UnitSpelling::new(MassUnits::Ounces, &["oz"], &["ounce", "ounces"]),
+];
+crate::parse::impl_from_str!(Mass, "mass", MASS_SPELLINGS);
+
+pub const TONNES_TO_KILOGRAMS: f64 = 1000.;
+pub const POUNDS_TO_KILOGRAMS: f64 = 4.535_923_7E-1; // Exact, as per NIST 811.2008
+pub const KILOGRAMS_TO_POUNDS: f64 = 1. / POUNDS_TO_KILOGRAMS;
+pub const OUNCES_TO_KILOGRAMS: f64 = POUNDS_TO_KILOGRAMS / 16.;
+pub const KILOGRAMS_TO_OUNCES: f64 = 1. / OUNCES_TO_KILOGRAMS;
+
+#[cfg(test)]
+mod tests {
+    use crate::units::mass::{Mass, MassUnits};
+    use crate::units::FromUnits;
+    use irox_tools::assert_eq_eps;
+
+    #[test]
+    pub fn test_conversions() {
+        assert_eq_eps!(
+            0.45359237,
+            MassUnits::Kilograms.from(1.0_f64, MassUnits::Pounds),
+            1e-9
+        );
+        assert_eq_eps!(
+            16.,
+            MassUnits::Ounces.from(1.0_f64, MassUnits::Pounds),
+            1e-12
+        );
+        assert_eq_eps!(
+            1e6,
+            MassUnits::Milligrams.from(1.0_f64, MassUnits::Kilograms),
+            1e-6
+        );
+        assert_eq_eps!(
+            2.5,
+            MassUnits::Tonnes.from(2.5e6_f64, MassUnits::Grams),
+            1e-12
+        );
+
+        let m = Mass::new_grams(1500.) + Mass::new_kilograms(1.);
+        assert_eq_eps!(2500., m.value(), 1e-12);
+        assert_eq!(Mass::new_kilograms(2.5), m);
+    }
+}
@@ -118,18 +118,22 @@ macro_rules! impl_mul {
                 <$($slf)+ as $crate::units::UnitStruct::<$units_type>>::new(self.value() * rhs, self.units())
             }
         }
-        impl<'a> core::ops::Mul<$($slf)+> for $($slf)+ {
-            type Output = $($mul)+;
+        impl<'a> core::ops::Mul<$($slf)+> for f64 {
+            type Output = $($out)+;
             fn mul(self, rhs: $($slf)+) -> Self::Output {
-                let upper = self.value();
-                let lower = <$($slf)+ as $crate::units::Unit::<$units_type>>::as_unit(&rhs, self.units()).value();
-                upper * lower
+                rhs * self
             }
         }
-        impl<'a> core::ops::Mul<$($slf)+> for f64 {
+    };
+}
+macro_rules! impl_mul_self {
+    ($($out:ty)+, $units_type:ident, $($slf:ty)+) => {
+        impl<'a> core::ops::Mul<$($slf)+> for $($slf)+ {
             type Output = $($out)+;
             fn mul(self, rhs: $($slf)+) -> Self::Output {
-                rhs * self
+                let upper = self.value();
+                let lower = <$($slf)+ as $crate::units::Unit::<$units_type>>::as_unit(&rhs, self.units()).value();
+                upper * lower
             }
         }
     };
@@ -173,9 +177,40 @@ macro_rules! impl_mutop {
     };
 }
 
+///
+/// Implements a cross-quantity operator, `lhs <op> rhs -> out`, for the owned and borrowed forms
+/// of both operands.  The function receives the owned operands and picks the output units.
+macro_rules! impl_dimensional_op {
+    ($trait:ident, $fn:ident, $lhs:ty, $rhs:ty => $out:ty, $func:expr) => {
+        impl_dimensional_op!(@impl $trait, $fn, $lhs, $rhs, $lhs, $rhs, $out, $func);
+        impl_dimensional_op!(@impl $trait, $fn, $lhs, $rhs, &$lhs, $rhs, $out, $func);
+        impl_dimensional_op!(@impl $trait, $fn, $lhs, $rhs, $lhs, &$rhs, $out, $func);
+        impl_dimensional_op!(@impl $trait, $fn, $lhs, $rhs, &$lhs, &$rhs, $out, $func);
+    };
+    (@impl $trait:ident, $fn:ident, $l:ty, $r:ty, $lt:ty, $rt:ty, $out:ty, $func:expr) => {
+        impl core::ops::$trait<$rt> for $lt {
+            type Output = $out;
+            fn $fn(self, rhs: $rt) -> $out {
+                let lhs: $l = *core::borrow::Borrow::<$l>::borrow(&self);
+                let rhs: $r = *core::borrow::Borrow::<$r>::borrow(&rhs);
+                let func: fn($l, $r) -> $out = $func;
+                func(lhs, rhs)
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! basic_unit {
     ($struct_type:ident, $units_type: ident, $default_units: ident) => {
+        basic_unit!($struct_type, $units_type, $default_units, no_self_mul);
+        impl_mul_self!(f64, $units_type, $struct_type);
+        impl_mul_self!(f64, $units_type, &$struct_type);
+        impl_mul_self!(f64, $units_type, &mut $struct_type);
+    };
+    // Omits the `Self * Self -> f64` operator, for types whose product with themselves is
+    // another quantity (`Length * Length -> Area`)
+    ($struct_type:ident, $units_type: ident, $default_units: ident, no_self_mul) => {
         #[derive(Debug, Clone, Copy, Default)]
         #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $struct_type {
@@ -194,6 +229,9 @@ macro_rules! basic_unit_no_serde {
             units: $units_type,
         }
         basic_unit_impl!($struct_type, $units_type, $default_units);
+        impl_mul_self!(f64, $units_type, $struct_type);
+        impl_mul_self!(f64, $units_type, &$struct_type);
+        impl_mul_self!(f64, $units_type, &mut $struct_type);
     };
 }
 macro_rules! basic_unit_impl {
@@ -259,6 +297,22 @@ macro_rules! basic_unit_impl {
     };
 }
 
+///
+/// Implements [`FromUnits`] for a units enum where every unit is a constant multiple of the SI
+/// unit, as returned by the enum's `const fn si_factor(&self) -> f64`
+macro_rules! from_units_by_factor {
+    ($units_type:ident, $type:ident) => {
+        impl $crate::units::FromUnits<$type> for $units_type {
+            fn from(&self, value: $type, units: Self) -> $type {
+                if *self == units {
+                    return value;
+                }
+                (value as f64 * units.si_factor() / self.si_factor()) as $type
+            }
+        }
+    };
+}
+
 ///
 /// Trait to allow the direct conversion of a unit scalar to another unit scalar
 pub trait FromUnits<T> {
@@ -291,12 +345,20 @@ pub trait Unit<T> {
         Self: Sized;
 }
 
+pub mod acceleration;
 pub mod angle;
+pub mod angular_rate;
+pub mod area;
 pub mod compass;
 pub mod datasize;
 pub mod drift;
 pub mod duration;
+pub mod energy;
 pub mod freq;
 pub mod length;
+pub mod mass;
+pub mod power;
+pub mod pressure;
 pub mod speed;
 pub mod temperature;
+pub mod volume;
@@ -0,0 +1,200 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! This module contains the basic types and conversions for the SI "Power" quantity
+use core::fmt::{Display, Formatter};
+
+use crate::parse::UnitSpelling;
+use crate::prefixes::{KILO, MEGA, MICRO, MILLI};
+use crate::quantities::{Quantity, Units};
+use crate::units::duration::Duration;
+use crate::units::energy::Energy;
+use crate::units::{FromUnits, Unit};
+
+///
+/// Represents a specific power unit - SI or otherwise
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum PowerUnits {
+    /// SI Derived Unit for Power - Watts, one Joule per Second
+    #[default]
+    Watts,
+
+    /// SI Derived unit microwatts
+    Microwatts,
+
+    /// SI Derived unit milliwatts
+    Milliwatts,
+
+    /// SI Derived unit kilowatts
+    Kilowatts,
+
+    /// SI Derived unit megawatts
+    Megawatts,
+
+    /// Mechanical (Imperial) horsepower, 550 foot-pounds force per second
+    Horsepower,
+
+    /// British Thermal Units (International Table) per Hour
+    BTUPerHour,
+}
+
+impl PowerUnits {
+    pub const fn short_name(&self) -> &'static str {
+        match self {
+            PowerUnits::Watts => "W",
+            PowerUnits::Microwatts => "\u{03BC}W",
+            PowerUnits::Milliwatts => "mW",
+            PowerUnits::Kilowatts => "kW",
+            PowerUnits::Megawatts => "MW",
+            PowerUnits::Horsepower => "hp",
+            PowerUnits::BTUPerHour => "BTU/h",
+        }
+    }
+
+    /// The number of watts in one of this unit
+    pub const fn si_factor(&self) -> f64 {
+        match self {
+            PowerUnits::Watts => 1.,
+            PowerUnits::Microwatts => MICRO.scale_factor(),
+            PowerUnits::Milliwatts => MILLI.scale_factor(),
+            PowerUnits::Kilowatts => KILO.scale_factor(),
+            PowerUnits::Megawatts => MEGA.scale_factor(),
+            PowerUnits::Horsepower => HORSEPOWER_TO_WATTS,
+            PowerUnits::BTUPerHour => BTU_PER_HOUR_TO_WATTS,
+        }
+    }
+}
+
+basic_unit!(Power, PowerUnits, Watts);
+from_units_by_factor!(PowerUnits, f32);
+from_units_by_factor!(PowerUnits, f64);
+
+///
+/// Represents a discrete quantity of 'Power' as defined in NIST 811.2008
+impl Power {
+    #[must_use]
+    pub const fn new_watts(value: f64) -> Power {
+        Self {
+            value,
+            units: PowerUnits::Watts,
+        }
+    }
+
+    #[must_use]
+    pub const fn new_milliwatts(value: f64) -> Power {
+        Self {
+            value,
+            units: PowerUnits::Milliwatts,
+        }
+    }
+
+    #[must_use]
+    pub fn as_watts(&self) -> Power {
+        self.as_unit(PowerUnits::Watts)
+    }
+
+    #[must_use]
+    pub fn as_milliwatts(&self) -> Power {
+        self.as_unit(PowerUnits::Milliwatts)
+    }
+
+    /// Returns this power as a generic [`Quantity`] of [`Units::Watt`], for SI-prefixed display
+    #[must_use]
+    pub fn as_quantity(&self) -> Quantity<f64> {
+        Quantity::new(self.as_watts().value, Units::Watt)
+    }
+}
+
+impl Display for Power {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_fmt(format_args!(
+            "{:02.3}{}",
+            self.value,
+            self.units.short_name()
+        ))
+    }
+}
+
+impl_dimensional_op!(Div, div, Energy, Duration => Power, |e, d| {
+    Power::new_watts(e.as_joules().value() / d.as_seconds_f64())
+});
+impl_dimensional_op!(Mul, mul, Power, Duration => Energy, |p, d| {
+    Energy::new_joules(p.as_watts().value() * d.as_seconds_f64())
+});
+impl_dimensional_op!(Mul, mul, Duration, Power => Energy, |d, p| p * d);
+impl_dimensional_op!(Div, div, Energy, Power => Duration, |e, p| {
+    Duration::new_seconds(e.as_joules().value() / p.as_watts().value())
+});
+
+static POWER_SPELLINGS: &[UnitSpelling<PowerUnits>] = &[
+    UnitSpelling::new(
+        PowerUnits::Microwatts,
+        &["\u{03BC}W", "\u{00B5}W", "uW"],
+        &["microwatt", "microwatts"],
+    ),
+    UnitSpelling::new(
+        PowerUnits::Milliwatts,
+        &["mW"],
+        &["milliwatt", "milliwatts"],
+    ),
+    UnitSpelling::new(PowerUnits::Kilowatts, &["kW"], &["kilowatt", "kilowatts"]),
+    UnitSpelling::new(PowerUnits::Megawatts, &["MW"], &["megawatt", "megawatts"]),
+    UnitSpelling::prefixed(PowerUnits::Watts, &["W"], &["watt", "watts"]),
+    UnitSpelling::new(PowerUnits::Horsepower, &["hp"], &["horsepower"]),
+    UnitSpelling::new(
+        PowerUnits::BTUPerHour,
+        &["BTU/h", "Btu/h", "BTU/hr"],
+        &["btu per hour"],
+    ),
+];
+crate::parse::impl_from_str!(Power, "power", POWER_SPELLINGS);
+
+pub const HORSEPOWER_TO_WATTS: f64 = 7.456_999E2; // as per NIST 811.2008
+pub const BTU_PER_HOUR_TO_WATTS: f64 = 2.930_711E-1; // as per NIST 811.2008
+
+#[cfg(test)]
+mod tests {
+    use crate::units::duration::Duration;
+    use crate::units::energy::Energy;
+    use crate::units::power::{Power, PowerUnits};
+    use crate::units::FromUnits;
+    use irox_tools::assert_eq_eps;
+
+    #[test]
+    pub fn test_conversions() {
+        assert_eq_eps!(
+            1000.,
+            PowerUnits::Watts.from(1.0_f64, PowerUnits::Kilowatts),
+            1e-9
+        );
+        assert_eq_eps!(
+            745.699_9,
+            PowerUnits::Watts.from(1.0_f64, PowerUnits::Horsepower),
+            1e-6
+        );
+        assert_eq_eps!(
+            3412.14,
+            PowerUnits::BTUPerHour.from(1.0_f64, PowerUnits::Kilowatts),
+            1e-2
+        );
+        assert_eq_eps!(1.5, Power::new_milliwatts(1500.).as_watts().value(), 1e-12);
+    }
+
+    #[test]
+    pub fn test_dimensional() {
+        let power = Energy::new_watt_hours(10.) / Duration::from_hours(2);
+        assert_eq_eps!(5., power.as_watts().value(), 1e-9);
+
+        let energy = Power::new_watts(100.) * Duration::from_minutes(30);
+        assert_eq_eps!(50., energy.as_watt_hours().value(), 1e-9);
+        let energy = Duration::new_seconds(2.) * Power::new_milliwatts(500.);
+        assert_eq_eps!(1., energy.as_joules().value(), 1e-12);
+
+        let runtime = Energy::new_watt_hours(20.) / Power::new_watts(5.);
+        assert_eq_eps!(4. * 3600., runtime.as_seconds_f64(), 1e-9);
+    }
+}
@@ -0,0 +1,246 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! This module contains the basic types and conversions for the SI "Pressure" quantity
+use core::fmt::{Display, Formatter};
+
+use crate::parse::UnitSpelling;
+use crate::prefixes::{HECTO, KILO, MEGA, MILLI};
+use crate::quantities::{Quantity, Units};
+use crate::units::{FromUnits, Unit};
+
+///
+/// Represents a specific pressure unit - SI or otherwise
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum PressureUnits {
+    /// SI Derived Unit for Pressure - Pascals, one Newton per square meter
+    #[default]
+    Pascals,
+
+    /// SI Derived unit hectopascals, commonly used for barometric pressure
+    Hectopascals,
+
+    /// SI Derived unit kilopascals
+    Kilopascals,
+
+    /// SI Derived unit megapascals
+    Megapascals,
+
+    /// Bar, exactly 100,000 Pascals
+    Bar,
+
+    /// Millibar, exactly one hectopascal
+    Millibar,
+
+    /// Standard Atmosphere, exactly 101,325 Pascals
+    Atmospheres,
+
+    /// Torr, 1/760 of a standard atmosphere
+    Torr,
+
+    /// Conventional Millimeters of Mercury
+    MillimetersOfMercury,
+
+    /// Conventional Inches of Mercury
+    InchesOfMercury,
+
+    /// Pound-force per square inch
+    PoundsPerSquareInch,
+}
+
+impl PressureUnits {
+    pub const fn short_name(&self) -> &'static str {
+        match self {
+            PressureUnits::Pascals => "Pa",
+            PressureUnits::Hectopascals => "hPa",
+            PressureUnits::Kilopascals => "kPa",
+            PressureUnits::Megapascals => "MPa",
+            PressureUnits::Bar => "bar",
+            PressureUnits::Millibar => "mbar",
+            PressureUnits::Atmospheres => "atm",
+            PressureUnits::Torr => "Torr",
+            PressureUnits::MillimetersOfMercury => "mmHg",
+            PressureUnits::InchesOfMercury => "inHg",
+            PressureUnits::PoundsPerSquareInch => "psi",
+        }
+    }
+
+    /// The number of pascals in one of this unit
+    pub const fn si_factor(&self) -> f64 {
+        match self {
+            PressureUnits::Pascals => 1.,
+            PressureUnits::Hectopascals => HECTO.scale_factor(),
+            PressureUnits::Kilopascals => KILO.scale_factor(),
+            PressureUnits::Megapascals => MEGA.scale_factor(),
+            PressureUnits::Bar => BAR_TO_PASCALS,
+            PressureUnits::Millibar => BAR_TO_PASCALS * MILLI.scale_factor(),
+            PressureUnits::Atmospheres => ATMOSPHERES_TO_PASCALS,
+            PressureUnits::Torr => TORR_TO_PASCALS,
+            PressureUnits::MillimetersOfMercury => MMHG_TO_PASCALS,
+            PressureUnits::InchesOfMercury => INHG_TO_PASCALS,
+            PressureUnits::PoundsPerSquareInch => PSI_TO_PASCALS,
+        }
+    }
+}
+
+basic_unit!(Pressure, PressureUnits, Pascals);
+from_units_by_factor!(PressureUnits, f32);
+from_units_by_factor!(PressureUnits, f64);
+
+///
+/// Represents a discrete quantity of 'Pressure' as defined in NIST 811.2008
+impl Pressure {
+    #[must_use]
+    pub const fn new_pascals(value: f64) -> Pressure {
+        Self {
+            value,
+            units: PressureUnits::Pascals,
+        }
+    }
+
+    #[must_use]
+    pub const fn new_hectopascals(value: f64) -> Pressure {
+        Self {
+            value,
+            units: PressureUnits::Hectopascals,
+        }
+    }
+
+    #[must_use]
+    pub const fn new_millibar(value: f64) -> Pressure {
+        Self {
+            value,
+            units: PressureUnits::Millibar,
+        }
+    }
+
+    #[must_use]
+    pub fn as_pascals(&self) -> Pressure {
+        self.as_unit(PressureUnits::Pascals)
+    }
+
+    #[must_use]
+    pub fn as_hectopascals(&self) -> Pressure {
+        self.as_unit(PressureUnits::Hectopascals)
+    }
+
+    #[must_use]
+    pub fn as_millibar(&self) -> Pressure {
+        self.as_unit(PressureUnits::Millibar)
+    }
+
+    /// Returns this pressure as a generic [`Quantity`] of [`Units::Pascal`], for SI-prefixed
+    /// display
+    #[must_use]
+    pub fn as_quantity(&self) -> Quantity<f64> {
+        Quantity::new(self.as_pascals().value, Units::Pascal)
+    }
+}
+
+impl Display for Pressure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_fmt(format_args!(
+            "{:02.3}{}",
+            self.value,
+            self.units.short_name()
+        ))
+    }
+}
+
+static PRESSURE_SPELLINGS: &[UnitSpelling<PressureUnits>] = &[
+    UnitSpelling::new(
+        PressureUnits::Hectopascals,
+        &["hPa"],
+        &["hectopascal", "hectopascals"],
+    ),
+    UnitSpelling::new(
+        PressureUnits::Kilopascals,
+        &["kPa"],
+        &["kilopascal", "kilopascals"],
+    ),
+    UnitSpelling::new(
+        PressureUnits::Megapascals,
+        &["MPa"],
+        &["megapascal", "megapascals"],
+    ),
+    UnitSpelling::prefixed(PressureUnits::Pascals, &["Pa"], &["pascal", "pascals"]),
+    UnitSpelling::new(
+        PressureUnits::Millibar,
+        &["mbar", "mb"],
+        &["millibar", "millibars"],
+    ),
+    UnitSpelling::prefixed(PressureUnits::Bar, &["bar"], &["bar", "bars"]),
+    UnitSpelling::new(
+        PressureUnits::Atmospheres,
+        &["atm"],
+        &["atmosphere", "atmospheres"],
+    ),
+    UnitSpelling::new(PressureUnits::Torr, &["Torr", "torr"], &["torr"]),
+    UnitSpelling::new(
+        PressureUnits::MillimetersOfMercury,
+        &["mmHg"],
+        &["millimeter of mercury", "millimeters of mercury"],
+    ),
+    UnitSpelling::new(
+        PressureUnits::InchesOfMercury,
+        &["inHg"],
+        &["inch of mercury", "inches of mercury"],
+    ),
+    UnitSpelling::new(
+        PressureUnits::PoundsPerSquareInch,
+        &["psi", "lbf/in\u{00B2}"],
+        &["pound per square inch", "pounds per square inch"],
+    ),
+];
+crate::parse::impl_from_str!(Pressure, "pressure", PRESSURE_SPELLINGS);
+
+pub const BAR_TO_PASCALS: f64 = 1.0E5; // Exact, as per NIST 811.2008
+pub const PASCALS_TO_BAR: f64 = 1. / BAR_TO_PASCALS;
+pub const ATMOSPHERES_TO_PASCALS: f64 = 1.013_25E5; // Exact, as per NIST 811.2008
+pub const PASCALS_TO_ATMOSPHERES: f64 = 1. / ATMOSPHERES_TO_PASCALS;
+pub const TORR_TO_PASCALS: f64 = ATMOSPHERES_TO_PASCALS / 760.;
+pub const MMHG_TO_PASCALS: f64 = 1.333_224E2; // as per NIST 811.2008
+pub const INHG_TO_PASCALS: f64 = 3.386_389E3; // as per NIST 811.2008
+pub const PSI_TO_PASCALS: f64 = 6.894_757E3; // as per NIST 811.2008
+pub const PASCALS_TO_PSI: f64 = 1. / PSI_TO_PASCALS;
+
+#[cfg(test)]
+mod tests {
+    use crate::units::pressure::{Pressure, PressureUnits};
+    use crate::units::FromUnits;
+    use irox_tools::assert_eq_eps;
+
+    #[test]
+    pub fn test_conversions() {
+        assert_eq_eps!(
+            101_325.,
+            PressureUnits::Pascals.from(1.0_f64, PressureUnits::Atmospheres),
+            1e-9
+        );
+        assert_eq_eps!(
+            1013.25,
+            PressureUnits::Millibar.from(1.0_f64, PressureUnits::Atmospheres),
+            1e-9
+        );
+        assert_eq_eps!(
+            760.,
+            PressureUnits::Torr.from(1.0_f64, PressureUnits::Atmospheres),
+            1e-9
+        );
+        assert_eq_eps!(
+            14.695_95,
+            PressureUnits::PoundsPerSquareInch.from(1.0_f64, PressureUnits::Atmospheres),
+            1e-5
+        );
+        assert_eq_eps!(
+            29.921_26,
+            PressureUnits::InchesOfMercury.from(1.0_f64, PressureUnits::Atmospheres),
+            1e-5
+        );
+        assert_eq!(Pressure::new_hectopascals(1.), Pressure::new_millibar(1.));
+    }
+}
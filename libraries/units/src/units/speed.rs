@@ -5,6 +5,9 @@
 //! This module contains the basic types and conversions for the SI "Speed" quantity
 use core::fmt::{Display, Formatter};
 
+use crate::parse::UnitSpelling;
+use crate::units::duration::Duration;
+use crate::units::length::Length;
 use crate::units::{FromUnits, Unit};
 
 ///
@@ -27,28 +30,21 @@ pub enum SpeedUnits {
     Knots,
 }
 
-macro_rules! from_units_speed {
-    ($type:ident) => {
-        impl crate::units::FromUnits<$type> for SpeedUnits {
-            fn from(&self, value: $type, units: Self) -> $type {
-                match self {
-                    // target
-                    SpeedUnits::MetersPerSecond => match units {
-                        // source
-                        SpeedUnits::MetersPerSecond => value as $type,
-                        SpeedUnits::MilesPerHour => value * MPH_TO_MPS as $type,
-                        SpeedUnits::KilometersPerHour => value * KPH_TO_MPS as $type,
-                        SpeedUnits::Knots => value * KNOT_TO_MPS as $type,
-                    },
-                    _ => todo!(),
-                }
-            }
+impl SpeedUnits {
+    /// The number of meters per second in one of this unit
+    pub const fn si_factor(&self) -> f64 {
+        match self {
+            SpeedUnits::MetersPerSecond => 1.,
+            SpeedUnits::MilesPerHour => MPH_TO_MPS,
+            SpeedUnits::KilometersPerHour => KPH_TO_MPS,
+            SpeedUnits::Knots => KNOT_TO_MPS,
         }
-    };
+    }
 }
+
 basic_unit!(Speed, SpeedUnits, MetersPerSecond);
-from_units_speed!(f32);
-from_units_speed!(f64);
+from_units_by_factor!(SpeedUnits, f32);
+from_units_by_factor!(SpeedUnits, f64);
 
 impl Speed {
     #[must_use]
@@ -68,6 +64,47 @@ impl Display for Speed {
     }
 }
 
+impl_dimensional_op!(Div, div, Length, Duration => Speed, |l, d| {
+    Speed::new_meters_per_second(l.as_meters().value() / d.as_seconds_f64())
+});
+impl_dimensional_op!(Mul, mul, Speed, Duration => Length, |s, d| {
+    Length::new_meters(s.as_meters_per_second().value() * d.as_seconds_f64())
+});
+impl_dimensional_op!(Mul, mul, Duration, Speed => Length, |d, s| s * d);
+impl_dimensional_op!(Div, div, Length, Speed => Duration, |l, s| {
+    Duration::new_seconds(l.as_meters().value() / s.as_meters_per_second().value())
+});
+
+static SPEED_SPELLINGS: &[UnitSpelling<SpeedUnits>] = &[
+    UnitSpelling::new(
+        SpeedUnits::MetersPerSecond,
+        &["m/s", "mps"],
+        &[
+            "meter per second",
+            "meters per second",
+            "metre per second",
+            "metres per second",
+        ],
+    ),
+    UnitSpelling::new(
+        SpeedUnits::KilometersPerHour,
+        &["km/h", "kph", "kmh"],
+        &[
+            "kilometer per hour",
+            "kilometers per hour",
+            "kilometre per hour",
+            "kilometres per hour",
+        ],
+    ),
+    UnitSpelling::new(
+        SpeedUnits::MilesPerHour,
+        &["mph", "mi/h"],
+        &["mile per hour", "miles per hour"],
+    ),
+    UnitSpelling::new(SpeedUnits::Knots, &["kn", "kt", "kts"], &["knot", "knots"]),
+];
+crate::parse::impl_from_str!(Speed, "speed", SPEED_SPELLINGS);
+
 pub const FPS_TO_MPS: f64 = 8.466_667E-5;
 pub const MPS_TO_FPS: f64 = 1.0 / FPS_TO_MPS;
 pub const KPH_TO_MPS: f64 = 2.777_778E-1;
@@ -76,3 +113,33 @@ pub const KNOT_TO_MPS: f64 = 5.144_444E-1;
 pub const MPS_TO_KNOT: f64 = 1.0 / KNOT_TO_MPS;
 pub const MPH_TO_MPS: f64 = 4.4704E-1;
 pub const MPS_TO_MPH: f64 = 1.0 / MPH_TO_MPS;
+
+#[cfg(test)]
+mod tests {
+    use crate::units::duration::Duration;
+    use crate::units::length::Length;
+    use crate::units::speed::Speed;
+    use irox_tools::assert_eq_eps;
+
+    #[test]
+    pub fn test_dimensional() {
+        let speed = Length::new_meters(100.) / Duration::new_seconds(20.);
+        assert_eq_eps!(5., speed.as_meters_per_second().value(), 1e-12);
+
+        let len = Speed::new_meters_per_second(3.) * Duration::from_minutes(1);
+        assert_eq_eps!(180., len.as_meters().value(), 1e-12);
+        let len = Duration::from_millis(500) * Speed::new_meters_per_second(3.);
+        assert_eq_eps!(1.5, len.as_meters().value(), 1e-12);
+
+        let dur = Length::new_feet(1000.) / Speed::new_meters_per_second(0.3048);
+        assert_eq_eps!(1000., dur.as_seconds_f64(), 1e-9);
+    }
+
+    #[test]
+    pub fn test_self_mul() {
+        let a = Speed::new_meters_per_second(2.);
+        let b = Speed::new_meters_per_second(3.);
+        let sq: f64 = a * b;
+        assert_eq_eps!(6., sq, 1e-12);
+    }
+}
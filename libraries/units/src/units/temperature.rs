@@ -4,6 +4,7 @@
 //!
 //! Contains [`Temperature`] and [`TemperatureUnits`] - physical measurements of the SI "Temperature" quantity
 //!
+use crate::parse::UnitSpelling;
 use crate::units::{FromUnits, Unit};
 
 ///
@@ -155,6 +156,26 @@ impl Temperature {
     }
 }
 
+static TEMPERATURE_SPELLINGS: &[UnitSpelling<TemperatureUnits>] = &[
+    UnitSpelling::prefixed(TemperatureUnits::Kelvin, &["K"], &["kelvin", "kelvins"]),
+    UnitSpelling::new(
+        TemperatureUnits::Celsius,
+        &["\u{00B0}C", "\u{2103}", "C", "degC"],
+        &["celsius", "degrees celsius"],
+    ),
+    UnitSpelling::new(
+        TemperatureUnits::Fahrenheit,
+        &["\u{00B0}F", "\u{2109}", "F", "degF"],
+        &["fahrenheit", "degrees fahrenheit"],
+    ),
+    UnitSpelling::new(
+        TemperatureUnits::Rankine,
+        &["\u{00B0}R", "R", "degR"],
+        &["rankine", "degrees rankine"],
+    ),
+];
+crate::parse::impl_from_str!(Temperature, "temperature", TEMPERATURE_SPELLINGS);
+
 pub const CELSIUS_KELVIN_OFFSET: f64 = 273.15;
 pub const CELSIUS_FAHRENHEIT_OFFSET: f64 = 32.;
 pub const CELSIUS_FAHRENHEIT_FACTOR: f64 = 1.8;
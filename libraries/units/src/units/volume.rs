@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2025 IROX Contributors
+//
+
+//!
+//! This module contains the basic types and conversions for the SI "Volume" quantity
+use core::fmt::{Display, Formatter};
+
+use crate::parse::UnitSpelling;
+use crate::prefixes::MILLI;
+use crate::quantities::{Quantity, Units};
+use crate::units::area::Area;
+use crate::units::length::{Length, FEET_TO_METERS};
+use crate::units::{FromUnits, Unit};
+
+///
+/// Represents a specific volume unit - SI or otherwise
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum VolumeUnits {
+    /// SI Derived Unit for Volume - Cubic Meters
+    #[default]
+    CubicMeters,
+
+    /// Liter, one cubic decimeter
+    Liters,
+
+    /// Milliliter, one cubic centimeter
+    Milliliters,
+
+    /// US Imperial "Cubic Foot"
+    CubicFeet,
+
+    /// US liquid gallon
+    USGallons,
+
+    /// US fluid ounce, 1/128 of a US liquid gallon
+    USFluidOunces,
+
+    /// UK Imperial gallon
+    ImperialGallons,
+
+    /// Oil barrel, 42 US liquid gallons
+    Barrels,
+}
+
+impl VolumeUnits {
+    pub const fn short_name(&self) -> &'static str {
+        match self {
+            VolumeUnits::CubicMeters => "m\u{00B3}",
+            VolumeUnits::Liters => "L",
+            VolumeUnits::Milliliters => "mL",
+            VolumeUnits::CubicFeet => "ft\u{00B3}",
+            VolumeUnits::USGallons => "gal",
+            VolumeUnits::USFluidOunces => "floz",
+            VolumeUnits::ImperialGallons => "impgal",
+            VolumeUnits::Barrels => "bbl",
+        }
+    }
+
+    /// The number of cubic meters in one of this unit
+    pub const fn si_factor(&self) -> f64 {
+        match self {
+            VolumeUnits::CubicMeters => 1.,
+            VolumeUnits::Liters => LITERS_TO_CUBIC_METERS,
+            VolumeUnits::Milliliters => LITERS_TO_CUBIC_METERS * MILLI.scale_factor(),
+            VolumeUnits::CubicFeet => FEET_TO_METERS * FEET_TO_METERS * FEET_TO_METERS,
+            VolumeUnits::USGallons => US_GALLONS_TO_CUBIC_METERS,
+            VolumeUnits::USFluidOunces => US_GALLONS_TO_CUBIC_METERS / 128.,
+            VolumeUnits::ImperialGallons => IMPERIAL_GALLONS_TO_CUBIC_METERS,
+            VolumeUnits::Barrels => US_GALLONS_TO_CUBIC_METERS * 42.,
+        }
+    }
+}
+
+basic_unit!(Volume, VolumeUnits, CubicMeters);
+from_units_by_factor!(VolumeUnits, f32);
+from_units_by_factor!(VolumeUnits, f64);
+
+///
+/// Represents a discrete quantity of 'Volume' as defined in NIST 811.2008
+impl Volume {
+    #[must_use]
+    pub const fn new_cubic_meters(value: f64) -> Volume {
+        Self {
+            value,
+            units: VolumeUnits::CubicMeters,
+        }
+    }
+
+    #[must_use]
+    pub const fn new_liters(value: f64) -> Volume {
+        Self {
+            value,
+            units: VolumeUnits::Liters,
+        }
+    }
+
+    #[must_use]
+    pub const fn new_us_gallons(value: f64) -> Volume {
+        Self {
+            value,
+            units: VolumeUnits::USGallons,
+        }
+    }
+
+    #[must_use]
+    pub fn as_cubic_meters(&self) -> Volume {
+        self.as_unit(VolumeUnits::CubicMeters)
+    }
+
+    #[must_use]
+    pub fn as_liters(&self) -> Volume {
+        self.as_unit(VolumeUnits::Liters)
+    }
+
+    #[must_use]
+    pub fn as_us_gallons(&self) -> Volume {
+        self.as_unit(VolumeUnits::USGallons)
+    }
+
+    /// Returns this volume as a generic [`Quantity`] of [`Units::CubicMeter`], for SI-prefixed
+    /// display
+    #[must_use]
+    pub fn as_quantity(&self) -> Quantity<f64> {
+        Quantity::new(self.as_cubic_meters().value, Units::CubicMeter)
+    }
+}
+
+impl Display for Volume {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_fmt(format_args!(
+            "{:02.3}{}",
+            self.value,
+            self.units.short_name()
+        ))
+    }
+}
+
+impl_dimensional_op!(Mul, mul, Area, Length => Volume, |a, l| {
+    Volume::new_cubic_meters(a.as_square_meters().value() * l.as_meters().value())
+});
+impl_dimensional_op!(Mul, mul, Length, Area => Volume, |l, a| a * l);
+impl_dimensional_op!(Div, div, Volume, Length => Area, |v, l| {
+    Area::new_square_meters(v.as_cubic_meters().value() / l.as_meters().value())
+});
+impl_dimensional_op!(Div, div, Volume, Area => Length, |v, a| {
+    Length::new_meters(v.as_cubic_meters().value() / a.as_square_meters().value())
+});
+
+static VOLUME_SPELLINGS: &[UnitSpelling<VolumeUnits>] = &[
+    UnitSpelling::new(
+        VolumeUnits::CubicMeters,
+        &["m\u{00B3}", "m3", "m^3"],
+        &["cubic meter", "cubic meters", "cubic metre", "cubic metres"],
+    ),
+    UnitSpelling::new(
+        VolumeUnits::Milliliters,
+        &["mL", "ml"],
+        &["milliliter", "milliliters", "millilitre", "millilitres"],
+    ),
+    UnitSpelling::scaled(
+        VolumeUnits::Milliliters,
+        1.,
+        &["cm\u{00B3}", "cm3", "cc"],
+        &[
+            "cubic centimeter",
+            "cubic centimeters",
+            "cubic centimetre",
+            "cubic centimetres",
+        ],
+    ),
+    UnitSpelling::prefixed(
+        VolumeUnits::Liters,
+        &["L", "l"],
+        &["liter", "liters", "litre", "litres"],
+    ),
+    UnitSpelling::new(
+        VolumeUnits::CubicFeet,
+        &["ft\u{00B3}", "ft3", "ft^3", "cuft"],
+        &["cubic foot", "cubic feet"],
+    ),
+    UnitSpelling::new(
+        VolumeUnits::USGallons,
+        &["gal"],
+        &["gallon", "gallons", "us gallon", "us gallons"],
+    ),
+    UnitSpelling::new(
+        VolumeUnits::USFluidOunces,
+        &["floz", "fl oz"],
+        &["fluid ounce", "fluid ounces"],
+    ),
+    UnitSpelling::new(
+        VolumeUnits::ImperialGallons,
+        &["impgal"],
+        &["imperial gallon", "imperial gallons"],
+    ),
+    UnitSpelling::new(VolumeUnits::Barrels, &["bbl"], &["barrel", "barrels"]),
+];
+crate::parse::impl_from_str!(Volume, "volume", VOLUME_SPELLINGS);
+
+pub const LITERS_TO_CUBIC_METERS: f64 = 1.0E-3; // Exact, as per NIST 811.2008
+pub const CUBIC_METERS_TO_LITERS: f64 = 1. / LITERS_TO_CUBIC_METERS;
+pub const US_GALLONS_TO_CUBIC_METERS: f64 = 3.785_411_784E-3; // Exact, as per NIST 811.2008
+pub const IMPERIAL_GALLONS_TO_CUBIC_METERS: f64 = 4.546_09E-3; // Exact, as per NIST 811.2008
+
+#[cfg(test)]
+mod tests {
+    use crate::units::area::Area;
+    use crate::units::length::Length;
+    use crate::units::volume::{Volume, VolumeUnits};
+    use crate::units::{FromUnits, Unit};
+    use irox_tools::assert_eq_eps;
+
+    #[test]
+    pub fn test_conversions() {
+        assert_eq_eps!(
+            3.785_411_784,
+            VolumeUnits::Liters.from(1.0_f64, VolumeUnits::USGallons),
+            1e-12
+        );
+        assert_eq_eps!(
+            1000.,
+            VolumeUnits::Liters.from(1.0_f64, VolumeUnits::CubicMeters),
+            1e-12
+        );
+        assert_eq_eps!(
+            28.316_846_592,
+            VolumeUnits::Liters.from(1.0_f64, VolumeUnits::CubicFeet),
+            1e-9
+        );
+        assert_eq_eps!(
+            158.987_294_928,
+            VolumeUnits::Liters.from(1.0_f64, VolumeUnits::Barrels),
+            1e-9
+        );
+        assert_eq_eps!(
+            128.,
+            Volume::new_us_gallons(1.)
+                .as_unit(VolumeUnits::USFluidOunces)
+                .value(),
+            1e-9
+        );
+    }
+
+    #[test]
+    pub fn test_dimensional() {
+        let vol = Length::new_meters(0.1) * Length::new_meters(0.1) * Length::new_meters(0.1);
+        assert_eq_eps!(1., vol.as_liters().value(), 1e-12);
+        let vol = Length::new_meters(2.) * Area::new_square_meters(3.);
+        assert_eq_eps!(6., vol.as_cubic_meters().value(), 1e-12);
+
+        let area = Volume::new_liters(1000.) / Length::new_meters(0.5);
+        assert_eq_eps!(2., area.as_square_meters().value(), 1e-12);
+        let len = Volume::new_cubic_meters(6.) / Area::new_square_meters(3.);
+        assert_eq_eps!(2., len.as_meters().value(), 1e-12);
+    }
+}
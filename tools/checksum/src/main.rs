@@ -145,7 +145,7 @@ async fn main() -> Result<(), std::io::Error> {
 #[command(version, about)] // Read from `Cargo.toml`
 pub struct Args {
     #[arg(short = 'b')]
-    /// <md5> <sha1> <sha224> <sha256> <sha384> <sha512> <murmur3_128|murmur3|m3> <b2|b2b|blake2b|blake2b512> <b2s|blake2s|blake2s256> <murmur3_32> <blake2s128>
+    /// <md5> <sha1> <sha224> <sha256> <sha384> <sha512> <murmur3_128|murmur3|m3> <b2|b2b|blake2b|blake2b512> <b2s|blake2s|blake2s256> <murmur3_32> <blake2s128> <sha3_224|sha3-224> <sha3_256|sha3-256|sha3> <sha3_384|sha3-384> <sha3_512|sha3-512> <shake128> <shake256> <keccak224|keccak-224> <keccak256|keccak-256|keccak> <keccak384|keccak-384> <keccak512|keccak-512>
     algorithm: Vec<HashAlgorithm>,
 
     #[arg(short = 'a')]
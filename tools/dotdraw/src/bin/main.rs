@@ -22,7 +22,9 @@ pub fn main() {
         use irox_egui_extras::{eframe, egui};
         use irox_log::log::error;
 
-        let viewport = ViewportBuilder::default().with_inner_size(Vec2::new(1024., 800.));
+        const WIDTH: f32 = 1024.;
+        const HEIGHT: f32 = 800.;
+        let viewport = ViewportBuilder::default().with_inner_size(Vec2::new(WIDTH, HEIGHT));
 
         let native_options = eframe::NativeOptions {
             viewport,
@@ -30,11 +32,40 @@ pub fn main() {
 
             ..Default::default()
         };
-        let init = InitData {
+        let mut init = InitData {
             dotjsondata: vec![],
+            dotjson: None,
             scale: 0.044,
             pos: [600., 1000.],
         };
+        // either a DOT file, laid out natively, or the JSON output of Graphviz.
+        if let Some(path) = std::env::args().nth(1) {
+            let data = match std::fs::read(&path) {
+                Ok(data) => data,
+                Err(e) => {
+                    error!("Error reading {path}: {e}");
+                    return;
+                }
+            };
+            let dotjson = match irox_dot::drawing::DotJson::load(&data) {
+                Ok(d) => d,
+                Err(e) => {
+                    error!("Error loading {path}: {e}");
+                    return;
+                }
+            };
+            // fit the drawing to the window, the drawing is y-up so it sits above the origin.
+            if let Some([x0, y0, x1, y1]) = dotjson.bounding_box() {
+                let (width, height) = ((x1 - x0) as f32, (y1 - y0) as f32);
+                let scale = (WIDTH / width).min(HEIGHT / height).min(1.0) * 0.9;
+                init.scale = scale;
+                init.pos = [
+                    WIDTH / 2. - f64::midpoint(x0, x1) as f32 * scale,
+                    HEIGHT / 2. + f64::midpoint(y0, y1) as f32 * scale,
+                ];
+            }
+            init.dotjson = Some(dotjson);
+        }
         if let Err(e) = eframe::run_native(
             "draw-panels",
             native_options,
@@ -62,6 +62,7 @@ impl WebHandle {
 
         let init = InitData {
             dotjsondata: data,
+            dotjson: None,
             scale,
             pos: [*x, *y],
         };
@@ -73,6 +73,12 @@ impl From<irox_sirf::error::Error> for GPSdError {
     }
 }
 
+impl From<irox_bits::BitsError> for GPSdError {
+    fn from(value: irox_bits::BitsError) -> Self {
+        GPSdError::new_str(format!("{value:?}"))
+    }
+}
+
 #[cfg(target_os = "windows")]
 impl From<irox_winlocation_api::Error> for GPSdError {
     fn from(value: irox_winlocation_api::Error) -> Self {
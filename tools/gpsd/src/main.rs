@@ -6,7 +6,7 @@ use std::time::Duration;
 use clap::Parser;
 use log::{error, info};
 
-use config::{GPSdConfig, Transport};
+use config::{EncodingType, GPSdConfig, Transport};
 use error::GPSdError;
 use output::FrameGenerator;
 #[cfg(not(target_arch = "wasm32"))]
@@ -76,6 +76,11 @@ pub fn start_serial(
             return Err(e.0);
         }
     };
+    if let EncodingType::SirfBinary = encoding {
+        if let Err(e) = sirf::setup_device(&mut port) {
+            error!("Unable to configure SiRF device: {e:?}");
+        }
+    }
     let mut framebuilder = FrameGenerator::new(encoding, &mut port);
     while !shouldquit.load(Ordering::Relaxed) {
         let frame = framebuilder.build_from();
@@ -13,6 +13,16 @@ impl MaybeFrom<Frame> for crate::output::Frame {
             FramePayload::GNS(_) => None,
             FramePayload::GSV(_) => None,
             FramePayload::RMC(_) => None,
+            FramePayload::VTG(_) => None,
+            FramePayload::GLL(_) => None,
+            FramePayload::ZDA(_) => None,
+            FramePayload::GST(_) => None,
+            FramePayload::GBS(_) => None,
+            FramePayload::HDT(_) => None,
+            FramePayload::THS(_) => None,
+            FramePayload::TXT(_) => None,
+            FramePayload::PUBX(_) => None,
+            FramePayload::AIVDM(_) => None,
             FramePayload::Unknown { .. } => None,
         }
     }
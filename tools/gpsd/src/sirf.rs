@@ -1,21 +1,35 @@
 // SPDX-License-Identifier: MIT
 // Copyright 2023 IROX Contributors
 
+use irox_bits::MutBits;
 use irox_carto::coordinate::{EllipticalCoordinateBuilder, Latitude, Longitude};
 use irox_carto::geo::standards::wgs84::WGS84_SHAPE;
 use irox_carto::geo::EllipticalShape;
 use irox_carto::gps::GPSFixType;
 use irox_sirf::input::x29_geonavdata::{GeodeticNavigationData, PositionFixType};
+use irox_sirf::output::x84_pollswversion::PollSoftwareVersion;
+use irox_sirf::output::xa6_setmsgrate::SetMessageRate;
 use irox_sirf::packet::PacketType;
 use irox_time::datetime::UTCDateTime;
 use irox_time::gregorian::Date;
 use irox_time::Time;
 use irox_tools::options::MaybeFrom;
+use irox_tools::packetio::Packet;
 use irox_units::units::angle::Angle;
 use irox_units::units::duration::MILLIS_TO_SEC;
 
+use crate::error::GPSdError;
 use crate::output::{Frame, FramePayload, TPV};
 
+///
+/// Configures a newly connected SiRF receiver: requests the geodetic navigation data every
+/// second, and polls the software version.
+pub fn setup_device<T: MutBits>(port: &mut T) -> Result<(), GPSdError> {
+    SetMessageRate::enable(0x29, 1).write_to(port)?;
+    PollSoftwareVersion.write_to(port)?;
+    Ok(())
+}
+
 impl MaybeFrom<PacketType> for Frame {
     #[allow(clippy::match_same_arms)]
     fn maybe_from(value: PacketType) -> Option<Self> {